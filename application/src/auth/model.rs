@@ -1,4 +1,44 @@
+use domain::{
+    depot::repositories::depot_repository::{DepotRepository, DepotRepositoryError},
+    user::entities::user::UserIdentity,
+};
+
+/// Built by [`AuthenticatedUser::resolve`] only, so the depot scope of a manager can't be left
+/// out.
+#[non_exhaustive]
 pub struct AuthenticatedUser {
     pub user_id: uuid::Uuid,
     pub email: String,
+    /// The organization (tenant) every use case of the user is scoped to.
+    pub organization_id: uuid::Uuid,
+    /// The depots a depot manager is limited to, `None` for a user who sees every vehicle of the
+    /// organization.
+    pub depot_scope: Option<Vec<uuid::Uuid>>,
+}
+
+impl AuthenticatedUser {
+    /// Resolves the user with their depot scope: a user managing depots is limited to them. The
+    /// tenant is resolved from the user, never from the input of a use case.
+    pub async fn resolve(
+        user: &UserIdentity,
+        depots: &impl DepotRepository,
+    ) -> Result<Self, DepotRepositoryError> {
+        let managed = depots.managed_depots(user.organization_id, user.id).await?;
+        Ok(AuthenticatedUser {
+            user_id: user.id,
+            email: user.email.value().to_string(),
+            organization_id: user.organization_id,
+            depot_scope: (!managed.is_empty()).then_some(managed),
+        })
+    }
+
+    /// Whether the user sees a vehicle based at `depot_id` (`None` for a vehicle without a home
+    /// depot, which only the users of the whole organization see).
+    pub fn sees_depot(&self, depot_id: Option<uuid::Uuid>) -> bool {
+        match (&self.depot_scope, depot_id) {
+            (None, _) => true,
+            (Some(scope), Some(depot_id)) => scope.contains(&depot_id),
+            (Some(_), None) => false,
+        }
+    }
 }
@@ -0,0 +1,12 @@
+pub struct AssignDepotManagerCommand {
+    pub depot_id: uuid::Uuid,
+    /// The user who becomes a manager of the depot, limited to the depots they manage.
+    pub user_id: uuid::Uuid,
+}
+
+pub struct AssignDepotManagerResponse {
+    pub depot_id: uuid::Uuid,
+    pub user_id: uuid::Uuid,
+    /// Every depot the user manages after the assignment.
+    pub managed_depots: Vec<uuid::Uuid>,
+}
@@ -0,0 +1,11 @@
+use domain::depot::repositories::depot_repository::DepotRepositoryError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum AssignDepotManagerError {
+    #[error("Depot managers can't assign depot managers")]
+    Forbidden,
+    #[error("Depot not found: {0}")]
+    DepotNotFound(uuid::Uuid),
+    #[error("Repository error: {0}")]
+    Repository(#[from] DepotRepositoryError),
+}
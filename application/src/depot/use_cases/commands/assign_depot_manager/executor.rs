@@ -0,0 +1,43 @@
+use super::{
+    dto::{AssignDepotManagerCommand as Input, AssignDepotManagerResponse as Output},
+    error::AssignDepotManagerError as Error,
+};
+use crate::auth::AuthenticatedUser;
+use domain::depot::repositories::depot_repository::DepotRepository;
+
+pub struct AssignDepotManagerUseCase<'a, DR: DepotRepository + 'a> {
+    depot_repository: &'a DR,
+}
+
+impl<'a, DR: DepotRepository + 'a> AssignDepotManagerUseCase<'a, DR> {
+    pub fn new(depot_repository: &'a DR) -> Self {
+        AssignDepotManagerUseCase { depot_repository }
+    }
+
+    pub async fn execute(&self, cmd: Input, user: &AuthenticatedUser) -> Result<Output, Error> {
+        // Only the users of the whole organization hand out depots
+        if user.depot_scope.is_some() {
+            return Err(Error::Forbidden);
+        }
+
+        // Check the depot exists
+        self.depot_repository
+            .find_depot(user.organization_id, cmd.depot_id)
+            .await?
+            .ok_or(Error::DepotNotFound(cmd.depot_id))?;
+
+        self.depot_repository
+            .assign_manager(user.organization_id, cmd.depot_id, cmd.user_id)
+            .await?;
+        let managed_depots = self
+            .depot_repository
+            .managed_depots(user.organization_id, cmd.user_id)
+            .await?;
+
+        Ok(Output {
+            depot_id: cmd.depot_id,
+            user_id: cmd.user_id,
+            managed_depots,
+        })
+    }
+}
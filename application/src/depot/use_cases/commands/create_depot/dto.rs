@@ -0,0 +1,24 @@
+use domain::depot::entities::depot::Depot;
+
+pub struct CreateDepotCommand {
+    pub region_id: uuid::Uuid,
+    pub name: String,
+}
+
+pub struct CreateDepotResponse {
+    pub id: uuid::Uuid,
+    pub region_id: uuid::Uuid,
+    pub name: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<Depot> for CreateDepotResponse {
+    fn from(depot: Depot) -> Self {
+        CreateDepotResponse {
+            id: depot.id,
+            region_id: depot.region_id,
+            name: depot.name.value().to_string(),
+            created_at: depot.created_at,
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use domain::depot::{
+    repositories::depot_repository::DepotRepositoryError,
+    value_types::location_name::LocationNameError,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum CreateDepotError {
+    #[error("Depot managers can't change the location hierarchy")]
+    Forbidden,
+    #[error("Region not found: {0}")]
+    RegionNotFound(uuid::Uuid),
+    #[error("Invalid name: {0}")]
+    InvalidName(#[from] LocationNameError),
+    #[error("Depot already exists: {0}")]
+    AlreadyExists(String),
+    #[error("Repository error: {0}")]
+    Repository(#[from] DepotRepositoryError),
+}
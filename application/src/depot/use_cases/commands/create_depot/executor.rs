@@ -0,0 +1,44 @@
+use super::{
+    dto::{CreateDepotCommand as Input, CreateDepotResponse as Output},
+    error::CreateDepotError as Error,
+};
+use crate::auth::AuthenticatedUser;
+use domain::depot::{
+    entities::depot::Depot,
+    repositories::depot_repository::{DepotRepository, DepotRepositoryError},
+    value_types::location_name::LocationName,
+};
+
+pub struct CreateDepotUseCase<'a, DR: DepotRepository + 'a> {
+    depot_repository: &'a DR,
+}
+
+impl<'a, DR: DepotRepository + 'a> CreateDepotUseCase<'a, DR> {
+    pub fn new(depot_repository: &'a DR) -> Self {
+        CreateDepotUseCase { depot_repository }
+    }
+
+    pub async fn execute(&self, cmd: Input, user: &AuthenticatedUser) -> Result<Output, Error> {
+        if user.depot_scope.is_some() {
+            return Err(Error::Forbidden);
+        }
+
+        // Check the region exists
+        self.depot_repository
+            .find_region(user.organization_id, cmd.region_id)
+            .await?
+            .ok_or(Error::RegionNotFound(cmd.region_id))?;
+
+        let depot = Depot::new(cmd.region_id, LocationName::new(cmd.name)?);
+        let created = self
+            .depot_repository
+            .create_depot(user.organization_id, depot)
+            .await
+            .map_err(|e| match e {
+                DepotRepositoryError::AlreadyExists(name) => Error::AlreadyExists(name),
+                e => e.into(),
+            })?;
+
+        Ok(Output::from(created))
+    }
+}
@@ -0,0 +1,21 @@
+use domain::depot::entities::region::Region;
+
+pub struct CreateRegionCommand {
+    pub name: String,
+}
+
+pub struct CreateRegionResponse {
+    pub id: uuid::Uuid,
+    pub name: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<Region> for CreateRegionResponse {
+    fn from(region: Region) -> Self {
+        CreateRegionResponse {
+            id: region.id,
+            name: region.name.value().to_string(),
+            created_at: region.created_at,
+        }
+    }
+}
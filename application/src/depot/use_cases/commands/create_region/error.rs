@@ -0,0 +1,16 @@
+use domain::depot::{
+    repositories::depot_repository::DepotRepositoryError,
+    value_types::location_name::LocationNameError,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum CreateRegionError {
+    #[error("Depot managers can't change the location hierarchy")]
+    Forbidden,
+    #[error("Invalid name: {0}")]
+    InvalidName(#[from] LocationNameError),
+    #[error("Region already exists: {0}")]
+    AlreadyExists(String),
+    #[error("Repository error: {0}")]
+    Repository(#[from] DepotRepositoryError),
+}
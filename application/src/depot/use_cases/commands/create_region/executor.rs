@@ -0,0 +1,38 @@
+use super::{
+    dto::{CreateRegionCommand as Input, CreateRegionResponse as Output},
+    error::CreateRegionError as Error,
+};
+use crate::auth::AuthenticatedUser;
+use domain::depot::{
+    entities::region::Region,
+    repositories::depot_repository::{DepotRepository, DepotRepositoryError},
+    value_types::location_name::LocationName,
+};
+
+pub struct CreateRegionUseCase<'a, DR: DepotRepository + 'a> {
+    depot_repository: &'a DR,
+}
+
+impl<'a, DR: DepotRepository + 'a> CreateRegionUseCase<'a, DR> {
+    pub fn new(depot_repository: &'a DR) -> Self {
+        CreateRegionUseCase { depot_repository }
+    }
+
+    pub async fn execute(&self, cmd: Input, user: &AuthenticatedUser) -> Result<Output, Error> {
+        if user.depot_scope.is_some() {
+            return Err(Error::Forbidden);
+        }
+
+        let region = Region::new(LocationName::new(cmd.name)?);
+        let created = self
+            .depot_repository
+            .create_region(user.organization_id, region)
+            .await
+            .map_err(|e| match e {
+                DepotRepositoryError::AlreadyExists(name) => Error::AlreadyExists(name),
+                e => e.into(),
+            })?;
+
+        Ok(Output::from(created))
+    }
+}
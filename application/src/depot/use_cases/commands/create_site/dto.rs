@@ -0,0 +1,24 @@
+use domain::depot::entities::site::Site;
+
+pub struct CreateSiteCommand {
+    pub depot_id: uuid::Uuid,
+    pub name: String,
+}
+
+pub struct CreateSiteResponse {
+    pub id: uuid::Uuid,
+    pub depot_id: uuid::Uuid,
+    pub name: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<Site> for CreateSiteResponse {
+    fn from(site: Site) -> Self {
+        CreateSiteResponse {
+            id: site.id,
+            depot_id: site.depot_id,
+            name: site.name.value().to_string(),
+            created_at: site.created_at,
+        }
+    }
+}
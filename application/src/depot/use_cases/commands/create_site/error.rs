@@ -0,0 +1,16 @@
+use domain::depot::{
+    repositories::depot_repository::DepotRepositoryError,
+    value_types::location_name::LocationNameError,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum CreateSiteError {
+    #[error("Depot not found: {0}")]
+    DepotNotFound(uuid::Uuid),
+    #[error("Invalid name: {0}")]
+    InvalidName(#[from] LocationNameError),
+    #[error("Site already exists: {0}")]
+    AlreadyExists(String),
+    #[error("Repository error: {0}")]
+    Repository(#[from] DepotRepositoryError),
+}
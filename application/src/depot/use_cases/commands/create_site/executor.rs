@@ -0,0 +1,43 @@
+use super::{
+    dto::{CreateSiteCommand as Input, CreateSiteResponse as Output},
+    error::CreateSiteError as Error,
+};
+use crate::auth::AuthenticatedUser;
+use domain::depot::{
+    entities::site::Site,
+    repositories::depot_repository::{DepotRepository, DepotRepositoryError},
+    value_types::location_name::LocationName,
+};
+
+pub struct CreateSiteUseCase<'a, DR: DepotRepository + 'a> {
+    depot_repository: &'a DR,
+}
+
+impl<'a, DR: DepotRepository + 'a> CreateSiteUseCase<'a, DR> {
+    pub fn new(depot_repository: &'a DR) -> Self {
+        CreateSiteUseCase { depot_repository }
+    }
+
+    /// A depot manager can add sites to the depots they manage.
+    pub async fn execute(&self, cmd: Input, user: &AuthenticatedUser) -> Result<Output, Error> {
+        // A depot out of the scope of the user is reported as missing
+        let depot = self
+            .depot_repository
+            .find_depot(user.organization_id, cmd.depot_id)
+            .await?
+            .filter(|depot| user.sees_depot(Some(depot.id)))
+            .ok_or(Error::DepotNotFound(cmd.depot_id))?;
+
+        let site = Site::new(depot.id, LocationName::new(cmd.name)?);
+        let created = self
+            .depot_repository
+            .create_site(user.organization_id, site)
+            .await
+            .map_err(|e| match e {
+                DepotRepositoryError::AlreadyExists(name) => Error::AlreadyExists(name),
+                e => e.into(),
+            })?;
+
+        Ok(Output::from(created))
+    }
+}
@@ -0,0 +1,5 @@
+pub mod assign_depot_manager;
+pub mod create_depot;
+pub mod create_region;
+pub mod create_site;
+pub mod move_vehicle;
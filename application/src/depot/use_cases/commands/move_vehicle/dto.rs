@@ -0,0 +1,33 @@
+use domain::depot::entities::vehicle_movement::VehicleMovement;
+
+pub struct MoveVehicleCommand {
+    pub vehicle_id: uuid::Uuid,
+    pub to_depot_id: uuid::Uuid,
+    /// When the vehicle moved, now if not given.
+    pub moved_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub notes: String,
+}
+
+pub struct MoveVehicleResponse {
+    pub id: uuid::Uuid,
+    pub vehicle_id: uuid::Uuid,
+    pub from_depot_id: Option<uuid::Uuid>,
+    pub to_depot_id: uuid::Uuid,
+    pub moved_at: chrono::DateTime<chrono::Utc>,
+    pub moved_by: uuid::Uuid,
+    pub notes: String,
+}
+
+impl From<VehicleMovement> for MoveVehicleResponse {
+    fn from(movement: VehicleMovement) -> Self {
+        MoveVehicleResponse {
+            id: movement.id,
+            vehicle_id: movement.vehicle_id,
+            from_depot_id: movement.from_depot_id,
+            to_depot_id: movement.to_depot_id,
+            moved_at: movement.moved_at,
+            moved_by: movement.moved_by,
+            notes: movement.notes,
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use crate::vehicle::traits::vehicle_repository::VehicleApplicationRepositoryError;
+use domain::depot::{
+    entities::vehicle_movement::VehicleMovementError,
+    repositories::{
+        depot_repository::DepotRepositoryError,
+        vehicle_movement_repository::VehicleMovementRepositoryError,
+    },
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum MoveVehicleError {
+    #[error("Vehicle not found: {0}")]
+    VehicleNotFound(uuid::Uuid),
+    #[error("Depot not found: {0}")]
+    DepotNotFound(uuid::Uuid),
+    #[error("Invalid movement: {0}")]
+    Validation(#[from] VehicleMovementError),
+    #[error("Vehicle repository error: {0}")]
+    VehicleRepository(#[from] VehicleApplicationRepositoryError),
+    #[error("Depot repository error: {0}")]
+    DepotRepository(#[from] DepotRepositoryError),
+    #[error("Vehicle movement repository error: {0}")]
+    VehicleMovementRepository(#[from] VehicleMovementRepositoryError),
+}
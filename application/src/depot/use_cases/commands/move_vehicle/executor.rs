@@ -0,0 +1,85 @@
+use super::{
+    dto::{MoveVehicleCommand as Input, MoveVehicleResponse as Output},
+    error::MoveVehicleError as Error,
+};
+use crate::{
+    auth::AuthenticatedUser,
+    vehicle::{
+        scope::ensure_vehicle_in_scope, traits::vehicle_repository::VehicleApplicationRepository,
+    },
+};
+use domain::depot::{
+    entities::vehicle_movement::VehicleMovement,
+    repositories::{
+        depot_repository::DepotRepository, vehicle_movement_repository::VehicleMovementRepository,
+    },
+};
+
+pub struct MoveVehicleUseCase<
+    'a,
+    VAR: VehicleApplicationRepository + 'a,
+    DR: DepotRepository + 'a,
+    VMR: VehicleMovementRepository + 'a,
+> {
+    vehicle_repository: &'a VAR,
+    depot_repository: &'a DR,
+    vehicle_movement_repository: &'a VMR,
+}
+
+impl<'a, VAR, DR, VMR> MoveVehicleUseCase<'a, VAR, DR, VMR>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    DR: DepotRepository + 'a,
+    VMR: VehicleMovementRepository + 'a,
+{
+    pub fn new(
+        vehicle_repository: &'a VAR,
+        depot_repository: &'a DR,
+        vehicle_movement_repository: &'a VMR,
+    ) -> Self {
+        MoveVehicleUseCase {
+            vehicle_repository,
+            depot_repository,
+            vehicle_movement_repository,
+        }
+    }
+
+    /// Moves a vehicle to a depot (its first assignment when it has no home depot yet).
+    ///
+    /// A depot manager can only move the vehicles based at their depots, to any depot of the
+    /// organization (handing them over to another depot).
+    pub async fn execute(&self, cmd: Input, user: &AuthenticatedUser) -> Result<Output, Error> {
+        ensure_vehicle_in_scope(
+            self.vehicle_repository,
+            user,
+            cmd.vehicle_id,
+            Error::VehicleNotFound(cmd.vehicle_id),
+        )
+        .await?;
+        let latest = self
+            .vehicle_movement_repository
+            .find_latest(user.organization_id, cmd.vehicle_id)
+            .await?;
+
+        // Check the destination exists
+        self.depot_repository
+            .find_depot(user.organization_id, cmd.to_depot_id)
+            .await?
+            .ok_or(Error::DepotNotFound(cmd.to_depot_id))?;
+
+        let movement = VehicleMovement::new(
+            cmd.vehicle_id,
+            latest.as_ref(),
+            cmd.to_depot_id,
+            cmd.moved_at.unwrap_or_else(chrono::Utc::now),
+            user.user_id,
+            cmd.notes,
+        )?;
+        let recorded = self
+            .vehicle_movement_repository
+            .record(user.organization_id, movement)
+            .await?;
+
+        Ok(Output::from(recorded))
+    }
+}
@@ -0,0 +1,21 @@
+pub struct RegionResponse {
+    pub id: uuid::Uuid,
+    pub name: String,
+    /// The depots of the region, by name.
+    pub depots: Vec<DepotResponse>,
+}
+
+pub struct DepotResponse {
+    pub id: uuid::Uuid,
+    pub name: String,
+    /// The sites of the depot, by name.
+    pub sites: Vec<SiteResponse>,
+}
+
+pub struct SiteResponse {
+    pub id: uuid::Uuid,
+    pub name: String,
+}
+
+/// The regions of the organization, by name.
+pub type GetDepotHierarchyResponse = Vec<RegionResponse>;
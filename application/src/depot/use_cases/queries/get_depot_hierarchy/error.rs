@@ -0,0 +1,7 @@
+use domain::depot::repositories::depot_repository::DepotRepositoryError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetDepotHierarchyError {
+    #[error("Repository error: {0}")]
+    Repository(#[from] DepotRepositoryError),
+}
@@ -0,0 +1,61 @@
+use super::{
+    dto::{DepotResponse, GetDepotHierarchyResponse as Output, RegionResponse, SiteResponse},
+    error::GetDepotHierarchyError as Error,
+};
+use crate::auth::AuthenticatedUser;
+use domain::depot::repositories::depot_repository::DepotRepository;
+
+pub struct GetDepotHierarchyUseCase<'a, DR: DepotRepository + 'a> {
+    depot_repository: &'a DR,
+}
+
+impl<'a, DR: DepotRepository + 'a> GetDepotHierarchyUseCase<'a, DR> {
+    pub fn new(depot_repository: &'a DR) -> Self {
+        GetDepotHierarchyUseCase { depot_repository }
+    }
+
+    /// Returns the regions, depots and sites the user sees: a depot manager only gets their
+    /// depots, and the regions holding them.
+    pub async fn execute(&self, user: &AuthenticatedUser) -> Result<Output, Error> {
+        let regions = self
+            .depot_repository
+            .find_regions(user.organization_id)
+            .await?;
+        let depots = self
+            .depot_repository
+            .find_depots(user.organization_id)
+            .await?;
+        let sites = self
+            .depot_repository
+            .find_sites(user.organization_id)
+            .await?;
+
+        // Every list is sorted by name, so are the children of each location
+        let hierarchy = regions
+            .into_iter()
+            .map(|region| RegionResponse {
+                id: region.id,
+                name: region.name.value().to_string(),
+                depots: depots
+                    .iter()
+                    .filter(|depot| depot.region_id == region.id && user.sees_depot(Some(depot.id)))
+                    .map(|depot| DepotResponse {
+                        id: depot.id,
+                        name: depot.name.value().to_string(),
+                        sites: sites
+                            .iter()
+                            .filter(|site| site.depot_id == depot.id)
+                            .map(|site| SiteResponse {
+                                id: site.id,
+                                name: site.name.value().to_string(),
+                            })
+                            .collect(),
+                    })
+                    .collect(),
+            })
+            .filter(|region| user.depot_scope.is_none() || !region.depots.is_empty())
+            .collect();
+
+        Ok(hierarchy)
+    }
+}
@@ -0,0 +1,30 @@
+use domain::depot::entities::vehicle_movement::VehicleMovement;
+
+pub struct GetVehicleMovementsQuery {
+    pub vehicle_id: uuid::Uuid,
+}
+
+pub struct VehicleMovementResponse {
+    pub id: uuid::Uuid,
+    pub from_depot_id: Option<uuid::Uuid>,
+    pub to_depot_id: uuid::Uuid,
+    pub moved_at: chrono::DateTime<chrono::Utc>,
+    pub moved_by: uuid::Uuid,
+    pub notes: String,
+}
+
+/// The movements of the vehicle, oldest first: the last one is its home depot.
+pub type GetVehicleMovementsResponse = Vec<VehicleMovementResponse>;
+
+impl From<VehicleMovement> for VehicleMovementResponse {
+    fn from(movement: VehicleMovement) -> Self {
+        VehicleMovementResponse {
+            id: movement.id,
+            from_depot_id: movement.from_depot_id,
+            to_depot_id: movement.to_depot_id,
+            moved_at: movement.moved_at,
+            moved_by: movement.moved_by,
+            notes: movement.notes,
+        }
+    }
+}
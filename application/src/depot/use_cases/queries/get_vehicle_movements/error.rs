@@ -0,0 +1,9 @@
+use domain::depot::repositories::vehicle_movement_repository::VehicleMovementRepositoryError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetVehicleMovementsError {
+    #[error("Vehicle not found: {0}")]
+    VehicleNotFound(uuid::Uuid),
+    #[error("Repository error: {0}")]
+    Repository(#[from] VehicleMovementRepositoryError),
+}
@@ -0,0 +1,38 @@
+use super::{
+    dto::{
+        GetVehicleMovementsQuery as Input, GetVehicleMovementsResponse as Output,
+        VehicleMovementResponse,
+    },
+    error::GetVehicleMovementsError as Error,
+};
+use crate::auth::AuthenticatedUser;
+use domain::depot::repositories::vehicle_movement_repository::VehicleMovementRepository;
+
+pub struct GetVehicleMovementsUseCase<'a, VMR: VehicleMovementRepository + 'a> {
+    vehicle_movement_repository: &'a VMR,
+}
+
+impl<'a, VMR: VehicleMovementRepository + 'a> GetVehicleMovementsUseCase<'a, VMR> {
+    pub fn new(vehicle_movement_repository: &'a VMR) -> Self {
+        GetVehicleMovementsUseCase {
+            vehicle_movement_repository,
+        }
+    }
+
+    pub async fn execute(&self, query: Input, user: &AuthenticatedUser) -> Result<Output, Error> {
+        let movements = self
+            .vehicle_movement_repository
+            .find_by_vehicle(user.organization_id, query.vehicle_id)
+            .await?;
+
+        // A depot manager sees the history of the vehicles currently based at their depots
+        if !user.sees_depot(movements.last().map(|latest| latest.to_depot_id)) {
+            return Err(Error::VehicleNotFound(query.vehicle_id));
+        }
+
+        Ok(movements
+            .into_iter()
+            .map(VehicleMovementResponse::from)
+            .collect())
+    }
+}
@@ -0,0 +1,2 @@
+pub mod get_depot_hierarchy;
+pub mod get_vehicle_movements;
@@ -0,0 +1,11 @@
+//! Diagnostics of the vehicles: the OBD-II trouble codes they report, and the rules reacting to
+//! them.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * Codes are reported for the vehicles the user sees, with the time the vehicle saw them. A
+//!   report without a severity takes the one of the catalog.
+//! * The rules fire when a code appears (a new DTC event), never on repeated reports; the alerts
+//!   they open are resolved when the code is cleared.
+//! * The rules are organization-wide: depot managers can't create them.
+pub mod use_cases;
@@ -0,0 +1,15 @@
+pub struct ClearDtcCodeCommand {
+    pub vehicle_id: uuid::Uuid,
+    pub code: String,
+    /// When the code was cleared, now when `None`.
+    pub cleared_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+pub struct ClearDtcCodeResponse {
+    pub event_id: uuid::Uuid,
+    pub code: String,
+    pub first_seen_at: chrono::DateTime<chrono::Utc>,
+    pub cleared_at: chrono::DateTime<chrono::Utc>,
+    /// Number of alerts and maintenance tasks of the event resolved.
+    pub alerts_resolved: u64,
+}
@@ -0,0 +1,22 @@
+use crate::vehicle::traits::vehicle_repository::VehicleApplicationRepositoryError;
+use domain::diagnostics::{
+    entities::dtc_event::DtcEventError,
+    repositories::diagnostic_repository::DiagnosticRepositoryError,
+    value_types::dtc_code::DtcCodeError,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ClearDtcCodeError {
+    #[error("Invalid DTC: {0}")]
+    InvalidCode(#[from] DtcCodeError),
+    #[error("Vehicle not found: {0}")]
+    VehicleNotFound(uuid::Uuid),
+    #[error("DTC {0} is not active on the vehicle")]
+    NotActive(String),
+    #[error("Invalid clearing: {0}")]
+    Event(#[from] DtcEventError),
+    #[error("Vehicle repository error: {0}")]
+    VehicleRepository(#[from] VehicleApplicationRepositoryError),
+    #[error("Diagnostic repository error: {0}")]
+    DiagnosticRepository(#[from] DiagnosticRepositoryError),
+}
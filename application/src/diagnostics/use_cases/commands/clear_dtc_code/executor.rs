@@ -0,0 +1,76 @@
+use super::{
+    dto::{ClearDtcCodeCommand as Input, ClearDtcCodeResponse as Output},
+    error::ClearDtcCodeError as Error,
+};
+use crate::{
+    auth::AuthenticatedUser,
+    vehicle::{
+        scope::ensure_vehicle_in_scope, traits::vehicle_repository::VehicleApplicationRepository,
+    },
+};
+use domain::diagnostics::{
+    repositories::diagnostic_repository::DiagnosticRepository, value_types::dtc_code::DtcCode,
+};
+
+pub struct ClearDtcCodeUseCase<'a, VAR, DR>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    DR: DiagnosticRepository + 'a,
+{
+    vehicle_repository: &'a VAR,
+    diagnostic_repository: &'a DR,
+}
+
+impl<'a, VAR, DR> ClearDtcCodeUseCase<'a, VAR, DR>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    DR: DiagnosticRepository + 'a,
+{
+    pub fn new(vehicle_repository: &'a VAR, diagnostic_repository: &'a DR) -> Self {
+        ClearDtcCodeUseCase {
+            vehicle_repository,
+            diagnostic_repository,
+        }
+    }
+
+    /// Clears an active code of a vehicle the user sees, and resolves the alerts it opened.
+    pub async fn execute(&self, cmd: Input, user: &AuthenticatedUser) -> Result<Output, Error> {
+        let code = DtcCode::new(cmd.code)?;
+
+        ensure_vehicle_in_scope(
+            self.vehicle_repository,
+            user,
+            cmd.vehicle_id,
+            Error::VehicleNotFound(cmd.vehicle_id),
+        )
+        .await?;
+
+        let mut event = self
+            .diagnostic_repository
+            .find_active_event(user.organization_id, cmd.vehicle_id, &code)
+            .await?
+            .ok_or_else(|| Error::NotActive(code.to_string()))?;
+        let cleared_at = cmd.cleared_at.unwrap_or_else(chrono::Utc::now);
+        event.clear(cleared_at, user.user_id)?;
+
+        let updated = self
+            .diagnostic_repository
+            .update_event(user.organization_id, event.clone())
+            .await?;
+        if !updated {
+            return Err(Error::NotActive(code.to_string()));
+        }
+        let alerts_resolved = self
+            .diagnostic_repository
+            .resolve_alerts(user.organization_id, event.id, cleared_at)
+            .await?;
+
+        Ok(Output {
+            event_id: event.id,
+            code: event.code.to_string(),
+            first_seen_at: event.first_seen_at,
+            cleared_at,
+            alerts_resolved,
+        })
+    }
+}
@@ -0,0 +1,35 @@
+use domain::diagnostics::{
+    entities::diagnostic_rule::{DiagnosticAction, DiagnosticRule},
+    value_types::dtc_severity::DtcSeverity,
+};
+
+pub struct CreateDiagnosticRuleCommand {
+    pub name: String,
+    pub min_severity: DtcSeverity,
+    /// Only the codes starting with this prefix (e.g., "P03"), every code when `None`.
+    pub code_prefix: Option<String>,
+    /// The maintenance type of the tasks to open, `None` for a rule opening alerts.
+    pub maintenance_type_id: Option<i32>,
+}
+
+pub struct CreateDiagnosticRuleResponse {
+    pub id: uuid::Uuid,
+    pub name: String,
+    pub min_severity: DtcSeverity,
+    pub code_prefix: Option<String>,
+    pub action: DiagnosticAction,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<DiagnosticRule> for CreateDiagnosticRuleResponse {
+    fn from(rule: DiagnosticRule) -> Self {
+        CreateDiagnosticRuleResponse {
+            id: rule.id,
+            name: rule.name,
+            min_severity: rule.min_severity,
+            code_prefix: rule.code_prefix,
+            action: rule.action,
+            created_at: rule.created_at,
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use domain::{
+    diagnostics::{
+        entities::diagnostic_rule::DiagnosticRuleError,
+        repositories::diagnostic_repository::DiagnosticRepositoryError,
+    },
+    maintenance::repositories::maintenance_type_repository::MaintenanceTypeRepositoryError,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum CreateDiagnosticRuleError {
+    #[error("Depot managers can't create diagnostic rules")]
+    Forbidden,
+    #[error("Invalid rule: {0}")]
+    InvalidRule(#[from] DiagnosticRuleError),
+    #[error("Maintenance type not found: {0}")]
+    MaintenanceTypeNotFound(i32),
+    #[error("Diagnostic rule already exists: {0}")]
+    AlreadyExists(String),
+    #[error("Maintenance type repository error: {0}")]
+    MaintenanceTypeRepository(#[from] MaintenanceTypeRepositoryError),
+    #[error("Diagnostic repository error: {0}")]
+    DiagnosticRepository(#[from] DiagnosticRepositoryError),
+}
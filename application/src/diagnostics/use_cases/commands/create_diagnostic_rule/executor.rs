@@ -0,0 +1,70 @@
+use super::{
+    dto::{CreateDiagnosticRuleCommand as Input, CreateDiagnosticRuleResponse as Output},
+    error::CreateDiagnosticRuleError as Error,
+};
+use crate::auth::AuthenticatedUser;
+use domain::{
+    diagnostics::{
+        entities::diagnostic_rule::{DiagnosticAction, DiagnosticRule, NewDiagnosticRule},
+        repositories::diagnostic_repository::{DiagnosticRepository, DiagnosticRepositoryError},
+    },
+    maintenance::repositories::maintenance_type_repository::MaintenanceTypeRepository,
+};
+
+pub struct CreateDiagnosticRuleUseCase<'a, MTR, DR>
+where
+    MTR: MaintenanceTypeRepository + 'a,
+    DR: DiagnosticRepository + 'a,
+{
+    maintenance_type_repository: &'a MTR,
+    diagnostic_repository: &'a DR,
+}
+
+impl<'a, MTR, DR> CreateDiagnosticRuleUseCase<'a, MTR, DR>
+where
+    MTR: MaintenanceTypeRepository + 'a,
+    DR: DiagnosticRepository + 'a,
+{
+    pub fn new(maintenance_type_repository: &'a MTR, diagnostic_repository: &'a DR) -> Self {
+        CreateDiagnosticRuleUseCase {
+            maintenance_type_repository,
+            diagnostic_repository,
+        }
+    }
+
+    pub async fn execute(&self, cmd: Input, user: &AuthenticatedUser) -> Result<Output, Error> {
+        if user.depot_scope.is_some() {
+            return Err(Error::Forbidden);
+        }
+
+        let action = match cmd.maintenance_type_id {
+            None => DiagnosticAction::Alert,
+            Some(maintenance_type_id) => {
+                self.maintenance_type_repository
+                    .get_by_id(user.organization_id, maintenance_type_id)
+                    .await?
+                    .ok_or(Error::MaintenanceTypeNotFound(maintenance_type_id))?;
+                DiagnosticAction::MaintenanceTask {
+                    maintenance_type_id,
+                }
+            }
+        };
+        let rule = DiagnosticRule::new(NewDiagnosticRule {
+            name: cmd.name,
+            min_severity: cmd.min_severity,
+            code_prefix: cmd.code_prefix,
+            action,
+        })?;
+
+        let created = self
+            .diagnostic_repository
+            .create_rule(user.organization_id, rule)
+            .await
+            .map_err(|e| match e {
+                DiagnosticRepositoryError::AlreadyExists(name) => Error::AlreadyExists(name),
+                e => e.into(),
+            })?;
+
+        Ok(Output::from(created))
+    }
+}
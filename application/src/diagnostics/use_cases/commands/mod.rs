@@ -0,0 +1,3 @@
+pub mod clear_dtc_code;
+pub mod create_diagnostic_rule;
+pub mod report_dtc_codes;
@@ -0,0 +1,47 @@
+use domain::diagnostics::{
+    entities::diagnostic_rule::DiagnosticAction, value_types::dtc_severity::DtcSeverity,
+};
+use std::collections::BTreeMap;
+
+/// A code reported by a vehicle.
+pub struct ReportedDtc {
+    pub code: String,
+    /// The severity reported by the device, the one of the catalog when `None`.
+    pub severity: Option<DtcSeverity>,
+    /// The freeze frame parameters, by name (empty when the device sends none).
+    pub freeze_frame: BTreeMap<String, String>,
+}
+
+pub struct ReportDtcCodesCommand {
+    pub vehicle_id: uuid::Uuid,
+    /// When the vehicle saw the codes.
+    pub seen_at: chrono::DateTime<chrono::Utc>,
+    pub codes: Vec<ReportedDtc>,
+}
+
+/// What became of a reported code.
+pub struct ReportedDtcOutcome {
+    pub code: String,
+    pub event_id: uuid::Uuid,
+    /// Whether the code appeared (a new event), or was already active.
+    pub new: bool,
+    pub severity: DtcSeverity,
+    pub description: String,
+    pub occurrences: i32,
+}
+
+/// An alert or maintenance task opened by a rule.
+pub struct OpenedDiagnosticAlert {
+    pub alert_id: uuid::Uuid,
+    pub rule_id: uuid::Uuid,
+    pub rule_name: String,
+    pub action: DiagnosticAction,
+    pub code: String,
+}
+
+pub struct ReportDtcCodesResponse {
+    pub vehicle_id: uuid::Uuid,
+    /// The reported codes, in the order of the report.
+    pub codes: Vec<ReportedDtcOutcome>,
+    pub alerts: Vec<OpenedDiagnosticAlert>,
+}
@@ -0,0 +1,162 @@
+use super::{
+    dto::{
+        OpenedDiagnosticAlert, ReportDtcCodesCommand as Input, ReportDtcCodesResponse as Output,
+        ReportedDtcOutcome,
+    },
+    error::ReportDtcCodesError as Error,
+};
+use crate::{
+    auth::AuthenticatedUser,
+    vehicle::{
+        scope::ensure_vehicle_in_scope, traits::vehicle_repository::VehicleApplicationRepository,
+    },
+};
+use domain::diagnostics::{
+    entities::dtc_event::DtcEvent,
+    repositories::diagnostic_repository::{DiagnosticRepository, DiagnosticRepositoryError},
+    services::{diagnostic_rule_service::alerts_for, dtc_catalog},
+    value_types::{dtc_code::DtcCode, dtc_severity::DtcSeverity, freeze_frame::FreezeFrame},
+};
+
+pub struct ReportDtcCodesUseCase<'a, VAR, DR>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    DR: DiagnosticRepository + 'a,
+{
+    vehicle_repository: &'a VAR,
+    diagnostic_repository: &'a DR,
+}
+
+impl<'a, VAR, DR> ReportDtcCodesUseCase<'a, VAR, DR>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    DR: DiagnosticRepository + 'a,
+{
+    pub fn new(vehicle_repository: &'a VAR, diagnostic_repository: &'a DR) -> Self {
+        ReportDtcCodesUseCase {
+            vehicle_repository,
+            diagnostic_repository,
+        }
+    }
+
+    /// Records the codes reported by a vehicle the user sees, and applies the rules to the codes
+    /// that appeared. The report is checked as a whole before anything is written.
+    pub async fn execute(&self, cmd: Input, user: &AuthenticatedUser) -> Result<Output, Error> {
+        let mut reported = Vec::with_capacity(cmd.codes.len());
+        for dtc in cmd.codes {
+            let code = DtcCode::new(dtc.code)?;
+            let freeze_frame = FreezeFrame::new(dtc.freeze_frame)
+                .map_err(|e| Error::InvalidFreezeFrame(code.to_string(), e))?;
+            let severity = dtc
+                .severity
+                .unwrap_or_else(|| dtc_catalog::describe(&code).severity);
+            reported.push((code, severity, freeze_frame));
+        }
+
+        ensure_vehicle_in_scope(
+            self.vehicle_repository,
+            user,
+            cmd.vehicle_id,
+            Error::VehicleNotFound(cmd.vehicle_id),
+        )
+        .await?;
+
+        let organization_id = user.organization_id;
+        let rules = self
+            .diagnostic_repository
+            .find_rules(organization_id)
+            .await?;
+        let mut response = Output {
+            vehicle_id: cmd.vehicle_id,
+            codes: Vec::new(),
+            alerts: Vec::new(),
+        };
+        for (code, severity, freeze_frame) in reported {
+            let (event, new) = self
+                .record(
+                    cmd.vehicle_id,
+                    code,
+                    severity,
+                    freeze_frame,
+                    cmd.seen_at,
+                    user,
+                )
+                .await?;
+
+            for alert in alerts_for(&event, new, &rules) {
+                let rule = rules.iter().find(|rule| rule.id == alert.rule_id).cloned();
+                let opened = self
+                    .diagnostic_repository
+                    .open_alert(organization_id, alert)
+                    .await?;
+                if let Some(rule) = rule {
+                    response.alerts.push(OpenedDiagnosticAlert {
+                        alert_id: opened.id,
+                        rule_id: rule.id,
+                        rule_name: rule.name,
+                        action: rule.action,
+                        code: event.code.to_string(),
+                    });
+                }
+            }
+
+            response.codes.push(ReportedDtcOutcome {
+                code: event.code.to_string(),
+                event_id: event.id,
+                new,
+                severity: event.severity,
+                description: dtc_catalog::describe(&event.code).description.to_string(),
+                occurrences: event.occurrences,
+            });
+        }
+
+        Ok(response)
+    }
+
+    /// Opens an event for a code that appeared, or updates the active one; returns the event and
+    /// whether it is new.
+    async fn record(
+        &self,
+        vehicle_id: uuid::Uuid,
+        code: DtcCode,
+        severity: DtcSeverity,
+        freeze_frame: FreezeFrame,
+        seen_at: chrono::DateTime<chrono::Utc>,
+        user: &AuthenticatedUser,
+    ) -> Result<(DtcEvent, bool), Error> {
+        let organization_id = user.organization_id;
+        let active = self
+            .diagnostic_repository
+            .find_active_event(organization_id, vehicle_id, &code)
+            .await?;
+        if active.is_none() {
+            let event = DtcEvent::new(vehicle_id, code.clone(), severity, freeze_frame, seen_at);
+            match self
+                .diagnostic_repository
+                .open_event(organization_id, event)
+                .await
+            {
+                Ok(opened) => return Ok((opened, true)),
+                // Opened concurrently since the read above: this report is a repeated one
+                Err(DiagnosticRepositoryError::AlreadyExists(_)) => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        let mut event = match active {
+            Some(event) => event,
+            None => self
+                .diagnostic_repository
+                .find_active_event(organization_id, vehicle_id, &code)
+                .await?
+                .ok_or_else(|| {
+                    DiagnosticRepositoryError::Database(format!("active event of {} lost", code))
+                })?,
+        };
+        event.seen_again(seen_at, severity)?;
+        self.diagnostic_repository
+            .update_event(organization_id, event.clone())
+            .await?;
+        Ok((event, false))
+    }
+}
@@ -0,0 +1,70 @@
+use domain::diagnostics::{
+    entities::{diagnostic_rule::DiagnosticAction, dtc_event::DtcEvent},
+    services::dtc_catalog::{self, DtcMatch},
+    value_types::dtc_severity::DtcSeverity,
+};
+use std::collections::BTreeMap;
+
+pub struct GetVehicleDiagnosticsQuery {
+    pub vehicle_id: uuid::Uuid,
+    /// Include the cleared codes and the resolved alerts (the history), not only the active ones.
+    pub include_cleared: bool,
+}
+
+pub struct DtcEventView {
+    pub id: uuid::Uuid,
+    pub code: String,
+    /// "powertrain", "chassis", "body" or "network".
+    pub system: String,
+    /// Whether the code has the same meaning for every make.
+    pub generic: bool,
+    /// The description of the catalog.
+    pub description: String,
+    /// How the catalog knows the code (the code itself, its group, or its system only).
+    pub matched: DtcMatch,
+    pub severity: DtcSeverity,
+    pub freeze_frame: BTreeMap<String, String>,
+    pub first_seen_at: chrono::DateTime<chrono::Utc>,
+    pub last_seen_at: chrono::DateTime<chrono::Utc>,
+    pub occurrences: i32,
+    pub cleared_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl From<DtcEvent> for DtcEventView {
+    fn from(event: DtcEvent) -> Self {
+        let description = dtc_catalog::describe(&event.code);
+        DtcEventView {
+            id: event.id,
+            code: event.code.to_string(),
+            system: event.code.system().as_str().to_string(),
+            generic: event.code.is_generic(),
+            description: description.description.to_string(),
+            matched: description.matched,
+            severity: event.severity,
+            freeze_frame: event.freeze_frame.parameters().clone(),
+            first_seen_at: event.first_seen_at,
+            last_seen_at: event.last_seen_at,
+            occurrences: event.occurrences,
+            cleared_at: event.cleared_at,
+        }
+    }
+}
+
+pub struct DiagnosticAlertView {
+    pub id: uuid::Uuid,
+    pub dtc_event_id: uuid::Uuid,
+    pub code: String,
+    pub rule_id: uuid::Uuid,
+    pub rule_name: String,
+    pub action: DiagnosticAction,
+    pub opened_at: chrono::DateTime<chrono::Utc>,
+    pub resolved_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+pub struct GetVehicleDiagnosticsResponse {
+    pub vehicle_id: uuid::Uuid,
+    /// The events, ordered by first report.
+    pub events: Vec<DtcEventView>,
+    /// The alerts and maintenance tasks, ordered by opening.
+    pub alerts: Vec<DiagnosticAlertView>,
+}
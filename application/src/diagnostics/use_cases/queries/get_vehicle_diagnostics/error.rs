@@ -0,0 +1,12 @@
+use crate::vehicle::traits::vehicle_repository::VehicleApplicationRepositoryError;
+use domain::diagnostics::repositories::diagnostic_repository::DiagnosticRepositoryError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetVehicleDiagnosticsError {
+    #[error("Vehicle not found: {0}")]
+    VehicleNotFound(uuid::Uuid),
+    #[error("Vehicle repository error: {0}")]
+    VehicleRepository(#[from] VehicleApplicationRepositoryError),
+    #[error("Diagnostic repository error: {0}")]
+    DiagnosticRepository(#[from] DiagnosticRepositoryError),
+}
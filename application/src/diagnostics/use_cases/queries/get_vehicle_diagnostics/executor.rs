@@ -0,0 +1,89 @@
+use super::{
+    dto::{
+        DiagnosticAlertView, DtcEventView, GetVehicleDiagnosticsQuery as Input,
+        GetVehicleDiagnosticsResponse as Output,
+    },
+    error::GetVehicleDiagnosticsError as Error,
+};
+use crate::{
+    auth::AuthenticatedUser,
+    vehicle::{
+        scope::ensure_vehicle_in_scope, traits::vehicle_repository::VehicleApplicationRepository,
+    },
+};
+use domain::diagnostics::repositories::diagnostic_repository::DiagnosticRepository;
+
+pub struct GetVehicleDiagnosticsUseCase<'a, VAR, DR>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    DR: DiagnosticRepository + 'a,
+{
+    vehicle_repository: &'a VAR,
+    diagnostic_repository: &'a DR,
+}
+
+impl<'a, VAR, DR> GetVehicleDiagnosticsUseCase<'a, VAR, DR>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    DR: DiagnosticRepository + 'a,
+{
+    pub fn new(vehicle_repository: &'a VAR, diagnostic_repository: &'a DR) -> Self {
+        GetVehicleDiagnosticsUseCase {
+            vehicle_repository,
+            diagnostic_repository,
+        }
+    }
+
+    /// Lists the codes of a vehicle the user sees, described by the catalog, and their alerts.
+    pub async fn execute(&self, query: Input, user: &AuthenticatedUser) -> Result<Output, Error> {
+        ensure_vehicle_in_scope(
+            self.vehicle_repository,
+            user,
+            query.vehicle_id,
+            Error::VehicleNotFound(query.vehicle_id),
+        )
+        .await?;
+
+        let organization_id = user.organization_id;
+        let events = self
+            .diagnostic_repository
+            .find_events_by_vehicle(organization_id, query.vehicle_id, query.include_cleared)
+            .await?;
+        let alerts = self
+            .diagnostic_repository
+            .find_alerts_by_vehicle(organization_id, query.vehicle_id, !query.include_cleared)
+            .await?;
+        let rules = self
+            .diagnostic_repository
+            .find_rules(organization_id)
+            .await?;
+
+        let alerts = alerts
+            .into_iter()
+            .filter_map(|alert| {
+                let rule = rules.iter().find(|rule| rule.id == alert.rule_id)?;
+                let code = events
+                    .iter()
+                    .find(|event| event.id == alert.dtc_event_id)
+                    .map(|event| event.code.to_string())
+                    .unwrap_or_default();
+                Some(DiagnosticAlertView {
+                    id: alert.id,
+                    dtc_event_id: alert.dtc_event_id,
+                    code,
+                    rule_id: rule.id,
+                    rule_name: rule.name.clone(),
+                    action: rule.action,
+                    opened_at: alert.opened_at,
+                    resolved_at: alert.resolved_at,
+                })
+            })
+            .collect();
+
+        Ok(Output {
+            vehicle_id: query.vehicle_id,
+            events: events.into_iter().map(DtcEventView::from).collect(),
+            alerts,
+        })
+    }
+}
@@ -0,0 +1,2 @@
+pub mod record_fuel_event;
+pub mod set_fuel_tank;
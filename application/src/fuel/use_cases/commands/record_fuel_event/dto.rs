@@ -0,0 +1,47 @@
+use domain::fuel::entities::fuel_event::FuelEventIdentity;
+use rust_decimal::Decimal;
+
+pub struct RecordFuelEventCommand {
+    pub vehicle_id: uuid::Uuid,
+    /// The driver who filled the vehicle (`None` means the caller).
+    pub driver_id: Option<uuid::Uuid>,
+    pub performed_at: chrono::DateTime<chrono::Utc>,
+    pub odometer: i32,
+    pub quantity: Decimal,
+    /// Energy unit (e.g., "L", "kWh", "kg").
+    pub unit: String,
+    pub total_price: Option<Decimal>,
+    /// ISO 4217 code, required when `total_price` is set.
+    pub currency: Option<String>,
+    pub station: Option<String>,
+    pub full: bool,
+}
+
+pub struct RecordFuelEventResponse {
+    pub id: i32,
+    pub vehicle_id: uuid::Uuid,
+    pub driver_id: uuid::Uuid,
+    pub performed_at: chrono::DateTime<chrono::Utc>,
+    pub odometer: i32,
+    pub quantity: Decimal,
+    pub unit: String,
+    pub full: bool,
+    /// Set when the quantity is larger than the tank of the vehicle.
+    pub exceeds_tank_capacity: bool,
+}
+
+impl From<FuelEventIdentity> for RecordFuelEventResponse {
+    fn from(identity: FuelEventIdentity) -> Self {
+        RecordFuelEventResponse {
+            id: identity.id,
+            vehicle_id: identity.vehicle_id,
+            driver_id: identity.driver_id,
+            performed_at: identity.performed_at,
+            odometer: identity.odometer,
+            quantity: identity.quantity,
+            unit: identity.unit.as_str().to_string(),
+            full: identity.full,
+            exceeds_tank_capacity: false,
+        }
+    }
+}
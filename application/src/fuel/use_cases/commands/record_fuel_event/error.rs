@@ -0,0 +1,32 @@
+use crate::vehicle::traits::vehicle_repository::VehicleApplicationRepositoryError;
+use domain::{
+    fuel::{
+        entities::fuel_event::FuelEventError,
+        repositories::{
+            fuel_event_repository::FuelEventRepositoryError,
+            fuel_tank_repository::FuelTankRepositoryError,
+        },
+    },
+    maintenance::value_types::currency::CurrencyError,
+    vehicle::repositories::vehicle_repository::VehicleRepositoryError,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum RecordFuelEventError {
+    #[error("Vehicle not found: {0}")]
+    VehicleNotFound(uuid::Uuid),
+    #[error("Invalid input data: {0}")]
+    Validation(#[from] FuelEventError),
+    #[error("Invalid currency: {0}")]
+    InvalidCurrency(#[from] CurrencyError),
+    #[error("A currency is required when a price is given")]
+    MissingCurrency,
+    #[error("Vehicle repository error: {0}")]
+    VehicleRepository(#[from] VehicleRepositoryError),
+    #[error("Vehicle repository error: {0}")]
+    VehicleApplicationRepository(#[from] VehicleApplicationRepositoryError),
+    #[error("Fuel event repository error: {0}")]
+    FuelEventRepository(#[from] FuelEventRepositoryError),
+    #[error("Fuel tank repository error: {0}")]
+    FuelTankRepository(#[from] FuelTankRepositoryError),
+}
@@ -0,0 +1,107 @@
+use super::{
+    dto::{RecordFuelEventCommand as Input, RecordFuelEventResponse as Output},
+    error::RecordFuelEventError as Error,
+};
+use crate::{
+    auth::AuthenticatedUser,
+    vehicle::{
+        scope::ensure_vehicle_in_scope, traits::vehicle_repository::VehicleApplicationRepository,
+    },
+};
+use domain::{
+    fuel::{
+        entities::{
+            fuel_event::{FuelEvent, NewFuelEvent},
+            fuel_tank::FuelTank,
+        },
+        repositories::{
+            fuel_event_repository::FuelEventRepository, fuel_tank_repository::FuelTankRepository,
+        },
+    },
+    maintenance::value_types::{currency::Currency, money::Money},
+    vehicle::repositories::vehicle_repository::VehicleRepository,
+};
+
+pub struct RecordFuelEventUseCase<'a, VR, FER, FTR>
+where
+    VR: VehicleRepository + VehicleApplicationRepository + 'a,
+    FER: FuelEventRepository + 'a,
+    FTR: FuelTankRepository + 'a,
+{
+    vehicle_repository: &'a VR,
+    fuel_event_repository: &'a FER,
+    fuel_tank_repository: &'a FTR,
+}
+
+impl<'a, VR, FER, FTR> RecordFuelEventUseCase<'a, VR, FER, FTR>
+where
+    VR: VehicleRepository + VehicleApplicationRepository + 'a,
+    FER: FuelEventRepository + 'a,
+    FTR: FuelTankRepository + 'a,
+{
+    pub fn new(
+        vehicle_repository: &'a VR,
+        fuel_event_repository: &'a FER,
+        fuel_tank_repository: &'a FTR,
+    ) -> Self {
+        RecordFuelEventUseCase {
+            vehicle_repository,
+            fuel_event_repository,
+            fuel_tank_repository,
+        }
+    }
+
+    pub async fn execute(&self, cmd: Input, user: &AuthenticatedUser) -> Result<Output, Error> {
+        // Check the vehicle exists and is in the scope of the user
+        ensure_vehicle_in_scope(
+            self.vehicle_repository,
+            user,
+            cmd.vehicle_id,
+            Error::VehicleNotFound(cmd.vehicle_id),
+        )
+        .await?;
+        let vehicle = self
+            .vehicle_repository
+            .find_by_id(user.organization_id, cmd.vehicle_id)
+            .await?
+            .ok_or(Error::VehicleNotFound(cmd.vehicle_id))?;
+
+        let total_price = match (cmd.total_price, cmd.currency) {
+            (Some(amount), Some(currency)) => Some(Money::new(amount, Currency::new(currency)?)),
+            (Some(_), None) => return Err(Error::MissingCurrency),
+            (None, _) => None,
+        };
+
+        // Create the event, the entity checks the unit against the engine type
+        let event = FuelEvent::new(
+            vehicle,
+            cmd.driver_id.unwrap_or(user.user_id),
+            user.user_id,
+            NewFuelEvent {
+                performed_at: cmd.performed_at,
+                odometer: cmd.odometer,
+                quantity: cmd.quantity,
+                unit: cmd.unit,
+                total_price,
+                station: cmd.station,
+                full: cmd.full,
+            },
+        )?;
+
+        // Check the quantity against the tank, an anomaly is reported but not rejected
+        let saved_tanks = self
+            .fuel_tank_repository
+            .find_by_vehicle(user.organization_id, cmd.vehicle_id)
+            .await?;
+        let exceeds_tank_capacity = FuelTank::for_vehicle(&event.vehicle, saved_tanks)
+            .iter()
+            .any(|tank| tank.unit == event.identity.unit && event.identity.quantity > tank.capacity);
+
+        let created = self.fuel_event_repository.create(user.organization_id, event).await?;
+
+        Ok(Output {
+            exceeds_tank_capacity,
+            ..Output::from(created)
+        })
+    }
+}
@@ -0,0 +1,15 @@
+use rust_decimal::Decimal;
+
+pub struct SetFuelTankCommand {
+    pub vehicle_id: uuid::Uuid,
+    /// Energy unit (e.g., "L", "kWh", "kg").
+    pub unit: String,
+    /// Usable capacity in `unit`.
+    pub capacity: Decimal,
+}
+
+pub struct SetFuelTankResponse {
+    pub vehicle_id: uuid::Uuid,
+    pub unit: String,
+    pub capacity: Decimal,
+}
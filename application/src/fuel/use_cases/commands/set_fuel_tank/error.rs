@@ -0,0 +1,24 @@
+use crate::vehicle::traits::vehicle_repository::VehicleApplicationRepositoryError;
+use domain::{
+    fuel::{
+        entities::fuel_tank::FuelTankError,
+        repositories::fuel_tank_repository::FuelTankRepositoryError,
+    },
+    vehicle::repositories::vehicle_repository::VehicleRepositoryError,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum SetFuelTankError {
+    #[error("Vehicle not found: {0}")]
+    VehicleNotFound(uuid::Uuid),
+    #[error("Unknown energy unit: {0}")]
+    UnknownUnit(String),
+    #[error("Invalid input data: {0}")]
+    Validation(#[from] FuelTankError),
+    #[error("Vehicle repository error: {0}")]
+    VehicleRepository(#[from] VehicleRepositoryError),
+    #[error("Vehicle repository error: {0}")]
+    VehicleApplicationRepository(#[from] VehicleApplicationRepositoryError),
+    #[error("Fuel tank repository error: {0}")]
+    FuelTankRepository(#[from] FuelTankRepositoryError),
+}
@@ -0,0 +1,70 @@
+use super::{
+    dto::{SetFuelTankCommand as Input, SetFuelTankResponse as Output},
+    error::SetFuelTankError as Error,
+};
+use crate::{
+    auth::AuthenticatedUser,
+    vehicle::{
+        scope::ensure_vehicle_in_scope, traits::vehicle_repository::VehicleApplicationRepository,
+    },
+};
+use domain::{
+    fuel::{
+        entities::fuel_tank::FuelTank,
+        repositories::fuel_tank_repository::FuelTankRepository,
+        value_types::energy_unit::EnergyUnit,
+    },
+    vehicle::repositories::vehicle_repository::VehicleRepository,
+};
+
+pub struct SetFuelTankUseCase<'a, VR, FTR>
+where
+    VR: VehicleRepository + VehicleApplicationRepository + 'a,
+    FTR: FuelTankRepository + 'a,
+{
+    vehicle_repository: &'a VR,
+    fuel_tank_repository: &'a FTR,
+}
+
+impl<'a, VR, FTR> SetFuelTankUseCase<'a, VR, FTR>
+where
+    VR: VehicleRepository + VehicleApplicationRepository + 'a,
+    FTR: FuelTankRepository + 'a,
+{
+    pub fn new(vehicle_repository: &'a VR, fuel_tank_repository: &'a FTR) -> Self {
+        SetFuelTankUseCase {
+            vehicle_repository,
+            fuel_tank_repository,
+        }
+    }
+
+    pub async fn execute(&self, cmd: Input, user: &AuthenticatedUser) -> Result<Output, Error> {
+        let unit = cmd
+            .unit
+            .parse::<EnergyUnit>()
+            .map_err(|_| Error::UnknownUnit(cmd.unit.clone()))?;
+
+        // Check the vehicle exists and is in the scope of the user
+        ensure_vehicle_in_scope(
+            self.vehicle_repository,
+            user,
+            cmd.vehicle_id,
+            Error::VehicleNotFound(cmd.vehicle_id),
+        )
+        .await?;
+        let vehicle = self
+            .vehicle_repository
+            .find_by_id(user.organization_id, cmd.vehicle_id)
+            .await?
+            .ok_or(Error::VehicleNotFound(cmd.vehicle_id))?;
+
+        let tank = FuelTank::new(&vehicle, unit, cmd.capacity)?;
+        self.fuel_tank_repository.save(user.organization_id, tank.clone()).await?;
+
+        Ok(Output {
+            vehicle_id: tank.vehicle_id,
+            unit: tank.unit.as_str().to_string(),
+            capacity: tank.capacity,
+        })
+    }
+}
@@ -0,0 +1,22 @@
+use domain::fuel::services::fuel_consumption_service::{
+    ConsumptionAnalysis, ConsumptionSettings,
+};
+
+pub struct GetFuelConsumptionQuery {
+    pub vehicle_id: uuid::Uuid,
+    /// Only events performed at or after this moment are included.
+    pub from: Option<chrono::DateTime<chrono::Utc>>,
+    /// Only events performed at or before this moment are included.
+    pub to: Option<chrono::DateTime<chrono::Utc>>,
+    /// Tuning of the anomaly detection (`None` means the defaults).
+    pub settings: Option<ConsumptionSettings>,
+}
+
+pub struct GetFuelConsumptionResponse {
+    pub vehicle_id: uuid::Uuid,
+    pub engine_type: String,
+    /// Number of events in the period.
+    pub event_count: usize,
+    /// One analysis per energy unit used by the vehicle (fuel and/or electricity).
+    pub analyses: Vec<ConsumptionAnalysis>,
+}
@@ -0,0 +1,24 @@
+use crate::vehicle::traits::vehicle_repository::VehicleApplicationRepositoryError;
+use domain::{
+    fuel::repositories::{
+        fuel_event_repository::FuelEventRepositoryError,
+        fuel_tank_repository::FuelTankRepositoryError,
+    },
+    vehicle::repositories::vehicle_repository::VehicleRepositoryError,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetFuelConsumptionError {
+    #[error("Vehicle not found: {0}")]
+    VehicleNotFound(uuid::Uuid),
+    #[error("Invalid date range: 'from' must not be after 'to'")]
+    InvalidDateRange,
+    #[error("Vehicle repository error: {0}")]
+    VehicleRepository(#[from] VehicleRepositoryError),
+    #[error("Vehicle repository error: {0}")]
+    VehicleApplicationRepository(#[from] VehicleApplicationRepositoryError),
+    #[error("Fuel event repository error: {0}")]
+    FuelEventRepository(#[from] FuelEventRepositoryError),
+    #[error("Fuel tank repository error: {0}")]
+    FuelTankRepository(#[from] FuelTankRepositoryError),
+}
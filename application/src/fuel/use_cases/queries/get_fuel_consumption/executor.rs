@@ -0,0 +1,117 @@
+use super::{
+    dto::{GetFuelConsumptionQuery as Input, GetFuelConsumptionResponse as Output},
+    error::GetFuelConsumptionError as Error,
+};
+use crate::{
+    auth::AuthenticatedUser,
+    vehicle::{
+        scope::ensure_vehicle_in_scope, traits::vehicle_repository::VehicleApplicationRepository,
+    },
+};
+use domain::{
+    fuel::{
+        entities::fuel_tank::FuelTank,
+        repositories::{
+            fuel_event_repository::FuelEventRepository, fuel_tank_repository::FuelTankRepository,
+        },
+        services::fuel_consumption_service::analyze_consumption,
+        value_types::energy_unit::EnergyUnit,
+    },
+    vehicle::{
+        repositories::vehicle_repository::VehicleRepository,
+        value_types::engine_type::EngineType,
+    },
+};
+use std::collections::BTreeSet;
+
+pub struct GetFuelConsumptionUseCase<'a, VR, FER, FTR>
+where
+    VR: VehicleRepository + VehicleApplicationRepository + 'a,
+    FER: FuelEventRepository + 'a,
+    FTR: FuelTankRepository + 'a,
+{
+    vehicle_repository: &'a VR,
+    fuel_event_repository: &'a FER,
+    fuel_tank_repository: &'a FTR,
+}
+
+impl<'a, VR, FER, FTR> GetFuelConsumptionUseCase<'a, VR, FER, FTR>
+where
+    VR: VehicleRepository + VehicleApplicationRepository + 'a,
+    FER: FuelEventRepository + 'a,
+    FTR: FuelTankRepository + 'a,
+{
+    pub fn new(
+        vehicle_repository: &'a VR,
+        fuel_event_repository: &'a FER,
+        fuel_tank_repository: &'a FTR,
+    ) -> Self {
+        GetFuelConsumptionUseCase {
+            vehicle_repository,
+            fuel_event_repository,
+            fuel_tank_repository,
+        }
+    }
+
+    pub async fn execute(&self, query: Input, user: &AuthenticatedUser) -> Result<Output, Error> {
+        if let (Some(from), Some(to)) = (query.from, query.to)
+            && from > to
+        {
+            return Err(Error::InvalidDateRange);
+        }
+
+        // Check the vehicle exists and is in the scope of the user
+        ensure_vehicle_in_scope(
+            self.vehicle_repository,
+            user,
+            query.vehicle_id,
+            Error::VehicleNotFound(query.vehicle_id),
+        )
+        .await?;
+        let vehicle = self
+            .vehicle_repository
+            .find_by_id(user.organization_id, query.vehicle_id)
+            .await?
+            .ok_or(Error::VehicleNotFound(query.vehicle_id))?;
+
+        let events = self
+            .fuel_event_repository
+            .find_by_vehicle(user.organization_id, query.vehicle_id, query.from, query.to)
+            .await?;
+        let saved_tanks = self
+            .fuel_tank_repository
+            .find_by_vehicle(user.organization_id, query.vehicle_id)
+            .await?;
+        let tanks = FuelTank::for_vehicle(&vehicle, saved_tanks);
+
+        // Units of the engine type, plus any unit actually recorded (e.g., unknown engine types)
+        let units: BTreeSet<EnergyUnit> = [
+            EnergyUnit::Liters,
+            EnergyUnit::KilowattHours,
+            EnergyUnit::Kilograms,
+        ]
+        .into_iter()
+        .filter(|unit| {
+            !matches!(vehicle.powertrain.engine_type(), EngineType::Other(_))
+                && unit.is_compatible_with(vehicle.powertrain.engine_type())
+        })
+        .chain(events.iter().map(|event| event.unit))
+        .collect();
+
+        let settings = query.settings.unwrap_or_default();
+        let analyses = units
+            .into_iter()
+            .map(|unit| {
+                let tank = tanks.iter().find(|tank| tank.unit == unit);
+                analyze_consumption(&events, unit, tank, &settings)
+            })
+            .collect();
+
+        Ok(Output {
+            vehicle_id: vehicle.id,
+            engine_type: vehicle.powertrain.engine_type().as_str().to_string(),
+            event_count: events.len(),
+            analyses,
+        })
+    }
+}
@@ -0,0 +1,11 @@
+//! Inspections of the vehicles: the checklists drivers fill before and after their trips (DVIRs),
+//! and the defects mechanics sign off.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * Inspections are submitted for the vehicles the user sees, with the readings of the vehicle:
+//!   they are recorded as a vehicle status first, which stands on its own if the report fails.
+//! * A failed critical item puts the vehicle out of service until every open critical defect is
+//!   signed off.
+//! * The templates are organization-wide: depot managers can't create them.
+pub mod use_cases;
@@ -0,0 +1,32 @@
+use domain::inspection::{
+    entities::inspection_template::{InspectionItem, InspectionTemplate, NewInspectionItem},
+    value_types::inspection_kind::InspectionKind,
+};
+
+pub struct CreateInspectionTemplateCommand {
+    pub name: String,
+    pub kind: InspectionKind,
+    /// The items, in the order they are checked.
+    pub items: Vec<NewInspectionItem>,
+}
+
+pub struct CreateInspectionTemplateResponse {
+    pub id: uuid::Uuid,
+    pub name: String,
+    pub kind: InspectionKind,
+    /// The items with their ids, referenced by the answers of the inspections.
+    pub items: Vec<InspectionItem>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<InspectionTemplate> for CreateInspectionTemplateResponse {
+    fn from(template: InspectionTemplate) -> Self {
+        CreateInspectionTemplateResponse {
+            id: template.id,
+            name: template.name,
+            kind: template.kind,
+            items: template.items,
+            created_at: template.created_at,
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use domain::inspection::{
+    entities::inspection_template::InspectionTemplateError,
+    repositories::inspection_repository::InspectionRepositoryError,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum CreateInspectionTemplateError {
+    #[error("Depot managers can't create inspection templates")]
+    Forbidden,
+    #[error("Invalid template: {0}")]
+    InvalidTemplate(#[from] InspectionTemplateError),
+    #[error("Inspection template already exists: {0}")]
+    AlreadyExists(String),
+    #[error("Inspection repository error: {0}")]
+    InspectionRepository(#[from] InspectionRepositoryError),
+}
@@ -0,0 +1,53 @@
+use super::{
+    dto::{CreateInspectionTemplateCommand as Input, CreateInspectionTemplateResponse as Output},
+    error::CreateInspectionTemplateError as Error,
+};
+use crate::auth::AuthenticatedUser;
+use domain::inspection::{
+    entities::inspection_template::{InspectionTemplate, NewInspectionTemplate},
+    repositories::inspection_repository::{InspectionRepository, InspectionRepositoryError},
+};
+
+pub struct CreateInspectionTemplateUseCase<'a, IR>
+where
+    IR: InspectionRepository + 'a,
+{
+    inspection_repository: &'a IR,
+}
+
+impl<'a, IR> CreateInspectionTemplateUseCase<'a, IR>
+where
+    IR: InspectionRepository + 'a,
+{
+    pub fn new(inspection_repository: &'a IR) -> Self {
+        CreateInspectionTemplateUseCase {
+            inspection_repository,
+        }
+    }
+
+    pub async fn execute(&self, cmd: Input, user: &AuthenticatedUser) -> Result<Output, Error> {
+        if user.depot_scope.is_some() {
+            return Err(Error::Forbidden);
+        }
+
+        let template = InspectionTemplate::new(
+            NewInspectionTemplate {
+                name: cmd.name,
+                kind: cmd.kind,
+                items: cmd.items,
+            },
+            user.user_id,
+        )?;
+
+        let created = self
+            .inspection_repository
+            .create_template(user.organization_id, template)
+            .await
+            .map_err(|e| match e {
+                InspectionRepositoryError::AlreadyExists(name) => Error::AlreadyExists(name),
+                e => e.into(),
+            })?;
+
+        Ok(Output::from(created))
+    }
+}
@@ -0,0 +1,3 @@
+pub mod create_inspection_template;
+pub mod sign_off_defect;
+pub mod submit_inspection;
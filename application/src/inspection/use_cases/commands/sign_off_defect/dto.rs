@@ -0,0 +1,19 @@
+use domain::inspection::value_types::defect_resolution::DefectResolution;
+
+pub struct SignOffDefectCommand {
+    pub defect_id: uuid::Uuid,
+    pub resolution: DefectResolution,
+    /// Required when no repair is needed, to explain why.
+    pub notes: Option<String>,
+    /// When the defect was signed off, now when `None`.
+    pub signed_off_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+pub struct SignOffDefectResponse {
+    pub defect_id: uuid::Uuid,
+    pub vehicle_id: uuid::Uuid,
+    pub resolution: DefectResolution,
+    pub signed_off_at: chrono::DateTime<chrono::Utc>,
+    /// Whether the vehicle is still out of service (another critical defect is open).
+    pub out_of_service: bool,
+}
@@ -0,0 +1,17 @@
+use crate::vehicle::traits::vehicle_repository::VehicleApplicationRepositoryError;
+use domain::inspection::{
+    entities::inspection_defect::InspectionDefectError,
+    repositories::inspection_repository::InspectionRepositoryError,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum SignOffDefectError {
+    #[error("Defect not found: {0}")]
+    DefectNotFound(uuid::Uuid),
+    #[error("Invalid sign-off: {0}")]
+    Defect(#[from] InspectionDefectError),
+    #[error("Vehicle repository error: {0}")]
+    VehicleRepository(#[from] VehicleApplicationRepositoryError),
+    #[error("Inspection repository error: {0}")]
+    InspectionRepository(#[from] InspectionRepositoryError),
+}
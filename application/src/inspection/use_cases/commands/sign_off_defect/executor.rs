@@ -0,0 +1,78 @@
+use super::{
+    dto::{SignOffDefectCommand as Input, SignOffDefectResponse as Output},
+    error::SignOffDefectError as Error,
+};
+use crate::{
+    auth::AuthenticatedUser,
+    vehicle::{
+        scope::ensure_vehicle_in_scope, traits::vehicle_repository::VehicleApplicationRepository,
+    },
+};
+use domain::inspection::{
+    entities::inspection_defect::InspectionDefectError,
+    repositories::inspection_repository::InspectionRepository, services::out_of_service_rule,
+};
+
+pub struct SignOffDefectUseCase<'a, VAR, IR>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    IR: InspectionRepository + 'a,
+{
+    vehicle_repository: &'a VAR,
+    inspection_repository: &'a IR,
+}
+
+impl<'a, VAR, IR> SignOffDefectUseCase<'a, VAR, IR>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    IR: InspectionRepository + 'a,
+{
+    pub fn new(vehicle_repository: &'a VAR, inspection_repository: &'a IR) -> Self {
+        SignOffDefectUseCase {
+            vehicle_repository,
+            inspection_repository,
+        }
+    }
+
+    /// Signs off an open defect of a vehicle the user sees, as the mechanic.
+    pub async fn execute(&self, cmd: Input, user: &AuthenticatedUser) -> Result<Output, Error> {
+        let mut defect = self
+            .inspection_repository
+            .find_defect(user.organization_id, cmd.defect_id)
+            .await?
+            .ok_or(Error::DefectNotFound(cmd.defect_id))?;
+
+        // The defect of a vehicle out of the scope of the user is reported as missing
+        ensure_vehicle_in_scope(
+            self.vehicle_repository,
+            user,
+            defect.vehicle_id,
+            Error::DefectNotFound(cmd.defect_id),
+        )
+        .await?;
+
+        let signed_off_at = cmd.signed_off_at.unwrap_or_else(chrono::Utc::now);
+        defect.sign_off(cmd.resolution, signed_off_at, user.user_id, cmd.notes)?;
+        // Signed off concurrently by another mechanic
+        let updated = self
+            .inspection_repository
+            .sign_off_defect(user.organization_id, defect.clone())
+            .await?;
+        if !updated {
+            return Err(InspectionDefectError::AlreadySignedOff(defect.label).into());
+        }
+
+        let defects = self
+            .inspection_repository
+            .find_defects_by_vehicle(user.organization_id, defect.vehicle_id, true)
+            .await?;
+
+        Ok(Output {
+            defect_id: defect.id,
+            vehicle_id: defect.vehicle_id,
+            resolution: cmd.resolution,
+            signed_off_at,
+            out_of_service: out_of_service_rule::out_of_service_since(&defects).is_some(),
+        })
+    }
+}
@@ -0,0 +1,48 @@
+use domain::inspection::{
+    entities::{inspection_defect::InspectionDefect, inspection_report::ItemAnswer},
+    value_types::inspection_kind::InspectionKind,
+};
+
+/// The inspection is submitted with the readings of the vehicle, recorded as its latest status.
+pub struct SubmitInspectionCommand {
+    pub vehicle_id: uuid::Uuid,
+    pub template_id: uuid::Uuid,
+    pub performed_at: chrono::DateTime<chrono::Utc>,
+    pub odometer: i32,
+    pub engine_hour_meter: Option<i32>,
+    pub fuel_level: Option<i32>,
+    /// Notes of the vehicle status.
+    pub notes: String,
+    /// An answer per item of the template.
+    pub answers: Vec<ItemAnswer>,
+}
+
+pub struct ReportedDefect {
+    pub id: uuid::Uuid,
+    pub item_id: uuid::Uuid,
+    pub label: String,
+    pub critical: bool,
+}
+
+impl From<InspectionDefect> for ReportedDefect {
+    fn from(defect: InspectionDefect) -> Self {
+        ReportedDefect {
+            id: defect.id,
+            item_id: defect.item_id,
+            label: defect.label,
+            critical: defect.critical,
+        }
+    }
+}
+
+pub struct SubmitInspectionResponse {
+    pub id: uuid::Uuid,
+    pub kind: InspectionKind,
+    pub vehicle_status_id: i32,
+    pub submitted_at: chrono::DateTime<chrono::Utc>,
+    /// The defects opened for the failed items.
+    pub defects: Vec<ReportedDefect>,
+    /// Whether the vehicle is out of service after the inspection (this one or an earlier one
+    /// found a critical defect still open).
+    pub out_of_service: bool,
+}
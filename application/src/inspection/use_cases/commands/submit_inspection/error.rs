@@ -0,0 +1,24 @@
+use crate::vehicle::traits::vehicle_repository::VehicleApplicationRepositoryError;
+use domain::{
+    inspection::{
+        entities::inspection_report::InspectionReportError,
+        repositories::inspection_repository::InspectionRepositoryError,
+    },
+    vehicle::repositories::vehicle_status_repository::VehicleStatusRepositoryError,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum SubmitInspectionError {
+    #[error("Vehicle not found: {0}")]
+    VehicleNotFound(uuid::Uuid),
+    #[error("Inspection template not found: {0}")]
+    TemplateNotFound(uuid::Uuid),
+    #[error("Invalid inspection: {0}")]
+    InvalidReport(#[from] InspectionReportError),
+    #[error("Vehicle repository error: {0}")]
+    VehicleRepository(#[from] VehicleApplicationRepositoryError),
+    #[error("Vehicle status repository error: {0}")]
+    VehicleStatusRepository(#[from] VehicleStatusRepositoryError),
+    #[error("Inspection repository error: {0}")]
+    InspectionRepository(#[from] InspectionRepositoryError),
+}
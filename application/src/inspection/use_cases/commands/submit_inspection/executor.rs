@@ -0,0 +1,124 @@
+use super::{
+    dto::{ReportedDefect, SubmitInspectionCommand as Input, SubmitInspectionResponse as Output},
+    error::SubmitInspectionError as Error,
+};
+use crate::{
+    auth::AuthenticatedUser,
+    vehicle::{
+        scope::ensure_vehicle_in_scope, traits::vehicle_repository::VehicleApplicationRepository,
+    },
+};
+use domain::{
+    inspection::{
+        entities::{inspection_defect::InspectionDefect, inspection_report::InspectionReport},
+        repositories::inspection_repository::InspectionRepository,
+        services::out_of_service_rule,
+    },
+    vehicle::{
+        entities::vehicle_status::VehicleStatusIdentity,
+        repositories::vehicle_status_repository::VehicleStatusRepository,
+    },
+};
+
+pub struct SubmitInspectionUseCase<'a, VAR, VSR, IR>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    VSR: VehicleStatusRepository + 'a,
+    IR: InspectionRepository + 'a,
+{
+    vehicle_repository: &'a VAR,
+    vehicle_status_repository: &'a VSR,
+    inspection_repository: &'a IR,
+}
+
+impl<'a, VAR, VSR, IR> SubmitInspectionUseCase<'a, VAR, VSR, IR>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    VSR: VehicleStatusRepository + 'a,
+    IR: InspectionRepository + 'a,
+{
+    pub fn new(
+        vehicle_repository: &'a VAR,
+        vehicle_status_repository: &'a VSR,
+        inspection_repository: &'a IR,
+    ) -> Self {
+        SubmitInspectionUseCase {
+            vehicle_repository,
+            vehicle_status_repository,
+            inspection_repository,
+        }
+    }
+
+    /// Submits the inspection of a vehicle the user sees, opening a defect per failed item.
+    pub async fn execute(&self, cmd: Input, user: &AuthenticatedUser) -> Result<Output, Error> {
+        ensure_vehicle_in_scope(
+            self.vehicle_repository,
+            user,
+            cmd.vehicle_id,
+            Error::VehicleNotFound(cmd.vehicle_id),
+        )
+        .await?;
+
+        let template = self
+            .inspection_repository
+            .find_template(user.organization_id, cmd.template_id)
+            .await?
+            .ok_or(Error::TemplateNotFound(cmd.template_id))?;
+        // The answers are checked before anything is written, the status is set once recorded
+        let mut report = InspectionReport::submit(
+            &template,
+            cmd.vehicle_id,
+            0,
+            user.user_id,
+            cmd.performed_at,
+            cmd.answers,
+        )?;
+
+        let now = chrono::Utc::now();
+        let status = self
+            .vehicle_status_repository
+            .create(
+                user.organization_id,
+                VehicleStatusIdentity {
+                    id: 0,
+                    vehicle_id: cmd.vehicle_id,
+                    performed_by: user.user_id,
+                    performed_at: cmd.performed_at,
+                    odometer: cmd.odometer,
+                    engine_hour_meter: cmd.engine_hour_meter,
+                    fuel_level: cmd.fuel_level,
+                    notes: cmd.notes,
+                    created_at: now,
+                    updated_at: now,
+                },
+            )
+            .await?;
+        report.vehicle_status_id = status.id;
+
+        let defects = InspectionDefect::open_all(&report);
+        let puts_out_of_service = out_of_service_rule::puts_out_of_service(&report);
+        let report = self
+            .inspection_repository
+            .submit_report(user.organization_id, report, defects.clone())
+            .await?;
+
+        // An earlier inspection may have put the vehicle out of service already
+        let out_of_service = puts_out_of_service
+            || out_of_service_rule::out_of_service_since(
+                &self
+                    .inspection_repository
+                    .find_defects_by_vehicle(user.organization_id, cmd.vehicle_id, true)
+                    .await?,
+            )
+            .is_some();
+
+        Ok(Output {
+            id: report.id,
+            kind: report.kind,
+            vehicle_status_id: report.vehicle_status_id,
+            submitted_at: report.submitted_at,
+            defects: defects.into_iter().map(ReportedDefect::from).collect(),
+            out_of_service,
+        })
+    }
+}
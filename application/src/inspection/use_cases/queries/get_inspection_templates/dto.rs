@@ -0,0 +1,34 @@
+use domain::inspection::{
+    entities::inspection_template::{InspectionItem, InspectionTemplate},
+    value_types::inspection_kind::InspectionKind,
+};
+
+pub struct GetInspectionTemplatesQuery {
+    /// Only the templates of this kind, every template when `None`.
+    pub kind: Option<InspectionKind>,
+}
+
+pub struct InspectionTemplateView {
+    pub id: uuid::Uuid,
+    pub name: String,
+    pub kind: InspectionKind,
+    pub items: Vec<InspectionItem>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<InspectionTemplate> for InspectionTemplateView {
+    fn from(template: InspectionTemplate) -> Self {
+        InspectionTemplateView {
+            id: template.id,
+            name: template.name,
+            kind: template.kind,
+            items: template.items,
+            created_at: template.created_at,
+        }
+    }
+}
+
+pub struct GetInspectionTemplatesResponse {
+    /// The templates, ordered by name.
+    pub templates: Vec<InspectionTemplateView>,
+}
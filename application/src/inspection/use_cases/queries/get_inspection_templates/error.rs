@@ -0,0 +1,7 @@
+use domain::inspection::repositories::inspection_repository::InspectionRepositoryError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetInspectionTemplatesError {
+    #[error("Inspection repository error: {0}")]
+    InspectionRepository(#[from] InspectionRepositoryError),
+}
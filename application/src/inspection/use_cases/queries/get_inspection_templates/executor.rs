@@ -0,0 +1,40 @@
+use super::{
+    dto::{
+        GetInspectionTemplatesQuery as Input, GetInspectionTemplatesResponse as Output,
+        InspectionTemplateView,
+    },
+    error::GetInspectionTemplatesError as Error,
+};
+use crate::auth::AuthenticatedUser;
+use domain::inspection::repositories::inspection_repository::InspectionRepository;
+
+pub struct GetInspectionTemplatesUseCase<'a, IR>
+where
+    IR: InspectionRepository + 'a,
+{
+    inspection_repository: &'a IR,
+}
+
+impl<'a, IR> GetInspectionTemplatesUseCase<'a, IR>
+where
+    IR: InspectionRepository + 'a,
+{
+    pub fn new(inspection_repository: &'a IR) -> Self {
+        GetInspectionTemplatesUseCase {
+            inspection_repository,
+        }
+    }
+
+    pub async fn execute(&self, query: Input, user: &AuthenticatedUser) -> Result<Output, Error> {
+        let templates = self
+            .inspection_repository
+            .find_templates(user.organization_id)
+            .await?
+            .into_iter()
+            .filter(|template| query.kind.is_none_or(|kind| template.kind == kind))
+            .map(InspectionTemplateView::from)
+            .collect();
+
+        Ok(Output { templates })
+    }
+}
@@ -0,0 +1,7 @@
+pub mod dto;
+pub mod error;
+pub mod executor;
+
+pub use dto::*;
+pub use error::*;
+pub use executor::*;
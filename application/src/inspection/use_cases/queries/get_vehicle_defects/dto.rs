@@ -0,0 +1,51 @@
+use domain::inspection::{
+    entities::inspection_defect::InspectionDefect, value_types::defect_resolution::DefectResolution,
+};
+
+pub struct GetVehicleDefectsQuery {
+    pub vehicle_id: uuid::Uuid,
+    /// Include the signed off defects (the history), not only the open ones.
+    pub include_signed_off: bool,
+}
+
+pub struct InspectionDefectView {
+    pub id: uuid::Uuid,
+    pub report_id: uuid::Uuid,
+    pub item_id: uuid::Uuid,
+    pub label: String,
+    pub critical: bool,
+    pub comment: Option<String>,
+    pub reported_at: chrono::DateTime<chrono::Utc>,
+    pub resolution: Option<DefectResolution>,
+    pub signed_off_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub signed_off_by: Option<uuid::Uuid>,
+    pub sign_off_notes: Option<String>,
+}
+
+impl From<InspectionDefect> for InspectionDefectView {
+    fn from(defect: InspectionDefect) -> Self {
+        InspectionDefectView {
+            id: defect.id,
+            report_id: defect.report_id,
+            item_id: defect.item_id,
+            label: defect.label,
+            critical: defect.critical,
+            comment: defect.comment,
+            reported_at: defect.reported_at,
+            resolution: defect.resolution,
+            signed_off_at: defect.signed_off_at,
+            signed_off_by: defect.signed_off_by,
+            sign_off_notes: defect.sign_off_notes,
+        }
+    }
+}
+
+pub struct GetVehicleDefectsResponse {
+    pub vehicle_id: uuid::Uuid,
+    /// Whether an open critical defect keeps the vehicle off the road.
+    pub out_of_service: bool,
+    /// When the oldest open critical defect was reported.
+    pub out_of_service_since: Option<chrono::DateTime<chrono::Utc>>,
+    /// The defects, ordered by report.
+    pub defects: Vec<InspectionDefectView>,
+}
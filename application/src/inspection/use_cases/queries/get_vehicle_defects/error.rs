@@ -0,0 +1,12 @@
+use crate::vehicle::traits::vehicle_repository::VehicleApplicationRepositoryError;
+use domain::inspection::repositories::inspection_repository::InspectionRepositoryError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetVehicleDefectsError {
+    #[error("Vehicle not found: {0}")]
+    VehicleNotFound(uuid::Uuid),
+    #[error("Vehicle repository error: {0}")]
+    VehicleRepository(#[from] VehicleApplicationRepositoryError),
+    #[error("Inspection repository error: {0}")]
+    InspectionRepository(#[from] InspectionRepositoryError),
+}
@@ -0,0 +1,68 @@
+use super::{
+    dto::{
+        GetVehicleDefectsQuery as Input, GetVehicleDefectsResponse as Output, InspectionDefectView,
+    },
+    error::GetVehicleDefectsError as Error,
+};
+use crate::{
+    auth::AuthenticatedUser,
+    vehicle::{
+        scope::ensure_vehicle_in_scope, traits::vehicle_repository::VehicleApplicationRepository,
+    },
+};
+use domain::inspection::{
+    repositories::inspection_repository::InspectionRepository, services::out_of_service_rule,
+};
+
+pub struct GetVehicleDefectsUseCase<'a, VAR, IR>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    IR: InspectionRepository + 'a,
+{
+    vehicle_repository: &'a VAR,
+    inspection_repository: &'a IR,
+}
+
+impl<'a, VAR, IR> GetVehicleDefectsUseCase<'a, VAR, IR>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    IR: InspectionRepository + 'a,
+{
+    pub fn new(vehicle_repository: &'a VAR, inspection_repository: &'a IR) -> Self {
+        GetVehicleDefectsUseCase {
+            vehicle_repository,
+            inspection_repository,
+        }
+    }
+
+    /// Lists the defects of a vehicle the user sees, and whether they keep it out of service.
+    pub async fn execute(&self, query: Input, user: &AuthenticatedUser) -> Result<Output, Error> {
+        ensure_vehicle_in_scope(
+            self.vehicle_repository,
+            user,
+            query.vehicle_id,
+            Error::VehicleNotFound(query.vehicle_id),
+        )
+        .await?;
+
+        let defects = self
+            .inspection_repository
+            .find_defects_by_vehicle(
+                user.organization_id,
+                query.vehicle_id,
+                !query.include_signed_off,
+            )
+            .await?;
+        let out_of_service_since = out_of_service_rule::out_of_service_since(&defects);
+
+        Ok(Output {
+            vehicle_id: query.vehicle_id,
+            out_of_service: out_of_service_since.is_some(),
+            out_of_service_since,
+            defects: defects
+                .into_iter()
+                .map(InspectionDefectView::from)
+                .collect(),
+        })
+    }
+}
@@ -0,0 +1,45 @@
+use domain::inspection::{
+    entities::inspection_report::{InspectionAnswer, InspectionReport},
+    value_types::inspection_kind::InspectionKind,
+};
+
+pub struct GetVehicleInspectionsQuery {
+    pub vehicle_id: uuid::Uuid,
+    /// Only the inspections submitted at or after this moment are included.
+    pub from: Option<chrono::DateTime<chrono::Utc>>,
+    /// Only the inspections submitted at or before this moment are included.
+    pub to: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+pub struct InspectionReportView {
+    pub id: uuid::Uuid,
+    pub template_id: uuid::Uuid,
+    pub kind: InspectionKind,
+    pub vehicle_status_id: i32,
+    pub submitted_by: uuid::Uuid,
+    pub submitted_at: chrono::DateTime<chrono::Utc>,
+    /// The answers, in the order of the template.
+    pub answers: Vec<InspectionAnswer>,
+    pub failed_items: usize,
+}
+
+impl From<InspectionReport> for InspectionReportView {
+    fn from(report: InspectionReport) -> Self {
+        InspectionReportView {
+            id: report.id,
+            template_id: report.template_id,
+            kind: report.kind,
+            vehicle_status_id: report.vehicle_status_id,
+            submitted_by: report.submitted_by,
+            submitted_at: report.submitted_at,
+            failed_items: report.failed().count(),
+            answers: report.answers,
+        }
+    }
+}
+
+pub struct GetVehicleInspectionsResponse {
+    pub vehicle_id: uuid::Uuid,
+    /// The inspections, ordered by submission.
+    pub inspections: Vec<InspectionReportView>,
+}
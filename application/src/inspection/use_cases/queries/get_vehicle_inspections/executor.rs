@@ -0,0 +1,60 @@
+use super::{
+    dto::{
+        GetVehicleInspectionsQuery as Input, GetVehicleInspectionsResponse as Output,
+        InspectionReportView,
+    },
+    error::GetVehicleInspectionsError as Error,
+};
+use crate::{
+    auth::AuthenticatedUser,
+    vehicle::{
+        scope::ensure_vehicle_in_scope, traits::vehicle_repository::VehicleApplicationRepository,
+    },
+};
+use domain::inspection::repositories::inspection_repository::InspectionRepository;
+
+pub struct GetVehicleInspectionsUseCase<'a, VAR, IR>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    IR: InspectionRepository + 'a,
+{
+    vehicle_repository: &'a VAR,
+    inspection_repository: &'a IR,
+}
+
+impl<'a, VAR, IR> GetVehicleInspectionsUseCase<'a, VAR, IR>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    IR: InspectionRepository + 'a,
+{
+    pub fn new(vehicle_repository: &'a VAR, inspection_repository: &'a IR) -> Self {
+        GetVehicleInspectionsUseCase {
+            vehicle_repository,
+            inspection_repository,
+        }
+    }
+
+    /// Lists the inspections of a vehicle the user sees.
+    pub async fn execute(&self, query: Input, user: &AuthenticatedUser) -> Result<Output, Error> {
+        ensure_vehicle_in_scope(
+            self.vehicle_repository,
+            user,
+            query.vehicle_id,
+            Error::VehicleNotFound(query.vehicle_id),
+        )
+        .await?;
+
+        let reports = self
+            .inspection_repository
+            .find_reports_by_vehicle(user.organization_id, query.vehicle_id, query.from, query.to)
+            .await?;
+
+        Ok(Output {
+            vehicle_id: query.vehicle_id,
+            inspections: reports
+                .into_iter()
+                .map(InspectionReportView::from)
+                .collect(),
+        })
+    }
+}
@@ -0,0 +1,3 @@
+pub mod get_inspection_templates;
+pub mod get_vehicle_defects;
+pub mod get_vehicle_inspections;
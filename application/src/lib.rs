@@ -1,5 +1,13 @@
 // pub mod use_cases;
 pub mod auth;
+pub mod depot;
+pub mod diagnostics;
+pub mod fuel;
+pub mod inspection;
 pub mod maintenance;
+pub mod reporting;
+pub mod search;
 pub mod vehicle;
-pub mod shared;
\ No newline at end of file
+pub mod shared;
+pub mod telematics;
+pub mod trip;
\ No newline at end of file
@@ -0,0 +1,234 @@
+use super::catalog_document::{CatalogMaintenanceType, MaintenanceCatalog, same_name};
+
+/// A difference of a maintenance type between two catalogs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CatalogChange {
+    /// The maintenance type is only in the new catalog.
+    Added { name: String },
+    /// The maintenance type is only in the old catalog.
+    Removed { name: String },
+    /// The maintenance type is in both catalogs, with different values for the listed fields.
+    Changed {
+        name: String,
+        fields: Vec<&'static str>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CatalogDiff {
+    pub from_version: String,
+    pub to_version: String,
+    pub changes: Vec<CatalogChange>,
+}
+
+impl CatalogDiff {
+    /// Compares two catalogs, maintenance types are matched by name.
+    pub fn between(old: &MaintenanceCatalog, new: &MaintenanceCatalog) -> Self {
+        let mut changes = Vec::new();
+
+        for new_type in &new.maintenance_types {
+            match old.find(&new_type.name) {
+                None => changes.push(CatalogChange::Added {
+                    name: new_type.name.clone(),
+                }),
+                Some(old_type) => {
+                    let fields = changed_fields(old_type, new_type);
+                    if !fields.is_empty() {
+                        changes.push(CatalogChange::Changed {
+                            name: new_type.name.clone(),
+                            fields,
+                        });
+                    }
+                }
+            }
+        }
+        for old_type in &old.maintenance_types {
+            if new.find(&old_type.name).is_none() {
+                changes.push(CatalogChange::Removed {
+                    name: old_type.name.clone(),
+                });
+            }
+        }
+
+        CatalogDiff {
+            from_version: old.version.clone(),
+            to_version: new.version.clone(),
+            changes,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Returns the fields of a maintenance type that changed, if any.
+    pub fn changed_fields(&self, name: &str) -> Option<&[&'static str]> {
+        self.changes.iter().find_map(|change| match change {
+            CatalogChange::Changed {
+                name: changed,
+                fields,
+            } if same_name(changed, name) => Some(fields.as_slice()),
+            _ => None,
+        })
+    }
+}
+
+fn changed_fields(old: &CatalogMaintenanceType, new: &CatalogMaintenanceType) -> Vec<&'static str> {
+    let normalize = |value: &str| value.trim().to_lowercase().replace([' ', '-', '_'], "");
+    let mut fields = Vec::new();
+
+    if old.description.trim() != new.description.trim() {
+        fields.push("description");
+    }
+    if old.category.as_deref().map(normalize) != new.category.as_deref().map(normalize) {
+        fields.push("category");
+    }
+    if normalize(&old.applicability) != normalize(&new.applicability) {
+        fields.push("applicability");
+    }
+    if old.checklist != new.checklist {
+        fields.push("checklist");
+    }
+    // The order of the intervals doesn't matter
+    let intervals = |maintenance_type: &CatalogMaintenanceType| {
+        let mut intervals: Vec<_> = maintenance_type
+            .intervals
+            .iter()
+            .map(|interval| {
+                (
+                    interval.make.as_deref().map(normalize),
+                    interval.model.as_deref().map(normalize),
+                    interval.engine_type.as_deref().map(normalize),
+                    normalize(&interval.interval_type),
+                    interval.interval_value,
+                    interval.yellow_threshold,
+                    interval.red_threshold,
+                )
+            })
+            .collect();
+        intervals.sort();
+        intervals
+    };
+    if intervals(old) != intervals(new) {
+        fields.push("intervals");
+    }
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::maintenance::catalog::catalog_document::CatalogInterval;
+
+    fn interval(interval_type: &str, interval_value: u32) -> CatalogInterval {
+        CatalogInterval {
+            make: None,
+            model: None,
+            engine_type: None,
+            interval_type: interval_type.to_string(),
+            interval_value,
+            yellow_threshold: 75,
+            red_threshold: 90,
+        }
+    }
+
+    fn maintenance_type(name: &str, intervals: Vec<CatalogInterval>) -> CatalogMaintenanceType {
+        CatalogMaintenanceType {
+            name: name.to_string(),
+            description: format!("{} of the vehicle", name),
+            category: Some("engine".to_string()),
+            applicability: "combustion_engine".to_string(),
+            checklist: vec!["Drain the oil".to_string()],
+            intervals,
+        }
+    }
+
+    fn catalog(
+        version: &str,
+        maintenance_types: Vec<CatalogMaintenanceType>,
+    ) -> MaintenanceCatalog {
+        MaintenanceCatalog {
+            version: version.to_string(),
+            maintenance_types,
+        }
+    }
+
+    #[test]
+    fn test_added_removed_and_changed_types() {
+        let old = catalog(
+            "1",
+            vec![
+                maintenance_type("Oil change", vec![interval("Kilometers", 10_000)]),
+                maintenance_type("Brake check", vec![]),
+            ],
+        );
+        let mut oil_change = maintenance_type("oil change ", vec![interval("Kilometers", 15_000)]);
+        oil_change.description = "New description".to_string();
+        let new = catalog(
+            "2",
+            vec![oil_change, maintenance_type("Tire rotation", vec![])],
+        );
+
+        let diff = CatalogDiff::between(&old, &new);
+        assert_eq!(
+            (diff.from_version.as_str(), diff.to_version.as_str()),
+            ("1", "2")
+        );
+        assert_eq!(
+            diff.changes,
+            vec![
+                CatalogChange::Changed {
+                    name: "oil change ".to_string(),
+                    fields: vec!["description", "intervals"],
+                },
+                CatalogChange::Added {
+                    name: "Tire rotation".to_string(),
+                },
+                CatalogChange::Removed {
+                    name: "Brake check".to_string(),
+                },
+            ]
+        );
+        assert_eq!(
+            diff.changed_fields("Oil Change"),
+            Some(&["description", "intervals"][..])
+        );
+        assert_eq!(diff.changed_fields("Tire rotation"), None);
+    }
+
+    #[test]
+    fn test_formatting_and_order_are_not_changes() {
+        let old = catalog(
+            "1",
+            vec![maintenance_type(
+                "Oil change",
+                vec![interval("Kilometers", 10_000), interval("Years", 1)],
+            )],
+        );
+        let mut reformatted = maintenance_type(
+            "Oil change",
+            vec![interval("years", 1), interval("Kilometers", 10_000)],
+        );
+        reformatted.category = Some("Engine".to_string());
+        reformatted.applicability = "Combustion Engine".to_string();
+        reformatted.description = " Oil change of the vehicle ".to_string();
+        let new = catalog("2", vec![reformatted]);
+
+        assert!(CatalogDiff::between(&old, &new).is_empty());
+    }
+
+    #[test]
+    fn test_every_changed_field_is_listed() {
+        let old = catalog("1", vec![maintenance_type("Oil change", vec![])]);
+        let mut changed = maintenance_type("Oil change", vec![interval("Kilometers", 10_000)]);
+        changed.category = None;
+        changed.applicability = "any".to_string();
+        changed.checklist.push("Replace the filter".to_string());
+        let new = catalog("2", vec![changed]);
+
+        assert_eq!(
+            CatalogDiff::between(&old, &new).changed_fields("Oil change"),
+            Some(&["category", "applicability", "checklist", "intervals"][..])
+        );
+    }
+}
@@ -0,0 +1,118 @@
+use domain::maintenance::entities::{
+    maintenance_interval_template::MaintenanceIntervalTemplate,
+    maintenance_type::MaintenanceTypeView,
+};
+
+/// A versioned catalog of maintenance types with their default intervals.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct MaintenanceCatalog {
+    pub version: String,
+    #[serde(default)]
+    pub maintenance_types: Vec<CatalogMaintenanceType>,
+}
+
+/// A maintenance type of the catalog, values are kept raw until the import validates them.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CatalogMaintenanceType {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// Category (e.g., "engine", "brakes").
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    /// Powertrains the type applies to (e.g., "combustion_engine"), every vehicle when missing.
+    #[serde(default = "default_applicability")]
+    pub applicability: String,
+    /// Steps of the standard procedure.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub checklist: Vec<String>,
+    /// Default intervals, see `MaintenanceIntervalTemplate`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub intervals: Vec<CatalogInterval>,
+}
+
+/// A default interval of a catalog maintenance type.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+pub struct CatalogInterval {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub make: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub engine_type: Option<String>,
+    /// Interval type (e.g., "Kilometers", "Engine Hours", "Years").
+    pub interval_type: String,
+    pub interval_value: u32,
+    pub yellow_threshold: u32,
+    pub red_threshold: u32,
+}
+
+fn default_applicability() -> String {
+    "any".to_string()
+}
+
+impl MaintenanceCatalog {
+    /// Builds the catalog of the maintenance types stored in the system.
+    pub fn from_views(
+        version: String,
+        views: Vec<MaintenanceTypeView>,
+        templates: Vec<MaintenanceIntervalTemplate>,
+    ) -> Self {
+        let mut maintenance_types: Vec<CatalogMaintenanceType> = views
+            .into_iter()
+            .map(|view| {
+                let mut intervals: Vec<CatalogInterval> = templates
+                    .iter()
+                    .filter(|template| template.maintenance_type_id == view.id)
+                    .map(CatalogInterval::from)
+                    .collect();
+                intervals.sort();
+
+                CatalogMaintenanceType {
+                    name: view.name,
+                    description: view.description,
+                    category: view.category.map(|category| category.as_str().to_string()),
+                    applicability: view.applicability.as_str().to_string(),
+                    checklist: view.checklist,
+                    intervals,
+                }
+            })
+            .collect();
+        maintenance_types.sort_by(|a, b| a.name.cmp(&b.name));
+
+        MaintenanceCatalog {
+            version,
+            maintenance_types,
+        }
+    }
+
+    /// Finds a maintenance type by name (ignoring case and surrounding spaces).
+    pub fn find(&self, name: &str) -> Option<&CatalogMaintenanceType> {
+        self.maintenance_types
+            .iter()
+            .find(|maintenance_type| same_name(&maintenance_type.name, name))
+    }
+}
+
+impl From<&MaintenanceIntervalTemplate> for CatalogInterval {
+    fn from(template: &MaintenanceIntervalTemplate) -> Self {
+        CatalogInterval {
+            make: template.scope.make.clone(),
+            model: template.scope.model.clone(),
+            engine_type: template
+                .scope
+                .engine_type
+                .as_ref()
+                .map(|engine_type| engine_type.to_string()),
+            interval_type: template.interval_type.as_str().to_string(),
+            interval_value: template.interval_value,
+            yellow_threshold: template.yellow_threshold,
+            red_threshold: template.red_threshold,
+        }
+    }
+}
+
+/// Maintenance type names are compared ignoring case and surrounding spaces.
+pub fn same_name(a: &str, b: &str) -> bool {
+    a.trim().to_lowercase() == b.trim().to_lowercase()
+}
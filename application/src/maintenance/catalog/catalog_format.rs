@@ -0,0 +1,199 @@
+use super::catalog_document::MaintenanceCatalog;
+use std::str::FromStr;
+
+/// Document formats of the maintenance catalog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CatalogFormat {
+    Json,
+    Yaml,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CatalogFormatError {
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("YAML error: {0}")]
+    Yaml(#[from] serde_yaml::Error),
+}
+
+impl CatalogFormat {
+    /// Returns the file extension of the format.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            CatalogFormat::Json => "json",
+            CatalogFormat::Yaml => "yaml",
+        }
+    }
+
+    /// Returns the MIME type of the format.
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            CatalogFormat::Json => "application/json",
+            CatalogFormat::Yaml => "application/yaml",
+        }
+    }
+
+    /// Parses a catalog document.
+    pub fn parse(&self, content: &str) -> Result<MaintenanceCatalog, CatalogFormatError> {
+        match self {
+            CatalogFormat::Json => Ok(serde_json::from_str(content)?),
+            CatalogFormat::Yaml => Ok(serde_yaml::from_str(content)?),
+        }
+    }
+
+    /// Writes a catalog document.
+    pub fn render(&self, catalog: &MaintenanceCatalog) -> Result<String, CatalogFormatError> {
+        match self {
+            CatalogFormat::Json => Ok(serde_json::to_string_pretty(catalog)?),
+            CatalogFormat::Yaml => Ok(serde_yaml::to_string(catalog)?),
+        }
+    }
+}
+
+impl FromStr for CatalogFormat {
+    type Err = String;
+
+    /// Accepts the format name or a file extension.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim_start_matches('.').to_lowercase().as_str() {
+            "json" => Ok(Self::Json),
+            "yaml" | "yml" => Ok(Self::Yaml),
+            _ => Err(format!("Invalid catalog format: {}", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::maintenance::catalog::catalog_document::{CatalogInterval, CatalogMaintenanceType};
+
+    const YAML: &str = "
+version: '2025.1'
+maintenance_types:
+  - name: Oil change
+    description: Engine oil and filter
+    category: engine
+    applicability: combustion_engine
+    checklist:
+      - Drain the oil
+      - Replace the filter
+    intervals:
+      - interval_type: Kilometers
+        interval_value: 10000
+        yellow_threshold: 75
+        red_threshold: 90
+      - engine_type: diesel
+        interval_type: Kilometers
+        interval_value: 8000
+        yellow_threshold: 80
+        red_threshold: 95
+  - name: Cabin filter
+";
+
+    fn expected() -> MaintenanceCatalog {
+        let interval = |engine_type: Option<&str>, value, yellow, red| CatalogInterval {
+            make: None,
+            model: None,
+            engine_type: engine_type.map(str::to_string),
+            interval_type: "Kilometers".to_string(),
+            interval_value: value,
+            yellow_threshold: yellow,
+            red_threshold: red,
+        };
+        MaintenanceCatalog {
+            version: "2025.1".to_string(),
+            maintenance_types: vec![
+                CatalogMaintenanceType {
+                    name: "Oil change".to_string(),
+                    description: "Engine oil and filter".to_string(),
+                    category: Some("engine".to_string()),
+                    applicability: "combustion_engine".to_string(),
+                    checklist: vec![
+                        "Drain the oil".to_string(),
+                        "Replace the filter".to_string(),
+                    ],
+                    intervals: vec![
+                        interval(None, 10_000, 75, 90),
+                        interval(Some("diesel"), 8_000, 80, 95),
+                    ],
+                },
+                CatalogMaintenanceType {
+                    name: "Cabin filter".to_string(),
+                    description: String::new(),
+                    category: None,
+                    applicability: "any".to_string(),
+                    checklist: Vec::new(),
+                    intervals: Vec::new(),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_parse_yaml_with_defaults() {
+        assert_eq!(CatalogFormat::Yaml.parse(YAML).unwrap(), expected());
+    }
+
+    #[test]
+    fn test_parse_json() {
+        let json = r#"{
+            "version": "2025.1",
+            "maintenance_types": [
+                {
+                    "name": "Oil change",
+                    "description": "Engine oil and filter",
+                    "category": "engine",
+                    "applicability": "combustion_engine",
+                    "checklist": ["Drain the oil", "Replace the filter"],
+                    "intervals": [
+                        {"interval_type": "Kilometers", "interval_value": 10000,
+                         "yellow_threshold": 75, "red_threshold": 90},
+                        {"engine_type": "diesel", "interval_type": "Kilometers",
+                         "interval_value": 8000, "yellow_threshold": 80, "red_threshold": 95}
+                    ]
+                },
+                {"name": "Cabin filter"}
+            ]
+        }"#;
+        assert_eq!(CatalogFormat::Json.parse(json).unwrap(), expected());
+    }
+
+    #[test]
+    fn test_render_round_trip() {
+        for format in [CatalogFormat::Json, CatalogFormat::Yaml] {
+            let content = format.render(&expected()).unwrap();
+            assert_eq!(format.parse(&content).unwrap(), expected(), "{:?}", format);
+        }
+    }
+
+    #[test]
+    fn test_invalid_documents() {
+        assert!(matches!(
+            CatalogFormat::Json.parse(r#"{"maintenance_types": []}"#),
+            Err(CatalogFormatError::Json(_))
+        ));
+        assert!(matches!(
+            CatalogFormat::Yaml.parse("version: 1\nmaintenance_types:\n  - description: no name\n"),
+            Err(CatalogFormatError::Yaml(_))
+        ));
+        assert!(matches!(
+            CatalogFormat::Yaml.parse("version: [unclosed"),
+            Err(CatalogFormatError::Yaml(_))
+        ));
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            ".yml".parse::<CatalogFormat>().unwrap(),
+            CatalogFormat::Yaml
+        );
+        assert_eq!(
+            "JSON".parse::<CatalogFormat>().unwrap(),
+            CatalogFormat::Json
+        );
+        assert!("xml".parse::<CatalogFormat>().is_err());
+    }
+}
@@ -0,0 +1,7 @@
+//! Catalog of standard maintenance types, shared between deployments as a YAML or JSON document.
+//!
+//! The document is versioned by its author; the version is only used to label the changes
+//! reported between two catalogs.
+pub mod catalog_diff;
+pub mod catalog_document;
+pub mod catalog_format;
@@ -0,0 +1,28 @@
+use crate::shared::filter_expression::filter_schema::{FilterField, FilterFieldKind, FilterSchema};
+
+/// The maintenance record fields of filter expressions.
+pub const MAINTENANCE_RECORD_FILTER_SCHEMA: FilterSchema = FilterSchema {
+    entity: "maintenance record",
+    fields: &[
+        FilterField {
+            name: "maintenance_id",
+            column: "maintenance_id",
+            kind: FilterFieldKind::Integer,
+        },
+        FilterField {
+            name: "details",
+            column: "details",
+            kind: FilterFieldKind::Text,
+        },
+        FilterField {
+            name: "performed_at",
+            column: "performed_at",
+            kind: FilterFieldKind::Timestamp,
+        },
+        FilterField {
+            name: "created_at",
+            column: "created_at",
+            kind: FilterFieldKind::Timestamp,
+        },
+    ],
+};
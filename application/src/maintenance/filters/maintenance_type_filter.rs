@@ -0,0 +1,56 @@
+use crate::shared::filter_expression::filter_schema::{FilterField, FilterFieldKind, FilterSchema};
+use domain::maintenance::value_types::{
+    maintenance_applicability::MaintenanceApplicability, maintenance_category::MaintenanceCategory,
+};
+use std::str::FromStr;
+
+/// The maintenance type fields of filter expressions.
+pub const MAINTENANCE_TYPE_FILTER_SCHEMA: FilterSchema = FilterSchema {
+    entity: "maintenance type",
+    fields: &[
+        FilterField {
+            name: "name",
+            column: "name",
+            kind: FilterFieldKind::Text,
+        },
+        FilterField {
+            name: "description",
+            column: "description",
+            kind: FilterFieldKind::Text,
+        },
+        FilterField {
+            name: "category",
+            column: "category",
+            kind: FilterFieldKind::Enum {
+                sql_type: "maintenance_category",
+                parse: |value| {
+                    MaintenanceCategory::from_str(value)
+                        .ok()
+                        .map(|category| category.as_sql().to_string())
+                },
+            },
+        },
+        FilterField {
+            name: "applicability",
+            column: "applicability",
+            kind: FilterFieldKind::Enum {
+                sql_type: "maintenance_applicability",
+                parse: |value| {
+                    MaintenanceApplicability::from_str(value)
+                        .ok()
+                        .map(|applicability| applicability.as_sql().to_string())
+                },
+            },
+        },
+        FilterField {
+            name: "created_at",
+            column: "created_at",
+            kind: FilterFieldKind::Timestamp,
+        },
+        FilterField {
+            name: "updated_at",
+            column: "updated_at",
+            kind: FilterFieldKind::Timestamp,
+        },
+    ],
+};
@@ -0,0 +1,2 @@
+pub mod maintenance_record_filter;
+pub mod maintenance_type_filter;
@@ -1 +1,5 @@
-pub mod use_cases;
\ No newline at end of file
+pub mod catalog;
+pub mod filters;
+pub mod models;
+pub mod traits;
+pub mod use_cases;
@@ -0,0 +1,58 @@
+use domain::maintenance::services::maintenance_cost_service::MaintenanceCostTotals;
+use std::str::FromStr;
+
+/// How maintenance costs are grouped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MaintenanceCostGroupBy {
+    Vehicle,
+    MaintenanceType,
+    /// Calendar month (UTC) in which the maintenance was performed.
+    Month,
+}
+
+impl FromStr for MaintenanceCostGroupBy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "vehicle" => Ok(MaintenanceCostGroupBy::Vehicle),
+            "maintenance_type" => Ok(MaintenanceCostGroupBy::MaintenanceType),
+            "month" => Ok(MaintenanceCostGroupBy::Month),
+            _ => Err(format!("Invalid cost grouping: {}", s)),
+        }
+    }
+}
+
+/// Identifies a group of costs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MaintenanceCostGroupKey {
+    Vehicle { vehicle_id: uuid::Uuid },
+    MaintenanceType { maintenance_type_id: i32 },
+    Month { year: i32, month: u32 },
+}
+
+/// Costs of the records of a group in one currency.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MaintenanceCostSum {
+    pub key: MaintenanceCostGroupKey,
+    /// License plate of the vehicle, or name of the maintenance type (deprecated or not); empty
+    /// for a month.
+    pub label: String,
+    pub totals: MaintenanceCostTotals,
+}
+
+/// Costs of the records of a period, grouped by key and currency.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MaintenanceCostSums {
+    /// Ordered by key, then by currency.
+    pub groups: Vec<MaintenanceCostSum>,
+    /// Number of records of the period without a cost.
+    pub records_without_cost: u64,
+}
+
+/// Distance travelled by a vehicle in a period, between its lowest and highest odometer readings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VehicleDistance {
+    pub vehicle_id: uuid::Uuid,
+    pub distance_km: i64,
+}
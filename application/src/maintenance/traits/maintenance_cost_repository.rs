@@ -0,0 +1,42 @@
+use crate::{
+    maintenance::models::maintenance_cost_sum::{
+        MaintenanceCostGroupBy, MaintenanceCostSums, VehicleDistance,
+    },
+    vehicle::filters::vehicle_filter::VehicleFilter,
+};
+use std::future::Future;
+
+#[derive(Debug, thiserror::Error)]
+pub enum MaintenanceCostApplicationRepositoryError {
+    #[error("database error: {0}")]
+    DatabaseError(String),
+}
+
+/// Repository trait for the cost totals of the maintenance records of the fleet
+pub trait MaintenanceCostApplicationRepository: Send + Sync {
+    /// Sum the costs of the records of the vehicles matching the filter performed within the
+    /// given range (both ends inclusive), by group and currency. The paging and sorting of the
+    /// filter are ignored.
+    fn sum_costs(
+        &self,
+        organization_id: uuid::Uuid,
+        vehicles: &VehicleFilter,
+        group_by: MaintenanceCostGroupBy,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl Future<Output = Result<MaintenanceCostSums, MaintenanceCostApplicationRepositoryError>>
+    + Send;
+
+    /// Find the distance travelled within the given range by the vehicles matching the filter
+    /// that have a record with a cost in it, ordered by vehicle. A vehicle without a status in
+    /// the range is left out.
+    fn distance_by_vehicle(
+        &self,
+        organization_id: uuid::Uuid,
+        vehicles: &VehicleFilter,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl Future<
+        Output = Result<Vec<VehicleDistance>, MaintenanceCostApplicationRepositoryError>,
+    > + Send;
+}
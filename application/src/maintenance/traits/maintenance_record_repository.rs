@@ -0,0 +1,35 @@
+use crate::shared::{filter_expression::filter_ast::FilterExpression, pagination::Keyset};
+use domain::maintenance::entities::maintenance_record::MaintenanceRecordIdentity;
+use std::future::Future;
+
+#[derive(Debug, thiserror::Error)]
+pub enum MaintenanceRecordApplicationRepositoryError {
+    #[error("database error: {0}")]
+    DatabaseError(String),
+}
+
+/// Repository trait for listing the maintenance records of a vehicle
+pub trait MaintenanceRecordApplicationRepository: Send + Sync {
+    /// Find the records of the vehicle matching the filter after (or before) the keyset position,
+    /// at most `keyset.limit`, in scanning order
+    fn get_page(
+        &self,
+        organization_id: uuid::Uuid,
+        vehicle_id: uuid::Uuid,
+        filter: Option<&FilterExpression>,
+        keyset: &Keyset,
+    ) -> impl Future<
+        Output = Result<
+            Vec<MaintenanceRecordIdentity>,
+            MaintenanceRecordApplicationRepositoryError,
+        >,
+    > + Send;
+
+    /// Count the records of the vehicle matching the filter
+    fn count(
+        &self,
+        organization_id: uuid::Uuid,
+        vehicle_id: uuid::Uuid,
+        filter: Option<&FilterExpression>,
+    ) -> impl Future<Output = Result<u64, MaintenanceRecordApplicationRepositoryError>> + Send;
+}
@@ -0,0 +1,30 @@
+use crate::shared::{filter_expression::filter_ast::FilterExpression, pagination::Keyset};
+use domain::maintenance::entities::maintenance_type::MaintenanceTypeView;
+use std::future::Future;
+
+#[derive(Debug, thiserror::Error)]
+pub enum MaintenanceTypeApplicationRepositoryError {
+    #[error("database error: {0}")]
+    DatabaseError(String),
+}
+
+/// Repository trait for listing maintenance types, deprecated types excluded
+pub trait MaintenanceTypeApplicationRepository: Send + Sync {
+    /// Find the maintenance type views matching the filter after (or before) the keyset position,
+    /// at most `keyset.limit`, in scanning order
+    fn get_view_page(
+        &self,
+        organization_id: uuid::Uuid,
+        filter: Option<&FilterExpression>,
+        keyset: &Keyset,
+    ) -> impl Future<
+        Output = Result<Vec<MaintenanceTypeView>, MaintenanceTypeApplicationRepositoryError>,
+    > + Send;
+
+    /// Count the maintenance types matching the filter
+    fn count(
+        &self,
+        organization_id: uuid::Uuid,
+        filter: Option<&FilterExpression>,
+    ) -> impl Future<Output = Result<u64, MaintenanceTypeApplicationRepositoryError>> + Send;
+}
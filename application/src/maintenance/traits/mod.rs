@@ -0,0 +1,3 @@
+pub mod maintenance_cost_repository;
+pub mod maintenance_record_repository;
+pub mod maintenance_type_repository;
@@ -0,0 +1,43 @@
+use domain::maintenance::entities::maintenance_interval_template::MaintenanceIntervalTemplate;
+
+pub struct CreateMaintenanceIntervalTemplateCommand {
+    pub maintenance_type_id: i32,
+    /// Restricts the template to a make (`None` means every make).
+    pub make: Option<String>,
+    /// Restricts the template to a model, requires a make.
+    pub model: Option<String>,
+    /// Restricts the template to an engine type (e.g., "diesel").
+    pub engine_type: Option<String>,
+    pub interval_type: String,
+    pub interval_value: u32,
+    pub yellow_threshold: u32,
+    pub red_threshold: u32,
+}
+
+pub struct CreateMaintenanceIntervalTemplateResponse {
+    pub id: i32,
+    pub maintenance_type_id: i32,
+    pub make: Option<String>,
+    pub model: Option<String>,
+    pub engine_type: Option<String>,
+    pub interval_type: String,
+    pub interval_value: u32,
+    pub yellow_threshold: u32,
+    pub red_threshold: u32,
+}
+
+impl From<MaintenanceIntervalTemplate> for CreateMaintenanceIntervalTemplateResponse {
+    fn from(template: MaintenanceIntervalTemplate) -> Self {
+        CreateMaintenanceIntervalTemplateResponse {
+            id: template.id,
+            maintenance_type_id: template.maintenance_type_id,
+            make: template.scope.make,
+            model: template.scope.model,
+            engine_type: template.scope.engine_type.map(|e| e.to_string()),
+            interval_type: template.interval_type.as_str().to_string(),
+            interval_value: template.interval_value,
+            yellow_threshold: template.yellow_threshold,
+            red_threshold: template.red_threshold,
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use domain::{
+    maintenance::{
+        entities::maintenance_interval_template::MaintenanceIntervalTemplateError,
+        repositories::{
+            maintenance_interval_template_repository::MaintenanceIntervalTemplateRepositoryError,
+            maintenance_type_repository::MaintenanceTypeRepositoryError,
+        },
+    },
+    vehicle::value_types::engine_type::EngineTypeError,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum CreateMaintenanceIntervalTemplateError {
+    #[error("Maintenance type not found: {0}")]
+    MaintenanceTypeNotFound(i32),
+    #[error("Maintenance type is deprecated: {0}")]
+    Deprecated(String),
+    #[error("Invalid input data: {0}")]
+    Validation(#[from] MaintenanceIntervalTemplateError),
+    #[error("Invalid engine type: {0}")]
+    InvalidEngineType(#[from] EngineTypeError),
+    #[error("The maintenance type doesn't apply to a {0} vehicle")]
+    NotApplicable(String),
+    #[error("Maintenance type repository error: {0}")]
+    MaintenanceTypeRepository(#[from] MaintenanceTypeRepositoryError),
+    #[error("Template repository error: {0}")]
+    TemplateRepository(#[from] MaintenanceIntervalTemplateRepositoryError),
+}
@@ -0,0 +1,84 @@
+use super::{
+    dto::{
+        CreateMaintenanceIntervalTemplateCommand as Input,
+        CreateMaintenanceIntervalTemplateResponse as Output,
+    },
+    error::CreateMaintenanceIntervalTemplateError as Error,
+};
+use crate::auth::AuthenticatedUser;
+use domain::{
+    maintenance::{
+        entities::maintenance_interval_template::{
+            MaintenanceIntervalTemplate, MaintenanceTemplateScope, NewMaintenanceIntervalTemplate,
+        },
+        repositories::{
+            maintenance_interval_template_repository::MaintenanceIntervalTemplateRepository,
+            maintenance_type_repository::MaintenanceTypeRepository,
+        },
+    },
+    vehicle::value_types::engine_type::EngineType,
+};
+
+pub struct CreateMaintenanceIntervalTemplateUseCase<'a, MTR, TR>
+where
+    MTR: MaintenanceTypeRepository + 'a,
+    TR: MaintenanceIntervalTemplateRepository + 'a,
+{
+    maintenance_type_repository: &'a MTR,
+    template_repository: &'a TR,
+}
+
+impl<'a, MTR, TR> CreateMaintenanceIntervalTemplateUseCase<'a, MTR, TR>
+where
+    MTR: MaintenanceTypeRepository + 'a,
+    TR: MaintenanceIntervalTemplateRepository + 'a,
+{
+    pub fn new(maintenance_type_repository: &'a MTR, template_repository: &'a TR) -> Self {
+        CreateMaintenanceIntervalTemplateUseCase {
+            maintenance_type_repository,
+            template_repository,
+        }
+    }
+
+    pub async fn execute(&self, cmd: Input, user: &AuthenticatedUser) -> Result<Output, Error> {
+        // Check the maintenance type exists
+        let maintenance_type = self
+            .maintenance_type_repository
+            .get_by_id(user.organization_id, cmd.maintenance_type_id)
+            .await?
+            .ok_or(Error::MaintenanceTypeNotFound(cmd.maintenance_type_id))?;
+        if maintenance_type.is_deprecated() {
+            return Err(Error::Deprecated(maintenance_type.name().to_string()));
+        }
+
+        // Check the maintenance type applies to the engine type of the scope
+        let engine_type = cmd.engine_type.map(EngineType::new).transpose()?;
+        if let Some(engine_type) = &engine_type
+            && !maintenance_type.applicability().applies_to(engine_type)
+        {
+            return Err(Error::NotApplicable(engine_type.to_string()));
+        }
+
+        let template = MaintenanceIntervalTemplate::new(
+            maintenance_type.id(),
+            MaintenanceTemplateScope {
+                make: cmd.make.map(|make| make.trim().to_string()),
+                model: cmd.model.map(|model| model.trim().to_string()),
+                engine_type,
+            },
+            NewMaintenanceIntervalTemplate {
+                interval_type: cmd.interval_type,
+                interval_value: cmd.interval_value,
+                yellow_threshold: cmd.yellow_threshold,
+                red_threshold: cmd.red_threshold,
+            },
+        )?;
+
+        let created = self
+            .template_repository
+            .create(user.organization_id, template, user.user_id)
+            .await?;
+
+        Ok(Output::from(created))
+    }
+}
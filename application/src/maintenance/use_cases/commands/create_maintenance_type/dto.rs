@@ -1,8 +1,20 @@
-use domain::maintenance::entities::maintenance_type::MaintenanceTypeView;
+use domain::maintenance::{
+    entities::maintenance_type::MaintenanceTypeView,
+    value_types::{
+        maintenance_applicability::MaintenanceApplicability,
+        maintenance_category::MaintenanceCategory,
+    },
+};
 
 pub struct CreateMaintenanceTypeCommand {
     pub name: String,
     pub description: String,
+    /// The category of the maintenance type, if any.
+    pub category: Option<MaintenanceCategory>,
+    /// The powertrains the maintenance type applies to.
+    pub applicability: MaintenanceApplicability,
+    /// Steps of the standard procedure.
+    pub checklist: Vec<String>,
     pub user_id: uuid::Uuid, // user (caller) info
 }
 
@@ -10,6 +22,9 @@ pub struct CreateMaintenanceTypeResponse {
     pub id: i32,
     pub name: String,
     pub description: String,
+    pub category: Option<MaintenanceCategory>,
+    pub applicability: MaintenanceApplicability,
+    pub checklist: Vec<String>,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub created_by: uuid::Uuid, // user ID
     pub created_by_email: String,
@@ -21,6 +36,9 @@ impl From<MaintenanceTypeView> for CreateMaintenanceTypeResponse {
             id: view.id,
             name: view.name,
             description: view.description,
+            category: view.category,
+            applicability: view.applicability,
+            checklist: view.checklist,
             created_at: view.created_at,
             created_by: view.created_by.id,
             created_by_email: view.created_by.email.into(),
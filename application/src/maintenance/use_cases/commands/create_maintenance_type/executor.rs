@@ -5,7 +5,9 @@ use super::{
 use crate::auth::AuthenticatedUser;
 use domain::maintenance::{
     entities::maintenance_type::MaintenanceType,
-    repositories::maintenance_type_repository::MaintenanceTypeRepository,
+    repositories::maintenance_type_repository::{
+        MaintenanceTypeRepository, MaintenanceTypeRepositoryError,
+    },
 };
 
 pub struct CreateMaintenanceTypeUseCase<'a, MTR: MaintenanceTypeRepository + 'a> {
@@ -21,22 +23,32 @@ impl<'a, MTR: MaintenanceTypeRepository + 'a> CreateMaintenanceTypeUseCase<'a, M
 
     pub async fn execute(&self, cmd: Input, user: &AuthenticatedUser) -> Result<Output, Error> {
         // Create a new MaintenanceType instance
-        let maintenance_type = MaintenanceType::new(cmd.name, cmd.description)?;
+        let mut maintenance_type = MaintenanceType::new(
+            cmd.name,
+            cmd.description,
+            cmd.category,
+            cmd.applicability,
+        )?;
+        maintenance_type.set_checklist(cmd.checklist);
 
         // Check if the maintenance type already exists
         if self
             .maintenance_type_repository
-            .exists_by_name(maintenance_type.name())
+            .exists_by_name(user.organization_id, maintenance_type.name())
             .await?
         {
             return Err(Error::AlreadyExists);
         }
 
-        // Create the maintenance type in the repository
+        // Create the maintenance type in the repository (the name may be taken since the check)
         let created_maintenance_type = self
             .maintenance_type_repository
-            .create(maintenance_type, user.user_id)
-            .await?;
+            .create(user.organization_id, maintenance_type, user.user_id)
+            .await
+            .map_err(|e| match e {
+                MaintenanceTypeRepositoryError::AlreadyExists(_) => Error::AlreadyExists,
+                e => e.into(),
+            })?;
 
         Ok(Output::from(created_maintenance_type))
     }
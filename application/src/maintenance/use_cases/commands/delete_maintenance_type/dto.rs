@@ -1,9 +1,21 @@
+/// What to do with a maintenance type.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DeleteMaintenanceTypeMode {
+    /// Delete the maintenance type, refused while rules or records reference it.
+    #[default]
+    Delete,
+    /// Hide the maintenance type and keep its history (soft delete).
+    Deprecate,
+}
+
 pub struct DeleteMaintenanceTypeCommand {
     pub id: i32,
+    pub mode: DeleteMaintenanceTypeMode,
     pub user_id: uuid::Uuid, // user (caller) info
 }
 
 pub struct DeleteMaintenanceTypeResponse {
     pub success: bool,
+    pub mode: DeleteMaintenanceTypeMode,
     pub message: String,
 }
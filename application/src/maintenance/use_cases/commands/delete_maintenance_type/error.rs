@@ -4,8 +4,13 @@ use domain::maintenance::repositories::maintenance_type_repository::MaintenanceT
 pub enum DeleteMaintenanceTypeError {
     #[error("Maintenance type not found")]
     NotFound,
-    #[error("Cannot delete maintenance type: it is currently in use")]
-    InUse,
+    #[error(
+        "Cannot delete maintenance type: it is used by {rules} rules and {records} records, \
+         deprecate or merge it instead"
+    )]
+    InUse { rules: u64, records: u64 },
+    #[error("Maintenance type is already deprecated")]
+    AlreadyDeprecated,
     #[error("Repository error: {0}")]
     Repository(#[from] MaintenanceTypeRepositoryError),
 }
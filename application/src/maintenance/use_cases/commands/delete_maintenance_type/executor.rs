@@ -1,5 +1,8 @@
 use super::{
-    dto::{DeleteMaintenanceTypeCommand as Input, DeleteMaintenanceTypeResponse as Output},
+    dto::{
+        DeleteMaintenanceTypeCommand as Input, DeleteMaintenanceTypeMode,
+        DeleteMaintenanceTypeResponse as Output,
+    },
     error::DeleteMaintenanceTypeError as Error,
 };
 use crate::auth::AuthenticatedUser;
@@ -20,22 +23,47 @@ impl<'a, MTR: MaintenanceTypeRepository + 'a> DeleteMaintenanceTypeUseCase<'a, M
         // First, check if the maintenance type exists
         let maintenance_type = self
             .maintenance_type_repository
-            .get_by_id(cmd.id)
+            .get_by_id(user.organization_id, cmd.id)
             .await?
             .ok_or(Error::NotFound)?;
 
-        // TODO: Add business logic to check if maintenance type is in use
-        // This would typically involve checking if there are any maintenance records
-        // or maintenance schedules that reference this maintenance type
-        // For now, we'll proceed with deletion
+        if cmd.mode == DeleteMaintenanceTypeMode::Deprecate {
+            if maintenance_type.is_deprecated() {
+                return Err(Error::AlreadyDeprecated);
+            }
+
+            // Hide the maintenance type, its rules and records are kept
+            self.maintenance_type_repository
+                .deprecate(user.organization_id, maintenance_type, user.user_id)
+                .await?;
+
+            return Ok(Output {
+                success: true,
+                mode: cmd.mode,
+                message: "Maintenance type deprecated successfully".to_string(),
+            });
+        }
+
+        // Check the maintenance type is not in use, deleting it would delete the history
+        let usage = self
+            .maintenance_type_repository
+            .usage(user.organization_id, cmd.id)
+            .await?;
+        if usage.is_in_use() {
+            return Err(Error::InUse {
+                rules: usage.rules,
+                records: usage.records,
+            });
+        }
 
         // Delete the maintenance type
         self.maintenance_type_repository
-            .delete(maintenance_type, user.user_id)
+            .delete(user.organization_id, maintenance_type, user.user_id)
             .await?;
 
         Ok(Output {
             success: true,
+            mode: cmd.mode,
             message: "Maintenance type deleted successfully".to_string(),
         })
     }
@@ -0,0 +1,86 @@
+use crate::maintenance::catalog::{catalog_diff::CatalogDiff, catalog_format::CatalogFormat};
+
+/// Version given to the catalog of the system when it is compared with the imported one.
+pub const CURRENT_CATALOG_VERSION: &str = "current";
+
+/// How the import treats the maintenance types that passed validation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CatalogImportMode {
+    /// Validate the catalog and report what would happen, without writing anything.
+    DryRun,
+    /// Validate the catalog, create every new maintenance type that passed validation and add the
+    /// missing intervals of the existing ones.
+    Commit,
+}
+
+pub struct ImportMaintenanceCatalogCommand {
+    pub format: CatalogFormat,
+    pub mode: CatalogImportMode,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CatalogImportOutcome {
+    /// The maintenance type and its intervals were created (commit mode only).
+    Created { id: i32 },
+    /// The maintenance type already existed, the catalog intervals it lacked were added (commit
+    /// mode only).
+    Completed { id: i32, intervals: usize },
+    /// The maintenance type is valid and would be created, or completed with the intervals it
+    /// lacks (dry-run mode only).
+    Valid,
+    /// A maintenance type with this name already exists with every interval of the catalog, it
+    /// is left untouched.
+    Skipped { reason: String },
+    /// The maintenance type failed validation.
+    Error { reason: String },
+}
+
+#[derive(Debug, Clone)]
+pub struct CatalogImportReport {
+    pub name: String,
+    /// Number of default intervals of the maintenance type in the catalog.
+    pub intervals: usize,
+    pub outcome: CatalogImportOutcome,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImportMaintenanceCatalogResponse {
+    pub mode: CatalogImportMode,
+    pub version: String,
+    /// Changes from the catalog of the system to the imported one.
+    pub diff: CatalogDiff,
+    pub maintenance_types: Vec<CatalogImportReport>,
+    pub created: usize,
+    pub completed: usize,
+    pub valid: usize,
+    pub skipped: usize,
+    pub errors: usize,
+}
+
+impl ImportMaintenanceCatalogResponse {
+    pub fn new(mode: CatalogImportMode, version: String, diff: CatalogDiff) -> Self {
+        ImportMaintenanceCatalogResponse {
+            mode,
+            version,
+            diff,
+            maintenance_types: Vec::new(),
+            created: 0,
+            completed: 0,
+            valid: 0,
+            skipped: 0,
+            errors: 0,
+        }
+    }
+
+    /// Appends a maintenance type to the report and updates the counters.
+    pub fn push(&mut self, report: CatalogImportReport) {
+        match report.outcome {
+            CatalogImportOutcome::Created { .. } => self.created += 1,
+            CatalogImportOutcome::Completed { .. } => self.completed += 1,
+            CatalogImportOutcome::Valid => self.valid += 1,
+            CatalogImportOutcome::Skipped { .. } => self.skipped += 1,
+            CatalogImportOutcome::Error { .. } => self.errors += 1,
+        }
+        self.maintenance_types.push(report);
+    }
+}
@@ -0,0 +1,34 @@
+use crate::maintenance::{
+    catalog::catalog_format::CatalogFormatError,
+    use_cases::{
+        commands::{
+            create_maintenance_interval_template::CreateMaintenanceIntervalTemplateError,
+            create_maintenance_type::error::CreateMaintenanceTypeError,
+        },
+        queries::export_maintenance_catalog::ExportMaintenanceCatalogError,
+    },
+};
+use domain::maintenance::repositories::{
+    maintenance_interval_template_repository::MaintenanceIntervalTemplateRepositoryError,
+    maintenance_type_repository::MaintenanceTypeRepositoryError,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ImportMaintenanceCatalogError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Invalid catalog: {0}")]
+    Format(#[from] CatalogFormatError),
+    #[error("The catalog has no version")]
+    MissingVersion,
+    #[error("Maintenance type repository error: {0}")]
+    Repository(#[from] MaintenanceTypeRepositoryError),
+    #[error("Interval template repository error: {0}")]
+    TemplateRepository(#[from] MaintenanceIntervalTemplateRepositoryError),
+    #[error("Could not read the current catalog: {0}")]
+    CurrentCatalog(#[from] ExportMaintenanceCatalogError),
+    #[error("Could not create the maintenance type: {0}")]
+    CreateMaintenanceType(#[from] CreateMaintenanceTypeError),
+    #[error("Could not create the default interval: {0}")]
+    CreateInterval(#[from] CreateMaintenanceIntervalTemplateError),
+}
@@ -0,0 +1,333 @@
+use super::{
+    dto::{
+        CURRENT_CATALOG_VERSION, CatalogImportMode, CatalogImportOutcome, CatalogImportReport,
+        ImportMaintenanceCatalogCommand as Input, ImportMaintenanceCatalogResponse as Output,
+    },
+    error::ImportMaintenanceCatalogError as Error,
+};
+use crate::{
+    auth::AuthenticatedUser,
+    maintenance::{
+        catalog::{
+            catalog_diff::CatalogDiff,
+            catalog_document::{CatalogInterval, CatalogMaintenanceType, same_name},
+        },
+        use_cases::{
+            commands::{
+                create_maintenance_interval_template::{
+                    CreateMaintenanceIntervalTemplateCommand,
+                    CreateMaintenanceIntervalTemplateUseCase,
+                },
+                create_maintenance_type::{
+                    dto::CreateMaintenanceTypeCommand, error::CreateMaintenanceTypeError,
+                    executor::CreateMaintenanceTypeUseCase,
+                },
+            },
+            queries::export_maintenance_catalog::ExportMaintenanceCatalogUseCase,
+        },
+    },
+};
+use domain::{
+    maintenance::{
+        entities::{
+            maintenance_interval_template::{
+                MaintenanceIntervalTemplate, MaintenanceTemplateScope,
+                NewMaintenanceIntervalTemplate,
+            },
+            maintenance_type::{MaintenanceType, MaintenanceTypeView},
+        },
+        repositories::{
+            maintenance_interval_template_repository::MaintenanceIntervalTemplateRepository,
+            maintenance_type_repository::MaintenanceTypeRepository,
+        },
+    },
+    vehicle::value_types::engine_type::EngineType,
+};
+
+/// Loads a catalog of standard maintenance types.
+///
+/// The import is idempotent: maintenance types are matched by name, and the fields of the ones
+/// already in the system are never modified, so the same catalog can be imported again safely.
+/// The catalog intervals an existing type lacks are added to it: a type is created before its
+/// intervals, so importing again completes an import that failed halfway.
+pub struct ImportMaintenanceCatalogUseCase<'a, MTR, TR>
+where
+    MTR: MaintenanceTypeRepository + 'a,
+    TR: MaintenanceIntervalTemplateRepository + 'a,
+{
+    maintenance_type_repository: &'a MTR,
+    template_repository: &'a TR,
+}
+
+impl<'a, MTR, TR> ImportMaintenanceCatalogUseCase<'a, MTR, TR>
+where
+    MTR: MaintenanceTypeRepository + 'a,
+    TR: MaintenanceIntervalTemplateRepository + 'a,
+{
+    pub fn new(maintenance_type_repository: &'a MTR, template_repository: &'a TR) -> Self {
+        ImportMaintenanceCatalogUseCase {
+            maintenance_type_repository,
+            template_repository,
+        }
+    }
+
+    pub async fn execute<R: std::io::Read>(
+        &self,
+        cmd: Input,
+        mut source: R,
+        user: &AuthenticatedUser,
+    ) -> Result<Output, Error> {
+        let mut content = String::new();
+        source.read_to_string(&mut content)?;
+        let catalog = cmd.format.parse(&content)?;
+        if catalog.version.trim().is_empty() {
+            return Err(Error::MissingVersion);
+        }
+
+        // Compare with the catalog of the system
+        let current = ExportMaintenanceCatalogUseCase::new(
+            self.maintenance_type_repository,
+            self.template_repository,
+        )
+        .catalog(user.organization_id, CURRENT_CATALOG_VERSION.to_string())
+        .await?;
+        let diff = CatalogDiff::between(&current, &catalog);
+        let stored = self
+            .maintenance_type_repository
+            .get_all_view(user.organization_id)
+            .await?;
+
+        let mut report = Output::new(cmd.mode, catalog.version.clone(), diff);
+        for (index, maintenance_type) in catalog.maintenance_types.iter().enumerate() {
+            let outcome = self
+                .import_type(
+                    &cmd,
+                    maintenance_type,
+                    &catalog.maintenance_types[..index],
+                    &stored,
+                    user,
+                )
+                .await?;
+            report.push(CatalogImportReport {
+                name: maintenance_type.name.clone(),
+                intervals: maintenance_type.intervals.len(),
+                outcome,
+            });
+        }
+
+        Ok(report)
+    }
+
+    async fn import_type(
+        &self,
+        cmd: &Input,
+        maintenance_type: &CatalogMaintenanceType,
+        previous: &[CatalogMaintenanceType],
+        stored: &[MaintenanceTypeView],
+        user: &AuthenticatedUser,
+    ) -> Result<CatalogImportOutcome, Error> {
+        // Check the name is not repeated in the catalog
+        if previous
+            .iter()
+            .any(|other| same_name(&other.name, &maintenance_type.name))
+        {
+            return Ok(CatalogImportOutcome::Error {
+                reason: "The name appears more than once in the catalog".to_string(),
+            });
+        }
+
+        // Check the whole type, intervals included, before writing anything
+        let validated = match validate_type(maintenance_type) {
+            Ok(validated) => validated,
+            Err(reason) => return Ok(CatalogImportOutcome::Error { reason }),
+        };
+
+        // Check if the maintenance type already exists
+        if let Some(existing) = stored.iter().find(|view| view.name == validated.name()) {
+            return self
+                .complete_type(cmd, existing.id, maintenance_type, user)
+                .await;
+        }
+
+        if cmd.mode == CatalogImportMode::DryRun {
+            return Ok(CatalogImportOutcome::Valid);
+        }
+
+        let created = match CreateMaintenanceTypeUseCase::new(self.maintenance_type_repository)
+            .execute(
+                CreateMaintenanceTypeCommand {
+                    name: validated.name().to_string(),
+                    description: validated.description().to_string(),
+                    category: validated.category(),
+                    applicability: validated.applicability(),
+                    checklist: maintenance_type.checklist.clone(),
+                    user_id: user.user_id,
+                },
+                user,
+            )
+            .await
+        {
+            Ok(created) => created,
+            // Created concurrently since the check above
+            Err(CreateMaintenanceTypeError::AlreadyExists) => return Ok(already_exists()),
+            Err(e) => return Err(e.into()),
+        };
+
+        self.create_intervals(created.id, &maintenance_type.intervals, user)
+            .await?;
+
+        Ok(CatalogImportOutcome::Created { id: created.id })
+    }
+
+    /// Adds the catalog intervals an existing maintenance type lacks, its other intervals and
+    /// fields are left untouched.
+    async fn complete_type(
+        &self,
+        cmd: &Input,
+        id: i32,
+        maintenance_type: &CatalogMaintenanceType,
+        user: &AuthenticatedUser,
+    ) -> Result<CatalogImportOutcome, Error> {
+        let Some(existing) = self
+            .maintenance_type_repository
+            .get_by_id(user.organization_id, id)
+            .await?
+        else {
+            return Ok(already_exists());
+        };
+        if existing.is_deprecated() {
+            return Ok(already_exists());
+        }
+        let templates = self
+            .template_repository
+            .find_by_maintenance_type(user.organization_id, id)
+            .await?;
+
+        // The intervals are checked against the stored type, its applicability may differ
+        let mut missing = Vec::new();
+        for interval in &maintenance_type.intervals {
+            let template = match validate_interval(&existing, interval) {
+                Ok(template) => template,
+                Err(reason) => {
+                    return Ok(CatalogImportOutcome::Error {
+                        reason: format!("Interval {}: {}", interval.interval_type, reason),
+                    });
+                }
+            };
+            if !templates.iter().any(|stored| {
+                stored.interval_type == template.interval_type
+                    && stored.scope.same_as(&template.scope)
+            }) {
+                missing.push(interval.clone());
+            }
+        }
+
+        match (missing.is_empty(), cmd.mode) {
+            (true, _) => Ok(already_exists()),
+            (false, CatalogImportMode::DryRun) => Ok(CatalogImportOutcome::Valid),
+            (false, CatalogImportMode::Commit) => {
+                self.create_intervals(id, &missing, user).await?;
+                Ok(CatalogImportOutcome::Completed {
+                    id,
+                    intervals: missing.len(),
+                })
+            }
+        }
+    }
+
+    async fn create_intervals(
+        &self,
+        maintenance_type_id: i32,
+        intervals: &[CatalogInterval],
+        user: &AuthenticatedUser,
+    ) -> Result<(), Error> {
+        let create_interval = CreateMaintenanceIntervalTemplateUseCase::new(
+            self.maintenance_type_repository,
+            self.template_repository,
+        );
+        for interval in intervals {
+            create_interval
+                .execute(
+                    CreateMaintenanceIntervalTemplateCommand {
+                        maintenance_type_id,
+                        make: interval.make.clone(),
+                        model: interval.model.clone(),
+                        engine_type: interval.engine_type.clone(),
+                        interval_type: interval.interval_type.clone(),
+                        interval_value: interval.interval_value,
+                        yellow_threshold: interval.yellow_threshold,
+                        red_threshold: interval.red_threshold,
+                    },
+                    user,
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+fn already_exists() -> CatalogImportOutcome {
+    CatalogImportOutcome::Skipped {
+        reason: "A maintenance type with this name already exists".to_string(),
+    }
+}
+
+/// Validates a catalog maintenance type with the domain rules, without writing anything.
+fn validate_type(maintenance_type: &CatalogMaintenanceType) -> Result<MaintenanceType, String> {
+    let category = maintenance_type
+        .category
+        .as_deref()
+        .map(str::parse)
+        .transpose()?;
+    let applicability = maintenance_type.applicability.parse()?;
+    let domain_type = MaintenanceType::new(
+        maintenance_type.name.trim().to_string(),
+        maintenance_type.description.trim().to_string(),
+        category,
+        applicability,
+    )
+    .map_err(|e| e.to_string())?;
+
+    for interval in &maintenance_type.intervals {
+        validate_interval(&domain_type, interval)
+            .map_err(|reason| format!("Interval {}: {}", interval.interval_type, reason))?;
+    }
+    Ok(domain_type)
+}
+
+fn validate_interval(
+    maintenance_type: &MaintenanceType,
+    interval: &CatalogInterval,
+) -> Result<MaintenanceIntervalTemplate, String> {
+    let engine_type = interval
+        .engine_type
+        .clone()
+        .map(EngineType::new)
+        .transpose()
+        .map_err(|e| e.to_string())?;
+    if let Some(engine_type) = &engine_type
+        && !maintenance_type.applicability().applies_to(engine_type)
+    {
+        return Err(format!(
+            "{} doesn't apply to a {} vehicle",
+            maintenance_type.name(),
+            engine_type
+        ));
+    }
+
+    MaintenanceIntervalTemplate::new(
+        maintenance_type.id(),
+        MaintenanceTemplateScope {
+            make: interval.make.clone(),
+            model: interval.model.clone(),
+            engine_type,
+        },
+        NewMaintenanceIntervalTemplate {
+            interval_type: interval.interval_type.clone(),
+            interval_value: interval.interval_value,
+            yellow_threshold: interval.yellow_threshold,
+            red_threshold: interval.red_threshold,
+        },
+    )
+    .map_err(|e| e.to_string())
+}
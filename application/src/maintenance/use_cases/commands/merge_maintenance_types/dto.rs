@@ -0,0 +1,30 @@
+use domain::maintenance::entities::maintenance_type::MaintenanceTypeMerge;
+
+pub struct MergeMaintenanceTypesCommand {
+    /// The maintenance type merged and deleted.
+    pub source_id: i32,
+    /// The maintenance type that receives the rules, records and default intervals.
+    pub target_id: i32,
+}
+
+pub struct MergeMaintenanceTypesResponse {
+    pub source_id: i32,
+    pub target_id: i32,
+    pub rules_moved: u64,
+    pub rules_merged: u64,
+    pub records_moved: u64,
+    pub templates_moved: u64,
+}
+
+impl MergeMaintenanceTypesResponse {
+    pub fn new(source_id: i32, target_id: i32, merge: MaintenanceTypeMerge) -> Self {
+        MergeMaintenanceTypesResponse {
+            source_id,
+            target_id,
+            rules_moved: merge.rules_moved,
+            rules_merged: merge.rules_merged,
+            records_moved: merge.records_moved,
+            templates_moved: merge.templates_moved,
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use domain::maintenance::repositories::{
+    maintenance_repository::MaintenanceRepositoryError,
+    maintenance_type_repository::MaintenanceTypeRepositoryError,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum MergeMaintenanceTypesError {
+    #[error("Maintenance type not found: {0}")]
+    NotFound(i32),
+    #[error("A maintenance type can't be merged into itself")]
+    SameType,
+    #[error("Cannot merge into a deprecated maintenance type")]
+    TargetDeprecated,
+    #[error("The target maintenance type doesn't apply to {vehicles} vehicles of the source rules")]
+    NotApplicable { vehicles: usize },
+    #[error("Maintenance type repository error: {0}")]
+    MaintenanceTypeRepository(#[from] MaintenanceTypeRepositoryError),
+    #[error("Maintenance repository error: {0}")]
+    MaintenanceRepository(#[from] MaintenanceRepositoryError),
+}
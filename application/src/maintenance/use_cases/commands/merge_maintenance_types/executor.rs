@@ -0,0 +1,79 @@
+use super::{
+    dto::{MergeMaintenanceTypesCommand as Input, MergeMaintenanceTypesResponse as Output},
+    error::MergeMaintenanceTypesError as Error,
+};
+use crate::auth::AuthenticatedUser;
+use domain::maintenance::repositories::{
+    maintenance_repository::MaintenanceRepository,
+    maintenance_type_repository::MaintenanceTypeRepository,
+};
+
+/// Merges a maintenance type into another one (e.g., duplicates created under two names).
+///
+/// Every rule, record and default interval of the source type is moved to the target type, then
+/// the source type is deleted; no history is lost.
+pub struct MergeMaintenanceTypesUseCase<'a, MTR, MR>
+where
+    MTR: MaintenanceTypeRepository + 'a,
+    MR: MaintenanceRepository + 'a,
+{
+    maintenance_type_repository: &'a MTR,
+    maintenance_repository: &'a MR,
+}
+
+impl<'a, MTR, MR> MergeMaintenanceTypesUseCase<'a, MTR, MR>
+where
+    MTR: MaintenanceTypeRepository + 'a,
+    MR: MaintenanceRepository + 'a,
+{
+    pub fn new(maintenance_type_repository: &'a MTR, maintenance_repository: &'a MR) -> Self {
+        MergeMaintenanceTypesUseCase {
+            maintenance_type_repository,
+            maintenance_repository,
+        }
+    }
+
+    pub async fn execute(&self, cmd: Input, user: &AuthenticatedUser) -> Result<Output, Error> {
+        if cmd.source_id == cmd.target_id {
+            return Err(Error::SameType);
+        }
+
+        // Check both maintenance types exist
+        let source = self
+            .maintenance_type_repository
+            .get_by_id(user.organization_id, cmd.source_id)
+            .await?
+            .ok_or(Error::NotFound(cmd.source_id))?;
+        let target = self
+            .maintenance_type_repository
+            .get_by_id(user.organization_id, cmd.target_id)
+            .await?
+            .ok_or(Error::NotFound(cmd.target_id))?;
+        if target.is_deprecated() {
+            return Err(Error::TargetDeprecated);
+        }
+
+        // Check the target type applies to every vehicle the source type is used on
+        let vehicles = self
+            .maintenance_repository
+            .find_by_maintenance_type(user.organization_id, source.id())
+            .await?
+            .iter()
+            .filter(|rule| {
+                !target
+                    .applicability()
+                    .applies_to(rule.vehicle.powertrain.engine_type())
+            })
+            .count();
+        if vehicles > 0 {
+            return Err(Error::NotApplicable { vehicles });
+        }
+
+        let merge = self
+            .maintenance_type_repository
+            .merge(user.organization_id, source, target, user.user_id)
+            .await?;
+
+        Ok(Output::new(cmd.source_id, cmd.target_id, merge))
+    }
+}
@@ -1,3 +1,8 @@
 pub mod create_maintenance_type;
 pub mod update_maintenance_type;
-pub mod delete_maintenance_type;
\ No newline at end of file
+pub mod delete_maintenance_type;
+pub mod create_maintenance_interval_template;
+pub mod import_maintenance_catalog;
+pub mod merge_maintenance_types;
+pub mod provision_vehicle_maintenances;
+pub mod record_maintenance;
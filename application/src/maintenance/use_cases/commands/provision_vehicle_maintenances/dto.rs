@@ -0,0 +1,27 @@
+pub struct ProvisionVehicleMaintenancesCommand {
+    pub vehicle_id: uuid::Uuid,
+}
+
+pub struct ProvisionedMaintenance {
+    pub maintenance_id: i32,
+    pub maintenance_type_id: i32,
+    pub maintenance_type: String,
+    pub interval_type: String,
+    pub interval_value: u32,
+    pub yellow_threshold: u32,
+    pub red_threshold: u32,
+    /// Id of the catalog template the rule was created from.
+    pub template_id: i32,
+}
+
+pub struct SkippedMaintenance {
+    pub maintenance_type_id: i32,
+    pub interval_type: String,
+    pub reason: String,
+}
+
+pub struct ProvisionVehicleMaintenancesResponse {
+    pub vehicle_id: uuid::Uuid,
+    pub created: Vec<ProvisionedMaintenance>,
+    pub skipped: Vec<SkippedMaintenance>,
+}
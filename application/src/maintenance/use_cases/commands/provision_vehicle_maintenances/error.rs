@@ -0,0 +1,25 @@
+use crate::vehicle::traits::vehicle_repository::VehicleApplicationRepositoryError;
+use domain::{
+    maintenance::repositories::{
+        maintenance_interval_template_repository::MaintenanceIntervalTemplateRepositoryError,
+        maintenance_repository::MaintenanceRepositoryError,
+        maintenance_type_repository::MaintenanceTypeRepositoryError,
+    },
+    vehicle::repositories::vehicle_repository::VehicleRepositoryError,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ProvisionVehicleMaintenancesError {
+    #[error("Vehicle not found: {0}")]
+    VehicleNotFound(uuid::Uuid),
+    #[error("Vehicle repository error: {0}")]
+    VehicleRepository(#[from] VehicleRepositoryError),
+    #[error("Vehicle repository error: {0}")]
+    VehicleApplicationRepository(#[from] VehicleApplicationRepositoryError),
+    #[error("Maintenance type repository error: {0}")]
+    MaintenanceTypeRepository(#[from] MaintenanceTypeRepositoryError),
+    #[error("Maintenance repository error: {0}")]
+    MaintenanceRepository(#[from] MaintenanceRepositoryError),
+    #[error("Maintenance interval template repository error: {0}")]
+    TemplateRepository(#[from] MaintenanceIntervalTemplateRepositoryError),
+}
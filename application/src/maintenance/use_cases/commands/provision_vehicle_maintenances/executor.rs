@@ -0,0 +1,159 @@
+use super::{
+    dto::{
+        ProvisionVehicleMaintenancesCommand as Input,
+        ProvisionVehicleMaintenancesResponse as Output, ProvisionedMaintenance, SkippedMaintenance,
+    },
+    error::ProvisionVehicleMaintenancesError as Error,
+};
+use crate::{
+    auth::AuthenticatedUser,
+    vehicle::{
+        scope::ensure_vehicle_in_scope, traits::vehicle_repository::VehicleApplicationRepository,
+    },
+};
+use domain::{
+    maintenance::{
+        entities::maintenance::{Maintenance, NewMaintenance},
+        repositories::{
+            maintenance_interval_template_repository::MaintenanceIntervalTemplateRepository,
+            maintenance_repository::MaintenanceRepository,
+            maintenance_type_repository::MaintenanceTypeRepository,
+        },
+        services::maintenance_catalog_service::select_templates,
+    },
+    vehicle::repositories::vehicle_repository::VehicleRepository,
+};
+
+/// Creates the maintenance rules of a vehicle from the default intervals of the catalog (UC-040).
+///
+/// Rules that already exist for the same maintenance type and interval type are kept untouched, so
+/// the use case can be run again after the catalog has grown.
+pub struct ProvisionVehicleMaintenancesUseCase<'a, VR, MTR, MR, TR>
+where
+    VR: VehicleRepository + VehicleApplicationRepository + 'a,
+    MTR: MaintenanceTypeRepository + 'a,
+    MR: MaintenanceRepository + 'a,
+    TR: MaintenanceIntervalTemplateRepository + 'a,
+{
+    vehicle_repository: &'a VR,
+    maintenance_type_repository: &'a MTR,
+    maintenance_repository: &'a MR,
+    template_repository: &'a TR,
+}
+
+impl<'a, VR, MTR, MR, TR> ProvisionVehicleMaintenancesUseCase<'a, VR, MTR, MR, TR>
+where
+    VR: VehicleRepository + VehicleApplicationRepository + 'a,
+    MTR: MaintenanceTypeRepository + 'a,
+    MR: MaintenanceRepository + 'a,
+    TR: MaintenanceIntervalTemplateRepository + 'a,
+{
+    pub fn new(
+        vehicle_repository: &'a VR,
+        maintenance_type_repository: &'a MTR,
+        maintenance_repository: &'a MR,
+        template_repository: &'a TR,
+    ) -> Self {
+        ProvisionVehicleMaintenancesUseCase {
+            vehicle_repository,
+            maintenance_type_repository,
+            maintenance_repository,
+            template_repository,
+        }
+    }
+
+    pub async fn execute(&self, cmd: Input, user: &AuthenticatedUser) -> Result<Output, Error> {
+        // Check the vehicle exists and is in the scope of the user
+        ensure_vehicle_in_scope(
+            self.vehicle_repository,
+            user,
+            cmd.vehicle_id,
+            Error::VehicleNotFound(cmd.vehicle_id),
+        )
+        .await?;
+        let vehicle = self
+            .vehicle_repository
+            .find_by_id(user.organization_id, cmd.vehicle_id)
+            .await?
+            .ok_or(Error::VehicleNotFound(cmd.vehicle_id))?;
+
+        let templates = self
+            .template_repository
+            .find_all(user.organization_id)
+            .await?;
+        let existing = self
+            .maintenance_repository
+            .find_by_vehicle(user.organization_id, vehicle.id)
+            .await?;
+
+        let mut created = Vec::new();
+        let mut skipped = Vec::new();
+        for template in select_templates(&templates, &vehicle) {
+            let skip = |reason: String| SkippedMaintenance {
+                maintenance_type_id: template.maintenance_type_id,
+                interval_type: template.interval_type.as_str().to_string(),
+                reason,
+            };
+
+            // Check the vehicle doesn't already have the rule
+            if existing.iter().any(|rule| {
+                rule.identity.maintenance_type_id == template.maintenance_type_id
+                    && rule.identity.interval_type == template.interval_type
+            }) {
+                skipped.push(skip(
+                    "A rule with this interval type already exists".to_string(),
+                ));
+                continue;
+            }
+
+            let Some(maintenance_type) = self
+                .maintenance_type_repository
+                .get_by_id(user.organization_id, template.maintenance_type_id)
+                .await?
+            else {
+                skipped.push(skip("The maintenance type no longer exists".to_string()));
+                continue;
+            };
+
+            // The entity checks the maintenance type applies to the powertrain
+            let maintenance = match Maintenance::new(
+                maintenance_type,
+                vehicle.clone(),
+                user.user_id,
+                NewMaintenance {
+                    interval_type: template.interval_type.as_str().to_string(),
+                    interval_value: template.interval_value,
+                    red_threshold: template.red_threshold,
+                    yellow_threshold: template.yellow_threshold,
+                },
+            ) {
+                Ok(maintenance) => maintenance,
+                Err(e) => {
+                    skipped.push(skip(e.to_string()));
+                    continue;
+                }
+            };
+
+            let maintenance = self
+                .maintenance_repository
+                .create(user.organization_id, maintenance)
+                .await?;
+            created.push(ProvisionedMaintenance {
+                maintenance_id: maintenance.identity.id,
+                maintenance_type_id: maintenance.identity.maintenance_type_id,
+                maintenance_type: maintenance.maintenance_type.name().to_string(),
+                interval_type: maintenance.identity.interval_type.as_str().to_string(),
+                interval_value: maintenance.identity.interval_value,
+                yellow_threshold: maintenance.identity.yellow_threshold,
+                red_threshold: maintenance.identity.red_threshold,
+                template_id: template.id,
+            });
+        }
+
+        Ok(Output {
+            vehicle_id: vehicle.id,
+            created,
+            skipped,
+        })
+    }
+}
@@ -0,0 +1,41 @@
+use domain::maintenance::entities::{
+    maintenance_cost::MaintenanceCost, maintenance_record::MaintenanceRecordIdentity,
+};
+
+/// The maintenance is recorded with the status of the vehicle it was performed at, which becomes
+/// the latest status of the vehicle.
+pub struct RecordMaintenanceCommand {
+    pub vehicle_id: uuid::Uuid,
+    pub maintenance_type_id: i32,
+    pub performed_at: chrono::DateTime<chrono::Utc>,
+    pub odometer: i32,
+    pub engine_hour_meter: Option<i32>,
+    pub fuel_level: Option<i32>,
+    /// Notes of the vehicle status.
+    pub notes: String,
+    /// Details of the maintenance.
+    pub details: String,
+    pub cost: Option<MaintenanceCost>,
+}
+
+pub struct RecordMaintenanceResponse {
+    pub id: uuid::Uuid,
+    pub maintenance_id: i32,
+    pub vehicle_status_id: i32,
+    pub performed_at: chrono::DateTime<chrono::Utc>,
+    pub details: String,
+    pub cost: Option<MaintenanceCost>,
+}
+
+impl From<MaintenanceRecordIdentity> for RecordMaintenanceResponse {
+    fn from(record: MaintenanceRecordIdentity) -> Self {
+        RecordMaintenanceResponse {
+            id: record.id,
+            maintenance_id: record.maintenance_id,
+            vehicle_status_id: record.vehicle_status_id,
+            performed_at: record.performed_at,
+            details: record.details,
+            cost: record.cost,
+        }
+    }
+}
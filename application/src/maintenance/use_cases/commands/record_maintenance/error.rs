@@ -0,0 +1,32 @@
+use crate::{
+    shared::traits::unit_of_work::UnitOfWorkError,
+    vehicle::traits::vehicle_repository::VehicleApplicationRepositoryError,
+};
+use domain::{
+    maintenance::repositories::{
+        maintenance_record_repository::MaintenanceRecordRepositoryError,
+        maintenance_repository::MaintenanceRepositoryError,
+    },
+    vehicle::repositories::vehicle_status_repository::VehicleStatusRepositoryError,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum RecordMaintenanceError {
+    #[error("Vehicle not found: {0}")]
+    VehicleNotFound(uuid::Uuid),
+    #[error("Maintenance of type {maintenance_type_id} not found for vehicle {vehicle_id}")]
+    MaintenanceNotFound {
+        vehicle_id: uuid::Uuid,
+        maintenance_type_id: i32,
+    },
+    #[error("Vehicle repository error: {0}")]
+    VehicleRepository(#[from] VehicleApplicationRepositoryError),
+    #[error("Maintenance repository error: {0}")]
+    MaintenanceRepository(#[from] MaintenanceRepositoryError),
+    #[error("Vehicle status repository error: {0}")]
+    VehicleStatusRepository(#[from] VehicleStatusRepositoryError),
+    #[error("Maintenance record repository error: {0}")]
+    MaintenanceRecordRepository(#[from] MaintenanceRecordRepositoryError),
+    #[error("Transaction error: {0}")]
+    UnitOfWork(#[from] UnitOfWorkError),
+}
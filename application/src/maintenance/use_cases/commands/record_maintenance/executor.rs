@@ -0,0 +1,121 @@
+use super::{
+    dto::{RecordMaintenanceCommand as Input, RecordMaintenanceResponse as Output},
+    error::RecordMaintenanceError as Error,
+};
+use crate::{
+    auth::AuthenticatedUser,
+    shared::traits::unit_of_work::{Transaction, UnitOfWork},
+    vehicle::{
+        scope::ensure_vehicle_in_scope, traits::vehicle_repository::VehicleApplicationRepository,
+    },
+};
+use domain::{
+    maintenance::{
+        entities::maintenance_record::MaintenanceRecordIdentity,
+        repositories::{
+            maintenance_record_repository::MaintenanceRecordRepository,
+            maintenance_repository::MaintenanceRepository,
+        },
+    },
+    vehicle::{
+        entities::vehicle_status::VehicleStatusIdentity,
+        repositories::vehicle_status_repository::VehicleStatusRepository,
+    },
+};
+
+pub struct RecordMaintenanceUseCase<'a, VAR, MR, UOW>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    MR: MaintenanceRepository + 'a,
+    UOW: UnitOfWork + 'a,
+{
+    vehicle_repository: &'a VAR,
+    maintenance_repository: &'a MR,
+    unit_of_work: &'a UOW,
+}
+
+impl<'a, VAR, MR, UOW> RecordMaintenanceUseCase<'a, VAR, MR, UOW>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    MR: MaintenanceRepository + 'a,
+    UOW: UnitOfWork + 'a,
+{
+    pub fn new(
+        vehicle_repository: &'a VAR,
+        maintenance_repository: &'a MR,
+        unit_of_work: &'a UOW,
+    ) -> Self {
+        RecordMaintenanceUseCase {
+            vehicle_repository,
+            maintenance_repository,
+            unit_of_work,
+        }
+    }
+
+    pub async fn execute(&self, cmd: Input, user: &AuthenticatedUser) -> Result<Output, Error> {
+        ensure_vehicle_in_scope(
+            self.vehicle_repository,
+            user,
+            cmd.vehicle_id,
+            Error::VehicleNotFound(cmd.vehicle_id),
+        )
+        .await?;
+
+        // Find the maintenance rule of the vehicle for the type
+        let maintenance = self
+            .maintenance_repository
+            .find_by_vehicle(user.organization_id, cmd.vehicle_id)
+            .await?
+            .into_iter()
+            .find(|maintenance| maintenance.maintenance_type.id() == cmd.maintenance_type_id)
+            .ok_or(Error::MaintenanceNotFound {
+                vehicle_id: cmd.vehicle_id,
+                maintenance_type_id: cmd.maintenance_type_id,
+            })?;
+
+        // The status and the record are written together, a failure leaves neither of them
+        let now = chrono::Utc::now();
+        let transaction = self.unit_of_work.begin(user.organization_id).await?;
+        let status = transaction
+            .vehicle_statuses()
+            .create(
+                user.organization_id,
+                VehicleStatusIdentity {
+                    id: 0,
+                    vehicle_id: cmd.vehicle_id,
+                    performed_by: user.user_id,
+                    performed_at: cmd.performed_at,
+                    odometer: cmd.odometer,
+                    engine_hour_meter: cmd.engine_hour_meter,
+                    fuel_level: cmd.fuel_level,
+                    notes: cmd.notes,
+                    created_at: now,
+                    updated_at: now,
+                },
+            )
+            .await?;
+        let record = transaction
+            .maintenance_records()
+            .create(
+                user.organization_id,
+                MaintenanceRecordIdentity {
+                    id: uuid::Uuid::new_v4(),
+                    vehicle_id: cmd.vehicle_id,
+                    maintenance_id: maintenance.identity.id,
+                    user_id: user.user_id,
+                    vehicle_status_id: status.id,
+                    performed_at: cmd.performed_at,
+                    details: cmd.details,
+                    cost: cmd.cost,
+                    created_at: now,
+                    created_by: user.user_id,
+                    updated_at: now,
+                    updated_by: user.user_id,
+                },
+            )
+            .await?;
+        transaction.commit().await?;
+
+        Ok(Output::from(record))
+    }
+}
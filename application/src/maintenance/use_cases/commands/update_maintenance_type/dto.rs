@@ -1,9 +1,21 @@
-use domain::maintenance::entities::maintenance_type::MaintenanceTypeView;
+use domain::maintenance::{
+    entities::maintenance_type::MaintenanceTypeView,
+    value_types::{
+        maintenance_applicability::MaintenanceApplicability,
+        maintenance_category::MaintenanceCategory,
+    },
+};
 
 pub struct UpdateMaintenanceTypeCommand {
     pub id: i32,
     pub name: String,
     pub description: String,
+    /// The category of the maintenance type, if any.
+    pub category: Option<MaintenanceCategory>,
+    /// The powertrains the maintenance type applies to.
+    pub applicability: MaintenanceApplicability,
+    /// Steps of the standard procedure.
+    pub checklist: Vec<String>,
     pub user_id: uuid::Uuid, // user (caller) info
 }
 
@@ -11,6 +23,9 @@ pub struct UpdateMaintenanceTypeResponse {
     pub id: i32,
     pub name: String,
     pub description: String,
+    pub category: Option<MaintenanceCategory>,
+    pub applicability: MaintenanceApplicability,
+    pub checklist: Vec<String>,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub created_by: uuid::Uuid,
     pub created_by_email: String,
@@ -25,6 +40,9 @@ impl From<MaintenanceTypeView> for UpdateMaintenanceTypeResponse {
             id: view.id,
             name: view.name,
             description: view.description,
+            category: view.category,
+            applicability: view.applicability,
+            checklist: view.checklist,
             created_at: view.created_at,
             created_by: view.created_by.id,
             created_by_email: view.created_by.email.into(),
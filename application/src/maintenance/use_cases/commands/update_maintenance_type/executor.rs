@@ -5,7 +5,9 @@ use super::{
 use crate::auth::AuthenticatedUser;
 use domain::maintenance::{
     entities::maintenance_type::MaintenanceType,
-    repositories::maintenance_type_repository::MaintenanceTypeRepository,
+    repositories::maintenance_type_repository::{
+        MaintenanceTypeRepository, MaintenanceTypeRepositoryError,
+    },
 };
 
 pub struct UpdateMaintenanceTypeUseCase<'a, MTR: MaintenanceTypeRepository + 'a> {
@@ -23,29 +25,40 @@ impl<'a, MTR: MaintenanceTypeRepository + 'a> UpdateMaintenanceTypeUseCase<'a, M
         // First, check if the maintenance type exists
         let existing_maintenance_type = self
             .maintenance_type_repository
-            .get_by_id(cmd.id)
+            .get_by_id(user.organization_id, cmd.id)
             .await?
             .ok_or(Error::NotFound)?;
 
         // Check if the new name already exists (only if name is changing)
-        if existing_maintenance_type.name() != cmd.name {
-            if self
+        if existing_maintenance_type.name() != cmd.name
+            && self
                 .maintenance_type_repository
-                .exists_by_name(&cmd.name)
+                .exists_by_name(user.organization_id, &cmd.name)
                 .await?
-            {
-                return Err(Error::NameAlreadyExists);
-            }
+        {
+            return Err(Error::NameAlreadyExists);
         }
 
         // Create a new MaintenanceType instance with updated data
-        let mut updated_maintenance_type = MaintenanceType::new(cmd.name, cmd.description)?;
+        let mut updated_maintenance_type = MaintenanceType::new(
+            cmd.name,
+            cmd.description,
+            cmd.category,
+            cmd.applicability,
+        )?;
+        updated_maintenance_type.set_checklist(cmd.checklist);
+        updated_maintenance_type.set_id(existing_maintenance_type.id());
+        updated_maintenance_type.set_deprecated_at(existing_maintenance_type.deprecated_at());
 
-        // Update the maintenance type in the repository
+        // Update the maintenance type in the repository (the name may be taken since the check)
         let updated_maintenance_type_view = self
             .maintenance_type_repository
-            .update(updated_maintenance_type, user.user_id)
-            .await?;
+            .update(user.organization_id, updated_maintenance_type, user.user_id)
+            .await
+            .map_err(|e| match e {
+                MaintenanceTypeRepositoryError::AlreadyExists(_) => Error::NameAlreadyExists,
+                e => e.into(),
+            })?;
 
         Ok(Output::from(updated_maintenance_type_view))
     }
@@ -0,0 +1,16 @@
+use crate::maintenance::catalog::{
+    catalog_document::MaintenanceCatalog, catalog_format::CatalogFormat,
+};
+
+pub struct ExportMaintenanceCatalogQuery {
+    pub format: CatalogFormat,
+    /// Version written in the document (e.g., "2025.08").
+    pub version: String,
+}
+
+pub struct ExportMaintenanceCatalogResponse {
+    pub format: CatalogFormat,
+    /// The catalog document, in the requested format.
+    pub content: String,
+    pub catalog: MaintenanceCatalog,
+}
@@ -0,0 +1,15 @@
+use crate::maintenance::catalog::catalog_format::CatalogFormatError;
+use domain::maintenance::repositories::{
+    maintenance_interval_template_repository::MaintenanceIntervalTemplateRepositoryError,
+    maintenance_type_repository::MaintenanceTypeRepositoryError,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ExportMaintenanceCatalogError {
+    #[error("Maintenance type repository error: {0}")]
+    MaintenanceTypeRepository(#[from] MaintenanceTypeRepositoryError),
+    #[error("Template repository error: {0}")]
+    TemplateRepository(#[from] MaintenanceIntervalTemplateRepositoryError),
+    #[error("Catalog format error: {0}")]
+    Format(#[from] CatalogFormatError),
+}
@@ -0,0 +1,57 @@
+use super::{
+    dto::{ExportMaintenanceCatalogQuery as Input, ExportMaintenanceCatalogResponse as Output},
+    error::ExportMaintenanceCatalogError as Error,
+};
+use crate::{auth::AuthenticatedUser, maintenance::catalog::catalog_document::MaintenanceCatalog};
+use domain::maintenance::repositories::{
+    maintenance_interval_template_repository::MaintenanceIntervalTemplateRepository,
+    maintenance_type_repository::MaintenanceTypeRepository,
+};
+
+pub struct ExportMaintenanceCatalogUseCase<'a, MTR, TR>
+where
+    MTR: MaintenanceTypeRepository + 'a,
+    TR: MaintenanceIntervalTemplateRepository + 'a,
+{
+    maintenance_type_repository: &'a MTR,
+    template_repository: &'a TR,
+}
+
+impl<'a, MTR, TR> ExportMaintenanceCatalogUseCase<'a, MTR, TR>
+where
+    MTR: MaintenanceTypeRepository + 'a,
+    TR: MaintenanceIntervalTemplateRepository + 'a,
+{
+    pub fn new(maintenance_type_repository: &'a MTR, template_repository: &'a TR) -> Self {
+        ExportMaintenanceCatalogUseCase {
+            maintenance_type_repository,
+            template_repository,
+        }
+    }
+
+    pub async fn execute(&self, query: Input, user: &AuthenticatedUser) -> Result<Output, Error> {
+        let catalog = self.catalog(user.organization_id, query.version).await?;
+        let content = query.format.render(&catalog)?;
+
+        Ok(Output {
+            format: query.format,
+            content,
+            catalog,
+        })
+    }
+
+    /// Returns the catalog of the maintenance types stored in the organization.
+    pub async fn catalog(
+        &self,
+        organization_id: uuid::Uuid,
+        version: String,
+    ) -> Result<MaintenanceCatalog, Error> {
+        let views = self
+            .maintenance_type_repository
+            .get_all_view(organization_id)
+            .await?;
+        let templates = self.template_repository.find_all(organization_id).await?;
+
+        Ok(MaintenanceCatalog::from_views(version, views, templates))
+    }
+}
@@ -1,19 +1,34 @@
-use domain::maintenance::entities::maintenance_type::MaintenanceTypeView;
+use crate::shared::{
+    filter_expression::filter_ast::FilterExpression,
+    pagination::{Page, PageRequest},
+};
+use domain::maintenance::{
+    entities::maintenance_type::MaintenanceTypeView,
+    value_types::{
+        maintenance_applicability::MaintenanceApplicability,
+        maintenance_category::MaintenanceCategory,
+    },
+};
 
-pub struct GetAllMaintenanceTypesQuery;
+/// Maintenance types are listed by name.
+pub struct GetAllMaintenanceTypesQuery {
+    /// Filter expression over `MAINTENANCE_TYPE_FILTER_SCHEMA`.
+    pub filter: Option<FilterExpression>,
+    pub page: PageRequest,
+}
 
 pub struct MaintenanceTypeSummary {
     pub id: i32,
     pub name: String,
     pub description: String,
+    pub category: Option<MaintenanceCategory>,
+    pub applicability: MaintenanceApplicability,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
 }
 
-pub struct GetAllMaintenanceTypesResponse {
-    pub maintenance_types: Vec<MaintenanceTypeSummary>,
-    pub total_count: usize,
-}
+/// A page of maintenance types, with the cursors of the next and previous pages.
+pub type GetAllMaintenanceTypesResponse = Page<MaintenanceTypeSummary>;
 
 impl From<MaintenanceTypeView> for MaintenanceTypeSummary {
     fn from(view: MaintenanceTypeView) -> Self {
@@ -21,23 +36,10 @@ impl From<MaintenanceTypeView> for MaintenanceTypeSummary {
             id: view.id,
             name: view.name,
             description: view.description,
+            category: view.category,
+            applicability: view.applicability,
             created_at: view.created_at,
             updated_at: view.updated_at,
         }
     }
 }
-
-impl From<Vec<MaintenanceTypeView>> for GetAllMaintenanceTypesResponse {
-    fn from(views: Vec<MaintenanceTypeView>) -> Self {
-        let total_count = views.len();
-        let maintenance_types = views
-            .into_iter()
-            .map(MaintenanceTypeSummary::from)
-            .collect();
-
-        GetAllMaintenanceTypesResponse {
-            maintenance_types,
-            total_count,
-        }
-    }
-}
@@ -1,7 +1,12 @@
-use domain::maintenance::repositories::maintenance_type_repository::MaintenanceTypeRepositoryError;
+use crate::{
+    maintenance::traits::maintenance_type_repository::MaintenanceTypeApplicationRepositoryError,
+    shared::cursor::CursorError,
+};
 
 #[derive(Debug, thiserror::Error)]
 pub enum GetAllMaintenanceTypesError {
+    #[error("Invalid cursor: {0}")]
+    InvalidCursor(#[from] CursorError),
     #[error("Repository error: {0}")]
-    Repository(#[from] MaintenanceTypeRepositoryError),
+    Repository(#[from] MaintenanceTypeApplicationRepositoryError),
 }
@@ -1,23 +1,58 @@
 use super::{
-    dto::{GetAllMaintenanceTypesQuery as Input, GetAllMaintenanceTypesResponse as Output},
+    dto::{
+        GetAllMaintenanceTypesQuery as Input, GetAllMaintenanceTypesResponse as Output,
+        MaintenanceTypeSummary,
+    },
     error::GetAllMaintenanceTypesError as Error,
 };
-use domain::maintenance::repositories::maintenance_type_repository::MaintenanceTypeRepository;
+use crate::{
+    auth::AuthenticatedUser,
+    maintenance::traits::maintenance_type_repository::MaintenanceTypeApplicationRepository,
+    shared::{
+        cursor::CursorCodec,
+        pagination::{Keyset, KeysetPosition, Page, SortOrder},
+    },
+};
 
-pub struct GetAllMaintenanceTypesUseCase<'a, MTR: MaintenanceTypeRepository + 'a> {
+pub struct GetAllMaintenanceTypesUseCase<'a, MTR: MaintenanceTypeApplicationRepository + 'a> {
     maintenance_type_repository: &'a MTR,
+    cursor_codec: &'a CursorCodec,
 }
 
-impl<'a, MTR: MaintenanceTypeRepository + 'a> GetAllMaintenanceTypesUseCase<'a, MTR> {
-    pub fn new(maintenance_type_repository: &'a MTR) -> Self {
+impl<'a, MTR: MaintenanceTypeApplicationRepository + 'a> GetAllMaintenanceTypesUseCase<'a, MTR> {
+    pub fn new(maintenance_type_repository: &'a MTR, cursor_codec: &'a CursorCodec) -> Self {
         GetAllMaintenanceTypesUseCase {
             maintenance_type_repository,
+            cursor_codec,
         }
     }
 
-    pub async fn execute(&self, _query: Input) -> Result<Output, Error> {
-        let maintenance_type_views = self.maintenance_type_repository.get_all_view().await?;
+    pub async fn execute(&self, query: Input, user: &AuthenticatedUser) -> Result<Output, Error> {
+        let keyset = Keyset::new(&query.page, "name", SortOrder::Asc, self.cursor_codec)?;
+
+        let total_count = match query.page.include_total {
+            true => Some(
+                self.maintenance_type_repository
+                    .count(user.organization_id, query.filter.as_ref())
+                    .await?,
+            ),
+            false => None,
+        };
+        let maintenance_type_views = self
+            .maintenance_type_repository
+            .get_view_page(user.organization_id, query.filter.as_ref(), &keyset)
+            .await?;
 
-        Ok(Output::from(maintenance_type_views))
+        let page = Page::from_rows(
+            maintenance_type_views,
+            &keyset,
+            self.cursor_codec,
+            |view| KeysetPosition {
+                value: Some(view.name.clone()),
+                id: view.id.to_string(),
+            },
+            total_count,
+        );
+        Ok(page.map(MaintenanceTypeSummary::from))
     }
 }
@@ -0,0 +1,45 @@
+pub use crate::maintenance::models::maintenance_cost_sum::{
+    MaintenanceCostGroupBy, MaintenanceCostGroupKey,
+};
+use crate::vehicle::filters::vehicle_filter::VehicleFilter;
+use rust_decimal::Decimal;
+
+pub struct GetMaintenanceCostsQuery {
+    pub group_by: MaintenanceCostGroupBy,
+    /// Only maintenance records performed at or after this moment are included.
+    pub from: Option<chrono::DateTime<chrono::Utc>>,
+    /// Only maintenance records performed at or before this moment are included.
+    pub to: Option<chrono::DateTime<chrono::Utc>>,
+    /// Vehicles in scope, its paging and sorting are ignored.
+    pub vehicle_filter: VehicleFilter,
+}
+
+/// Totals of one group in one currency.
+pub struct MaintenanceCostGroup {
+    pub key: MaintenanceCostGroupKey,
+    /// Human readable name of the group (license plate, maintenance type name, "2025-07").
+    pub label: String,
+    pub currency: String,
+    pub parts: Decimal,
+    pub labor: Decimal,
+    pub taxes: Decimal,
+    pub fees: Decimal,
+    pub total: Decimal,
+    pub labor_hours: Decimal,
+    /// Number of maintenance records with a cost in the group.
+    pub record_count: usize,
+    /// Distance travelled in the period, only when grouping by vehicle.
+    pub distance_km: Option<i64>,
+    /// `total / distance_km`, only when grouping by vehicle and the vehicle moved.
+    pub cost_per_km: Option<Decimal>,
+}
+
+pub struct GetMaintenanceCostsResponse {
+    pub group_by: MaintenanceCostGroupBy,
+    /// Groups ordered by key, then by currency.
+    pub groups: Vec<MaintenanceCostGroup>,
+    /// Number of vehicles matching the filter.
+    pub vehicle_count: usize,
+    /// Number of records in the period without a cost.
+    pub records_without_cost: usize,
+}
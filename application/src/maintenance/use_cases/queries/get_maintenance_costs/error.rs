@@ -0,0 +1,14 @@
+use crate::{
+    maintenance::traits::maintenance_cost_repository::MaintenanceCostApplicationRepositoryError,
+    vehicle::traits::vehicle_repository::VehicleApplicationRepositoryError,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetMaintenanceCostsError {
+    #[error("Invalid date range: 'from' must not be after 'to'")]
+    InvalidDateRange,
+    #[error("Vehicle repository error: {0}")]
+    VehicleRepository(#[from] VehicleApplicationRepositoryError),
+    #[error("Maintenance cost repository error: {0}")]
+    MaintenanceCostRepository(#[from] MaintenanceCostApplicationRepositoryError),
+}
@@ -0,0 +1,118 @@
+use super::{
+    dto::{
+        GetMaintenanceCostsQuery as Input, GetMaintenanceCostsResponse as Output,
+        MaintenanceCostGroup, MaintenanceCostGroupBy, MaintenanceCostGroupKey,
+    },
+    error::GetMaintenanceCostsError as Error,
+};
+use crate::{
+    auth::AuthenticatedUser,
+    maintenance::traits::maintenance_cost_repository::MaintenanceCostApplicationRepository,
+    vehicle::traits::vehicle_repository::VehicleApplicationRepository,
+};
+use std::collections::HashMap;
+
+pub struct GetMaintenanceCostsUseCase<'a, VAR, MCR>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    MCR: MaintenanceCostApplicationRepository + 'a,
+{
+    vehicle_repository: &'a VAR,
+    maintenance_cost_repository: &'a MCR,
+}
+
+impl<'a, VAR, MCR> GetMaintenanceCostsUseCase<'a, VAR, MCR>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    MCR: MaintenanceCostApplicationRepository + 'a,
+{
+    pub fn new(vehicle_repository: &'a VAR, maintenance_cost_repository: &'a MCR) -> Self {
+        GetMaintenanceCostsUseCase {
+            vehicle_repository,
+            maintenance_cost_repository,
+        }
+    }
+
+    pub async fn execute(&self, query: Input, user: &AuthenticatedUser) -> Result<Output, Error> {
+        if let (Some(from), Some(to)) = (query.from, query.to)
+            && from > to
+        {
+            return Err(Error::InvalidDateRange);
+        }
+
+        let filter = query.vehicle_filter.scoped_to(user);
+        let vehicle_count = self
+            .vehicle_repository
+            .count(user.organization_id, filter.clone())
+            .await?;
+        let sums = self
+            .maintenance_cost_repository
+            .sum_costs(
+                user.organization_id,
+                &filter,
+                query.group_by,
+                query.from,
+                query.to,
+            )
+            .await?;
+
+        // Distances are only needed when grouping by vehicle
+        let mut distances: HashMap<uuid::Uuid, i64> = HashMap::new();
+        if query.group_by == MaintenanceCostGroupBy::Vehicle && !sums.groups.is_empty() {
+            for distance in self
+                .maintenance_cost_repository
+                .distance_by_vehicle(user.organization_id, &filter, query.from, query.to)
+                .await?
+            {
+                distances.insert(distance.vehicle_id, distance.distance_km);
+            }
+        }
+
+        let groups = sums
+            .groups
+            .into_iter()
+            .map(|sum| {
+                let key = sum.key;
+                let totals = sum.totals;
+                // A vehicle without statuses in the period didn't move
+                let distance_km = match key {
+                    MaintenanceCostGroupKey::Vehicle { vehicle_id } => {
+                        Some(distances.get(&vehicle_id).copied().unwrap_or(0))
+                    }
+                    _ => None,
+                };
+                let label = match key {
+                    MaintenanceCostGroupKey::Month { year, month } => {
+                        format!("{:04}-{:02}", year, month)
+                    }
+                    _ => sum.label,
+                };
+                let breakdown = totals.breakdown;
+
+                MaintenanceCostGroup {
+                    label,
+                    currency: totals.currency().to_string(),
+                    parts: breakdown.parts.amount(),
+                    labor: breakdown.labor.amount(),
+                    taxes: breakdown.taxes.amount(),
+                    fees: breakdown.fees.amount(),
+                    total: breakdown.total.amount(),
+                    labor_hours: breakdown.labor_hours,
+                    record_count: totals.record_count,
+                    distance_km,
+                    cost_per_km: distance_km
+                        .and_then(|distance| totals.per_kilometer(distance))
+                        .map(|money| money.amount()),
+                    key,
+                }
+            })
+            .collect();
+
+        Ok(Output {
+            group_by: query.group_by,
+            groups,
+            vehicle_count: vehicle_count as usize,
+            records_without_cost: sums.records_without_cost as usize,
+        })
+    }
+}
@@ -0,0 +1,42 @@
+use crate::vehicle::filters::vehicle_filter::VehicleFilter;
+use domain::maintenance::services::maintenance_forecast_service::UsageTrendSettings;
+
+pub struct GetMaintenanceForecastQuery {
+    /// Vehicles in scope. Its page size is used as the batch size when walking the fleet.
+    pub vehicle_filter: VehicleFilter,
+    /// Tuning of the usage rate estimation (`None` means the defaults).
+    pub settings: Option<UsageTrendSettings>,
+    /// Only keep rules due on or before this date (`None` keeps every rule).
+    pub due_before: Option<chrono::NaiveDate>,
+}
+
+/// Forecast of a single maintenance rule.
+pub struct MaintenanceForecastItem {
+    pub maintenance_id: i32,
+    pub maintenance_type: String,
+    pub interval_type: String,
+    pub interval_value: u32,
+    /// Current progress in percent.
+    pub percentage: u32,
+    /// Current level ("green", "yellow", "red", "overdue").
+    pub level: String,
+    pub yellow_at: Option<chrono::NaiveDate>,
+    pub red_at: Option<chrono::NaiveDate>,
+    /// Expected due date, `None` when it can't be estimated (no usage history).
+    pub due_at: Option<chrono::NaiveDate>,
+}
+
+pub struct VehicleMaintenanceForecast {
+    pub vehicle_id: String,
+    pub license_plate: String,
+    pub km_per_day: Option<f64>,
+    pub engine_hours_per_day: Option<f64>,
+    /// Rules ordered by due date, rules without a date last.
+    pub forecasts: Vec<MaintenanceForecastItem>,
+}
+
+pub struct GetMaintenanceForecastResponse {
+    pub vehicles: Vec<VehicleMaintenanceForecast>,
+    /// The date the forecast was made on.
+    pub today: chrono::NaiveDate,
+}
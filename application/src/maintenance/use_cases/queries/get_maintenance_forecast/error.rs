@@ -0,0 +1,22 @@
+use crate::vehicle::traits::vehicle_repository::VehicleApplicationRepositoryError;
+use domain::{
+    maintenance::repositories::{
+        maintenance_record_repository::MaintenanceRecordRepositoryError,
+        maintenance_repository::MaintenanceRepositoryError,
+    },
+    vehicle::repositories::vehicle_status_repository::VehicleStatusRepositoryError,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetMaintenanceForecastError {
+    #[error("Invalid vehicle id: {0}")]
+    InvalidVehicleId(String),
+    #[error("Vehicle repository error: {0}")]
+    VehicleRepository(#[from] VehicleApplicationRepositoryError),
+    #[error("Maintenance repository error: {0}")]
+    MaintenanceRepository(#[from] MaintenanceRepositoryError),
+    #[error("Maintenance record repository error: {0}")]
+    MaintenanceRecordRepository(#[from] MaintenanceRecordRepositoryError),
+    #[error("Vehicle status repository error: {0}")]
+    VehicleStatusRepository(#[from] VehicleStatusRepositoryError),
+}
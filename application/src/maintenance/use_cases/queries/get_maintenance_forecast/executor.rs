@@ -0,0 +1,168 @@
+use super::{
+    dto::{
+        GetMaintenanceForecastQuery as Input, GetMaintenanceForecastResponse as Output,
+        MaintenanceForecastItem, VehicleMaintenanceForecast,
+    },
+    error::GetMaintenanceForecastError as Error,
+};
+use crate::{
+    auth::AuthenticatedUser,
+    shared::pagination::{DEFAULT_PAGE, DEFAULT_PAGE_SIZE},
+    vehicle::traits::vehicle_repository::VehicleApplicationRepository,
+};
+use domain::{
+    maintenance::{
+        entities::maintenance_status::MaintenanceStatus,
+        repositories::{
+            maintenance_record_repository::MaintenanceRecordRepository,
+            maintenance_repository::MaintenanceRepository,
+        },
+        services::maintenance_forecast_service::{estimate_usage, forecast},
+    },
+    vehicle::repositories::vehicle_status_repository::VehicleStatusRepository,
+};
+
+pub struct GetMaintenanceForecastUseCase<'a, VAR, MR, MRR, VSR>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    MR: MaintenanceRepository + 'a,
+    MRR: MaintenanceRecordRepository + 'a,
+    VSR: VehicleStatusRepository + 'a,
+{
+    vehicle_repository: &'a VAR,
+    maintenance_repository: &'a MR,
+    maintenance_record_repository: &'a MRR,
+    vehicle_status_repository: &'a VSR,
+}
+
+impl<'a, VAR, MR, MRR, VSR> GetMaintenanceForecastUseCase<'a, VAR, MR, MRR, VSR>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    MR: MaintenanceRepository + 'a,
+    MRR: MaintenanceRecordRepository + 'a,
+    VSR: VehicleStatusRepository + 'a,
+{
+    pub fn new(
+        vehicle_repository: &'a VAR,
+        maintenance_repository: &'a MR,
+        maintenance_record_repository: &'a MRR,
+        vehicle_status_repository: &'a VSR,
+    ) -> Self {
+        GetMaintenanceForecastUseCase {
+            vehicle_repository,
+            maintenance_repository,
+            maintenance_record_repository,
+            vehicle_status_repository,
+        }
+    }
+
+    pub async fn execute(&self, query: Input, user: &AuthenticatedUser) -> Result<Output, Error> {
+        let settings = query.settings.unwrap_or_default();
+        let now = chrono::Utc::now();
+        let today = now.date_naive();
+        let look_back_start = now - chrono::Duration::days(i64::from(settings.look_back_days));
+
+        let mut filter = query.vehicle_filter.scoped_to(user);
+        filter.page = DEFAULT_PAGE;
+        if filter.page_size == 0 {
+            filter.page_size = DEFAULT_PAGE_SIZE;
+        }
+
+        let mut vehicles_forecast = Vec::new();
+        loop {
+            let vehicles = self
+                .vehicle_repository
+                .get_by_filter(user.organization_id, filter.clone())
+                .await?;
+            let fetched = vehicles.len();
+
+            for vehicle in vehicles {
+                let vehicle_id = uuid::Uuid::parse_str(&vehicle.id)
+                    .map_err(|_| Error::InvalidVehicleId(vehicle.id.clone()))?;
+
+                // Usage trend over the look-back window
+                let history = self
+                    .vehicle_status_repository
+                    .find_by_vehicle(
+                        user.organization_id,
+                        vehicle_id,
+                        Some(look_back_start),
+                        Some(now),
+                    )
+                    .await?;
+                let rates = estimate_usage(&history, now, &settings);
+                let latest_status = self
+                    .vehicle_status_repository
+                    .find_latest(user.organization_id, vehicle_id)
+                    .await?;
+
+                let mut forecasts = Vec::new();
+                let rules = self
+                    .maintenance_repository
+                    .find_by_vehicle(user.organization_id, vehicle_id)
+                    .await?;
+                for rule in rules {
+                    let last_record = self
+                        .maintenance_record_repository
+                        .find_latest_by_maintenance(user.organization_id, rule.identity.id)
+                        .await?;
+                    let status = MaintenanceStatus::calculate(
+                        &rule.identity,
+                        last_record.as_ref(),
+                        latest_status.as_ref(),
+                        today,
+                    );
+                    let projection = forecast(
+                        &status,
+                        &rule.identity.interval_type,
+                        rule.identity.yellow_threshold,
+                        rule.identity.red_threshold,
+                        &rates,
+                        today,
+                    );
+
+                    // Check the rule is due within the horizon
+                    if let Some(due_before) = query.due_before
+                        && projection.due_at.is_none_or(|due_at| due_at > due_before)
+                    {
+                        continue;
+                    }
+
+                    forecasts.push(MaintenanceForecastItem {
+                        maintenance_id: rule.identity.id,
+                        maintenance_type: rule.maintenance_type.name().to_string(),
+                        interval_type: rule.identity.interval_type.as_str().to_string(),
+                        interval_value: rule.identity.interval_value,
+                        percentage: status.percentage,
+                        level: status.level.as_str().to_string(),
+                        yellow_at: projection.yellow_at,
+                        red_at: projection.red_at,
+                        due_at: projection.due_at,
+                    });
+                }
+                forecasts.sort_by_key(|item| (item.due_at.is_none(), item.due_at));
+
+                if query.due_before.is_some() && forecasts.is_empty() {
+                    continue;
+                }
+                vehicles_forecast.push(VehicleMaintenanceForecast {
+                    vehicle_id: vehicle.id,
+                    license_plate: vehicle.license_plate,
+                    km_per_day: rates.km_per_day,
+                    engine_hours_per_day: rates.engine_hours_per_day,
+                    forecasts,
+                });
+            }
+
+            if fetched < filter.page_size as usize {
+                break;
+            }
+            filter.page += 1;
+        }
+
+        Ok(Output {
+            vehicles: vehicles_forecast,
+            today,
+        })
+    }
+}
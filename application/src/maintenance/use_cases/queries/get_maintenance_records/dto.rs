@@ -0,0 +1,42 @@
+use crate::shared::{
+    filter_expression::filter_ast::FilterExpression,
+    pagination::{Page, PageRequest},
+};
+use domain::maintenance::entities::{
+    maintenance_cost::MaintenanceCost, maintenance_record::MaintenanceRecordIdentity,
+};
+
+/// Records are listed from the most recent one.
+pub struct GetMaintenanceRecordsQuery {
+    pub vehicle_id: uuid::Uuid,
+    /// Filter expression over `MAINTENANCE_RECORD_FILTER_SCHEMA`.
+    pub filter: Option<FilterExpression>,
+    pub page: PageRequest,
+}
+
+pub struct MaintenanceRecordResponse {
+    pub id: uuid::Uuid,
+    pub maintenance_id: i32,
+    pub user_id: uuid::Uuid,
+    pub vehicle_status_id: i32,
+    pub performed_at: chrono::DateTime<chrono::Utc>,
+    pub details: String,
+    pub cost: Option<MaintenanceCost>,
+}
+
+/// A page of records, with the cursors of the next and previous pages.
+pub type GetMaintenanceRecordsResponse = Page<MaintenanceRecordResponse>;
+
+impl From<MaintenanceRecordIdentity> for MaintenanceRecordResponse {
+    fn from(record: MaintenanceRecordIdentity) -> Self {
+        MaintenanceRecordResponse {
+            id: record.id,
+            maintenance_id: record.maintenance_id,
+            user_id: record.user_id,
+            vehicle_status_id: record.vehicle_status_id,
+            performed_at: record.performed_at,
+            details: record.details,
+            cost: record.cost,
+        }
+    }
+}
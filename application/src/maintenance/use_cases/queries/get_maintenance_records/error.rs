@@ -0,0 +1,17 @@
+use crate::{
+    maintenance::traits::maintenance_record_repository::MaintenanceRecordApplicationRepositoryError,
+    shared::cursor::CursorError,
+    vehicle::traits::vehicle_repository::VehicleApplicationRepositoryError,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetMaintenanceRecordsError {
+    #[error("Vehicle not found: {0}")]
+    VehicleNotFound(uuid::Uuid),
+    #[error("Invalid cursor: {0}")]
+    InvalidCursor(#[from] CursorError),
+    #[error("Vehicle repository error: {0}")]
+    VehicleRepository(#[from] VehicleApplicationRepositoryError),
+    #[error("Repository error: {0}")]
+    Repository(#[from] MaintenanceRecordApplicationRepositoryError),
+}
@@ -0,0 +1,96 @@
+use super::{
+    dto::{
+        GetMaintenanceRecordsQuery as Input, GetMaintenanceRecordsResponse as Output,
+        MaintenanceRecordResponse,
+    },
+    error::GetMaintenanceRecordsError as Error,
+};
+use crate::{
+    auth::AuthenticatedUser,
+    maintenance::traits::maintenance_record_repository::MaintenanceRecordApplicationRepository,
+    shared::{
+        cursor::CursorCodec,
+        pagination::{Keyset, KeysetPosition, Page, SortOrder},
+    },
+    vehicle::{
+        scope::ensure_vehicle_in_scope, traits::vehicle_repository::VehicleApplicationRepository,
+    },
+};
+
+pub struct GetMaintenanceRecordsUseCase<'a, VAR, MRR>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    MRR: MaintenanceRecordApplicationRepository + 'a,
+{
+    vehicle_repository: &'a VAR,
+    maintenance_record_repository: &'a MRR,
+    cursor_codec: &'a CursorCodec,
+}
+
+impl<'a, VAR, MRR> GetMaintenanceRecordsUseCase<'a, VAR, MRR>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    MRR: MaintenanceRecordApplicationRepository + 'a,
+{
+    pub fn new(
+        vehicle_repository: &'a VAR,
+        maintenance_record_repository: &'a MRR,
+        cursor_codec: &'a CursorCodec,
+    ) -> Self {
+        GetMaintenanceRecordsUseCase {
+            vehicle_repository,
+            maintenance_record_repository,
+            cursor_codec,
+        }
+    }
+
+    pub async fn execute(&self, query: Input, user: &AuthenticatedUser) -> Result<Output, Error> {
+        ensure_vehicle_in_scope(
+            self.vehicle_repository,
+            user,
+            query.vehicle_id,
+            Error::VehicleNotFound(query.vehicle_id),
+        )
+        .await?;
+        let keyset = Keyset::new(
+            &query.page,
+            "performed_at",
+            SortOrder::Desc,
+            self.cursor_codec,
+        )?;
+
+        let total_count = match query.page.include_total {
+            true => Some(
+                self.maintenance_record_repository
+                    .count(
+                        user.organization_id,
+                        query.vehicle_id,
+                        query.filter.as_ref(),
+                    )
+                    .await?,
+            ),
+            false => None,
+        };
+        let records = self
+            .maintenance_record_repository
+            .get_page(
+                user.organization_id,
+                query.vehicle_id,
+                query.filter.as_ref(),
+                &keyset,
+            )
+            .await?;
+
+        let page = Page::from_rows(
+            records,
+            &keyset,
+            self.cursor_codec,
+            |record| KeysetPosition {
+                value: Some(record.performed_at.to_rfc3339()),
+                id: record.id.to_string(),
+            },
+            total_count,
+        );
+        Ok(page.map(MaintenanceRecordResponse::from))
+    }
+}
@@ -1,4 +1,10 @@
-use domain::maintenance::entities::maintenance_type::MaintenanceTypeView;
+use domain::maintenance::{
+    entities::maintenance_type::MaintenanceTypeView,
+    value_types::{
+        maintenance_applicability::MaintenanceApplicability,
+        maintenance_category::MaintenanceCategory,
+    },
+};
 
 pub struct GetMaintenanceTypeByIdQuery {
     pub id: i32,
@@ -8,6 +14,10 @@ pub struct GetMaintenanceTypeByIdResponse {
     pub id: i32,
     pub name: String,
     pub description: String,
+    pub category: Option<MaintenanceCategory>,
+    pub applicability: MaintenanceApplicability,
+    pub checklist: Vec<String>,
+    pub deprecated_at: Option<chrono::DateTime<chrono::Utc>>,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub created_by: uuid::Uuid,
     pub created_by_email: String,
@@ -22,6 +32,10 @@ impl From<MaintenanceTypeView> for GetMaintenanceTypeByIdResponse {
             id: view.id,
             name: view.name,
             description: view.description,
+            category: view.category,
+            applicability: view.applicability,
+            checklist: view.checklist,
+            deprecated_at: view.deprecated_at,
             created_at: view.created_at,
             created_by: view.created_by.id,
             created_by_email: view.created_by.email.into(),
@@ -2,6 +2,7 @@ use super::{
     dto::{GetMaintenanceTypeByIdQuery as Input, GetMaintenanceTypeByIdResponse as Output},
     error::GetMaintenanceTypeByIdError as Error,
 };
+use crate::auth::AuthenticatedUser;
 use domain::maintenance::repositories::maintenance_type_repository::MaintenanceTypeRepository;
 
 pub struct GetMaintenanceTypeByIdUseCase<'a, MTR: MaintenanceTypeRepository + 'a> {
@@ -15,10 +16,10 @@ impl<'a, MTR: MaintenanceTypeRepository + 'a> GetMaintenanceTypeByIdUseCase<'a,
         }
     }
 
-    pub async fn execute(&self, query: Input) -> Result<Output, Error> {
+    pub async fn execute(&self, query: Input, user: &AuthenticatedUser) -> Result<Output, Error> {
         let maintenance_type_view = self
             .maintenance_type_repository
-            .get_view_by_id(query.id)
+            .get_view_by_id(user.organization_id, query.id)
             .await?
             .ok_or(Error::NotFound)?;
 
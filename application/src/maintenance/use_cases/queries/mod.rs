@@ -1,3 +1,7 @@
 pub mod get_maintenance_type_by_id;
 pub mod get_all_maintenance_types;
-pub mod search_maintenance_types;
\ No newline at end of file
+pub mod get_maintenance_costs;
+pub mod get_maintenance_forecast;
+pub mod search_maintenance_types;
+pub mod export_maintenance_catalog;
+pub mod get_maintenance_records;
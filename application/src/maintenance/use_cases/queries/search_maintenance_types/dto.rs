@@ -1,7 +1,18 @@
-use domain::maintenance::entities::maintenance_type::MaintenanceTypeView;
+use crate::search::models::search_language::SearchLanguage;
+use domain::maintenance::{
+    entities::maintenance_type::MaintenanceTypeView,
+    value_types::{
+        maintenance_applicability::MaintenanceApplicability,
+        maintenance_category::MaintenanceCategory,
+    },
+};
 
 pub struct SearchMaintenanceTypesQuery {
     pub search_term: String,
+    /// Only returns the maintenance types of this category.
+    pub category: Option<MaintenanceCategory>,
+    /// Text search configuration, detected from the term when `None`.
+    pub language: Option<SearchLanguage>,
     pub limit: Option<usize>,
 }
 
@@ -9,6 +20,12 @@ pub struct MaintenanceTypeSearchResult {
     pub id: i32,
     pub name: String,
     pub description: String,
+    pub category: Option<MaintenanceCategory>,
+    pub applicability: MaintenanceApplicability,
+    /// Relevance of the result, higher is better.
+    pub rank: f32,
+    /// Excerpt of the name or description with the matched words highlighted.
+    pub snippet: String,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
 }
@@ -19,12 +36,16 @@ pub struct SearchMaintenanceTypesResponse {
     pub search_term: String,
 }
 
-impl From<MaintenanceTypeView> for MaintenanceTypeSearchResult {
-    fn from(view: MaintenanceTypeView) -> Self {
+impl MaintenanceTypeSearchResult {
+    pub fn new(view: MaintenanceTypeView, rank: f32, snippet: String) -> Self {
         MaintenanceTypeSearchResult {
             id: view.id,
             name: view.name,
             description: view.description,
+            category: view.category,
+            applicability: view.applicability,
+            rank,
+            snippet,
             created_at: view.created_at,
             updated_at: view.updated_at,
         }
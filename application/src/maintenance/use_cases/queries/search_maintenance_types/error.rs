@@ -1,9 +1,16 @@
+use crate::search::{
+    models::text_search::TextSearchError, traits::search_repository::SearchRepositoryError,
+};
 use domain::maintenance::repositories::maintenance_type_repository::MaintenanceTypeRepositoryError;
 
 #[derive(Debug, thiserror::Error)]
 pub enum SearchMaintenanceTypesError {
     #[error("Invalid search term: must not be empty")]
     EmptySearchTerm,
+    #[error("Invalid search: {0}")]
+    InvalidSearch(TextSearchError),
+    #[error("Search repository error: {0}")]
+    Search(#[from] SearchRepositoryError),
     #[error("Repository error: {0}")]
     Repository(#[from] MaintenanceTypeRepositoryError),
 }
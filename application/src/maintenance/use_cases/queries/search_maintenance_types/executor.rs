@@ -2,39 +2,71 @@ use super::{
     dto::{SearchMaintenanceTypesQuery as Input, SearchMaintenanceTypesResponse as Output, MaintenanceTypeSearchResult},
     error::SearchMaintenanceTypesError as Error,
 };
+use crate::{
+    auth::AuthenticatedUser,
+    search::{
+        models::text_search::{MAX_SEARCH_LIMIT, TextSearch, TextSearchError},
+        traits::search_repository::SearchRepository,
+    },
+};
 use domain::maintenance::repositories::maintenance_type_repository::MaintenanceTypeRepository;
+use std::collections::HashMap;
 
-pub struct SearchMaintenanceTypesUseCase<'a, MTR: MaintenanceTypeRepository + 'a> {
+pub struct SearchMaintenanceTypesUseCase<'a, MTR, SR>
+where
+    MTR: MaintenanceTypeRepository + 'a,
+    SR: SearchRepository + 'a,
+{
     maintenance_type_repository: &'a MTR,
+    search_repository: &'a SR,
 }
 
-impl<'a, MTR: MaintenanceTypeRepository + 'a> SearchMaintenanceTypesUseCase<'a, MTR> {
-    pub fn new(maintenance_type_repository: &'a MTR) -> Self {
+impl<'a, MTR, SR> SearchMaintenanceTypesUseCase<'a, MTR, SR>
+where
+    MTR: MaintenanceTypeRepository + 'a,
+    SR: SearchRepository + 'a,
+{
+    pub fn new(maintenance_type_repository: &'a MTR, search_repository: &'a SR) -> Self {
         SearchMaintenanceTypesUseCase {
             maintenance_type_repository,
+            search_repository,
         }
     }
 
-    pub async fn execute(&self, query: Input) -> Result<Output, Error> {
-        if query.search_term.trim().is_empty() {
-            return Err(Error::EmptySearchTerm);
-        }
+    pub async fn execute(&self, query: Input, user: &AuthenticatedUser) -> Result<Output, Error> {
+        // The category is filtered afterwards, so fetch as many hits as allowed
+        let search = TextSearch::new(
+            &query.search_term,
+            query.language,
+            None,
+            Some(MAX_SEARCH_LIMIT),
+        )
+        .map_err(|e| match e {
+            TextSearchError::EmptyTerm => Error::EmptySearchTerm,
+            e => Error::InvalidSearch(e),
+        })?;
 
-        // Get all maintenance types and filter them in-memory
-        // In a real implementation, you might want to add a search method to the repository
-        let all_maintenance_types = self
-            .maintenance_type_repository
-            .get_all_view()
+        // Ranked full-text and fuzzy hits, hydrated with the maintenance type views
+        let hits = self
+            .search_repository
+            .search_maintenance_types(user.organization_id, &search)
             .await?;
+        let mut views: HashMap<String, _> = self
+            .maintenance_type_repository
+            .get_all_view(user.organization_id)
+            .await?
+            .into_iter()
+            .map(|view| (view.id.to_string(), view))
+            .collect();
 
-        let search_term_lower = query.search_term.to_lowercase();
-        let mut filtered_results: Vec<MaintenanceTypeSearchResult> = all_maintenance_types
+        let mut filtered_results: Vec<MaintenanceTypeSearchResult> = hits
             .into_iter()
-            .filter(|mt| {
-                mt.name.to_lowercase().contains(&search_term_lower) ||
-                mt.description.to_lowercase().contains(&search_term_lower)
+            .filter_map(|hit| {
+                views
+                    .remove(&hit.id)
+                    .map(|view| MaintenanceTypeSearchResult::new(view, hit.rank, hit.snippet))
             })
-            .map(MaintenanceTypeSearchResult::from)
+            .filter(|result| query.category.is_none() || result.category == query.category)
             .collect();
 
         // Apply limit if specified
@@ -0,0 +1,3 @@
+pub mod models;
+pub mod renderers;
+pub mod use_cases;
@@ -0,0 +1,4 @@
+pub mod report_cell;
+pub mod report_column;
+pub mod report_format;
+pub mod report_kind;
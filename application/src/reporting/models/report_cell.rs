@@ -0,0 +1,51 @@
+use std::fmt;
+
+/// A single value of a report row, kept typed so each format can render it natively.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReportCell {
+    Empty,
+    Text(String),
+    Integer(i64),
+    DateTime(chrono::DateTime<chrono::Utc>),
+}
+
+impl fmt::Display for ReportCell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReportCell::Empty => Ok(()),
+            ReportCell::Text(value) => write!(f, "{}", value),
+            ReportCell::Integer(value) => write!(f, "{}", value),
+            ReportCell::DateTime(value) => write!(f, "{}", value.format("%Y-%m-%d %H:%M")),
+        }
+    }
+}
+
+impl From<String> for ReportCell {
+    fn from(value: String) -> Self {
+        ReportCell::Text(value)
+    }
+}
+
+impl From<&str> for ReportCell {
+    fn from(value: &str) -> Self {
+        ReportCell::Text(value.to_string())
+    }
+}
+
+impl<T: Into<ReportCell>> From<Option<T>> for ReportCell {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(ReportCell::Empty, Into::into)
+    }
+}
+
+impl From<i64> for ReportCell {
+    fn from(value: i64) -> Self {
+        ReportCell::Integer(value)
+    }
+}
+
+impl From<chrono::DateTime<chrono::Utc>> for ReportCell {
+    fn from(value: chrono::DateTime<chrono::Utc>) -> Self {
+        ReportCell::DateTime(value)
+    }
+}
@@ -0,0 +1,142 @@
+use std::str::FromStr;
+
+/// Every column a maintenance report can contain.
+///
+/// Which columns are valid depends on the report, see `ReportKind::available_columns`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportColumn {
+    /* Vehicle */
+    LicensePlate,
+    Vin,
+    Make,
+    Model,
+    Year,
+    EngineType,
+    CurrentOdometer,
+    /* Maintenance record */
+    MaintenanceType,
+    PerformedAt,
+    Odometer,
+    EngineHours,
+    PerformedBy,
+    Details,
+    /* Fleet summary */
+    RecordCount,
+    LastPerformedAt,
+    RulesTotal,
+    RulesYellow,
+    RulesRed,
+    RulesOverdue,
+    /* Due status */
+    IntervalType,
+    IntervalValue,
+    LastValue,
+    CurrentValue,
+    NextValue,
+    Percentage,
+    Status,
+}
+
+impl ReportColumn {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::LicensePlate => "license_plate",
+            Self::Vin => "vin",
+            Self::Make => "make",
+            Self::Model => "model",
+            Self::Year => "year",
+            Self::EngineType => "engine_type",
+            Self::CurrentOdometer => "current_odometer",
+            Self::MaintenanceType => "maintenance_type",
+            Self::PerformedAt => "performed_at",
+            Self::Odometer => "odometer",
+            Self::EngineHours => "engine_hours",
+            Self::PerformedBy => "performed_by",
+            Self::Details => "details",
+            Self::RecordCount => "record_count",
+            Self::LastPerformedAt => "last_performed_at",
+            Self::RulesTotal => "rules_total",
+            Self::RulesYellow => "rules_yellow",
+            Self::RulesRed => "rules_red",
+            Self::RulesOverdue => "rules_overdue",
+            Self::IntervalType => "interval_type",
+            Self::IntervalValue => "interval_value",
+            Self::LastValue => "last_value",
+            Self::CurrentValue => "current_value",
+            Self::NextValue => "next_value",
+            Self::Percentage => "percentage",
+            Self::Status => "status",
+        }
+    }
+
+    /// Returns the human readable header of the column.
+    pub fn header(&self) -> &'static str {
+        match self {
+            Self::LicensePlate => "License Plate",
+            Self::Vin => "VIN",
+            Self::Make => "Make",
+            Self::Model => "Model",
+            Self::Year => "Year",
+            Self::EngineType => "Engine Type",
+            Self::CurrentOdometer => "Current Odometer",
+            Self::MaintenanceType => "Maintenance Type",
+            Self::PerformedAt => "Performed At",
+            Self::Odometer => "Odometer",
+            Self::EngineHours => "Engine Hours",
+            Self::PerformedBy => "Performed By",
+            Self::Details => "Details",
+            Self::RecordCount => "Records",
+            Self::LastPerformedAt => "Last Performed At",
+            Self::RulesTotal => "Rules",
+            Self::RulesYellow => "Yellow",
+            Self::RulesRed => "Red",
+            Self::RulesOverdue => "Overdue",
+            Self::IntervalType => "Interval Type",
+            Self::IntervalValue => "Interval",
+            Self::LastValue => "Last Done",
+            Self::CurrentValue => "Current",
+            Self::NextValue => "Due At",
+            Self::Percentage => "Used %",
+            Self::Status => "Status",
+        }
+    }
+}
+
+impl FromStr for ReportColumn {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use ReportColumn::*;
+        let column = match s.to_lowercase().as_str() {
+            "license_plate" => LicensePlate,
+            "vin" => Vin,
+            "make" => Make,
+            "model" => Model,
+            "year" => Year,
+            "engine_type" => EngineType,
+            "current_odometer" => CurrentOdometer,
+            "maintenance_type" => MaintenanceType,
+            "performed_at" => PerformedAt,
+            "odometer" => Odometer,
+            "engine_hours" => EngineHours,
+            "performed_by" => PerformedBy,
+            "details" => Details,
+            "record_count" => RecordCount,
+            "last_performed_at" => LastPerformedAt,
+            "rules_total" => RulesTotal,
+            "rules_yellow" => RulesYellow,
+            "rules_red" => RulesRed,
+            "rules_overdue" => RulesOverdue,
+            "interval_type" => IntervalType,
+            "interval_value" => IntervalValue,
+            "last_value" => LastValue,
+            "current_value" => CurrentValue,
+            "next_value" => NextValue,
+            "percentage" => Percentage,
+            "status" => Status,
+            _ => return Err(format!("Invalid report column: {}", s)),
+        };
+        Ok(column)
+    }
+}
@@ -0,0 +1,85 @@
+use super::report_column::ReportColumn;
+use std::str::FromStr;
+
+/// The maintenance reports the system can produce (UC-071..UC-073).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportKind {
+    /// One row per maintenance record of every vehicle in scope.
+    VehicleHistory,
+    /// One row per vehicle with its record count and due-status totals.
+    FleetSummary,
+    /// One row per overdue maintenance rule.
+    Overdue,
+}
+
+impl ReportKind {
+    pub fn title(&self) -> &'static str {
+        match self {
+            ReportKind::VehicleHistory => "Vehicle Maintenance History",
+            ReportKind::FleetSummary => "Fleet Maintenance Summary",
+            ReportKind::Overdue => "Overdue Maintenance",
+        }
+    }
+
+    /// Returns the columns that can be selected for this report, in their default order.
+    pub fn available_columns(&self) -> &'static [ReportColumn] {
+        use ReportColumn::*;
+        match self {
+            ReportKind::VehicleHistory => &[
+                LicensePlate,
+                Vin,
+                Make,
+                Model,
+                MaintenanceType,
+                PerformedAt,
+                Odometer,
+                EngineHours,
+                PerformedBy,
+                Details,
+            ],
+            ReportKind::FleetSummary => &[
+                LicensePlate,
+                Vin,
+                Make,
+                Model,
+                Year,
+                EngineType,
+                CurrentOdometer,
+                RecordCount,
+                LastPerformedAt,
+                RulesTotal,
+                RulesYellow,
+                RulesRed,
+                RulesOverdue,
+            ],
+            ReportKind::Overdue => &[
+                LicensePlate,
+                Vin,
+                Make,
+                Model,
+                MaintenanceType,
+                IntervalType,
+                IntervalValue,
+                LastValue,
+                CurrentValue,
+                NextValue,
+                Percentage,
+                Status,
+            ],
+        }
+    }
+}
+
+impl FromStr for ReportKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "vehicle_history" => Ok(Self::VehicleHistory),
+            "fleet_summary" => Ok(Self::FleetSummary),
+            "overdue" => Ok(Self::Overdue),
+            _ => Err(format!("Invalid report kind: {}", s)),
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use super::{ReportRenderError, ReportRenderer};
+use crate::reporting::models::{report_cell::ReportCell, report_column::ReportColumn};
+use std::io::Write;
+
+/// Renders a report as CSV, one line per row.
+pub struct CsvReportRenderer<W: Write> {
+    writer: csv::Writer<W>,
+}
+
+impl<W: Write> CsvReportRenderer<W> {
+    pub fn new(output: W) -> Self {
+        CsvReportRenderer {
+            writer: csv::Writer::from_writer(output),
+        }
+    }
+}
+
+impl<W: Write + Send> ReportRenderer for CsvReportRenderer<W> {
+    fn begin(&mut self, _title: &str, columns: &[ReportColumn]) -> Result<(), ReportRenderError> {
+        self.writer
+            .write_record(columns.iter().map(|column| column.header()))?;
+        Ok(())
+    }
+
+    fn write_row(&mut self, cells: &[ReportCell]) -> Result<(), ReportRenderError> {
+        self.writer
+            .write_record(cells.iter().map(|cell| cell.to_string()))?;
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<(), ReportRenderError> {
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_header_and_rows() {
+        let mut output = Vec::new();
+        let mut renderer = CsvReportRenderer::new(&mut output);
+        renderer
+            .begin(
+                "ignored",
+                &[
+                    ReportColumn::LicensePlate,
+                    ReportColumn::Year,
+                    ReportColumn::PerformedAt,
+                    ReportColumn::Details,
+                ],
+            )
+            .expect("header written");
+        renderer
+            .write_row(&[
+                "123ABC02".into(),
+                ReportCell::Integer(2021),
+                chrono::Utc
+                    .with_ymd_and_hms(2025, 3, 14, 9, 26, 53)
+                    .unwrap()
+                    .into(),
+                "Oil, filter and \"wipers\"".into(),
+            ])
+            .expect("row written");
+        renderer
+            .write_row(&[
+                "456DEF02".into(),
+                ReportCell::Empty,
+                ReportCell::Empty,
+                ReportCell::Empty,
+            ])
+            .expect("row written");
+        renderer.finish().expect("report finished");
+        drop(renderer);
+
+        assert_eq!(
+            String::from_utf8(output).expect("UTF-8 output"),
+            "License Plate,Year,Performed At,Details\n\
+             123ABC02,2021,2025-03-14 09:26,\"Oil, filter and \"\"wipers\"\"\"\n\
+             456DEF02,,,\n"
+        );
+    }
+}
@@ -0,0 +1,51 @@
+//! Renderers turn report rows into documents.
+//!
+//! Every renderer writes to its output as rows come in, so a report never has to be held in
+//! memory as a whole (XLSX keeps its rows in a temporary file until the workbook is assembled).
+pub mod csv_renderer;
+mod pdf_font;
+pub mod pdf_renderer;
+pub mod xlsx_renderer;
+
+use crate::reporting::models::{
+    report_cell::ReportCell, report_column::ReportColumn, report_format::ReportFormat,
+};
+use std::io::Write;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ReportRenderError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("CSV error: {0}")]
+    Csv(#[from] csv::Error),
+    #[error("XLSX error: {0}")]
+    Xlsx(#[from] rust_xlsxwriter::XlsxError),
+    #[error("Font error: {0}")]
+    Font(String),
+    #[error("Character not supported by the PDF font: {0:?}")]
+    UnsupportedCharacter(char),
+}
+
+/// Writes a report document row by row.
+pub trait ReportRenderer: Send {
+    /// Starts the document and writes the column headers.
+    fn begin(&mut self, title: &str, columns: &[ReportColumn]) -> Result<(), ReportRenderError>;
+
+    /// Writes one row, the cells being in the order of the columns given to `begin`.
+    fn write_row(&mut self, cells: &[ReportCell]) -> Result<(), ReportRenderError>;
+
+    /// Completes the document and flushes it to the output.
+    fn finish(&mut self) -> Result<(), ReportRenderError>;
+}
+
+/// Returns the renderer of the given format writing to `output`.
+pub fn renderer_for<'w, W: Write + Send + 'w>(
+    format: ReportFormat,
+    output: W,
+) -> Box<dyn ReportRenderer + 'w> {
+    match format {
+        ReportFormat::Csv => Box::new(csv_renderer::CsvReportRenderer::new(output)),
+        ReportFormat::Xlsx => Box::new(xlsx_renderer::XlsxReportRenderer::new(output)),
+        ReportFormat::Pdf => Box::new(pdf_renderer::PdfReportRenderer::new(output)),
+    }
+}
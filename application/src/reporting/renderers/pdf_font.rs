@@ -0,0 +1,423 @@
+//! The fonts of the PDF reports, DejaVu Sans (`application/fonts`), embedded so any script the
+//! font covers (Latin, Cyrillic and its Kazakh letters, Greek...) can be written.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * A font is a `Type0` font of a `CIDFontType2` (TrueType) one: text is written as glyph ids
+//!   (`Identity-H`), and its `ToUnicode` map gives viewers the characters back, to copy or search.
+//! * Only the used glyphs are embedded: the outlines of the other ones are emptied, so the glyph
+//!   ids are the ones of the font.
+//! * A character the font doesn't have is an error, never replaced.
+use super::ReportRenderError;
+use flate2::{Compression, write::ZlibEncoder};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    io::Write,
+};
+use ttf_parser::{Face, GlyphId, RawFace, Tag};
+
+const REGULAR: &[u8] = include_bytes!("../../../fonts/DejaVuSans.ttf");
+const BOLD: &[u8] = include_bytes!("../../../fonts/DejaVuSans-Bold.ttf");
+
+/// Tables of an embedded TrueType font, the ones PDF readers use.
+const EMBEDDED_TABLES: [&[u8; 4]; 10] = [
+    b"cmap", b"cvt ", b"fpgm", b"glyf", b"head", b"hhea", b"hmtx", b"loca", b"maxp", b"prep",
+];
+
+/// PDF glyph space, in units per em.
+const GLYPH_SPACE: f32 = 1000.0;
+
+/// Flags of composite glyphs (`glyf` table).
+const ARG_1_AND_2_ARE_WORDS: u16 = 0x0001;
+const WE_HAVE_A_SCALE: u16 = 0x0008;
+const MORE_COMPONENTS: u16 = 0x0020;
+const WE_HAVE_AN_X_AND_Y_SCALE: u16 = 0x0040;
+const WE_HAVE_A_TWO_BY_TWO: u16 = 0x0080;
+
+pub(super) struct PdfFont {
+    /// PostScript name of the font.
+    name: &'static str,
+    data: &'static [u8],
+    face: Face<'static>,
+    /// Used glyphs and the character each one shows.
+    used: BTreeMap<u16, char>,
+}
+
+impl PdfFont {
+    pub(super) fn regular() -> Self {
+        Self::new("DejaVuSans", REGULAR)
+    }
+
+    pub(super) fn bold() -> Self {
+        Self::new("DejaVuSans-Bold", BOLD)
+    }
+
+    fn new(name: &'static str, data: &'static [u8]) -> Self {
+        PdfFont {
+            name,
+            data,
+            face: Face::parse(data, 0).expect("embedded font"),
+            used: BTreeMap::new(),
+        }
+    }
+
+    /// Width of `text` at `size`, in points; characters the font doesn't have are not counted.
+    pub(super) fn width(&self, text: &str, size: f32) -> f32 {
+        let units: u32 = text
+            .chars()
+            .filter_map(|c| self.face.glyph_index(c))
+            .filter_map(|glyph| self.face.glyph_hor_advance(glyph))
+            .map(u32::from)
+            .sum();
+        units as f32 * size / f32::from(self.face.units_per_em())
+    }
+
+    /// Encodes `text` as a PDF string of glyph ids, noting the glyphs to embed.
+    pub(super) fn encode(&mut self, text: &str) -> Result<String, ReportRenderError> {
+        let mut encoded = String::with_capacity(2 + 4 * text.len());
+        encoded.push('<');
+        for c in text.chars() {
+            let glyph = self
+                .face
+                .glyph_index(c)
+                .ok_or(ReportRenderError::UnsupportedCharacter(c))?;
+            self.used.entry(glyph.0).or_insert(c);
+            encoded.push_str(&format!("{:04X}", glyph.0));
+        }
+        encoded.push('>');
+        Ok(encoded)
+    }
+
+    /// The objects of the font: the `Type0` font as `font_id`, then its descendant font, font
+    /// descriptor, font file and `ToUnicode` map from `first_id` on.
+    pub(super) fn objects(
+        &self,
+        font_id: usize,
+        first_id: usize,
+    ) -> Result<Vec<(usize, Vec<u8>)>, ReportRenderError> {
+        let (cid_font_id, descriptor_id, file_id, to_unicode_id) =
+            (first_id, first_id + 1, first_id + 2, first_id + 3);
+        let base_font = format!("{}+{}", self.subset_tag(), self.name);
+
+        let font = format!(
+            "<< /Type /Font /Subtype /Type0 /BaseFont /{} /Encoding /Identity-H \
+             /DescendantFonts [{} 0 R] /ToUnicode {} 0 R >>",
+            base_font, cid_font_id, to_unicode_id
+        );
+        let widths = self
+            .used
+            .keys()
+            .map(|&glyph| {
+                let advance = self.face.glyph_hor_advance(GlyphId(glyph)).unwrap_or(0);
+                format!("{} [{}]", glyph, self.scaled(f32::from(advance)))
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        let cid_font = format!(
+            "<< /Type /Font /Subtype /CIDFontType2 /BaseFont /{} \
+             /CIDSystemInfo << /Registry (Adobe) /Ordering (Identity) /Supplement 0 >> \
+             /FontDescriptor {} 0 R /W [{}] /CIDToGIDMap /Identity >>",
+            base_font, descriptor_id, widths
+        );
+        let bbox = self.face.global_bounding_box();
+        let descriptor = format!(
+            "<< /Type /FontDescriptor /FontName /{} /Flags 4 /FontBBox [{} {} {} {}] \
+             /ItalicAngle {} /Ascent {} /Descent {} /CapHeight {} /StemV 80 /FontFile2 {} 0 R >>",
+            base_font,
+            self.scaled(f32::from(bbox.x_min)),
+            self.scaled(f32::from(bbox.y_min)),
+            self.scaled(f32::from(bbox.x_max)),
+            self.scaled(f32::from(bbox.y_max)),
+            self.face.italic_angle(),
+            self.scaled(f32::from(self.face.ascender())),
+            self.scaled(f32::from(self.face.descender())),
+            self.scaled(f32::from(
+                self.face
+                    .capital_height()
+                    .unwrap_or(self.face.ascender()),
+            )),
+            file_id
+        );
+
+        let subset = subset(self.data, self.used.keys().copied())?;
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&subset)?;
+        let compressed = encoder.finish()?;
+        let mut file = format!(
+            "<< /Length {} /Length1 {} /Filter /FlateDecode >>\nstream\n",
+            compressed.len(),
+            subset.len()
+        )
+        .into_bytes();
+        file.extend_from_slice(&compressed);
+        file.extend_from_slice(b"\nendstream");
+
+        Ok(vec![
+            (cid_font_id, cid_font.into_bytes()),
+            (descriptor_id, descriptor.into_bytes()),
+            (file_id, file),
+            (to_unicode_id, stream(self.to_unicode().as_bytes())),
+            (font_id, font.into_bytes()),
+        ])
+    }
+
+    /// A value in font units, in PDF glyph space.
+    fn scaled(&self, units: f32) -> i32 {
+        (units * GLYPH_SPACE / f32::from(self.face.units_per_em())).round() as i32
+    }
+
+    /// Six letters naming the subset, the same for the same glyphs.
+    fn subset_tag(&self) -> String {
+        // FNV-1a of the glyph ids
+        let mut hash: u32 = 0x811c_9dc5;
+        for &glyph in self.used.keys() {
+            for byte in glyph.to_be_bytes() {
+                hash = (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193);
+            }
+        }
+        (0..6)
+            .map(|_| {
+                let letter = (b'A' + (hash % 26) as u8) as char;
+                hash /= 26;
+                letter
+            })
+            .collect()
+    }
+
+    /// The CMap from the glyph ids to the characters they show.
+    fn to_unicode(&self) -> String {
+        let mut cmap = String::from(
+            "/CIDInit /ProcSet findresource begin\n12 dict begin\nbegincmap\n\
+             /CIDSystemInfo << /Registry (Adobe) /Ordering (UCS) /Supplement 0 >> def\n\
+             /CMapName /Adobe-Identity-UCS def\n/CMapType 2 def\n\
+             1 begincodespacerange\n<0000> <FFFF>\nendcodespacerange\n",
+        );
+        let used: Vec<(&u16, &char)> = self.used.iter().collect();
+        // At most 100 mappings a block
+        for block in used.chunks(100) {
+            cmap.push_str(&format!("{} beginbfchar\n", block.len()));
+            for (glyph, c) in block {
+                let utf16: String = c
+                    .encode_utf16(&mut [0; 2])
+                    .iter()
+                    .map(|unit| format!("{:04X}", unit))
+                    .collect();
+                cmap.push_str(&format!("<{:04X}> <{}>\n", glyph, utf16));
+            }
+            cmap.push_str("endbfchar\n");
+        }
+        cmap.push_str(
+            "endcmap\nCMapName currentdict /CMapResource defineresource pop\nend\nend",
+        );
+        cmap
+    }
+}
+
+fn stream(content: &[u8]) -> Vec<u8> {
+    let mut stream = format!("<< /Length {} >>\nstream\n", content.len()).into_bytes();
+    stream.extend_from_slice(content);
+    stream.extend_from_slice(b"\nendstream");
+    stream
+}
+
+fn font_error(table: &str) -> ReportRenderError {
+    ReportRenderError::Font(format!("invalid {} table", table))
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    data.get(offset..offset + 2)
+        .map(|bytes| u16::from_be_bytes([bytes[0], bytes[1]]))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    data.get(offset..offset + 4)
+        .map(|bytes| u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+/// Copies the font with the outlines of the `used` glyphs only (and of the glyphs they are
+/// composed of, and of `.notdef`), keeping the embedded tables.
+fn subset(data: &[u8], used: impl Iterator<Item = u16>) -> Result<Vec<u8>, ReportRenderError> {
+    let raw = RawFace::parse(data, 0).map_err(|_| font_error("directory"))?;
+    let table = |tag: &[u8; 4]| raw.table(Tag::from_bytes(tag));
+    let head = table(b"head").ok_or_else(|| font_error("head"))?;
+    let maxp = table(b"maxp").ok_or_else(|| font_error("maxp"))?;
+    let loca = table(b"loca").ok_or_else(|| font_error("loca"))?;
+    let glyf = table(b"glyf").ok_or_else(|| font_error("glyf"))?;
+
+    let glyph_count = usize::from(read_u16(maxp, 4).ok_or_else(|| font_error("maxp"))?);
+    let long_offsets = read_u16(head, 50).ok_or_else(|| font_error("head"))? == 1;
+    let offsets = (0..=glyph_count)
+        .map(|glyph| match long_offsets {
+            true => read_u32(loca, glyph * 4).map(|offset| offset as usize),
+            false => read_u16(loca, glyph * 2).map(|offset| usize::from(offset) * 2),
+        })
+        .collect::<Option<Vec<usize>>>()
+        .ok_or_else(|| font_error("loca"))?;
+    let outline = |glyph: usize| -> Result<&[u8], ReportRenderError> {
+        glyf.get(offsets[glyph]..offsets[glyph + 1])
+            .ok_or_else(|| font_error("glyf"))
+    };
+
+    // The used glyphs and their components
+    let mut kept = BTreeSet::new();
+    let mut pending: Vec<usize> = std::iter::once(0).chain(used.map(usize::from)).collect();
+    while let Some(glyph) = pending.pop() {
+        if glyph < glyph_count && kept.insert(glyph) {
+            pending.extend(components(outline(glyph)?)?);
+        }
+    }
+
+    // Outlines of the kept glyphs, long offsets
+    let mut new_glyf = Vec::new();
+    let mut new_loca = Vec::with_capacity((glyph_count + 1) * 4);
+    for glyph in 0..glyph_count {
+        new_loca.extend_from_slice(&(new_glyf.len() as u32).to_be_bytes());
+        if kept.contains(&glyph) {
+            new_glyf.extend_from_slice(outline(glyph)?);
+            new_glyf.resize(new_glyf.len().next_multiple_of(4), 0);
+        }
+    }
+    new_loca.extend_from_slice(&(new_glyf.len() as u32).to_be_bytes());
+    let mut new_head = head.to_vec();
+    new_head[8..12].fill(0);
+    new_head[50..52].copy_from_slice(&1u16.to_be_bytes());
+
+    let tables: Vec<(&[u8; 4], Vec<u8>)> = EMBEDDED_TABLES
+        .iter()
+        .filter_map(|&tag| match tag {
+            b"glyf" => Some((tag, new_glyf.clone())),
+            b"loca" => Some((tag, new_loca.clone())),
+            b"head" => Some((tag, new_head.clone())),
+            _ => table(tag).map(|data| (tag, data.to_vec())),
+        })
+        .collect();
+    Ok(font_file(&tables))
+}
+
+/// The glyphs a composite glyph is made of, none for a simple one.
+fn components(outline: &[u8]) -> Result<Vec<usize>, ReportRenderError> {
+    // An empty glyph, or a simple one (a positive number of contours)
+    if outline.len() < 10 || outline[0] & 0x80 == 0 {
+        return Ok(Vec::new());
+    }
+    let mut components = Vec::new();
+    let mut offset = 10;
+    loop {
+        let flags = read_u16(outline, offset).ok_or_else(|| font_error("glyf"))?;
+        let glyph = read_u16(outline, offset + 2).ok_or_else(|| font_error("glyf"))?;
+        components.push(usize::from(glyph));
+        offset += 4;
+        offset += if flags & ARG_1_AND_2_ARE_WORDS != 0 { 4 } else { 2 };
+        if flags & WE_HAVE_A_SCALE != 0 {
+            offset += 2;
+        } else if flags & WE_HAVE_AN_X_AND_Y_SCALE != 0 {
+            offset += 4;
+        } else if flags & WE_HAVE_A_TWO_BY_TWO != 0 {
+            offset += 8;
+        }
+        if flags & MORE_COMPONENTS == 0 {
+            return Ok(components);
+        }
+    }
+}
+
+fn checksum(data: &[u8]) -> u32 {
+    data.chunks(4)
+        .map(|chunk| {
+            let mut word = [0; 4];
+            word[..chunk.len()].copy_from_slice(chunk);
+            u32::from_be_bytes(word)
+        })
+        .fold(0, u32::wrapping_add)
+}
+
+/// Writes a TrueType font of `tables`, sorted by tag.
+fn font_file(tables: &[(&[u8; 4], Vec<u8>)]) -> Vec<u8> {
+    let count = tables.len() as u16;
+    let power = 1u16 << (15 - count.leading_zeros());
+    let mut font = Vec::new();
+    font.extend_from_slice(&0x0001_0000u32.to_be_bytes());
+    for value in [count, power * 16, power.trailing_zeros() as u16, (count - power) * 16] {
+        font.extend_from_slice(&value.to_be_bytes());
+    }
+
+    let mut offset = 12 + 16 * tables.len();
+    let mut head_offset = None;
+    for (tag, data) in tables {
+        if *tag == b"head" {
+            head_offset = Some(offset);
+        }
+        font.extend_from_slice(*tag);
+        font.extend_from_slice(&checksum(data).to_be_bytes());
+        font.extend_from_slice(&(offset as u32).to_be_bytes());
+        font.extend_from_slice(&(data.len() as u32).to_be_bytes());
+        offset += data.len().next_multiple_of(4);
+    }
+    for (_, data) in tables {
+        font.extend_from_slice(data);
+        font.resize(font.len().next_multiple_of(4), 0);
+    }
+
+    if let Some(head) = head_offset {
+        let adjustment = 0xB1B0_AFBAu32.wrapping_sub(checksum(&font));
+        font[head + 8..head + 12].copy_from_slice(&adjustment.to_be_bytes());
+    }
+    font
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::read::ZlibDecoder;
+    use std::io::Read;
+
+    #[test]
+    fn test_text_is_encoded_as_glyph_ids() {
+        let mut font = PdfFont::regular();
+        let a = font.face.glyph_index('A').unwrap().0;
+        let kazakh = font.face.glyph_index('Қ').unwrap().0;
+        assert_eq!(
+            font.encode("AҚA").unwrap(),
+            format!("<{:04X}{:04X}{:04X}>", a, kazakh, a)
+        );
+        assert!(matches!(
+            font.encode("A漢"),
+            Err(ReportRenderError::UnsupportedCharacter('漢'))
+        ));
+        assert!(font.to_unicode().contains(&format!("<{:04X}> <049A>", kazakh)));
+    }
+
+    #[test]
+    fn test_only_the_used_glyphs_are_embedded() {
+        let mut font = PdfFont::regular();
+        // `Й` is composed of `И` and a breve
+        font.encode("Й1").unwrap();
+        let objects = font.objects(3, 10).unwrap();
+        let ids: Vec<usize> = objects.iter().map(|(id, _)| *id).collect();
+        assert_eq!(ids, vec![10, 11, 12, 13, 3]);
+
+        let file = &objects[2].1;
+        let start = file.windows(7).position(|w| w == b"stream\n").unwrap() + 7;
+        let end = file.len() - b"\nendstream".len();
+        let mut subset = Vec::new();
+        ZlibDecoder::new(&file[start..end])
+            .read_to_end(&mut subset)
+            .unwrap();
+        assert!(subset.len() < REGULAR.len() / 4);
+        assert_eq!(checksum(&subset), 0xB1B0_AFBA, "checksum adjustment");
+
+        let face = Face::parse(&subset, 0).unwrap();
+        let has_outline = |c: char| {
+            let glyph = face.glyph_index(c).unwrap();
+            face.glyph_bounding_box(glyph).is_some()
+        };
+        assert!(has_outline('Й') && has_outline('И') && has_outline('1'));
+        assert!(!has_outline('Z') && !has_outline('2'));
+        // Glyph ids and metrics are the ones of the font
+        assert_eq!(face.number_of_glyphs(), font.face.number_of_glyphs());
+        assert_eq!(
+            face.glyph_hor_advance(face.glyph_index('1').unwrap()),
+            font.face.glyph_hor_advance(font.face.glyph_index('1').unwrap())
+        );
+    }
+}
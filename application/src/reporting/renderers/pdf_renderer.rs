@@ -0,0 +1,508 @@
+//! Minimal PDF renderer: a table of text on landscape A4 pages.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! Each page is written to the output as soon as it is full, and the fonts (see `pdf_font`), page
+//! tree, cross-reference table and trailer are written at the end, the fonts holding the glyphs
+//! the pages used.
+use super::{ReportRenderError, ReportRenderer, pdf_font::PdfFont};
+use crate::reporting::models::{report_cell::ReportCell, report_column::ReportColumn};
+use std::io::Write;
+
+const PAGE_WIDTH: f32 = 842.0;
+const PAGE_HEIGHT: f32 = 595.0;
+const MARGIN: f32 = 36.0;
+const TITLE_SIZE: f32 = 14.0;
+const FONT_SIZE: f32 = 7.0;
+const LINE_HEIGHT: f32 = 11.0;
+/// Space left after the text of a cell.
+const CELL_PADDING: f32 = 4.0;
+
+/* Fixed object ids, pages are numbered after them */
+const CATALOG_ID: usize = 1;
+const PAGES_ID: usize = 2;
+const FONT_ID: usize = 3;
+const BOLD_FONT_ID: usize = 4;
+
+/// Font of a text, and its name in the resources of the pages.
+#[derive(Debug, Clone, Copy)]
+enum Style {
+    Regular,
+    Bold,
+}
+
+impl Style {
+    fn resource(self) -> &'static str {
+        match self {
+            Style::Regular => "F1",
+            Style::Bold => "F2",
+        }
+    }
+}
+
+/// Renders a report as a PDF table.
+pub struct PdfReportRenderer<W: Write> {
+    output: W,
+    /// Number of bytes written so far, needed for the cross-reference table.
+    position: u64,
+    /// Byte offset of every written object, indexed by object id.
+    offsets: Vec<u64>,
+    page_ids: Vec<usize>,
+    regular: PdfFont,
+    bold: PdfFont,
+    title: String,
+    headers: Vec<&'static str>,
+    /// Content stream of the page being filled.
+    content: Vec<u8>,
+    y: f32,
+}
+
+impl<W: Write> PdfReportRenderer<W> {
+    pub fn new(output: W) -> Self {
+        PdfReportRenderer {
+            output,
+            position: 0,
+            offsets: vec![0; BOLD_FONT_ID + 1],
+            page_ids: Vec::new(),
+            regular: PdfFont::regular(),
+            bold: PdfFont::bold(),
+            title: String::new(),
+            headers: Vec::new(),
+            content: Vec::new(),
+            y: 0.0,
+        }
+    }
+
+    fn write_raw(&mut self, bytes: &[u8]) -> Result<(), ReportRenderError> {
+        self.output.write_all(bytes)?;
+        self.position += bytes.len() as u64;
+        Ok(())
+    }
+
+    fn write_object(&mut self, id: usize, body: &[u8]) -> Result<(), ReportRenderError> {
+        if self.offsets.len() <= id {
+            self.offsets.resize(id + 1, 0);
+        }
+        self.offsets[id] = self.position;
+        self.write_raw(format!("{} 0 obj\n", id).as_bytes())?;
+        self.write_raw(body)?;
+        self.write_raw(b"\nendobj\n")
+    }
+
+    fn next_id(&self) -> usize {
+        self.offsets.len()
+    }
+
+    fn column_width(&self) -> f32 {
+        (PAGE_WIDTH - 2.0 * MARGIN) / self.headers.len().max(1) as f32
+    }
+
+    fn font(&mut self, style: Style) -> &mut PdfFont {
+        match style {
+            Style::Regular => &mut self.regular,
+            Style::Bold => &mut self.bold,
+        }
+    }
+
+    fn start_page(&mut self) -> Result<(), ReportRenderError> {
+        self.content.clear();
+        self.y = PAGE_HEIGHT - MARGIN;
+
+        if self.page_ids.is_empty() {
+            let title = self.title.clone();
+            self.text(Style::Bold, TITLE_SIZE, MARGIN, self.y, &title)?;
+            self.y -= TITLE_SIZE * 2.0;
+        }
+
+        let headers = self.headers.clone();
+        self.cells(Style::Bold, &headers)?;
+        // Rule under the header
+        let rule_y = self.y + LINE_HEIGHT - FONT_SIZE - 2.0;
+        self.content.extend_from_slice(
+            format!("{} {} m {} {} l S\n", MARGIN, rule_y, PAGE_WIDTH - MARGIN, rule_y).as_bytes(),
+        );
+        Ok(())
+    }
+
+    fn end_page(&mut self) -> Result<(), ReportRenderError> {
+        let content_id = self.next_id();
+        let page_id = content_id + 1;
+
+        let mut stream = format!("<< /Length {} >>\nstream\n", self.content.len()).into_bytes();
+        stream.extend_from_slice(&self.content);
+        stream.extend_from_slice(b"\nendstream");
+        self.write_object(content_id, &stream)?;
+
+        let page = format!(
+            "<< /Type /Page /Parent {} 0 R /MediaBox [0 0 {} {}] \
+             /Resources << /Font << /{} {} 0 R /{} {} 0 R >> >> /Contents {} 0 R >>",
+            PAGES_ID,
+            PAGE_WIDTH,
+            PAGE_HEIGHT,
+            Style::Regular.resource(),
+            FONT_ID,
+            Style::Bold.resource(),
+            BOLD_FONT_ID,
+            content_id
+        );
+        self.write_object(page_id, page.as_bytes())?;
+        self.page_ids.push(page_id);
+
+        Ok(())
+    }
+
+    /// Writes one line of cells, truncating each one to the column width.
+    fn cells<T: AsRef<str>>(
+        &mut self,
+        style: Style,
+        values: &[T],
+    ) -> Result<(), ReportRenderError> {
+        let width = self.column_width();
+        let available = width - CELL_PADDING;
+
+        for (i, value) in values.iter().enumerate() {
+            let value = value.as_ref();
+            let font = self.font(style);
+            let text = if font.width(value, FONT_SIZE) > available {
+                let mut truncated = String::new();
+                for c in value.chars() {
+                    truncated.push(c);
+                    if font.width(&truncated, FONT_SIZE) + font.width("..", FONT_SIZE) > available {
+                        truncated.pop();
+                        break;
+                    }
+                }
+                truncated.push_str("..");
+                truncated
+            } else {
+                value.to_string()
+            };
+            self.text(style, FONT_SIZE, MARGIN + i as f32 * width, self.y, &text)?;
+        }
+        self.y -= LINE_HEIGHT;
+        Ok(())
+    }
+
+    fn text(
+        &mut self,
+        style: Style,
+        size: f32,
+        x: f32,
+        y: f32,
+        text: &str,
+    ) -> Result<(), ReportRenderError> {
+        let encoded = self.font(style).encode(text)?;
+        self.content.extend_from_slice(
+            format!(
+                "BT /{} {} Tf {} {} Td {} Tj ET\n",
+                style.resource(),
+                size,
+                x,
+                y,
+                encoded
+            )
+            .as_bytes(),
+        );
+        Ok(())
+    }
+}
+
+impl<W: Write + Send> ReportRenderer for PdfReportRenderer<W> {
+    fn begin(&mut self, title: &str, columns: &[ReportColumn]) -> Result<(), ReportRenderError> {
+        self.title = title.to_string();
+        self.headers = columns.iter().map(|column| column.header()).collect();
+
+        self.write_raw(b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n")?;
+        self.start_page()
+    }
+
+    fn write_row(&mut self, cells: &[ReportCell]) -> Result<(), ReportRenderError> {
+        if self.y < MARGIN {
+            self.end_page()?;
+            self.start_page()?;
+        }
+
+        let values: Vec<String> = cells.iter().map(|cell| cell.to_string()).collect();
+        self.cells(Style::Regular, &values)
+    }
+
+    fn finish(&mut self) -> Result<(), ReportRenderError> {
+        self.end_page()?;
+
+        let fonts = [
+            self.regular.objects(FONT_ID, self.next_id())?,
+            self.bold.objects(BOLD_FONT_ID, self.next_id() + 4)?,
+        ];
+        for (id, body) in fonts.iter().flatten() {
+            self.write_object(*id, body)?;
+        }
+
+        let kids = self
+            .page_ids
+            .iter()
+            .map(|id| format!("{} 0 R", id))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let pages = format!(
+            "<< /Type /Pages /Kids [{}] /Count {} >>",
+            kids,
+            self.page_ids.len()
+        );
+        self.write_object(PAGES_ID, pages.as_bytes())?;
+        let catalog = format!("<< /Type /Catalog /Pages {} 0 R >>", PAGES_ID);
+        self.write_object(CATALOG_ID, catalog.as_bytes())?;
+
+        let xref_position = self.position;
+        let mut xref = format!("xref\n0 {}\n0000000000 65535 f \n", self.offsets.len());
+        for offset in &self.offsets[1..] {
+            xref.push_str(&format!("{:010} 00000 n \n", offset));
+        }
+        xref.push_str(&format!(
+            "trailer\n<< /Size {} /Root {} 0 R >>\nstartxref\n{}\n%%EOF\n",
+            self.offsets.len(),
+            CATALOG_ID,
+            xref_position
+        ));
+        self.write_raw(xref.as_bytes())?;
+        self.output.flush()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    /// Renders `rows` rows of two columns.
+    fn render(rows: usize) -> Vec<u8> {
+        let mut output = Vec::new();
+        let mut renderer = PdfReportRenderer::new(&mut output);
+        renderer
+            .begin(
+                "Fleet (KZ)",
+                &[ReportColumn::LicensePlate, ReportColumn::Details],
+            )
+            .expect("header written");
+        for index in 0..rows {
+            renderer
+                .write_row(&[
+                    format!("{:03}ABC02", index).into(),
+                    "Oil \\ (filter)".into(),
+                ])
+                .expect("row written");
+        }
+        renderer.finish().expect("report finished");
+        drop(renderer);
+        output
+    }
+
+    /// The document as text, one char per byte, so the offsets of the text are the offsets of the
+    /// document.
+    fn as_text(pdf: &[u8]) -> String {
+        pdf.iter()
+            .map(|&byte| if byte.is_ascii() { byte as char } else { '?' })
+            .collect()
+    }
+
+    /// Reads the cross-reference table, the offsets of the objects from 1 on.
+    fn parse_xref(pdf: &[u8]) -> Vec<usize> {
+        let text = as_text(pdf);
+        let startxref = text.rfind("startxref\n").expect("startxref") + "startxref\n".len();
+        let xref: usize = text[startxref..]
+            .lines()
+            .next()
+            .and_then(|line| line.parse().ok())
+            .expect("xref offset");
+        assert!(
+            text[xref..].starts_with("xref\n0 "),
+            "startxref points to the xref"
+        );
+
+        let mut lines = text[xref..].lines().skip(1);
+        let size: usize = lines
+            .next()
+            .and_then(|line| line.split(' ').nth(1))
+            .and_then(|size| size.parse().ok())
+            .expect("xref size");
+        assert!(text.contains(&format!("/Size {} /Root 1 0 R", size)));
+        lines
+            .skip(1)
+            .take(size - 1)
+            .map(|entry| entry[..10].parse().expect("object offset"))
+            .collect()
+    }
+
+    /// Checks the structure of the document and returns its page count.
+    fn parse_page_count(pdf: &[u8]) -> usize {
+        assert!(pdf.starts_with(b"%PDF-1.4\n"), "PDF header");
+        assert!(pdf.ends_with(b"%%EOF\n"), "PDF trailer");
+        let text = as_text(pdf);
+
+        // Every object of the cross-reference table starts at its offset
+        for (id, &offset) in (1..).zip(&parse_xref(pdf)) {
+            assert!(
+                pdf[offset..].starts_with(format!("{} 0 obj\n", id).as_bytes()),
+                "object {} is at {}",
+                id,
+                offset
+            );
+        }
+
+        let pages = text.matches("/Type /Page ").count();
+        let count: usize = text
+            .split("/Count ")
+            .nth(1)
+            .and_then(|rest| rest.split(' ').next())
+            .and_then(|count| count.parse().ok())
+            .expect("page count");
+        assert_eq!(count, pages, "the page tree lists every page");
+        pages
+    }
+
+    /// Reads the characters of the glyph ids of a font from its `ToUnicode` map.
+    fn parse_to_unicode(pdf: &[u8], font_id: usize) -> HashMap<String, char> {
+        let text = as_text(pdf);
+        let offsets = parse_xref(pdf);
+        let object = |id: usize| &text[offsets[id - 1]..];
+        let font = object(font_id);
+        let to_unicode: usize = font[font.find("/ToUnicode ").expect("ToUnicode") + 11..]
+            .split(' ')
+            .next()
+            .and_then(|id| id.parse().ok())
+            .expect("ToUnicode id");
+        let cmap = object(to_unicode);
+        let cmap = &cmap[..cmap.find("endcmap").expect("end of the map")];
+
+        let mut characters = HashMap::new();
+        for line in cmap
+            .lines()
+            .skip_while(|line| !line.ends_with("beginbfchar"))
+        {
+            if let Some((glyph, utf16)) = line.split_once("> <") {
+                let utf16: Vec<u16> = utf16
+                    .trim_end_matches('>')
+                    .as_bytes()
+                    .chunks(4)
+                    .map(|unit| u16::from_str_radix(std::str::from_utf8(unit).unwrap(), 16))
+                    .collect::<Result<_, _>>()
+                    .expect("UTF-16 code units");
+                let c = char::decode_utf16(utf16)
+                    .next()
+                    .unwrap()
+                    .expect("character");
+                characters.insert(glyph.trim_start_matches('<').to_string(), c);
+            }
+        }
+        characters
+    }
+
+    /// Returns the texts shown on the pages, with their font resource.
+    fn parse_shown(pdf: &[u8]) -> Vec<(String, String)> {
+        let fonts = [
+            ("F1", parse_to_unicode(pdf, FONT_ID)),
+            ("F2", parse_to_unicode(pdf, BOLD_FONT_ID)),
+        ];
+        as_text(pdf)
+            .lines()
+            .filter_map(|line| line.strip_prefix("BT /"))
+            .map(|line| {
+                let (name, characters) = fonts
+                    .iter()
+                    .find(|(name, _)| line.starts_with(name))
+                    .expect("font resource");
+                let glyphs =
+                    &line[line.find('<').expect("hex string") + 1..line.find('>').unwrap()];
+                let shown = glyphs
+                    .as_bytes()
+                    .chunks(4)
+                    .map(|glyph| characters[std::str::from_utf8(glyph).unwrap()])
+                    .collect();
+                (name.to_string(), shown)
+            })
+            .collect()
+    }
+
+    fn shown_count(shown: &[(String, String)], font: &str, text: &str) -> usize {
+        shown
+            .iter()
+            .filter(|(name, shown)| name == font && shown == text)
+            .count()
+    }
+
+    #[test]
+    fn test_page_count() {
+        // The first page holds the title and 45 rows, the next ones 47 rows
+        assert_eq!(parse_page_count(&render(0)), 1);
+        assert_eq!(parse_page_count(&render(45)), 1);
+        assert_eq!(parse_page_count(&render(46)), 2);
+        assert_eq!(parse_page_count(&render(92)), 2);
+        assert_eq!(parse_page_count(&render(93)), 3);
+    }
+
+    #[test]
+    fn test_text_is_shown_as_written() {
+        let shown = parse_shown(&render(1));
+        assert_eq!(shown_count(&shown, "F2", "Fleet (KZ)"), 1, "title");
+        assert_eq!(shown_count(&shown, "F2", "License Plate"), 1, "header");
+        assert_eq!(shown_count(&shown, "F1", "000ABC02"), 1, "cell");
+        assert_eq!(shown_count(&shown, "F1", "Oil \\ (filter)"), 1, "cell");
+    }
+
+    #[test]
+    fn test_unicode_text_is_kept() {
+        let mut output = Vec::new();
+        let mut renderer = PdfReportRenderer::new(&mut output);
+        renderer
+            .begin(
+                "Автопарк Қарағанды",
+                &[ReportColumn::MaintenanceType, ReportColumn::Details],
+            )
+            .expect("header written");
+        renderer
+            .write_row(&[
+                "Замена масла".into(),
+                "Майды ауыстыру, сүзгіні тексеру және тежегіш сұйықтығын толтыру. "
+                    .repeat(3)
+                    .into(),
+            ])
+            .expect("row written");
+        renderer.finish().expect("report finished");
+        drop(renderer);
+
+        let shown = parse_shown(&output);
+        assert_eq!(shown_count(&shown, "F2", "Автопарк Қарағанды"), 1, "title");
+        assert_eq!(shown_count(&shown, "F1", "Замена масла"), 1, "cell");
+        // The long cell is truncated to its column
+        let details = &shown.last().expect("details cell").1;
+        assert!(details.starts_with("Майды ауыстыру"), "{}", details);
+        assert!(details.ends_with(".."), "{}", details);
+    }
+
+    #[test]
+    fn test_unsupported_character_is_an_error() {
+        let mut output = Vec::new();
+        let mut renderer = PdfReportRenderer::new(&mut output);
+        renderer
+            .begin("Fleet", &[ReportColumn::Details])
+            .expect("header written");
+        let error = renderer
+            .write_row(&["机油".into()])
+            .expect_err("no glyph for the character");
+        assert!(matches!(
+            error,
+            ReportRenderError::UnsupportedCharacter('机')
+        ));
+    }
+
+    #[test]
+    fn test_header_is_repeated_on_every_page() {
+        let shown = parse_shown(&render(93));
+        assert_eq!(shown_count(&shown, "F2", "License Plate"), 3);
+        assert_eq!(
+            shown_count(&shown, "F2", "Fleet (KZ)"),
+            1,
+            "title on the first page"
+        );
+    }
+}
@@ -0,0 +1,133 @@
+use super::{ReportRenderError, ReportRenderer};
+use crate::reporting::models::{report_cell::ReportCell, report_column::ReportColumn};
+use rust_xlsxwriter::{Format, Workbook};
+use std::io::Write;
+
+/// Excel limits worksheet names to 31 characters.
+const MAX_SHEET_NAME_LENGTH: usize = 31;
+
+/// Renders a report as a single worksheet XLSX workbook.
+///
+/// The worksheet uses the constant memory mode: rows are flushed to a temporary file as they are
+/// written, and only the final (compressed) workbook is assembled in memory.
+pub struct XlsxReportRenderer<W: Write> {
+    workbook: Workbook,
+    output: W,
+    row: u32,
+}
+
+impl<W: Write> XlsxReportRenderer<W> {
+    pub fn new(output: W) -> Self {
+        XlsxReportRenderer {
+            workbook: Workbook::new(),
+            output,
+            row: 0,
+        }
+    }
+}
+
+impl<W: Write + Send> ReportRenderer for XlsxReportRenderer<W> {
+    fn begin(&mut self, title: &str, columns: &[ReportColumn]) -> Result<(), ReportRenderError> {
+        let bold = Format::new().set_bold();
+        let worksheet = self.workbook.add_worksheet_with_constant_memory();
+        worksheet.set_name(title.chars().take(MAX_SHEET_NAME_LENGTH).collect::<String>())?;
+
+        for (col, column) in (0u16..).zip(columns) {
+            worksheet.write_string_with_format(0, col, column.header(), &bold)?;
+            worksheet.set_column_width(col, column.header().len().max(12) as f64)?;
+        }
+        self.row = 1;
+
+        Ok(())
+    }
+
+    fn write_row(&mut self, cells: &[ReportCell]) -> Result<(), ReportRenderError> {
+        let worksheet = self.workbook.worksheet_from_index(0)?;
+
+        for (col, cell) in (0u16..).zip(cells) {
+            match cell {
+                ReportCell::Empty => {}
+                ReportCell::Integer(value) => {
+                    worksheet.write_number(self.row, col, *value as f64)?;
+                }
+                ReportCell::Text(_) | ReportCell::DateTime(_) => {
+                    worksheet.write_string(self.row, col, cell.to_string())?;
+                }
+            }
+        }
+        self.row += 1;
+
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<(), ReportRenderError> {
+        let buffer = self.workbook.save_to_buffer()?;
+        self.output.write_all(&buffer)?;
+        self.output.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Cursor, Read};
+
+    /// Renders `rows` rows and returns the XML of the worksheet and the workbook.
+    fn render(title: &str, rows: usize) -> (String, String) {
+        let mut output = Vec::new();
+        let mut renderer = XlsxReportRenderer::new(&mut output);
+        renderer
+            .begin(title, &[ReportColumn::LicensePlate, ReportColumn::Year])
+            .expect("header written");
+        for index in 0..rows {
+            renderer
+                .write_row(&[
+                    format!("{:03}ABC02", index).into(),
+                    ReportCell::Integer(2000 + index as i64),
+                ])
+                .expect("row written");
+        }
+        renderer.finish().expect("report finished");
+        drop(renderer);
+
+        let mut archive = zip::ZipArchive::new(Cursor::new(output)).expect("XLSX is a zip");
+        let mut read = |name: &str| {
+            let mut xml = String::new();
+            archive
+                .by_name(name)
+                .expect("part exists")
+                .read_to_string(&mut xml)
+                .expect("part read");
+            xml
+        };
+        (read("xl/worksheets/sheet1.xml"), read("xl/workbook.xml"))
+    }
+
+    #[test]
+    fn test_one_row_per_report_row_and_the_header() {
+        let (sheet, _) = render("Fleet", 250);
+        assert_eq!(sheet.matches("<row ").count(), 251);
+        assert!(sheet.contains("<t>License Plate</t>"), "inline header");
+        assert!(
+            sheet.contains("<v>2249</v>"),
+            "numbers are written as numbers"
+        );
+    }
+
+    #[test]
+    fn test_empty_report_keeps_the_header() {
+        let (sheet, _) = render("Fleet", 0);
+        assert_eq!(sheet.matches("<row ").count(), 1);
+    }
+
+    #[test]
+    fn test_sheet_name_is_truncated() {
+        let (_, workbook) = render("A title much longer than thirty-one characters", 1);
+        assert!(
+            workbook.contains("name=\"A title much longer than thirty\""),
+            "{}",
+            workbook
+        );
+    }
+}
@@ -0,0 +1,29 @@
+use crate::{
+    reporting::models::{
+        report_column::ReportColumn, report_format::ReportFormat, report_kind::ReportKind,
+    },
+    vehicle::filters::vehicle_filter::VehicleFilter,
+};
+
+pub struct ExportMaintenanceReportQuery {
+    pub kind: ReportKind,
+    pub format: ReportFormat,
+    /// Columns to include, in order (`None` means every column available for the report).
+    pub columns: Option<Vec<ReportColumn>>,
+    /// Only maintenance records performed at or after this moment are included.
+    pub from: Option<chrono::DateTime<chrono::Utc>>,
+    /// Only maintenance records performed at or before this moment are included.
+    pub to: Option<chrono::DateTime<chrono::Utc>>,
+    /// Vehicles in scope. Its page size is used as the batch size when walking the fleet.
+    pub vehicle_filter: VehicleFilter,
+}
+
+pub struct ExportMaintenanceReportResponse {
+    pub kind: ReportKind,
+    pub format: ReportFormat,
+    pub columns: Vec<ReportColumn>,
+    /// Number of vehicles the report went through.
+    pub vehicle_count: usize,
+    /// Number of rows written (header excluded).
+    pub row_count: usize,
+}
@@ -0,0 +1,33 @@
+use crate::{
+    reporting::renderers::ReportRenderError,
+    vehicle::traits::vehicle_repository::VehicleApplicationRepositoryError,
+};
+use domain::{
+    maintenance::repositories::{
+        maintenance_record_repository::MaintenanceRecordRepositoryError,
+        maintenance_repository::MaintenanceRepositoryError,
+    },
+    vehicle::repositories::vehicle_status_repository::VehicleStatusRepositoryError,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ExportMaintenanceReportError {
+    #[error("Column '{0}' is not available in this report")]
+    InvalidColumn(String),
+    #[error("No column selected")]
+    NoColumns,
+    #[error("Invalid date range: 'from' must not be after 'to'")]
+    InvalidDateRange,
+    #[error("Invalid vehicle id: {0}")]
+    InvalidVehicleId(String),
+    #[error("Vehicle repository error: {0}")]
+    VehicleRepository(#[from] VehicleApplicationRepositoryError),
+    #[error("Maintenance repository error: {0}")]
+    MaintenanceRepository(#[from] MaintenanceRepositoryError),
+    #[error("Maintenance record repository error: {0}")]
+    MaintenanceRecordRepository(#[from] MaintenanceRecordRepositoryError),
+    #[error("Vehicle status repository error: {0}")]
+    VehicleStatusRepository(#[from] VehicleStatusRepositoryError),
+    #[error("Rendering error: {0}")]
+    Render(#[from] ReportRenderError),
+}
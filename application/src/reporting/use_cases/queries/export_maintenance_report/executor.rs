@@ -0,0 +1,389 @@
+use super::{
+    dto::{ExportMaintenanceReportQuery as Input, ExportMaintenanceReportResponse as Output},
+    error::ExportMaintenanceReportError as Error,
+};
+use crate::{
+    auth::AuthenticatedUser,
+    reporting::{
+        models::{report_cell::ReportCell, report_column::ReportColumn, report_kind::ReportKind},
+        renderers::renderer_for,
+    },
+    shared::pagination::{DEFAULT_PAGE, DEFAULT_PAGE_SIZE},
+    vehicle::{
+        models::vehicle::VehicleView, traits::vehicle_repository::VehicleApplicationRepository,
+    },
+};
+use domain::{
+    maintenance::{
+        entities::{
+            maintenance::Maintenance,
+            maintenance_record::MaintenanceRecord,
+            maintenance_status::{MaintenanceStatus, MaintenanceStatusLevel},
+        },
+        repositories::{
+            maintenance_record_repository::MaintenanceRecordRepository,
+            maintenance_repository::MaintenanceRepository,
+        },
+    },
+    vehicle::{
+        entities::vehicle_status::VehicleStatusIdentity,
+        repositories::vehicle_status_repository::VehicleStatusRepository,
+    },
+};
+use std::{collections::HashMap, io::Write};
+
+pub struct ExportMaintenanceReportUseCase<'a, VAR, MR, MRR, VSR>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    MR: MaintenanceRepository + 'a,
+    MRR: MaintenanceRecordRepository + 'a,
+    VSR: VehicleStatusRepository + 'a,
+{
+    vehicle_repository: &'a VAR,
+    maintenance_repository: &'a MR,
+    maintenance_record_repository: &'a MRR,
+    vehicle_status_repository: &'a VSR,
+}
+
+impl<'a, VAR, MR, MRR, VSR> ExportMaintenanceReportUseCase<'a, VAR, MR, MRR, VSR>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    MR: MaintenanceRepository + 'a,
+    MRR: MaintenanceRecordRepository + 'a,
+    VSR: VehicleStatusRepository + 'a,
+{
+    pub fn new(
+        vehicle_repository: &'a VAR,
+        maintenance_repository: &'a MR,
+        maintenance_record_repository: &'a MRR,
+        vehicle_status_repository: &'a VSR,
+    ) -> Self {
+        ExportMaintenanceReportUseCase {
+            vehicle_repository,
+            maintenance_repository,
+            maintenance_record_repository,
+            vehicle_status_repository,
+        }
+    }
+
+    /// Renders the report into `output`, one page of vehicles at a time.
+    pub async fn execute<W: Write + Send>(
+        &self,
+        query: Input,
+        output: W,
+        user: &AuthenticatedUser,
+    ) -> Result<Output, Error> {
+        if let (Some(from), Some(to)) = (query.from, query.to)
+            && from > to
+        {
+            return Err(Error::InvalidDateRange);
+        }
+
+        let organization_id = user.organization_id;
+        let columns = select_columns(query.kind, query.columns)?;
+        let range = (query.from, query.to);
+        let today = chrono::Utc::now().date_naive();
+
+        let mut renderer = renderer_for(query.format, output);
+        renderer.begin(query.kind.title(), &columns)?;
+
+        let mut filter = query.vehicle_filter.scoped_to(user);
+        filter.page = DEFAULT_PAGE;
+        if filter.page_size == 0 {
+            filter.page_size = DEFAULT_PAGE_SIZE;
+        }
+
+        let mut vehicle_count = 0;
+        let mut row_count = 0;
+        loop {
+            let vehicles = self
+                .vehicle_repository
+                .get_by_filter(organization_id, filter.clone())
+                .await?;
+            let fetched = vehicles.len();
+
+            for vehicle in vehicles {
+                let vehicle_id = uuid::Uuid::parse_str(&vehicle.id)
+                    .map_err(|_| Error::InvalidVehicleId(vehicle.id.clone()))?;
+
+                let rows = match query.kind {
+                    ReportKind::VehicleHistory => {
+                        self.history_rows(organization_id, &vehicle, vehicle_id, range, &columns)
+                            .await?
+                    }
+                    ReportKind::FleetSummary => {
+                        self.summary_rows(
+                            organization_id,
+                            &vehicle,
+                            vehicle_id,
+                            range,
+                            &columns,
+                            today,
+                        )
+                        .await?
+                    }
+                    ReportKind::Overdue => {
+                        self.overdue_rows(organization_id, &vehicle, vehicle_id, &columns, today)
+                            .await?
+                    }
+                };
+
+                for row in rows {
+                    renderer.write_row(&row)?;
+                    row_count += 1;
+                }
+                vehicle_count += 1;
+            }
+
+            if fetched < filter.page_size as usize {
+                break;
+            }
+            filter.page += 1;
+        }
+
+        renderer.finish()?;
+
+        Ok(Output {
+            kind: query.kind,
+            format: query.format,
+            columns,
+            vehicle_count,
+            row_count,
+        })
+    }
+
+    async fn history_rows(
+        &self,
+        organization_id: uuid::Uuid,
+        vehicle: &VehicleView,
+        vehicle_id: uuid::Uuid,
+        (from, to): DateRange,
+        columns: &[ReportColumn],
+    ) -> Result<Vec<Vec<ReportCell>>, Error> {
+        let type_names: HashMap<i32, String> = self
+            .maintenance_repository
+            .find_by_vehicle(organization_id, vehicle_id)
+            .await?
+            .into_iter()
+            .map(|rule| (rule.identity.id, rule.maintenance_type.name().to_string()))
+            .collect();
+
+        let records = self
+            .maintenance_record_repository
+            .find_by_vehicle(organization_id, vehicle_id, from, to)
+            .await?;
+
+        Ok(records
+            .iter()
+            .map(|record| {
+                let type_name = type_names.get(&record.identity.maintenance_id);
+                columns
+                    .iter()
+                    .map(|column| history_cell(vehicle, type_name, record, *column))
+                    .collect()
+            })
+            .collect())
+    }
+
+    async fn summary_rows(
+        &self,
+        organization_id: uuid::Uuid,
+        vehicle: &VehicleView,
+        vehicle_id: uuid::Uuid,
+        (from, to): DateRange,
+        columns: &[ReportColumn],
+        today: chrono::NaiveDate,
+    ) -> Result<Vec<Vec<ReportCell>>, Error> {
+        let records = self
+            .maintenance_record_repository
+            .find_by_vehicle(organization_id, vehicle_id, from, to)
+            .await?;
+        let latest_status = self
+            .vehicle_status_repository
+            .find_latest(organization_id, vehicle_id)
+            .await?;
+        let statuses = self
+            .due_statuses(organization_id, vehicle_id, latest_status.as_ref(), today)
+            .await?;
+
+        let count_level = |level: MaintenanceStatusLevel| {
+            statuses
+                .iter()
+                .filter(|(_, status)| status.level == level)
+                .count()
+        };
+        let summary = VehicleSummary {
+            record_count: records.len(),
+            last_performed_at: records.iter().map(|record| record.performed_at()).max(),
+            current_odometer: latest_status.map(|status| status.odometer),
+            rules_total: statuses.len(),
+            rules_yellow: count_level(MaintenanceStatusLevel::Yellow),
+            rules_red: count_level(MaintenanceStatusLevel::Red),
+            rules_overdue: count_level(MaintenanceStatusLevel::Overdue),
+        };
+
+        Ok(vec![
+            columns
+                .iter()
+                .map(|column| summary_cell(vehicle, &summary, *column))
+                .collect(),
+        ])
+    }
+
+    async fn overdue_rows(
+        &self,
+        organization_id: uuid::Uuid,
+        vehicle: &VehicleView,
+        vehicle_id: uuid::Uuid,
+        columns: &[ReportColumn],
+        today: chrono::NaiveDate,
+    ) -> Result<Vec<Vec<ReportCell>>, Error> {
+        let latest_status = self
+            .vehicle_status_repository
+            .find_latest(organization_id, vehicle_id)
+            .await?;
+        Ok(self
+            .due_statuses(organization_id, vehicle_id, latest_status.as_ref(), today)
+            .await?
+            .iter()
+            .filter(|(_, status)| status.is_overdue())
+            .map(|(rule, status)| {
+                columns
+                    .iter()
+                    .map(|column| overdue_cell(vehicle, rule, status, *column))
+                    .collect()
+            })
+            .collect())
+    }
+
+    /// Calculates the due status of every maintenance rule of a vehicle at its latest status.
+    async fn due_statuses(
+        &self,
+        organization_id: uuid::Uuid,
+        vehicle_id: uuid::Uuid,
+        latest_status: Option<&VehicleStatusIdentity>,
+        today: chrono::NaiveDate,
+    ) -> Result<Vec<(Maintenance, MaintenanceStatus)>, Error> {
+        let rules = self
+            .maintenance_repository
+            .find_by_vehicle(organization_id, vehicle_id)
+            .await?;
+
+        let mut statuses = Vec::with_capacity(rules.len());
+        for rule in rules {
+            let last_record = self
+                .maintenance_record_repository
+                .find_latest_by_maintenance(organization_id, rule.identity.id)
+                .await?;
+            let status = MaintenanceStatus::calculate(
+                &rule.identity,
+                last_record.as_ref(),
+                latest_status,
+                today,
+            );
+            statuses.push((rule, status));
+        }
+
+        Ok(statuses)
+    }
+}
+
+/// Optional bounds on the date a maintenance record was performed.
+type DateRange = (
+    Option<chrono::DateTime<chrono::Utc>>,
+    Option<chrono::DateTime<chrono::Utc>>,
+);
+
+/// Aggregated figures of a vehicle for the fleet summary.
+struct VehicleSummary {
+    record_count: usize,
+    last_performed_at: Option<chrono::DateTime<chrono::Utc>>,
+    current_odometer: Option<i32>,
+    rules_total: usize,
+    rules_yellow: usize,
+    rules_red: usize,
+    rules_overdue: usize,
+}
+
+/// Resolves the selected columns, checking they are available in the report.
+fn select_columns(
+    kind: ReportKind,
+    columns: Option<Vec<ReportColumn>>,
+) -> Result<Vec<ReportColumn>, Error> {
+    let available = kind.available_columns();
+    let columns = columns.unwrap_or_else(|| available.to_vec());
+
+    if columns.is_empty() {
+        return Err(Error::NoColumns);
+    }
+    if let Some(invalid) = columns.iter().find(|column| !available.contains(column)) {
+        return Err(Error::InvalidColumn(invalid.as_str().to_string()));
+    }
+
+    Ok(columns)
+}
+
+fn count(value: usize) -> ReportCell {
+    ReportCell::Integer(value as i64)
+}
+
+fn vehicle_cell(vehicle: &VehicleView, column: ReportColumn) -> ReportCell {
+    match column {
+        ReportColumn::LicensePlate => vehicle.license_plate.as_str().into(),
+        ReportColumn::Vin => vehicle.vin.as_str().into(),
+        ReportColumn::Make => vehicle.make.as_str().into(),
+        ReportColumn::Model => vehicle.model.as_str().into(),
+        ReportColumn::Year => ReportCell::Integer(i64::from(vehicle.year)),
+        ReportColumn::EngineType => vehicle.engine_type.as_str().into(),
+        _ => ReportCell::Empty,
+    }
+}
+
+fn history_cell(
+    vehicle: &VehicleView,
+    type_name: Option<&String>,
+    record: &MaintenanceRecord,
+    column: ReportColumn,
+) -> ReportCell {
+    match column {
+        ReportColumn::MaintenanceType => type_name.map(String::as_str).into(),
+        ReportColumn::PerformedAt => record.performed_at().into(),
+        ReportColumn::Odometer => ReportCell::Integer(i64::from(record.vehicle_status.odometer)),
+        ReportColumn::EngineHours => record.vehicle_status.engine_hour_meter.map(i64::from).into(),
+        ReportColumn::PerformedBy => record.user.email.value().into(),
+        ReportColumn::Details => record.details().into(),
+        _ => vehicle_cell(vehicle, column),
+    }
+}
+
+fn summary_cell(vehicle: &VehicleView, summary: &VehicleSummary, column: ReportColumn) -> ReportCell {
+    match column {
+        ReportColumn::CurrentOdometer => summary.current_odometer.map(i64::from).into(),
+        ReportColumn::RecordCount => count(summary.record_count),
+        ReportColumn::LastPerformedAt => summary.last_performed_at.into(),
+        ReportColumn::RulesTotal => count(summary.rules_total),
+        ReportColumn::RulesYellow => count(summary.rules_yellow),
+        ReportColumn::RulesRed => count(summary.rules_red),
+        ReportColumn::RulesOverdue => count(summary.rules_overdue),
+        _ => vehicle_cell(vehicle, column),
+    }
+}
+
+fn overdue_cell(
+    vehicle: &VehicleView,
+    rule: &Maintenance,
+    status: &MaintenanceStatus,
+    column: ReportColumn,
+) -> ReportCell {
+    match column {
+        ReportColumn::MaintenanceType => rule.maintenance_type.name().into(),
+        ReportColumn::IntervalType => rule.identity.interval_type.as_str().into(),
+        ReportColumn::IntervalValue => ReportCell::Integer(i64::from(rule.identity.interval_value)),
+        ReportColumn::LastValue => status.last_value.to_string().into(),
+        ReportColumn::CurrentValue => status.current_value.to_string().into(),
+        ReportColumn::NextValue => status.next_value.to_string().into(),
+        ReportColumn::Percentage => ReportCell::Integer(i64::from(status.percentage)),
+        ReportColumn::Status => status.level.as_str().into(),
+        _ => vehicle_cell(vehicle, column),
+    }
+}
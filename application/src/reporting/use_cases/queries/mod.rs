@@ -0,0 +1 @@
+pub mod export_maintenance_report;
@@ -0,0 +1,13 @@
+//! Full-text and fuzzy search across vehicles, maintenance types and maintenance records.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * Words are matched with Postgres full-text search, stemmed with the English or Russian
+//!   configuration (picked from the alphabet of the search term when not given).
+//! * Identifiers and typos are matched with trigram similarity (`pg_trgm`), a hit needs a
+//!   similarity of at least `min_similarity`.
+//! * Hits are ranked by the full-text rank plus the similarity, the best first, and carry a
+//!   snippet where the matched words are wrapped in `HIGHLIGHT_START`/`HIGHLIGHT_END`.
+pub mod models;
+pub mod traits;
+pub mod use_cases;
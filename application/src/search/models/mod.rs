@@ -0,0 +1,3 @@
+pub mod search_hit;
+pub mod search_language;
+pub mod text_search;
@@ -0,0 +1,64 @@
+use std::str::FromStr;
+
+/// What a search hit is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchHitKind {
+    Vehicle,
+    MaintenanceType,
+    MaintenanceRecord,
+}
+
+impl SearchHitKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchHitKind::Vehicle => "vehicle",
+            SearchHitKind::MaintenanceType => "maintenance_type",
+            SearchHitKind::MaintenanceRecord => "maintenance_record",
+        }
+    }
+
+    pub fn all() -> [SearchHitKind; 3] {
+        [
+            SearchHitKind::Vehicle,
+            SearchHitKind::MaintenanceType,
+            SearchHitKind::MaintenanceRecord,
+        ]
+    }
+}
+
+impl FromStr for SearchHitKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "vehicle" => Ok(Self::Vehicle),
+            "maintenance_type" => Ok(Self::MaintenanceType),
+            "maintenance_record" => Ok(Self::MaintenanceRecord),
+            _ => Err(format!("Invalid search kind: {}", s)),
+        }
+    }
+}
+
+/// How a hit matched the search term.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchMatch {
+    /// The words of the term were found (stemmed).
+    FullText,
+    /// The term is similar to a value (typo, partial identifier).
+    Fuzzy,
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchHit {
+    pub kind: SearchHitKind,
+    /// Id of the vehicle (UUID), maintenance type or maintenance record.
+    pub id: String,
+    /// Short label of the hit (e.g., "Toyota Camry 123ABC02", "Oil Change").
+    pub title: String,
+    /// Excerpt of the matched text, the matched words are highlighted.
+    pub snippet: String,
+    /// Relevance, higher is better; only comparable within a single search.
+    pub rank: f32,
+    pub matched_by: SearchMatch,
+}
@@ -0,0 +1,41 @@
+use std::str::FromStr;
+
+/// Text search configurations of the indexed columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchLanguage {
+    English,
+    Russian,
+}
+
+impl SearchLanguage {
+    /// Returns the Postgres text search configuration (`regconfig`) of the language.
+    pub fn pg_config(&self) -> &'static str {
+        match self {
+            SearchLanguage::English => "english",
+            SearchLanguage::Russian => "russian",
+        }
+    }
+
+    /// Guesses the language of a search term from its alphabet.
+    pub fn detect(term: &str) -> Self {
+        let cyrillic = term.chars().any(|c| matches!(c, '\u{0400}'..='\u{04FF}'));
+        if cyrillic {
+            SearchLanguage::Russian
+        } else {
+            SearchLanguage::English
+        }
+    }
+}
+
+impl FromStr for SearchLanguage {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "english" | "en" => Ok(Self::English),
+            "russian" | "ru" => Ok(Self::Russian),
+            _ => Err(format!("Invalid search language: {}", s)),
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use super::{search_hit::SearchHitKind, search_language::SearchLanguage};
+
+pub const DEFAULT_SEARCH_LIMIT: usize = 20;
+pub const MAX_SEARCH_LIMIT: usize = 100;
+/// Minimum trigram similarity of a fuzzy hit (the `pg_trgm` default).
+pub const DEFAULT_MIN_SIMILARITY: f32 = 0.3;
+/// Markers around the matched words of a snippet (`ts_headline` `StartSel`/`StopSel`).
+pub const HIGHLIGHT_START: &str = "<mark>";
+pub const HIGHLIGHT_END: &str = "</mark>";
+
+/// A search term with its options, validated.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextSearch {
+    pub term: String,
+    pub language: SearchLanguage,
+    pub min_similarity: f32,
+    pub limit: usize,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TextSearchError {
+    #[error("Invalid search term: must not be empty")]
+    EmptyTerm,
+    #[error("Invalid minimum similarity: {0} (must be between 0 and 1)")]
+    InvalidSimilarity(f32),
+}
+
+impl TextSearch {
+    /// Creates a search, the language is detected from the term when not given.
+    pub fn new(
+        term: &str,
+        language: Option<SearchLanguage>,
+        min_similarity: Option<f32>,
+        limit: Option<usize>,
+    ) -> Result<Self, TextSearchError> {
+        let term = term.split_whitespace().collect::<Vec<_>>().join(" ");
+        if term.is_empty() {
+            return Err(TextSearchError::EmptyTerm);
+        }
+        let min_similarity = min_similarity.unwrap_or(DEFAULT_MIN_SIMILARITY);
+        if !(0.0..=1.0).contains(&min_similarity) {
+            return Err(TextSearchError::InvalidSimilarity(min_similarity));
+        }
+
+        Ok(TextSearch {
+            language: language.unwrap_or_else(|| SearchLanguage::detect(&term)),
+            term,
+            min_similarity,
+            limit: limit
+                .unwrap_or(DEFAULT_SEARCH_LIMIT)
+                .clamp(1, MAX_SEARCH_LIMIT),
+        })
+    }
+}
+
+/// Kinds searched when none is given.
+pub fn default_kinds() -> Vec<SearchHitKind> {
+    SearchHitKind::all().to_vec()
+}
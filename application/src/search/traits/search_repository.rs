@@ -0,0 +1,37 @@
+use crate::search::models::{search_hit::SearchHit, text_search::TextSearch};
+use std::future::Future;
+
+#[derive(Debug, thiserror::Error)]
+pub enum SearchRepositoryError {
+    #[error("database error: {0}")]
+    DatabaseError(String),
+}
+
+/// Repository trait for ranked text search, every method returns at most `search.limit` hits,
+/// the best first
+pub trait SearchRepository: Send + Sync {
+    /// Searches maintenance types by name and description (deprecated types excluded)
+    fn search_maintenance_types(
+        &self,
+        organization_id: uuid::Uuid,
+        search: &TextSearch,
+    ) -> impl Future<Output = Result<Vec<SearchHit>, SearchRepositoryError>> + Send;
+
+    /// Searches vehicles by make, model, license plate and VIN, of the vehicles based at
+    /// `depot_scope` only when given
+    fn search_vehicles(
+        &self,
+        organization_id: uuid::Uuid,
+        search: &TextSearch,
+        depot_scope: Option<&[uuid::Uuid]>,
+    ) -> impl Future<Output = Result<Vec<SearchHit>, SearchRepositoryError>> + Send;
+
+    /// Searches maintenance records by their details, of the vehicles based at
+    /// `depot_scope` only when given
+    fn search_maintenance_records(
+        &self,
+        organization_id: uuid::Uuid,
+        search: &TextSearch,
+        depot_scope: Option<&[uuid::Uuid]>,
+    ) -> impl Future<Output = Result<Vec<SearchHit>, SearchRepositoryError>> + Send;
+}
@@ -0,0 +1,24 @@
+use crate::search::models::{
+    search_hit::{SearchHit, SearchHitKind},
+    search_language::SearchLanguage,
+};
+
+pub struct GlobalSearchQuery {
+    pub term: String,
+    /// Text search configuration, detected from the term when `None`.
+    pub language: Option<SearchLanguage>,
+    /// Kinds of hits to return, every kind when empty.
+    pub kinds: Vec<SearchHitKind>,
+    pub min_similarity: Option<f32>,
+    pub limit: Option<usize>,
+}
+
+pub struct GlobalSearchResponse {
+    pub term: String,
+    pub language: SearchLanguage,
+    /// Hits of every kind, the best first.
+    pub hits: Vec<SearchHit>,
+    pub vehicles: usize,
+    pub maintenance_types: usize,
+    pub maintenance_records: usize,
+}
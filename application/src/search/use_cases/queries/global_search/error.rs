@@ -0,0 +1,11 @@
+use crate::search::{
+    models::text_search::TextSearchError, traits::search_repository::SearchRepositoryError,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum GlobalSearchError {
+    #[error("Invalid search: {0}")]
+    InvalidSearch(#[from] TextSearchError),
+    #[error("Repository error: {0}")]
+    Repository(#[from] SearchRepositoryError),
+}
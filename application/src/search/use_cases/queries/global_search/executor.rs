@@ -0,0 +1,77 @@
+use super::{
+    dto::{GlobalSearchQuery as Input, GlobalSearchResponse as Output},
+    error::GlobalSearchError as Error,
+};
+use crate::{
+    auth::AuthenticatedUser,
+    search::{
+        models::{
+            search_hit::SearchHitKind,
+            text_search::{TextSearch, default_kinds},
+        },
+        traits::search_repository::SearchRepository,
+    },
+};
+
+/// Searches vehicles, maintenance types and maintenance records at once.
+pub struct GlobalSearchUseCase<'a, SR: SearchRepository + 'a> {
+    search_repository: &'a SR,
+}
+
+impl<'a, SR: SearchRepository + 'a> GlobalSearchUseCase<'a, SR> {
+    pub fn new(search_repository: &'a SR) -> Self {
+        GlobalSearchUseCase { search_repository }
+    }
+
+    pub async fn execute(&self, query: Input, user: &AuthenticatedUser) -> Result<Output, Error> {
+        let search = TextSearch::new(
+            &query.term,
+            query.language,
+            query.min_similarity,
+            query.limit,
+        )?;
+        let kinds = match query.kinds.is_empty() {
+            true => default_kinds(),
+            false => query.kinds,
+        };
+
+        // Every kind returns its best hits, the overall best are kept; a depot manager only finds
+        // the vehicles of their depots and their records
+        let depot_scope = user.depot_scope.as_deref();
+        let mut hits = Vec::new();
+        for kind in SearchHitKind::all() {
+            if !kinds.contains(&kind) {
+                continue;
+            }
+            hits.extend(match kind {
+                SearchHitKind::Vehicle => {
+                    self.search_repository
+                        .search_vehicles(user.organization_id, &search, depot_scope)
+                        .await?
+                }
+                SearchHitKind::MaintenanceType => {
+                    self.search_repository
+                        .search_maintenance_types(user.organization_id, &search)
+                        .await?
+                }
+                SearchHitKind::MaintenanceRecord => {
+                    self.search_repository
+                        .search_maintenance_records(user.organization_id, &search, depot_scope)
+                        .await?
+                }
+            });
+        }
+        hits.sort_by(|a, b| b.rank.total_cmp(&a.rank));
+        hits.truncate(search.limit);
+
+        let count = |kind: SearchHitKind| hits.iter().filter(|hit| hit.kind == kind).count();
+        Ok(Output {
+            vehicles: count(SearchHitKind::Vehicle),
+            maintenance_types: count(SearchHitKind::MaintenanceType),
+            maintenance_records: count(SearchHitKind::MaintenanceRecord),
+            term: search.term,
+            language: search.language,
+            hits,
+        })
+    }
+}
@@ -0,0 +1 @@
+pub mod global_search;
@@ -0,0 +1,165 @@
+//! CSV records read from an asynchronous source.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * The `csv` reader pulls from a blocking `std::io::Read`; an upload is read here with
+//!   `AsyncRead` instead and fed chunk by chunk to the `csv_core` parser, so a large file never
+//!   blocks the executor of the use case.
+//! * Fields are trimmed, the number of fields may vary from a record to the next and blank lines
+//!   are skipped (as `csv` does with `Trim::All` and `flexible`).
+//! * A record is returned with the line it starts on, the header being line 1.
+
+use csv_core::ReadRecordResult;
+use futures::io::{AsyncRead, AsyncReadExt};
+
+const CHUNK_SIZE: usize = 8 * 1024;
+
+pub struct CsvStream<R> {
+    source: R,
+    parser: csv_core::Reader,
+    chunk: Vec<u8>,
+    /// Unparsed part of `chunk`
+    start: usize,
+    end: usize,
+    eof: bool,
+    /// Line of the next unparsed byte
+    line: u64,
+}
+
+impl<R: AsyncRead + Unpin> CsvStream<R> {
+    pub fn new(source: R) -> Self {
+        CsvStream {
+            source,
+            parser: csv_core::Reader::new(),
+            chunk: vec![0; CHUNK_SIZE],
+            start: 0,
+            end: 0,
+            eof: false,
+            line: 1,
+        }
+    }
+
+    /// Reads the next record and the line it starts on, `None` at the end of the source.
+    pub async fn read_record(&mut self) -> Result<Option<(u64, csv::StringRecord)>, csv::Error> {
+        let mut line = None;
+        let mut output = vec![0; 256];
+        let mut ends = vec![0; 16];
+        let (mut written, mut ended) = (0, 0);
+
+        loop {
+            if self.start == self.end && !self.eof {
+                self.start = 0;
+                self.end = self.source.read(&mut self.chunk).await?;
+                // An empty input tells the parser the source is over
+                self.eof = self.end == 0;
+            }
+
+            // The terminators before a record (blank lines included) are not part of it
+            if line.is_none() {
+                while self.start < self.end && matches!(self.chunk[self.start], b'\r' | b'\n') {
+                    if self.chunk[self.start] == b'\n' {
+                        self.line += 1;
+                    }
+                    self.start += 1;
+                }
+                if self.start == self.end && !self.eof {
+                    continue;
+                }
+                line = Some(self.line);
+            }
+
+            let input = &self.chunk[self.start..self.end];
+            let (result, read, out, end) =
+                self.parser
+                    .read_record(input, &mut output[written..], &mut ends[ended..]);
+            self.line += input[..read].iter().filter(|&&byte| byte == b'\n').count() as u64;
+            self.start += read;
+            written += out;
+            ended += end;
+
+            match result {
+                ReadRecordResult::InputEmpty => {}
+                ReadRecordResult::OutputFull => output.resize(output.len() * 2, 0),
+                ReadRecordResult::OutputEndsFull => ends.resize(ends.len() * 2, 0),
+                ReadRecordResult::Record => {
+                    let line = line.unwrap_or(self.line);
+                    return record(&output, &ends[..ended]).map(|record| Some((line, record)));
+                }
+                ReadRecordResult::End => return Ok(None),
+            }
+        }
+    }
+}
+
+/// The trimmed record of the fields ending at `ends` in `output`.
+fn record(output: &[u8], ends: &[usize]) -> Result<csv::StringRecord, csv::Error> {
+    let mut start = 0;
+    let fields: Vec<&[u8]> = ends
+        .iter()
+        .map(|&end| {
+            let field = &output[start..end];
+            start = end;
+            field
+        })
+        .collect();
+    let mut record = csv::StringRecord::from_byte_record(csv::ByteRecord::from(fields))
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+    record.trim();
+    Ok(record)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn read_all(source: &[u8]) -> Vec<(u64, Vec<String>)> {
+        let mut stream = CsvStream::new(source);
+        let mut records = Vec::new();
+        while let Some((line, record)) = stream.read_record().await.expect("valid CSV") {
+            records.push((line, record.iter().map(str::to_string).collect()));
+        }
+        records
+    }
+
+    fn fields(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn test_records_with_their_lines() {
+        let records = futures::executor::block_on(read_all(
+            b"make, model\n Toyota ,Camry\r\n\n\"Lada\",\"Niva\nLegend\"\nKia,Rio",
+        ));
+        assert_eq!(
+            records,
+            vec![
+                (1, fields(&["make", "model"])),
+                (2, fields(&["Toyota", "Camry"])),
+                (4, fields(&["Lada", "Niva\nLegend"])),
+                (6, fields(&["Kia", "Rio"])),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_records_larger_than_a_chunk() {
+        let long = "x".repeat(CHUNK_SIZE * 2 + 17);
+        let source = format!("a,b\n{},\"q\"\"uoted\"\n", long);
+        let records = futures::executor::block_on(read_all(source.as_bytes()));
+        assert_eq!(records[1], (2, vec![long, "q\"uoted".to_string()]));
+    }
+
+    #[test]
+    fn test_empty_source() {
+        assert!(futures::executor::block_on(read_all(b"")).is_empty());
+    }
+
+    #[test]
+    fn test_invalid_utf8_fails() {
+        let mut stream = CsvStream::new(&b"a,b\n\xff,1\n"[..]);
+        futures::executor::block_on(async {
+            stream.read_record().await.expect("valid header");
+            assert!(stream.read_record().await.is_err());
+        });
+    }
+}
@@ -0,0 +1,215 @@
+//! Opaque, signed cursors of keyset pagination.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * A cursor is `<payload>.<signature>`, both base64url encoded: the payload is the position
+//!   (sort column, order, direction, sort value and id) as JSON, the signature its HMAC-SHA256.
+//! * Clients can't read or forge a cursor; a cursor made for another sort is refused.
+//! * The key is shared by every instance of the service, changing it invalidates the cursors in
+//!   flight (clients restart from the first page).
+use crate::shared::pagination::{KeysetPosition, SortOrder};
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Direction a cursor continues in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum CursorDirection {
+    /// The rows after the position.
+    #[serde(rename = "n")]
+    Next,
+    /// The rows before the position.
+    #[serde(rename = "p")]
+    Previous,
+}
+
+/// The decoded content of a cursor.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CursorPayload {
+    /// Sort column the position belongs to.
+    #[serde(rename = "s")]
+    pub sort_column: String,
+    #[serde(rename = "o")]
+    pub sort_order: SortOrder,
+    #[serde(rename = "d")]
+    pub direction: CursorDirection,
+    #[serde(rename = "v")]
+    pub value: Option<String>,
+    #[serde(rename = "i")]
+    pub id: String,
+}
+
+impl CursorPayload {
+    pub fn position(&self) -> KeysetPosition {
+        KeysetPosition {
+            value: self.value.clone(),
+            id: self.id.clone(),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CursorError {
+    #[error("Malformed cursor")]
+    Malformed,
+    #[error("Invalid cursor signature")]
+    InvalidSignature,
+    #[error("The cursor belongs to another sort (expected {expected})")]
+    SortMismatch { expected: String },
+}
+
+/// Signs and verifies cursors.
+#[derive(Clone)]
+pub struct CursorCodec {
+    key: Vec<u8>,
+}
+
+impl CursorCodec {
+    pub fn new(key: impl Into<Vec<u8>>) -> Self {
+        CursorCodec { key: key.into() }
+    }
+
+    pub fn encode(&self, payload: &CursorPayload) -> String {
+        // Serializing a struct of strings can't fail
+        let json = serde_json::to_vec(payload).unwrap_or_default();
+        let payload = URL_SAFE_NO_PAD.encode(json);
+        let signature =
+            URL_SAFE_NO_PAD.encode(self.mac(payload.as_bytes()).finalize().into_bytes());
+        format!("{}.{}", payload, signature)
+    }
+
+    pub fn decode(&self, cursor: &str) -> Result<CursorPayload, CursorError> {
+        let (payload, signature) = cursor.split_once('.').ok_or(CursorError::Malformed)?;
+        let signature = URL_SAFE_NO_PAD
+            .decode(signature)
+            .map_err(|_| CursorError::Malformed)?;
+        // Constant time comparison
+        self.mac(payload.as_bytes())
+            .verify_slice(&signature)
+            .map_err(|_| CursorError::InvalidSignature)?;
+
+        let json = URL_SAFE_NO_PAD
+            .decode(payload)
+            .map_err(|_| CursorError::Malformed)?;
+        serde_json::from_slice(&json).map_err(|_| CursorError::Malformed)
+    }
+
+    /// Decodes a cursor and checks it was made for the given sort.
+    pub fn decode_for(
+        &self,
+        cursor: &str,
+        sort_column: &str,
+        sort_order: SortOrder,
+    ) -> Result<CursorPayload, CursorError> {
+        let payload = self.decode(cursor)?;
+        if payload.sort_column != sort_column || payload.sort_order != sort_order {
+            return Err(CursorError::SortMismatch {
+                expected: format!("{} {}", sort_column, sort_order.to_str()),
+            });
+        }
+        Ok(payload)
+    }
+
+    fn mac(&self, data: &[u8]) -> HmacSha256 {
+        // HMAC accepts keys of any length
+        let mut mac = HmacSha256::new_from_slice(&self.key).expect("HMAC accepts any key length");
+        mac.update(data);
+        mac
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn payload() -> CursorPayload {
+        CursorPayload {
+            sort_column: "year".to_string(),
+            sort_order: SortOrder::Asc,
+            direction: CursorDirection::Next,
+            value: Some("2019".to_string()),
+            id: "7d3c5a2e-0b1f-4c8e-9a6d-2f4b8c1e5d90".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let codec = CursorCodec::new("secret");
+        let cursor = codec.encode(&payload());
+        assert_eq!(codec.decode(&cursor).unwrap(), payload());
+        assert_eq!(
+            codec.decode_for(&cursor, "year", SortOrder::Asc).unwrap(),
+            payload()
+        );
+    }
+
+    #[test]
+    fn test_tampered_cursor_is_rejected() {
+        let codec = CursorCodec::new("secret");
+        let cursor = codec.encode(&payload());
+        let (encoded, signature) = cursor.split_once('.').unwrap();
+
+        // Another position, keeping the signature of the original one
+        let forged = CursorPayload {
+            value: Some("2024".to_string()),
+            ..payload()
+        };
+        let forged = URL_SAFE_NO_PAD.encode(serde_json::to_vec(&forged).unwrap());
+        let results = [
+            codec.decode(&format!("{}.{}", forged, signature)),
+            codec.decode(&format!(
+                "{}.{}",
+                encoded,
+                URL_SAFE_NO_PAD.encode([0u8; 32])
+            )),
+            CursorCodec::new("another secret").decode(&cursor),
+        ];
+        for result in results {
+            assert!(
+                matches!(result, Err(CursorError::InvalidSignature)),
+                "{:?}",
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn test_malformed_cursor_is_rejected() {
+        let codec = CursorCodec::new("secret");
+        let signed = |payload: &str| {
+            let signature = codec.mac(payload.as_bytes()).finalize().into_bytes();
+            format!("{}.{}", payload, URL_SAFE_NO_PAD.encode(signature))
+        };
+        let cursors = [
+            "no separator".to_string(),
+            "payload.not base64!".to_string(),
+            // Signed, but not a payload
+            signed("not base64!"),
+            signed(&URL_SAFE_NO_PAD.encode("{\"s\":\"year\"}")),
+        ];
+        for cursor in cursors {
+            let result = codec.decode(&cursor);
+            assert!(
+                matches!(result, Err(CursorError::Malformed)),
+                "{:?}",
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn test_cursor_of_another_sort_is_rejected() {
+        let codec = CursorCodec::new("secret");
+        let cursor = codec.encode(&payload());
+        for (column, order) in [("make", SortOrder::Asc), ("year", SortOrder::Desc)] {
+            match codec.decode_for(&cursor, column, order) {
+                Err(CursorError::SortMismatch { expected }) => {
+                    assert_eq!(expected, format!("{} {}", column, order.to_str()))
+                }
+                other => panic!("expected a sort mismatch, got {:?}", other),
+            }
+        }
+    }
+}
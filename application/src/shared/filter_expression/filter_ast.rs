@@ -0,0 +1,86 @@
+use super::filter_schema::FilterField;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterOperator {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    /// Case-insensitive substring.
+    Contains,
+    In,
+    NotIn,
+}
+
+impl FilterOperator {
+    pub fn as_str(&self) -> &str {
+        match self {
+            FilterOperator::Eq => "=",
+            FilterOperator::Ne => "!=",
+            FilterOperator::Lt => "<",
+            FilterOperator::Le => "<=",
+            FilterOperator::Gt => ">",
+            FilterOperator::Ge => ">=",
+            FilterOperator::Contains => "~",
+            FilterOperator::In => "in",
+            FilterOperator::NotIn => "not in",
+        }
+    }
+}
+
+/// A value converted to the type of its field.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterValue {
+    Text(String),
+    Integer(i64),
+    Decimal(rust_decimal::Decimal),
+    Timestamp(chrono::DateTime<chrono::Utc>),
+    /// The label of a PostgreSQL enum value.
+    Enum(String),
+}
+
+/// The right-hand side of a condition: a list for `in` and `not in`, a single value otherwise.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterOperand {
+    Value(FilterValue),
+    List(Vec<FilterValue>),
+}
+
+#[derive(Debug, Clone)]
+pub struct FilterCondition {
+    pub field: &'static FilterField,
+    pub operator: FilterOperator,
+    pub operand: FilterOperand,
+}
+
+/// A parsed and validated filter expression.
+#[derive(Debug, Clone)]
+pub enum FilterExpression {
+    Condition(FilterCondition),
+    And(Box<FilterExpression>, Box<FilterExpression>),
+    Or(Box<FilterExpression>, Box<FilterExpression>),
+    Not(Box<FilterExpression>),
+}
+
+impl FilterExpression {
+    /// Combines two expressions of the same entity (e.g., a saved dashboard filter and the one
+    /// typed by the user).
+    pub fn and(self, other: FilterExpression) -> FilterExpression {
+        FilterExpression::And(Box::new(self), Box::new(other))
+    }
+
+    /// Returns the conditions of the expression, left to right.
+    pub fn conditions(&self) -> Vec<&FilterCondition> {
+        match self {
+            FilterExpression::Condition(condition) => vec![condition],
+            FilterExpression::And(left, right) | FilterExpression::Or(left, right) => {
+                let mut conditions = left.conditions();
+                conditions.extend(right.conditions());
+                conditions
+            }
+            FilterExpression::Not(expression) => expression.conditions(),
+        }
+    }
+}
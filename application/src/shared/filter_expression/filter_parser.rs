@@ -0,0 +1,663 @@
+use super::{
+    filter_ast::{FilterCondition, FilterExpression, FilterOperand, FilterOperator, FilterValue},
+    filter_schema::{FilterField, FilterFieldKind, FilterSchema},
+};
+use std::str::FromStr;
+
+pub const MAX_FILTER_LENGTH: usize = 2_000;
+pub const MAX_FILTER_CONDITIONS: usize = 32;
+pub const MAX_FILTER_LIST_VALUES: usize = 100;
+/// Maximum nesting of parentheses and `not`.
+pub const MAX_FILTER_DEPTH: usize = 16;
+
+#[derive(Debug, thiserror::Error)]
+pub enum FilterExpressionError {
+    #[error("Invalid filter: must not be empty")]
+    Empty,
+    #[error("Invalid filter at {position}: {message}")]
+    Syntax { position: usize, message: String },
+    #[error("Unknown {entity} field: {field} (expected one of: {expected})")]
+    UnknownField {
+        entity: &'static str,
+        field: String,
+        expected: String,
+    },
+    #[error("Operator {operator} is not supported by field {field}")]
+    UnsupportedOperator {
+        field: &'static str,
+        operator: String,
+    },
+    #[error("Invalid value for field {field}: {value} (expected {expected})")]
+    InvalidValue {
+        field: &'static str,
+        value: String,
+        expected: &'static str,
+    },
+    #[error("Filter too complex: {0}")]
+    TooComplex(String),
+}
+
+impl FilterExpression {
+    /// Parses an expression against the schema of an entity.
+    pub fn parse(input: &str, schema: &FilterSchema) -> Result<Self, FilterExpressionError> {
+        if input.trim().is_empty() {
+            return Err(FilterExpressionError::Empty);
+        }
+        if input.len() > MAX_FILTER_LENGTH {
+            return Err(FilterExpressionError::TooComplex(format!(
+                "longer than {} characters",
+                MAX_FILTER_LENGTH
+            )));
+        }
+
+        let mut parser = Parser {
+            tokens: tokenize(input)?,
+            next: 0,
+            end: input.len(),
+            schema,
+            conditions: 0,
+            depth: 0,
+        };
+        let expression = parser.parse_or()?;
+        match parser.peek() {
+            None => Ok(expression),
+            Some((position, token)) => Err(syntax(
+                *position,
+                format!("unexpected {}", token.describe()),
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    /// A field name, keyword or unquoted value.
+    Word(String),
+    Quoted(String),
+    Operator(FilterOperator),
+    OpenParen,
+    CloseParen,
+    Comma,
+}
+
+impl Token {
+    fn describe(&self) -> String {
+        match self {
+            Token::Word(word) => format!("'{}'", word),
+            Token::Quoted(text) => format!("\"{}\"", text),
+            Token::Operator(operator) => format!("'{}'", operator.as_str()),
+            Token::OpenParen => "'('".to_string(),
+            Token::CloseParen => "')'".to_string(),
+            Token::Comma => "','".to_string(),
+        }
+    }
+
+    fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(self, Token::Word(word) if word.eq_ignore_ascii_case(keyword))
+    }
+}
+
+fn syntax(position: usize, message: impl Into<String>) -> FilterExpressionError {
+    FilterExpressionError::Syntax {
+        position,
+        message: message.into(),
+    }
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '.' | ':' | '+' | '-')
+}
+
+/// Splits the input into tokens with their byte position.
+fn tokenize(input: &str) -> Result<Vec<(usize, Token)>, FilterExpressionError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.char_indices().peekable();
+
+    while let Some((position, c)) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '(' => Token::OpenParen,
+            ')' => Token::CloseParen,
+            ',' => Token::Comma,
+            '=' => Token::Operator(FilterOperator::Eq),
+            '~' => Token::Operator(FilterOperator::Contains),
+            '!' => match chars.next_if(|(_, c)| *c == '=') {
+                Some(_) => Token::Operator(FilterOperator::Ne),
+                None => return Err(syntax(position, "expected '=' after '!'")),
+            },
+            '<' | '>' => {
+                let or_equal = chars.next_if(|(_, c)| *c == '=').is_some();
+                Token::Operator(match (c, or_equal) {
+                    ('<', false) => FilterOperator::Lt,
+                    ('<', true) => FilterOperator::Le,
+                    (_, false) => FilterOperator::Gt,
+                    (_, true) => FilterOperator::Ge,
+                })
+            }
+            '"' => {
+                let mut text = String::new();
+                loop {
+                    match chars.next() {
+                        Some((_, '"')) => break,
+                        Some((_, '\\')) => match chars.next() {
+                            Some((_, escaped @ ('"' | '\\'))) => text.push(escaped),
+                            _ => return Err(syntax(position, "invalid escape in quoted value")),
+                        },
+                        Some((_, c)) => text.push(c),
+                        None => return Err(syntax(position, "unterminated quoted value")),
+                    }
+                }
+                Token::Quoted(text)
+            }
+            c if is_word_char(c) => {
+                let mut word = c.to_string();
+                while let Some((_, c)) = chars.next_if(|(_, c)| is_word_char(*c)) {
+                    word.push(c);
+                }
+                Token::Word(word)
+            }
+            c => return Err(syntax(position, format!("unexpected character '{}'", c))),
+        };
+        tokens.push((position, token));
+    }
+
+    Ok(tokens)
+}
+
+/// Recursive descent parser: `or` of `and` of (`not`) conditions or parenthesized expressions.
+struct Parser<'a> {
+    tokens: Vec<(usize, Token)>,
+    next: usize,
+    /// Position of the end of the input, for errors.
+    end: usize,
+    schema: &'a FilterSchema,
+    conditions: usize,
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&(usize, Token)> {
+        self.tokens.get(self.next)
+    }
+
+    fn advance(&mut self) -> Result<(usize, Token), FilterExpressionError> {
+        let token = self
+            .tokens
+            .get(self.next)
+            .cloned()
+            .ok_or_else(|| syntax(self.end, "unexpected end of filter"))?;
+        self.next += 1;
+        Ok(token)
+    }
+
+    fn next_is_keyword(&self, keyword: &str) -> bool {
+        self.peek()
+            .is_some_and(|(_, token)| token.is_keyword(keyword))
+    }
+
+    fn expect(&mut self, expected: Token) -> Result<(), FilterExpressionError> {
+        let (position, token) = self.advance()?;
+        match token == expected {
+            true => Ok(()),
+            false => Err(syntax(
+                position,
+                format!(
+                    "expected {}, found {}",
+                    expected.describe(),
+                    token.describe()
+                ),
+            )),
+        }
+    }
+
+    fn nested<T>(
+        &mut self,
+        parse: impl FnOnce(&mut Self) -> Result<T, FilterExpressionError>,
+    ) -> Result<T, FilterExpressionError> {
+        self.depth += 1;
+        if self.depth > MAX_FILTER_DEPTH {
+            return Err(FilterExpressionError::TooComplex(format!(
+                "nested more than {} levels",
+                MAX_FILTER_DEPTH
+            )));
+        }
+        let result = parse(self);
+        self.depth -= 1;
+        result
+    }
+
+    fn parse_or(&mut self) -> Result<FilterExpression, FilterExpressionError> {
+        let mut expression = self.parse_and()?;
+        while self.next_is_keyword("or") {
+            self.next += 1;
+            let right = self.parse_and()?;
+            expression = FilterExpression::Or(Box::new(expression), Box::new(right));
+        }
+        Ok(expression)
+    }
+
+    fn parse_and(&mut self) -> Result<FilterExpression, FilterExpressionError> {
+        let mut expression = self.parse_unary()?;
+        while self.next_is_keyword("and") {
+            self.next += 1;
+            let right = self.parse_unary()?;
+            expression = FilterExpression::And(Box::new(expression), Box::new(right));
+        }
+        Ok(expression)
+    }
+
+    fn parse_unary(&mut self) -> Result<FilterExpression, FilterExpressionError> {
+        if self.next_is_keyword("not") {
+            self.next += 1;
+            let expression = self.nested(Self::parse_unary)?;
+            return Ok(FilterExpression::Not(Box::new(expression)));
+        }
+        if let Some((_, Token::OpenParen)) = self.peek() {
+            self.next += 1;
+            let expression = self.nested(Self::parse_or)?;
+            self.expect(Token::CloseParen)?;
+            return Ok(expression);
+        }
+        self.parse_condition()
+    }
+
+    fn parse_condition(&mut self) -> Result<FilterExpression, FilterExpressionError> {
+        let (position, token) = self.advance()?;
+        let name = match token {
+            Token::Word(name) => name,
+            token => {
+                return Err(syntax(
+                    position,
+                    format!("expected a field, found {}", token.describe()),
+                ));
+            }
+        };
+        let field =
+            self.schema
+                .field(&name)
+                .ok_or_else(|| FilterExpressionError::UnknownField {
+                    entity: self.schema.entity,
+                    field: name,
+                    expected: self.schema.field_names(),
+                })?;
+
+        self.conditions += 1;
+        if self.conditions > MAX_FILTER_CONDITIONS {
+            return Err(FilterExpressionError::TooComplex(format!(
+                "more than {} conditions",
+                MAX_FILTER_CONDITIONS
+            )));
+        }
+
+        // Check the operator, `in` and `not in` are keywords
+        let (position, token) = self.advance()?;
+        let operator = match token {
+            Token::Operator(operator) => operator,
+            token if token.is_keyword("in") => FilterOperator::In,
+            token if token.is_keyword("not") && self.next_is_keyword("in") => {
+                self.next += 1;
+                FilterOperator::NotIn
+            }
+            token => {
+                return Err(syntax(
+                    position,
+                    format!("expected an operator, found {}", token.describe()),
+                ));
+            }
+        };
+        if !field.kind.supports(operator) {
+            return Err(FilterExpressionError::UnsupportedOperator {
+                field: field.name,
+                operator: operator.as_str().to_string(),
+            });
+        }
+
+        let operand = match operator {
+            FilterOperator::In | FilterOperator::NotIn => {
+                FilterOperand::List(self.parse_list(field)?)
+            }
+            _ => FilterOperand::Value(self.parse_value(field)?),
+        };
+
+        Ok(FilterExpression::Condition(FilterCondition {
+            field,
+            operator,
+            operand,
+        }))
+    }
+
+    fn parse_list(
+        &mut self,
+        field: &FilterField,
+    ) -> Result<Vec<FilterValue>, FilterExpressionError> {
+        self.expect(Token::OpenParen)?;
+        let mut values = vec![self.parse_value(field)?];
+        while let Some((_, Token::Comma)) = self.peek() {
+            self.next += 1;
+            values.push(self.parse_value(field)?);
+        }
+        self.expect(Token::CloseParen)?;
+
+        if values.len() > MAX_FILTER_LIST_VALUES {
+            return Err(FilterExpressionError::TooComplex(format!(
+                "more than {} values in a list",
+                MAX_FILTER_LIST_VALUES
+            )));
+        }
+        Ok(values)
+    }
+
+    fn parse_value(&mut self, field: &FilterField) -> Result<FilterValue, FilterExpressionError> {
+        let (position, token) = self.advance()?;
+        let raw = match token {
+            Token::Word(value) | Token::Quoted(value) => value,
+            token => {
+                return Err(syntax(
+                    position,
+                    format!("expected a value, found {}", token.describe()),
+                ));
+            }
+        };
+        convert(field, raw)
+    }
+}
+
+/// Converts a raw value to the type of the field.
+fn convert(field: &FilterField, raw: String) -> Result<FilterValue, FilterExpressionError> {
+    let value = match field.kind {
+        FilterFieldKind::Text => Some(FilterValue::Text(raw.clone())),
+        FilterFieldKind::Integer => raw.parse().ok().map(FilterValue::Integer),
+        FilterFieldKind::Decimal => rust_decimal::Decimal::from_str(&raw)
+            .ok()
+            .map(FilterValue::Decimal),
+        FilterFieldKind::Timestamp => parse_timestamp(&raw).map(FilterValue::Timestamp),
+        FilterFieldKind::Enum { parse, .. } => parse(&raw).map(FilterValue::Enum),
+    };
+
+    value.ok_or_else(|| FilterExpressionError::InvalidValue {
+        field: field.name,
+        value: raw,
+        expected: field.kind.expected(),
+    })
+}
+
+fn parse_timestamp(raw: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+    if let Ok(timestamp) = chrono::DateTime::parse_from_rfc3339(raw) {
+        return Some(timestamp.with_timezone(&chrono::Utc));
+    }
+    chrono::NaiveDate::parse_from_str(raw, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|date| date.and_utc())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vehicle::filters::vehicle_filter::VEHICLE_FILTER_SCHEMA;
+
+    fn parse(input: &str) -> Result<FilterExpression, FilterExpressionError> {
+        FilterExpression::parse(input, &VEHICLE_FILTER_SCHEMA)
+    }
+
+    /// Writes an expression with every operation parenthesized, to check how it was grouped.
+    fn grouped(expression: &FilterExpression) -> String {
+        let value = |value: &FilterValue| match value {
+            FilterValue::Text(text) | FilterValue::Enum(text) => format!("{:?}", text),
+            FilterValue::Integer(integer) => integer.to_string(),
+            FilterValue::Decimal(decimal) => decimal.to_string(),
+            FilterValue::Timestamp(timestamp) => timestamp.to_rfc3339(),
+        };
+        match expression {
+            FilterExpression::Condition(condition) => {
+                let operand = match &condition.operand {
+                    FilterOperand::Value(operand) => value(operand),
+                    FilterOperand::List(values) => format!(
+                        "({})",
+                        values.iter().map(value).collect::<Vec<_>>().join(", ")
+                    ),
+                };
+                format!(
+                    "{} {} {}",
+                    condition.field.name,
+                    condition.operator.as_str(),
+                    operand
+                )
+            }
+            FilterExpression::And(left, right) => {
+                format!("({} and {})", grouped(left), grouped(right))
+            }
+            FilterExpression::Or(left, right) => {
+                format!("({} or {})", grouped(left), grouped(right))
+            }
+            FilterExpression::Not(expression) => format!("not {}", grouped(expression)),
+        }
+    }
+
+    fn parsed(input: &str) -> String {
+        grouped(&parse(input).unwrap())
+    }
+
+    #[test]
+    fn test_and_binds_tighter_than_or() {
+        assert_eq!(
+            parsed("make = a or make = b and year = 2020"),
+            r#"(make = "a" or (make = "b" and year = 2020))"#
+        );
+        assert_eq!(
+            parsed("(make = a or make = b) and year = 2020"),
+            r#"((make = "a" or make = "b") and year = 2020)"#
+        );
+        assert_eq!(
+            parsed("year = 1 AND year = 2 Or year = 3 and year = 4"),
+            "((year = 1 and year = 2) or (year = 3 and year = 4))",
+            "keywords are case-insensitive"
+        );
+    }
+
+    #[test]
+    fn test_not_in_is_an_operator() {
+        assert_eq!(
+            parsed("engine_type not in (diesel, electric)"),
+            r#"engine_type not in ("Diesel", "Electric")"#
+        );
+        assert_eq!(
+            parsed("not (engine_type in (diesel)) and not year = 2020"),
+            r#"(not engine_type in ("Diesel") and not year = 2020)"#,
+            "a prefix `not` negates the next condition only"
+        );
+        assert_eq!(parsed("not not year = 2020"), "not not year = 2020");
+        assert!(matches!(
+            parse("year not = 2020"),
+            Err(FilterExpressionError::Syntax { position: 5, .. })
+        ));
+    }
+
+    #[test]
+    fn test_quoted_values() {
+        assert_eq!(
+            parsed(r#"model = "Land \"Cruiser\" \\ 200" or make in ("a,b", "(c)")"#),
+            r#"(model = "Land \"Cruiser\" \\ 200" or make in ("a,b", "(c)"))"#
+        );
+        assert_eq!(parsed(r#"make = """#), r#"make = """#);
+
+        match parse(r#"make = "bad \n escape""#) {
+            Err(FilterExpressionError::Syntax { position, message }) => {
+                assert_eq!(
+                    (position, message.as_str()),
+                    (7, "invalid escape in quoted value")
+                )
+            }
+            other => panic!("expected a syntax error, got {:?}", other),
+        }
+        match parse(r#"make = "open"#) {
+            Err(FilterExpressionError::Syntax { position, message }) => {
+                assert_eq!(
+                    (position, message.as_str()),
+                    (7, "unterminated quoted value")
+                )
+            }
+            other => panic!("expected a syntax error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_dates_and_timestamps() {
+        assert_eq!(
+            parsed("created_at >= 2025-01-31"),
+            "created_at >= 2025-01-31T00:00:00+00:00",
+            "a date is midnight UTC"
+        );
+        assert_eq!(
+            parsed("created_at < 2025-01-31T10:30:00+05:00"),
+            "created_at < 2025-01-31T05:30:00+00:00",
+            "a timestamp is converted to UTC"
+        );
+        assert_eq!(
+            parsed(r#"updated_at > "2025-01-31T10:30:00.250Z""#),
+            "updated_at > 2025-01-31T10:30:00.250+00:00"
+        );
+
+        for value in [
+            "2025-13-01",
+            "2025-01-31T25:00:00Z",
+            "31/01/2025",
+            "2025-01-31 10:30",
+        ] {
+            match parse(&format!(r#"created_at = "{}""#, value)) {
+                Err(FilterExpressionError::InvalidValue {
+                    field,
+                    value: invalid,
+                    expected,
+                }) => {
+                    assert_eq!((field, invalid.as_str()), ("created_at", value));
+                    assert_eq!(expected, FilterFieldKind::Timestamp.expected());
+                }
+                other => panic!("expected an invalid value, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_unknown_field() {
+        match parse("year = 2020 and id = 1") {
+            Err(FilterExpressionError::UnknownField {
+                entity,
+                field,
+                expected,
+            }) => {
+                assert_eq!((entity, field.as_str()), ("vehicle", "id"));
+                assert_eq!(expected, VEHICLE_FILTER_SCHEMA.field_names());
+            }
+            other => panic!("expected an unknown field, got {:?}", other),
+        }
+        assert_eq!(
+            parsed("YEAR = 2020"),
+            "year = 2020",
+            "field names are case-insensitive"
+        );
+        assert!(matches!(
+            parse(r#""year" = 2020"#),
+            Err(FilterExpressionError::Syntax { position: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn test_unsupported_operator() {
+        for (input, field, operator) in [
+            ("year ~ 20", "year", "~"),
+            ("created_at in (2025-01-31)", "created_at", "in"),
+            ("make < Toyota", "make", "<"),
+            ("engine_type >= diesel", "engine_type", ">="),
+        ] {
+            match parse(input) {
+                Err(FilterExpressionError::UnsupportedOperator {
+                    field: unsupported,
+                    operator: unsupported_operator,
+                }) => assert_eq!(
+                    (unsupported, unsupported_operator.as_str()),
+                    (field, operator)
+                ),
+                other => panic!(
+                    "{}: expected an unsupported operator, got {:?}",
+                    input, other
+                ),
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_values() {
+        for (input, field, value) in [
+            ("engine_type = steam", "engine_type", "steam"),
+            ("engine_type in (diesel, rotary)", "engine_type", "rotary"),
+            ("year = recent", "year", "recent"),
+            ("battery_capacity > lots", "battery_capacity", "lots"),
+        ] {
+            match parse(input) {
+                Err(FilterExpressionError::InvalidValue {
+                    field: invalid,
+                    value: invalid_value,
+                    ..
+                }) => assert_eq!((invalid, invalid_value.as_str()), (field, value)),
+                other => panic!("{}: expected an invalid value, got {:?}", input, other),
+            }
+        }
+        assert_eq!(
+            parsed("engine_type = DIESEL and battery_capacity >= 75.5"),
+            r#"(engine_type = "Diesel" and battery_capacity >= 75.5)"#
+        );
+    }
+
+    #[test]
+    fn test_depth_limit() {
+        let nots = |depth: usize| format!("{}year = 2020", "not ".repeat(depth));
+        let parentheses =
+            |depth: usize| format!("{}year = 2020{}", "(".repeat(depth), ")".repeat(depth));
+
+        assert!(parse(&nots(MAX_FILTER_DEPTH)).is_ok());
+        assert!(parse(&parentheses(MAX_FILTER_DEPTH)).is_ok());
+        for input in [
+            nots(MAX_FILTER_DEPTH + 1),
+            parentheses(MAX_FILTER_DEPTH + 1),
+        ] {
+            assert!(
+                matches!(parse(&input), Err(FilterExpressionError::TooComplex(_))),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_conditions_limit() {
+        let conditions = |count: usize| vec!["year = 2020"; count].join(" or ");
+
+        assert!(parse(&conditions(MAX_FILTER_CONDITIONS)).is_ok());
+        assert!(matches!(
+            parse(&conditions(MAX_FILTER_CONDITIONS + 1)),
+            Err(FilterExpressionError::TooComplex(_))
+        ));
+    }
+
+    #[test]
+    fn test_list_values_limit() {
+        let list = |count: usize| format!("year in ({})", vec!["2020"; count].join(","));
+
+        assert!(parse(&list(MAX_FILTER_LIST_VALUES)).is_ok());
+        assert!(matches!(
+            parse(&list(MAX_FILTER_LIST_VALUES + 1)),
+            Err(FilterExpressionError::TooComplex(_))
+        ));
+    }
+
+    #[test]
+    fn test_length_limit() {
+        let input = format!(r#"make = "{}""#, "a".repeat(MAX_FILTER_LENGTH));
+        assert!(matches!(
+            parse(&input),
+            Err(FilterExpressionError::TooComplex(_))
+        ));
+        assert!(matches!(parse(" \t"), Err(FilterExpressionError::Empty)));
+    }
+}
@@ -0,0 +1,74 @@
+use super::filter_ast::FilterOperator;
+
+/// The type of a filterable field, which determines its values and operators.
+#[derive(Debug, Clone, Copy)]
+pub enum FilterFieldKind {
+    Text,
+    Integer,
+    Decimal,
+    /// RFC 3339 timestamps or dates (midnight UTC).
+    Timestamp,
+    /// A PostgreSQL enum, `parse` returns the label of a value (`None` if it is invalid).
+    Enum {
+        sql_type: &'static str,
+        parse: fn(&str) -> Option<String>,
+    },
+}
+
+impl FilterFieldKind {
+    pub fn supports(&self, operator: FilterOperator) -> bool {
+        use FilterOperator::*;
+        match self {
+            FilterFieldKind::Text => matches!(operator, Eq | Ne | Contains | In | NotIn),
+            FilterFieldKind::Integer | FilterFieldKind::Decimal => operator != Contains,
+            FilterFieldKind::Timestamp => matches!(operator, Eq | Ne | Lt | Le | Gt | Ge),
+            FilterFieldKind::Enum { .. } => matches!(operator, Eq | Ne | In | NotIn),
+        }
+    }
+
+    /// Description of the expected values, for error messages.
+    pub fn expected(&self) -> &'static str {
+        match self {
+            FilterFieldKind::Text => "a text",
+            FilterFieldKind::Integer => "an integer",
+            FilterFieldKind::Decimal => "a number",
+            FilterFieldKind::Timestamp => "a date (YYYY-MM-DD) or an RFC 3339 timestamp",
+            FilterFieldKind::Enum { .. } => "one of the values of the field",
+        }
+    }
+}
+
+/// A filterable field of an entity.
+#[derive(Debug, Clone, Copy)]
+pub struct FilterField {
+    /// Name of the field in expressions.
+    pub name: &'static str,
+    /// Column of the field, interpolated in the SQL (never user input).
+    pub column: &'static str,
+    pub kind: FilterFieldKind,
+}
+
+/// The whitelist of the fields of an entity that can be filtered.
+#[derive(Debug)]
+pub struct FilterSchema {
+    /// Name of the entity, for error messages.
+    pub entity: &'static str,
+    pub fields: &'static [FilterField],
+}
+
+impl FilterSchema {
+    /// Finds a field by name (case-insensitive).
+    pub fn field(&self, name: &str) -> Option<&'static FilterField> {
+        self.fields
+            .iter()
+            .find(|field| field.name.eq_ignore_ascii_case(name))
+    }
+
+    pub fn field_names(&self) -> String {
+        self.fields
+            .iter()
+            .map(|field| field.name)
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
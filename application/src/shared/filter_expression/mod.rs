@@ -0,0 +1,17 @@
+//! Filter expressions of the list queries (e.g.,
+//! `year>=2018 and engine_type in (diesel,electric) and make~"toy"`).
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * An expression is parsed against the schema of an entity: only its whitelisted fields can be
+//!   filtered, every value is converted to the type of its field, so a parsed expression is valid.
+//! * Conditions are combined with `and`, `or`, `not` and parentheses (`and` binds tighter).
+//! * Operators: `=`, `!=`, `<`, `<=`, `>`, `>=`, `~` (contains, case-insensitive), `in (...)` and
+//!   `not in (...)`; each kind of field supports a subset of them.
+//! * Values are bare words (`diesel`, `2018`, `2025-01-31`) or double-quoted strings (`"Land
+//!   Cruiser"`, `\"` and `\\` escaped).
+//! * Keywords are case-insensitive; a field name can't be quoted.
+//! * The repositories compile expressions to parameterized SQL, values are never inlined.
+pub mod filter_ast;
+pub mod filter_parser;
+pub mod filter_schema;
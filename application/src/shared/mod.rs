@@ -1 +1,5 @@
-pub mod pagination;
\ No newline at end of file
+pub mod csv_stream;
+pub mod cursor;
+pub mod filter_expression;
+pub mod pagination;
+pub mod traits;
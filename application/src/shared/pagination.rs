@@ -1,11 +1,12 @@
 // application/pagination.rs
+use crate::shared::cursor::{CursorCodec, CursorDirection, CursorError, CursorPayload};
 use serde::Deserialize;
 
 pub const DEFAULT_PAGE: u32 = 1;
 pub const DEFAULT_PAGE_SIZE: u32 = 10;
 pub const MAX_PAGE_SIZE: u32 = 100;
 
-#[derive(Debug, Clone, Copy, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, serde::Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum SortOrder {
     Asc,
@@ -20,3 +21,284 @@ impl SortOrder {
         }
     }
 }
+
+/// A page request of a list query using keyset (cursor) pagination.
+#[derive(Debug, Clone, Default)]
+pub struct PageRequest {
+    /// Cursor returned by the previous page (`None` for the first page).
+    pub cursor: Option<String>,
+    /// Number of rows of the page (`0` means the default, capped to `MAX_PAGE_SIZE`).
+    pub page_size: u32,
+    /// Also count the rows matching the filter (an extra query, only when needed).
+    pub include_total: bool,
+}
+
+/// Where a page starts: the sort value (as text, `None` for NULL) and id of a row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeysetPosition {
+    pub value: Option<String>,
+    pub id: String,
+}
+
+/// How the repository must read a page: rows strictly after (or before) `position` in the
+/// `(sort_column, id)` order.
+#[derive(Debug, Clone)]
+pub struct Keyset {
+    /// A whitelisted column (e.g., `VehicleSortBy::as_column_name`), the id as tiebreaker (the
+    /// `uuid` column of a vehicle).
+    pub sort_column: &'static str,
+    pub sort_order: SortOrder,
+    pub position: Option<KeysetPosition>,
+    pub direction: CursorDirection,
+    /// Rows to read, one more than the page size to know if there is a next page.
+    pub limit: u32,
+}
+
+impl Keyset {
+    /// Resolves a page request for the given sort, checking its cursor.
+    pub fn new(
+        request: &PageRequest,
+        sort_column: &'static str,
+        sort_order: SortOrder,
+        codec: &CursorCodec,
+    ) -> Result<Self, CursorError> {
+        let payload = request
+            .cursor
+            .as_deref()
+            .map(|cursor| codec.decode_for(cursor, sort_column, sort_order))
+            .transpose()?;
+
+        Ok(Keyset {
+            sort_column,
+            sort_order,
+            direction: payload
+                .as_ref()
+                .map_or(CursorDirection::Next, |payload| payload.direction),
+            position: payload.as_ref().map(CursorPayload::position),
+            limit: page_size(request.page_size) + 1,
+        })
+    }
+
+    pub fn page_size(&self) -> u32 {
+        self.limit - 1
+    }
+
+    /// Whether the repository must scan in the reverse of `sort_order` (previous pages are read
+    /// backwards from their position).
+    pub fn is_backward(&self) -> bool {
+        self.direction == CursorDirection::Previous
+    }
+}
+
+/// Response envelope of a list query using keyset pagination.
+#[derive(Debug, Clone)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub page_size: u32,
+    pub next_cursor: Option<String>,
+    pub previous_cursor: Option<String>,
+    /// Only when requested.
+    pub total_count: Option<u64>,
+}
+
+impl<T> Page<T> {
+    /// Builds a page from the rows read with `keyset` (in scanning order, up to `keyset.limit`).
+    pub fn from_rows(
+        mut rows: Vec<T>,
+        keyset: &Keyset,
+        codec: &CursorCodec,
+        position_of: impl Fn(&T) -> KeysetPosition,
+        total_count: Option<u64>,
+    ) -> Self {
+        let page_size = keyset.page_size();
+        let has_more = rows.len() > page_size as usize;
+        rows.truncate(page_size as usize);
+        if keyset.is_backward() {
+            rows.reverse();
+        }
+
+        let cursor = |row: Option<&T>, direction: CursorDirection| {
+            row.map(|row| {
+                let position = position_of(row);
+                codec.encode(&CursorPayload {
+                    sort_column: keyset.sort_column.to_string(),
+                    sort_order: keyset.sort_order,
+                    direction,
+                    value: position.value,
+                    id: position.id,
+                })
+            })
+        };
+        // Going forward there are previous rows if we started from a position, going backward
+        // there are next rows (the ones we came from)
+        let (has_next, has_previous) = match keyset.is_backward() {
+            false => (has_more, keyset.position.is_some()),
+            true => (true, has_more),
+        };
+
+        Page {
+            next_cursor: has_next
+                .then(|| cursor(rows.last(), CursorDirection::Next))
+                .flatten(),
+            previous_cursor: has_previous
+                .then(|| cursor(rows.first(), CursorDirection::Previous))
+                .flatten(),
+            items: rows,
+            page_size,
+            total_count,
+        }
+    }
+
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Page<U> {
+        Page {
+            items: self.items.into_iter().map(f).collect(),
+            page_size: self.page_size,
+            next_cursor: self.next_cursor,
+            previous_cursor: self.previous_cursor,
+            total_count: self.total_count,
+        }
+    }
+}
+
+/// Returns the page size to use, `0` meaning the default.
+pub fn page_size(requested: u32) -> u32 {
+    match requested {
+        0 => DEFAULT_PAGE_SIZE,
+        size => size.min(MAX_PAGE_SIZE),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn codec() -> CursorCodec {
+        CursorCodec::new("secret")
+    }
+
+    fn keyset(cursor: Option<String>, page_size: u32) -> Keyset {
+        let request = PageRequest {
+            cursor,
+            page_size,
+            include_total: false,
+        };
+        Keyset::new(&request, "year", SortOrder::Asc, &codec()).expect("valid cursor")
+    }
+
+    /// Rows are `(year, id)`.
+    fn page(rows: &[(i32, i32)], keyset: &Keyset) -> Page<(i32, i32)> {
+        Page::from_rows(
+            rows.to_vec(),
+            keyset,
+            &codec(),
+            |(year, id)| KeysetPosition {
+                value: Some(year.to_string()),
+                id: id.to_string(),
+            },
+            None,
+        )
+    }
+
+    fn decoded(cursor: &Option<String>) -> CursorPayload {
+        codec()
+            .decode(cursor.as_deref().expect("a cursor"))
+            .expect("valid cursor")
+    }
+
+    #[test]
+    fn test_page_size() {
+        assert_eq!(page_size(0), DEFAULT_PAGE_SIZE);
+        assert_eq!(page_size(25), 25);
+        assert_eq!(page_size(MAX_PAGE_SIZE + 1), MAX_PAGE_SIZE);
+        assert_eq!(keyset(None, 2).limit, 3, "one more row than the page");
+    }
+
+    #[test]
+    fn test_first_page_has_a_next_cursor_only() {
+        let keyset = keyset(None, 2);
+        let first = page(&[(2018, 1), (2019, 2), (2019, 3)], &keyset);
+
+        assert_eq!(first.items, vec![(2018, 1), (2019, 2)]);
+        assert_eq!(first.previous_cursor, None);
+        let next = decoded(&first.next_cursor);
+        assert_eq!(next.direction, CursorDirection::Next);
+        assert_eq!(
+            next.position(),
+            KeysetPosition {
+                value: Some("2019".to_string()),
+                id: "2".to_string()
+            },
+            "the next page starts after the last row"
+        );
+        assert_eq!(
+            (next.sort_column.as_str(), next.sort_order),
+            ("year", SortOrder::Asc)
+        );
+    }
+
+    #[test]
+    fn test_last_page_has_a_previous_cursor_only() {
+        let first = page(&[(2018, 1), (2019, 2), (2019, 3)], &keyset(None, 2));
+        let keyset = keyset(first.next_cursor, 2);
+        assert_eq!(keyset.position.as_ref().map(|p| p.id.as_str()), Some("2"));
+
+        let last = page(&[(2019, 3)], &keyset);
+        assert_eq!(last.items, vec![(2019, 3)]);
+        assert_eq!(last.next_cursor, None);
+        let previous = decoded(&last.previous_cursor);
+        assert_eq!(previous.direction, CursorDirection::Previous);
+        assert_eq!(
+            previous.id, "3",
+            "the previous page ends before the first row"
+        );
+    }
+
+    #[test]
+    fn test_previous_page_is_read_backwards() {
+        let keyset = keyset(
+            Some(codec().encode(&CursorPayload {
+                sort_column: "year".to_string(),
+                sort_order: SortOrder::Asc,
+                direction: CursorDirection::Previous,
+                value: Some("2020".to_string()),
+                id: "4".to_string(),
+            })),
+            2,
+        );
+        assert!(keyset.is_backward());
+
+        // Scanned from the position backwards: 3, 2, then 1 beyond the page
+        let middle = page(&[(2019, 3), (2019, 2), (2018, 1)], &keyset);
+        assert_eq!(middle.items, vec![(2019, 2), (2019, 3)], "in sort order");
+        assert_eq!(decoded(&middle.next_cursor).id, "3");
+        assert_eq!(decoded(&middle.previous_cursor).id, "2");
+
+        let first = page(&[(2019, 3), (2019, 2)], &keyset);
+        assert_eq!(first.previous_cursor, None, "no row before the first page");
+        assert_eq!(decoded(&first.next_cursor).id, "3");
+    }
+
+    #[test]
+    fn test_cursor_of_another_sort_is_rejected() {
+        let cursor = codec().encode(&CursorPayload {
+            sort_column: "make".to_string(),
+            sort_order: SortOrder::Asc,
+            direction: CursorDirection::Next,
+            value: Some("Toyota".to_string()),
+            id: "1".to_string(),
+        });
+        let request = PageRequest {
+            cursor: Some(cursor.clone()),
+            ..Default::default()
+        };
+        let result = Keyset::new(&request, "year", SortOrder::Asc, &codec());
+        assert!(matches!(result, Err(CursorError::SortMismatch { .. })));
+
+        let tampered = PageRequest {
+            cursor: Some(cursor.replacen('.', ".x", 1)),
+            ..Default::default()
+        };
+        let result = Keyset::new(&tampered, "make", SortOrder::Asc, &codec());
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,57 @@
+//! Unit of work: the repositories of a use case sharing a database transaction, so writes across
+//! aggregates (e.g., a maintenance record and the vehicle status it was performed at) are applied
+//! together or not at all.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * `UnitOfWork::begin` starts a transaction, its repositories see its own uncommitted writes.
+//! * `Transaction::begin` starts a nested transaction (a savepoint): rolling it back discards its
+//!   writes only, committing it keeps them in the outer transaction. The outer transaction is
+//!   borrowed until the nested one ends.
+//! * A transaction dropped without `commit` is rolled back.
+//! * The repositories of a transaction are only valid until it ends.
+use domain::{
+    maintenance::repositories::maintenance_record_repository::MaintenanceRecordRepository,
+    vehicle::repositories::vehicle_status_repository::VehicleStatusRepository,
+};
+use std::future::Future;
+
+#[derive(Debug, thiserror::Error)]
+pub enum UnitOfWorkError {
+    #[error("database error: {0}")]
+    DatabaseError(String),
+}
+
+/// The repositories of a transaction, committed or rolled back together
+pub trait Transaction: Send + Sync {
+    type VehicleStatuses: VehicleStatusRepository;
+    type MaintenanceRecords: MaintenanceRecordRepository;
+    type Nested<'a>: Transaction
+    where
+        Self: 'a;
+
+    fn vehicle_statuses(&self) -> &Self::VehicleStatuses;
+
+    fn maintenance_records(&self) -> &Self::MaintenanceRecords;
+
+    /// Start a nested transaction (a savepoint of this one)
+    fn begin(&mut self) -> impl Future<Output = Result<Self::Nested<'_>, UnitOfWorkError>> + Send;
+
+    /// Apply the writes of the transaction (to the outer transaction, if nested)
+    fn commit(self) -> impl Future<Output = Result<(), UnitOfWorkError>> + Send;
+
+    /// Discard the writes of the transaction
+    fn rollback(self) -> impl Future<Output = Result<(), UnitOfWorkError>> + Send;
+}
+
+/// Factory of transactions
+pub trait UnitOfWork: Send + Sync {
+    type Transaction: Transaction;
+
+    /// Start a transaction of an organization (its repositories are still called with the
+    /// organization, the transaction only reads and writes its rows)
+    fn begin(
+        &self,
+        organization_id: uuid::Uuid,
+    ) -> impl Future<Output = Result<Self::Transaction, UnitOfWorkError>> + Send;
+}
@@ -0,0 +1,14 @@
+//! Ingestion of telematics readings (odometer, engine hours, fuel level) into vehicle statuses.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * Readings arrive in batches, as a JSON payload (see `payload::telematics_payload`) posted over
+//!   HTTP or published to the broker of the organization (see `traits::telematics_broker`).
+//! * A reading names its vehicle by VIN or by a registered device id; readings of vehicles the
+//!   ingesting user doesn't see are rejected like unknown vehicles.
+//! * The readings of a vehicle are sampled before they become statuses (see
+//!   `domain::telematics::services::telematics_sampling_service`), and the due statuses of its
+//!   maintenance rules are recalculated with the new latest status.
+pub mod payload;
+pub mod traits;
+pub mod use_cases;
@@ -0,0 +1,95 @@
+use domain::{
+    telematics::{
+        entities::telematics_reading::TelematicsReading, value_types::device_id::DeviceId,
+    },
+    vehicle::value_types::vehicle_vin::VehicleVin,
+};
+use std::fmt;
+
+/// Most readings accepted in one payload.
+pub const MAX_PAYLOAD_READINGS: usize = 10_000;
+
+/// A batch of readings, as posted over HTTP or published to the broker:
+/// `{"readings": [{"vin": "...", "recorded_at": "2025-10-01T08:00:00Z", "odometer": 1200}]}`.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+pub struct TelematicsPayload {
+    pub readings: Vec<TelematicsPayloadReading>,
+}
+
+/// A reading of the payload, values are kept raw until `parse` validates them.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+pub struct TelematicsPayloadReading {
+    /// VIN of the vehicle, when the reading isn't keyed by device.
+    #[serde(default)]
+    pub vin: Option<String>,
+    /// Id of a registered device, when the reading isn't keyed by VIN.
+    #[serde(default)]
+    pub device_id: Option<String>,
+    pub recorded_at: chrono::DateTime<chrono::Utc>,
+    #[serde(default)]
+    pub odometer: Option<i32>,
+    #[serde(default)]
+    pub engine_hours: Option<i32>,
+    #[serde(default)]
+    pub fuel_level: Option<i32>,
+}
+
+/// How a reading names its vehicle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum VehicleKey {
+    Vin(VehicleVin),
+    Device(DeviceId),
+}
+
+impl fmt::Display for VehicleKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VehicleKey::Vin(vin) => write!(f, "vin {}", vin.value()),
+            VehicleKey::Device(device_id) => write!(f, "device {}", device_id),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TelematicsPayloadError {
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("Too many readings: maximum {MAX_PAYLOAD_READINGS}, got {0}")]
+    TooManyReadings(usize),
+}
+
+impl TelematicsPayload {
+    /// Parses a JSON payload.
+    pub fn from_json(content: &[u8]) -> Result<Self, TelematicsPayloadError> {
+        let payload: TelematicsPayload = serde_json::from_slice(content)?;
+        if payload.readings.len() > MAX_PAYLOAD_READINGS {
+            return Err(TelematicsPayloadError::TooManyReadings(
+                payload.readings.len(),
+            ));
+        }
+        Ok(payload)
+    }
+}
+
+impl TelematicsPayloadReading {
+    /// Validates the reading, the error is the reason it is rejected.
+    pub fn parse(&self) -> Result<(VehicleKey, TelematicsReading), String> {
+        let key = match (&self.vin, &self.device_id) {
+            (Some(vin), None) => VehicleKey::Vin(VehicleVin::new(vin).map_err(|e| e.to_string())?),
+            (None, Some(device_id)) => {
+                VehicleKey::Device(DeviceId::new(device_id).map_err(|e| e.to_string())?)
+            }
+            (Some(_), Some(_)) => return Err("Reading has both a VIN and a device id".to_string()),
+            (None, None) => return Err("Reading has neither a VIN nor a device id".to_string()),
+        };
+        let reading = TelematicsReading::new(
+            self.recorded_at,
+            self.odometer,
+            self.engine_hours,
+            self.fuel_level,
+        )
+        .map_err(|e| e.to_string())?;
+
+        Ok((key, reading))
+    }
+}
@@ -0,0 +1 @@
+pub mod telematics_broker;
@@ -0,0 +1,36 @@
+use std::future::Future;
+
+/// A message published by a device or a gateway, its payload is a `TelematicsPayload`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TelematicsMessage {
+    /// Topic the message was published to (e.g., `fleet/<organization>/telematics/<device>`).
+    pub topic: String,
+    pub payload: Vec<u8>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TelematicsBrokerError {
+    #[error("broker error: {0}")]
+    Broker(String),
+}
+
+/// Message broker (MQTT) the telematics messages of an organization are received from
+pub trait TelematicsBroker: Send + Sync {
+    /// Takes at most `limit` pending messages of the organization off the broker, in the order
+    /// they were published (a message is received once)
+    fn receive(
+        &self,
+        organization_id: uuid::Uuid,
+        limit: usize,
+    ) -> impl Future<Output = Result<Vec<TelematicsMessage>, TelematicsBrokerError>> + Send;
+}
+
+/// Topic filter of the telematics messages of an organization.
+pub fn organization_topic(organization_id: uuid::Uuid) -> String {
+    format!("fleet/{}/telematics/#", organization_id)
+}
+
+/// Topic a device of an organization publishes its messages to.
+pub fn device_topic(organization_id: uuid::Uuid, device_id: &str) -> String {
+    format!("fleet/{}/telematics/{}", organization_id, device_id)
+}
@@ -0,0 +1,27 @@
+use crate::telematics::use_cases::commands::ingest_telematics::IngestTelematicsResponse;
+use domain::telematics::services::telematics_sampling_service::SamplingSettings;
+
+/// Messages taken off the broker at once when no limit is given.
+pub const DEFAULT_MAX_MESSAGES: usize = 100;
+
+pub struct ConsumeTelematicsMessagesCommand {
+    /// Most messages to take off the broker (`0` means the default).
+    pub max_messages: usize,
+    pub sampling: SamplingSettings,
+}
+
+/// A message whose payload couldn't be read, it is dropped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidTelematicsMessage {
+    pub topic: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ConsumeTelematicsMessagesResponse {
+    /// Messages taken off the broker.
+    pub messages: usize,
+    pub invalid_messages: Vec<InvalidTelematicsMessage>,
+    /// The ingestion of the valid messages, added up.
+    pub ingestion: IngestTelematicsResponse,
+}
@@ -0,0 +1,12 @@
+use crate::telematics::{
+    traits::telematics_broker::TelematicsBrokerError,
+    use_cases::commands::ingest_telematics::IngestTelematicsError,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ConsumeTelematicsMessagesError {
+    #[error("Broker error: {0}")]
+    Broker(#[from] TelematicsBrokerError),
+    #[error("Ingestion error: {0}")]
+    Ingestion(#[from] IngestTelematicsError),
+}
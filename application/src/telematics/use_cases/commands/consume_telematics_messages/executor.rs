@@ -0,0 +1,106 @@
+use super::{
+    dto::{
+        ConsumeTelematicsMessagesCommand as Input, ConsumeTelematicsMessagesResponse as Output,
+        DEFAULT_MAX_MESSAGES, InvalidTelematicsMessage,
+    },
+    error::ConsumeTelematicsMessagesError as Error,
+};
+use crate::{
+    auth::AuthenticatedUser,
+    shared::traits::unit_of_work::UnitOfWork,
+    telematics::{
+        traits::telematics_broker::TelematicsBroker,
+        use_cases::commands::ingest_telematics::{
+            IngestTelematicsCommand, IngestTelematicsError, IngestTelematicsUseCase,
+        },
+    },
+    vehicle::traits::vehicle_repository::VehicleApplicationRepository,
+};
+use domain::{
+    maintenance::repositories::maintenance_repository::MaintenanceRepository,
+    telematics::repositories::telematics_device_repository::TelematicsDeviceRepository,
+};
+
+pub struct ConsumeTelematicsMessagesUseCase<'a, TB, VAR, TDR, MR, UOW>
+where
+    TB: TelematicsBroker + 'a,
+    VAR: VehicleApplicationRepository + 'a,
+    TDR: TelematicsDeviceRepository + 'a,
+    MR: MaintenanceRepository + 'a,
+    UOW: UnitOfWork + 'a,
+{
+    telematics_broker: &'a TB,
+    ingest: IngestTelematicsUseCase<'a, VAR, TDR, MR, UOW>,
+}
+
+impl<'a, TB, VAR, TDR, MR, UOW> ConsumeTelematicsMessagesUseCase<'a, TB, VAR, TDR, MR, UOW>
+where
+    TB: TelematicsBroker + 'a,
+    VAR: VehicleApplicationRepository + 'a,
+    TDR: TelematicsDeviceRepository + 'a,
+    MR: MaintenanceRepository + 'a,
+    UOW: UnitOfWork + 'a,
+{
+    pub fn new(
+        telematics_broker: &'a TB,
+        vehicle_repository: &'a VAR,
+        telematics_device_repository: &'a TDR,
+        maintenance_repository: &'a MR,
+        unit_of_work: &'a UOW,
+    ) -> Self {
+        ConsumeTelematicsMessagesUseCase {
+            telematics_broker,
+            ingest: IngestTelematicsUseCase::new(
+                vehicle_repository,
+                telematics_device_repository,
+                maintenance_repository,
+                unit_of_work,
+            ),
+        }
+    }
+
+    /// Ingests the pending messages of the organization of the user, in the order they were
+    /// published.
+    ///
+    /// A message is taken off the broker before it is ingested: a message whose payload can't be
+    /// read is reported and dropped, a redelivered one is dropped by the sampling as duplicates.
+    pub async fn execute(&self, cmd: Input, user: &AuthenticatedUser) -> Result<Output, Error> {
+        let max_messages = match cmd.max_messages {
+            0 => DEFAULT_MAX_MESSAGES,
+            max_messages => max_messages,
+        };
+        let messages = self
+            .telematics_broker
+            .receive(user.organization_id, max_messages)
+            .await?;
+
+        let mut output = Output {
+            messages: messages.len(),
+            ..Output::default()
+        };
+        for message in messages {
+            let ingested = self
+                .ingest
+                .execute(
+                    IngestTelematicsCommand {
+                        payload: message.payload,
+                        sampling: cmd.sampling,
+                    },
+                    user,
+                )
+                .await;
+            match ingested {
+                Ok(ingested) => output.ingestion.merge(ingested),
+                Err(IngestTelematicsError::InvalidPayload(e)) => {
+                    output.invalid_messages.push(InvalidTelematicsMessage {
+                        topic: message.topic,
+                        reason: e.to_string(),
+                    })
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        Ok(output)
+    }
+}
@@ -0,0 +1,55 @@
+use domain::{
+    maintenance::entities::maintenance_status::MaintenanceStatusLevel,
+    telematics::services::telematics_sampling_service::SamplingSettings,
+};
+
+pub struct IngestTelematicsCommand {
+    /// The JSON payload (`TelematicsPayload`), as received.
+    pub payload: Vec<u8>,
+    pub sampling: SamplingSettings,
+}
+
+/// A reading that was not stored, and why.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RejectedTelematicsReading {
+    /// The VIN or device the reading named, when valid.
+    pub vehicle: Option<String>,
+    pub recorded_at: chrono::DateTime<chrono::Utc>,
+    pub reason: String,
+}
+
+/// A maintenance rule whose due status changed with the new readings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DueStatusChange {
+    pub vehicle_id: uuid::Uuid,
+    pub maintenance_id: i32,
+    pub from: MaintenanceStatusLevel,
+    pub to: MaintenanceStatusLevel,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct IngestTelematicsResponse {
+    /// Readings in the payload.
+    pub received: usize,
+    /// Readings stored as vehicle statuses.
+    pub stored: usize,
+    pub duplicates: usize,
+    /// Stored readings recorded before the latest status of their vehicle.
+    pub late: usize,
+    pub throttled: usize,
+    pub rejected: Vec<RejectedTelematicsReading>,
+    pub due_status_changes: Vec<DueStatusChange>,
+}
+
+impl IngestTelematicsResponse {
+    /// Adds the counts of another ingestion.
+    pub fn merge(&mut self, other: IngestTelematicsResponse) {
+        self.received += other.received;
+        self.stored += other.stored;
+        self.duplicates += other.duplicates;
+        self.late += other.late;
+        self.throttled += other.throttled;
+        self.rejected.extend(other.rejected);
+        self.due_status_changes.extend(other.due_status_changes);
+    }
+}
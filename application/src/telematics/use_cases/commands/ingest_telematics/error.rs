@@ -0,0 +1,31 @@
+use crate::{
+    shared::traits::unit_of_work::UnitOfWorkError,
+    telematics::payload::telematics_payload::TelematicsPayloadError,
+    vehicle::traits::vehicle_repository::VehicleApplicationRepositoryError,
+};
+use domain::{
+    maintenance::repositories::{
+        maintenance_record_repository::MaintenanceRecordRepositoryError,
+        maintenance_repository::MaintenanceRepositoryError,
+    },
+    telematics::repositories::telematics_device_repository::TelematicsDeviceRepositoryError,
+    vehicle::repositories::vehicle_status_repository::VehicleStatusRepositoryError,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum IngestTelematicsError {
+    #[error("Invalid payload: {0}")]
+    InvalidPayload(#[from] TelematicsPayloadError),
+    #[error("Vehicle repository error: {0}")]
+    VehicleRepository(#[from] VehicleApplicationRepositoryError),
+    #[error("Telematics device repository error: {0}")]
+    TelematicsDeviceRepository(#[from] TelematicsDeviceRepositoryError),
+    #[error("Vehicle status repository error: {0}")]
+    VehicleStatusRepository(#[from] VehicleStatusRepositoryError),
+    #[error("Maintenance repository error: {0}")]
+    MaintenanceRepository(#[from] MaintenanceRepositoryError),
+    #[error("Maintenance record repository error: {0}")]
+    MaintenanceRecordRepository(#[from] MaintenanceRecordRepositoryError),
+    #[error("Transaction error: {0}")]
+    UnitOfWork(#[from] UnitOfWorkError),
+}
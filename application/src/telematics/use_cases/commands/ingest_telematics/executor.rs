@@ -0,0 +1,303 @@
+use super::{
+    dto::{
+        DueStatusChange, IngestTelematicsCommand as Input, IngestTelematicsResponse as Output,
+        RejectedTelematicsReading,
+    },
+    error::IngestTelematicsError as Error,
+};
+use crate::{
+    auth::AuthenticatedUser,
+    shared::traits::unit_of_work::{Transaction, UnitOfWork},
+    telematics::payload::telematics_payload::{TelematicsPayload, VehicleKey},
+    vehicle::{
+        filters::vehicle_filter::{NewVehicleFilter, VehicleFilter},
+        scope::vehicle_in_scope,
+        traits::vehicle_repository::VehicleApplicationRepository,
+    },
+};
+use domain::{
+    maintenance::{
+        entities::maintenance_status::MaintenanceStatus,
+        repositories::{
+            maintenance_record_repository::MaintenanceRecordRepository,
+            maintenance_repository::MaintenanceRepository,
+        },
+    },
+    telematics::{
+        entities::telematics_reading::TelematicsReading,
+        repositories::telematics_device_repository::TelematicsDeviceRepository,
+        services::telematics_sampling_service::{SampledReading, sample},
+    },
+    vehicle::{
+        entities::vehicle_status::VehicleStatusIdentity,
+        repositories::vehicle_status_repository::VehicleStatusRepository,
+    },
+};
+use std::collections::{BTreeMap, HashMap};
+
+pub struct IngestTelematicsUseCase<'a, VAR, TDR, MR, UOW>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    TDR: TelematicsDeviceRepository + 'a,
+    MR: MaintenanceRepository + 'a,
+    UOW: UnitOfWork + 'a,
+{
+    vehicle_repository: &'a VAR,
+    telematics_device_repository: &'a TDR,
+    maintenance_repository: &'a MR,
+    unit_of_work: &'a UOW,
+}
+
+impl<'a, VAR, TDR, MR, UOW> IngestTelematicsUseCase<'a, VAR, TDR, MR, UOW>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    TDR: TelematicsDeviceRepository + 'a,
+    MR: MaintenanceRepository + 'a,
+    UOW: UnitOfWork + 'a,
+{
+    pub fn new(
+        vehicle_repository: &'a VAR,
+        telematics_device_repository: &'a TDR,
+        maintenance_repository: &'a MR,
+        unit_of_work: &'a UOW,
+    ) -> Self {
+        IngestTelematicsUseCase {
+            vehicle_repository,
+            telematics_device_repository,
+            maintenance_repository,
+            unit_of_work,
+        }
+    }
+
+    /// Stores the sampled readings of a payload as vehicle statuses and reports the maintenance
+    /// rules whose due status changed.
+    ///
+    /// An invalid reading, or one of an unknown vehicle, is rejected on its own; only a payload
+    /// that can't be read fails the whole batch. The statuses of the batch are written in one
+    /// transaction, a failure stores none of them.
+    pub async fn execute(&self, cmd: Input, user: &AuthenticatedUser) -> Result<Output, Error> {
+        let payload = TelematicsPayload::from_json(&cmd.payload)?;
+        let mut output = Output {
+            received: payload.readings.len(),
+            ..Output::default()
+        };
+
+        // Group the readings by vehicle, resolving each VIN or device once
+        let mut vehicles: HashMap<VehicleKey, Option<uuid::Uuid>> = HashMap::new();
+        let mut readings: BTreeMap<uuid::Uuid, Vec<(VehicleKey, TelematicsReading)>> =
+            BTreeMap::new();
+        for raw in &payload.readings {
+            let (key, reading) = match raw.parse() {
+                Ok(parsed) => parsed,
+                Err(reason) => {
+                    output.rejected.push(RejectedTelematicsReading {
+                        vehicle: None,
+                        recorded_at: raw.recorded_at,
+                        reason,
+                    });
+                    continue;
+                }
+            };
+            let vehicle_id = match vehicles.get(&key) {
+                Some(vehicle_id) => *vehicle_id,
+                None => {
+                    let vehicle_id = self.resolve(&key, user).await?;
+                    vehicles.insert(key.clone(), vehicle_id);
+                    vehicle_id
+                }
+            };
+            match vehicle_id {
+                Some(vehicle_id) => readings.entry(vehicle_id).or_default().push((key, reading)),
+                None => output.rejected.push(RejectedTelematicsReading {
+                    vehicle: Some(key.to_string()),
+                    recorded_at: reading.recorded_at,
+                    reason: format!("Unknown vehicle: {}", key),
+                }),
+            }
+        }
+
+        let transaction = self.unit_of_work.begin(user.organization_id).await?;
+        let statuses = transaction.vehicle_statuses();
+        for (vehicle_id, keyed) in readings {
+            // The key of a reading by its time, the first one of a duplicated time
+            let mut keys: HashMap<chrono::DateTime<chrono::Utc>, VehicleKey> = HashMap::new();
+            let mut vehicle_readings = Vec::with_capacity(keyed.len());
+            for (key, reading) in keyed {
+                keys.entry(reading.recorded_at).or_insert(key);
+                vehicle_readings.push(reading);
+            }
+
+            // Late readings are sampled into the stored history from the status before them on
+            let latest = statuses
+                .find_latest(user.organization_id, vehicle_id)
+                .await?;
+            let earliest = vehicle_readings
+                .iter()
+                .map(|reading| reading.recorded_at)
+                .min();
+            let history = match (&latest, earliest) {
+                (Some(latest), Some(earliest)) if earliest < latest.performed_at => {
+                    let mut history: Vec<_> = statuses
+                        .find_latest_before(user.organization_id, vehicle_id, earliest)
+                        .await?
+                        .into_iter()
+                        .collect();
+                    history.extend(
+                        statuses
+                            .find_by_vehicle(user.organization_id, vehicle_id, Some(earliest), None)
+                            .await?,
+                    );
+                    history
+                }
+                (latest, _) => latest.iter().cloned().collect(),
+            };
+            let sampled = sample(vehicle_readings, &history, &cmd.sampling);
+            output.duplicates += sampled.duplicates;
+            output.late += sampled.late;
+            output.throttled += sampled.throttled;
+            output
+                .rejected
+                .extend(sampled.rejected.into_iter().map(|(reading, rejection)| {
+                    RejectedTelematicsReading {
+                        vehicle: keys.get(&reading.recorded_at).map(VehicleKey::to_string),
+                        recorded_at: reading.recorded_at,
+                        reason: rejection.to_string(),
+                    }
+                }));
+            if sampled.kept.is_empty() {
+                continue;
+            }
+
+            // A late status is inserted before the latest one, which stays
+            let mut new_latest = latest.clone();
+            for kept in sampled.kept {
+                let status = statuses
+                    .create(
+                        user.organization_id,
+                        new_status(vehicle_id, keys.get(&kept.recorded_at), kept, user),
+                    )
+                    .await?;
+                if new_latest
+                    .as_ref()
+                    .is_none_or(|latest| status.performed_at >= latest.performed_at)
+                {
+                    new_latest = Some(status);
+                }
+                output.stored += 1;
+            }
+
+            output.due_status_changes.extend(
+                self.due_status_changes(
+                    &transaction,
+                    user.organization_id,
+                    vehicle_id,
+                    latest.as_ref(),
+                    new_latest.as_ref(),
+                )
+                .await?,
+            );
+        }
+        transaction.commit().await?;
+
+        Ok(output)
+    }
+
+    /// Returns the vehicle a reading names, `None` when unknown or out of the scope of the user.
+    async fn resolve(
+        &self,
+        key: &VehicleKey,
+        user: &AuthenticatedUser,
+    ) -> Result<Option<uuid::Uuid>, Error> {
+        let vin = match key {
+            VehicleKey::Vin(vin) => vin,
+            VehicleKey::Device(device_id) => {
+                let device = self
+                    .telematics_device_repository
+                    .find_by_device_id(user.organization_id, device_id)
+                    .await?;
+                let Some(device) = device else {
+                    return Ok(None);
+                };
+                let in_scope =
+                    vehicle_in_scope(self.vehicle_repository, user, device.vehicle_id).await?;
+                return Ok(in_scope.then_some(device.vehicle_id));
+            }
+        };
+
+        let filter = VehicleFilter::new(NewVehicleFilter {
+            vin: Some(vin.clone()),
+            ..NewVehicleFilter::default()
+        });
+        let found = self
+            .vehicle_repository
+            .get_by_filter(user.organization_id, filter.scoped_to(user))
+            .await?;
+        Ok(found
+            .first()
+            .and_then(|vehicle| uuid::Uuid::parse_str(&vehicle.id).ok()))
+    }
+
+    /// Compares the due status of every maintenance rule of the vehicle before and after the new
+    /// latest status.
+    async fn due_status_changes(
+        &self,
+        transaction: &UOW::Transaction,
+        organization_id: uuid::Uuid,
+        vehicle_id: uuid::Uuid,
+        before: Option<&VehicleStatusIdentity>,
+        after: Option<&VehicleStatusIdentity>,
+    ) -> Result<Vec<DueStatusChange>, Error> {
+        let today = chrono::Utc::now().date_naive();
+        let rules = self
+            .maintenance_repository
+            .find_by_vehicle(organization_id, vehicle_id)
+            .await?;
+
+        let mut changes = Vec::new();
+        for rule in rules {
+            let last_record = transaction
+                .maintenance_records()
+                .find_latest_by_maintenance(organization_id, rule.identity.id)
+                .await?;
+            let from =
+                MaintenanceStatus::calculate(&rule.identity, last_record.as_ref(), before, today);
+            let to =
+                MaintenanceStatus::calculate(&rule.identity, last_record.as_ref(), after, today);
+            if from.level != to.level {
+                changes.push(DueStatusChange {
+                    vehicle_id,
+                    maintenance_id: rule.identity.id,
+                    from: from.level,
+                    to: to.level,
+                });
+            }
+        }
+
+        Ok(changes)
+    }
+}
+
+/// The status a kept reading is stored as (the id is assigned by the storage).
+fn new_status(
+    vehicle_id: uuid::Uuid,
+    key: Option<&VehicleKey>,
+    reading: SampledReading,
+    user: &AuthenticatedUser,
+) -> VehicleStatusIdentity {
+    let now = chrono::Utc::now();
+    VehicleStatusIdentity {
+        id: 0,
+        vehicle_id,
+        performed_by: user.user_id,
+        performed_at: reading.recorded_at,
+        odometer: reading.odometer,
+        engine_hour_meter: reading.engine_hour_meter,
+        fuel_level: reading.fuel_level,
+        notes: match key {
+            Some(key) => format!("Telematics reading ({})", key),
+            None => "Telematics reading".to_string(),
+        },
+        created_at: now,
+        updated_at: now,
+    }
+}
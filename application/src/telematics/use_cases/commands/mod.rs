@@ -0,0 +1,3 @@
+pub mod consume_telematics_messages;
+pub mod ingest_telematics;
+pub mod register_telematics_device;
@@ -0,0 +1,22 @@
+use domain::telematics::entities::telematics_device::TelematicsDevice;
+
+pub struct RegisterTelematicsDeviceCommand {
+    pub device_id: String,
+    pub vehicle_id: uuid::Uuid,
+}
+
+pub struct RegisterTelematicsDeviceResponse {
+    pub device_id: String,
+    pub vehicle_id: uuid::Uuid,
+    pub registered_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<TelematicsDevice> for RegisterTelematicsDeviceResponse {
+    fn from(device: TelematicsDevice) -> Self {
+        RegisterTelematicsDeviceResponse {
+            device_id: device.device_id.value().to_string(),
+            vehicle_id: device.vehicle_id,
+            registered_at: device.registered_at,
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use crate::vehicle::traits::vehicle_repository::VehicleApplicationRepositoryError;
+use domain::telematics::{
+    repositories::telematics_device_repository::TelematicsDeviceRepositoryError,
+    value_types::device_id::DeviceIdError,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum RegisterTelematicsDeviceError {
+    #[error("Invalid device id: {0}")]
+    InvalidDeviceId(#[from] DeviceIdError),
+    #[error("Vehicle not found: {0}")]
+    VehicleNotFound(uuid::Uuid),
+    #[error("Device already registered: {0}")]
+    AlreadyExists(String),
+    #[error("Vehicle repository error: {0}")]
+    VehicleRepository(#[from] VehicleApplicationRepositoryError),
+    #[error("Telematics device repository error: {0}")]
+    TelematicsDeviceRepository(#[from] TelematicsDeviceRepositoryError),
+}
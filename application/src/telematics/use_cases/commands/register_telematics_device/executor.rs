@@ -0,0 +1,68 @@
+use super::{
+    dto::{RegisterTelematicsDeviceCommand as Input, RegisterTelematicsDeviceResponse as Output},
+    error::RegisterTelematicsDeviceError as Error,
+};
+use crate::{
+    auth::AuthenticatedUser,
+    vehicle::{
+        scope::ensure_vehicle_in_scope, traits::vehicle_repository::VehicleApplicationRepository,
+    },
+};
+use domain::telematics::{
+    entities::telematics_device::TelematicsDevice,
+    repositories::telematics_device_repository::{
+        TelematicsDeviceRepository, TelematicsDeviceRepositoryError,
+    },
+    value_types::device_id::DeviceId,
+};
+
+pub struct RegisterTelematicsDeviceUseCase<'a, VAR, TDR>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    TDR: TelematicsDeviceRepository + 'a,
+{
+    vehicle_repository: &'a VAR,
+    telematics_device_repository: &'a TDR,
+}
+
+impl<'a, VAR, TDR> RegisterTelematicsDeviceUseCase<'a, VAR, TDR>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    TDR: TelematicsDeviceRepository + 'a,
+{
+    pub fn new(vehicle_repository: &'a VAR, telematics_device_repository: &'a TDR) -> Self {
+        RegisterTelematicsDeviceUseCase {
+            vehicle_repository,
+            telematics_device_repository,
+        }
+    }
+
+    /// Registers a device fitted in a vehicle the user sees.
+    pub async fn execute(&self, cmd: Input, user: &AuthenticatedUser) -> Result<Output, Error> {
+        let device_id = DeviceId::new(cmd.device_id)?;
+
+        ensure_vehicle_in_scope(
+            self.vehicle_repository,
+            user,
+            cmd.vehicle_id,
+            Error::VehicleNotFound(cmd.vehicle_id),
+        )
+        .await?;
+
+        let registered = self
+            .telematics_device_repository
+            .register(
+                user.organization_id,
+                TelematicsDevice::new(device_id, cmd.vehicle_id),
+            )
+            .await
+            .map_err(|e| match e {
+                TelematicsDeviceRepositoryError::AlreadyExists(device_id) => {
+                    Error::AlreadyExists(device_id)
+                }
+                e => e.into(),
+            })?;
+
+        Ok(Output::from(registered))
+    }
+}
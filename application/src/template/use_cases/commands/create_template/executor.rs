@@ -1,3 +1,4 @@
+use crate::auth::AuthenticatedUser;
 use domain::vehicle::{
     entities::vehicle::{NewVehicle, VehicleError, VehicleIdentity},
     repositories::vehicle_repository::{VehicleRepository, VehicleRepositoryError},
@@ -12,12 +13,19 @@ impl<'a, VR: VehicleRepository + 'a> CreateVehicleUseCase<'a, VR> {
         CreateVehicleUseCase { vehicle_repository }
     }
 
-    pub async fn execute(&self, data: CreateVehicleCmd) -> Result<VehicleIdentity, Error> {
+    pub async fn execute(
+        &self,
+        data: CreateVehicleCmd,
+        user: &AuthenticatedUser,
+    ) -> Result<VehicleIdentity, Error> {
         // Validate input data
         let vehicle: NewVehicle = data.try_into()?;
 
         // Create the vehicle
-        let vehicle = self.vehicle_repository.create(vehicle).await?;
+        let vehicle = self
+            .vehicle_repository
+            .create(user.organization_id, vehicle)
+            .await?;
 
         Ok(vehicle)
     }
@@ -0,0 +1,315 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+use quick_xml::{
+    Reader, Writer,
+    events::{BytesDecl, BytesEnd, BytesStart, BytesText, Event},
+};
+use std::io::Write;
+
+/// Namespace of GPX 1.1 documents.
+pub const GPX_NAMESPACE: &str = "http://www.topografix.com/GPX/1/1";
+
+/// Tracks of a GPX document (waypoints and routes are ignored).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GpxDocument {
+    pub tracks: Vec<GpxTrack>,
+}
+
+/// A track, the points of its segments joined in order.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GpxTrack {
+    pub name: Option<String>,
+    pub points: Vec<GpxPoint>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GpxPoint {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub time: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GpxError {
+    #[error("XML error: {0}")]
+    Xml(#[from] quick_xml::Error),
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Not a GPX document")]
+    NotGpx,
+    #[error("Track point without {0}")]
+    MissingCoordinate(&'static str),
+    #[error("Invalid coordinate: {0}")]
+    InvalidCoordinate(String),
+    #[error("Invalid time: {0}")]
+    InvalidTime(String),
+}
+
+impl GpxDocument {
+    /// Parses a GPX document.
+    pub fn parse(content: &str) -> Result<Self, GpxError> {
+        let mut reader = Reader::from_str(content);
+        reader.config_mut().trim_text(true);
+
+        let mut document = GpxDocument::default();
+        // Local names of the open elements
+        let mut path: Vec<Vec<u8>> = Vec::new();
+        loop {
+            match reader.read_event()? {
+                Event::Start(element) => {
+                    open(&mut document, &path, &element)?;
+                    path.push(element.local_name().as_ref().to_vec());
+                }
+                Event::Empty(element) => open(&mut document, &path, &element)?,
+                Event::End(_) => {
+                    path.pop();
+                }
+                Event::Text(text) => {
+                    let text = text.unescape()?;
+                    text_of(&mut document, &path, &text)?;
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+
+        Ok(document)
+    }
+
+    /// Writes the document, with every point's time when known.
+    pub fn render<W: Write>(&self, output: W) -> Result<(), GpxError> {
+        let mut writer = Writer::new_with_indent(output, b' ', 2);
+        writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+        writer.write_event(Event::Start(BytesStart::new("gpx").with_attributes([
+            ("version", "1.1"),
+            ("creator", "backend-vehicle-management"),
+            ("xmlns", GPX_NAMESPACE),
+        ])))?;
+
+        for track in &self.tracks {
+            writer.write_event(Event::Start(BytesStart::new("trk")))?;
+            if let Some(name) = &track.name {
+                write_text_element(&mut writer, "name", name)?;
+            }
+            writer.write_event(Event::Start(BytesStart::new("trkseg")))?;
+            for point in &track.points {
+                let latitude = format!("{:.5}", point.latitude);
+                let longitude = format!("{:.5}", point.longitude);
+                let element = BytesStart::new("trkpt")
+                    .with_attributes([("lat", latitude.as_str()), ("lon", longitude.as_str())]);
+                match point.time {
+                    Some(time) => {
+                        writer.write_event(Event::Start(element))?;
+                        write_text_element(
+                            &mut writer,
+                            "time",
+                            &time.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+                        )?;
+                        writer.write_event(Event::End(BytesEnd::new("trkpt")))?;
+                    }
+                    None => writer.write_event(Event::Empty(element))?,
+                }
+            }
+            writer.write_event(Event::End(BytesEnd::new("trkseg")))?;
+            writer.write_event(Event::End(BytesEnd::new("trk")))?;
+        }
+
+        writer.write_event(Event::End(BytesEnd::new("gpx")))?;
+        Ok(())
+    }
+}
+
+/// Whether the open elements end with `names`.
+fn ends_with(path: &[Vec<u8>], names: &[&str]) -> bool {
+    path.len() >= names.len()
+        && path[path.len() - names.len()..]
+            .iter()
+            .zip(names)
+            .all(|(open, name)| open.as_slice() == name.as_bytes())
+}
+
+/// Handles the start of an element.
+fn open(
+    document: &mut GpxDocument,
+    path: &[Vec<u8>],
+    element: &BytesStart,
+) -> Result<(), GpxError> {
+    match element.local_name().as_ref() {
+        b"gpx" if path.is_empty() => Ok(()),
+        _ if path.is_empty() => Err(GpxError::NotGpx),
+        b"trk" if ends_with(path, &["gpx"]) => {
+            document.tracks.push(GpxTrack::default());
+            Ok(())
+        }
+        b"trkpt" if ends_with(path, &["trk", "trkseg"]) => {
+            let point = GpxPoint {
+                latitude: coordinate(element, "lat")?,
+                longitude: coordinate(element, "lon")?,
+                time: None,
+            };
+            if let Some(track) = document.tracks.last_mut() {
+                track.points.push(point);
+            }
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Handles the text of an element.
+fn text_of(document: &mut GpxDocument, path: &[Vec<u8>], text: &str) -> Result<(), GpxError> {
+    let Some(track) = document.tracks.last_mut() else {
+        return Ok(());
+    };
+    if ends_with(path, &["gpx", "trk", "name"]) {
+        track.name = Some(text.to_string());
+    } else if ends_with(path, &["trkseg", "trkpt", "time"])
+        && let Some(point) = track.points.last_mut()
+    {
+        let time = DateTime::parse_from_rfc3339(text)
+            .map_err(|e| GpxError::InvalidTime(format!("{}: {}", text, e)))?;
+        point.time = Some(time.with_timezone(&Utc));
+    }
+    Ok(())
+}
+
+fn coordinate(element: &BytesStart, name: &'static str) -> Result<f64, GpxError> {
+    let attribute = element
+        .try_get_attribute(name)
+        .map_err(quick_xml::Error::from)?
+        .ok_or(GpxError::MissingCoordinate(name))?;
+    let value = attribute.unescape_value()?;
+    value
+        .trim()
+        .parse()
+        .map_err(|_| GpxError::InvalidCoordinate(value.to_string()))
+}
+
+fn write_text_element<W: Write>(
+    writer: &mut Writer<W>,
+    name: &str,
+    text: &str,
+) -> Result<(), GpxError> {
+    writer.write_event(Event::Start(BytesStart::new(name)))?;
+    writer.write_event(Event::Text(BytesText::new(text)))?;
+    writer.write_event(Event::End(BytesEnd::new(name)))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(hour: u32, minute: u32) -> Option<DateTime<Utc>> {
+        Some(Utc.with_ymd_and_hms(2025, 10, 1, hour, minute, 0).unwrap())
+    }
+
+    #[test]
+    fn test_malformed_documents_are_rejected() {
+        let mismatched = "<gpx><trk><trkseg></trk></gpx>";
+        assert!(matches!(
+            GpxDocument::parse(mismatched),
+            Err(GpxError::Xml(_))
+        ));
+        assert!(matches!(
+            GpxDocument::parse("<kml><Document/></kml>"),
+            Err(GpxError::NotGpx)
+        ));
+
+        let point = |attributes: &str, time: &str| {
+            format!(
+                "<gpx><trk><trkseg><trkpt {}><time>{}</time></trkpt></trkseg></trk></gpx>",
+                attributes, time
+            )
+        };
+        assert!(matches!(
+            GpxDocument::parse(&point(r#"lat="51.1""#, "2025-10-01T08:00:00Z")),
+            Err(GpxError::MissingCoordinate("lon"))
+        ));
+        assert!(matches!(
+            GpxDocument::parse(&point(r#"lat="north" lon="71.4""#, "2025-10-01T08:00:00Z")),
+            Err(GpxError::InvalidCoordinate(value)) if value == "north"
+        ));
+        assert!(matches!(
+            GpxDocument::parse(&point(r#"lat="51.1" lon="71.4""#, "yesterday")),
+            Err(GpxError::InvalidTime(_))
+        ));
+    }
+
+    #[test]
+    fn test_points_without_time() {
+        let content = r#"<?xml version="1.0"?>
+            <gpx version="1.1" xmlns="http://www.topografix.com/GPX/1/1">
+              <trk><trkseg>
+                <trkpt lat="51.12" lon="71.43"/>
+                <trkpt lat="51.13" lon="71.44"><ele>347</ele></trkpt>
+              </trkseg></trk>
+            </gpx>"#;
+        let document = GpxDocument::parse(content).unwrap();
+        let times: Vec<_> = document.tracks[0].points.iter().map(|p| p.time).collect();
+        assert_eq!(times, vec![None, None]);
+    }
+
+    #[test]
+    fn test_segments_of_a_track_are_joined() {
+        let content = r#"<gpx xmlns="http://www.topografix.com/GPX/1/1">
+              <wpt lat="0" lon="0"><name>Depot</name></wpt>
+              <trk>
+                <name>Astana - Karaganda</name>
+                <trkseg>
+                  <trkpt lat="51.12" lon="71.43"><time>2025-10-01T08:00:00Z</time></trkpt>
+                </trkseg>
+                <trkseg>
+                  <trkpt lat="50.5" lon="72.1"><time>2025-10-01T09:00:00Z</time></trkpt>
+                  <trkpt lat="49.8" lon="73.1"><time>2025-10-01T10:00:00Z</time></trkpt>
+                </trkseg>
+              </trk>
+              <trk><trkseg><trkpt lat="49.8" lon="73.1"/></trkseg></trk>
+            </gpx>"#;
+        let document = GpxDocument::parse(content).unwrap();
+        assert_eq!(document.tracks.len(), 2);
+        let track = &document.tracks[0];
+        assert_eq!(track.name.as_deref(), Some("Astana - Karaganda"));
+        let times: Vec<_> = track.points.iter().map(|p| p.time).collect();
+        assert_eq!(times, vec![at(8, 0), at(9, 0), at(10, 0)]);
+        assert_eq!(document.tracks[1].name, None);
+        assert_eq!(document.tracks[1].points.len(), 1);
+    }
+
+    #[test]
+    fn test_exported_documents_are_imported_back() {
+        let document = GpxDocument {
+            tracks: vec![
+                GpxTrack {
+                    name: Some("Delivery <north> & back".to_string()),
+                    points: vec![
+                        GpxPoint {
+                            latitude: 51.12845,
+                            longitude: 71.43042,
+                            time: at(8, 0),
+                        },
+                        GpxPoint {
+                            latitude: -33.5,
+                            longitude: -70.25,
+                            time: None,
+                        },
+                    ],
+                },
+                GpxTrack {
+                    name: None,
+                    points: vec![GpxPoint {
+                        latitude: 49.8,
+                        longitude: 73.1,
+                        time: at(9, 30),
+                    }],
+                },
+            ],
+        };
+
+        let mut output = Vec::new();
+        document.render(&mut output).unwrap();
+        let rendered = String::from_utf8(output).unwrap();
+        assert!(rendered.contains(GPX_NAMESPACE));
+        assert_eq!(GpxDocument::parse(&rendered).unwrap(), document);
+    }
+}
@@ -0,0 +1,3 @@
+//! GPX 1.1, the exchange format of GPS tracks: trips are imported from and exported to the tracks
+//! of a GPX document.
+pub mod gpx_document;
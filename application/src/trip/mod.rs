@@ -0,0 +1,12 @@
+//! Trips of the vehicles: recording GPS tracks, GPX import and export, and the reconciliation of
+//! their distance with the odometer readings.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * Trips are recorded for the vehicles the user sees; the driver is optional.
+//! * A GPX track becomes a trip when its first and last points have a time: the start and the end
+//!   of the trip. The times of the other points are not kept.
+//! * The reconciliation compares, for each pair of consecutive statuses of a vehicle, the odometer
+//!   delta with the distance of the trips (see `domain::trip::services`).
+pub mod gpx;
+pub mod use_cases;
@@ -0,0 +1,46 @@
+use rust_decimal::Decimal;
+
+/// Imports the tracks of a GPX document as trips of one vehicle.
+pub struct ImportGpxTripsCommand {
+    pub vehicle_id: uuid::Uuid,
+    /// The driver of every trip, `None` when unknown.
+    pub driver_id: Option<uuid::Uuid>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GpxTrackOutcome {
+    /// The track was stored as a trip.
+    Created {
+        id: uuid::Uuid,
+        distance_km: Decimal,
+    },
+    /// The track is not a valid trip, it is skipped.
+    Error { reason: String },
+}
+
+#[derive(Debug, Clone)]
+pub struct GpxTrackReport {
+    /// Position of the track in the document, from 1.
+    pub index: usize,
+    pub name: Option<String>,
+    pub point_count: usize,
+    pub outcome: GpxTrackOutcome,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ImportGpxTripsResponse {
+    pub tracks: Vec<GpxTrackReport>,
+    pub created: usize,
+    pub errors: usize,
+}
+
+impl ImportGpxTripsResponse {
+    /// Appends a track to the report and updates the counters.
+    pub fn push(&mut self, report: GpxTrackReport) {
+        match report.outcome {
+            GpxTrackOutcome::Created { .. } => self.created += 1,
+            GpxTrackOutcome::Error { .. } => self.errors += 1,
+        }
+        self.tracks.push(report);
+    }
+}
@@ -0,0 +1,112 @@
+use super::{
+    dto::{
+        GpxTrackOutcome, GpxTrackReport, ImportGpxTripsCommand as Input,
+        ImportGpxTripsResponse as Output,
+    },
+    error::ImportGpxTripsError as Error,
+};
+use crate::{
+    auth::AuthenticatedUser,
+    trip::gpx::gpx_document::{GpxDocument, GpxTrack},
+    vehicle::{
+        scope::ensure_vehicle_in_scope, traits::vehicle_repository::VehicleApplicationRepository,
+    },
+};
+use domain::trip::{
+    entities::trip::{NewTrip, Trip},
+    repositories::trip_repository::TripRepository,
+    value_types::geo_point::GeoPoint,
+};
+
+pub struct ImportGpxTripsUseCase<'a, VAR, TR>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    TR: TripRepository + 'a,
+{
+    vehicle_repository: &'a VAR,
+    trip_repository: &'a TR,
+}
+
+impl<'a, VAR, TR> ImportGpxTripsUseCase<'a, VAR, TR>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    TR: TripRepository + 'a,
+{
+    pub fn new(vehicle_repository: &'a VAR, trip_repository: &'a TR) -> Self {
+        ImportGpxTripsUseCase {
+            vehicle_repository,
+            trip_repository,
+        }
+    }
+
+    /// Creates a trip for each valid track of the document; invalid tracks are reported and
+    /// skipped, a document that isn't GPX fails as a whole.
+    pub async fn execute<R: std::io::Read>(
+        &self,
+        cmd: Input,
+        mut source: R,
+        user: &AuthenticatedUser,
+    ) -> Result<Output, Error> {
+        let mut content = String::new();
+        source.read_to_string(&mut content)?;
+        let document = GpxDocument::parse(&content)?;
+
+        ensure_vehicle_in_scope(
+            self.vehicle_repository,
+            user,
+            cmd.vehicle_id,
+            Error::VehicleNotFound(cmd.vehicle_id),
+        )
+        .await?;
+
+        let mut report = Output::default();
+        for (index, track) in document.tracks.into_iter().enumerate() {
+            let outcome = match trip_of(&cmd, &track) {
+                Ok(trip) => {
+                    let created = self
+                        .trip_repository
+                        .create(user.organization_id, trip)
+                        .await?;
+                    GpxTrackOutcome::Created {
+                        id: created.id,
+                        distance_km: created.distance_km,
+                    }
+                }
+                Err(reason) => GpxTrackOutcome::Error { reason },
+            };
+            report.push(GpxTrackReport {
+                index: index + 1,
+                name: track.name,
+                point_count: track.points.len(),
+                outcome,
+            });
+        }
+
+        Ok(report)
+    }
+}
+
+/// The trip of a track, or why the track isn't one.
+fn trip_of(cmd: &Input, track: &GpxTrack) -> Result<Trip, String> {
+    let (Some(first), Some(last)) = (track.points.first(), track.points.last()) else {
+        return Err("The track has no point".to_string());
+    };
+    let (Some(started_at), Some(ended_at)) = (first.time, last.time) else {
+        return Err("The first and last points of the track need a time".to_string());
+    };
+    let polyline = track
+        .points
+        .iter()
+        .map(|point| GeoPoint::new(point.latitude, point.longitude))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+
+    Trip::new(NewTrip {
+        vehicle_id: cmd.vehicle_id,
+        driver_id: cmd.driver_id,
+        started_at,
+        ended_at,
+        polyline,
+    })
+    .map_err(|e| e.to_string())
+}
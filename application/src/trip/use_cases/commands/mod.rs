@@ -0,0 +1,2 @@
+pub mod import_gpx_trips;
+pub mod record_trip;
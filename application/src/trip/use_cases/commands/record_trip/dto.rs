@@ -0,0 +1,40 @@
+use domain::trip::{entities::trip::Trip, value_types::polyline};
+use rust_decimal::Decimal;
+
+pub struct RecordTripCommand {
+    pub vehicle_id: uuid::Uuid,
+    pub driver_id: Option<uuid::Uuid>,
+    pub started_at: chrono::DateTime<chrono::Utc>,
+    pub ended_at: chrono::DateTime<chrono::Utc>,
+    /// The track, as (latitude, longitude) in degrees, in driving order.
+    pub points: Vec<(f64, f64)>,
+}
+
+pub struct RecordTripResponse {
+    pub id: uuid::Uuid,
+    pub vehicle_id: uuid::Uuid,
+    pub driver_id: Option<uuid::Uuid>,
+    pub started_at: chrono::DateTime<chrono::Utc>,
+    pub ended_at: chrono::DateTime<chrono::Utc>,
+    /// The track, as an encoded polyline.
+    pub polyline: String,
+    pub point_count: usize,
+    pub distance_km: Decimal,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<Trip> for RecordTripResponse {
+    fn from(trip: Trip) -> Self {
+        RecordTripResponse {
+            id: trip.id,
+            vehicle_id: trip.vehicle_id,
+            driver_id: trip.driver_id,
+            started_at: trip.started_at,
+            ended_at: trip.ended_at,
+            polyline: polyline::encode(&trip.polyline),
+            point_count: trip.polyline.len(),
+            distance_km: trip.distance_km,
+            created_at: trip.created_at,
+        }
+    }
+}
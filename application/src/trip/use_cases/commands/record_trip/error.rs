@@ -0,0 +1,19 @@
+use crate::vehicle::traits::vehicle_repository::VehicleApplicationRepositoryError;
+use domain::trip::{
+    entities::trip::TripError, repositories::trip_repository::TripRepositoryError,
+    value_types::geo_point::GeoPointError,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum RecordTripError {
+    #[error("Invalid point: {0}")]
+    InvalidPoint(#[from] GeoPointError),
+    #[error("Invalid trip: {0}")]
+    InvalidTrip(#[from] TripError),
+    #[error("Vehicle not found: {0}")]
+    VehicleNotFound(uuid::Uuid),
+    #[error("Vehicle repository error: {0}")]
+    VehicleRepository(#[from] VehicleApplicationRepositoryError),
+    #[error("Trip repository error: {0}")]
+    TripRepository(#[from] TripRepositoryError),
+}
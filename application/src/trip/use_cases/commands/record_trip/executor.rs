@@ -0,0 +1,68 @@
+use super::{
+    dto::{RecordTripCommand as Input, RecordTripResponse as Output},
+    error::RecordTripError as Error,
+};
+use crate::{
+    auth::AuthenticatedUser,
+    vehicle::{
+        scope::ensure_vehicle_in_scope, traits::vehicle_repository::VehicleApplicationRepository,
+    },
+};
+use domain::trip::{
+    entities::trip::{NewTrip, Trip},
+    repositories::trip_repository::TripRepository,
+    value_types::geo_point::GeoPoint,
+};
+
+pub struct RecordTripUseCase<'a, VAR, TR>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    TR: TripRepository + 'a,
+{
+    vehicle_repository: &'a VAR,
+    trip_repository: &'a TR,
+}
+
+impl<'a, VAR, TR> RecordTripUseCase<'a, VAR, TR>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    TR: TripRepository + 'a,
+{
+    pub fn new(vehicle_repository: &'a VAR, trip_repository: &'a TR) -> Self {
+        RecordTripUseCase {
+            vehicle_repository,
+            trip_repository,
+        }
+    }
+
+    /// Records a trip of a vehicle the user sees, its distance computed from the track.
+    pub async fn execute(&self, cmd: Input, user: &AuthenticatedUser) -> Result<Output, Error> {
+        let polyline = cmd
+            .points
+            .iter()
+            .map(|&(latitude, longitude)| GeoPoint::new(latitude, longitude))
+            .collect::<Result<Vec<_>, _>>()?;
+        let trip = Trip::new(NewTrip {
+            vehicle_id: cmd.vehicle_id,
+            driver_id: cmd.driver_id,
+            started_at: cmd.started_at,
+            ended_at: cmd.ended_at,
+            polyline,
+        })?;
+
+        ensure_vehicle_in_scope(
+            self.vehicle_repository,
+            user,
+            cmd.vehicle_id,
+            Error::VehicleNotFound(cmd.vehicle_id),
+        )
+        .await?;
+
+        let created = self
+            .trip_repository
+            .create(user.organization_id, trip)
+            .await?;
+
+        Ok(Output::from(created))
+    }
+}
@@ -0,0 +1,13 @@
+pub struct ExportTripsGpxQuery {
+    pub vehicle_id: uuid::Uuid,
+    /// Only trips ending at or after this moment are exported.
+    pub from: Option<chrono::DateTime<chrono::Utc>>,
+    /// Only trips starting at or before this moment are exported.
+    pub to: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+pub struct ExportTripsGpxResponse {
+    /// Number of tracks written, one per trip.
+    pub trip_count: usize,
+    pub point_count: usize,
+}
@@ -0,0 +1,19 @@
+use crate::{
+    trip::gpx::gpx_document::GpxError,
+    vehicle::traits::vehicle_repository::VehicleApplicationRepositoryError,
+};
+use domain::trip::repositories::trip_repository::TripRepositoryError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ExportTripsGpxError {
+    #[error("Invalid date range: 'from' must not be after 'to'")]
+    InvalidDateRange,
+    #[error("Vehicle not found: {0}")]
+    VehicleNotFound(uuid::Uuid),
+    #[error("Vehicle repository error: {0}")]
+    VehicleRepository(#[from] VehicleApplicationRepositoryError),
+    #[error("Trip repository error: {0}")]
+    TripRepository(#[from] TripRepositoryError),
+    #[error("GPX error: {0}")]
+    Gpx(#[from] GpxError),
+}
@@ -0,0 +1,94 @@
+use super::{
+    dto::{ExportTripsGpxQuery as Input, ExportTripsGpxResponse as Output},
+    error::ExportTripsGpxError as Error,
+};
+use crate::{
+    auth::AuthenticatedUser,
+    trip::gpx::gpx_document::{GpxDocument, GpxPoint, GpxTrack},
+    vehicle::{
+        scope::ensure_vehicle_in_scope, traits::vehicle_repository::VehicleApplicationRepository,
+    },
+};
+use domain::trip::{entities::trip::Trip, repositories::trip_repository::TripRepository};
+use std::io::Write;
+
+pub struct ExportTripsGpxUseCase<'a, VAR, TR>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    TR: TripRepository + 'a,
+{
+    vehicle_repository: &'a VAR,
+    trip_repository: &'a TR,
+}
+
+impl<'a, VAR, TR> ExportTripsGpxUseCase<'a, VAR, TR>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    TR: TripRepository + 'a,
+{
+    pub fn new(vehicle_repository: &'a VAR, trip_repository: &'a TR) -> Self {
+        ExportTripsGpxUseCase {
+            vehicle_repository,
+            trip_repository,
+        }
+    }
+
+    /// Writes the trips of a vehicle the user sees as a GPX document, a track per trip.
+    pub async fn execute<W: Write + Send>(
+        &self,
+        query: Input,
+        output: W,
+        user: &AuthenticatedUser,
+    ) -> Result<Output, Error> {
+        if let (Some(from), Some(to)) = (query.from, query.to)
+            && from > to
+        {
+            return Err(Error::InvalidDateRange);
+        }
+
+        ensure_vehicle_in_scope(
+            self.vehicle_repository,
+            user,
+            query.vehicle_id,
+            Error::VehicleNotFound(query.vehicle_id),
+        )
+        .await?;
+
+        let trips = self
+            .trip_repository
+            .find_by_vehicle(user.organization_id, query.vehicle_id, query.from, query.to)
+            .await?;
+
+        let document = GpxDocument {
+            tracks: trips.iter().map(track_of).collect(),
+        };
+        document.render(output)?;
+
+        Ok(Output {
+            trip_count: trips.len(),
+            point_count: trips.iter().map(|trip| trip.polyline.len()).sum(),
+        })
+    }
+}
+
+/// The track of a trip; only its first and last points have a time, the start and the end.
+fn track_of(trip: &Trip) -> GpxTrack {
+    let last = trip.polyline.len().saturating_sub(1);
+    GpxTrack {
+        name: Some(trip.id.to_string()),
+        points: trip
+            .polyline
+            .iter()
+            .enumerate()
+            .map(|(index, point)| GpxPoint {
+                latitude: point.latitude(),
+                longitude: point.longitude(),
+                time: match index {
+                    0 => Some(trip.started_at),
+                    _ if index == last => Some(trip.ended_at),
+                    _ => None,
+                },
+            })
+            .collect(),
+    }
+}
@@ -0,0 +1,27 @@
+use domain::trip::services::distance_reconciliation_service::{
+    DistanceReconciliation, ReconciliationSettings,
+};
+use rust_decimal::Decimal;
+
+pub struct GetDistanceReconciliationQuery {
+    pub vehicle_id: uuid::Uuid,
+    /// Only statuses performed at or after this moment are reconciled.
+    pub from: Option<chrono::DateTime<chrono::Utc>>,
+    /// Only statuses performed at or before this moment are reconciled.
+    pub to: Option<chrono::DateTime<chrono::Utc>>,
+    /// Tolerance of the comparison, the defaults of `ReconciliationSettings` when `None`.
+    pub settings: Option<ReconciliationSettings>,
+}
+
+pub struct GetDistanceReconciliationResponse {
+    pub vehicle_id: uuid::Uuid,
+    pub settings: ReconciliationSettings,
+    /// A period per pair of consecutive statuses, in order.
+    pub periods: Vec<DistanceReconciliation>,
+    /// Sum of the odometer deltas of the periods.
+    pub odometer_km: i64,
+    /// Sum of the GPS distances of the periods.
+    pub gps_km: Decimal,
+    /// Number of periods flagged as discrepancies.
+    pub discrepancies: usize,
+}
@@ -0,0 +1,21 @@
+use crate::vehicle::traits::vehicle_repository::VehicleApplicationRepositoryError;
+use domain::{
+    trip::repositories::trip_repository::TripRepositoryError,
+    vehicle::repositories::vehicle_status_repository::VehicleStatusRepositoryError,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetDistanceReconciliationError {
+    #[error("Invalid date range: 'from' must not be after 'to'")]
+    InvalidDateRange,
+    #[error("Invalid tolerance: it must not be negative")]
+    InvalidTolerance,
+    #[error("Vehicle not found: {0}")]
+    VehicleNotFound(uuid::Uuid),
+    #[error("Vehicle repository error: {0}")]
+    VehicleRepository(#[from] VehicleApplicationRepositoryError),
+    #[error("Vehicle status repository error: {0}")]
+    VehicleStatusRepository(#[from] VehicleStatusRepositoryError),
+    #[error("Trip repository error: {0}")]
+    TripRepository(#[from] TripRepositoryError),
+}
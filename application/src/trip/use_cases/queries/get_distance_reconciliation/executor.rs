@@ -0,0 +1,101 @@
+use super::{
+    dto::{GetDistanceReconciliationQuery as Input, GetDistanceReconciliationResponse as Output},
+    error::GetDistanceReconciliationError as Error,
+};
+use crate::{
+    auth::AuthenticatedUser,
+    vehicle::{
+        scope::ensure_vehicle_in_scope, traits::vehicle_repository::VehicleApplicationRepository,
+    },
+};
+use domain::{
+    trip::{
+        repositories::trip_repository::TripRepository,
+        services::distance_reconciliation_service::reconcile,
+    },
+    vehicle::repositories::vehicle_status_repository::VehicleStatusRepository,
+};
+use rust_decimal::Decimal;
+
+pub struct GetDistanceReconciliationUseCase<'a, VAR, VSR, TR>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    VSR: VehicleStatusRepository + 'a,
+    TR: TripRepository + 'a,
+{
+    vehicle_repository: &'a VAR,
+    vehicle_status_repository: &'a VSR,
+    trip_repository: &'a TR,
+}
+
+impl<'a, VAR, VSR, TR> GetDistanceReconciliationUseCase<'a, VAR, VSR, TR>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    VSR: VehicleStatusRepository + 'a,
+    TR: TripRepository + 'a,
+{
+    pub fn new(
+        vehicle_repository: &'a VAR,
+        vehicle_status_repository: &'a VSR,
+        trip_repository: &'a TR,
+    ) -> Self {
+        GetDistanceReconciliationUseCase {
+            vehicle_repository,
+            vehicle_status_repository,
+            trip_repository,
+        }
+    }
+
+    /// Compares the odometer delta between consecutive statuses of a vehicle the user sees with
+    /// the distance of its trips.
+    pub async fn execute(&self, query: Input, user: &AuthenticatedUser) -> Result<Output, Error> {
+        if let (Some(from), Some(to)) = (query.from, query.to)
+            && from > to
+        {
+            return Err(Error::InvalidDateRange);
+        }
+        let settings = query.settings.unwrap_or_default();
+        if settings.tolerance_ratio < Decimal::ZERO || settings.min_tolerance_km < Decimal::ZERO {
+            return Err(Error::InvalidTolerance);
+        }
+
+        ensure_vehicle_in_scope(
+            self.vehicle_repository,
+            user,
+            query.vehicle_id,
+            Error::VehicleNotFound(query.vehicle_id),
+        )
+        .await?;
+
+        let statuses = self
+            .vehicle_status_repository
+            .find_by_vehicle(user.organization_id, query.vehicle_id, query.from, query.to)
+            .await?;
+
+        // Only the trips between the first and the last status can count
+        let periods = match (statuses.first(), statuses.last()) {
+            (Some(first), Some(last)) if first.performed_at < last.performed_at => {
+                let trips = self
+                    .trip_repository
+                    .find_by_vehicle(
+                        user.organization_id,
+                        query.vehicle_id,
+                        Some(first.performed_at),
+                        Some(last.performed_at),
+                    )
+                    .await?;
+                reconcile(&statuses, &trips, &settings)
+            }
+            _ => Vec::new(),
+        };
+
+        Ok(Output {
+            vehicle_id: query.vehicle_id,
+            settings,
+            odometer_km: periods.iter().map(|period| period.odometer_km).sum(),
+            gps_km: periods.iter().map(|period| period.gps_km).sum(),
+            discrepancies: periods.iter().filter(|period| period.discrepancy).count(),
+            periods,
+        })
+    }
+}
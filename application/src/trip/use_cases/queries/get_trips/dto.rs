@@ -0,0 +1,42 @@
+use domain::trip::{entities::trip::Trip, value_types::polyline};
+use rust_decimal::Decimal;
+
+pub struct GetTripsQuery {
+    pub vehicle_id: uuid::Uuid,
+    /// Only trips ending at or after this moment are included.
+    pub from: Option<chrono::DateTime<chrono::Utc>>,
+    /// Only trips starting at or before this moment are included.
+    pub to: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+pub struct TripSummary {
+    pub id: uuid::Uuid,
+    pub driver_id: Option<uuid::Uuid>,
+    pub started_at: chrono::DateTime<chrono::Utc>,
+    pub ended_at: chrono::DateTime<chrono::Utc>,
+    /// The track, as an encoded polyline.
+    pub polyline: String,
+    pub point_count: usize,
+    pub distance_km: Decimal,
+}
+
+impl From<Trip> for TripSummary {
+    fn from(trip: Trip) -> Self {
+        TripSummary {
+            id: trip.id,
+            driver_id: trip.driver_id,
+            started_at: trip.started_at,
+            ended_at: trip.ended_at,
+            polyline: polyline::encode(&trip.polyline),
+            point_count: trip.polyline.len(),
+            distance_km: trip.distance_km,
+        }
+    }
+}
+
+pub struct GetTripsResponse {
+    pub vehicle_id: uuid::Uuid,
+    /// The trips, ordered by start.
+    pub trips: Vec<TripSummary>,
+    pub total_distance_km: Decimal,
+}
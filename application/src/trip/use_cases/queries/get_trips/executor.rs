@@ -0,0 +1,61 @@
+use super::{
+    dto::{GetTripsQuery as Input, GetTripsResponse as Output, TripSummary},
+    error::GetTripsError as Error,
+};
+use crate::{
+    auth::AuthenticatedUser,
+    vehicle::{
+        scope::ensure_vehicle_in_scope, traits::vehicle_repository::VehicleApplicationRepository,
+    },
+};
+use domain::trip::repositories::trip_repository::TripRepository;
+
+pub struct GetTripsUseCase<'a, VAR, TR>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    TR: TripRepository + 'a,
+{
+    vehicle_repository: &'a VAR,
+    trip_repository: &'a TR,
+}
+
+impl<'a, VAR, TR> GetTripsUseCase<'a, VAR, TR>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    TR: TripRepository + 'a,
+{
+    pub fn new(vehicle_repository: &'a VAR, trip_repository: &'a TR) -> Self {
+        GetTripsUseCase {
+            vehicle_repository,
+            trip_repository,
+        }
+    }
+
+    /// Lists the trips of a vehicle the user sees overlapping the range.
+    pub async fn execute(&self, query: Input, user: &AuthenticatedUser) -> Result<Output, Error> {
+        if let (Some(from), Some(to)) = (query.from, query.to)
+            && from > to
+        {
+            return Err(Error::InvalidDateRange);
+        }
+
+        ensure_vehicle_in_scope(
+            self.vehicle_repository,
+            user,
+            query.vehicle_id,
+            Error::VehicleNotFound(query.vehicle_id),
+        )
+        .await?;
+
+        let trips = self
+            .trip_repository
+            .find_by_vehicle(user.organization_id, query.vehicle_id, query.from, query.to)
+            .await?;
+
+        Ok(Output {
+            vehicle_id: query.vehicle_id,
+            total_distance_km: trips.iter().map(|trip| trip.distance_km).sum(),
+            trips: trips.into_iter().map(TripSummary::from).collect(),
+        })
+    }
+}
@@ -0,0 +1,3 @@
+pub mod export_trips_gpx;
+pub mod get_distance_reconciliation;
+pub mod get_trips;
@@ -1,3 +1,4 @@
 pub mod vehicle_filter;
+pub mod vehicle_status_filter;
 
-// pub use vehicle_filter::VehicleFilter;
\ No newline at end of file
+// pub use vehicle_filter::VehicleFilter;
@@ -1,8 +1,91 @@
 // application/filter/vehicle_filter.rs
-use crate::shared::pagination::SortOrder;
-use domain::vehicle::value_types::{engine_type, license_plate, vehicle_vin};
+use crate::{
+    auth::AuthenticatedUser,
+    shared::{
+        filter_expression::{
+            filter_ast::FilterExpression,
+            filter_schema::{FilterField, FilterFieldKind, FilterSchema},
+        },
+        pagination::SortOrder,
+    },
+    vehicle::models::vehicle::VehicleView,
+};
+use domain::vehicle::value_types::{country_code, engine_type, license_plate, vehicle_vin};
 use std::str::FromStr;
 
+/// The vehicle fields of filter expressions (the columns of `VehicleSortBy`, and the capacities).
+pub const VEHICLE_FILTER_SCHEMA: FilterSchema = FilterSchema {
+    entity: "vehicle",
+    fields: &[
+        FilterField {
+            name: "make",
+            column: "make",
+            kind: FilterFieldKind::Text,
+        },
+        FilterField {
+            name: "model",
+            column: "model",
+            kind: FilterFieldKind::Text,
+        },
+        FilterField {
+            name: "year",
+            column: "year",
+            kind: FilterFieldKind::Integer,
+        },
+        FilterField {
+            name: "vin",
+            column: "vin",
+            kind: FilterFieldKind::Text,
+        },
+        FilterField {
+            name: "license_plate",
+            column: "license_plate",
+            kind: FilterFieldKind::Text,
+        },
+        FilterField {
+            name: "country",
+            column: "country",
+            kind: FilterFieldKind::Text,
+        },
+        FilterField {
+            name: "engine_type",
+            column: "engine_type",
+            kind: FilterFieldKind::Enum {
+                sql_type: "engine_type",
+                parse: parse_engine_type,
+            },
+        },
+        FilterField {
+            name: "battery_capacity",
+            column: "battery_capacity",
+            kind: FilterFieldKind::Decimal,
+        },
+        FilterField {
+            name: "tank_capacity",
+            column: "tank_capacity",
+            kind: FilterFieldKind::Decimal,
+        },
+        FilterField {
+            name: "created_at",
+            column: "created_at",
+            kind: FilterFieldKind::Timestamp,
+        },
+        FilterField {
+            name: "updated_at",
+            column: "updated_at",
+            kind: FilterFieldKind::Timestamp,
+        },
+    ],
+};
+
+/// Custom engine types (`Other`) can't be filtered by name.
+fn parse_engine_type(value: &str) -> Option<String> {
+    match engine_type::EngineType::from_str(value) {
+        Ok(engine_type::EngineType::Other(_)) | Err(_) => None,
+        Ok(engine_type) => Some(engine_type.as_sql().to_string()),
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct VehicleFilter {
     pub uuid: Option<uuid::Uuid>,
@@ -11,21 +94,43 @@ pub struct VehicleFilter {
     pub year: Option<u16>,
     pub vin: Option<vehicle_vin::VehicleVin>,
     pub license_plate: Option<license_plate::LicensePlate>,
+    pub country: Option<country_code::CountryCode>,
     pub engine_type: Option<engine_type::EngineType>,
+    /// Vehicles whose home depot is this depot.
+    pub depot_id: Option<uuid::Uuid>,
+    /// Vehicles whose home depot is in this region.
+    pub region_id: Option<uuid::Uuid>,
+    /// Free text matched against make, model, license plate and VIN (full-text and fuzzy, see
+    /// `search::models::text_search`).
+    pub search: Option<String>,
+    /// Filter expression over `VEHICLE_FILTER_SCHEMA`, combined with the fields above.
+    pub expression: Option<FilterExpression>,
+    /// The depots of a depot manager (see `scoped_to`): vehicles based elsewhere, or without a
+    /// home depot, are left out. `None` doesn't restrict the depots.
+    pub depot_scope: Option<Vec<uuid::Uuid>>,
 
+    /// Offset paging of `get_by_filter` (batch walks of the fleet), list queries use cursors
+    /// (`shared::pagination::PageRequest`).
     pub page: u32,
     pub page_size: u32,
+    /// `None` sorts by creation date.
     pub sort_by: Option<VehicleSortBy>,
     pub sort_order: SortOrder,
 }
 
+#[derive(Default)]
 pub struct NewVehicleFilter {
     pub make: Option<String>,
     pub model: Option<String>,
     pub year: Option<u16>,
     pub vin: Option<vehicle_vin::VehicleVin>,
     pub license_plate: Option<license_plate::LicensePlate>,
+    pub country: Option<country_code::CountryCode>,
     pub engine_type: Option<engine_type::EngineType>,
+    pub depot_id: Option<uuid::Uuid>,
+    pub region_id: Option<uuid::Uuid>,
+    pub search: Option<String>,
+    pub expression: Option<FilterExpression>,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -36,6 +141,8 @@ pub enum VehicleFilterError {
     InvalidVin(#[from] vehicle_vin::VehicleVinError),
     #[error("Invalid License Plate: {0}")]
     InvalidLicensePlate(#[from] license_plate::LicensePlateError),
+    #[error("Invalid Country: {0}")]
+    InvalidCountry(#[from] country_code::CountryCodeError),
     #[error("Invalid Engine Type: {0}")]
     InvalidEngineType(#[from] engine_type::EngineTypeError),
     #[error("Invalid Sort By: {0}")]
@@ -50,14 +157,37 @@ impl VehicleFilter {
             model: filter.model,
             year: filter.year,
             vin: filter.vin,
-            license_plate: Some(filter.license_plate),
-            engine_type: Some(filter.engine_type),
+            license_plate: filter.license_plate,
+            country: filter.country,
+            engine_type: filter.engine_type,
+            depot_id: filter.depot_id,
+            region_id: filter.region_id,
+            search: filter
+                .search
+                .map(|search| search.trim().to_string())
+                .filter(|search| !search.is_empty()),
+            expression: filter.expression,
+            depot_scope: None,
             page: 1,
             page_size: 10,
             sort_by: None,
             sort_order: SortOrder::Asc,
         }
     }
+
+    /// Filter matching the vehicle with the given id.
+    pub fn by_id(id: uuid::Uuid) -> Self {
+        Self {
+            uuid: Some(id),
+            ..Self::new(NewVehicleFilter::default())
+        }
+    }
+
+    /// Limits the filter to the vehicles the user sees, whatever the input asked for.
+    pub fn scoped_to(mut self, user: &AuthenticatedUser) -> Self {
+        self.depot_scope = user.depot_scope.clone();
+        self
+    }
 }
 
 /*  This logic should be in presentation layer, not the application */
@@ -102,7 +232,7 @@ impl VehicleFilter {
 //     }
 // }
 
-#[derive(Debug, Clone, Copy, serde::Deserialize)]
+#[derive(Debug, Clone, Copy, Default, serde::Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum VehicleSortBy {
     Make,
@@ -110,9 +240,13 @@ pub enum VehicleSortBy {
     Year,
     Vin,
     LicensePlate,
+    Country,
     EngineType,
+    #[default]
     CreatedAt,
     UpdatedAt,
+    /// Best matches of `search` first (only meaningful with a search).
+    Relevance,
 }
 
 impl VehicleSortBy {
@@ -123,9 +257,27 @@ impl VehicleSortBy {
             Self::Year => "year",
             Self::Vin => "vin",
             Self::LicensePlate => "license_plate",
+            Self::Country => "country",
             Self::EngineType => "engine_type",
             Self::CreatedAt => "created_at",
             Self::UpdatedAt => "updated_at",
+            Self::Relevance => "search_rank",
+        }
+    }
+
+    /// Returns the sort key of a vehicle (the value of `as_column_name`), as kept in cursors.
+    pub fn keyset_value(&self, vehicle: &VehicleView) -> Option<String> {
+        match self {
+            Self::Make => Some(vehicle.make.clone()),
+            Self::Model => Some(vehicle.model.clone()),
+            Self::Year => Some(vehicle.year.to_string()),
+            Self::Vin => Some(vehicle.vin.clone()),
+            Self::LicensePlate => Some(vehicle.license_plate.clone()),
+            Self::Country => Some(vehicle.country.clone()),
+            Self::EngineType => Some(vehicle.engine_type.clone()),
+            Self::CreatedAt => Some(vehicle.created_at.to_rfc3339()),
+            Self::UpdatedAt => Some(vehicle.updated_at.to_rfc3339()),
+            Self::Relevance => vehicle.search_rank.map(|rank| rank.to_string()),
         }
     }
 }
@@ -140,9 +292,11 @@ impl FromStr for VehicleSortBy {
             "year" => Ok(Self::Year),
             "vin" => Ok(Self::Vin),
             "license_plate" => Ok(Self::LicensePlate),
+            "country" => Ok(Self::Country),
             "engine_type" => Ok(Self::EngineType),
             "created_at" => Ok(Self::CreatedAt),
             "updated_at" => Ok(Self::UpdatedAt),
+            "relevance" => Ok(Self::Relevance),
             _ => Err(format!("Invalid sort field: {}", s)),
         }
     }
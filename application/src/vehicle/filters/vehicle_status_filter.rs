@@ -0,0 +1,33 @@
+use crate::shared::filter_expression::filter_schema::{FilterField, FilterFieldKind, FilterSchema};
+
+/// The vehicle status fields of filter expressions.
+pub const VEHICLE_STATUS_FILTER_SCHEMA: FilterSchema = FilterSchema {
+    entity: "vehicle status",
+    fields: &[
+        FilterField {
+            name: "odometer",
+            column: "odometer",
+            kind: FilterFieldKind::Integer,
+        },
+        FilterField {
+            name: "engine_hour_meter",
+            column: "engine_hour_meter",
+            kind: FilterFieldKind::Integer,
+        },
+        FilterField {
+            name: "fuel_level",
+            column: "fuel_level",
+            kind: FilterFieldKind::Integer,
+        },
+        FilterField {
+            name: "notes",
+            column: "notes",
+            kind: FilterFieldKind::Text,
+        },
+        FilterField {
+            name: "created_at",
+            column: "created_at",
+            kind: FilterFieldKind::Timestamp,
+        },
+    ],
+};
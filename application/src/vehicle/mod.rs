@@ -1,4 +1,5 @@
 pub mod filters;
 pub mod models;
+pub mod scope;
 pub mod use_cases;
 pub mod traits;
\ No newline at end of file
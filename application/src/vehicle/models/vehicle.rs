@@ -13,8 +13,18 @@ pub struct VehicleView {
     pub vin: String,
     /// License plate number of the vehicle.
     pub license_plate: String,
+    /// The country the vehicle is registered in (e.g., "KZ", "US-CA").
+    pub country: String,
     /// The engine type of the vehicle (e.g., Gasoline, Diesel, Electric).
     pub engine_type: String,
+    /// Traction battery capacity in kWh, if known.
+    pub battery_capacity: Option<rust_decimal::Decimal>,
+    /// Fuel tank capacity in liters, if known.
+    pub tank_capacity: Option<rust_decimal::Decimal>,
+    /// The depot the vehicle is based at, if it was ever assigned one.
+    pub home_depot_id: Option<uuid::Uuid>,
+    /// Rank of the vehicle against `VehicleFilter::search`, only with a search.
+    pub search_rank: Option<f32>,
     /// The date and time when the vehicle was created.
     pub created_at: chrono::DateTime<chrono::Utc>,
     /// The date and time when the vehicle was last updated.
@@ -0,0 +1,43 @@
+use crate::{
+    auth::AuthenticatedUser,
+    vehicle::{
+        filters::vehicle_filter::VehicleFilter,
+        traits::vehicle_repository::{
+            VehicleApplicationRepository, VehicleApplicationRepositoryError,
+        },
+    },
+};
+
+/// Whether the vehicle exists and is in the scope of the user.
+pub async fn vehicle_in_scope<VAR>(
+    vehicle_repository: &VAR,
+    user: &AuthenticatedUser,
+    vehicle_id: uuid::Uuid,
+) -> Result<bool, VehicleApplicationRepositoryError>
+where
+    VAR: VehicleApplicationRepository,
+{
+    let filter = VehicleFilter::by_id(vehicle_id).scoped_to(user);
+    let found = vehicle_repository
+        .count(user.organization_id, filter)
+        .await?;
+    Ok(found > 0)
+}
+
+/// Checks the vehicle exists and is in the scope of the user, `not_found` otherwise: a vehicle
+/// out of the scope of the user is reported as missing, like one of another organization.
+pub async fn ensure_vehicle_in_scope<VAR, E>(
+    vehicle_repository: &VAR,
+    user: &AuthenticatedUser,
+    vehicle_id: uuid::Uuid,
+    not_found: E,
+) -> Result<(), E>
+where
+    VAR: VehicleApplicationRepository,
+    E: From<VehicleApplicationRepositoryError>,
+{
+    if !vehicle_in_scope(vehicle_repository, user, vehicle_id).await? {
+        return Err(not_found);
+    }
+    Ok(())
+}
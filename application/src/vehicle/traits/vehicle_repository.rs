@@ -1,4 +1,7 @@
-use crate::vehicle::{filters::vehicle_filter::VehicleFilter, models::vehicle::VehicleView};
+use crate::{
+    shared::pagination::Keyset,
+    vehicle::{filters::vehicle_filter::VehicleFilter, models::vehicle::VehicleView},
+};
 use std::future::Future;
 use uuid::Uuid;
 
@@ -17,6 +20,23 @@ pub trait VehicleApplicationRepository: Send + Sync {
     /// Find a vehicle by its ID
     fn get_by_filter(
         &self,
+        organization_id: uuid::Uuid,
         filter: VehicleFilter,
     ) -> impl Future<Output = Result<Vec<VehicleView>, VehicleApplicationRepositoryError>> + Send;
+
+    /// Find the vehicles matching the filter after (or before) the keyset position, at most
+    /// `keyset.limit`, in scanning order. `filter.page` and `filter.page_size` are ignored.
+    fn get_page(
+        &self,
+        organization_id: uuid::Uuid,
+        filter: VehicleFilter,
+        keyset: &Keyset,
+    ) -> impl Future<Output = Result<Vec<VehicleView>, VehicleApplicationRepositoryError>> + Send;
+
+    /// Count the vehicles matching the filter
+    fn count(
+        &self,
+        organization_id: uuid::Uuid,
+        filter: VehicleFilter,
+    ) -> impl Future<Output = Result<u64, VehicleApplicationRepositoryError>> + Send;
 }
@@ -0,0 +1,33 @@
+use crate::shared::{filter_expression::filter_ast::FilterExpression, pagination::Keyset};
+use domain::vehicle::entities::vehicle_status::VehicleStatusIdentity;
+use std::future::Future;
+use uuid::Uuid;
+
+#[derive(Debug, thiserror::Error)]
+pub enum VehicleStatusApplicationRepositoryError {
+    #[error("database error: {0}")]
+    DatabaseError(String),
+}
+
+/// Repository trait for listing the statuses of a vehicle
+pub trait VehicleStatusApplicationRepository: Send + Sync {
+    /// Find the statuses of the vehicle matching the filter after (or before) the keyset position,
+    /// at most `keyset.limit`, in scanning order
+    fn get_page(
+        &self,
+        organization_id: uuid::Uuid,
+        vehicle_id: Uuid,
+        filter: Option<&FilterExpression>,
+        keyset: &Keyset,
+    ) -> impl Future<
+        Output = Result<Vec<VehicleStatusIdentity>, VehicleStatusApplicationRepositoryError>,
+    > + Send;
+
+    /// Count the statuses of the vehicle matching the filter
+    fn count(
+        &self,
+        organization_id: uuid::Uuid,
+        vehicle_id: Uuid,
+        filter: Option<&FilterExpression>,
+    ) -> impl Future<Output = Result<u64, VehicleStatusApplicationRepositoryError>> + Send;
+}
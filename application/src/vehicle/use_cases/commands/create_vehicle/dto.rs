@@ -1,10 +1,36 @@
+use domain::vehicle::{
+    entities::vehicle::VehicleIdentity, value_types::validation_policy::ValidationPolicy,
+};
+
+/// How mismatches between the VIN and the submitted data are handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VinCheckMode {
+    /// Mismatches reject the vehicle.
+    #[default]
+    Strict,
+    /// Mismatches are returned as warnings.
+    Lenient,
+}
+
 pub struct CreateVehicleCommand {
     pub make: String,
     pub model: String,
     pub year: u16,
     pub vin: String,
     pub license_plate: String,
+    /// Country the vehicle is registered in (e.g., "KZ", "US-CA"), decides the plate format.
+    pub country: String,
     pub engine_type: String,
+    /// Traction battery capacity in kWh (electric and hybrid vehicles).
+    pub battery_capacity: Option<rust_decimal::Decimal>,
+    /// Fuel tank capacity in liters (vehicles with a combustion engine).
+    pub tank_capacity: Option<rust_decimal::Decimal>,
+    /// How strictly the vehicle data is validated.
+    pub validation: ValidationPolicy,
+    /// How the VIN is cross-checked with the make and year.
+    pub vin_check: VinCheckMode,
+    /// Accept a pre-1981 / non-ISO VIN, which is stored as is and never decoded.
+    pub legacy_vin: bool,
     // user (caller) info
     pub user_id: String,
 }
@@ -16,6 +42,36 @@ pub struct CreateVehicleResponse {
     pub year: u16,
     pub vin: String,
     pub license_plate: String,
+    pub country: String,
     pub engine_type: String,
+    pub battery_capacity: Option<rust_decimal::Decimal>,
+    pub tank_capacity: Option<rust_decimal::Decimal>,
     pub created_at: String,
+    /// Manufacturer decoded from the VIN, if known.
+    pub vin_manufacturer: Option<String>,
+    /// Country of manufacture decoded from the VIN, if known.
+    pub vin_country: Option<String>,
+    /// VIN mismatches accepted in lenient mode, unchecked plate formats, and other notes.
+    pub warnings: Vec<String>,
+}
+
+impl From<VehicleIdentity> for CreateVehicleResponse {
+    fn from(identity: VehicleIdentity) -> Self {
+        CreateVehicleResponse {
+            id: identity.id.to_string(),
+            make: identity.make,
+            model: identity.model,
+            year: identity.year,
+            vin: identity.vin.into_string(),
+            license_plate: identity.license_plate.value().to_string(),
+            country: identity.country.to_string(),
+            engine_type: identity.powertrain.engine_type().as_str().to_string(),
+            battery_capacity: identity.powertrain.battery_capacity(),
+            tank_capacity: identity.powertrain.tank_capacity(),
+            created_at: identity.created_at.to_rfc3339(),
+            vin_manufacturer: None,
+            vin_country: None,
+            warnings: Vec::new(),
+        }
+    }
 }
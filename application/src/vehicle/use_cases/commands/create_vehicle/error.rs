@@ -1,9 +1,15 @@
-use domain::vehicle::repositories::vehicle_repository::VehicleRepositoryError;
+use domain::vehicle::{
+    entities::vehicle::VehicleError, repositories::vehicle_repository::VehicleRepositoryError,
+};
 
 #[derive(Debug, thiserror::Error)]
 pub enum CreateVehicleError {
     #[error("Invalid input: {0}")]
     InvalidInput(String),
+    #[error("Invalid vehicle data: {0}")]
+    Validation(#[from] VehicleError),
+    #[error("VIN doesn't match the vehicle data: {}", .0.join("; "))]
+    VinMismatch(Vec<String>),
     #[error("Vehicle already exists: {0}")]
     VehicleAlreadyExists(String),
     #[error("Repository error: {0}")]
@@ -1,30 +1,119 @@
 use super::{
-    dto::{CreateVehicleCommand as Input, CreateVehicleResponse as Output},
+    dto::{CreateVehicleCommand as Input, CreateVehicleResponse as Output, VinCheckMode},
     error::CreateVehicleError as Error,
 };
+use crate::auth::AuthenticatedUser;
 use domain::vehicle::{
-    entities::vehicle::{NewVehicle, VehicleError},
-    repositories::vehicle_repository::VehicleRepository,
+    entities::vehicle::{NewVehicle, Vehicle, VehicleError},
+    repositories::vehicle_repository::{VehicleRepository, VehicleRepositoryError},
+    services::{
+        license_plate_registry::LicensePlateRegistry,
+        vehicle_validator::VehicleValidator,
+        vin_decoder::{cross_check_vin, decode_vin},
+    },
 };
 
 pub struct CreateVehicleUseCase<'a, VR: VehicleRepository + 'a> {
     vehicle_repository: &'a VR,
+    plate_registry: &'a LicensePlateRegistry,
 }
 
 impl<'a, VR: VehicleRepository + 'a> CreateVehicleUseCase<'a, VR> {
     pub fn new(vehicle_repository: &'a VR) -> Self {
-        CreateVehicleUseCase { vehicle_repository }
+        CreateVehicleUseCase {
+            vehicle_repository,
+            plate_registry: LicensePlateRegistry::standard(),
+        }
     }
 
-    pub async fn execute(&self, cmd: Input) -> Result<Output, Error> {
-        unimplemented!();
-        // Validate input data
-        // let vehicle: NewVehicle = cmd.try_into()?;
+    /// Validates license plates with a custom registry instead of the standard formats.
+    pub fn with_plate_registry(mut self, plate_registry: &'a LicensePlateRegistry) -> Self {
+        self.plate_registry = plate_registry;
+        self
+    }
+
+    pub async fn execute(&self, cmd: Input, user: &AuthenticatedUser) -> Result<Output, Error> {
+        let vin_check = cmd.vin_check;
+        let validator =
+            VehicleValidator::new(cmd.validation).with_plate_registry(self.plate_registry);
+
+        // Validate input data, all field errors are reported at once
+        let new_vehicle: NewVehicle = cmd.try_into()?;
+        let vehicle = Vehicle::with_validator(new_vehicle, &validator)?;
+
+        // Check the VIN against the make and year
+        let decoded = decode_vin(vehicle.vin());
+        let mut warnings: Vec<String> = decoded
+            .as_ref()
+            .map(|decoded| cross_check_vin(decoded, vehicle.make(), vehicle.year()))
+            .unwrap_or_default()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        if vin_check == VinCheckMode::Strict && !warnings.is_empty() {
+            return Err(Error::VinMismatch(warnings));
+        }
+        if decoded.is_none() {
+            warnings.push("legacy VIN, not decoded".to_string());
+        }
+
+        // Only the strict policy rejects countries without a known plate format
+        if !self.plate_registry.supports(vehicle.country()) {
+            warnings.push(format!(
+                "no license plate format for country {}, plate not checked",
+                vehicle.country()
+            ));
+        }
+
+        // Check if the vehicle already exists
+        if self
+            .vehicle_repository
+            .exists_by_vin_or_license_plate(
+                user.organization_id,
+                vehicle.vin().value(),
+                vehicle.country().value(),
+                vehicle.license_plate().value(),
+            )
+            .await?
+        {
+            return Err(Error::VehicleAlreadyExists(vehicle.vin().value().to_string()));
+        }
 
-        // Create the vehicle
-        // let vehicle = self.vehicle_repository.create(vehicle).await?;
+        // Create the vehicle with the normalized values (it may exist since the check)
+        let created = self
+            .vehicle_repository
+            .create(user.organization_id, NewVehicle {
+                make: vehicle.make().to_string(),
+                model: vehicle.model().to_string(),
+                year: vehicle.year(),
+                vin: vehicle.vin().value().to_string(),
+                license_plate: vehicle.license_plate().value().to_string(),
+                country: vehicle.country().to_string(),
+                engine_type: vehicle.engine_type().as_str().to_string(),
+                battery_capacity: vehicle.powertrain().battery_capacity(),
+                tank_capacity: vehicle.powertrain().tank_capacity(),
+                legacy_vin: vehicle.vin().is_legacy(),
+            })
+            .await
+            .map_err(|e| match e {
+                VehicleRepositoryError::AlreadyExists(_) => {
+                    Error::VehicleAlreadyExists(vehicle.vin().value().to_string())
+                }
+                e => e.into(),
+            })?;
 
-        // Ok(vehicle)
+        Ok(Output {
+            vin_manufacturer: decoded
+                .as_ref()
+                .and_then(|decoded| decoded.manufacturer)
+                .map(|entry| entry.manufacturer.to_string()),
+            vin_country: decoded
+                .as_ref()
+                .and_then(|decoded| decoded.country)
+                .map(str::to_string),
+            warnings,
+            ..Output::from(created)
+        })
     }
 }
 
@@ -32,13 +121,17 @@ impl TryInto<NewVehicle> for Input {
     type Error = VehicleError;
 
     fn try_into(self) -> Result<NewVehicle, Self::Error> {
-        NewVehicle::new(
-            self.make,
-            self.model,
-            self.year,
-            self.vin,
-            self.license_plate,
-            self.engine_type,
-        )
+        Ok(NewVehicle {
+            make: self.make,
+            model: self.model,
+            year: self.year,
+            vin: self.vin,
+            license_plate: self.license_plate,
+            country: self.country,
+            engine_type: self.engine_type,
+            battery_capacity: self.battery_capacity,
+            tank_capacity: self.tank_capacity,
+            legacy_vin: self.legacy_vin,
+        })
     }
 }
@@ -0,0 +1,115 @@
+use domain::vehicle::value_types::validation_policy::ValidationPolicy;
+
+pub const DEFAULT_IMPORT_BATCH_SIZE: usize = 500;
+
+/// Country assumed for rows without a `country` column.
+pub const DEFAULT_IMPORT_COUNTRY: &str = "KZ";
+
+/// Columns every import file must provide (`country`, `battery_capacity` and `tank_capacity` are
+/// optional, extra columns are ignored).
+pub const REQUIRED_COLUMNS: [&str; 6] = [
+    "make",
+    "model",
+    "year",
+    "vin",
+    "license_plate",
+    "engine_type",
+];
+
+/// How the import treats the rows that passed validation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportMode {
+    /// Validate the file and report what would happen, without writing anything.
+    DryRun,
+    /// Validate the file and create every vehicle that passed validation.
+    Commit,
+}
+
+pub struct ImportVehiclesCommand {
+    pub mode: ImportMode,
+    /// How strictly every row is validated (usually lenient, or legacy import for old records).
+    pub validation: ValidationPolicy,
+    /// Number of rows validated and written together (`0` means the default).
+    pub batch_size: usize,
+}
+
+/// A raw row of the import file, before any validation.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct VehicleCsvRow {
+    pub make: String,
+    pub model: String,
+    pub year: String,
+    pub vin: String,
+    pub license_plate: String,
+    #[serde(default = "default_country")]
+    pub country: String,
+    pub engine_type: String,
+    /// Traction battery capacity in kWh, optional column.
+    #[serde(default)]
+    pub battery_capacity: String,
+    /// Fuel tank capacity in liters, optional column.
+    #[serde(default)]
+    pub tank_capacity: String,
+}
+
+fn default_country() -> String {
+    DEFAULT_IMPORT_COUNTRY.to_string()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImportRowOutcome {
+    /// The vehicle was created (commit mode only).
+    Created { id: uuid::Uuid },
+    /// The row is valid and would be created (dry-run mode only).
+    Valid,
+    /// The row is valid but the vehicle already exists in the file or in the system.
+    Skipped { reason: String },
+    /// The row failed validation.
+    Error { reason: String },
+}
+
+#[derive(Debug, Clone)]
+pub struct ImportRowReport {
+    /// Line of the row in the source file (the header is line 1).
+    pub line: u64,
+    pub vin: String,
+    pub license_plate: String,
+    pub outcome: ImportRowOutcome,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImportVehiclesResponse {
+    pub mode: ImportMode,
+    pub rows: Vec<ImportRowReport>,
+    pub total_rows: usize,
+    pub created: usize,
+    pub valid: usize,
+    pub skipped: usize,
+    pub errors: usize,
+}
+
+impl ImportVehiclesResponse {
+    pub fn new(mode: ImportMode) -> Self {
+        ImportVehiclesResponse {
+            mode,
+            rows: Vec::new(),
+            total_rows: 0,
+            created: 0,
+            valid: 0,
+            skipped: 0,
+            errors: 0,
+        }
+    }
+
+    /// Appends a row to the report and updates the counters.
+    pub fn push(&mut self, row: ImportRowReport) {
+        self.total_rows += 1;
+        match row.outcome {
+            ImportRowOutcome::Created { .. } => self.created += 1,
+            ImportRowOutcome::Valid => self.valid += 1,
+            ImportRowOutcome::Skipped { .. } => self.skipped += 1,
+            ImportRowOutcome::Error { .. } => self.errors += 1,
+        }
+        self.rows.push(row);
+    }
+}
@@ -0,0 +1,11 @@
+use domain::vehicle::repositories::vehicle_repository::VehicleRepositoryError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ImportVehiclesError {
+    #[error("Invalid CSV file: {0}")]
+    InvalidCsv(#[from] csv::Error),
+    #[error("Missing required column: {0}")]
+    MissingColumn(String),
+    #[error("Repository error: {0}")]
+    Repository(#[from] VehicleRepositoryError),
+}
@@ -0,0 +1,272 @@
+use super::{
+    dto::{
+        DEFAULT_IMPORT_BATCH_SIZE, DEFAULT_IMPORT_COUNTRY, ImportMode, ImportRowOutcome,
+        ImportRowReport, ImportVehiclesCommand as Input, ImportVehiclesResponse as Output,
+        REQUIRED_COLUMNS, VehicleCsvRow,
+    },
+    error::ImportVehiclesError as Error,
+};
+use crate::{auth::AuthenticatedUser, shared::csv_stream::CsvStream};
+use domain::vehicle::{
+    entities::vehicle::{NewVehicle, Vehicle},
+    repositories::vehicle_repository::{VehicleRepository, VehicleRepositoryError},
+    services::vehicle_validator::VehicleValidator,
+};
+use futures::io::AsyncRead;
+use std::collections::HashMap;
+
+/// A validated row waiting to be checked against the repository.
+struct PendingRow {
+    line: u64,
+    vehicle: NewVehicle,
+}
+
+pub struct ImportVehiclesUseCase<'a, VR: VehicleRepository + 'a> {
+    vehicle_repository: &'a VR,
+}
+
+impl<'a, VR: VehicleRepository + 'a> ImportVehiclesUseCase<'a, VR> {
+    pub fn new(vehicle_repository: &'a VR) -> Self {
+        ImportVehiclesUseCase { vehicle_repository }
+    }
+
+    /// Streams the CSV `source` row by row, looking the valid rows up and writing them one batch
+    /// at a time. The report has a row per line of the file, and the VINs and plates already
+    /// read are kept to find the duplicates inside the file, so both grow with the file.
+    pub async fn execute<R: AsyncRead + Unpin>(
+        &self,
+        cmd: Input,
+        source: R,
+        user: &AuthenticatedUser,
+    ) -> Result<Output, Error> {
+        let batch_size = match cmd.batch_size {
+            0 => DEFAULT_IMPORT_BATCH_SIZE,
+            size => size,
+        };
+
+        let mut reader = CsvStream::new(source);
+
+        // Check the header before reading any row
+        let headers = match reader.read_record().await? {
+            Some((_, headers)) => headers,
+            None => csv::StringRecord::new(),
+        };
+        if let Some(missing) = REQUIRED_COLUMNS
+            .iter()
+            .find(|column| !headers.iter().any(|header| header == **column))
+        {
+            return Err(Error::MissingColumn(missing.to_string()));
+        }
+
+        let validator = VehicleValidator::new(cmd.validation);
+        let mut report = Output::new(cmd.mode);
+        // VINs and (country, license plate) pairs already seen in the file, with the line they
+        // first appeared on
+        let mut seen_vins: HashMap<String, u64> = HashMap::new();
+        let mut seen_plates: HashMap<(String, String), u64> = HashMap::new();
+        let mut batch: Vec<PendingRow> = Vec::with_capacity(batch_size);
+
+        while let Some((line, record)) = reader.read_record().await? {
+            let row: VehicleCsvRow = match record.deserialize(Some(&headers)) {
+                Ok(row) => row,
+                Err(e) => {
+                    report.push(ImportRowReport {
+                        line,
+                        vin: String::new(),
+                        license_plate: String::new(),
+                        outcome: ImportRowOutcome::Error {
+                            reason: e.to_string(),
+                        },
+                    });
+                    continue;
+                }
+            };
+
+            let vehicle = match validate_row(row.clone(), &validator) {
+                Ok(vehicle) => vehicle,
+                Err(reasons) => {
+                    report.push(ImportRowReport {
+                        line,
+                        vin: row.vin,
+                        license_plate: row.license_plate,
+                        outcome: ImportRowOutcome::Error {
+                            reason: reasons.join("; "),
+                        },
+                    });
+                    continue;
+                }
+            };
+
+            // Detect duplicates inside the file itself
+            let plate_key = (vehicle.country.clone(), vehicle.license_plate.clone());
+            let duplicate_of = seen_vins
+                .get(&vehicle.vin)
+                .or_else(|| seen_plates.get(&plate_key));
+            if let Some(first_line) = duplicate_of {
+                report.push(ImportRowReport {
+                    line,
+                    vin: vehicle.vin,
+                    license_plate: vehicle.license_plate,
+                    outcome: ImportRowOutcome::Skipped {
+                        reason: format!("Duplicate of line {} in the same file", first_line),
+                    },
+                });
+                continue;
+            }
+            seen_vins.insert(vehicle.vin.clone(), line);
+            seen_plates.insert(plate_key, line);
+
+            batch.push(PendingRow { line, vehicle });
+            if batch.len() >= batch_size {
+                self.flush(user.organization_id, &mut batch, cmd.mode, &mut report)
+                    .await?;
+            }
+        }
+        self.flush(user.organization_id, &mut batch, cmd.mode, &mut report)
+            .await?;
+
+        // Invalid rows are reported right away, valid ones when their batch is flushed
+        report.rows.sort_by_key(|row| row.line);
+
+        Ok(report)
+    }
+
+    /// Checks a batch of valid rows against the repository in one lookup, and creates them in
+    /// commit mode.
+    async fn flush(
+        &self,
+        organization_id: uuid::Uuid,
+        batch: &mut Vec<PendingRow>,
+        mode: ImportMode,
+        report: &mut Output,
+    ) -> Result<(), Error> {
+        if batch.is_empty() {
+            return Ok(());
+        }
+        let vins: Vec<String> = batch.iter().map(|row| row.vehicle.vin.clone()).collect();
+        let plates: Vec<(String, String)> = batch
+            .iter()
+            .map(|row| {
+                (
+                    row.vehicle.country.clone(),
+                    row.vehicle.license_plate.clone(),
+                )
+            })
+            .collect();
+        let taken = self
+            .vehicle_repository
+            .find_taken_identifiers(organization_id, &vins, &plates)
+            .await?;
+
+        for PendingRow { line, vehicle } in batch.drain(..) {
+            let vin = vehicle.vin.clone();
+            let license_plate = vehicle.license_plate.clone();
+
+            let outcome = if taken.vins.contains(&vin)
+                || taken
+                    .license_plates
+                    .contains(&(vehicle.country.clone(), license_plate.clone()))
+            {
+                already_exists()
+            } else {
+                match mode {
+                    ImportMode::DryRun => ImportRowOutcome::Valid,
+                    // A vehicle created since the lookup is still reported as existing
+                    ImportMode::Commit => match self
+                        .vehicle_repository
+                        .create(organization_id, vehicle)
+                        .await
+                    {
+                        Ok(created) => ImportRowOutcome::Created { id: created.id },
+                        Err(VehicleRepositoryError::AlreadyExists(_)) => already_exists(),
+                        Err(e) => return Err(e.into()),
+                    },
+                }
+            };
+
+            report.push(ImportRowReport {
+                line,
+                vin,
+                license_plate,
+                outcome,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+fn already_exists() -> ImportRowOutcome {
+    ImportRowOutcome::Skipped {
+        reason: "A vehicle with this VIN or license plate (in this country) already exists"
+            .to_string(),
+    }
+}
+
+/// Validates a raw row with the policy of the import, collecting every problem found.
+fn validate_row(
+    row: VehicleCsvRow,
+    validator: &VehicleValidator,
+) -> Result<NewVehicle, Vec<String>> {
+    let mut errors = Vec::new();
+    let parsed_year = row.year.parse::<u16>().ok();
+    if parsed_year.is_none() {
+        errors.push(format!("Invalid year: {}", row.year));
+    }
+    let mut capacity = |name: &str, value: &str| {
+        if value.is_empty() {
+            return None;
+        }
+        value
+            .parse::<rust_decimal::Decimal>()
+            .map_err(|_| errors.push(format!("Invalid {}: {}", name, value)))
+            .ok()
+    };
+    let battery_capacity = capacity("battery capacity", &row.battery_capacity);
+    let tank_capacity = capacity("tank capacity", &row.tank_capacity);
+    let country = if row.country.is_empty() {
+        DEFAULT_IMPORT_COUNTRY.to_string()
+    } else {
+        row.country
+    };
+
+    let data = NewVehicle {
+        make: row.make,
+        model: row.model,
+        year: parsed_year.unwrap_or_default(),
+        vin: row.vin,
+        license_plate: row.license_plate,
+        country,
+        engine_type: row.engine_type,
+        battery_capacity,
+        tank_capacity,
+        legacy_vin: false,
+    };
+    let vehicle = match Vehicle::with_validator(data, validator) {
+        Ok(vehicle) if errors.is_empty() => vehicle,
+        Ok(_) => return Err(errors),
+        Err(e) => {
+            // An unparsable year was already reported
+            errors.extend(
+                e.errors()
+                    .iter()
+                    .filter(|error| parsed_year.is_some() || error.field() != "year")
+                    .map(ToString::to_string),
+            );
+            return Err(errors);
+        }
+    };
+
+    Ok(NewVehicle {
+        make: vehicle.make().to_string(),
+        model: vehicle.model().to_string(),
+        year: vehicle.year(),
+        vin: vehicle.vin().value().to_string(),
+        license_plate: vehicle.license_plate().value().to_string(),
+        country: vehicle.country().to_string(),
+        engine_type: vehicle.engine_type().as_str().to_string(),
+        battery_capacity: vehicle.powertrain().battery_capacity(),
+        tank_capacity: vehicle.powertrain().tank_capacity(),
+        legacy_vin: vehicle.vin().is_legacy(),
+    })
+}
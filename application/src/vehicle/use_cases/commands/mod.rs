@@ -1 +1,2 @@
-pub mod create_vehicle;
\ No newline at end of file
+pub mod create_vehicle;
+pub mod import_vehicles;
\ No newline at end of file
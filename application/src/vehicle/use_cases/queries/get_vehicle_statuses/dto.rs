@@ -0,0 +1,40 @@
+use crate::shared::{
+    filter_expression::filter_ast::FilterExpression,
+    pagination::{Page, PageRequest},
+};
+use domain::vehicle::entities::vehicle_status::VehicleStatusIdentity;
+
+/// Statuses are listed from the most recently recorded one.
+pub struct GetVehicleStatusesQuery {
+    pub vehicle_id: uuid::Uuid,
+    /// Filter expression over `VEHICLE_STATUS_FILTER_SCHEMA`.
+    pub filter: Option<FilterExpression>,
+    pub page: PageRequest,
+}
+
+pub struct VehicleStatusResponse {
+    pub id: i32,
+    pub performed_by: uuid::Uuid,
+    pub performed_at: chrono::DateTime<chrono::Utc>,
+    pub odometer: i32,
+    pub engine_hour_meter: Option<i32>,
+    pub fuel_level: Option<i32>,
+    pub notes: String,
+}
+
+/// A page of statuses, with the cursors of the next and previous pages.
+pub type GetVehicleStatusesResponse = Page<VehicleStatusResponse>;
+
+impl From<VehicleStatusIdentity> for VehicleStatusResponse {
+    fn from(status: VehicleStatusIdentity) -> Self {
+        VehicleStatusResponse {
+            id: status.id,
+            performed_by: status.performed_by,
+            performed_at: status.performed_at,
+            odometer: status.odometer,
+            engine_hour_meter: status.engine_hour_meter,
+            fuel_level: status.fuel_level,
+            notes: status.notes,
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use crate::{
+    shared::cursor::CursorError,
+    vehicle::traits::{
+        vehicle_repository::VehicleApplicationRepositoryError,
+        vehicle_status_repository::VehicleStatusApplicationRepositoryError,
+    },
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetVehicleStatusesError {
+    #[error("Vehicle not found: {0}")]
+    VehicleNotFound(uuid::Uuid),
+    #[error("Invalid cursor: {0}")]
+    InvalidCursor(#[from] CursorError),
+    #[error("Vehicle repository error: {0}")]
+    VehicleRepository(#[from] VehicleApplicationRepositoryError),
+    #[error("Repository error: {0}")]
+    Repository(#[from] VehicleStatusApplicationRepositoryError),
+}
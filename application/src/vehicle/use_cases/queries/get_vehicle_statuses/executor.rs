@@ -0,0 +1,99 @@
+use super::{
+    dto::{
+        GetVehicleStatusesQuery as Input, GetVehicleStatusesResponse as Output,
+        VehicleStatusResponse,
+    },
+    error::GetVehicleStatusesError as Error,
+};
+use crate::{
+    auth::AuthenticatedUser,
+    shared::{
+        cursor::CursorCodec,
+        pagination::{Keyset, KeysetPosition, Page, SortOrder},
+    },
+    vehicle::{
+        scope::ensure_vehicle_in_scope,
+        traits::{
+            vehicle_repository::VehicleApplicationRepository,
+            vehicle_status_repository::VehicleStatusApplicationRepository,
+        },
+    },
+};
+
+pub struct GetVehicleStatusesUseCase<'a, VAR, VSR>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    VSR: VehicleStatusApplicationRepository + 'a,
+{
+    vehicle_repository: &'a VAR,
+    vehicle_status_repository: &'a VSR,
+    cursor_codec: &'a CursorCodec,
+}
+
+impl<'a, VAR, VSR> GetVehicleStatusesUseCase<'a, VAR, VSR>
+where
+    VAR: VehicleApplicationRepository + 'a,
+    VSR: VehicleStatusApplicationRepository + 'a,
+{
+    pub fn new(
+        vehicle_repository: &'a VAR,
+        vehicle_status_repository: &'a VSR,
+        cursor_codec: &'a CursorCodec,
+    ) -> Self {
+        GetVehicleStatusesUseCase {
+            vehicle_repository,
+            vehicle_status_repository,
+            cursor_codec,
+        }
+    }
+
+    pub async fn execute(&self, query: Input, user: &AuthenticatedUser) -> Result<Output, Error> {
+        ensure_vehicle_in_scope(
+            self.vehicle_repository,
+            user,
+            query.vehicle_id,
+            Error::VehicleNotFound(query.vehicle_id),
+        )
+        .await?;
+        let keyset = Keyset::new(
+            &query.page,
+            "created_at",
+            SortOrder::Desc,
+            self.cursor_codec,
+        )?;
+
+        let total_count = match query.page.include_total {
+            true => Some(
+                self.vehicle_status_repository
+                    .count(
+                        user.organization_id,
+                        query.vehicle_id,
+                        query.filter.as_ref(),
+                    )
+                    .await?,
+            ),
+            false => None,
+        };
+        let statuses = self
+            .vehicle_status_repository
+            .get_page(
+                user.organization_id,
+                query.vehicle_id,
+                query.filter.as_ref(),
+                &keyset,
+            )
+            .await?;
+
+        let page = Page::from_rows(
+            statuses,
+            &keyset,
+            self.cursor_codec,
+            |status| KeysetPosition {
+                value: Some(status.created_at.to_rfc3339()),
+                id: status.id.to_string(),
+            },
+            total_count,
+        );
+        Ok(page.map(VehicleStatusResponse::from))
+    }
+}
@@ -1,5 +1,4 @@
-// use crate::shared::pagination::{SortOrder, DEFAULT_PAGE, DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE};
-use crate::vehicle::models::vehicle::VehicleView;
+use crate::{shared::pagination::Page, vehicle::models::vehicle::VehicleView};
 
 #[derive(Debug, Clone)]
 pub struct VehicleResponse {
@@ -9,37 +8,17 @@ pub struct VehicleResponse {
     pub year: u16,
     pub vin: String,
     pub license_plate: String,
+    pub country: String,
     pub engine_type: String,
+    pub battery_capacity: Option<rust_decimal::Decimal>,
+    pub tank_capacity: Option<rust_decimal::Decimal>,
+    pub home_depot_id: Option<uuid::Uuid>,
     pub created_at: String,
     pub updated_at: String,
 }
 
-#[derive(Debug, Clone)]
-pub struct GetVehiclesResponse {
-    pub vehicles: Vec<VehicleResponse>,
-    pub total_count: usize,
-    pub page: u32,
-    pub page_size: u32,
-    pub total_pages: u32,
-}
-
-// impl GetVehiclesResponse {
-//     pub fn new(vehicles: Vec<VehicleResponse>, total_count: usize, page: u32, page_size: u32) -> Self {
-//         let total_pages = if total_count == 0 {
-//             1
-//         } else {
-//             ((total_count as f64) / (page_size as f64)).ceil() as u32
-//         };
-
-//         Self {
-//             vehicles,
-//             total_count,
-//             page,
-//             page_size,
-//             total_pages,
-//         }
-//     }
-// }
+/// A page of vehicles, with the cursors of the next and previous pages.
+pub type GetVehiclesResponse = Page<VehicleResponse>;
 
 impl From<VehicleView> for VehicleResponse {
     fn from(vehicle: VehicleView) -> Self {
@@ -50,9 +29,13 @@ impl From<VehicleView> for VehicleResponse {
             year: vehicle.year,
             vin: vehicle.vin,
             license_plate: vehicle.license_plate,
+            country: vehicle.country,
             engine_type: vehicle.engine_type,
+            battery_capacity: vehicle.battery_capacity,
+            tank_capacity: vehicle.tank_capacity,
+            home_depot_id: vehicle.home_depot_id,
             created_at: vehicle.created_at.to_rfc3339(),
             updated_at: vehicle.updated_at.to_rfc3339(),
         }
     }
-}
\ No newline at end of file
+}
@@ -1,9 +1,12 @@
-use crate::vehicle::traits::vehicle_repository::VehicleApplicationRepositoryError;
+use crate::{
+    shared::cursor::CursorError,
+    vehicle::traits::vehicle_repository::VehicleApplicationRepositoryError,
+};
 
 #[derive(Debug, thiserror::Error)]
 pub enum GetVehiclesError {
-    // #[error("Invalid pagination parameters: {0}")]
-    // InvalidPagination(String),
+    #[error("Invalid cursor: {0}")]
+    InvalidCursor(#[from] CursorError),
     #[error("Repository error: {0}")]
     RepositoryError(#[from] VehicleApplicationRepositoryError),
 }
@@ -2,36 +2,68 @@ use super::{
     dto::{GetVehiclesResponse as Output, VehicleResponse},
     error::GetVehiclesError as Error,
 };
-use crate::vehicle::{
-    filters::vehicle_filter::VehicleFilter,
-    traits::vehicle_repository::VehicleApplicationRepository,
+use crate::{
+    auth::AuthenticatedUser,
+    shared::{
+        cursor::CursorCodec,
+        pagination::{Keyset, KeysetPosition, Page, PageRequest},
+    },
+    vehicle::{
+        filters::vehicle_filter::VehicleFilter,
+        traits::vehicle_repository::VehicleApplicationRepository,
+    },
 };
 
 pub struct GetVehiclesUseCase<'a, VAR: VehicleApplicationRepository + 'a> {
     repo: &'a VAR,
+    cursor_codec: &'a CursorCodec,
 }
 
 impl<'a, VAR: VehicleApplicationRepository + 'a> GetVehiclesUseCase<'a, VAR> {
-    pub fn new(repo: &'a VAR) -> Self {
-        GetVehiclesUseCase { repo }
+    pub fn new(repo: &'a VAR, cursor_codec: &'a CursorCodec) -> Self {
+        GetVehiclesUseCase { repo, cursor_codec }
     }
 
-    pub async fn execute(&self, filter: VehicleFilter) -> Result<Output, Error> {
-        // validate pagination parameters
-        // ?
+    pub async fn execute(
+        &self,
+        filter: VehicleFilter,
+        page: PageRequest,
+        user: &AuthenticatedUser,
+    ) -> Result<Output, Error> {
+        let filter = filter.scoped_to(user);
 
-        let vehicles = self.repo.get_by_filter(filter).await?;
+        // Check the cursor belongs to the sort of the filter
+        let sort_by = filter.sort_by.unwrap_or_default();
+        let keyset = Keyset::new(
+            &page,
+            sort_by.as_column_name(),
+            filter.sort_order,
+            self.cursor_codec,
+        )?;
 
-        Ok(Output {
-            vehicles: vehicles.into_iter().map(VehicleResponse::from).collect(),
-            total_count: vehicles.len(),
-            page: filter.page,
-            page_size: filter.page_size,
-            total_pages: if filter.page_size == 0 {
-                1
-            } else {
-                (total_count as f64 / filter.page_size as f64).ceil() as u32
+        let total_count = match page.include_total {
+            true => Some(
+                self.repo
+                    .count(user.organization_id, filter.clone())
+                    .await?,
+            ),
+            false => None,
+        };
+        let vehicles = self
+            .repo
+            .get_page(user.organization_id, filter, &keyset)
+            .await?;
+
+        let page = Page::from_rows(
+            vehicles,
+            &keyset,
+            self.cursor_codec,
+            |vehicle| KeysetPosition {
+                value: sort_by.keyset_value(vehicle),
+                id: vehicle.id.clone(),
             },
-        })
+            total_count,
+        );
+        Ok(page.map(VehicleResponse::from))
     }
 }
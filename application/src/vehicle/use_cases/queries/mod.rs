@@ -1,3 +1,4 @@
 // pub mod get_vehicle;
 // pub mod get_vehicle_status;
-pub mod get_vehicles;
\ No newline at end of file
+pub mod get_vehicles;
+pub mod get_vehicle_statuses;
@@ -0,0 +1,204 @@
+//! An organization with a user on the in-memory backend, for the tests of the use cases. The
+//! fleet is a `ConformanceBackend`, so the entities of a test are seeded with
+//! `conformance::fixtures`.
+#![allow(dead_code)]
+
+use application::auth::AuthenticatedUser;
+use conformance::{ConformanceBackend, fixtures};
+use domain::{
+    depot::{entities::depot::Depot, repositories::depot_repository::DepotRepository},
+    organization::{
+        entities::organization::Organization,
+        repositories::organization_repository::OrganizationRepository,
+    },
+    user::{
+        entities::user::UserIdentity,
+        value_types::{Email, UserId},
+    },
+    vehicle::entities::vehicle::VehicleIdentity,
+};
+use memory::{
+    repositories::{
+        depot_repository::MemoryDepotRepository, diagnostic_repository::MemoryDiagnosticRepository,
+        inspection_repository::MemoryInspectionRepository,
+        maintenance_interval_template_repository::MemoryMaintenanceIntervalTemplateRepository,
+        maintenance_record_repository::MemoryMaintenanceRecordRepository,
+        maintenance_repository::MemoryMaintenanceRepository,
+        maintenance_type_repository::MemoryMaintenanceTypeRepository,
+        organization_repository::MemoryOrganizationRepository,
+        telematics_device_repository::MemoryTelematicsDeviceRepository,
+        trip_repository::MemoryTripRepository,
+        vehicle_movement_repository::MemoryVehicleMovementRepository,
+        vehicle_repository::MemoryVehicleRepository,
+        vehicle_status_repository::MemoryVehicleStatusRepository,
+    },
+    store::MemoryStore,
+};
+
+/// Two vehicles based at two depots, and a manager of the depot of the first one.
+pub struct Managed {
+    pub manager: AuthenticatedUser,
+    pub based: VehicleIdentity,
+    pub elsewhere: VehicleIdentity,
+}
+
+pub struct Fleet {
+    pub store: MemoryStore,
+    pub organization: Organization,
+    pub user: UserIdentity,
+    pub organizations: MemoryOrganizationRepository,
+    pub vehicles: MemoryVehicleRepository,
+    pub vehicle_statuses: MemoryVehicleStatusRepository,
+    pub maintenance_types: MemoryMaintenanceTypeRepository,
+    pub maintenances: MemoryMaintenanceRepository,
+    pub maintenance_records: MemoryMaintenanceRecordRepository,
+    pub maintenance_interval_templates: MemoryMaintenanceIntervalTemplateRepository,
+    pub depots: MemoryDepotRepository,
+    pub vehicle_movements: MemoryVehicleMovementRepository,
+    pub telematics_devices: MemoryTelematicsDeviceRepository,
+    pub trips: MemoryTripRepository,
+    pub diagnostics: MemoryDiagnosticRepository,
+    pub inspections: MemoryInspectionRepository,
+}
+
+impl Fleet {
+    pub async fn new() -> Self {
+        let store = MemoryStore::new();
+        let organizations = MemoryOrganizationRepository::new(&store);
+        let organization = organizations
+            .create(Organization::new("Steppe Logistics").expect("valid organization"))
+            .await
+            .expect("organization created");
+        let user = user(&store, &organization, "alice");
+        Fleet {
+            organization,
+            user,
+            organizations,
+            vehicles: MemoryVehicleRepository::new(&store),
+            vehicle_statuses: MemoryVehicleStatusRepository::new(&store),
+            maintenance_types: MemoryMaintenanceTypeRepository::new(&store),
+            maintenances: MemoryMaintenanceRepository::new(&store),
+            maintenance_records: MemoryMaintenanceRecordRepository::new(&store),
+            maintenance_interval_templates: MemoryMaintenanceIntervalTemplateRepository::new(
+                &store,
+            ),
+            depots: MemoryDepotRepository::new(&store),
+            vehicle_movements: MemoryVehicleMovementRepository::new(&store),
+            telematics_devices: MemoryTelematicsDeviceRepository::new(&store),
+            trips: MemoryTripRepository::new(&store),
+            diagnostics: MemoryDiagnosticRepository::new(&store),
+            inspections: MemoryInspectionRepository::new(&store),
+            store,
+        }
+    }
+
+    /// The user, seeing every vehicle of the organization.
+    pub async fn authenticated(&self) -> AuthenticatedUser {
+        AuthenticatedUser::resolve(&self.user, &self.depots)
+            .await
+            .expect("user resolved")
+    }
+
+    /// A new user of the organization managing `depots`, limited to their vehicles.
+    pub async fn manager(&self, name: &str, depots: &[&Depot]) -> AuthenticatedUser {
+        let manager = user(&self.store, &self.organization, name);
+        for depot in depots {
+            self.depots
+                .assign_manager(self.organization.id, depot.id, manager.id)
+                .await
+                .expect("manager assigned");
+        }
+        AuthenticatedUser::resolve(&manager, &self.depots)
+            .await
+            .expect("manager resolved")
+    }
+
+    /// Seeds the vehicles in and out of the scope of a manager.
+    pub async fn managed(&self) -> Managed {
+        let region = fixtures::region(self, &self.organization, "North").await;
+        let astana = fixtures::depot(self, &self.organization, &region, "Astana").await;
+        let almaty = fixtures::depot(self, &self.organization, &region, "Almaty").await;
+        let based = fixtures::vehicle(self, &self.organization, 1, "123ABC02").await;
+        let elsewhere = fixtures::vehicle(self, &self.organization, 2, "456DEF02").await;
+        fixtures::movement(self, &based, &astana, &self.user, 1).await;
+        fixtures::movement(self, &elsewhere, &almaty, &self.user, 1).await;
+        Managed {
+            manager: self.manager("bob", &[&astana]).await,
+            based,
+            elsewhere,
+        }
+    }
+}
+
+/// Seeds a user of the organization, named `name`.
+pub fn user(store: &MemoryStore, organization: &Organization, name: &str) -> UserIdentity {
+    let id = uuid::Uuid::new_v4();
+    let user = UserIdentity {
+        id,
+        uuid: UserId::new(id),
+        organization_id: organization.id,
+        username: name.to_string(),
+        email: Email::new(format!("{}@fleet.test", name)).expect("valid email"),
+        first_name: name.to_string(),
+        last_name: "Tester".to_string(),
+    };
+    store.insert_user(user.clone());
+    user
+}
+
+impl ConformanceBackend for Fleet {
+    type Organizations = MemoryOrganizationRepository;
+    type Vehicles = MemoryVehicleRepository;
+    type VehicleStatuses = MemoryVehicleStatusRepository;
+    type MaintenanceTypes = MemoryMaintenanceTypeRepository;
+    type Maintenances = MemoryMaintenanceRepository;
+    type MaintenanceRecords = MemoryMaintenanceRecordRepository;
+    type Depots = MemoryDepotRepository;
+    type VehicleMovements = MemoryVehicleMovementRepository;
+    type TelematicsDevices = MemoryTelematicsDeviceRepository;
+    type Trips = MemoryTripRepository;
+    type Diagnostics = MemoryDiagnosticRepository;
+    type Inspections = MemoryInspectionRepository;
+
+    fn organizations(&self) -> &Self::Organizations {
+        &self.organizations
+    }
+    fn vehicles(&self) -> &Self::Vehicles {
+        &self.vehicles
+    }
+    fn vehicle_statuses(&self) -> &Self::VehicleStatuses {
+        &self.vehicle_statuses
+    }
+    fn maintenance_types(&self) -> &Self::MaintenanceTypes {
+        &self.maintenance_types
+    }
+    fn maintenances(&self) -> &Self::Maintenances {
+        &self.maintenances
+    }
+    fn maintenance_records(&self) -> &Self::MaintenanceRecords {
+        &self.maintenance_records
+    }
+    fn depots(&self) -> &Self::Depots {
+        &self.depots
+    }
+    fn vehicle_movements(&self) -> &Self::VehicleMovements {
+        &self.vehicle_movements
+    }
+    fn telematics_devices(&self) -> &Self::TelematicsDevices {
+        &self.telematics_devices
+    }
+    fn trips(&self) -> &Self::Trips {
+        &self.trips
+    }
+    fn diagnostics(&self) -> &Self::Diagnostics {
+        &self.diagnostics
+    }
+    fn inspections(&self) -> &Self::Inspections {
+        &self.inspections
+    }
+
+    async fn insert_user(&self, user: UserIdentity) -> Result<(), String> {
+        self.store.insert_user(user);
+        Ok(())
+    }
+}
@@ -0,0 +1,259 @@
+mod common;
+
+use application::{
+    auth::AuthenticatedUser,
+    reporting::{
+        models::{
+            report_column::ReportColumn, report_format::ReportFormat, report_kind::ReportKind,
+        },
+        use_cases::queries::export_maintenance_report::{
+            ExportMaintenanceReportError, ExportMaintenanceReportQuery,
+            ExportMaintenanceReportResponse, ExportMaintenanceReportUseCase,
+        },
+    },
+    vehicle::filters::vehicle_filter::{NewVehicleFilter, VehicleFilter},
+};
+use common::Fleet;
+use conformance::fixtures::{self, at};
+use domain::depot::entities::depot::Depot;
+
+/// Two vehicles based in two depots, with an oil change performed on days 1, 10 and 20 for the
+/// first one and on day 10 for the second one.
+struct Seeded {
+    fleet: Fleet,
+    north: Depot,
+    south: Depot,
+}
+
+async fn seeded() -> Seeded {
+    let fleet = Fleet::new().await;
+    let (organization, user) = (&fleet.organization, &fleet.user);
+    let region = fixtures::region(&fleet, organization, "Akmola").await;
+    let north = fixtures::depot(&fleet, organization, &region, "Astana North").await;
+    let south = fixtures::depot(&fleet, organization, &region, "Astana South").await;
+    let oil_change = fixtures::maintenance_type(&fleet, "Oil change", user).await;
+
+    let first = fixtures::vehicle(&fleet, organization, 1, "123ABC02").await;
+    let second = fixtures::vehicle(&fleet, organization, 2, "456DEF02").await;
+    fixtures::movement(&fleet, &first, &north, user, 0).await;
+    fixtures::movement(&fleet, &second, &south, user, 0).await;
+
+    for (vehicle, days) in [(&first, &[1, 10, 20][..]), (&second, &[10][..])] {
+        let rule = fixtures::maintenance(&fleet, oil_change.id, vehicle, user, "Kilometers").await;
+        for &day in days {
+            let status = fixtures::status(&fleet, vehicle, user, day, 1_000 * day as i32).await;
+            fixtures::record(&fleet, &rule, &status, user, day, None).await;
+        }
+    }
+
+    Seeded {
+        fleet,
+        north,
+        south,
+    }
+}
+
+fn query(kind: ReportKind, columns: Option<Vec<ReportColumn>>) -> ExportMaintenanceReportQuery {
+    ExportMaintenanceReportQuery {
+        kind,
+        format: ReportFormat::Csv,
+        columns,
+        from: None,
+        to: None,
+        vehicle_filter: VehicleFilter::new(NewVehicleFilter::default()),
+    }
+}
+
+async fn export(
+    fleet: &Fleet,
+    query: ExportMaintenanceReportQuery,
+    user: &AuthenticatedUser,
+) -> Result<(ExportMaintenanceReportResponse, String), ExportMaintenanceReportError> {
+    let mut output = Vec::new();
+    let response = ExportMaintenanceReportUseCase::new(
+        &fleet.vehicles,
+        &fleet.maintenances,
+        &fleet.maintenance_records,
+        &fleet.vehicle_statuses,
+    )
+    .execute(query, &mut output, user)
+    .await?;
+    Ok((response, String::from_utf8(output).expect("UTF-8 CSV")))
+}
+
+fn performed_at(day: i64) -> String {
+    at(day).format("%Y-%m-%d %H:%M").to_string()
+}
+
+#[tokio::test]
+async fn history_is_limited_to_the_date_range() {
+    let Seeded { fleet, .. } = seeded().await;
+    let columns = vec![
+        ReportColumn::LicensePlate,
+        ReportColumn::PerformedAt,
+        ReportColumn::Details,
+    ];
+    let (response, csv) = export(
+        &fleet,
+        ExportMaintenanceReportQuery {
+            from: Some(at(5)),
+            to: Some(at(15)),
+            ..query(ReportKind::VehicleHistory, Some(columns.clone()))
+        },
+        &fleet.authenticated().await,
+    )
+    .await
+    .expect("report exported");
+
+    assert_eq!(response.columns, columns);
+    assert_eq!(response.vehicle_count, 2);
+    assert_eq!(response.row_count, 2);
+    assert_eq!(
+        csv,
+        format!(
+            "License Plate,Performed At,Details\n\
+             123ABC02,{day},Performed on day 10\n\
+             456DEF02,{day},Performed on day 10\n",
+            day = performed_at(10)
+        )
+    );
+
+    let (response, _) = export(
+        &fleet,
+        ExportMaintenanceReportQuery {
+            from: Some(at(5)),
+            ..query(ReportKind::VehicleHistory, None)
+        },
+        &fleet.authenticated().await,
+    )
+    .await
+    .expect("report exported");
+    assert_eq!(
+        response.row_count, 3,
+        "an open range keeps the later records"
+    );
+}
+
+#[tokio::test]
+async fn inverted_date_range_is_rejected() {
+    let Seeded { fleet, .. } = seeded().await;
+    let result = export(
+        &fleet,
+        ExportMaintenanceReportQuery {
+            from: Some(at(15)),
+            to: Some(at(5)),
+            ..query(ReportKind::VehicleHistory, None)
+        },
+        &fleet.authenticated().await,
+    )
+    .await;
+    assert!(
+        matches!(result, Err(ExportMaintenanceReportError::InvalidDateRange)),
+        "{:?}",
+        result.map(|(_, csv)| csv)
+    );
+}
+
+#[tokio::test]
+async fn vehicles_are_limited_to_the_filter_and_the_scope_of_the_user() {
+    let Seeded {
+        fleet,
+        north,
+        south,
+    } = seeded().await;
+    let columns = Some(vec![ReportColumn::LicensePlate, ReportColumn::RecordCount]);
+
+    let (_, csv) = export(
+        &fleet,
+        ExportMaintenanceReportQuery {
+            vehicle_filter: VehicleFilter::new(NewVehicleFilter {
+                depot_id: Some(south.id),
+                ..Default::default()
+            }),
+            ..query(ReportKind::FleetSummary, columns.clone())
+        },
+        &fleet.authenticated().await,
+    )
+    .await
+    .expect("report exported");
+    assert_eq!(
+        csv, "License Plate,Records\n456DEF02,1\n",
+        "the filter applies"
+    );
+
+    // A depot manager sees the vehicles of their depots, whatever the filter asks for
+    let manager = fleet.manager("bob", &[&north]).await;
+    let mut widened = VehicleFilter::new(NewVehicleFilter::default());
+    widened.depot_scope = Some(vec![north.id, south.id]);
+    let (response, csv) = export(
+        &fleet,
+        ExportMaintenanceReportQuery {
+            vehicle_filter: widened,
+            ..query(ReportKind::FleetSummary, columns)
+        },
+        &manager,
+    )
+    .await
+    .expect("report exported");
+    assert_eq!(response.vehicle_count, 1);
+    assert_eq!(csv, "License Plate,Records\n123ABC02,3\n");
+}
+
+#[tokio::test]
+async fn every_page_of_vehicles_is_exported() {
+    let Seeded { fleet, .. } = seeded().await;
+    let mut filter = VehicleFilter::new(NewVehicleFilter::default());
+    filter.page_size = 1;
+    let (response, _) = export(
+        &fleet,
+        ExportMaintenanceReportQuery {
+            vehicle_filter: filter,
+            ..query(ReportKind::FleetSummary, None)
+        },
+        &fleet.authenticated().await,
+    )
+    .await
+    .expect("report exported");
+    assert_eq!(response.vehicle_count, 2);
+    assert_eq!(response.row_count, 2);
+}
+
+#[tokio::test]
+async fn columns_default_to_the_report_and_must_be_available() {
+    let Seeded { fleet, .. } = seeded().await;
+    let user = fleet.authenticated().await;
+
+    let (response, csv) = export(&fleet, query(ReportKind::Overdue, None), &user)
+        .await
+        .expect("report exported");
+    assert_eq!(response.columns, ReportKind::Overdue.available_columns());
+    assert_eq!(
+        csv.lines().next(),
+        Some(
+            "License Plate,VIN,Make,Model,Maintenance Type,Interval Type,Interval,Last Done,\
+             Current,Due At,Used %,Status"
+        )
+    );
+
+    let invalid = export(
+        &fleet,
+        query(ReportKind::VehicleHistory, Some(vec![ReportColumn::Status])),
+        &user,
+    )
+    .await;
+    match invalid {
+        Err(ExportMaintenanceReportError::InvalidColumn(column)) => assert_eq!(column, "status"),
+        other => panic!(
+            "a due-status column isn't in the history, got {:?}",
+            other.err()
+        ),
+    }
+
+    let none = export(
+        &fleet,
+        query(ReportKind::VehicleHistory, Some(vec![])),
+        &user,
+    )
+    .await;
+    assert!(matches!(none, Err(ExportMaintenanceReportError::NoColumns)));
+}
@@ -0,0 +1,55 @@
+mod common;
+
+use application::{
+    auth::AuthenticatedUser,
+    fuel::use_cases::queries::get_fuel_consumption::{
+        GetFuelConsumptionError, GetFuelConsumptionQuery, GetFuelConsumptionResponse,
+        GetFuelConsumptionUseCase,
+    },
+};
+use common::{Fleet, Managed};
+use memory::repositories::{
+    fuel_event_repository::MemoryFuelEventRepository,
+    fuel_tank_repository::MemoryFuelTankRepository,
+};
+
+async fn consumption(
+    fleet: &Fleet,
+    vehicle_id: uuid::Uuid,
+    user: &AuthenticatedUser,
+) -> Result<GetFuelConsumptionResponse, GetFuelConsumptionError> {
+    let events = MemoryFuelEventRepository::new(&fleet.store);
+    let tanks = MemoryFuelTankRepository::new(&fleet.store);
+    GetFuelConsumptionUseCase::new(&fleet.vehicles, &events, &tanks)
+        .execute(
+            GetFuelConsumptionQuery {
+                vehicle_id,
+                from: None,
+                to: None,
+                settings: None,
+            },
+            user,
+        )
+        .await
+}
+
+#[tokio::test]
+async fn a_manager_reads_the_consumption_of_the_vehicles_of_their_depots_only() {
+    let fleet = Fleet::new().await;
+    let Managed {
+        manager,
+        based,
+        elsewhere,
+    } = fleet.managed().await;
+
+    consumption(&fleet, based.id, &manager)
+        .await
+        .expect("consumption read");
+
+    let error = consumption(&fleet, elsewhere.id, &manager).await;
+    assert!(
+        matches!(error, Err(GetFuelConsumptionError::VehicleNotFound(id)) if id == elsewhere.id),
+        "{:?}",
+        error.err()
+    );
+}
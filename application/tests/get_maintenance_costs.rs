@@ -0,0 +1,236 @@
+mod common;
+
+use application::{
+    auth::AuthenticatedUser,
+    maintenance::use_cases::queries::get_maintenance_costs::{
+        GetMaintenanceCostsError, GetMaintenanceCostsQuery, GetMaintenanceCostsResponse,
+        GetMaintenanceCostsUseCase, MaintenanceCostGroupBy, MaintenanceCostGroupKey,
+    },
+    vehicle::filters::vehicle_filter::{NewVehicleFilter, VehicleFilter},
+};
+use common::Fleet;
+use conformance::{
+    ConformanceBackend,
+    fixtures::{self, at},
+};
+use domain::{
+    depot::entities::depot::Depot,
+    maintenance::repositories::maintenance_type_repository::MaintenanceTypeRepository,
+    vehicle::entities::vehicle::VehicleIdentity,
+};
+use rust_decimal::Decimal;
+
+/// Two vehicles based in two depots. The first one had an oil change on day 1 and an oil
+/// analysis, a type deprecated since, on day 20, both with a cost, and drove 2 500 km in between;
+/// the second one had an oil change without a cost on day 10.
+struct Seeded {
+    fleet: Fleet,
+    camry: VehicleIdentity,
+    oil_change_id: i32,
+    oil_analysis_id: i32,
+    south: Depot,
+}
+
+async fn seeded() -> Seeded {
+    let fleet = Fleet::new().await;
+    let (organization, user) = (&fleet.organization, &fleet.user);
+    let region = fixtures::region(&fleet, organization, "Akmola").await;
+    let north = fixtures::depot(&fleet, organization, &region, "Astana North").await;
+    let south = fixtures::depot(&fleet, organization, &region, "Astana South").await;
+    let oil_change = fixtures::maintenance_type(&fleet, "Oil change", user).await;
+    let oil_analysis = fixtures::maintenance_type(&fleet, "Oil analysis", user).await;
+
+    let camry = fixtures::vehicle(&fleet, organization, 1, "123ABC02").await;
+    let volvo = fixtures::vehicle(&fleet, organization, 2, "456DEF02").await;
+    fixtures::movement(&fleet, &camry, &north, user, 0).await;
+    fixtures::movement(&fleet, &volvo, &south, user, 0).await;
+
+    let rule = fixtures::maintenance(&fleet, oil_change.id, &camry, user, "Kilometers").await;
+    let status = fixtures::status(&fleet, &camry, user, 1, 10_000).await;
+    fixtures::record(&fleet, &rule, &status, user, 1, Some(fixtures::cost())).await;
+    let rule = fixtures::maintenance(&fleet, oil_analysis.id, &camry, user, "Kilometers").await;
+    let status = fixtures::status(&fleet, &camry, user, 20, 12_500).await;
+    fixtures::record(&fleet, &rule, &status, user, 20, Some(fixtures::cost())).await;
+
+    let rule = fixtures::maintenance(&fleet, oil_change.id, &volvo, user, "Kilometers").await;
+    let status = fixtures::status(&fleet, &volvo, user, 10, 50_000).await;
+    fixtures::record(&fleet, &rule, &status, user, 10, None).await;
+
+    let deprecated = fixtures::maintenance_type_entity(&fleet, organization, oil_analysis.id).await;
+    fleet
+        .maintenance_types()
+        .deprecate(organization.id, deprecated, user.id)
+        .await
+        .expect("maintenance type deprecated");
+
+    Seeded {
+        fleet,
+        camry,
+        oil_change_id: oil_change.id,
+        oil_analysis_id: oil_analysis.id,
+        south,
+    }
+}
+
+fn query(group_by: MaintenanceCostGroupBy) -> GetMaintenanceCostsQuery {
+    GetMaintenanceCostsQuery {
+        group_by,
+        from: None,
+        to: None,
+        vehicle_filter: VehicleFilter::new(NewVehicleFilter::default()),
+    }
+}
+
+async fn costs(
+    fleet: &Fleet,
+    query: GetMaintenanceCostsQuery,
+    user: &AuthenticatedUser,
+) -> Result<GetMaintenanceCostsResponse, GetMaintenanceCostsError> {
+    GetMaintenanceCostsUseCase::new(&fleet.vehicles, &fleet.maintenance_records)
+        .execute(query, user)
+        .await
+}
+
+#[tokio::test]
+async fn costs_per_vehicle_come_with_the_cost_per_kilometer() {
+    let Seeded { fleet, camry, .. } = seeded().await;
+
+    let response = costs(
+        &fleet,
+        query(MaintenanceCostGroupBy::Vehicle),
+        &fleet.authenticated().await,
+    )
+    .await
+    .expect("costs read");
+    assert_eq!(response.vehicle_count, 2);
+    assert_eq!(response.records_without_cost, 1);
+    assert_eq!(response.groups.len(), 1);
+
+    // Two oil changes of 4.5 l at 4 500.50 and an hour at 8 000
+    let group = &response.groups[0];
+    assert_eq!(
+        group.key,
+        MaintenanceCostGroupKey::Vehicle {
+            vehicle_id: camry.id
+        }
+    );
+    assert_eq!(group.label, "123ABC02");
+    assert_eq!(group.currency, "KZT");
+    assert_eq!(group.parts, Decimal::new(4_050_450, 2));
+    assert_eq!(group.labor, Decimal::from(16_000));
+    assert_eq!(group.total, Decimal::new(5_650_450, 2));
+    assert_eq!(group.labor_hours, Decimal::from(2));
+    assert_eq!(group.record_count, 2);
+    assert_eq!(group.distance_km, Some(2_500));
+    assert_eq!(group.cost_per_km, Some(Decimal::new(226_018, 4)));
+}
+
+#[tokio::test]
+async fn deprecated_maintenance_types_keep_their_name() {
+    let Seeded {
+        fleet,
+        oil_change_id,
+        oil_analysis_id,
+        ..
+    } = seeded().await;
+
+    let response = costs(
+        &fleet,
+        query(MaintenanceCostGroupBy::MaintenanceType),
+        &fleet.authenticated().await,
+    )
+    .await
+    .expect("costs read");
+    let groups: Vec<_> = response
+        .groups
+        .iter()
+        .map(|group| (group.key, group.label.as_str(), group.distance_km))
+        .collect();
+    assert_eq!(
+        groups,
+        vec![
+            (
+                MaintenanceCostGroupKey::MaintenanceType {
+                    maintenance_type_id: oil_change_id
+                },
+                "Oil change",
+                None
+            ),
+            (
+                MaintenanceCostGroupKey::MaintenanceType {
+                    maintenance_type_id: oil_analysis_id
+                },
+                "Oil analysis",
+                None
+            ),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn costs_per_month_are_limited_to_the_period() {
+    let Seeded { fleet, .. } = seeded().await;
+
+    // Day 1 is in June 2025, day 20 in July
+    let response = costs(
+        &fleet,
+        query(MaintenanceCostGroupBy::Month),
+        &fleet.authenticated().await,
+    )
+    .await
+    .expect("costs read");
+    let labels: Vec<&str> = response
+        .groups
+        .iter()
+        .map(|group| group.label.as_str())
+        .collect();
+    assert_eq!(labels, vec!["2025-06", "2025-07"]);
+
+    let response = costs(
+        &fleet,
+        GetMaintenanceCostsQuery {
+            from: Some(at(10)),
+            ..query(MaintenanceCostGroupBy::Month)
+        },
+        &fleet.authenticated().await,
+    )
+    .await
+    .expect("costs read");
+    assert_eq!(response.groups.len(), 1);
+    assert_eq!(
+        response.groups[0].key,
+        MaintenanceCostGroupKey::Month {
+            year: 2025,
+            month: 7
+        }
+    );
+    assert_eq!(response.records_without_cost, 1);
+
+    let error = costs(
+        &fleet,
+        GetMaintenanceCostsQuery {
+            from: Some(at(10)),
+            to: Some(at(1)),
+            ..query(MaintenanceCostGroupBy::Month)
+        },
+        &fleet.authenticated().await,
+    )
+    .await;
+    assert!(matches!(
+        error,
+        Err(GetMaintenanceCostsError::InvalidDateRange)
+    ));
+}
+
+#[tokio::test]
+async fn a_depot_manager_only_sums_the_costs_of_their_depots() {
+    let Seeded { fleet, south, .. } = seeded().await;
+    let manager = fleet.manager("bob", &[&south]).await;
+
+    let response = costs(&fleet, query(MaintenanceCostGroupBy::Vehicle), &manager)
+        .await
+        .expect("costs read");
+    assert_eq!(response.vehicle_count, 1);
+    assert!(response.groups.is_empty());
+    assert_eq!(response.records_without_cost, 1);
+}
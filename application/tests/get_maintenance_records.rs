@@ -0,0 +1,59 @@
+mod common;
+
+use application::{
+    auth::AuthenticatedUser,
+    maintenance::use_cases::queries::get_maintenance_records::{
+        GetMaintenanceRecordsError, GetMaintenanceRecordsQuery, GetMaintenanceRecordsResponse,
+        GetMaintenanceRecordsUseCase,
+    },
+    shared::{cursor::CursorCodec, pagination::PageRequest},
+};
+use common::{Fleet, Managed};
+use conformance::fixtures;
+
+async fn records(
+    fleet: &Fleet,
+    vehicle_id: uuid::Uuid,
+    user: &AuthenticatedUser,
+) -> Result<GetMaintenanceRecordsResponse, GetMaintenanceRecordsError> {
+    let codec = CursorCodec::new("secret");
+    GetMaintenanceRecordsUseCase::new(&fleet.vehicles, &fleet.maintenance_records, &codec)
+        .execute(
+            GetMaintenanceRecordsQuery {
+                vehicle_id,
+                filter: None,
+                page: PageRequest::default(),
+            },
+            user,
+        )
+        .await
+}
+
+#[tokio::test]
+async fn a_manager_lists_the_records_of_the_vehicles_of_their_depots_only() {
+    let fleet = Fleet::new().await;
+    let Managed {
+        manager,
+        based,
+        elsewhere,
+    } = fleet.managed().await;
+    let oil_change = fixtures::maintenance_type(&fleet, "Oil change", &fleet.user).await;
+    for vehicle in [&based, &elsewhere] {
+        let rule =
+            fixtures::maintenance(&fleet, oil_change.id, vehicle, &fleet.user, "Kilometers").await;
+        let status = fixtures::status(&fleet, vehicle, &fleet.user, 1, 1_000).await;
+        fixtures::record(&fleet, &rule, &status, &fleet.user, 1, None).await;
+    }
+
+    let listed = records(&fleet, based.id, &manager)
+        .await
+        .expect("records listed");
+    assert_eq!(listed.items.len(), 1);
+
+    let error = records(&fleet, elsewhere.id, &manager).await;
+    assert!(
+        matches!(error, Err(GetMaintenanceRecordsError::VehicleNotFound(id)) if id == elsewhere.id),
+        "{:?}",
+        error.err()
+    );
+}
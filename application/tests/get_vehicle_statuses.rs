@@ -0,0 +1,54 @@
+mod common;
+
+use application::{
+    auth::AuthenticatedUser,
+    shared::{cursor::CursorCodec, pagination::PageRequest},
+    vehicle::use_cases::queries::get_vehicle_statuses::{
+        GetVehicleStatusesError, GetVehicleStatusesQuery, GetVehicleStatusesResponse,
+        GetVehicleStatusesUseCase,
+    },
+};
+use common::{Fleet, Managed};
+use conformance::fixtures;
+
+async fn statuses(
+    fleet: &Fleet,
+    vehicle_id: uuid::Uuid,
+    user: &AuthenticatedUser,
+) -> Result<GetVehicleStatusesResponse, GetVehicleStatusesError> {
+    let codec = CursorCodec::new("secret");
+    GetVehicleStatusesUseCase::new(&fleet.vehicles, &fleet.vehicle_statuses, &codec)
+        .execute(
+            GetVehicleStatusesQuery {
+                vehicle_id,
+                filter: None,
+                page: PageRequest::default(),
+            },
+            user,
+        )
+        .await
+}
+
+#[tokio::test]
+async fn a_manager_lists_the_statuses_of_the_vehicles_of_their_depots_only() {
+    let fleet = Fleet::new().await;
+    let Managed {
+        manager,
+        based,
+        elsewhere,
+    } = fleet.managed().await;
+    fixtures::status(&fleet, &based, &fleet.user, 1, 1_000).await;
+    fixtures::status(&fleet, &elsewhere, &fleet.user, 1, 2_000).await;
+
+    let listed = statuses(&fleet, based.id, &manager)
+        .await
+        .expect("statuses listed");
+    assert_eq!(listed.items.len(), 1);
+
+    let error = statuses(&fleet, elsewhere.id, &manager).await;
+    assert!(
+        matches!(error, Err(GetVehicleStatusesError::VehicleNotFound(id)) if id == elsewhere.id),
+        "{:?}",
+        error.err()
+    );
+}
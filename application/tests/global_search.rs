@@ -0,0 +1,51 @@
+mod common;
+
+use application::search::{
+    models::search_hit::SearchHitKind,
+    use_cases::queries::global_search::{GlobalSearchQuery, GlobalSearchUseCase},
+};
+use common::{Fleet, Managed};
+use conformance::fixtures;
+use memory::repositories::search_repository::MemorySearchRepository;
+
+#[tokio::test]
+async fn a_manager_finds_the_vehicles_of_their_depots_and_their_records_only() {
+    let fleet = Fleet::new().await;
+    let Managed {
+        manager,
+        based,
+        elsewhere,
+    } = fleet.managed().await;
+    let oil_change = fixtures::maintenance_type(&fleet, "Oil change", &fleet.user).await;
+    for vehicle in [&based, &elsewhere] {
+        let rule =
+            fixtures::maintenance(&fleet, oil_change.id, vehicle, &fleet.user, "Kilometers").await;
+        let status = fixtures::status(&fleet, vehicle, &fleet.user, 1, 1_000).await;
+        fixtures::record(&fleet, &rule, &status, &fleet.user, 1, None).await;
+    }
+    let repository = MemorySearchRepository::new(&fleet.store);
+    let search = |kind: SearchHitKind, term: &str| GlobalSearchQuery {
+        term: term.to_string(),
+        language: None,
+        kinds: vec![kind],
+        min_similarity: None,
+        limit: None,
+    };
+
+    for (kind, term) in [
+        (SearchHitKind::Vehicle, "camry"),
+        (SearchHitKind::MaintenanceRecord, "performed"),
+    ] {
+        let found = GlobalSearchUseCase::new(&repository)
+            .execute(search(kind, term), &fleet.authenticated().await)
+            .await
+            .expect("searched");
+        assert_eq!(found.hits.len(), 2, "{:?}", kind);
+
+        let found = GlobalSearchUseCase::new(&repository)
+            .execute(search(kind, term), &manager)
+            .await
+            .expect("searched");
+        assert_eq!(found.hits.len(), 1, "{:?}", kind);
+    }
+}
@@ -0,0 +1,214 @@
+mod common;
+
+use application::maintenance::{
+    catalog::catalog_format::CatalogFormat,
+    use_cases::commands::{
+        create_maintenance_interval_template::{
+            CreateMaintenanceIntervalTemplateCommand, CreateMaintenanceIntervalTemplateUseCase,
+        },
+        import_maintenance_catalog::{
+            CatalogImportMode, CatalogImportOutcome, ImportMaintenanceCatalogCommand,
+            ImportMaintenanceCatalogResponse, ImportMaintenanceCatalogUseCase,
+        },
+    },
+};
+use common::Fleet;
+use conformance::fixtures;
+use domain::maintenance::repositories::{
+    maintenance_interval_template_repository::MaintenanceIntervalTemplateRepository,
+    maintenance_type_repository::MaintenanceTypeRepository,
+};
+
+const CATALOG: &str = "
+version: '2025.1'
+maintenance_types:
+  - name: Oil change
+    description: Engine oil and filter
+    category: engine
+    applicability: combustion_engine
+    intervals:
+      - interval_type: Kilometers
+        interval_value: 10000
+        yellow_threshold: 75
+        red_threshold: 90
+      - engine_type: diesel
+        interval_type: Kilometers
+        interval_value: 8000
+        yellow_threshold: 80
+        red_threshold: 95
+  - name: Cabin filter
+    description: Cabin air filter
+    intervals:
+      - interval_type: Years
+        interval_value: 1
+        yellow_threshold: 75
+        red_threshold: 90
+";
+
+async fn import(
+    fleet: &Fleet,
+    mode: CatalogImportMode,
+    catalog: &str,
+) -> ImportMaintenanceCatalogResponse {
+    ImportMaintenanceCatalogUseCase::new(
+        &fleet.maintenance_types,
+        &fleet.maintenance_interval_templates,
+    )
+    .execute(
+        ImportMaintenanceCatalogCommand {
+            format: CatalogFormat::Yaml,
+            mode,
+        },
+        catalog.as_bytes(),
+        &fleet.authenticated().await,
+    )
+    .await
+    .expect("catalog imported")
+}
+
+/// The number of maintenance types and interval templates of the organization.
+async fn stored(fleet: &Fleet) -> (usize, usize) {
+    let types = fleet
+        .maintenance_types
+        .get_all_view(fleet.organization.id)
+        .await
+        .expect("maintenance types read");
+    let templates = fleet
+        .maintenance_interval_templates
+        .find_all(fleet.organization.id)
+        .await
+        .expect("templates read");
+    (types.len(), templates.len())
+}
+
+#[tokio::test]
+async fn dry_run_reports_without_writing() {
+    let fleet = Fleet::new().await;
+    let report = import(&fleet, CatalogImportMode::DryRun, CATALOG).await;
+
+    assert_eq!(report.version, "2025.1");
+    assert_eq!(report.valid, 2);
+    assert_eq!(report.diff.changes.len(), 2, "both types are added");
+    assert_eq!(stored(&fleet).await, (0, 0));
+}
+
+#[tokio::test]
+async fn import_again_changes_nothing() {
+    let fleet = Fleet::new().await;
+    let first = import(&fleet, CatalogImportMode::Commit, CATALOG).await;
+    assert_eq!(first.created, 2);
+    assert_eq!(
+        first
+            .maintenance_types
+            .iter()
+            .map(|report| report.intervals)
+            .collect::<Vec<_>>(),
+        vec![2, 1]
+    );
+    assert_eq!(stored(&fleet).await, (2, 3));
+
+    let again = import(&fleet, CatalogImportMode::Commit, CATALOG).await;
+    assert!(again.diff.is_empty(), "{:?}", again.diff.changes);
+    assert_eq!(again.skipped, 2);
+    assert_eq!((again.created, again.completed, again.errors), (0, 0, 0));
+    assert_eq!(stored(&fleet).await, (2, 3));
+}
+
+#[tokio::test]
+async fn missing_intervals_of_an_existing_type_are_added() {
+    let fleet = Fleet::new().await;
+    // An import that stopped after the type and its first interval
+    let oil_change = fixtures::maintenance_type(&fleet, "Oil change", &fleet.user).await;
+    CreateMaintenanceIntervalTemplateUseCase::new(
+        &fleet.maintenance_types,
+        &fleet.maintenance_interval_templates,
+    )
+    .execute(
+        CreateMaintenanceIntervalTemplateCommand {
+            maintenance_type_id: oil_change.id,
+            make: None,
+            model: None,
+            engine_type: None,
+            interval_type: "Kilometers".to_string(),
+            interval_value: 12_000,
+            yellow_threshold: 75,
+            red_threshold: 90,
+        },
+        &fleet.authenticated().await,
+    )
+    .await
+    .expect("interval created");
+
+    let dry_run = import(&fleet, CatalogImportMode::DryRun, CATALOG).await;
+    assert_eq!(
+        dry_run.maintenance_types[0].outcome,
+        CatalogImportOutcome::Valid
+    );
+    assert_eq!(stored(&fleet).await, (1, 1));
+
+    let report = import(&fleet, CatalogImportMode::Commit, CATALOG).await;
+    assert_eq!(
+        report.maintenance_types[0].outcome,
+        CatalogImportOutcome::Completed {
+            id: oil_change.id,
+            intervals: 1
+        },
+        "only the diesel interval is missing"
+    );
+    assert_eq!((report.completed, report.created), (1, 1));
+    assert_eq!(stored(&fleet).await, (2, 3));
+
+    let kept = fleet
+        .maintenance_types
+        .get_view_by_id(fleet.organization.id, oil_change.id)
+        .await
+        .expect("maintenance type read")
+        .expect("maintenance type exists");
+    assert_eq!(
+        kept.description, "Oil change of the vehicle",
+        "fields are kept"
+    );
+    let intervals = fleet
+        .maintenance_interval_templates
+        .find_by_maintenance_type(fleet.organization.id, oil_change.id)
+        .await
+        .expect("templates read");
+    assert!(
+        intervals
+            .iter()
+            .any(|template| template.interval_value == 12_000),
+        "the stored interval is kept"
+    );
+
+    let again = import(&fleet, CatalogImportMode::Commit, CATALOG).await;
+    assert_eq!(again.skipped, 2, "the type is complete now");
+}
+
+#[tokio::test]
+async fn invalid_and_repeated_types_are_reported() {
+    let fleet = Fleet::new().await;
+    let catalog = "
+version: '2025.1'
+maintenance_types:
+  - name: Oil change
+    applicability: combustion_engine
+    intervals:
+      - engine_type: electric
+        interval_type: Kilometers
+        interval_value: 10000
+        yellow_threshold: 75
+        red_threshold: 90
+  - name: Cabin filter
+  - name: ' cabin FILTER '
+";
+    let report = import(&fleet, CatalogImportMode::Commit, catalog).await;
+
+    assert_eq!((report.created, report.errors), (1, 2));
+    let reason = |index: usize| match &report.maintenance_types[index].outcome {
+        CatalogImportOutcome::Error { reason } => reason.clone(),
+        other => panic!("expected an error, got {:?}", other),
+    };
+    assert!(reason(0).contains("Electric vehicle"), "{}", reason(0));
+    assert!(reason(2).contains("more than once"), "{}", reason(2));
+    assert_eq!(stored(&fleet).await, (1, 0));
+}
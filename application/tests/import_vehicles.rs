@@ -0,0 +1,235 @@
+mod common;
+
+use application::vehicle::use_cases::commands::import_vehicles::{
+    ImportMode, ImportRowOutcome, ImportVehiclesCommand, ImportVehiclesError,
+    ImportVehiclesResponse, ImportVehiclesUseCase,
+};
+use common::Fleet;
+use domain::vehicle::{
+    entities::vehicle::NewVehicle, repositories::vehicle_repository::VehicleRepository,
+    value_types::validation_policy::ValidationPolicy,
+};
+use memory::repositories::vehicle_repository::MemoryVehicleRepository;
+
+const HEADER: &str = "make,model,year,vin,license_plate,engine_type\n";
+
+async fn import(
+    fleet: &Fleet,
+    mode: ImportMode,
+    batch_size: usize,
+    rows: &str,
+) -> Result<ImportVehiclesResponse, ImportVehiclesError> {
+    let vehicles = MemoryVehicleRepository::new(&fleet.store);
+    let csv = format!("{}{}", HEADER, rows);
+    ImportVehiclesUseCase::new(&vehicles)
+        .execute(
+            ImportVehiclesCommand {
+                mode,
+                validation: ValidationPolicy::Lenient,
+                batch_size,
+            },
+            csv.as_bytes(),
+            &fleet.authenticated().await,
+        )
+        .await
+}
+
+async fn stored(fleet: &Fleet) -> Vec<String> {
+    let mut vins: Vec<String> = MemoryVehicleRepository::new(&fleet.store)
+        .find_all(fleet.organization.id)
+        .await
+        .expect("vehicles read")
+        .into_iter()
+        .map(|vehicle| vehicle.vin.into_string())
+        .collect();
+    vins.sort();
+    vins
+}
+
+#[tokio::test]
+async fn dry_run_reports_without_writing() {
+    let fleet = Fleet::new().await;
+    let report = import(
+        &fleet,
+        ImportMode::DryRun,
+        0,
+        "Toyota,Camry,2021,JTDBR32E700000001,123ABC02,gasoline\n\
+         Kia,Rio,2019,KNADN512AK6000002,456DEF02,gasoline\n",
+    )
+    .await
+    .expect("file imported");
+
+    assert_eq!(report.valid, 2);
+    assert_eq!(report.created, 0);
+    assert!(
+        report
+            .rows
+            .iter()
+            .all(|row| row.outcome == ImportRowOutcome::Valid)
+    );
+    assert!(stored(&fleet).await.is_empty(), "a dry run writes nothing");
+}
+
+#[tokio::test]
+async fn commit_creates_the_valid_rows() {
+    let fleet = Fleet::new().await;
+    let report = import(
+        &fleet,
+        ImportMode::Commit,
+        0,
+        "Toyota,Camry,2021,JTDBR32E700000001,123ABC02,gasoline\n\
+         Kia,Rio,2019,KNADN512AK6000002,456DEF02,gasoline\n",
+    )
+    .await
+    .expect("file imported");
+
+    assert_eq!(report.created, 2);
+    assert_eq!(
+        report.rows.iter().map(|row| row.line).collect::<Vec<_>>(),
+        vec![2, 3]
+    );
+    assert!(
+        report
+            .rows
+            .iter()
+            .all(|row| matches!(row.outcome, ImportRowOutcome::Created { .. }))
+    );
+    assert_eq!(
+        stored(&fleet).await,
+        vec!["JTDBR32E700000001", "KNADN512AK6000002"]
+    );
+}
+
+#[tokio::test]
+async fn duplicates_inside_the_file_are_skipped() {
+    let fleet = Fleet::new().await;
+    let report = import(
+        &fleet,
+        ImportMode::Commit,
+        0,
+        "Toyota,Camry,2021,JTDBR32E700000001,123ABC02,gasoline\n\
+         Toyota,Corolla,2020,JTDBR32E700000001,789GHI02,gasoline\n\
+         Kia,Rio,2019,KNADN512AK6000002,123 abc 02,gasoline\n",
+    )
+    .await
+    .expect("file imported");
+
+    assert_eq!(report.created, 1);
+    assert_eq!(report.skipped, 2);
+    for row in &report.rows[1..] {
+        assert_eq!(
+            row.outcome,
+            ImportRowOutcome::Skipped {
+                reason: "Duplicate of line 2 in the same file".to_string()
+            },
+            "the same VIN, or the same plate once normalized"
+        );
+    }
+    assert_eq!(stored(&fleet).await, vec!["JTDBR32E700000001"]);
+}
+
+#[tokio::test]
+async fn stored_vehicles_are_skipped_in_every_batch() {
+    let fleet = Fleet::new().await;
+    MemoryVehicleRepository::new(&fleet.store)
+        .create(
+            fleet.organization.id,
+            NewVehicle {
+                make: "Toyota".to_string(),
+                model: "Camry".to_string(),
+                year: 2021,
+                vin: "JTDBR32E700000001".to_string(),
+                license_plate: "123ABC02".to_string(),
+                country: "KZ".to_string(),
+                engine_type: "gasoline".to_string(),
+                battery_capacity: None,
+                tank_capacity: None,
+                legacy_vin: false,
+            },
+        )
+        .await
+        .expect("vehicle created");
+
+    // One row per batch: every batch is looked up on its own
+    let report = import(
+        &fleet,
+        ImportMode::Commit,
+        1,
+        "Kia,Rio,2019,KNADN512AK6000002,456DEF02,gasoline\n\
+         Toyota,Camry,2021,JTDBR32E700000001,789GHI02,gasoline\n\
+         Lada,Niva,2018,XTA212140J0000003,123ABC02,gasoline\n",
+    )
+    .await
+    .expect("file imported");
+
+    assert_eq!(report.created, 1);
+    assert_eq!(report.skipped, 2);
+    assert!(matches!(
+        report.rows[0].outcome,
+        ImportRowOutcome::Created { .. }
+    ));
+    for row in &report.rows[1..] {
+        assert!(
+            matches!(
+                &row.outcome,
+                ImportRowOutcome::Skipped { reason } if reason.contains("already exists")
+            ),
+            "line {} is stored already: {:?}",
+            row.line,
+            row.outcome
+        );
+    }
+}
+
+#[tokio::test]
+async fn missing_column_fails_the_import() {
+    let fleet = Fleet::new().await;
+    let vehicles = MemoryVehicleRepository::new(&fleet.store);
+    let result = ImportVehiclesUseCase::new(&vehicles)
+        .execute(
+            ImportVehiclesCommand {
+                mode: ImportMode::Commit,
+                validation: ValidationPolicy::Lenient,
+                batch_size: 0,
+            },
+            "make,model,year,license_plate,engine_type\n\
+             Toyota,Camry,2021,123ABC02,gasoline\n"
+                .as_bytes(),
+            &fleet.authenticated().await,
+        )
+        .await;
+
+    match result {
+        Err(ImportVehiclesError::MissingColumn(column)) => assert_eq!(column, "vin"),
+        other => panic!("expected MissingColumn, got {:?}", other),
+    }
+    assert!(stored(&fleet).await.is_empty());
+}
+
+#[tokio::test]
+async fn invalid_rows_report_every_reason() {
+    let fleet = Fleet::new().await;
+    let report = import(
+        &fleet,
+        ImportMode::Commit,
+        0,
+        ",Camry,20x1,JTDBR32E700000001,123ABC02,gasoline\n\
+         Toyota,Camry,2021,JTDBR32E7O0000004,456DEF02,gasoline\n\
+         Kia,Rio,2019,KNADN512AK6000002,789GHI02,gasoline\n",
+    )
+    .await
+    .expect("file imported");
+
+    assert_eq!(report.errors, 2);
+    assert_eq!(report.created, 1);
+    let reason = |index: usize| match &report.rows[index].outcome {
+        ImportRowOutcome::Error { reason } => reason.clone(),
+        other => panic!("expected an error, got {:?}", other),
+    };
+    let first = reason(0);
+    assert!(first.contains("Invalid year: 20x1"), "{}", first);
+    assert!(first.to_lowercase().contains("make"), "{}", first);
+    assert!(reason(1).contains("invalid characters: O"), "{}", reason(1));
+    assert_eq!(report.rows[2].line, 4);
+    assert_eq!(stored(&fleet).await, vec!["KNADN512AK6000002"]);
+}
@@ -0,0 +1,263 @@
+mod common;
+
+use application::{
+    auth::AuthenticatedUser,
+    telematics::{
+        traits::telematics_broker::device_topic,
+        use_cases::commands::{
+            consume_telematics_messages::{
+                ConsumeTelematicsMessagesCommand, ConsumeTelematicsMessagesResponse,
+                ConsumeTelematicsMessagesUseCase,
+            },
+            ingest_telematics::{
+                DueStatusChange, IngestTelematicsCommand, IngestTelematicsResponse,
+                IngestTelematicsUseCase,
+            },
+        },
+    },
+};
+use common::Fleet;
+use conformance::fixtures;
+use domain::{
+    maintenance::entities::maintenance_status::MaintenanceStatusLevel,
+    telematics::services::telematics_sampling_service::SamplingSettings,
+    vehicle::repositories::vehicle_status_repository::VehicleStatusRepository,
+};
+use memory::{telematics_broker::MemoryTelematicsBroker, unit_of_work::MemoryUnitOfWork};
+
+async fn ingest(
+    fleet: &Fleet,
+    payload: &str,
+    user: &AuthenticatedUser,
+) -> IngestTelematicsResponse {
+    let unit_of_work = MemoryUnitOfWork::new(&fleet.store);
+    IngestTelematicsUseCase::new(
+        &fleet.vehicles,
+        &fleet.telematics_devices,
+        &fleet.maintenances,
+        &unit_of_work,
+    )
+    .execute(
+        IngestTelematicsCommand {
+            payload: payload.as_bytes().to_vec(),
+            sampling: SamplingSettings::default(),
+        },
+        user,
+    )
+    .await
+    .expect("readings ingested")
+}
+
+async fn consume(
+    fleet: &Fleet,
+    broker: &MemoryTelematicsBroker,
+    max_messages: usize,
+) -> ConsumeTelematicsMessagesResponse {
+    let unit_of_work = MemoryUnitOfWork::new(&fleet.store);
+    ConsumeTelematicsMessagesUseCase::new(
+        broker,
+        &fleet.vehicles,
+        &fleet.telematics_devices,
+        &fleet.maintenances,
+        &unit_of_work,
+    )
+    .execute(
+        ConsumeTelematicsMessagesCommand {
+            max_messages,
+            sampling: SamplingSettings::default(),
+        },
+        &fleet.authenticated().await,
+    )
+    .await
+    .expect("messages consumed")
+}
+
+async fn odometer(fleet: &Fleet, vehicle_id: uuid::Uuid) -> Option<i32> {
+    fleet
+        .vehicle_statuses
+        .find_latest(fleet.organization.id, vehicle_id)
+        .await
+        .expect("latest status read")
+        .map(|status| status.odometer)
+}
+
+#[tokio::test]
+async fn readings_name_their_vehicle_by_vin_or_device() {
+    let fleet = Fleet::new().await;
+    let region = fixtures::region(&fleet, &fleet.organization, "North").await;
+    let astana = fixtures::depot(&fleet, &fleet.organization, &region, "Astana").await;
+    let almaty = fixtures::depot(&fleet, &fleet.organization, &region, "Almaty").await;
+    let by_vin = fixtures::vehicle(&fleet, &fleet.organization, 1, "123ABC02").await;
+    let by_device = fixtures::vehicle(&fleet, &fleet.organization, 2, "456DEF02").await;
+    let elsewhere = fixtures::vehicle(&fleet, &fleet.organization, 3, "789GHI02").await;
+    fixtures::device(&fleet, &fleet.organization, &by_device, "imei-2").await;
+    fixtures::device(&fleet, &fleet.organization, &elsewhere, "imei-3").await;
+    fixtures::movement(&fleet, &by_vin, &astana, &fleet.user, 1).await;
+    fixtures::movement(&fleet, &by_device, &astana, &fleet.user, 1).await;
+    fixtures::movement(&fleet, &elsewhere, &almaty, &fleet.user, 1).await;
+    let manager = fleet.manager("bob", &[&astana]).await;
+
+    let payload = format!(
+        r#"{{"readings": [
+            {{"vin": "{}", "recorded_at": "2025-10-01T08:00:00Z", "odometer": 1200}},
+            {{"device_id": "imei-2", "recorded_at": "2025-10-01T08:00:00Z", "odometer": 3400}},
+            {{"device_id": "imei-3", "recorded_at": "2025-10-01T08:00:00Z", "odometer": 5600}},
+            {{"vin": "{}", "recorded_at": "2025-10-01T08:00:00Z", "odometer": 7800}},
+            {{"device_id": "imei-9", "recorded_at": "2025-10-01T08:00:00Z", "odometer": 9000}}
+        ]}}"#,
+        by_vin.vin, elsewhere.vin
+    );
+    let ingested = ingest(&fleet, &payload, &manager).await;
+    assert_eq!(ingested.received, 5);
+    assert_eq!(ingested.stored, 2);
+    assert_eq!(odometer(&fleet, by_vin.id).await, Some(1200));
+    assert_eq!(odometer(&fleet, by_device.id).await, Some(3400));
+
+    // A vehicle out of the scope of the manager is unknown to them, by device or by VIN
+    assert_eq!(odometer(&fleet, elsewhere.id).await, None);
+    let reasons: Vec<&str> = ingested
+        .rejected
+        .iter()
+        .map(|rejected| rejected.reason.as_str())
+        .collect();
+    assert_eq!(
+        reasons,
+        vec![
+            "Unknown vehicle: device imei-3",
+            format!("Unknown vehicle: vin {}", elsewhere.vin).as_str(),
+            "Unknown vehicle: device imei-9",
+        ]
+    );
+
+    // The readings of the third vehicle by device and by VIN share a time, the first one is kept
+    let ingested = ingest(&fleet, &payload, &fleet.authenticated().await).await;
+    assert_eq!(ingested.stored, 1);
+    assert_eq!(ingested.duplicates, 3);
+    assert_eq!(odometer(&fleet, elsewhere.id).await, Some(5600));
+}
+
+#[tokio::test]
+async fn late_readings_are_inserted_into_the_history() {
+    let fleet = Fleet::new().await;
+    let vehicle = fixtures::vehicle(&fleet, &fleet.organization, 1, "123ABC02").await;
+    fixtures::device(&fleet, &fleet.organization, &vehicle, "imei-1").await;
+    let payload = r#"{"readings": [
+        {"device_id": "imei-1", "recorded_at": "2025-10-01T08:00:00Z", "odometer": 1000},
+        {"device_id": "imei-1", "recorded_at": "2025-10-01T10:00:00Z", "odometer": 1200}
+    ]}"#;
+    ingest(&fleet, payload, &fleet.authenticated().await).await;
+
+    let payload = r#"{"readings": [
+        {"device_id": "imei-1", "recorded_at": "2025-10-01T09:00:00Z", "odometer": 1100},
+        {"device_id": "imei-1", "recorded_at": "2025-10-01T09:05:00Z", "odometer": 1105},
+        {"device_id": "imei-1", "recorded_at": "2025-10-01T09:30:00Z", "odometer": 1300}
+    ]}"#;
+    let ingested = ingest(&fleet, payload, &fleet.authenticated().await).await;
+    assert_eq!((ingested.stored, ingested.late), (1, 1));
+    assert_eq!(ingested.throttled, 1);
+    assert_eq!(
+        ingested.rejected[0].reason,
+        "Odometer 1300 is higher than the later 1200"
+    );
+
+    // The latest status stays the last recorded one
+    assert_eq!(odometer(&fleet, vehicle.id).await, Some(1200));
+    let history = fleet
+        .vehicle_statuses
+        .find_by_vehicle(fleet.organization.id, vehicle.id, None, None)
+        .await
+        .expect("statuses read");
+    let odometers: Vec<i32> = history.iter().map(|status| status.odometer).collect();
+    assert_eq!(odometers, vec![1000, 1100, 1200]);
+}
+
+#[tokio::test]
+async fn due_statuses_are_recalculated_with_the_new_readings() {
+    let fleet = Fleet::new().await;
+    let vehicle = fixtures::vehicle(&fleet, &fleet.organization, 1, "123ABC02").await;
+    fixtures::device(&fleet, &fleet.organization, &vehicle, "imei-1").await;
+    let oil_change = fixtures::maintenance_type(&fleet, "Oil change", &fleet.user).await;
+    // Every 10 000 km, yellow at 75% and red at 90%
+    let rule =
+        fixtures::maintenance(&fleet, oil_change.id, &vehicle, &fleet.user, "Kilometers").await;
+    fixtures::status(&fleet, &vehicle, &fleet.user, 1, 5_000).await;
+
+    let payload = r#"{"readings": [
+        {"device_id": "imei-1", "recorded_at": "2025-10-01T08:00:00Z", "odometer": 6000},
+        {"device_id": "imei-1", "recorded_at": "2025-10-01T09:00:00Z", "odometer": 7000}
+    ]}"#;
+    let ingested = ingest(&fleet, payload, &fleet.authenticated().await).await;
+    assert_eq!(ingested.stored, 2);
+    assert!(ingested.due_status_changes.is_empty());
+
+    // Only the level of the new latest status is compared with the one before the readings
+    let payload = r#"{"readings": [
+        {"device_id": "imei-1", "recorded_at": "2025-10-01T10:00:00Z", "odometer": 8000},
+        {"device_id": "imei-1", "recorded_at": "2025-10-01T11:00:00Z", "odometer": 9500}
+    ]}"#;
+    let ingested = ingest(&fleet, payload, &fleet.authenticated().await).await;
+    assert_eq!(
+        ingested.due_status_changes,
+        vec![DueStatusChange {
+            vehicle_id: vehicle.id,
+            maintenance_id: rule.identity.id,
+            from: MaintenanceStatusLevel::Green,
+            to: MaintenanceStatusLevel::Red,
+        }]
+    );
+
+    let payload = r#"{"readings": [
+        {"device_id": "imei-1", "recorded_at": "2025-10-01T12:00:00Z", "odometer": 10100}
+    ]}"#;
+    let ingested = ingest(&fleet, payload, &fleet.authenticated().await).await;
+    assert_eq!(
+        ingested
+            .due_status_changes
+            .iter()
+            .map(|change| (change.from, change.to))
+            .collect::<Vec<_>>(),
+        vec![(MaintenanceStatusLevel::Red, MaintenanceStatusLevel::Overdue)]
+    );
+}
+
+#[tokio::test]
+async fn pending_messages_are_consumed_in_order() {
+    let fleet = Fleet::new().await;
+    let vehicle = fixtures::vehicle(&fleet, &fleet.organization, 1, "123ABC02").await;
+    fixtures::device(&fleet, &fleet.organization, &vehicle, "imei-1").await;
+    let topic = device_topic(fleet.organization.id, "imei-1");
+    let broker = MemoryTelematicsBroker::new();
+    let reading = |at: &str, odometer: i32| {
+        format!(
+            r#"{{"readings": [{{"device_id": "imei-1", "recorded_at": "{}", "odometer": {}}}]}}"#,
+            at, odometer
+        )
+    };
+    broker.publish(&topic, reading("2025-10-01T08:00:00Z", 1000));
+    broker.publish(&topic, "not json");
+    broker.publish(&topic, reading("2025-10-01T09:00:00Z", 1050));
+    // Redelivered by the device
+    broker.publish(&topic, reading("2025-10-01T09:00:00Z", 1050));
+    broker.publish(&topic, reading("2025-10-01T10:00:00Z", 1100));
+    broker.publish(
+        device_topic(uuid::Uuid::new_v4(), "imei-1"),
+        reading("2025-10-01T08:00:00Z", 5000),
+    );
+
+    let consumed = consume(&fleet, &broker, 4).await;
+    assert_eq!(consumed.messages, 4);
+    assert_eq!(consumed.invalid_messages.len(), 1);
+    assert_eq!(consumed.invalid_messages[0].topic, topic);
+    assert_eq!(consumed.ingestion.received, 3);
+    assert_eq!(consumed.ingestion.stored, 2);
+    assert_eq!(consumed.ingestion.duplicates, 1);
+    assert_eq!(odometer(&fleet, vehicle.id).await, Some(1050));
+
+    // The rest of the organization, the message of the other one is left on the broker
+    let consumed = consume(&fleet, &broker, 0).await;
+    assert_eq!(consumed.messages, 1);
+    assert_eq!(consumed.ingestion.stored, 1);
+    assert_eq!(odometer(&fleet, vehicle.id).await, Some(1100));
+    assert_eq!(broker.pending(), 1);
+    assert_eq!(consume(&fleet, &broker, 0).await.messages, 0);
+}
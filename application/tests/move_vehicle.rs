@@ -0,0 +1,65 @@
+mod common;
+
+use application::{
+    auth::AuthenticatedUser,
+    depot::use_cases::commands::move_vehicle::{
+        MoveVehicleCommand, MoveVehicleError, MoveVehicleResponse, MoveVehicleUseCase,
+    },
+};
+use common::Fleet;
+use conformance::fixtures;
+use domain::depot::entities::depot::Depot;
+
+async fn move_vehicle(
+    fleet: &Fleet,
+    vehicle_id: uuid::Uuid,
+    to: &Depot,
+    user: &AuthenticatedUser,
+) -> Result<MoveVehicleResponse, MoveVehicleError> {
+    MoveVehicleUseCase::new(&fleet.vehicles, &fleet.depots, &fleet.vehicle_movements)
+        .execute(
+            MoveVehicleCommand {
+                vehicle_id,
+                to_depot_id: to.id,
+                moved_at: Some(fixtures::at(2)),
+                notes: "handover".to_string(),
+            },
+            user,
+        )
+        .await
+}
+
+#[tokio::test]
+async fn a_manager_moves_the_vehicles_of_their_depots_only() {
+    let fleet = Fleet::new().await;
+    let region = fixtures::region(&fleet, &fleet.organization, "North").await;
+    let astana = fixtures::depot(&fleet, &fleet.organization, &region, "Astana").await;
+    let almaty = fixtures::depot(&fleet, &fleet.organization, &region, "Almaty").await;
+    let based = fixtures::vehicle(&fleet, &fleet.organization, 1, "123ABC02").await;
+    let elsewhere = fixtures::vehicle(&fleet, &fleet.organization, 2, "456DEF02").await;
+    let unassigned = fixtures::vehicle(&fleet, &fleet.organization, 3, "789GHI02").await;
+    fixtures::movement(&fleet, &based, &astana, &fleet.user, 1).await;
+    fixtures::movement(&fleet, &elsewhere, &almaty, &fleet.user, 1).await;
+    let manager = fleet.manager("bob", &[&astana]).await;
+
+    let moved = move_vehicle(&fleet, based.id, &almaty, &manager)
+        .await
+        .expect("vehicle moved");
+    assert_eq!(moved.from_depot_id, Some(astana.id));
+    assert_eq!(moved.to_depot_id, almaty.id);
+
+    // Out of the scope of the manager now, like the vehicles of other depots or without one
+    for vehicle_id in [based.id, elsewhere.id, unassigned.id, uuid::Uuid::new_v4()] {
+        let error = move_vehicle(&fleet, vehicle_id, &astana, &manager).await;
+        assert!(
+            matches!(error, Err(MoveVehicleError::VehicleNotFound(id)) if id == vehicle_id),
+            "{:?}",
+            error.err()
+        );
+    }
+
+    let first = move_vehicle(&fleet, unassigned.id, &astana, &fleet.authenticated().await)
+        .await
+        .expect("vehicle assigned");
+    assert_eq!(first.from_depot_id, None);
+}
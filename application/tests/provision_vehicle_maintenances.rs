@@ -0,0 +1,50 @@
+mod common;
+
+use application::{
+    auth::AuthenticatedUser,
+    maintenance::use_cases::commands::provision_vehicle_maintenances::{
+        ProvisionVehicleMaintenancesCommand, ProvisionVehicleMaintenancesError,
+        ProvisionVehicleMaintenancesResponse, ProvisionVehicleMaintenancesUseCase,
+    },
+};
+use common::{Fleet, Managed};
+
+async fn provision(
+    fleet: &Fleet,
+    vehicle_id: uuid::Uuid,
+    user: &AuthenticatedUser,
+) -> Result<ProvisionVehicleMaintenancesResponse, ProvisionVehicleMaintenancesError> {
+    ProvisionVehicleMaintenancesUseCase::new(
+        &fleet.vehicles,
+        &fleet.maintenance_types,
+        &fleet.maintenances,
+        &fleet.maintenance_interval_templates,
+    )
+    .execute(ProvisionVehicleMaintenancesCommand { vehicle_id }, user)
+    .await
+}
+
+#[tokio::test]
+async fn a_manager_provisions_the_vehicles_of_their_depots_only() {
+    let fleet = Fleet::new().await;
+    let Managed {
+        manager,
+        based,
+        elsewhere,
+    } = fleet.managed().await;
+
+    let provisioned = provision(&fleet, based.id, &manager)
+        .await
+        .expect("vehicle provisioned");
+    assert_eq!(provisioned.vehicle_id, based.id);
+
+    let error = provision(&fleet, elsewhere.id, &manager).await;
+    assert!(
+        matches!(
+            error,
+            Err(ProvisionVehicleMaintenancesError::VehicleNotFound(id)) if id == elsewhere.id
+        ),
+        "{:?}",
+        error.err()
+    );
+}
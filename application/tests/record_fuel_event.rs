@@ -0,0 +1,63 @@
+mod common;
+
+use application::{
+    auth::AuthenticatedUser,
+    fuel::use_cases::commands::record_fuel_event::{
+        RecordFuelEventCommand, RecordFuelEventError, RecordFuelEventResponse,
+        RecordFuelEventUseCase,
+    },
+};
+use common::{Fleet, Managed};
+use conformance::fixtures;
+use memory::repositories::{
+    fuel_event_repository::MemoryFuelEventRepository,
+    fuel_tank_repository::MemoryFuelTankRepository,
+};
+use rust_decimal::Decimal;
+
+async fn record(
+    fleet: &Fleet,
+    vehicle_id: uuid::Uuid,
+    user: &AuthenticatedUser,
+) -> Result<RecordFuelEventResponse, RecordFuelEventError> {
+    let events = MemoryFuelEventRepository::new(&fleet.store);
+    let tanks = MemoryFuelTankRepository::new(&fleet.store);
+    RecordFuelEventUseCase::new(&fleet.vehicles, &events, &tanks)
+        .execute(
+            RecordFuelEventCommand {
+                vehicle_id,
+                driver_id: None,
+                performed_at: fixtures::at(2),
+                odometer: 15_000,
+                quantity: Decimal::new(40, 0),
+                unit: "L".to_string(),
+                total_price: None,
+                currency: None,
+                station: None,
+                full: true,
+            },
+            user,
+        )
+        .await
+}
+
+#[tokio::test]
+async fn a_manager_records_the_fuel_of_the_vehicles_of_their_depots_only() {
+    let fleet = Fleet::new().await;
+    let Managed {
+        manager,
+        based,
+        elsewhere,
+    } = fleet.managed().await;
+
+    record(&fleet, based.id, &manager)
+        .await
+        .expect("fuel event recorded");
+
+    let error = record(&fleet, elsewhere.id, &manager).await;
+    assert!(
+        matches!(error, Err(RecordFuelEventError::VehicleNotFound(id)) if id == elsewhere.id),
+        "{:?}",
+        error.err()
+    );
+}
@@ -0,0 +1,76 @@
+mod common;
+
+use application::{
+    auth::AuthenticatedUser,
+    maintenance::use_cases::commands::record_maintenance::{
+        RecordMaintenanceCommand, RecordMaintenanceError, RecordMaintenanceResponse,
+        RecordMaintenanceUseCase,
+    },
+};
+use common::{Fleet, Managed};
+use conformance::fixtures;
+use domain::vehicle::{
+    entities::vehicle::VehicleIdentity,
+    repositories::vehicle_status_repository::VehicleStatusRepository,
+};
+use memory::unit_of_work::MemoryUnitOfWork;
+
+async fn record(
+    fleet: &Fleet,
+    vehicle_id: uuid::Uuid,
+    maintenance_type_id: i32,
+    user: &AuthenticatedUser,
+) -> Result<RecordMaintenanceResponse, RecordMaintenanceError> {
+    let unit_of_work = MemoryUnitOfWork::new(&fleet.store);
+    RecordMaintenanceUseCase::new(&fleet.vehicles, &fleet.maintenances, &unit_of_work)
+        .execute(
+            RecordMaintenanceCommand {
+                vehicle_id,
+                maintenance_type_id,
+                performed_at: fixtures::at(2),
+                odometer: 15_000,
+                engine_hour_meter: None,
+                fuel_level: None,
+                notes: String::new(),
+                details: "Replaced the oil filter".to_string(),
+                cost: None,
+            },
+            user,
+        )
+        .await
+}
+
+async fn statuses(fleet: &Fleet, vehicle: &VehicleIdentity) -> usize {
+    fleet
+        .vehicle_statuses
+        .find_by_vehicle(fleet.organization.id, vehicle.id, None, None)
+        .await
+        .expect("statuses read")
+        .len()
+}
+
+#[tokio::test]
+async fn a_manager_records_the_maintenance_of_the_vehicles_of_their_depots_only() {
+    let fleet = Fleet::new().await;
+    let Managed {
+        manager,
+        based,
+        elsewhere,
+    } = fleet.managed().await;
+    let oil_change = fixtures::maintenance_type(&fleet, "Oil change", &fleet.user).await;
+    for vehicle in [&based, &elsewhere] {
+        fixtures::maintenance(&fleet, oil_change.id, vehicle, &fleet.user, "Kilometers").await;
+    }
+
+    record(&fleet, based.id, oil_change.id, &manager)
+        .await
+        .expect("maintenance recorded");
+
+    let error = record(&fleet, elsewhere.id, oil_change.id, &manager).await;
+    assert!(
+        matches!(error, Err(RecordMaintenanceError::VehicleNotFound(id)) if id == elsewhere.id),
+        "{:?}",
+        error.err()
+    );
+    assert_eq!(statuses(&fleet, &elsewhere).await, 0);
+}
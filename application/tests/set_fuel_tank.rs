@@ -0,0 +1,51 @@
+mod common;
+
+use application::{
+    auth::AuthenticatedUser,
+    fuel::use_cases::commands::set_fuel_tank::{
+        SetFuelTankCommand, SetFuelTankError, SetFuelTankResponse, SetFuelTankUseCase,
+    },
+};
+use common::{Fleet, Managed};
+use memory::repositories::fuel_tank_repository::MemoryFuelTankRepository;
+use rust_decimal::Decimal;
+
+async fn set_tank(
+    fleet: &Fleet,
+    vehicle_id: uuid::Uuid,
+    user: &AuthenticatedUser,
+) -> Result<SetFuelTankResponse, SetFuelTankError> {
+    let tanks = MemoryFuelTankRepository::new(&fleet.store);
+    SetFuelTankUseCase::new(&fleet.vehicles, &tanks)
+        .execute(
+            SetFuelTankCommand {
+                vehicle_id,
+                unit: "L".to_string(),
+                capacity: Decimal::new(60, 0),
+            },
+            user,
+        )
+        .await
+}
+
+#[tokio::test]
+async fn a_manager_sets_the_tanks_of_the_vehicles_of_their_depots_only() {
+    let fleet = Fleet::new().await;
+    let Managed {
+        manager,
+        based,
+        elsewhere,
+    } = fleet.managed().await;
+
+    let tank = set_tank(&fleet, based.id, &manager)
+        .await
+        .expect("tank set");
+    assert_eq!(tank.vehicle_id, based.id);
+
+    let error = set_tank(&fleet, elsewhere.id, &manager).await;
+    assert!(
+        matches!(error, Err(SetFuelTankError::VehicleNotFound(id)) if id == elsewhere.id),
+        "{:?}",
+        error.err()
+    );
+}
@@ -0,0 +1,30 @@
+//! Represents a depot: where vehicles are based, in a region.
+use crate::depot::value_types::location_name::LocationName;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Depot {
+    /// The unique identifier of the depot.
+    pub id: uuid::Uuid,
+    /// The region of the depot.
+    pub region_id: uuid::Uuid,
+    /// The name of the depot, unique in the organization.
+    pub name: LocationName,
+    /// Created at timestamp.
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    /// Updated at timestamp.
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl Depot {
+    /// Creates a new depot in a region, with a new id.
+    pub fn new(region_id: uuid::Uuid, name: LocationName) -> Self {
+        let now = chrono::Utc::now();
+        Depot {
+            id: uuid::Uuid::new_v4(),
+            region_id,
+            name,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod depot;
+pub mod region;
+pub mod site;
+pub mod vehicle_movement;
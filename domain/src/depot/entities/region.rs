@@ -0,0 +1,27 @@
+//! Represents a region of an organization, grouping its depots.
+use crate::depot::value_types::location_name::LocationName;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    /// The unique identifier of the region.
+    pub id: uuid::Uuid,
+    /// The name of the region, unique in the organization.
+    pub name: LocationName,
+    /// Created at timestamp.
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    /// Updated at timestamp.
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl Region {
+    /// Creates a new region, with a new id.
+    pub fn new(name: LocationName) -> Self {
+        let now = chrono::Utc::now();
+        Region {
+            id: uuid::Uuid::new_v4(),
+            name,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
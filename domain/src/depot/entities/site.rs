@@ -0,0 +1,30 @@
+//! Represents a site of a depot (a yard, a workshop, a parking lot).
+use crate::depot::value_types::location_name::LocationName;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Site {
+    /// The unique identifier of the site.
+    pub id: uuid::Uuid,
+    /// The depot the site belongs to.
+    pub depot_id: uuid::Uuid,
+    /// The name of the site, unique in its depot.
+    pub name: LocationName,
+    /// Created at timestamp.
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    /// Updated at timestamp.
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl Site {
+    /// Creates a new site of a depot, with a new id.
+    pub fn new(depot_id: uuid::Uuid, name: LocationName) -> Self {
+        let now = chrono::Utc::now();
+        Site {
+            id: uuid::Uuid::new_v4(),
+            depot_id,
+            name,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
@@ -0,0 +1,146 @@
+//! Represents the move of a vehicle to a depot, which becomes its home depot.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * The first movement of a vehicle assigns its home depot (no origin), the next ones move it
+//!   from the depot of the previous movement.
+//! * A vehicle can't move to the depot it is based at, nor before its latest movement: the
+//!   history is append-only, in time order.
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VehicleMovement {
+    /// The unique identifier of the movement.
+    pub id: uuid::Uuid,
+    /// The vehicle moved.
+    pub vehicle_id: uuid::Uuid,
+    /// The depot the vehicle was based at, `None` for its first assignment.
+    pub from_depot_id: Option<uuid::Uuid>,
+    /// The new home depot of the vehicle.
+    pub to_depot_id: uuid::Uuid,
+    /// When the vehicle moved.
+    pub moved_at: DateTime<Utc>,
+    /// The user who recorded the move.
+    pub moved_by: uuid::Uuid,
+    /// Reason or comments about the move.
+    pub notes: String,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum VehicleMovementError {
+    #[error("Vehicle is already based at depot {0}")]
+    AlreadyBased(uuid::Uuid),
+    #[error("Vehicle can't move before its latest movement ({0})")]
+    BeforeLatest(DateTime<Utc>),
+}
+
+impl VehicleMovement {
+    /// Moves a vehicle to a depot, after its latest movement (if any).
+    pub fn new(
+        vehicle_id: uuid::Uuid,
+        latest: Option<&VehicleMovement>,
+        to_depot_id: uuid::Uuid,
+        moved_at: DateTime<Utc>,
+        moved_by: uuid::Uuid,
+        notes: String,
+    ) -> Result<Self, VehicleMovementError> {
+        if let Some(latest) = latest {
+            if latest.to_depot_id == to_depot_id {
+                return Err(VehicleMovementError::AlreadyBased(to_depot_id));
+            }
+            if moved_at < latest.moved_at {
+                return Err(VehicleMovementError::BeforeLatest(latest.moved_at));
+            }
+        }
+
+        Ok(VehicleMovement {
+            id: uuid::Uuid::new_v4(),
+            vehicle_id,
+            from_depot_id: latest.map(|latest| latest.to_depot_id),
+            to_depot_id,
+            moved_at,
+            moved_by,
+            notes: notes.trim().to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 9, day, 8, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_first_movement_has_no_origin() {
+        let depot = uuid::Uuid::new_v4();
+        let movement = VehicleMovement::new(
+            uuid::Uuid::new_v4(),
+            None,
+            depot,
+            at(1),
+            uuid::Uuid::new_v4(),
+            " Delivered ".to_string(),
+        )
+        .unwrap();
+        assert_eq!(movement.from_depot_id, None);
+        assert_eq!(movement.to_depot_id, depot);
+        assert_eq!(movement.notes, "Delivered");
+    }
+
+    #[test]
+    fn test_movement_starts_from_the_latest_depot() {
+        let (vehicle, user) = (uuid::Uuid::new_v4(), uuid::Uuid::new_v4());
+        let (first, second) = (uuid::Uuid::new_v4(), uuid::Uuid::new_v4());
+        let latest =
+            VehicleMovement::new(vehicle, None, first, at(1), user, String::new()).unwrap();
+
+        let movement =
+            VehicleMovement::new(vehicle, Some(&latest), second, at(2), user, String::new())
+                .unwrap();
+        assert_eq!(movement.from_depot_id, Some(first));
+        assert_eq!(movement.to_depot_id, second);
+    }
+
+    #[test]
+    fn test_movement_to_the_same_depot_is_rejected() {
+        let (vehicle, user, depot) = (
+            uuid::Uuid::new_v4(),
+            uuid::Uuid::new_v4(),
+            uuid::Uuid::new_v4(),
+        );
+        let latest =
+            VehicleMovement::new(vehicle, None, depot, at(1), user, String::new()).unwrap();
+
+        let movement =
+            VehicleMovement::new(vehicle, Some(&latest), depot, at(2), user, String::new());
+        assert_eq!(movement, Err(VehicleMovementError::AlreadyBased(depot)));
+    }
+
+    #[test]
+    fn test_movement_before_the_latest_is_rejected() {
+        let (vehicle, user) = (uuid::Uuid::new_v4(), uuid::Uuid::new_v4());
+        let latest = VehicleMovement::new(
+            vehicle,
+            None,
+            uuid::Uuid::new_v4(),
+            at(2),
+            user,
+            String::new(),
+        )
+        .unwrap();
+
+        let movement = VehicleMovement::new(
+            vehicle,
+            Some(&latest),
+            uuid::Uuid::new_v4(),
+            at(1),
+            user,
+            String::new(),
+        );
+        assert_eq!(movement, Err(VehicleMovementError::BeforeLatest(at(2))));
+    }
+}
@@ -0,0 +1,14 @@
+//! Depots: where the vehicles of an organization are based, in a hierarchy of regions, depots and
+//! sites.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * A region groups depots, a depot has sites (yards, workshops, parking lots). Region and depot
+//!   names are unique per organization, site names per depot.
+//! * A vehicle is based at a home depot: the depot of its latest movement. A move appends a
+//!   movement, the history is never rewritten.
+//! * A depot manager only sees the vehicles based at the depots they manage; the other users see
+//!   every vehicle of the organization.
+pub mod entities;
+pub mod repositories;
+pub mod value_types;
@@ -0,0 +1,85 @@
+//! Repository for managing the regions, depots and sites of an organization, and the managers of
+//! its depots.
+
+use crate::depot::entities::{depot::Depot, region::Region, site::Site};
+use std::future::Future;
+
+/// Errors that can occur when interacting with the depot repository
+#[derive(Debug, thiserror::Error)]
+pub enum DepotRepositoryError {
+    #[error("location already exists: {0}")]
+    AlreadyExists(String),
+    #[error("database error: {0}")]
+    Database(String),
+}
+
+/// Repository interface for the location hierarchy (region -> depot -> site)
+pub trait DepotRepository: Send + Sync {
+    /// Creates a region (`AlreadyExists` when the name is taken in the organization)
+    fn create_region(
+        &self,
+        organization_id: uuid::Uuid,
+        region: Region,
+    ) -> impl Future<Output = Result<Region, DepotRepositoryError>> + Send;
+
+    /// Creates a depot in a region (`AlreadyExists` when the name is taken in the organization)
+    fn create_depot(
+        &self,
+        organization_id: uuid::Uuid,
+        depot: Depot,
+    ) -> impl Future<Output = Result<Depot, DepotRepositoryError>> + Send;
+
+    /// Creates a site of a depot (`AlreadyExists` when the name is taken in the depot)
+    fn create_site(
+        &self,
+        organization_id: uuid::Uuid,
+        site: Site,
+    ) -> impl Future<Output = Result<Site, DepotRepositoryError>> + Send;
+
+    /// Retrieves the regions, by name
+    fn find_regions(
+        &self,
+        organization_id: uuid::Uuid,
+    ) -> impl Future<Output = Result<Vec<Region>, DepotRepositoryError>> + Send;
+
+    /// Retrieves the depots, by name
+    fn find_depots(
+        &self,
+        organization_id: uuid::Uuid,
+    ) -> impl Future<Output = Result<Vec<Depot>, DepotRepositoryError>> + Send;
+
+    /// Retrieves the sites of every depot, by name
+    fn find_sites(
+        &self,
+        organization_id: uuid::Uuid,
+    ) -> impl Future<Output = Result<Vec<Site>, DepotRepositoryError>> + Send;
+
+    /// Retrieves a region by its id
+    fn find_region(
+        &self,
+        organization_id: uuid::Uuid,
+        id: uuid::Uuid,
+    ) -> impl Future<Output = Result<Option<Region>, DepotRepositoryError>> + Send;
+
+    /// Retrieves a depot by its id
+    fn find_depot(
+        &self,
+        organization_id: uuid::Uuid,
+        id: uuid::Uuid,
+    ) -> impl Future<Output = Result<Option<Depot>, DepotRepositoryError>> + Send;
+
+    /// Makes a user a manager of a depot (assigning it twice is a no-op)
+    fn assign_manager(
+        &self,
+        organization_id: uuid::Uuid,
+        depot_id: uuid::Uuid,
+        user_id: uuid::Uuid,
+    ) -> impl Future<Output = Result<(), DepotRepositoryError>> + Send;
+
+    /// Retrieves the depots a user manages (none for a user who isn't a depot manager)
+    fn managed_depots(
+        &self,
+        organization_id: uuid::Uuid,
+        user_id: uuid::Uuid,
+    ) -> impl Future<Output = Result<Vec<uuid::Uuid>, DepotRepositoryError>> + Send;
+}
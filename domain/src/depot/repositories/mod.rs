@@ -0,0 +1,2 @@
+pub mod depot_repository;
+pub mod vehicle_movement_repository;
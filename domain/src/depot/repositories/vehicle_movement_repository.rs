@@ -0,0 +1,36 @@
+//! Repository for managing the movements of vehicles between depots.
+
+use crate::depot::entities::vehicle_movement::VehicleMovement;
+use std::future::Future;
+
+/// Errors that can occur when interacting with the vehicle movement repository
+#[derive(Debug, thiserror::Error)]
+pub enum VehicleMovementRepositoryError {
+    #[error("database error: {0}")]
+    Database(String),
+}
+
+/// Repository interface for vehicle movement operations
+pub trait VehicleMovementRepository: Send + Sync {
+    /// Records a movement, which becomes the latest one of its vehicle (at most one latest
+    /// movement per vehicle, like the statuses)
+    fn record(
+        &self,
+        organization_id: uuid::Uuid,
+        movement: VehicleMovement,
+    ) -> impl Future<Output = Result<VehicleMovement, VehicleMovementRepositoryError>> + Send;
+
+    /// Retrieves the latest movement of a vehicle, whose depot is its home depot
+    fn find_latest(
+        &self,
+        organization_id: uuid::Uuid,
+        vehicle_id: uuid::Uuid,
+    ) -> impl Future<Output = Result<Option<VehicleMovement>, VehicleMovementRepositoryError>> + Send;
+
+    /// Retrieves the movements of a vehicle, oldest first
+    fn find_by_vehicle(
+        &self,
+        organization_id: uuid::Uuid,
+        vehicle_id: uuid::Uuid,
+    ) -> impl Future<Output = Result<Vec<VehicleMovement>, VehicleMovementRepositoryError>> + Send;
+}
@@ -0,0 +1,71 @@
+//! Represents the name of a region, depot or site.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * The name is trimmed, required and at most `MAX_LENGTH` characters.
+//! * Names are compared as typed: "North" and "north" are two regions.
+use std::fmt;
+
+/// Longest name of a location, in characters.
+pub const MAX_LENGTH: usize = 100;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LocationName(String);
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum LocationNameError {
+    #[error("Location name cannot be empty")]
+    Empty,
+    #[error("Location name too long: maximum {MAX_LENGTH} characters, got {0}")]
+    TooLong(usize),
+}
+
+impl LocationName {
+    pub fn new(value: impl Into<String>) -> Result<Self, LocationNameError> {
+        let value = value.into().trim().to_string();
+        if value.is_empty() {
+            return Err(LocationNameError::Empty);
+        }
+        let length = value.chars().count();
+        if length > MAX_LENGTH {
+            return Err(LocationNameError::TooLong(length));
+        }
+        Ok(LocationName(value))
+    }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for LocationName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_name_is_trimmed() {
+        assert_eq!(
+            LocationName::new("  Almaty North ").unwrap().value(),
+            "Almaty North"
+        );
+    }
+
+    #[test]
+    fn test_blank_name_is_rejected() {
+        assert_eq!(LocationName::new("   "), Err(LocationNameError::Empty));
+    }
+
+    #[test]
+    fn test_long_name_is_rejected() {
+        assert_eq!(
+            LocationName::new("x".repeat(MAX_LENGTH + 1)),
+            Err(LocationNameError::TooLong(MAX_LENGTH + 1))
+        );
+    }
+}
@@ -0,0 +1 @@
+pub mod location_name;
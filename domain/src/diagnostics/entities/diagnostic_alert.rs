@@ -0,0 +1,41 @@
+//! Represents an alert or a maintenance task opened by a diagnostic rule for a DTC event.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * A rule opens at most one alert per event; the action (alert or maintenance task) is the one
+//!   of the rule.
+//! * The alert is resolved when the code of its event is cleared.
+use crate::diagnostics::entities::{diagnostic_rule::DiagnosticRule, dtc_event::DtcEvent};
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiagnosticAlert {
+    /// The unique identifier of the alert.
+    pub id: uuid::Uuid,
+    pub vehicle_id: uuid::Uuid,
+    /// The event of the code that appeared.
+    pub dtc_event_id: uuid::Uuid,
+    /// The rule that opened the alert.
+    pub rule_id: uuid::Uuid,
+    pub opened_at: DateTime<Utc>,
+    /// When the code was cleared, `None` while the alert is open.
+    pub resolved_at: Option<DateTime<Utc>>,
+}
+
+impl DiagnosticAlert {
+    /// Opens the alert of a rule for an event, when the code first appeared.
+    pub fn open(rule: &DiagnosticRule, event: &DtcEvent) -> Self {
+        DiagnosticAlert {
+            id: uuid::Uuid::new_v4(),
+            vehicle_id: event.vehicle_id,
+            dtc_event_id: event.id,
+            rule_id: rule.id,
+            opened_at: event.first_seen_at,
+            resolved_at: None,
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.resolved_at.is_none()
+    }
+}
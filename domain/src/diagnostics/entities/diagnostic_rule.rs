@@ -0,0 +1,185 @@
+//! Represents a rule reacting to the diagnostic trouble codes appearing on the vehicles.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * A rule matches the codes of at least its severity, optionally only the ones starting with its
+//!   code prefix (e.g., "P03" for the misfires, "C" for the chassis).
+//! * The action of a rule is an alert, or a maintenance task of a maintenance type (the work to do
+//!   on the vehicle).
+//! * Rule names are unique per organization.
+use crate::diagnostics::{
+    entities::dtc_event::DtcEvent,
+    value_types::{dtc_code, dtc_severity::DtcSeverity},
+};
+use chrono::{DateTime, Utc};
+
+/// Longest rule name, in characters.
+pub const MAX_NAME_LENGTH: usize = 100;
+
+/// What a rule does when a matching code appears.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DiagnosticAction {
+    /// Alert the fleet managers.
+    Alert,
+    /// Open a maintenance task of a maintenance type.
+    MaintenanceTask { maintenance_type_id: i32 },
+}
+
+impl DiagnosticAction {
+    pub fn as_str(&self) -> &str {
+        match self {
+            DiagnosticAction::Alert => "alert",
+            DiagnosticAction::MaintenanceTask { .. } => "maintenance_task",
+        }
+    }
+
+    /// The maintenance type of a maintenance task.
+    pub fn maintenance_type_id(&self) -> Option<i32> {
+        match self {
+            DiagnosticAction::Alert => None,
+            DiagnosticAction::MaintenanceTask {
+                maintenance_type_id,
+            } => Some(*maintenance_type_id),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiagnosticRule {
+    /// The unique identifier of the rule.
+    pub id: uuid::Uuid,
+    pub name: String,
+    /// The lowest severity of the codes matched.
+    pub min_severity: DtcSeverity,
+    /// Only the codes starting with this prefix are matched, every code when `None`.
+    pub code_prefix: Option<String>,
+    pub action: DiagnosticAction,
+    /// Created at timestamp.
+    pub created_at: DateTime<Utc>,
+}
+
+/// The values of a rule to create.
+#[derive(Debug, Clone)]
+pub struct NewDiagnosticRule {
+    pub name: String,
+    pub min_severity: DtcSeverity,
+    pub code_prefix: Option<String>,
+    pub action: DiagnosticAction,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum DiagnosticRuleError {
+    #[error("Rule name cannot be empty")]
+    EmptyName,
+    #[error("Rule name too long: maximum {MAX_NAME_LENGTH} characters, got {0}")]
+    NameTooLong(usize),
+    #[error("Invalid code prefix: {0}")]
+    InvalidCodePrefix(String),
+}
+
+impl DiagnosticRule {
+    pub fn new(rule: NewDiagnosticRule) -> Result<Self, DiagnosticRuleError> {
+        let name = rule.name.trim().to_string();
+        if name.is_empty() {
+            return Err(DiagnosticRuleError::EmptyName);
+        }
+        let length = name.chars().count();
+        if length > MAX_NAME_LENGTH {
+            return Err(DiagnosticRuleError::NameTooLong(length));
+        }
+
+        // A blank prefix matches every code, like no prefix
+        let code_prefix = rule
+            .code_prefix
+            .map(|prefix| prefix.trim().to_uppercase())
+            .filter(|prefix| !prefix.is_empty());
+        if let Some(prefix) = &code_prefix
+            && !is_code_prefix(prefix)
+        {
+            return Err(DiagnosticRuleError::InvalidCodePrefix(prefix.clone()));
+        }
+
+        Ok(DiagnosticRule {
+            id: uuid::Uuid::new_v4(),
+            name,
+            min_severity: rule.min_severity,
+            code_prefix,
+            action: rule.action,
+            created_at: Utc::now(),
+        })
+    }
+
+    /// Whether the rule reacts to the event.
+    pub fn matches(&self, event: &DtcEvent) -> bool {
+        event.severity >= self.min_severity
+            && self
+                .code_prefix
+                .as_ref()
+                .is_none_or(|prefix| event.code.value().starts_with(prefix.as_str()))
+    }
+}
+
+/// Whether a prefix can start a valid code: completed with zeros, it must be one.
+fn is_code_prefix(prefix: &str) -> bool {
+    prefix.len() <= dtc_code::LENGTH
+        && prefix.is_ascii()
+        && dtc_code::DtcCode::new(format!("{:0<width$}", prefix, width = dtc_code::LENGTH)).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostics::value_types::{dtc_code::DtcCode, freeze_frame::FreezeFrame};
+
+    fn new_rule(prefix: Option<&str>, min_severity: DtcSeverity) -> NewDiagnosticRule {
+        NewDiagnosticRule {
+            name: " Misfires ".to_string(),
+            min_severity,
+            code_prefix: prefix.map(str::to_string),
+            action: DiagnosticAction::Alert,
+        }
+    }
+
+    fn event(code: &str, severity: DtcSeverity) -> DtcEvent {
+        DtcEvent::new(
+            uuid::Uuid::new_v4(),
+            DtcCode::new(code).unwrap(),
+            severity,
+            FreezeFrame::default(),
+            Utc::now(),
+        )
+    }
+
+    #[test]
+    fn test_rule_matches_severity_and_prefix() {
+        let rule = DiagnosticRule::new(new_rule(Some("p03"), DtcSeverity::Warning)).unwrap();
+        assert_eq!(rule.name, "Misfires");
+        assert_eq!(rule.code_prefix.as_deref(), Some("P03"));
+        assert!(rule.matches(&event("P0301", DtcSeverity::Critical)));
+        assert!(!rule.matches(&event("P0301", DtcSeverity::Info)));
+        assert!(!rule.matches(&event("P0420", DtcSeverity::Critical)));
+
+        let any = DiagnosticRule::new(new_rule(Some(" "), DtcSeverity::Critical)).unwrap();
+        assert_eq!(any.code_prefix, None);
+        assert!(any.matches(&event("U0100", DtcSeverity::Critical)));
+    }
+
+    #[test]
+    fn test_invalid_rules_are_rejected() {
+        assert_eq!(
+            DiagnosticRule::new(new_rule(Some("X0"), DtcSeverity::Info)),
+            Err(DiagnosticRuleError::InvalidCodePrefix("X0".to_string()))
+        );
+        assert_eq!(
+            DiagnosticRule::new(new_rule(Some("P03011"), DtcSeverity::Info)),
+            Err(DiagnosticRuleError::InvalidCodePrefix("P03011".to_string()))
+        );
+        assert_eq!(
+            DiagnosticRule::new(NewDiagnosticRule {
+                name: " ".to_string(),
+                ..new_rule(None, DtcSeverity::Info)
+            }),
+            Err(DiagnosticRuleError::EmptyName)
+        );
+    }
+}
@@ -0,0 +1,155 @@
+//! Represents a diagnostic trouble code of a vehicle, from its first report until it is cleared.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * An event is active until it is cleared; reports of the same code while it is active update
+//!   it (earliest and latest times, number of reports, highest severity) instead of opening
+//!   another event.
+//! * The freeze frame is the one of the first report.
+//! * A cleared event is never updated again.
+use crate::diagnostics::value_types::{
+    dtc_code::DtcCode, dtc_severity::DtcSeverity, freeze_frame::FreezeFrame,
+};
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DtcEvent {
+    /// The unique identifier of the event.
+    pub id: uuid::Uuid,
+    /// The vehicle reporting the code.
+    pub vehicle_id: uuid::Uuid,
+    pub code: DtcCode,
+    /// The highest severity reported.
+    pub severity: DtcSeverity,
+    /// The engine parameters when the code was set.
+    pub freeze_frame: FreezeFrame,
+    pub first_seen_at: DateTime<Utc>,
+    pub last_seen_at: DateTime<Utc>,
+    /// Number of reports of the code while the event is active.
+    pub occurrences: i32,
+    /// When the code was cleared, `None` while it is active.
+    pub cleared_at: Option<DateTime<Utc>>,
+    /// The user who cleared the code.
+    pub cleared_by: Option<uuid::Uuid>,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum DtcEventError {
+    #[error("DTC {0} is already cleared")]
+    AlreadyCleared(DtcCode),
+    #[error("DTC can't be cleared before it was first seen ({0})")]
+    ClearedBeforeFirstSeen(DateTime<Utc>),
+}
+
+impl DtcEvent {
+    /// Opens an event for the first report of a code.
+    pub fn new(
+        vehicle_id: uuid::Uuid,
+        code: DtcCode,
+        severity: DtcSeverity,
+        freeze_frame: FreezeFrame,
+        seen_at: DateTime<Utc>,
+    ) -> Self {
+        DtcEvent {
+            id: uuid::Uuid::new_v4(),
+            vehicle_id,
+            code,
+            severity,
+            freeze_frame,
+            first_seen_at: seen_at,
+            last_seen_at: seen_at,
+            occurrences: 1,
+            cleared_at: None,
+            cleared_by: None,
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.cleared_at.is_none()
+    }
+
+    /// Records another report of the code; reports may arrive out of order.
+    pub fn seen_again(
+        &mut self,
+        seen_at: DateTime<Utc>,
+        severity: DtcSeverity,
+    ) -> Result<(), DtcEventError> {
+        if !self.is_active() {
+            return Err(DtcEventError::AlreadyCleared(self.code.clone()));
+        }
+        self.first_seen_at = self.first_seen_at.min(seen_at);
+        self.last_seen_at = self.last_seen_at.max(seen_at);
+        self.occurrences = self.occurrences.saturating_add(1);
+        self.severity = self.severity.max(severity);
+        Ok(())
+    }
+
+    /// Clears the code (repaired, or reset with a scan tool).
+    pub fn clear(
+        &mut self,
+        cleared_at: DateTime<Utc>,
+        cleared_by: uuid::Uuid,
+    ) -> Result<(), DtcEventError> {
+        if !self.is_active() {
+            return Err(DtcEventError::AlreadyCleared(self.code.clone()));
+        }
+        if cleared_at < self.first_seen_at {
+            return Err(DtcEventError::ClearedBeforeFirstSeen(self.first_seen_at));
+        }
+        self.cleared_at = Some(cleared_at);
+        self.cleared_by = Some(cleared_by);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 10, 1, hour, 0, 0).unwrap()
+    }
+
+    fn event(hour: u32) -> DtcEvent {
+        DtcEvent::new(
+            uuid::Uuid::new_v4(),
+            DtcCode::new("P0301").unwrap(),
+            DtcSeverity::Warning,
+            FreezeFrame::default(),
+            at(hour),
+        )
+    }
+
+    #[test]
+    fn test_reports_update_the_active_event() {
+        let mut event = event(10);
+        event.seen_again(at(12), DtcSeverity::Critical).unwrap();
+        event.seen_again(at(8), DtcSeverity::Info).unwrap();
+        assert_eq!(event.first_seen_at, at(8));
+        assert_eq!(event.last_seen_at, at(12));
+        assert_eq!(event.occurrences, 3);
+        assert_eq!(event.severity, DtcSeverity::Critical);
+    }
+
+    #[test]
+    fn test_cleared_event_is_closed() {
+        let mut event = event(10);
+        let user = uuid::Uuid::new_v4();
+        assert_eq!(
+            event.clear(at(9), user),
+            Err(DtcEventError::ClearedBeforeFirstSeen(at(10)))
+        );
+        event.clear(at(11), user).unwrap();
+        assert!(!event.is_active());
+        assert_eq!(event.cleared_by, Some(user));
+        assert!(matches!(
+            event.seen_again(at(12), DtcSeverity::Info),
+            Err(DtcEventError::AlreadyCleared(_))
+        ));
+        assert!(matches!(
+            event.clear(at(12), user),
+            Err(DtcEventError::AlreadyCleared(_))
+        ));
+    }
+}
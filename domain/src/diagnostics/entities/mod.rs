@@ -0,0 +1,3 @@
+pub mod diagnostic_alert;
+pub mod diagnostic_rule;
+pub mod dtc_event;
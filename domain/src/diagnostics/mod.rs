@@ -0,0 +1,16 @@
+//! Diagnostics: the OBD-II diagnostic trouble codes (DTCs) reported by the vehicles.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * A code reported by a vehicle opens a DTC event, which stays active until the code is cleared:
+//!   reporting an active code again only updates when it was last seen. A vehicle has at most one
+//!   active event per code; a cleared code reported again opens a new event.
+//! * The freeze frame (the engine parameters when the code was set) is kept from the first report.
+//! * Codes are described by an embedded, offline catalog of the generic codes (see
+//!   `services::dtc_catalog`); manufacturer codes are described by their system only.
+//! * Diagnostic rules open an alert or a maintenance task when a code of at least their severity
+//!   appears (a new event, never a repeated report); clearing the code resolves them.
+pub mod entities;
+pub mod repositories;
+pub mod services;
+pub mod value_types;
@@ -0,0 +1,99 @@
+//! Repository for managing the DTC events of the vehicles, the diagnostic rules of an
+//! organization and the alerts they open.
+
+use crate::diagnostics::{
+    entities::{
+        diagnostic_alert::DiagnosticAlert, diagnostic_rule::DiagnosticRule, dtc_event::DtcEvent,
+    },
+    value_types::dtc_code::DtcCode,
+};
+use std::future::Future;
+
+/// Errors that can occur when interacting with the diagnostic repository
+#[derive(Debug, thiserror::Error)]
+pub enum DiagnosticRepositoryError {
+    #[error("already exists: {0}")]
+    AlreadyExists(String),
+    #[error("database error: {0}")]
+    Database(String),
+}
+
+/// Repository interface for diagnostics operations
+pub trait DiagnosticRepository: Send + Sync {
+    /// Opens a DTC event (`AlreadyExists` with the code when the vehicle has an active event of
+    /// the same code)
+    fn open_event(
+        &self,
+        organization_id: uuid::Uuid,
+        event: DtcEvent,
+    ) -> impl Future<Output = Result<DtcEvent, DiagnosticRepositoryError>> + Send;
+
+    /// Saves the times, occurrences, severity and clearing of an event (`false` when there is no
+    /// such event)
+    fn update_event(
+        &self,
+        organization_id: uuid::Uuid,
+        event: DtcEvent,
+    ) -> impl Future<Output = Result<bool, DiagnosticRepositoryError>> + Send;
+
+    /// Retrieves an event by its id
+    fn find_event(
+        &self,
+        organization_id: uuid::Uuid,
+        id: uuid::Uuid,
+    ) -> impl Future<Output = Result<Option<DtcEvent>, DiagnosticRepositoryError>> + Send;
+
+    /// Retrieves the active event of a code of a vehicle
+    fn find_active_event(
+        &self,
+        organization_id: uuid::Uuid,
+        vehicle_id: uuid::Uuid,
+        code: &DtcCode,
+    ) -> impl Future<Output = Result<Option<DtcEvent>, DiagnosticRepositoryError>> + Send;
+
+    /// Retrieves the events of a vehicle, the cleared ones too when `include_cleared`, ordered by
+    /// `first_seen_at`
+    fn find_events_by_vehicle(
+        &self,
+        organization_id: uuid::Uuid,
+        vehicle_id: uuid::Uuid,
+        include_cleared: bool,
+    ) -> impl Future<Output = Result<Vec<DtcEvent>, DiagnosticRepositoryError>> + Send;
+
+    /// Creates a rule (`AlreadyExists` when the name is taken in the organization)
+    fn create_rule(
+        &self,
+        organization_id: uuid::Uuid,
+        rule: DiagnosticRule,
+    ) -> impl Future<Output = Result<DiagnosticRule, DiagnosticRepositoryError>> + Send;
+
+    /// Retrieves the rules, by name
+    fn find_rules(
+        &self,
+        organization_id: uuid::Uuid,
+    ) -> impl Future<Output = Result<Vec<DiagnosticRule>, DiagnosticRepositoryError>> + Send;
+
+    /// Opens an alert (`AlreadyExists` when the rule already opened one for the event)
+    fn open_alert(
+        &self,
+        organization_id: uuid::Uuid,
+        alert: DiagnosticAlert,
+    ) -> impl Future<Output = Result<DiagnosticAlert, DiagnosticRepositoryError>> + Send;
+
+    /// Retrieves the alerts of a vehicle, only the open ones when `open_only`, ordered by
+    /// `opened_at`
+    fn find_alerts_by_vehicle(
+        &self,
+        organization_id: uuid::Uuid,
+        vehicle_id: uuid::Uuid,
+        open_only: bool,
+    ) -> impl Future<Output = Result<Vec<DiagnosticAlert>, DiagnosticRepositoryError>> + Send;
+
+    /// Resolves the open alerts of an event, returns how many were resolved
+    fn resolve_alerts(
+        &self,
+        organization_id: uuid::Uuid,
+        dtc_event_id: uuid::Uuid,
+        resolved_at: chrono::DateTime<chrono::Utc>,
+    ) -> impl Future<Output = Result<u64, DiagnosticRepositoryError>> + Send;
+}
@@ -0,0 +1 @@
+pub mod diagnostic_repository;
@@ -0,0 +1,85 @@
+//! Applies the diagnostic rules to the codes appearing on a vehicle.
+use crate::diagnostics::entities::{
+    diagnostic_alert::DiagnosticAlert, diagnostic_rule::DiagnosticRule, dtc_event::DtcEvent,
+};
+
+/// Opens the alerts of the rules matching a new event, in the order of the rules. A repeated
+/// report of an active code opens none: the rules fired when the event was opened.
+pub fn alerts_for(
+    event: &DtcEvent,
+    is_new: bool,
+    rules: &[DiagnosticRule],
+) -> Vec<DiagnosticAlert> {
+    if !is_new || !event.is_active() {
+        return Vec::new();
+    }
+    rules
+        .iter()
+        .filter(|rule| rule.matches(event))
+        .map(|rule| DiagnosticAlert::open(rule, event))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostics::{
+        entities::diagnostic_rule::{DiagnosticAction, NewDiagnosticRule},
+        value_types::{dtc_code::DtcCode, dtc_severity::DtcSeverity, freeze_frame::FreezeFrame},
+    };
+
+    fn rule(name: &str, min_severity: DtcSeverity, action: DiagnosticAction) -> DiagnosticRule {
+        DiagnosticRule::new(NewDiagnosticRule {
+            name: name.to_string(),
+            min_severity,
+            code_prefix: None,
+            action,
+        })
+        .unwrap()
+    }
+
+    fn event(severity: DtcSeverity) -> DtcEvent {
+        DtcEvent::new(
+            uuid::Uuid::new_v4(),
+            DtcCode::new("P0524").unwrap(),
+            severity,
+            FreezeFrame::default(),
+            chrono::Utc::now(),
+        )
+    }
+
+    #[test]
+    fn test_matching_rules_open_alerts() {
+        let rules = [
+            rule(
+                "Severe codes",
+                DtcSeverity::Critical,
+                DiagnosticAction::Alert,
+            ),
+            rule(
+                "Inspect",
+                DtcSeverity::Warning,
+                DiagnosticAction::MaintenanceTask {
+                    maintenance_type_id: 7,
+                },
+            ),
+        ];
+
+        let event = event(DtcSeverity::Critical);
+        let alerts = alerts_for(&event, true, &rules);
+        assert_eq!(alerts.len(), 2);
+        assert!(alerts.iter().all(|alert| alert.dtc_event_id == event.id));
+        assert_eq!(alerts[0].rule_id, rules[0].id);
+        assert_eq!(alerts[0].opened_at, event.first_seen_at);
+
+        let warning = alerts_for(&self::event(DtcSeverity::Warning), true, &rules);
+        assert_eq!(warning.len(), 1);
+        assert_eq!(warning[0].rule_id, rules[1].id);
+    }
+
+    #[test]
+    fn test_repeated_reports_open_no_alert() {
+        let rules = [rule("Any", DtcSeverity::Info, DiagnosticAction::Alert)];
+        assert!(alerts_for(&event(DtcSeverity::Critical), false, &rules).is_empty());
+    }
+}
@@ -0,0 +1,489 @@
+//! Embedded, offline catalog of the generic OBD-II diagnostic trouble codes (SAE J2012).
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # Personal notes:
+//! * Only the generic codes commonly seen in the fleet are listed, with the severity they usually
+//!   deserve. The other codes are described by their group (e.g., "P03": ignition system or
+//!   misfire) or, for manufacturer codes, by their system only; their default severity is
+//!   `Warning`.
+//! * The table is sorted by code, for a binary search (checked by a test).
+use crate::diagnostics::value_types::{
+    dtc_code::{DtcCode, DtcSystem},
+    dtc_severity::DtcSeverity::{self, Critical, Info, Warning},
+};
+
+/// A generic code of the catalog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DtcCatalogEntry {
+    pub code: &'static str,
+    pub description: &'static str,
+    pub severity: DtcSeverity,
+}
+
+/// How well a code is known to the catalog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DtcMatch {
+    /// The code is in the catalog.
+    Code,
+    /// A generic code described by its group.
+    Group,
+    /// A manufacturer code, described by its system.
+    System,
+}
+
+/// The description of a code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DtcDescription {
+    pub description: &'static str,
+    /// The severity the code usually deserves.
+    pub severity: DtcSeverity,
+    pub matched: DtcMatch,
+}
+
+const fn entry(
+    code: &'static str,
+    description: &'static str,
+    severity: DtcSeverity,
+) -> DtcCatalogEntry {
+    DtcCatalogEntry {
+        code,
+        description,
+        severity,
+    }
+}
+
+pub const GENERIC_DTC_CATALOG: &[DtcCatalogEntry] = &[
+    entry(
+        "B0001",
+        "Driver frontal stage 1 deployment control",
+        Critical,
+    ),
+    entry(
+        "B0002",
+        "Driver frontal stage 2 deployment control",
+        Critical,
+    ),
+    entry(
+        "B0010",
+        "Passenger frontal stage 1 deployment control",
+        Critical,
+    ),
+    entry("B0020", "Left side airbag deployment control", Critical),
+    entry("B0028", "Right side airbag deployment control", Critical),
+    entry("B0050", "Driver seatbelt sensor", Warning),
+    entry("B0081", "Occupant classification system", Warning),
+    entry("B0100", "Electronic frontal sensor 1", Critical),
+    entry("C0035", "Left front wheel speed sensor circuit", Warning),
+    entry("C0040", "Right front wheel speed sensor circuit", Warning),
+    entry("C0045", "Left rear wheel speed sensor circuit", Warning),
+    entry("C0050", "Right rear wheel speed sensor circuit", Warning),
+    entry("C0110", "Pump motor circuit", Critical),
+    entry("C0121", "Valve relay circuit", Critical),
+    entry("C0131", "ABS/TCS system pressure circuit", Critical),
+    entry("C0161", "ABS/TCS brake switch circuit", Warning),
+    entry("C0196", "Yaw rate sensor circuit", Warning),
+    entry("C0265", "EBCM motor relay circuit", Critical),
+    entry("C0460", "Steering position sensor", Warning),
+    entry("C0550", "Electronic control unit performance", Critical),
+    entry(
+        "P0010",
+        "Intake camshaft position actuator circuit (bank 1)",
+        Warning,
+    ),
+    entry(
+        "P0011",
+        "Intake camshaft timing over-advanced (bank 1)",
+        Warning,
+    ),
+    entry(
+        "P0016",
+        "Crankshaft/camshaft position correlation (bank 1 sensor A)",
+        Warning,
+    ),
+    entry(
+        "P0030",
+        "HO2S heater control circuit (bank 1 sensor 1)",
+        Info,
+    ),
+    entry("P0087", "Fuel rail/system pressure too low", Critical),
+    entry("P0088", "Fuel rail/system pressure too high", Critical),
+    entry("P0100", "Mass air flow circuit", Warning),
+    entry("P0101", "Mass air flow circuit range/performance", Warning),
+    entry("P0102", "Mass air flow circuit low", Warning),
+    entry("P0103", "Mass air flow circuit high", Warning),
+    entry(
+        "P0106",
+        "Manifold absolute pressure circuit range/performance",
+        Warning,
+    ),
+    entry("P0110", "Intake air temperature circuit", Info),
+    entry("P0113", "Intake air temperature circuit high", Info),
+    entry("P0115", "Engine coolant temperature circuit", Warning),
+    entry("P0117", "Engine coolant temperature circuit low", Warning),
+    entry("P0118", "Engine coolant temperature circuit high", Warning),
+    entry("P0120", "Throttle position sensor circuit", Warning),
+    entry(
+        "P0121",
+        "Throttle position sensor circuit range/performance",
+        Warning,
+    ),
+    entry(
+        "P0125",
+        "Insufficient coolant temperature for closed loop fuel control",
+        Info,
+    ),
+    entry(
+        "P0128",
+        "Coolant thermostat below regulating temperature",
+        Info,
+    ),
+    entry("P0130", "O2 sensor circuit (bank 1 sensor 1)", Info),
+    entry(
+        "P0133",
+        "O2 sensor circuit slow response (bank 1 sensor 1)",
+        Info,
+    ),
+    entry("P0135", "O2 sensor heater circuit (bank 1 sensor 1)", Info),
+    entry("P0141", "O2 sensor heater circuit (bank 1 sensor 2)", Info),
+    entry("P0171", "System too lean (bank 1)", Warning),
+    entry("P0172", "System too rich (bank 1)", Warning),
+    entry("P0174", "System too lean (bank 2)", Warning),
+    entry("P0175", "System too rich (bank 2)", Warning),
+    entry(
+        "P0191",
+        "Fuel rail pressure sensor circuit range/performance",
+        Warning,
+    ),
+    entry("P0200", "Injector circuit", Warning),
+    entry("P0201", "Injector circuit, cylinder 1", Warning),
+    entry("P0202", "Injector circuit, cylinder 2", Warning),
+    entry("P0203", "Injector circuit, cylinder 3", Warning),
+    entry("P0204", "Injector circuit, cylinder 4", Warning),
+    entry(
+        "P0217",
+        "Engine coolant over temperature condition",
+        Critical,
+    ),
+    entry(
+        "P0218",
+        "Transmission fluid over temperature condition",
+        Critical,
+    ),
+    entry("P0219", "Engine overspeed condition", Critical),
+    entry("P0230", "Fuel pump primary circuit", Critical),
+    entry(
+        "P0234",
+        "Turbocharger/supercharger overboost condition",
+        Critical,
+    ),
+    entry("P0299", "Turbocharger/supercharger underboost", Warning),
+    entry(
+        "P0300",
+        "Random/multiple cylinder misfire detected",
+        Critical,
+    ),
+    entry("P0301", "Cylinder 1 misfire detected", Critical),
+    entry("P0302", "Cylinder 2 misfire detected", Critical),
+    entry("P0303", "Cylinder 3 misfire detected", Critical),
+    entry("P0304", "Cylinder 4 misfire detected", Critical),
+    entry("P0305", "Cylinder 5 misfire detected", Critical),
+    entry("P0306", "Cylinder 6 misfire detected", Critical),
+    entry("P0307", "Cylinder 7 misfire detected", Critical),
+    entry("P0308", "Cylinder 8 misfire detected", Critical),
+    entry("P0325", "Knock sensor 1 circuit (bank 1)", Warning),
+    entry("P0335", "Crankshaft position sensor A circuit", Critical),
+    entry(
+        "P0340",
+        "Camshaft position sensor A circuit (bank 1)",
+        Warning,
+    ),
+    entry(
+        "P0351",
+        "Ignition coil A primary/secondary circuit",
+        Warning,
+    ),
+    entry("P0400", "Exhaust gas recirculation flow", Warning),
+    entry(
+        "P0401",
+        "Exhaust gas recirculation flow insufficient",
+        Warning,
+    ),
+    entry("P0402", "Exhaust gas recirculation flow excessive", Warning),
+    entry(
+        "P0420",
+        "Catalyst system efficiency below threshold (bank 1)",
+        Warning,
+    ),
+    entry(
+        "P0430",
+        "Catalyst system efficiency below threshold (bank 2)",
+        Warning,
+    ),
+    entry("P0440", "Evaporative emission system", Info),
+    entry(
+        "P0441",
+        "Evaporative emission system incorrect purge flow",
+        Info,
+    ),
+    entry(
+        "P0442",
+        "Evaporative emission system leak detected (small leak)",
+        Info,
+    ),
+    entry(
+        "P0446",
+        "Evaporative emission system vent control circuit",
+        Info,
+    ),
+    entry(
+        "P0455",
+        "Evaporative emission system leak detected (large leak)",
+        Info,
+    ),
+    entry(
+        "P0456",
+        "Evaporative emission system leak detected (very small leak)",
+        Info,
+    ),
+    entry("P0480", "Fan 1 control circuit", Warning),
+    entry("P0500", "Vehicle speed sensor A", Warning),
+    entry("P0505", "Idle air control system", Warning),
+    entry(
+        "P0506",
+        "Idle air control system RPM lower than expected",
+        Info,
+    ),
+    entry(
+        "P0507",
+        "Idle air control system RPM higher than expected",
+        Info,
+    ),
+    entry(
+        "P0520",
+        "Engine oil pressure sensor/switch circuit",
+        Warning,
+    ),
+    entry(
+        "P0521",
+        "Engine oil pressure sensor/switch range/performance",
+        Warning,
+    ),
+    entry("P0524", "Engine oil pressure too low", Critical),
+    entry("P0562", "System voltage low", Warning),
+    entry("P0563", "System voltage high", Warning),
+    entry("P0571", "Brake switch A circuit", Warning),
+    entry("P0600", "Serial communication link", Warning),
+    entry(
+        "P0601",
+        "Internal control module memory check sum error",
+        Critical,
+    ),
+    entry(
+        "P0603",
+        "Internal control module keep alive memory (KAM) error",
+        Warning,
+    ),
+    entry(
+        "P0605",
+        "Internal control module read only memory (ROM) error",
+        Critical,
+    ),
+    entry("P0606", "Control module processor", Critical),
+    entry(
+        "P0700",
+        "Transmission control system (MIL request)",
+        Warning,
+    ),
+    entry(
+        "P0705",
+        "Transmission range sensor circuit (PRNDL input)",
+        Warning,
+    ),
+    entry("P0715", "Input/turbine speed sensor A circuit", Warning),
+    entry(
+        "P0717",
+        "Input/turbine speed sensor A circuit no signal",
+        Warning,
+    ),
+    entry("P0720", "Output speed sensor circuit", Warning),
+    entry("P0730", "Incorrect gear ratio", Critical),
+    entry("P0740", "Torque converter clutch solenoid circuit", Warning),
+    entry(
+        "P0741",
+        "Torque converter clutch solenoid circuit performance/stuck off",
+        Warning,
+    ),
+    entry("P0750", "Shift solenoid A", Warning),
+    entry("P0755", "Shift solenoid B", Warning),
+    entry("P0A80", "Replace hybrid battery pack", Critical),
+    entry(
+        "P2002",
+        "Diesel particulate filter efficiency below threshold (bank 1)",
+        Warning,
+    ),
+    entry(
+        "P2096",
+        "Post catalyst fuel trim system too lean (bank 1)",
+        Warning,
+    ),
+    entry(
+        "P2097",
+        "Post catalyst fuel trim system too rich (bank 1)",
+        Warning,
+    ),
+    entry(
+        "P2135",
+        "Throttle/pedal position sensor/switch A/B voltage correlation",
+        Critical,
+    ),
+    entry(
+        "P242F",
+        "Diesel particulate filter restriction - ash accumulation",
+        Warning,
+    ),
+    entry(
+        "P2463",
+        "Diesel particulate filter restriction - soot accumulation",
+        Warning,
+    ),
+    entry("U0001", "High speed CAN communication bus", Critical),
+    entry("U0073", "Control module communication bus A off", Critical),
+    entry("U0100", "Lost communication with ECM/PCM A", Critical),
+    entry("U0101", "Lost communication with TCM", Critical),
+    entry(
+        "U0121",
+        "Lost communication with anti-lock brake system (ABS) control module",
+        Critical,
+    ),
+    entry(
+        "U0140",
+        "Lost communication with body control module",
+        Warning,
+    ),
+    entry(
+        "U0151",
+        "Lost communication with restraints control module",
+        Critical,
+    ),
+    entry(
+        "U0155",
+        "Lost communication with instrument panel cluster (IPC) control module",
+        Warning,
+    ),
+];
+
+/// Descriptions of the groups of generic codes, by prefix (the longest prefix wins).
+const GENERIC_GROUPS: &[(&str, &str)] = &[
+    ("B0", "Body, generic code"),
+    ("C0", "Chassis, generic code"),
+    (
+        "P00",
+        "Fuel and air metering and auxiliary emission controls",
+    ),
+    ("P01", "Fuel and air metering"),
+    ("P02", "Fuel and air metering (injector circuit)"),
+    ("P03", "Ignition system or misfire"),
+    ("P04", "Auxiliary emission controls"),
+    ("P05", "Vehicle speed controls and idle control system"),
+    ("P06", "Computer output circuit"),
+    ("P07", "Transmission"),
+    ("P08", "Transmission"),
+    ("P09", "Transmission"),
+    ("P0A", "Hybrid propulsion"),
+    ("P0B", "Hybrid propulsion"),
+    ("P0C", "Hybrid propulsion"),
+    ("P2", "Powertrain, generic code"),
+    ("P3", "Powertrain, generic code"),
+    ("U0", "Network communication, generic code"),
+];
+
+/// Describes a code, from the catalog when it is listed.
+pub fn describe(code: &DtcCode) -> DtcDescription {
+    if let Ok(index) = GENERIC_DTC_CATALOG.binary_search_by(|entry| entry.code.cmp(code.value())) {
+        let entry = &GENERIC_DTC_CATALOG[index];
+        return DtcDescription {
+            description: entry.description,
+            severity: entry.severity,
+            matched: DtcMatch::Code,
+        };
+    }
+
+    if code.is_generic()
+        && let Some((_, description)) = GENERIC_GROUPS
+            .iter()
+            .filter(|(prefix, _)| code.value().starts_with(prefix))
+            .max_by_key(|(prefix, _)| prefix.len())
+    {
+        return DtcDescription {
+            description,
+            severity: DtcSeverity::Warning,
+            matched: DtcMatch::Group,
+        };
+    }
+
+    DtcDescription {
+        description: match code.system() {
+            DtcSystem::Powertrain => "Powertrain, manufacturer specific code",
+            DtcSystem::Chassis => "Chassis, manufacturer specific code",
+            DtcSystem::Body => "Body, manufacturer specific code",
+            DtcSystem::Network => "Network communication, manufacturer specific code",
+        },
+        severity: DtcSeverity::Warning,
+        matched: DtcMatch::System,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn describe_code(code: &str) -> DtcDescription {
+        describe(&DtcCode::new(code).unwrap())
+    }
+
+    #[test]
+    fn test_catalog_is_sorted_and_valid() {
+        for pair in GENERIC_DTC_CATALOG.windows(2) {
+            assert!(
+                pair[0].code < pair[1].code,
+                "{} is out of order",
+                pair[1].code
+            );
+        }
+        for entry in GENERIC_DTC_CATALOG {
+            let code = DtcCode::new(entry.code).unwrap();
+            assert_eq!(code.value(), entry.code);
+            assert!(code.is_generic(), "{} is not generic", entry.code);
+        }
+    }
+
+    #[test]
+    fn test_listed_code() {
+        let description = describe_code("p0301");
+        assert_eq!(description.description, "Cylinder 1 misfire detected");
+        assert_eq!(description.severity, DtcSeverity::Critical);
+        assert_eq!(description.matched, DtcMatch::Code);
+    }
+
+    #[test]
+    fn test_unlisted_generic_code_is_described_by_its_group() {
+        let description = describe_code("P0312");
+        assert_eq!(description.description, "Ignition system or misfire");
+        assert_eq!(description.severity, DtcSeverity::Warning);
+        assert_eq!(description.matched, DtcMatch::Group);
+        assert_eq!(describe_code("P0A0F").description, "Hybrid propulsion");
+        assert_eq!(describe_code("U0300").matched, DtcMatch::Group);
+    }
+
+    #[test]
+    fn test_manufacturer_code_is_described_by_its_system() {
+        let description = describe_code("P1456");
+        assert_eq!(
+            description.description,
+            "Powertrain, manufacturer specific code"
+        );
+        assert_eq!(description.matched, DtcMatch::System);
+        assert_eq!(describe_code("P3000").matched, DtcMatch::System);
+        assert_eq!(describe_code("B1318").matched, DtcMatch::System);
+    }
+}
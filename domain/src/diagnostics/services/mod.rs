@@ -0,0 +1,2 @@
+pub mod diagnostic_rule_service;
+pub mod dtc_catalog;
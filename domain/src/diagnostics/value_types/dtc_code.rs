@@ -0,0 +1,152 @@
+//! Represents an OBD-II diagnostic trouble code (SAE J2012), e.g. "P0301".
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * A code is a system letter (P, C, B or U), a digit from 0 to 3 and three hexadecimal digits.
+//!   It is stored in uppercase, surrounding whitespace ignored.
+//! * The second character tells generic codes (the same meaning for every make) from manufacturer
+//!   codes: 0 is generic, 1 manufacturer, 2 generic for the powertrain only, and 3 generic for the
+//!   powertrain from P3400.
+use std::fmt;
+
+/// Length of a code, in characters.
+pub const LENGTH: usize = 5;
+
+/// The system of the vehicle a code is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DtcSystem {
+    /// P: engine and transmission
+    Powertrain,
+    /// C: brakes, steering, suspension
+    Chassis,
+    /// B: airbags, climate, seats, lights
+    Body,
+    /// U: communication between the control modules
+    Network,
+}
+
+impl DtcSystem {
+    pub fn as_str(&self) -> &str {
+        match self {
+            DtcSystem::Powertrain => "powertrain",
+            DtcSystem::Chassis => "chassis",
+            DtcSystem::Body => "body",
+            DtcSystem::Network => "network",
+        }
+    }
+
+    /// The letter of the codes of the system.
+    pub fn letter(&self) -> char {
+        match self {
+            DtcSystem::Powertrain => 'P',
+            DtcSystem::Chassis => 'C',
+            DtcSystem::Body => 'B',
+            DtcSystem::Network => 'U',
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DtcCode(String);
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum DtcCodeError {
+    #[error("DTC must be {LENGTH} characters, got {0}")]
+    InvalidLength(usize),
+    #[error("DTC must start with P, C, B or U, got '{0}'")]
+    InvalidSystem(char),
+    #[error("Invalid DTC: {0}")]
+    InvalidFormat(String),
+}
+
+impl DtcCode {
+    pub fn new(value: impl Into<String>) -> Result<Self, DtcCodeError> {
+        let value = value.into().trim().to_uppercase();
+        let length = value.chars().count();
+        if length != LENGTH {
+            return Err(DtcCodeError::InvalidLength(length));
+        }
+
+        let mut chars = value.chars();
+        let system = chars.next().unwrap_or_default();
+        if !matches!(system, 'P' | 'C' | 'B' | 'U') {
+            return Err(DtcCodeError::InvalidSystem(system));
+        }
+        let origin = chars.next().unwrap_or_default();
+        if !('0'..='3').contains(&origin) || !chars.all(|c| c.is_ascii_hexdigit()) {
+            return Err(DtcCodeError::InvalidFormat(value));
+        }
+
+        Ok(DtcCode(value))
+    }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+
+    pub fn system(&self) -> DtcSystem {
+        match self.0.as_bytes()[0] {
+            b'P' => DtcSystem::Powertrain,
+            b'C' => DtcSystem::Chassis,
+            b'B' => DtcSystem::Body,
+            _ => DtcSystem::Network,
+        }
+    }
+
+    /// Whether the code has the same meaning for every make.
+    pub fn is_generic(&self) -> bool {
+        let bytes = self.0.as_bytes();
+        match (self.system(), bytes[1]) {
+            (_, b'0') => true,
+            (DtcSystem::Powertrain, b'2') => true,
+            (DtcSystem::Powertrain, b'3') => bytes[2] >= b'4' && bytes[2] <= b'9',
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for DtcCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_code_is_normalized() {
+        let code = DtcCode::new(" p0301 ").unwrap();
+        assert_eq!(code.value(), "P0301");
+        assert_eq!(code.system(), DtcSystem::Powertrain);
+        assert_eq!(DtcCode::new("u0a1f").unwrap().value(), "U0A1F");
+    }
+
+    #[test]
+    fn test_invalid_codes_are_rejected() {
+        assert_eq!(DtcCode::new("P030"), Err(DtcCodeError::InvalidLength(4)));
+        assert_eq!(DtcCode::new("X0301"), Err(DtcCodeError::InvalidSystem('X')));
+        assert!(matches!(
+            DtcCode::new("P4301"),
+            Err(DtcCodeError::InvalidFormat(_))
+        ));
+        assert!(matches!(
+            DtcCode::new("P03G1"),
+            Err(DtcCodeError::InvalidFormat(_))
+        ));
+    }
+
+    #[test]
+    fn test_generic_codes() {
+        let generic = |value: &str| DtcCode::new(value).unwrap().is_generic();
+        assert!(generic("P0301"));
+        assert!(generic("P2096"));
+        assert!(generic("P3400"));
+        assert!(generic("C0035"));
+        assert!(!generic("P1456"));
+        assert!(!generic("P3000"));
+        assert!(!generic("B2AAA"));
+        assert!(!generic("U1000"));
+    }
+}
@@ -0,0 +1,70 @@
+//! Represents how serious a diagnostic trouble code is, from `Info` to `Critical`.
+
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DtcSeverity {
+    /// Worth a look at the next service
+    Info,
+    /// To be checked soon, the vehicle can still be driven
+    Warning,
+    /// The vehicle should be stopped (e.g., misfires damaging the catalyst, brake faults)
+    Critical,
+}
+
+impl DtcSeverity {
+    pub fn as_str(&self) -> &str {
+        match self {
+            DtcSeverity::Info => "info",
+            DtcSeverity::Warning => "warning",
+            DtcSeverity::Critical => "critical",
+        }
+    }
+
+    /// Returns the value of the SQL `dtc_severity` enum
+    pub fn as_sql(&self) -> &str {
+        match self {
+            DtcSeverity::Info => "Info",
+            DtcSeverity::Warning => "Warning",
+            DtcSeverity::Critical => "Critical",
+        }
+    }
+}
+
+impl fmt::Display for DtcSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for DtcSeverity {
+    type Err = String;
+
+    /// Accepts both the string and the SQL representations
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "info" => Ok(DtcSeverity::Info),
+            "warning" => Ok(DtcSeverity::Warning),
+            "critical" => Ok(DtcSeverity::Critical),
+            _ => Err(format!("Invalid DTC severity: {}", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_severities_are_ordered() {
+        assert!(DtcSeverity::Info < DtcSeverity::Warning);
+        assert!(DtcSeverity::Warning < DtcSeverity::Critical);
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(" Critical".parse(), Ok(DtcSeverity::Critical));
+        assert!("severe".parse::<DtcSeverity>().is_err());
+    }
+}
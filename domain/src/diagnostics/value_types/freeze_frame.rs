@@ -0,0 +1,106 @@
+//! Represents the freeze frame of a diagnostic trouble code: the engine parameters recorded by the
+//! vehicle when the code was set (e.g., "engine_rpm" = "2150", "coolant_temp_c" = "96").
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * Parameters are named freely (devices don't agree on names), and their values kept as
+//!   reported, as text. Names are trimmed and required, values trimmed.
+//! * A frame has at most `MAX_PARAMETERS` parameters, so a faulty device can't flood the storage.
+use std::collections::BTreeMap;
+
+pub const MAX_PARAMETERS: usize = 64;
+/// Longest parameter name, in characters.
+pub const MAX_NAME_LENGTH: usize = 64;
+/// Longest parameter value, in characters.
+pub const MAX_VALUE_LENGTH: usize = 256;
+
+/// The parameters of a freeze frame, by name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FreezeFrame(BTreeMap<String, String>);
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum FreezeFrameError {
+    #[error("Freeze frame has too many parameters: maximum {MAX_PARAMETERS}, got {0}")]
+    TooManyParameters(usize),
+    #[error("Freeze frame parameter name cannot be empty")]
+    EmptyName,
+    #[error("Freeze frame parameter name too long: {0}")]
+    NameTooLong(String),
+    #[error("Freeze frame value of {0} too long")]
+    ValueTooLong(String),
+}
+
+impl FreezeFrame {
+    pub fn new(parameters: BTreeMap<String, String>) -> Result<Self, FreezeFrameError> {
+        if parameters.len() > MAX_PARAMETERS {
+            return Err(FreezeFrameError::TooManyParameters(parameters.len()));
+        }
+
+        let mut frame = BTreeMap::new();
+        for (name, value) in parameters {
+            let name = name.trim().to_string();
+            if name.is_empty() {
+                return Err(FreezeFrameError::EmptyName);
+            }
+            if name.chars().count() > MAX_NAME_LENGTH {
+                return Err(FreezeFrameError::NameTooLong(name));
+            }
+            let value = value.trim().to_string();
+            if value.chars().count() > MAX_VALUE_LENGTH {
+                return Err(FreezeFrameError::ValueTooLong(name));
+            }
+            frame.insert(name, value);
+        }
+
+        Ok(FreezeFrame(frame))
+    }
+
+    pub fn parameters(&self) -> &BTreeMap<String, String> {
+        &self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parameters(entries: &[(&str, &str)]) -> BTreeMap<String, String> {
+        entries
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_parameters_are_trimmed() {
+        let frame = FreezeFrame::new(parameters(&[(" engine_rpm ", " 2150 ")])).unwrap();
+        assert_eq!(
+            frame.parameters().get("engine_rpm").map(String::as_str),
+            Some("2150")
+        );
+    }
+
+    #[test]
+    fn test_invalid_frames_are_rejected() {
+        assert_eq!(
+            FreezeFrame::new(parameters(&[(" ", "1")])),
+            Err(FreezeFrameError::EmptyName)
+        );
+        let many = (0..=MAX_PARAMETERS)
+            .map(|index| (format!("pid_{}", index), "0".to_string()))
+            .collect();
+        assert_eq!(
+            FreezeFrame::new(many),
+            Err(FreezeFrameError::TooManyParameters(MAX_PARAMETERS + 1))
+        );
+        let long = "x".repeat(MAX_VALUE_LENGTH + 1);
+        assert_eq!(
+            FreezeFrame::new(parameters(&[("vin", &long)])),
+            Err(FreezeFrameError::ValueTooLong("vin".to_string()))
+        );
+    }
+}
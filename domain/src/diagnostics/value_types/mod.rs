@@ -0,0 +1,3 @@
+pub mod dtc_code;
+pub mod dtc_severity;
+pub mod freeze_frame;
@@ -0,0 +1,153 @@
+//! Represents a refueling or charging of a vehicle.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * The energy unit must match the engine type: electric vehicles are charged in kWh, combustion
+//!   vehicles are filled in liters, hydrogen vehicles in kilograms (CNG in liters or kilograms).
+//!   Plug-in hybrids accept liters and kWh.
+//! * `full` means the tank was filled up (or the battery charged to the target level); consumption
+//!   can only be computed between two full events.
+//! * The driver is the person who filled the vehicle, not necessarily the one who logged it.
+use crate::{
+    fuel::value_types::energy_unit::EnergyUnit,
+    maintenance::value_types::money::Money,
+    vehicle::entities::vehicle::VehicleIdentity,
+};
+use rust_decimal::Decimal;
+
+/// Represents the identity of a fuel event (DB record, non-hydrated).
+#[derive(Debug, Clone)]
+pub struct FuelEventIdentity {
+    /// The unique identifier for the fuel event.
+    pub id: i32,
+    /// The unique identifier for the vehicle.
+    pub vehicle_id: uuid::Uuid,
+    /// The unique identifier for the driver who filled the vehicle.
+    pub driver_id: uuid::Uuid,
+
+    /// The timestamp when the vehicle was filled.
+    pub performed_at: chrono::DateTime<chrono::Utc>,
+    /// Odometer reading at the time of the fill.
+    pub odometer: i32,
+    /// Amount of energy put into the vehicle.
+    pub quantity: Decimal,
+    /// Unit of `quantity` (liters or kWh).
+    pub unit: EnergyUnit,
+    /// Total price paid, if known.
+    pub total_price: Option<Money>,
+    /// Name or address of the station.
+    pub station: Option<String>,
+    /// Whether the tank was filled up (battery fully charged).
+    pub full: bool,
+
+    /// Created at timestamp.
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    /// Created by user ID.
+    pub created_by: uuid::Uuid,
+    /// Updated at timestamp.
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+    /// Updated by user ID.
+    pub updated_by: uuid::Uuid,
+}
+
+/// Represents a hydrated version of a fuel event.
+#[derive(Debug, Clone)]
+pub struct FuelEvent {
+    /// The identity details of the fuel event.
+    pub identity: FuelEventIdentity,
+    /// The vehicle that was filled.
+    pub vehicle: VehicleIdentity,
+}
+
+impl FuelEvent {
+    /// Creates a new fuel event after validating it against the vehicle.
+    pub fn new(
+        vehicle: VehicleIdentity,
+        driver_id: uuid::Uuid,
+        created_by: uuid::Uuid,
+        data: NewFuelEvent,
+    ) -> Result<Self, FuelEventError> {
+        let unit = data
+            .unit
+            .parse::<EnergyUnit>()
+            .map_err(|_| FuelEventError::UnknownUnit(data.unit.clone()))?;
+        if !unit.is_compatible_with(vehicle.powertrain.engine_type()) {
+            return Err(FuelEventError::IncompatibleUnit(
+                unit,
+                vehicle.powertrain.engine_type().to_string(),
+            ));
+        }
+        if data.quantity <= Decimal::ZERO {
+            return Err(FuelEventError::InvalidQuantity(data.quantity));
+        }
+        if data.odometer < 0 {
+            return Err(FuelEventError::InvalidOdometer(data.odometer));
+        }
+        if data.total_price.is_some_and(|price| price.is_negative()) {
+            return Err(FuelEventError::NegativePrice);
+        }
+
+        let station = data
+            .station
+            .map(|station| station.trim().to_string())
+            .filter(|station| !station.is_empty());
+
+        Ok(FuelEvent {
+            identity: FuelEventIdentity {
+                id: 0, // This will be set by the database
+                vehicle_id: vehicle.id,
+                driver_id,
+                performed_at: data.performed_at,
+                odometer: data.odometer,
+                quantity: data.quantity,
+                unit,
+                total_price: data.total_price,
+                station,
+                full: data.full,
+                created_at: chrono::Utc::now(),
+                created_by,
+                updated_at: chrono::Utc::now(),
+                updated_by: created_by,
+            },
+            vehicle,
+        })
+    }
+
+    /// Returns the price of a single unit (per liter or per kWh), if the total price is known
+    pub fn unit_price(&self) -> Option<Money> {
+        self.identity
+            .total_price
+            .and_then(|price| price.checked_div(self.identity.quantity))
+    }
+}
+
+pub struct NewFuelEvent {
+    /// The timestamp when the vehicle was filled.
+    pub performed_at: chrono::DateTime<chrono::Utc>,
+    /// Odometer reading at the time of the fill.
+    pub odometer: i32,
+    /// Amount of energy put into the vehicle.
+    pub quantity: Decimal,
+    /// Unit of `quantity` (e.g., "L", "kWh", "kg").
+    pub unit: String,
+    /// Total price paid, if known.
+    pub total_price: Option<Money>,
+    /// Name or address of the station.
+    pub station: Option<String>,
+    /// Whether the tank was filled up (battery fully charged).
+    pub full: bool,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum FuelEventError {
+    #[error("Unknown energy unit: {0}")]
+    UnknownUnit(String),
+    #[error("Energy unit {0} is not compatible with engine type {1}")]
+    IncompatibleUnit(EnergyUnit, String),
+    #[error("Invalid quantity: {0}")]
+    InvalidQuantity(Decimal),
+    #[error("Invalid odometer reading: {0}")]
+    InvalidOdometer(i32),
+    #[error("Price can't be negative")]
+    NegativePrice,
+}
@@ -0,0 +1,73 @@
+//! Represents the energy storage of a vehicle (fuel tank or traction battery).
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * A vehicle has at most one tank per energy unit (a plug-in hybrid has a tank and a battery).
+//! * The capacity is the usable capacity, fills above it are reported as anomalies.
+//! * Tanks saved for a vehicle take precedence over the capacities of its powertrain.
+use crate::{fuel::value_types::energy_unit::EnergyUnit, vehicle::entities::vehicle::VehicleIdentity};
+use rust_decimal::Decimal;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuelTank {
+    /// The vehicle this tank belongs to.
+    pub vehicle_id: uuid::Uuid,
+    /// The unit of the stored energy.
+    pub unit: EnergyUnit,
+    /// Usable capacity (liters or kWh).
+    pub capacity: Decimal,
+}
+
+impl FuelTank {
+    /// Creates a new tank for a vehicle, checking it matches the engine type.
+    pub fn new(
+        vehicle: &VehicleIdentity,
+        unit: EnergyUnit,
+        capacity: Decimal,
+    ) -> Result<Self, FuelTankError> {
+        if !unit.is_compatible_with(vehicle.powertrain.engine_type()) {
+            return Err(FuelTankError::IncompatibleUnit(
+                unit,
+                vehicle.powertrain.engine_type().to_string(),
+            ));
+        }
+        if capacity <= Decimal::ZERO {
+            return Err(FuelTankError::InvalidCapacity(capacity));
+        }
+
+        Ok(FuelTank {
+            vehicle_id: vehicle.id,
+            unit,
+            capacity,
+        })
+    }
+
+    /// Completes the saved tanks of a vehicle with the capacities of its powertrain.
+    pub fn for_vehicle(vehicle: &VehicleIdentity, mut saved: Vec<FuelTank>) -> Vec<FuelTank> {
+        let powertrain = &vehicle.powertrain;
+        let capacities = [
+            (EnergyUnit::Liters, powertrain.tank_capacity()),
+            (EnergyUnit::KilowattHours, powertrain.battery_capacity()),
+        ];
+        for (unit, capacity) in capacities {
+            if let Some(capacity) = capacity
+                && !saved.iter().any(|tank| tank.unit == unit)
+            {
+                saved.push(FuelTank {
+                    vehicle_id: vehicle.id,
+                    unit,
+                    capacity,
+                });
+            }
+        }
+        saved
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum FuelTankError {
+    #[error("Energy unit {0} is not compatible with engine type {1}")]
+    IncompatibleUnit(EnergyUnit, String),
+    #[error("Invalid tank capacity: {0}")]
+    InvalidCapacity(Decimal),
+}
@@ -0,0 +1,2 @@
+pub mod fuel_event;
+pub mod fuel_tank;
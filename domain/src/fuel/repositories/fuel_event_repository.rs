@@ -0,0 +1,31 @@
+//! Repository for managing fuel and charging events.
+
+use crate::fuel::entities::fuel_event::{FuelEvent, FuelEventIdentity};
+use std::future::Future;
+
+/// Errors that can occur when interacting with the fuel event repository
+#[derive(Debug, thiserror::Error)]
+pub enum FuelEventRepositoryError {
+    #[error("database error: {0}")]
+    Database(String),
+}
+
+/// Repository interface for fuel event operations
+pub trait FuelEventRepository: Send + Sync {
+    /// Creates a new fuel event
+    fn create(
+        &self,
+        organization_id: uuid::Uuid,
+        event: FuelEvent,
+    ) -> impl Future<Output = Result<FuelEventIdentity, FuelEventRepositoryError>> + Send;
+
+    /// Retrieves the fuel events of a vehicle performed within the given range (both ends
+    /// inclusive), ordered by `performed_at`
+    fn find_by_vehicle(
+        &self,
+        organization_id: uuid::Uuid,
+        vehicle_id: uuid::Uuid,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl Future<Output = Result<Vec<FuelEventIdentity>, FuelEventRepositoryError>> + Send;
+}
@@ -0,0 +1,28 @@
+//! Repository for managing the fuel tanks and batteries of vehicles.
+
+use crate::fuel::entities::fuel_tank::FuelTank;
+use std::future::Future;
+
+/// Errors that can occur when interacting with the fuel tank repository
+#[derive(Debug, thiserror::Error)]
+pub enum FuelTankRepositoryError {
+    #[error("database error: {0}")]
+    Database(String),
+}
+
+/// Repository interface for fuel tank operations
+pub trait FuelTankRepository: Send + Sync {
+    /// Retrieves the tanks of a vehicle (at most one per energy unit)
+    fn find_by_vehicle(
+        &self,
+        organization_id: uuid::Uuid,
+        vehicle_id: uuid::Uuid,
+    ) -> impl Future<Output = Result<Vec<FuelTank>, FuelTankRepositoryError>> + Send;
+
+    /// Creates or replaces the tank of a vehicle for the tank's energy unit
+    fn save(
+        &self,
+        organization_id: uuid::Uuid,
+        tank: FuelTank,
+    ) -> impl Future<Output = Result<(), FuelTankRepositoryError>> + Send;
+}
@@ -0,0 +1,2 @@
+pub mod fuel_event_repository;
+pub mod fuel_tank_repository;
@@ -0,0 +1,353 @@
+//! Consumption analytics of fuel and charging events (L/100km, kWh/100km) and anomaly detection.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * Consumption is computed between two consecutive full events ("full-to-full"): the energy of
+//!   every event after the first full one, up to and including the second, divided by the distance.
+//! * Events of different units are never mixed, each unit is analysed on its own.
+//! * An odometer going backwards breaks the chain: the next full event starts a new segment.
+//! * A full event at the odometer of the previous full one (a top-up) doesn't close the segment,
+//!   its energy is counted in the segment ending at the next full event.
+//! * A segment is a consumption jump when it exceeds the median of the previous segments by more
+//!   than `jump_ratio`. At least `min_baseline_segments` segments are needed to have a median.
+use crate::fuel::{
+    entities::{fuel_event::FuelEventIdentity, fuel_tank::FuelTank},
+    value_types::energy_unit::EnergyUnit,
+};
+use rust_decimal::Decimal;
+
+/// Tuning of the anomaly detection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConsumptionSettings {
+    /// Relative increase over the baseline that is reported as a jump (e.g., 0.3 = +30%).
+    pub jump_ratio: Decimal,
+    /// Number of previous segments the baseline (median) is computed from.
+    pub baseline_window: usize,
+    /// Minimum number of previous segments needed to detect jumps.
+    pub min_baseline_segments: usize,
+}
+
+impl Default for ConsumptionSettings {
+    fn default() -> Self {
+        ConsumptionSettings {
+            jump_ratio: Decimal::new(3, 1),
+            baseline_window: 5,
+            min_baseline_segments: 3,
+        }
+    }
+}
+
+/// Consumption between two full events.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsumptionSegment {
+    /// The full event the segment starts from.
+    pub from_event_id: i32,
+    /// The full event the segment ends with.
+    pub to_event_id: i32,
+    pub distance_km: i64,
+    /// Energy used over the segment.
+    pub quantity: Decimal,
+    /// Energy used per 100 km.
+    pub per_100_km: Decimal,
+}
+
+/// Something suspicious about the fuel events of a vehicle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FuelAnomaly {
+    /// More energy was put in than the tank can hold.
+    ExceedsTankCapacity {
+        event_id: i32,
+        quantity: Decimal,
+        capacity: Decimal,
+    },
+    /// The consumption of a segment is much higher than usual.
+    ConsumptionJump {
+        to_event_id: i32,
+        per_100_km: Decimal,
+        baseline: Decimal,
+    },
+    /// The odometer is lower than at the previous event.
+    OdometerRollback {
+        event_id: i32,
+        odometer: i32,
+        previous_odometer: i32,
+    },
+}
+
+/// Result of the analysis of the events of one unit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsumptionAnalysis {
+    pub unit: EnergyUnit,
+    pub segments: Vec<ConsumptionSegment>,
+    pub anomalies: Vec<FuelAnomaly>,
+    /// Total energy used over total distance of all segments, per 100 km.
+    pub average_per_100_km: Option<Decimal>,
+    /// Energy put into the vehicle over the analysed events.
+    pub total_quantity: Decimal,
+}
+
+/// Analyses the events of a vehicle in the given unit.
+///
+/// # Arguments
+/// * `events` - Fuel events of the vehicle, in any order. Events in other units are ignored.
+/// * `tank` - The tank of the vehicle for this unit, if known.
+/// * `settings` - Tuning of the anomaly detection.
+pub fn analyze_consumption(
+    events: &[FuelEventIdentity],
+    unit: EnergyUnit,
+    tank: Option<&FuelTank>,
+    settings: &ConsumptionSettings,
+) -> ConsumptionAnalysis {
+    let mut events: Vec<&FuelEventIdentity> = events.iter().filter(|e| e.unit == unit).collect();
+    events.sort_by_key(|event| (event.performed_at, event.odometer));
+
+    let capacity = tank.filter(|tank| tank.unit == unit).map(|tank| tank.capacity);
+    let mut segments: Vec<ConsumptionSegment> = Vec::new();
+    let mut anomalies = Vec::new();
+    let mut total_quantity = Decimal::ZERO;
+
+    let mut last_full: Option<&FuelEventIdentity> = None;
+    let mut previous: Option<&FuelEventIdentity> = None;
+    let mut accumulated = Decimal::ZERO;
+
+    for event in events {
+        total_quantity += event.quantity;
+
+        if let Some(capacity) = capacity
+            && event.quantity > capacity
+        {
+            anomalies.push(FuelAnomaly::ExceedsTankCapacity {
+                event_id: event.id,
+                quantity: event.quantity,
+                capacity,
+            });
+        }
+
+        // Check the odometer, a rollback breaks the current segment
+        if let Some(previous) = previous
+            && event.odometer < previous.odometer
+        {
+            anomalies.push(FuelAnomaly::OdometerRollback {
+                event_id: event.id,
+                odometer: event.odometer,
+                previous_odometer: previous.odometer,
+            });
+            last_full = None;
+        }
+        previous = Some(event);
+
+        if last_full.is_some() {
+            accumulated += event.quantity;
+        }
+        if !event.full {
+            continue;
+        }
+
+        if let Some(start) = last_full {
+            let distance_km = i64::from(event.odometer - start.odometer);
+            // A full top-up without driving: its energy goes to the segment still open
+            if distance_km <= 0 {
+                continue;
+            }
+
+            let per_100_km =
+                (accumulated * Decimal::ONE_HUNDRED / Decimal::from(distance_km)).round_dp(2);
+
+            if let Some(baseline) = baseline(&segments, settings)
+                && per_100_km > baseline * (Decimal::ONE + settings.jump_ratio)
+            {
+                anomalies.push(FuelAnomaly::ConsumptionJump {
+                    to_event_id: event.id,
+                    per_100_km,
+                    baseline,
+                });
+            }
+
+            segments.push(ConsumptionSegment {
+                from_event_id: start.id,
+                to_event_id: event.id,
+                distance_km,
+                quantity: accumulated,
+                per_100_km,
+            });
+        }
+        last_full = Some(event);
+        accumulated = Decimal::ZERO;
+    }
+
+    let total_distance: i64 = segments.iter().map(|segment| segment.distance_km).sum();
+    let segments_quantity: Decimal = segments.iter().map(|segment| segment.quantity).sum();
+    let average_per_100_km = (total_distance > 0).then(|| {
+        (segments_quantity * Decimal::ONE_HUNDRED / Decimal::from(total_distance)).round_dp(2)
+    });
+
+    ConsumptionAnalysis {
+        unit,
+        segments,
+        anomalies,
+        average_per_100_km,
+        total_quantity,
+    }
+}
+
+/// Median consumption of the last segments, `None` if there are not enough of them
+fn baseline(segments: &[ConsumptionSegment], settings: &ConsumptionSettings) -> Option<Decimal> {
+    if segments.len() < settings.min_baseline_segments.max(1) {
+        return None;
+    }
+
+    let window = settings.baseline_window.max(1);
+    let mut values: Vec<Decimal> = segments
+        .iter()
+        .rev()
+        .take(window)
+        .map(|segment| segment.per_100_km)
+        .collect();
+    values.sort();
+
+    let middle = values.len() / 2;
+    Some(if values.len().is_multiple_of(2) {
+        (values[middle - 1] + values[middle]) / Decimal::TWO
+    } else {
+        values[middle]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone, Utc};
+
+    fn event(id: i32, odometer: i32, quantity: i64, full: bool) -> FuelEventIdentity {
+        let at = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap() + Duration::days(id.into());
+        FuelEventIdentity {
+            id,
+            vehicle_id: uuid::Uuid::nil(),
+            driver_id: uuid::Uuid::nil(),
+            performed_at: at,
+            odometer,
+            quantity: Decimal::from(quantity),
+            unit: EnergyUnit::Liters,
+            total_price: None,
+            station: None,
+            full,
+            created_at: at,
+            created_by: uuid::Uuid::nil(),
+            updated_at: at,
+            updated_by: uuid::Uuid::nil(),
+        }
+    }
+
+    fn analyze(events: &[FuelEventIdentity], tank: Option<&FuelTank>) -> ConsumptionAnalysis {
+        analyze_consumption(events, EnergyUnit::Liters, tank, &ConsumptionSettings::default())
+    }
+
+    #[test]
+    fn test_full_to_full() {
+        let events = vec![
+            event(1, 1000, 40, true),
+            event(2, 1300, 20, false),
+            event(3, 1500, 20, true),
+        ];
+        let analysis = analyze(&events, None);
+
+        assert_eq!(analysis.segments.len(), 1);
+        assert_eq!(analysis.segments[0].distance_km, 500);
+        assert_eq!(analysis.segments[0].per_100_km, Decimal::from(8));
+        assert_eq!(analysis.average_per_100_km, Some(Decimal::from(8)));
+        assert_eq!(analysis.total_quantity, Decimal::from(80));
+    }
+
+    #[test]
+    fn test_no_segment_without_two_full_events() {
+        let events = vec![event(1, 1000, 40, false), event(2, 1500, 40, true)];
+        let analysis = analyze(&events, None);
+        assert!(analysis.segments.is_empty());
+        assert_eq!(analysis.average_per_100_km, None);
+    }
+
+    #[test]
+    fn test_exceeds_tank_capacity() {
+        let tank = FuelTank {
+            vehicle_id: uuid::Uuid::nil(),
+            unit: EnergyUnit::Liters,
+            capacity: Decimal::from(50),
+        };
+        let events = vec![event(1, 1000, 40, true), event(2, 1500, 65, true)];
+        let analysis = analyze(&events, Some(&tank));
+
+        assert_eq!(
+            analysis.anomalies,
+            vec![FuelAnomaly::ExceedsTankCapacity {
+                event_id: 2,
+                quantity: Decimal::from(65),
+                capacity: Decimal::from(50),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_consumption_jump() {
+        let mut events = vec![event(0, 0, 40, true)];
+        for i in 1..=3 {
+            events.push(event(i, i * 500, 40, true)); // 8 L/100km
+        }
+        events.push(event(4, 2000, 60, true)); // 12 L/100km
+        let analysis = analyze(&events, None);
+
+        assert_eq!(analysis.segments.len(), 4);
+        assert_eq!(
+            analysis.anomalies,
+            vec![FuelAnomaly::ConsumptionJump {
+                to_event_id: 4,
+                per_100_km: Decimal::from(12),
+                baseline: Decimal::from(8),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_odometer_rollback_breaks_segment() {
+        let events = vec![
+            event(1, 1000, 40, true),
+            event(2, 900, 40, true),
+            event(3, 1400, 40, true),
+        ];
+        let analysis = analyze(&events, None);
+
+        assert_eq!(analysis.segments.len(), 1);
+        assert_eq!(analysis.segments[0].from_event_id, 2);
+        assert!(matches!(
+            analysis.anomalies[0],
+            FuelAnomaly::OdometerRollback { event_id: 2, .. }
+        ));
+    }
+
+    #[test]
+    fn test_full_top_up_keeps_the_segment_open() {
+        let events = vec![
+            event(1, 1000, 40, true),
+            event(2, 1000, 5, true),
+            event(3, 1300, 20, false),
+            event(4, 1500, 20, true),
+        ];
+        let analysis = analyze(&events, None);
+
+        assert_eq!(analysis.segments.len(), 1);
+        assert_eq!(analysis.segments[0].from_event_id, 1);
+        assert_eq!(analysis.segments[0].distance_km, 500);
+        assert_eq!(analysis.segments[0].quantity, Decimal::from(45));
+        assert_eq!(analysis.segments[0].per_100_km, Decimal::from(9));
+    }
+
+    #[test]
+    fn test_other_units_are_ignored() {
+        let mut charge = event(2, 1200, 30, true);
+        charge.unit = EnergyUnit::KilowattHours;
+        let events = vec![event(1, 1000, 40, true), charge, event(3, 1500, 40, true)];
+        let analysis = analyze(&events, None);
+
+        assert_eq!(analysis.segments[0].quantity, Decimal::from(40));
+        assert_eq!(analysis.total_quantity, Decimal::from(80));
+    }
+}
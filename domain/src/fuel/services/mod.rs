@@ -0,0 +1 @@
+pub mod fuel_consumption_service;
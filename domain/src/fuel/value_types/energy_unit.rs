@@ -0,0 +1,106 @@
+//! Represents the unit in which the energy put into a vehicle is measured.
+
+use crate::vehicle::value_types::engine_type::EngineType;
+use std::fmt;
+use std::str::FromStr;
+
+/// Liters for fuel, kilowatt-hours for electric charging, kilograms for gases sold by weight
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EnergyUnit {
+    Liters,
+    KilowattHours,
+    Kilograms,
+}
+
+impl EnergyUnit {
+    /// Returns the string representation of the unit
+    pub fn as_str(&self) -> &str {
+        match self {
+            EnergyUnit::Liters => "Liters",
+            EnergyUnit::KilowattHours => "KilowattHours",
+            EnergyUnit::Kilograms => "Kilograms",
+        }
+    }
+
+    /// Returns the short symbol of the unit (e.g., "L", "kWh", "kg")
+    pub fn symbol(&self) -> &str {
+        match self {
+            EnergyUnit::Liters => "L",
+            EnergyUnit::KilowattHours => "kWh",
+            EnergyUnit::Kilograms => "kg",
+        }
+    }
+
+    /// Checks if a vehicle with the given engine can receive energy in this unit.
+    ///
+    /// Hydrogen is filled in kilograms; CNG in kilograms or liters (of the liquid equivalent, as
+    /// some stations sell it). Unknown engine types (`Other`) accept any unit.
+    pub fn is_compatible_with(&self, engine_type: &EngineType) -> bool {
+        if matches!(engine_type, EngineType::Other(_)) {
+            return true;
+        }
+        match self {
+            EnergyUnit::Liters => engine_type.uses_fossil_fuel(),
+            EnergyUnit::KilowattHours => engine_type.is_chargeable(),
+            EnergyUnit::Kilograms => {
+                matches!(engine_type, EngineType::Hydrogen | EngineType::Cng)
+            }
+        }
+    }
+}
+
+impl fmt::Display for EnergyUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
+impl FromStr for EnergyUnit {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "liters" | "litres" | "l" => Ok(EnergyUnit::Liters),
+            "kilowatthours" | "kwh" => Ok(EnergyUnit::KilowattHours),
+            "kilograms" | "kg" => Ok(EnergyUnit::Kilograms),
+            _ => Err(format!("Invalid energy unit: {}", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("L".parse::<EnergyUnit>().unwrap(), EnergyUnit::Liters);
+        assert_eq!("kWh".parse::<EnergyUnit>().unwrap(), EnergyUnit::KilowattHours);
+        assert_eq!("KilowattHours".parse::<EnergyUnit>().unwrap(), EnergyUnit::KilowattHours);
+        assert_eq!("kg".parse::<EnergyUnit>().unwrap(), EnergyUnit::Kilograms);
+        assert_eq!("Kilograms".parse::<EnergyUnit>().unwrap(), EnergyUnit::Kilograms);
+        assert!("gallons".parse::<EnergyUnit>().is_err());
+    }
+
+    #[test]
+    fn test_engine_compatibility() {
+        assert!(EnergyUnit::Liters.is_compatible_with(&EngineType::Diesel));
+        assert!(!EnergyUnit::Liters.is_compatible_with(&EngineType::Electric));
+        assert!(EnergyUnit::KilowattHours.is_compatible_with(&EngineType::Electric));
+        assert!(!EnergyUnit::KilowattHours.is_compatible_with(&EngineType::Gasoline));
+        assert!(EnergyUnit::KilowattHours.is_compatible_with(&EngineType::PluginHybrid));
+        assert!(!EnergyUnit::KilowattHours.is_compatible_with(&EngineType::Hybrid));
+        assert!(EnergyUnit::KilowattHours.is_compatible_with(&EngineType::Other("rotary".into())));
+    }
+
+    #[test]
+    fn test_hydrogen_is_filled_in_kilograms() {
+        assert!(EnergyUnit::Kilograms.is_compatible_with(&EngineType::Hydrogen));
+        assert!(!EnergyUnit::Liters.is_compatible_with(&EngineType::Hydrogen));
+        assert!(!EnergyUnit::KilowattHours.is_compatible_with(&EngineType::Hydrogen));
+        assert!(EnergyUnit::Kilograms.is_compatible_with(&EngineType::Cng));
+        assert!(EnergyUnit::Liters.is_compatible_with(&EngineType::Cng));
+        assert!(!EnergyUnit::Kilograms.is_compatible_with(&EngineType::Diesel));
+        assert!(!EnergyUnit::Kilograms.is_compatible_with(&EngineType::Electric));
+    }
+}
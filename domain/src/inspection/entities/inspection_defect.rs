@@ -0,0 +1,192 @@
+//! Represents a defect found by an inspection: a failed item, open until a mechanic signs it off.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * A defect is opened for each failed item of a report, with the item as it was answered.
+//! * It is signed off once, not before it was reported. Signing off a defect as not needing a
+//!   repair requires notes explaining why.
+use crate::inspection::{
+    entities::inspection_report::{InspectionAnswer, InspectionReport},
+    value_types::defect_resolution::DefectResolution,
+};
+use chrono::{DateTime, Utc};
+
+/// Longest sign-off notes, in characters.
+pub const MAX_NOTES_LENGTH: usize = 1000;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InspectionDefect {
+    /// The unique identifier of the defect.
+    pub id: uuid::Uuid,
+    /// The report which found the defect.
+    pub report_id: uuid::Uuid,
+    pub vehicle_id: uuid::Uuid,
+    /// The failed item of the template.
+    pub item_id: uuid::Uuid,
+    pub label: String,
+    /// A defect of a critical item puts the vehicle out of service until it is signed off.
+    pub critical: bool,
+    /// The comment of the driver.
+    pub comment: Option<String>,
+    pub reported_at: DateTime<Utc>,
+    /// How the mechanic signed the defect off, `None` while it is open.
+    pub resolution: Option<DefectResolution>,
+    pub signed_off_at: Option<DateTime<Utc>>,
+    /// The mechanic who signed the defect off.
+    pub signed_off_by: Option<uuid::Uuid>,
+    pub sign_off_notes: Option<String>,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum InspectionDefectError {
+    #[error("Defect already signed off: {0}")]
+    AlreadySignedOff(String),
+    #[error("Defect can't be signed off before it was reported ({0})")]
+    SignedOffBeforeReported(DateTime<Utc>),
+    #[error("Notes are required to sign off a defect without repair")]
+    NotesRequired,
+    #[error("Notes too long: maximum {MAX_NOTES_LENGTH} characters")]
+    NotesTooLong,
+}
+
+impl InspectionDefect {
+    /// Opens the defect of a failed answer of a report.
+    pub fn open(report: &InspectionReport, answer: &InspectionAnswer) -> Self {
+        InspectionDefect {
+            id: uuid::Uuid::new_v4(),
+            report_id: report.id,
+            vehicle_id: report.vehicle_id,
+            item_id: answer.item_id,
+            label: answer.label.clone(),
+            critical: answer.critical,
+            comment: answer.comment.clone(),
+            reported_at: report.submitted_at,
+            resolution: None,
+            signed_off_at: None,
+            signed_off_by: None,
+            sign_off_notes: None,
+        }
+    }
+
+    /// The defects of the failed items of a report.
+    pub fn open_all(report: &InspectionReport) -> Vec<Self> {
+        report
+            .failed()
+            .map(|answer| InspectionDefect::open(report, answer))
+            .collect()
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.signed_off_at.is_none()
+    }
+
+    /// Signs the defect off by a mechanic.
+    pub fn sign_off(
+        &mut self,
+        resolution: DefectResolution,
+        signed_off_at: DateTime<Utc>,
+        signed_off_by: uuid::Uuid,
+        notes: Option<String>,
+    ) -> Result<(), InspectionDefectError> {
+        if !self.is_open() {
+            return Err(InspectionDefectError::AlreadySignedOff(self.label.clone()));
+        }
+        if signed_off_at < self.reported_at {
+            return Err(InspectionDefectError::SignedOffBeforeReported(
+                self.reported_at,
+            ));
+        }
+        let notes = notes
+            .map(|notes| notes.trim().to_string())
+            .filter(|notes| !notes.is_empty());
+        if notes
+            .as_ref()
+            .is_some_and(|notes| notes.chars().count() > MAX_NOTES_LENGTH)
+        {
+            return Err(InspectionDefectError::NotesTooLong);
+        }
+        if resolution == DefectResolution::NoRepairNeeded && notes.is_none() {
+            return Err(InspectionDefectError::NotesRequired);
+        }
+
+        self.resolution = Some(resolution);
+        self.signed_off_at = Some(signed_off_at);
+        self.signed_off_by = Some(signed_off_by);
+        self.sign_off_notes = notes;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn defect() -> InspectionDefect {
+        InspectionDefect {
+            id: uuid::Uuid::new_v4(),
+            report_id: uuid::Uuid::new_v4(),
+            vehicle_id: uuid::Uuid::new_v4(),
+            item_id: uuid::Uuid::new_v4(),
+            label: "Service brakes".to_string(),
+            critical: true,
+            comment: Some("Soft pedal".to_string()),
+            reported_at: Utc::now(),
+            resolution: None,
+            signed_off_at: None,
+            signed_off_by: None,
+            sign_off_notes: None,
+        }
+    }
+
+    #[test]
+    fn test_sign_off() {
+        let mut defect = defect();
+        let mechanic = uuid::Uuid::new_v4();
+        let at = defect.reported_at + Duration::hours(2);
+        defect
+            .sign_off(
+                DefectResolution::Repaired,
+                at,
+                mechanic,
+                Some(" ".to_string()),
+            )
+            .unwrap();
+        assert!(!defect.is_open());
+        assert_eq!(defect.resolution, Some(DefectResolution::Repaired));
+        assert_eq!(defect.signed_off_by, Some(mechanic));
+        assert_eq!(defect.sign_off_notes, None);
+        assert_eq!(
+            defect.sign_off(DefectResolution::Repaired, at, mechanic, None),
+            Err(InspectionDefectError::AlreadySignedOff(
+                "Service brakes".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_invalid_sign_offs_are_rejected() {
+        let mut defect = defect();
+        let mechanic = uuid::Uuid::new_v4();
+        let reported_at = defect.reported_at;
+        assert_eq!(
+            defect.sign_off(
+                DefectResolution::NoRepairNeeded,
+                reported_at,
+                mechanic,
+                None
+            ),
+            Err(InspectionDefectError::NotesRequired)
+        );
+        assert_eq!(
+            defect.sign_off(
+                DefectResolution::Repaired,
+                reported_at - Duration::minutes(1),
+                mechanic,
+                None
+            ),
+            Err(InspectionDefectError::SignedOffBeforeReported(reported_at))
+        );
+        assert!(defect.is_open(), "a rejected sign-off leaves it open");
+    }
+}
@@ -0,0 +1,355 @@
+//! Represents an inspection of a vehicle submitted by a driver (a DVIR).
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * Every item of the template is answered exactly once, the answers keep the order of the
+//!   template. A blank comment is no comment.
+//! * A photo is a reference to a file stored elsewhere (e.g., an URL or a storage key); an item
+//!   requiring photos needs one unless it is not applicable.
+//! * The report is tied to the vehicle status recorded with it, and never changes once submitted.
+use crate::inspection::{
+    entities::inspection_template::InspectionTemplate,
+    value_types::{inspection_kind::InspectionKind, item_result::ItemResult},
+};
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+
+/// Longest comment of an answer, in characters.
+pub const MAX_COMMENT_LENGTH: usize = 1000;
+/// Most photos of an answer.
+pub const MAX_PHOTOS: usize = 10;
+/// Longest photo reference, in characters.
+pub const MAX_PHOTO_LENGTH: usize = 500;
+
+/// The answer of the driver to an item, as submitted.
+#[derive(Debug, Clone)]
+pub struct ItemAnswer {
+    pub item_id: uuid::Uuid,
+    pub result: ItemResult,
+    pub comment: Option<String>,
+    pub photos: Vec<String>,
+}
+
+/// An answer of a report, with the item as it was in the template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InspectionAnswer {
+    pub item_id: uuid::Uuid,
+    pub label: String,
+    pub critical: bool,
+    pub result: ItemResult,
+    pub comment: Option<String>,
+    pub photos: Vec<String>,
+}
+
+impl InspectionAnswer {
+    pub fn is_failed(&self) -> bool {
+        self.result == ItemResult::Fail
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InspectionReport {
+    /// The unique identifier of the report.
+    pub id: uuid::Uuid,
+    pub template_id: uuid::Uuid,
+    pub vehicle_id: uuid::Uuid,
+    /// The status of the vehicle recorded with the report.
+    pub vehicle_status_id: i32,
+    pub kind: InspectionKind,
+    /// The driver who performed the inspection.
+    pub submitted_by: uuid::Uuid,
+    pub submitted_at: DateTime<Utc>,
+    pub answers: Vec<InspectionAnswer>,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum InspectionReportError {
+    #[error("Unknown item: {0}")]
+    UnknownItem(uuid::Uuid),
+    #[error("Item answered twice: {0}")]
+    DuplicateAnswer(String),
+    #[error("Item not answered: {0}")]
+    MissingAnswer(String),
+    #[error("A comment is required when this item fails: {0}")]
+    CommentRequired(String),
+    #[error("Comment too long for {0}: maximum {MAX_COMMENT_LENGTH} characters")]
+    CommentTooLong(String),
+    #[error("A photo is required for this item: {0}")]
+    PhotoRequired(String),
+    #[error("Too many photos for {0}: maximum {MAX_PHOTOS}")]
+    TooManyPhotos(String),
+    #[error("Invalid photo reference for {0}")]
+    InvalidPhoto(String),
+}
+
+impl InspectionReport {
+    /// Checks the answers against the template; the status is the one recorded with the report.
+    pub fn submit(
+        template: &InspectionTemplate,
+        vehicle_id: uuid::Uuid,
+        vehicle_status_id: i32,
+        submitted_by: uuid::Uuid,
+        submitted_at: DateTime<Utc>,
+        answers: Vec<ItemAnswer>,
+    ) -> Result<Self, InspectionReportError> {
+        let mut by_item: HashMap<uuid::Uuid, ItemAnswer> = HashMap::new();
+        for answer in answers {
+            let item = template
+                .item(answer.item_id)
+                .ok_or(InspectionReportError::UnknownItem(answer.item_id))?;
+            if by_item.insert(answer.item_id, answer).is_some() {
+                return Err(InspectionReportError::DuplicateAnswer(item.label.clone()));
+            }
+        }
+
+        let mut checked = Vec::with_capacity(template.items.len());
+        for item in &template.items {
+            let answer = by_item
+                .remove(&item.id)
+                .ok_or_else(|| InspectionReportError::MissingAnswer(item.label.clone()))?;
+            let label = || item.label.clone();
+
+            let comment = answer
+                .comment
+                .map(|comment| comment.trim().to_string())
+                .filter(|comment| !comment.is_empty());
+            if comment
+                .as_ref()
+                .is_some_and(|comment| comment.chars().count() > MAX_COMMENT_LENGTH)
+            {
+                return Err(InspectionReportError::CommentTooLong(label()));
+            }
+            if answer.result == ItemResult::Fail
+                && item.comment_required_on_fail
+                && comment.is_none()
+            {
+                return Err(InspectionReportError::CommentRequired(label()));
+            }
+
+            let photos: Vec<String> = answer
+                .photos
+                .iter()
+                .map(|photo| photo.trim().to_string())
+                .collect();
+            if photos.len() > MAX_PHOTOS {
+                return Err(InspectionReportError::TooManyPhotos(label()));
+            }
+            if photos
+                .iter()
+                .any(|photo| photo.is_empty() || photo.chars().count() > MAX_PHOTO_LENGTH)
+            {
+                return Err(InspectionReportError::InvalidPhoto(label()));
+            }
+            if item.photo_required
+                && answer.result != ItemResult::NotApplicable
+                && photos.is_empty()
+            {
+                return Err(InspectionReportError::PhotoRequired(label()));
+            }
+
+            checked.push(InspectionAnswer {
+                item_id: item.id,
+                label: item.label.clone(),
+                critical: item.critical,
+                result: answer.result,
+                comment,
+                photos,
+            });
+        }
+
+        Ok(InspectionReport {
+            id: uuid::Uuid::new_v4(),
+            template_id: template.id,
+            vehicle_id,
+            vehicle_status_id,
+            kind: template.kind,
+            submitted_by,
+            submitted_at,
+            answers: checked,
+        })
+    }
+
+    /// The answers of the failed items.
+    pub fn failed(&self) -> impl Iterator<Item = &InspectionAnswer> {
+        self.answers.iter().filter(|answer| answer.is_failed())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inspection::entities::inspection_template::{
+        NewInspectionItem, NewInspectionTemplate,
+    };
+
+    fn template() -> InspectionTemplate {
+        let item = |label: &str, critical: bool, photo_required: bool| NewInspectionItem {
+            label: label.to_string(),
+            critical,
+            photo_required,
+            comment_required_on_fail: true,
+        };
+        InspectionTemplate::new(
+            NewInspectionTemplate {
+                name: "Daily pre-trip".to_string(),
+                kind: InspectionKind::PreTrip,
+                items: vec![
+                    item("Service brakes", true, false),
+                    item("Tires", false, true),
+                    item("Coupling devices", false, false),
+                ],
+            },
+            uuid::Uuid::new_v4(),
+        )
+        .unwrap()
+    }
+
+    fn answer(template: &InspectionTemplate, index: usize, result: ItemResult) -> ItemAnswer {
+        ItemAnswer {
+            item_id: template.items[index].id,
+            result,
+            comment: None,
+            photos: vec!["photos/tires.jpg".to_string()],
+        }
+    }
+
+    fn submit(
+        template: &InspectionTemplate,
+        answers: Vec<ItemAnswer>,
+    ) -> Result<InspectionReport, InspectionReportError> {
+        InspectionReport::submit(
+            template,
+            uuid::Uuid::new_v4(),
+            1,
+            uuid::Uuid::new_v4(),
+            Utc::now(),
+            answers,
+        )
+    }
+
+    #[test]
+    fn test_answers_follow_the_template() {
+        let template = template();
+        let report = submit(
+            &template,
+            vec![
+                answer(&template, 2, ItemResult::NotApplicable),
+                ItemAnswer {
+                    comment: Some(" Soft pedal ".to_string()),
+                    ..answer(&template, 0, ItemResult::Fail)
+                },
+                answer(&template, 1, ItemResult::Pass),
+            ],
+        )
+        .unwrap();
+        assert_eq!(report.kind, InspectionKind::PreTrip);
+        let labels: Vec<&str> = report.answers.iter().map(|a| a.label.as_str()).collect();
+        assert_eq!(labels, vec!["Service brakes", "Tires", "Coupling devices"]);
+        assert!(report.answers[0].critical);
+        assert_eq!(report.answers[0].comment.as_deref(), Some("Soft pedal"));
+        assert_eq!(report.failed().count(), 1);
+    }
+
+    #[test]
+    fn test_every_item_is_answered_once() {
+        let template = template();
+        assert_eq!(
+            submit(
+                &template,
+                vec![
+                    answer(&template, 0, ItemResult::Pass),
+                    answer(&template, 1, ItemResult::Pass)
+                ]
+            ),
+            Err(InspectionReportError::MissingAnswer(
+                "Coupling devices".to_string()
+            ))
+        );
+        assert_eq!(
+            submit(
+                &template,
+                vec![
+                    answer(&template, 0, ItemResult::Pass),
+                    answer(&template, 0, ItemResult::Fail)
+                ]
+            ),
+            Err(InspectionReportError::DuplicateAnswer(
+                "Service brakes".to_string()
+            ))
+        );
+        let unknown = uuid::Uuid::new_v4();
+        assert_eq!(
+            submit(
+                &template,
+                vec![ItemAnswer {
+                    item_id: unknown,
+                    ..answer(&template, 0, ItemResult::Pass)
+                }]
+            ),
+            Err(InspectionReportError::UnknownItem(unknown))
+        );
+    }
+
+    #[test]
+    fn test_comments_and_photos_are_required() {
+        let template = template();
+        let answers = |brakes: ItemAnswer, tires: ItemAnswer| {
+            vec![brakes, tires, answer(&template, 2, ItemResult::Pass)]
+        };
+        assert_eq!(
+            submit(
+                &template,
+                answers(
+                    ItemAnswer {
+                        comment: Some("  ".to_string()),
+                        ..answer(&template, 0, ItemResult::Fail)
+                    },
+                    answer(&template, 1, ItemResult::Pass)
+                )
+            ),
+            Err(InspectionReportError::CommentRequired(
+                "Service brakes".to_string()
+            ))
+        );
+        assert_eq!(
+            submit(
+                &template,
+                answers(
+                    answer(&template, 0, ItemResult::Pass),
+                    ItemAnswer {
+                        photos: vec![],
+                        ..answer(&template, 1, ItemResult::Pass)
+                    }
+                )
+            ),
+            Err(InspectionReportError::PhotoRequired("Tires".to_string()))
+        );
+        assert!(
+            submit(
+                &template,
+                answers(
+                    answer(&template, 0, ItemResult::Pass),
+                    ItemAnswer {
+                        photos: vec![],
+                        ..answer(&template, 1, ItemResult::NotApplicable)
+                    }
+                )
+            )
+            .is_ok(),
+            "a photo is not required when the item doesn't apply"
+        );
+        assert_eq!(
+            submit(
+                &template,
+                answers(
+                    answer(&template, 0, ItemResult::Pass),
+                    ItemAnswer {
+                        photos: vec![" ".to_string()],
+                        ..answer(&template, 1, ItemResult::Pass)
+                    }
+                )
+            ),
+            Err(InspectionReportError::InvalidPhoto("Tires".to_string()))
+        );
+    }
+}
@@ -0,0 +1,208 @@
+//! Represents a template of inspection: the items a driver checks on a vehicle.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * A template has at least one item, item labels are unique within it (case-insensitive).
+//! * A critical item puts the vehicle out of service when it fails.
+//! * Templates are not edited: a report refers to the items of its template by id, and keeps
+//!   their label and criticality as they were when it was submitted.
+//! * Template names are unique per organization.
+use crate::inspection::value_types::inspection_kind::InspectionKind;
+use chrono::{DateTime, Utc};
+use std::collections::HashSet;
+
+/// Longest template name, in characters.
+pub const MAX_NAME_LENGTH: usize = 100;
+/// Longest item label, in characters.
+pub const MAX_LABEL_LENGTH: usize = 200;
+/// Most items of a template.
+pub const MAX_ITEMS: usize = 100;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InspectionItem {
+    /// The identifier of the item, referenced by the answers.
+    pub id: uuid::Uuid,
+    /// What to check (e.g., "Service brakes").
+    pub label: String,
+    /// A failure puts the vehicle out of service.
+    pub critical: bool,
+    /// A photo is required, unless the item is not applicable.
+    pub photo_required: bool,
+    /// A comment describing the defect is required when the item fails.
+    pub comment_required_on_fail: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InspectionTemplate {
+    /// The unique identifier of the template.
+    pub id: uuid::Uuid,
+    pub name: String,
+    pub kind: InspectionKind,
+    /// The items, in the order they are checked.
+    pub items: Vec<InspectionItem>,
+    /// Created at timestamp.
+    pub created_at: DateTime<Utc>,
+    /// The user who created the template.
+    pub created_by: uuid::Uuid,
+}
+
+/// The values of an item of a template to create.
+#[derive(Debug, Clone)]
+pub struct NewInspectionItem {
+    pub label: String,
+    pub critical: bool,
+    pub photo_required: bool,
+    pub comment_required_on_fail: bool,
+}
+
+/// The values of a template to create.
+#[derive(Debug, Clone)]
+pub struct NewInspectionTemplate {
+    pub name: String,
+    pub kind: InspectionKind,
+    pub items: Vec<NewInspectionItem>,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum InspectionTemplateError {
+    #[error("Template name cannot be empty")]
+    EmptyName,
+    #[error("Template name too long: maximum {MAX_NAME_LENGTH} characters, got {0}")]
+    NameTooLong(usize),
+    #[error("A template needs at least one item")]
+    NoItems,
+    #[error("Too many items: maximum {MAX_ITEMS}, got {0}")]
+    TooManyItems(usize),
+    #[error("Label of item {0} cannot be empty")]
+    EmptyLabel(usize),
+    #[error("Label of item {0} too long: maximum {MAX_LABEL_LENGTH} characters")]
+    LabelTooLong(usize),
+    #[error("Duplicate item: {0}")]
+    DuplicateItem(String),
+}
+
+impl InspectionTemplate {
+    /// Creates a template, the items are numbered from 1 in the errors.
+    pub fn new(
+        template: NewInspectionTemplate,
+        created_by: uuid::Uuid,
+    ) -> Result<Self, InspectionTemplateError> {
+        let name = template.name.trim().to_string();
+        if name.is_empty() {
+            return Err(InspectionTemplateError::EmptyName);
+        }
+        let length = name.chars().count();
+        if length > MAX_NAME_LENGTH {
+            return Err(InspectionTemplateError::NameTooLong(length));
+        }
+        if template.items.is_empty() {
+            return Err(InspectionTemplateError::NoItems);
+        }
+        if template.items.len() > MAX_ITEMS {
+            return Err(InspectionTemplateError::TooManyItems(template.items.len()));
+        }
+
+        let mut labels = HashSet::new();
+        let mut items = Vec::with_capacity(template.items.len());
+        for (index, item) in template.items.into_iter().enumerate() {
+            let label = item.label.trim().to_string();
+            if label.is_empty() {
+                return Err(InspectionTemplateError::EmptyLabel(index + 1));
+            }
+            if label.chars().count() > MAX_LABEL_LENGTH {
+                return Err(InspectionTemplateError::LabelTooLong(index + 1));
+            }
+            if !labels.insert(label.to_lowercase()) {
+                return Err(InspectionTemplateError::DuplicateItem(label));
+            }
+            items.push(InspectionItem {
+                id: uuid::Uuid::new_v4(),
+                label,
+                critical: item.critical,
+                photo_required: item.photo_required,
+                comment_required_on_fail: item.comment_required_on_fail,
+            });
+        }
+
+        Ok(InspectionTemplate {
+            id: uuid::Uuid::new_v4(),
+            name,
+            kind: template.kind,
+            items,
+            created_at: Utc::now(),
+            created_by,
+        })
+    }
+
+    pub fn item(&self, id: uuid::Uuid) -> Option<&InspectionItem> {
+        self.items.iter().find(|item| item.id == id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(label: &str) -> NewInspectionItem {
+        NewInspectionItem {
+            label: label.to_string(),
+            critical: false,
+            photo_required: false,
+            comment_required_on_fail: true,
+        }
+    }
+
+    fn new_template(items: Vec<NewInspectionItem>) -> NewInspectionTemplate {
+        NewInspectionTemplate {
+            name: " Daily pre-trip ".to_string(),
+            kind: InspectionKind::PreTrip,
+            items,
+        }
+    }
+
+    #[test]
+    fn test_new_template() {
+        let user = uuid::Uuid::new_v4();
+        let template =
+            InspectionTemplate::new(new_template(vec![item(" Tires "), item("Lights")]), user)
+                .unwrap();
+        assert_eq!(template.name, "Daily pre-trip");
+        assert_eq!(template.items.len(), 2);
+        assert_eq!(template.items[0].label, "Tires");
+        assert_ne!(template.items[0].id, template.items[1].id);
+        assert_eq!(
+            template
+                .item(template.items[1].id)
+                .map(|item| item.label.as_str()),
+            Some("Lights")
+        );
+        assert_eq!(template.created_by, user);
+    }
+
+    #[test]
+    fn test_invalid_templates_are_rejected() {
+        let user = uuid::Uuid::new_v4();
+        assert_eq!(
+            InspectionTemplate::new(new_template(vec![]), user),
+            Err(InspectionTemplateError::NoItems)
+        );
+        assert_eq!(
+            InspectionTemplate::new(new_template(vec![item("Tires"), item(" ")]), user),
+            Err(InspectionTemplateError::EmptyLabel(2))
+        );
+        assert_eq!(
+            InspectionTemplate::new(new_template(vec![item("Tires"), item("tires")]), user),
+            Err(InspectionTemplateError::DuplicateItem("tires".to_string()))
+        );
+        assert_eq!(
+            InspectionTemplate::new(
+                NewInspectionTemplate {
+                    name: String::new(),
+                    ..new_template(vec![item("Tires")])
+                },
+                user
+            ),
+            Err(InspectionTemplateError::EmptyName)
+        );
+    }
+}
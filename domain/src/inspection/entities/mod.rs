@@ -0,0 +1,3 @@
+pub mod inspection_defect;
+pub mod inspection_report;
+pub mod inspection_template;
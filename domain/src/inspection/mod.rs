@@ -0,0 +1,16 @@
+//! Inspections: the daily vehicle inspections of the drivers (pre-trip and post-trip DVIRs).
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * An inspection follows a template of the organization: its items are answered pass, fail or
+//!   not applicable, each one exactly once. An item may require photos (unless not applicable)
+//!   and a comment when it fails.
+//! * A submitted report is tied to the vehicle status recorded with it (odometer, fuel level).
+//! * Each failed item opens a defect, open until a mechanic signs it off (repaired, or no repair
+//!   needed). Reports are never changed once submitted.
+//! * A vehicle with an open defect of a critical item is out of service
+//!   (see `services::out_of_service_rule`).
+pub mod entities;
+pub mod repositories;
+pub mod services;
+pub mod value_types;
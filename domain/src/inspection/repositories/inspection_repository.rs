@@ -0,0 +1,89 @@
+//! Repository for managing the inspection templates of an organization, the reports submitted
+//! with them and the defects they found.
+
+use crate::inspection::entities::{
+    inspection_defect::InspectionDefect, inspection_report::InspectionReport,
+    inspection_template::InspectionTemplate,
+};
+use std::future::Future;
+
+/// Errors that can occur when interacting with the inspection repository
+#[derive(Debug, thiserror::Error)]
+pub enum InspectionRepositoryError {
+    #[error("already exists: {0}")]
+    AlreadyExists(String),
+    #[error("database error: {0}")]
+    Database(String),
+}
+
+/// Repository interface for inspection operations
+pub trait InspectionRepository: Send + Sync {
+    /// Creates a template (`AlreadyExists` when the name is taken in the organization)
+    fn create_template(
+        &self,
+        organization_id: uuid::Uuid,
+        template: InspectionTemplate,
+    ) -> impl Future<Output = Result<InspectionTemplate, InspectionRepositoryError>> + Send;
+
+    /// Retrieves a template by its id
+    fn find_template(
+        &self,
+        organization_id: uuid::Uuid,
+        id: uuid::Uuid,
+    ) -> impl Future<Output = Result<Option<InspectionTemplate>, InspectionRepositoryError>> + Send;
+
+    /// Retrieves the templates, by name
+    fn find_templates(
+        &self,
+        organization_id: uuid::Uuid,
+    ) -> impl Future<Output = Result<Vec<InspectionTemplate>, InspectionRepositoryError>> + Send;
+
+    /// Stores a report with the defects it found, all of them or none
+    fn submit_report(
+        &self,
+        organization_id: uuid::Uuid,
+        report: InspectionReport,
+        defects: Vec<InspectionDefect>,
+    ) -> impl Future<Output = Result<InspectionReport, InspectionRepositoryError>> + Send;
+
+    /// Retrieves a report by its id
+    fn find_report(
+        &self,
+        organization_id: uuid::Uuid,
+        id: uuid::Uuid,
+    ) -> impl Future<Output = Result<Option<InspectionReport>, InspectionRepositoryError>> + Send;
+
+    /// Retrieves the reports of a vehicle submitted within the given range (both ends inclusive),
+    /// ordered by `submitted_at`
+    fn find_reports_by_vehicle(
+        &self,
+        organization_id: uuid::Uuid,
+        vehicle_id: uuid::Uuid,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl Future<Output = Result<Vec<InspectionReport>, InspectionRepositoryError>> + Send;
+
+    /// Retrieves a defect by its id
+    fn find_defect(
+        &self,
+        organization_id: uuid::Uuid,
+        id: uuid::Uuid,
+    ) -> impl Future<Output = Result<Option<InspectionDefect>, InspectionRepositoryError>> + Send;
+
+    /// Retrieves the defects of a vehicle, only the open ones when `open_only`, ordered by
+    /// `reported_at`
+    fn find_defects_by_vehicle(
+        &self,
+        organization_id: uuid::Uuid,
+        vehicle_id: uuid::Uuid,
+        open_only: bool,
+    ) -> impl Future<Output = Result<Vec<InspectionDefect>, InspectionRepositoryError>> + Send;
+
+    /// Saves the sign-off of a defect (`false` when there is no such defect, or it was already
+    /// signed off)
+    fn sign_off_defect(
+        &self,
+        organization_id: uuid::Uuid,
+        defect: InspectionDefect,
+    ) -> impl Future<Output = Result<bool, InspectionRepositoryError>> + Send;
+}
@@ -0,0 +1 @@
+pub mod inspection_repository;
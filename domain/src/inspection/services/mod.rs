@@ -0,0 +1 @@
+pub mod out_of_service_rule;
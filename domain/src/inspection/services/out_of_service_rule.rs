@@ -0,0 +1,73 @@
+//! The rule putting a vehicle out of service: an open defect of a critical item keeps it off
+//! the road until a mechanic signs the defect off.
+use crate::inspection::entities::{
+    inspection_defect::InspectionDefect, inspection_report::InspectionReport,
+};
+use chrono::{DateTime, Utc};
+
+/// Whether the report puts the vehicle out of service: a critical item failed.
+pub fn puts_out_of_service(report: &InspectionReport) -> bool {
+    report.failed().any(|answer| answer.critical)
+}
+
+/// Since when a vehicle is out of service, given its defects: the report of its oldest open
+/// critical defect. `None` when the vehicle is in service.
+pub fn out_of_service_since(defects: &[InspectionDefect]) -> Option<DateTime<Utc>> {
+    defects
+        .iter()
+        .filter(|defect| defect.critical && defect.is_open())
+        .map(|defect| defect.reported_at)
+        .min()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inspection::value_types::defect_resolution::DefectResolution;
+    use chrono::{Duration, TimeZone};
+
+    fn defect(critical: bool, day: i64) -> InspectionDefect {
+        InspectionDefect {
+            id: uuid::Uuid::new_v4(),
+            report_id: uuid::Uuid::new_v4(),
+            vehicle_id: uuid::Uuid::new_v4(),
+            item_id: uuid::Uuid::new_v4(),
+            label: "Service brakes".to_string(),
+            critical,
+            comment: None,
+            reported_at: Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap() + Duration::days(day),
+            resolution: None,
+            signed_off_at: None,
+            signed_off_by: None,
+            sign_off_notes: None,
+        }
+    }
+
+    #[test]
+    fn test_open_critical_defects_put_the_vehicle_out_of_service() {
+        assert_eq!(out_of_service_since(&[]), None);
+        assert_eq!(out_of_service_since(&[defect(false, 1)]), None);
+
+        let first = defect(true, 2);
+        let second = defect(true, 3);
+        assert_eq!(
+            out_of_service_since(&[defect(false, 1), second.clone(), first.clone()]),
+            Some(first.reported_at)
+        );
+
+        let mut repaired = first.clone();
+        repaired
+            .sign_off(
+                DefectResolution::Repaired,
+                repaired.reported_at,
+                uuid::Uuid::new_v4(),
+                None,
+            )
+            .unwrap();
+        assert_eq!(
+            out_of_service_since(&[repaired.clone(), second.clone()]),
+            Some(second.reported_at)
+        );
+        assert_eq!(out_of_service_since(&[repaired]), None);
+    }
+}
@@ -0,0 +1,66 @@
+//! Represents how a mechanic signed off a defect.
+
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DefectResolution {
+    /// The defect was repaired
+    Repaired,
+    /// The defect doesn't affect the safe operation of the vehicle
+    NoRepairNeeded,
+}
+
+impl DefectResolution {
+    pub fn as_str(&self) -> &str {
+        match self {
+            DefectResolution::Repaired => "repaired",
+            DefectResolution::NoRepairNeeded => "no_repair_needed",
+        }
+    }
+
+    /// Returns the value of the SQL `defect_resolution` enum
+    pub fn as_sql(&self) -> &str {
+        match self {
+            DefectResolution::Repaired => "Repaired",
+            DefectResolution::NoRepairNeeded => "NoRepairNeeded",
+        }
+    }
+}
+
+impl fmt::Display for DefectResolution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for DefectResolution {
+    type Err = String;
+
+    /// Accepts both the string and the SQL representations
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().replace('_', "").as_str() {
+            "repaired" => Ok(DefectResolution::Repaired),
+            "norepairneeded" => Ok(DefectResolution::NoRepairNeeded),
+            _ => Err(format!("Invalid defect resolution: {}", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            "no_repair_needed".parse(),
+            Ok(DefectResolution::NoRepairNeeded)
+        );
+        assert_eq!(
+            DefectResolution::NoRepairNeeded.as_sql().parse(),
+            Ok(DefectResolution::NoRepairNeeded)
+        );
+        assert!("fixed".parse::<DefectResolution>().is_err());
+    }
+}
@@ -0,0 +1,64 @@
+//! Represents when an inspection is performed: before or after a trip.
+
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InspectionKind {
+    /// Before driving the vehicle
+    PreTrip,
+    /// After driving the vehicle, reporting what came up on the road
+    PostTrip,
+}
+
+impl InspectionKind {
+    pub fn as_str(&self) -> &str {
+        match self {
+            InspectionKind::PreTrip => "pre_trip",
+            InspectionKind::PostTrip => "post_trip",
+        }
+    }
+
+    /// Returns the value of the SQL `inspection_kind` enum
+    pub fn as_sql(&self) -> &str {
+        match self {
+            InspectionKind::PreTrip => "PreTrip",
+            InspectionKind::PostTrip => "PostTrip",
+        }
+    }
+}
+
+impl fmt::Display for InspectionKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for InspectionKind {
+    type Err = String;
+
+    /// Accepts both the string and the SQL representations
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().replace(['_', '-'], "").as_str() {
+            "pretrip" => Ok(InspectionKind::PreTrip),
+            "posttrip" => Ok(InspectionKind::PostTrip),
+            _ => Err(format!("Invalid inspection kind: {}", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("pre_trip".parse(), Ok(InspectionKind::PreTrip));
+        assert_eq!("PostTrip".parse(), Ok(InspectionKind::PostTrip));
+        assert_eq!(
+            InspectionKind::PostTrip.as_sql().parse(),
+            Ok(InspectionKind::PostTrip)
+        );
+        assert!("daily".parse::<InspectionKind>().is_err());
+    }
+}
@@ -0,0 +1,58 @@
+//! Represents the answer to an item of an inspection.
+
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ItemResult {
+    Pass,
+    /// A defect was found
+    Fail,
+    /// The item doesn't apply to the vehicle (e.g., the coupling device of a van)
+    NotApplicable,
+}
+
+impl ItemResult {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ItemResult::Pass => "pass",
+            ItemResult::Fail => "fail",
+            ItemResult::NotApplicable => "na",
+        }
+    }
+}
+
+impl fmt::Display for ItemResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for ItemResult {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "pass" => Ok(ItemResult::Pass),
+            "fail" => Ok(ItemResult::Fail),
+            "na" | "n/a" | "not_applicable" => Ok(ItemResult::NotApplicable),
+            _ => Err(format!("Invalid item result: {}", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(" FAIL".parse(), Ok(ItemResult::Fail));
+        assert_eq!("N/A".parse(), Ok(ItemResult::NotApplicable));
+        assert_eq!(
+            ItemResult::NotApplicable.as_str().parse(),
+            Ok(ItemResult::NotApplicable)
+        );
+        assert!("ok".parse::<ItemResult>().is_err());
+    }
+}
@@ -0,0 +1,3 @@
+pub mod defect_resolution;
+pub mod inspection_kind;
+pub mod item_result;
@@ -1,3 +1,10 @@
+pub mod depot;
+pub mod diagnostics;
+pub mod fuel;
+pub mod inspection;
 pub mod user;
 pub mod maintenance;
+pub mod organization;
 pub mod vehicle;
+pub mod telematics;
+pub mod trip;
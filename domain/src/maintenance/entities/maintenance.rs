@@ -12,7 +12,6 @@ use crate::{
         value_types::maintenance_interval_type::MaintenanceIntervalType,
     },
     vehicle::entities::vehicle::VehicleIdentity,
-    user::entities::user::UserIdentity,
 };
 
 /// Represents the identity of a maintenance (DB record, non-hydrated).
@@ -60,10 +59,23 @@ impl Maintenance {
     pub fn new(
         maintenance_type: MaintenanceType,
         vehicle: VehicleIdentity,
-        created_by: UserIdentity,
+        created_by: uuid::Uuid,
         data: NewMaintenance,
     ) -> Result<Self, MaintenanceError>  {
-        if (data.red_threshold < 0 || data.red_threshold > 100) || (data.yellow_threshold < 0 || data.yellow_threshold > data.red_threshold) {
+        if maintenance_type.is_deprecated() {
+            return Err(MaintenanceError::Deprecated(maintenance_type.name().to_string()));
+        }
+        if !maintenance_type
+            .applicability()
+            .applies_to(vehicle.powertrain.engine_type())
+        {
+            return Err(MaintenanceError::NotApplicable {
+                maintenance_type: maintenance_type.name().to_string(),
+                engine_type: vehicle.powertrain.engine_type().to_string(),
+            });
+        }
+
+        if data.red_threshold > 100 || data.yellow_threshold > data.red_threshold {
             return Err(MaintenanceError::InvalidThreshold(
                 "Threshold values must be between 0 and 100".to_string(),
             ));
@@ -74,16 +86,17 @@ impl Maintenance {
                 id: 0, // This will be set by the database
                 vehicle_id: vehicle.id,
                 maintenance_type_id: maintenance_type.id(),
-                interval_type: MaintenanceIntervalType::from_str(&data.interval_type).ok_or(
-                    MaintenanceError::UnknownIntervalType(data.interval_type.clone()),
-                )?,
+                interval_type: data
+                    .interval_type
+                    .parse::<MaintenanceIntervalType>()
+                    .map_err(|_| MaintenanceError::UnknownIntervalType(data.interval_type.clone()))?,
                 interval_value: data.interval_value,
                 red_threshold: data.red_threshold,
                 yellow_threshold: data.yellow_threshold,
                 created_at: chrono::Utc::now(),
-                created_by: created_by.id,
+                created_by,
                 updated_at: chrono::Utc::now(),
-                updated_by: created_by.id, // Initially set to the creator
+                updated_by: created_by, // Initially set to the creator
             },
             maintenance_type,
             vehicle,
@@ -108,4 +121,75 @@ pub enum MaintenanceError {
     InvalidThreshold(String),
     #[error("Unknown maintenance interval type: {0}")]
     UnknownIntervalType(String),
-}
\ No newline at end of file
+    #[error("{0} is deprecated, no new rule can use it")]
+    Deprecated(String),
+    #[error("{maintenance_type} doesn't apply to a {engine_type} vehicle")]
+    NotApplicable {
+        maintenance_type: String,
+        engine_type: String,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        maintenance::value_types::maintenance_applicability::MaintenanceApplicability,
+        vehicle::value_types::{
+            country_code::CountryCode, engine_type::EngineType, license_plate::LicensePlate,
+            vehicle_vin::VehicleVin,
+        },
+    };
+
+    fn vehicle(engine_type: EngineType) -> VehicleIdentity {
+        VehicleIdentity {
+            id: uuid::Uuid::new_v4(),
+            make: "Toyota".to_string(),
+            model: "Camry".to_string(),
+            year: 2020,
+            vin: VehicleVin::new("1HGBH41JXMN109186").unwrap(),
+            license_plate: LicensePlate::new("123ABC02").unwrap(),
+            country: CountryCode::new("KZ").unwrap(),
+            powertrain: engine_type.into(),
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        }
+    }
+
+    fn oil_change() -> MaintenanceType {
+        MaintenanceType::new(
+            "Oil Change".to_string(),
+            String::new(),
+            None,
+            MaintenanceApplicability::CombustionEngine,
+        )
+        .unwrap()
+    }
+
+    fn data() -> NewMaintenance {
+        NewMaintenance {
+            interval_type: "Kilometers".to_string(),
+            interval_value: 10_000,
+            red_threshold: 95,
+            yellow_threshold: 80,
+        }
+    }
+
+    #[test]
+    fn test_rule_checks() {
+        let user_id = uuid::Uuid::new_v4();
+        let rule = Maintenance::new(oil_change(), vehicle(EngineType::Diesel), user_id, data());
+        assert!(rule.is_ok());
+        assert!(matches!(
+            Maintenance::new(oil_change(), vehicle(EngineType::Electric), user_id, data()),
+            Err(MaintenanceError::NotApplicable { .. })
+        ));
+
+        let mut deprecated = oil_change();
+        deprecated.set_deprecated_at(Some(chrono::Utc::now()));
+        assert!(matches!(
+            Maintenance::new(deprecated, vehicle(EngineType::Diesel), user_id, data()),
+            Err(MaintenanceError::Deprecated(_))
+        ));
+    }
+}
@@ -0,0 +1,290 @@
+//! Represents the cost of a performed maintenance, itemized as parts, labor, taxes and fees.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * All lines of a cost share the currency of the cost, amounts are never converted.
+//! * For labor lines, `quantity` is the number of hours and `unit_price` is the hourly rate.
+//! * Quantities and unit prices can't be negative; discounts are not supported yet.
+use crate::maintenance::value_types::{
+    currency::Currency,
+    money::{Money, MoneyError},
+};
+use rust_decimal::Decimal;
+use std::{fmt, str::FromStr};
+
+/// The kind of a cost line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MaintenanceCostLineKind {
+    Part,
+    Labor,
+    Tax,
+    Fee,
+}
+
+impl MaintenanceCostLineKind {
+    /// Returns the string representation of the line kind.
+    pub fn as_str(&self) -> &str {
+        match self {
+            MaintenanceCostLineKind::Part => "Part",
+            MaintenanceCostLineKind::Labor => "Labor",
+            MaintenanceCostLineKind::Tax => "Tax",
+            MaintenanceCostLineKind::Fee => "Fee",
+        }
+    }
+}
+
+impl fmt::Display for MaintenanceCostLineKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for MaintenanceCostLineKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Part" => Ok(MaintenanceCostLineKind::Part),
+            "Labor" => Ok(MaintenanceCostLineKind::Labor),
+            "Tax" => Ok(MaintenanceCostLineKind::Tax),
+            "Fee" => Ok(MaintenanceCostLineKind::Fee),
+            _ => Err(format!("Invalid maintenance cost line kind: {}", s)),
+        }
+    }
+}
+
+/// A single line item of a maintenance cost.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MaintenanceCostLine {
+    /// The kind of the line (part, labor, tax or fee).
+    pub kind: MaintenanceCostLineKind,
+    /// Free-text description (e.g., "Oil filter", "Mechanic work").
+    pub description: String,
+    /// Manufacturer part number, only meaningful for parts.
+    pub part_number: Option<String>,
+    /// Number of units, or number of hours for labor.
+    pub quantity: Decimal,
+    /// Price of a single unit, or hourly rate for labor.
+    pub unit_price: Money,
+}
+
+impl MaintenanceCostLine {
+    /// Returns `quantity * unit_price`
+    pub fn total(&self) -> Result<Money, MoneyError> {
+        self.unit_price.checked_mul(self.quantity)
+    }
+}
+
+/// Totals of a maintenance cost, grouped by line kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MaintenanceCostBreakdown {
+    pub parts: Money,
+    pub labor: Money,
+    pub taxes: Money,
+    pub fees: Money,
+    pub total: Money,
+    /// Total number of labor hours.
+    pub labor_hours: Decimal,
+}
+
+impl MaintenanceCostBreakdown {
+    /// Creates an empty breakdown in the given currency.
+    pub fn zero(currency: Currency) -> Self {
+        let zero = Money::zero(currency);
+        MaintenanceCostBreakdown {
+            parts: zero,
+            labor: zero,
+            taxes: zero,
+            fees: zero,
+            total: zero,
+            labor_hours: Decimal::ZERO,
+        }
+    }
+
+    /// Adds another breakdown of the same currency to this one.
+    pub fn checked_add(&self, other: &MaintenanceCostBreakdown) -> Result<Self, MoneyError> {
+        Ok(MaintenanceCostBreakdown {
+            parts: self.parts.checked_add(&other.parts)?,
+            labor: self.labor.checked_add(&other.labor)?,
+            taxes: self.taxes.checked_add(&other.taxes)?,
+            fees: self.fees.checked_add(&other.fees)?,
+            total: self.total.checked_add(&other.total)?,
+            labor_hours: self
+                .labor_hours
+                .checked_add(other.labor_hours)
+                .ok_or(MoneyError::Overflow)?,
+        })
+    }
+
+    pub fn currency(&self) -> Currency {
+        self.total.currency()
+    }
+}
+
+/// The itemized cost of a maintenance record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MaintenanceCost {
+    currency: Currency,
+    lines: Vec<MaintenanceCostLine>,
+    /// Reference of the invoice issued by an external workshop, if any.
+    invoice_reference: Option<String>,
+    breakdown: MaintenanceCostBreakdown,
+}
+
+impl MaintenanceCost {
+    /// Creates a new maintenance cost after validating its lines.
+    pub fn new(
+        currency: Currency,
+        lines: Vec<MaintenanceCostLine>,
+        invoice_reference: Option<String>,
+    ) -> Result<Self, MaintenanceCostError> {
+        let mut breakdown = MaintenanceCostBreakdown::zero(currency);
+
+        for (index, line) in lines.iter().enumerate() {
+            if line.unit_price.currency() != currency {
+                return Err(MaintenanceCostError::CurrencyMismatch {
+                    line: index,
+                    expected: currency,
+                    found: line.unit_price.currency(),
+                });
+            }
+            if line.quantity.is_sign_negative() || line.unit_price.is_negative() {
+                return Err(MaintenanceCostError::NegativeAmount { line: index });
+            }
+            if line.description.trim().is_empty() {
+                return Err(MaintenanceCostError::EmptyDescription { line: index });
+            }
+
+            let total = line.total()?;
+            let bucket = match line.kind {
+                MaintenanceCostLineKind::Part => &mut breakdown.parts,
+                MaintenanceCostLineKind::Labor => &mut breakdown.labor,
+                MaintenanceCostLineKind::Tax => &mut breakdown.taxes,
+                MaintenanceCostLineKind::Fee => &mut breakdown.fees,
+            };
+            *bucket = bucket.checked_add(&total)?;
+            breakdown.total = breakdown.total.checked_add(&total)?;
+            if line.kind == MaintenanceCostLineKind::Labor {
+                breakdown.labor_hours = breakdown
+                    .labor_hours
+                    .checked_add(line.quantity)
+                    .ok_or(MoneyError::Overflow)?;
+            }
+        }
+
+        let invoice_reference = invoice_reference
+            .map(|reference| reference.trim().to_string())
+            .filter(|reference| !reference.is_empty());
+
+        Ok(MaintenanceCost {
+            currency,
+            lines,
+            invoice_reference,
+            breakdown,
+        })
+    }
+
+    /* Getters */
+    pub fn currency(&self) -> Currency {
+        self.currency
+    }
+    pub fn lines(&self) -> &[MaintenanceCostLine] {
+        &self.lines
+    }
+    pub fn invoice_reference(&self) -> Option<&str> {
+        self.invoice_reference.as_deref()
+    }
+    pub fn breakdown(&self) -> &MaintenanceCostBreakdown {
+        &self.breakdown
+    }
+    pub fn total(&self) -> Money {
+        self.breakdown.total
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum MaintenanceCostError {
+    #[error("Line {line}: expected currency {expected}, found {found}")]
+    CurrencyMismatch {
+        line: usize,
+        expected: Currency,
+        found: Currency,
+    },
+    #[error("Line {line}: quantity and unit price can't be negative")]
+    NegativeAmount { line: usize },
+    #[error("Line {line}: description can't be empty")]
+    EmptyDescription { line: usize },
+    #[error("Invalid amount: {0}")]
+    Money(#[from] MoneyError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kzt() -> Currency {
+        Currency::new("KZT").unwrap()
+    }
+
+    fn line(kind: MaintenanceCostLineKind, quantity: &str, unit_price: &str) -> MaintenanceCostLine {
+        MaintenanceCostLine {
+            kind,
+            description: kind.to_string(),
+            part_number: None,
+            quantity: quantity.parse().unwrap(),
+            unit_price: Money::new(unit_price.parse().unwrap(), kzt()),
+        }
+    }
+
+    #[test]
+    fn test_breakdown() {
+        let cost = MaintenanceCost::new(
+            kzt(),
+            vec![
+                line(MaintenanceCostLineKind::Part, "2", "4500.50"),
+                line(MaintenanceCostLineKind::Labor, "1.5", "8000"),
+                line(MaintenanceCostLineKind::Tax, "1", "2100"),
+                line(MaintenanceCostLineKind::Fee, "1", "500"),
+            ],
+            Some("  INV-001 ".to_string()),
+        )
+        .unwrap();
+
+        let breakdown = cost.breakdown();
+        assert_eq!(breakdown.parts.amount(), "9001.00".parse().unwrap());
+        assert_eq!(breakdown.labor.amount(), "12000".parse().unwrap());
+        assert_eq!(breakdown.labor_hours, "1.5".parse().unwrap());
+        assert_eq!(cost.total().amount(), "23601".parse().unwrap());
+        assert_eq!(cost.invoice_reference(), Some("INV-001"));
+    }
+
+    #[test]
+    fn test_currency_mismatch() {
+        let mut usd_line = line(MaintenanceCostLineKind::Part, "1", "10");
+        usd_line.unit_price = Money::new(Decimal::TEN, Currency::new("USD").unwrap());
+
+        assert!(matches!(
+            MaintenanceCost::new(kzt(), vec![usd_line], None),
+            Err(MaintenanceCostError::CurrencyMismatch { line: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn test_negative_quantity() {
+        assert!(matches!(
+            MaintenanceCost::new(
+                kzt(),
+                vec![line(MaintenanceCostLineKind::Labor, "-1", "8000")],
+                None
+            ),
+            Err(MaintenanceCostError::NegativeAmount { line: 0 })
+        ));
+    }
+
+    #[test]
+    fn test_empty_cost() {
+        let cost = MaintenanceCost::new(kzt(), Vec::new(), Some(" ".to_string())).unwrap();
+        assert!(cost.total().amount().is_zero());
+        assert_eq!(cost.invoice_reference(), None);
+    }
+}
@@ -0,0 +1,199 @@
+//! Represents a default maintenance interval of the catalog (UC-040).
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * A template gives the default interval and thresholds of a maintenance type. It can be
+//!   narrowed to a make, a make and model, and/or an engine type; an unset scope field matches
+//!   every vehicle.
+//! * When several templates of the same maintenance type and interval type match a vehicle, the
+//!   most specific one wins (model > make > engine type > generic).
+//! * A maintenance type can have templates for several interval types (e.g., oil change every
+//!   10 000 km and every year), each one becomes a separate maintenance rule.
+use crate::{
+    maintenance::value_types::maintenance_interval_type::MaintenanceIntervalType,
+    vehicle::{entities::vehicle::VehicleIdentity, value_types::engine_type::EngineType},
+};
+
+/// The vehicles a template applies to.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MaintenanceTemplateScope {
+    /// Make of the vehicle (compared ignoring case).
+    pub make: Option<String>,
+    /// Model of the vehicle (compared ignoring case), requires a make.
+    pub model: Option<String>,
+    /// Engine type of the vehicle.
+    pub engine_type: Option<EngineType>,
+}
+
+impl MaintenanceTemplateScope {
+    /// Checks if the scope includes the vehicle.
+    pub fn matches(&self, vehicle: &VehicleIdentity) -> bool {
+        let same = |expected: &Option<String>, actual: &str| {
+            expected
+                .as_ref()
+                .is_none_or(|expected| expected.eq_ignore_ascii_case(actual))
+        };
+        same(&self.make, &vehicle.make)
+            && same(&self.model, &vehicle.model)
+            && self
+                .engine_type
+                .as_ref()
+                .is_none_or(|engine_type| engine_type == vehicle.powertrain.engine_type())
+    }
+
+    /// Checks if both scopes include the same vehicles (two templates of a maintenance type and an
+    /// interval type can't have the same scope).
+    pub fn same_as(&self, other: &MaintenanceTemplateScope) -> bool {
+        let same = |left: &Option<String>, right: &Option<String>| {
+            left.as_deref().unwrap_or_default().trim().to_lowercase()
+                == right.as_deref().unwrap_or_default().trim().to_lowercase()
+        };
+        same(&self.make, &other.make)
+            && same(&self.model, &other.model)
+            && self.engine_type == other.engine_type
+    }
+
+    /// Returns how specific the scope is, higher wins.
+    pub fn specificity(&self) -> u8 {
+        let mut specificity = 0;
+        if self.model.is_some() {
+            specificity += 4;
+        }
+        if self.make.is_some() {
+            specificity += 2;
+        }
+        if self.engine_type.is_some() {
+            specificity += 1;
+        }
+        specificity
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MaintenanceIntervalTemplate {
+    /// The unique identifier for the template (0 until it is persisted).
+    pub id: i32,
+    /// The maintenance type the template is for.
+    pub maintenance_type_id: i32,
+    /// The vehicles the template applies to.
+    pub scope: MaintenanceTemplateScope,
+    /// The interval type (e.g., kilometers, hours, by date).
+    pub interval_type: MaintenanceIntervalType,
+    /// Interval value (e.g., 10000 km, 500 hours, 1 year).
+    pub interval_value: u32,
+    /// Threshold (Yellow) value in percentage (e.g., 80%).
+    pub yellow_threshold: u32,
+    /// Threshold (Red) value in percentage (e.g., 95%).
+    pub red_threshold: u32,
+}
+
+impl MaintenanceIntervalTemplate {
+    /// Creates a new template, with the same threshold rules as a maintenance rule.
+    pub fn new(
+        maintenance_type_id: i32,
+        scope: MaintenanceTemplateScope,
+        data: NewMaintenanceIntervalTemplate,
+    ) -> Result<Self, MaintenanceIntervalTemplateError> {
+        if scope.model.is_some() && scope.make.is_none() {
+            return Err(MaintenanceIntervalTemplateError::ModelWithoutMake);
+        }
+        if data.interval_value == 0 {
+            return Err(MaintenanceIntervalTemplateError::InvalidInterval);
+        }
+        if data.red_threshold > 100 || data.yellow_threshold > data.red_threshold {
+            return Err(MaintenanceIntervalTemplateError::InvalidThreshold);
+        }
+
+        Ok(MaintenanceIntervalTemplate {
+            id: 0, // This will be set by the database
+            maintenance_type_id,
+            scope,
+            interval_type: data.interval_type.parse().map_err(|_| {
+                MaintenanceIntervalTemplateError::UnknownIntervalType(data.interval_type.clone())
+            })?,
+            interval_value: data.interval_value,
+            yellow_threshold: data.yellow_threshold,
+            red_threshold: data.red_threshold,
+        })
+    }
+}
+
+pub struct NewMaintenanceIntervalTemplate {
+    /// The interval type for the maintenance (e.g., kilometers, hours, by date).
+    pub interval_type: String,
+    /// Interval value (e.g., 10000 km, 500 hours, 1 year).
+    pub interval_value: u32,
+    /// Threshold (Yellow) value in percentage (e.g., 80%).
+    pub yellow_threshold: u32,
+    /// Threshold (Red) value in percentage (e.g., 95%).
+    pub red_threshold: u32,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum MaintenanceIntervalTemplateError {
+    #[error("A model can only be set together with a make")]
+    ModelWithoutMake,
+    #[error("Interval value must be positive")]
+    InvalidInterval,
+    #[error("Threshold values must be between 0 and 100, yellow below red")]
+    InvalidThreshold,
+    #[error("Unknown maintenance interval type: {0}")]
+    UnknownIntervalType(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data() -> NewMaintenanceIntervalTemplate {
+        NewMaintenanceIntervalTemplate {
+            interval_type: "Kilometers".to_string(),
+            interval_value: 10_000,
+            yellow_threshold: 80,
+            red_threshold: 95,
+        }
+    }
+
+    #[test]
+    fn test_validation() {
+        let model_only = MaintenanceTemplateScope {
+            model: Some("Camry".to_string()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            MaintenanceIntervalTemplate::new(1, model_only, data()),
+            Err(MaintenanceIntervalTemplateError::ModelWithoutMake)
+        ));
+
+        let thresholds = NewMaintenanceIntervalTemplate {
+            yellow_threshold: 96,
+            ..data()
+        };
+        assert!(matches!(
+            MaintenanceIntervalTemplate::new(1, Default::default(), thresholds),
+            Err(MaintenanceIntervalTemplateError::InvalidThreshold)
+        ));
+
+        let template = MaintenanceIntervalTemplate::new(1, Default::default(), data()).unwrap();
+        assert_eq!(template.interval_type, MaintenanceIntervalType::Kilometers);
+    }
+
+    #[test]
+    fn test_specificity() {
+        let make = MaintenanceTemplateScope {
+            make: Some("Toyota".to_string()),
+            ..Default::default()
+        };
+        let model = MaintenanceTemplateScope {
+            model: Some("Camry".to_string()),
+            ..make.clone()
+        };
+        let engine = MaintenanceTemplateScope {
+            engine_type: Some(EngineType::Diesel),
+            ..Default::default()
+        };
+        assert!(model.specificity() > make.specificity());
+        assert!(make.specificity() > engine.specificity());
+        assert!(engine.specificity() > MaintenanceTemplateScope::default().specificity());
+    }
+}
@@ -10,7 +10,7 @@
 //! TODO list:
 //! * Add validation rules for the details field (e.g., length, content)
 use crate::{
-    maintenance::entities::maintenance::MaintenanceIdentity,
+    maintenance::entities::{maintenance::MaintenanceIdentity, maintenance_cost::MaintenanceCost},
     user::entities::user::UserIdentity,
     vehicle::entities::{vehicle::VehicleIdentity, vehicle_status::VehicleStatusIdentity},
 };
@@ -34,6 +34,8 @@ pub struct MaintenanceRecordIdentity {
     pub performed_at: chrono::DateTime<chrono::Utc>,
     /// The details of the maintenance action performed.
     pub details: String,
+    /// The itemized cost of the maintenance action, if known.
+    pub cost: Option<MaintenanceCost>,
 
     /// The timestamp when the maintenance log entry was created.
     pub created_at: chrono::DateTime<chrono::Utc>,
@@ -72,6 +74,7 @@ impl MaintenanceRecord {
         vehicle_status: VehicleStatusIdentity,
         performed_at: chrono::DateTime<chrono::Utc>,
         details: String,
+        cost: Option<MaintenanceCost>,
     ) -> Self {
         let identity = MaintenanceRecordIdentity {
             id: uuid::Uuid::new_v4(),
@@ -81,8 +84,11 @@ impl MaintenanceRecord {
             vehicle_status_id: vehicle_status.id,
             performed_at,
             details,
+            cost,
             created_at: chrono::Utc::now(),
+            created_by: user.id,
             updated_at: chrono::Utc::now(),
+            updated_by: user.id,
         };
 
         Self {
@@ -104,4 +110,7 @@ impl MaintenanceRecord {
     pub fn details(&self) -> &str {
         &self.identity.details
     }
+    pub fn cost(&self) -> Option<&MaintenanceCost> {
+        self.identity.cost.as_ref()
+    }
 }
@@ -1,6 +1,284 @@
+//! Represents the due status of a maintenance rule at a given moment.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * Progress is measured from the last time the maintenance was performed towards
+//!   `last + interval`. If it was never performed, progress starts from zero (kilometers, engine
+//!   hours) or from the date the rule was created (years).
+//! * The level is derived from the thresholds of the rule: yellow and red are warnings, 100% and
+//!   above means the maintenance is overdue.
+use crate::{
+    maintenance::{
+        entities::{maintenance::MaintenanceIdentity, maintenance_record::MaintenanceRecord},
+        value_types::maintenance_interval_type::MaintenanceIntervalType,
+    },
+    vehicle::entities::vehicle_status::VehicleStatusIdentity,
+};
+use std::fmt;
+
+/// A reading expressed in the unit of a maintenance interval type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MaintenanceReading {
+    Kilometers(i64),
+    EngineHours(i64),
+    Date(chrono::NaiveDate),
+}
+
+impl fmt::Display for MaintenanceReading {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MaintenanceReading::Kilometers(value) => write!(f, "{} km", value),
+            MaintenanceReading::EngineHours(value) => write!(f, "{} h", value),
+            MaintenanceReading::Date(date) => write!(f, "{}", date.format("%Y-%m-%d")),
+        }
+    }
+}
+
+/// Urgency level of a maintenance rule, ordered from the least to the most urgent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MaintenanceStatusLevel {
+    /// Below the yellow threshold.
+    Green,
+    /// At or above the yellow threshold.
+    Yellow,
+    /// At or above the red threshold.
+    Red,
+    /// The interval has been fully used (100% or more).
+    Overdue,
+}
+
+impl MaintenanceStatusLevel {
+    /// Derives the level from a progress percentage and the rule thresholds.
+    pub fn from_percentage(percentage: u32, yellow_threshold: u32, red_threshold: u32) -> Self {
+        if percentage >= 100 {
+            MaintenanceStatusLevel::Overdue
+        } else if percentage >= red_threshold {
+            MaintenanceStatusLevel::Red
+        } else if percentage >= yellow_threshold {
+            MaintenanceStatusLevel::Yellow
+        } else {
+            MaintenanceStatusLevel::Green
+        }
+    }
+
+    /// Returns the string representation of the level.
+    pub fn as_str(&self) -> &str {
+        match self {
+            MaintenanceStatusLevel::Green => "green",
+            MaintenanceStatusLevel::Yellow => "yellow",
+            MaintenanceStatusLevel::Red => "red",
+            MaintenanceStatusLevel::Overdue => "overdue",
+        }
+    }
+}
+
+impl fmt::Display for MaintenanceStatusLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Due status of a maintenance rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct MaintenanceStatus {
-    last_value: String,
-    current_value: String,
-    next_value: String,
-    percentage: i32,
-}
\ No newline at end of file
+    /// The maintenance rule this status belongs to.
+    pub maintenance_id: i32,
+    /// Reading at which the interval started (last time the maintenance was performed).
+    pub last_value: MaintenanceReading,
+    /// Current reading of the vehicle.
+    pub current_value: MaintenanceReading,
+    /// Reading at which the maintenance becomes due.
+    pub next_value: MaintenanceReading,
+    /// Used part of the interval in percent (may exceed 100 when overdue).
+    pub percentage: u32,
+    /// Urgency level derived from the rule thresholds.
+    pub level: MaintenanceStatusLevel,
+}
+
+impl MaintenanceStatus {
+    /// Calculates the due status of a maintenance rule.
+    ///
+    /// # Arguments
+    /// * `rule` - The maintenance rule.
+    /// * `last_record` - The last time this maintenance was performed, if ever.
+    /// * `current_status` - The latest known status of the vehicle, if any.
+    /// * `today` - The date used for year-based intervals.
+    pub fn calculate(
+        rule: &MaintenanceIdentity,
+        last_record: Option<&MaintenanceRecord>,
+        current_status: Option<&VehicleStatusIdentity>,
+        today: chrono::NaiveDate,
+    ) -> Self {
+        let interval = i64::from(rule.interval_value);
+
+        let (last_value, current_value, next_value, elapsed, total) = match rule.interval_type {
+            MaintenanceIntervalType::Kilometers => {
+                let last = last_record.map_or(0, |r| i64::from(r.vehicle_status.odometer));
+                let current = current_status.map_or(last, |s| i64::from(s.odometer));
+                (
+                    MaintenanceReading::Kilometers(last),
+                    MaintenanceReading::Kilometers(current),
+                    MaintenanceReading::Kilometers(last + interval),
+                    current - last,
+                    interval,
+                )
+            }
+            MaintenanceIntervalType::EngineHours => {
+                let last = last_record
+                    .and_then(|r| r.vehicle_status.engine_hour_meter)
+                    .map_or(0, i64::from);
+                let current = current_status
+                    .and_then(|s| s.engine_hour_meter)
+                    .map_or(last, i64::from);
+                (
+                    MaintenanceReading::EngineHours(last),
+                    MaintenanceReading::EngineHours(current),
+                    MaintenanceReading::EngineHours(last + interval),
+                    current - last,
+                    interval,
+                )
+            }
+            MaintenanceIntervalType::Years => {
+                let last = last_record
+                    .map_or(rule.created_at, |r| r.performed_at())
+                    .date_naive();
+                let next = last
+                    .checked_add_months(chrono::Months::new(rule.interval_value.saturating_mul(12)))
+                    .unwrap_or(chrono::NaiveDate::MAX);
+                (
+                    MaintenanceReading::Date(last),
+                    MaintenanceReading::Date(today),
+                    MaintenanceReading::Date(next),
+                    (today - last).num_days(),
+                    (next - last).num_days(),
+                )
+            }
+        };
+
+        let percentage = if total <= 0 {
+            100
+        } else {
+            u32::try_from(elapsed.max(0) * 100 / total).unwrap_or(u32::MAX)
+        };
+
+        MaintenanceStatus {
+            maintenance_id: rule.id,
+            last_value,
+            current_value,
+            next_value,
+            percentage,
+            level: MaintenanceStatusLevel::from_percentage(
+                percentage,
+                rule.yellow_threshold,
+                rule.red_threshold,
+            ),
+        }
+    }
+
+    pub fn is_overdue(&self) -> bool {
+        self.level == MaintenanceStatusLevel::Overdue
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, TimeZone, Utc};
+
+    fn rule(interval_type: MaintenanceIntervalType, interval_value: u32) -> MaintenanceIdentity {
+        let created_at = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        MaintenanceIdentity {
+            id: 7,
+            vehicle_id: uuid::Uuid::nil(),
+            maintenance_type_id: 1,
+            interval_type,
+            interval_value,
+            red_threshold: 95,
+            yellow_threshold: 80,
+            created_at,
+            created_by: uuid::Uuid::nil(),
+            updated_at: created_at,
+            updated_by: uuid::Uuid::nil(),
+        }
+    }
+
+    fn status(odometer: i32, engine_hour_meter: Option<i32>) -> VehicleStatusIdentity {
+        let now = Utc::now();
+        VehicleStatusIdentity {
+            id: 1,
+            vehicle_id: uuid::Uuid::nil(),
+            performed_by: uuid::Uuid::nil(),
+            performed_at: now,
+            odometer,
+            engine_hour_meter,
+            fuel_level: None,
+            notes: String::new(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 6, 1).unwrap()
+    }
+
+    #[test]
+    fn test_never_performed_kilometers() {
+        let status = MaintenanceStatus::calculate(
+            &rule(MaintenanceIntervalType::Kilometers, 10_000),
+            None,
+            Some(&status(4_000, None)),
+            today(),
+        );
+        assert_eq!(status.last_value, MaintenanceReading::Kilometers(0));
+        assert_eq!(status.next_value, MaintenanceReading::Kilometers(10_000));
+        assert_eq!(status.percentage, 40);
+        assert_eq!(status.level, MaintenanceStatusLevel::Green);
+    }
+
+    #[test]
+    fn test_levels_follow_thresholds() {
+        let rule = rule(MaintenanceIntervalType::EngineHours, 100);
+        let at = |hours| {
+            MaintenanceStatus::calculate(&rule, None, Some(&status(0, Some(hours))), today()).level
+        };
+        assert_eq!(at(79), MaintenanceStatusLevel::Green);
+        assert_eq!(at(80), MaintenanceStatusLevel::Yellow);
+        assert_eq!(at(95), MaintenanceStatusLevel::Red);
+        assert_eq!(at(120), MaintenanceStatusLevel::Overdue);
+    }
+
+    #[test]
+    fn test_years_from_rule_creation() {
+        let status = MaintenanceStatus::calculate(
+            &rule(MaintenanceIntervalType::Years, 1),
+            None,
+            None,
+            NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+        );
+        assert_eq!(
+            status.next_value,
+            MaintenanceReading::Date(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap())
+        );
+        assert_eq!(status.percentage, 100);
+        assert!(status.is_overdue());
+    }
+
+    #[test]
+    fn test_missing_status_means_no_progress() {
+        let status = MaintenanceStatus::calculate(
+            &rule(MaintenanceIntervalType::Kilometers, 10_000),
+            None,
+            None,
+            today(),
+        );
+        assert_eq!(status.percentage, 0);
+    }
+
+    #[test]
+    fn test_display_reading() {
+        assert_eq!(MaintenanceReading::Kilometers(1500).to_string(), "1500 km");
+        assert_eq!(MaintenanceReading::EngineHours(20).to_string(), "20 h");
+        assert_eq!(MaintenanceReading::Date(today()).to_string(), "2024-06-01");
+    }
+}
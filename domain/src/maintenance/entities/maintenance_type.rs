@@ -4,18 +4,42 @@
 //! # General rules:
 //! * Maintenance types are used to categorize different maintenance actions (e.g., Oil Change, Tire
 //!   Rotation).
+//! * The applicability restricts the vehicles a maintenance type can be attached to (e.g., an oil
+//!   change needs a combustion engine).
+//! * The category groups maintenance types by the part of the vehicle they are about, it is
+//!   optional for the types created before categories existed.
+//! * The checklist lists the steps of the standard procedure (UC-038), blank steps are dropped.
+//! * A maintenance type referenced by maintenance rules or records can't be deleted, as the
+//!   history would go with it. It can be deprecated instead: it is hidden from the lists, keeps its
+//!   history, and no new rule can use it. Or it can be merged into another type.
 //!
 //! # Use cases:
 //! 1. Show the list of available maintenance types in the system.
 //!
-use crate::user::entities::user::UserIdentity;
+use crate::{
+    maintenance::value_types::{
+        maintenance_applicability::MaintenanceApplicability,
+        maintenance_category::MaintenanceCategory,
+    },
+    user::entities::user::UserIdentity,
+};
 
 #[derive(Debug, Clone)]
 pub struct MaintenanceType {
+    /// The unique identifier for the maintenance type (0 until it is persisted).
+    id: i32,
     /// The name of the maintenance type (e.g., Oil Change, Tire Rotation).
     name: String,
     /// Description of the maintenance type.
     description: String,
+    /// The category of the maintenance type (e.g., Engine, Brakes).
+    category: Option<MaintenanceCategory>,
+    /// The powertrains the maintenance type applies to.
+    applicability: MaintenanceApplicability,
+    /// Steps of the standard procedure (e.g., Drain the oil, Replace the filter).
+    checklist: Vec<String>,
+    /// When the maintenance type was deprecated, if it was.
+    deprecated_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
 #[derive(Debug, Clone)]
@@ -26,6 +50,14 @@ pub struct MaintenanceTypeView {
     pub name: String,
     /// Description of the maintenance type.
     pub description: String,
+    /// The category of the maintenance type (e.g., Engine, Brakes).
+    pub category: Option<MaintenanceCategory>,
+    /// The powertrains the maintenance type applies to.
+    pub applicability: MaintenanceApplicability,
+    /// Steps of the standard procedure.
+    pub checklist: Vec<String>,
+    /// When the maintenance type was deprecated, if it was.
+    pub deprecated_at: Option<chrono::DateTime<chrono::Utc>>,
     /// Created at timestamp.
     pub created_at: chrono::DateTime<chrono::Utc>,
     /// Created by user ID.
@@ -42,11 +74,30 @@ impl MaintenanceType {
     /// # Arguments
     /// * `name` - The name of the maintenance type.
     /// * `description` - The description of the maintenance type.
-    pub fn new(name: String, description: String) -> Result<Self, MaintenanceTypeError> {
+    /// * `category` - The category of the maintenance type, if any.
+    /// * `applicability` - The powertrains the maintenance type applies to.
+    pub fn new(
+        name: String,
+        description: String,
+        category: Option<MaintenanceCategory>,
+        applicability: MaintenanceApplicability,
+    ) -> Result<Self, MaintenanceTypeError> {
         if name.is_empty() {
             return Err(MaintenanceTypeError::EmptyField);
         }
-        Ok(MaintenanceType { name, description })
+        Ok(MaintenanceType {
+            id: 0, // This will be set by the database
+            name,
+            description,
+            category,
+            applicability,
+            checklist: Vec::new(),
+            deprecated_at: None,
+        })
+    }
+
+    pub fn id(&self) -> i32 {
+        self.id
     }
 
     pub fn name(&self) -> &str {
@@ -57,7 +108,31 @@ impl MaintenanceType {
         &self.description
     }
 
+    pub fn category(&self) -> Option<MaintenanceCategory> {
+        self.category
+    }
+
+    pub fn applicability(&self) -> MaintenanceApplicability {
+        self.applicability
+    }
+
+    pub fn checklist(&self) -> &[String] {
+        &self.checklist
+    }
+
+    pub fn deprecated_at(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.deprecated_at
+    }
+
+    pub fn is_deprecated(&self) -> bool {
+        self.deprecated_at.is_some()
+    }
+
     /* Setters */
+    pub fn set_id(&mut self, id: i32) {
+        self.id = id;
+    }
+
     pub fn set_name(&mut self, name: String) {
         self.name = name;
     }
@@ -65,6 +140,56 @@ impl MaintenanceType {
     pub fn set_description(&mut self, description: String) {
         self.description = description;
     }
+
+    pub fn set_category(&mut self, category: Option<MaintenanceCategory>) {
+        self.category = category;
+    }
+
+    pub fn set_applicability(&mut self, applicability: MaintenanceApplicability) {
+        self.applicability = applicability;
+    }
+
+    pub fn set_checklist(&mut self, checklist: Vec<String>) {
+        self.checklist = checklist
+            .into_iter()
+            .map(|step| step.trim().to_string())
+            .filter(|step| !step.is_empty())
+            .collect();
+    }
+
+    pub fn set_deprecated_at(&mut self, deprecated_at: Option<chrono::DateTime<chrono::Utc>>) {
+        self.deprecated_at = deprecated_at;
+    }
+}
+
+/// What references a maintenance type.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MaintenanceTypeUsage {
+    /// Number of maintenance rules (per vehicle) of the type.
+    pub rules: u64,
+    /// Number of maintenance records of these rules.
+    pub records: u64,
+}
+
+impl MaintenanceTypeUsage {
+    pub fn is_in_use(&self) -> bool {
+        self.rules > 0 || self.records > 0
+    }
+}
+
+/// What a merge of two maintenance types moved to the target type.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MaintenanceTypeMerge {
+    /// Rules re-pointed to the target type.
+    pub rules_moved: u64,
+    /// Rules removed because the vehicle already had a target rule with the same interval type,
+    /// their records were moved to that rule.
+    pub rules_merged: u64,
+    /// Records moved from a merged rule to the target rule.
+    pub records_moved: u64,
+    /// Default intervals re-pointed to the target type (duplicates of a target interval are
+    /// dropped).
+    pub templates_moved: u64,
 }
 
 #[derive(Debug, thiserror::Error)]
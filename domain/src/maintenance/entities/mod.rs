@@ -1,3 +1,6 @@
 pub mod maintenance;
+pub mod maintenance_cost;
+pub mod maintenance_interval_template;
 pub mod maintenance_record;
+pub mod maintenance_status;
 pub mod maintenance_type;
@@ -0,0 +1,47 @@
+//! Repository for managing the default maintenance intervals of the catalog.
+
+use crate::maintenance::entities::maintenance_interval_template::MaintenanceIntervalTemplate;
+use std::future::Future;
+
+/// Errors that can occur when interacting with the maintenance interval template repository
+#[derive(Debug, thiserror::Error)]
+pub enum MaintenanceIntervalTemplateRepositoryError {
+    #[error("database error: {0}")]
+    Database(String),
+}
+
+/// Repository interface for maintenance interval template operations
+pub trait MaintenanceIntervalTemplateRepository: Send + Sync {
+    /// Creates a new template
+    fn create(
+        &self,
+        organization_id: uuid::Uuid,
+        template: MaintenanceIntervalTemplate,
+        user_id: uuid::Uuid,
+    ) -> impl Future<
+        Output = Result<MaintenanceIntervalTemplate, MaintenanceIntervalTemplateRepositoryError>,
+    > + Send;
+
+    /// Retrieves all the templates of the catalog
+    fn find_all(
+        &self,
+        organization_id: uuid::Uuid,
+    ) -> impl Future<
+        Output = Result<
+            Vec<MaintenanceIntervalTemplate>,
+            MaintenanceIntervalTemplateRepositoryError,
+        >,
+    > + Send;
+
+    /// Retrieves the templates of a maintenance type
+    fn find_by_maintenance_type(
+        &self,
+        organization_id: uuid::Uuid,
+        maintenance_type_id: i32,
+    ) -> impl Future<
+        Output = Result<
+            Vec<MaintenanceIntervalTemplate>,
+            MaintenanceIntervalTemplateRepositoryError,
+        >,
+    > + Send;
+}
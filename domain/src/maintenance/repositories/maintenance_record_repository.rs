@@ -0,0 +1,38 @@
+//! Repository for managing maintenance records (the log of performed maintenance).
+
+use crate::maintenance::entities::maintenance_record::{MaintenanceRecord, MaintenanceRecordIdentity};
+use std::future::Future;
+
+/// Errors that can occur when interacting with the maintenance record repository
+#[derive(Debug, thiserror::Error)]
+pub enum MaintenanceRecordRepositoryError {
+    #[error("database error: {0}")]
+    Database(String),
+}
+
+/// Repository interface for maintenance record operations
+pub trait MaintenanceRecordRepository: Send + Sync {
+    /// Records a maintenance performed on a vehicle, with its itemized cost
+    fn create(
+        &self,
+        organization_id: uuid::Uuid,
+        record: MaintenanceRecordIdentity,
+    ) -> impl Future<Output = Result<MaintenanceRecordIdentity, MaintenanceRecordRepositoryError>> + Send;
+
+    /// Retrieves the records of a vehicle performed within the given range (both ends inclusive),
+    /// ordered by `performed_at`
+    fn find_by_vehicle(
+        &self,
+        organization_id: uuid::Uuid,
+        vehicle_id: uuid::Uuid,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl Future<Output = Result<Vec<MaintenanceRecord>, MaintenanceRecordRepositoryError>> + Send;
+
+    /// Retrieves the most recent record of a maintenance rule
+    fn find_latest_by_maintenance(
+        &self,
+        organization_id: uuid::Uuid,
+        maintenance_id: i32,
+    ) -> impl Future<Output = Result<Option<MaintenanceRecord>, MaintenanceRecordRepositoryError>> + Send;
+}
@@ -0,0 +1,35 @@
+//! Repository for managing maintenance rules (per vehicle and maintenance type).
+
+use crate::maintenance::entities::maintenance::Maintenance;
+use std::future::Future;
+
+/// Errors that can occur when interacting with the maintenance repository
+#[derive(Debug, thiserror::Error)]
+pub enum MaintenanceRepositoryError {
+    #[error("database error: {0}")]
+    Database(String),
+}
+
+/// Repository interface for maintenance rule operations
+pub trait MaintenanceRepository: Send + Sync {
+    /// Retrieves all maintenance rules of a vehicle, hydrated with their maintenance type
+    fn find_by_vehicle(
+        &self,
+        organization_id: uuid::Uuid,
+        vehicle_id: uuid::Uuid,
+    ) -> impl Future<Output = Result<Vec<Maintenance>, MaintenanceRepositoryError>> + Send;
+
+    /// Retrieves all maintenance rules of a maintenance type, hydrated with their vehicle
+    fn find_by_maintenance_type(
+        &self,
+        organization_id: uuid::Uuid,
+        maintenance_type_id: i32,
+    ) -> impl Future<Output = Result<Vec<Maintenance>, MaintenanceRepositoryError>> + Send;
+
+    /// Creates a new maintenance rule
+    fn create(
+        &self,
+        organization_id: uuid::Uuid,
+        maintenance: Maintenance,
+    ) -> impl Future<Output = Result<Maintenance, MaintenanceRepositoryError>> + Send;
+}
@@ -1,20 +1,25 @@
 //! Repository for managing maintenance types.
 
-use crate::maintenance::entities::maintenance_type::{MaintenanceType, MaintenanceTypeView};
+use crate::maintenance::entities::maintenance_type::{
+    MaintenanceType, MaintenanceTypeMerge, MaintenanceTypeUsage, MaintenanceTypeView,
+};
 use std::future::Future;
 
 /// Errors that can occur when interacting with the maintenance type repository
 #[derive(Debug, thiserror::Error)]
 pub enum MaintenanceTypeRepositoryError {
+    #[error("maintenance type already exists: {0}")]
+    AlreadyExists(String),
     #[error("database error: {0}")]
     Database(String),
 }
 
 /// Repository interface for maintenance type operations
 pub trait MaintenanceTypeRepository: Send + Sync {
-    /// Creates a new maintenance type
+    /// Creates a new maintenance type (`AlreadyExists` when the name is taken in the organization)
     fn create(
         &self,
+        organization_id: uuid::Uuid,
         maintenance_type: MaintenanceType,
         user_id: uuid::Uuid,
     ) -> impl Future<Output = Result<MaintenanceTypeView, MaintenanceTypeRepositoryError>> + Send;
@@ -22,36 +27,68 @@ pub trait MaintenanceTypeRepository: Send + Sync {
     /// Retrieves a maintenance type by ID
     fn get_by_id(
         &self,
+        organization_id: uuid::Uuid,
         id: i32,
     ) -> impl Future<Output = Result<Option<MaintenanceType>, MaintenanceTypeRepositoryError>> + Send;
 
     /// Retrieves a maintenance type view by ID
     fn get_view_by_id(
         &self,
+        organization_id: uuid::Uuid,
         id: i32,
     ) -> impl Future<Output = Result<Option<MaintenanceTypeView>, MaintenanceTypeRepositoryError>> + Send;
 
-    /// Retrieves all maintenance type views
+    /// Retrieves all maintenance type views, deprecated types excluded
     fn get_all_view(
         &self,
+        organization_id: uuid::Uuid,
     ) -> impl Future<Output = Result<Vec<MaintenanceTypeView>, MaintenanceTypeRepositoryError>> + Send;
 
-    /// Checks if a maintenance type exists by name
+    /// Checks if a maintenance type of the organization exists by name (deprecated types included)
     fn exists_by_name(
         &self,
+        organization_id: uuid::Uuid,
         name: &str,
     ) -> impl Future<Output = Result<bool, MaintenanceTypeRepositoryError>> + Send;
 
-    /// Updates an existing maintenance type
+    /// Updates an existing maintenance type and returns its refreshed view (`AlreadyExists` when
+    /// the new name is taken)
     fn update(
         &self,
+        organization_id: uuid::Uuid,
         maintenance_type: MaintenanceType,
         user_id: uuid::Uuid,
     ) -> impl Future<Output = Result<MaintenanceTypeView, MaintenanceTypeRepositoryError>> + Send;
 
-    /// Deletes a maintenance type
+    /// Counts the rules and records referencing a maintenance type
+    fn usage(
+        &self,
+        organization_id: uuid::Uuid,
+        id: i32,
+    ) -> impl Future<Output = Result<MaintenanceTypeUsage, MaintenanceTypeRepositoryError>> + Send;
+
+    /// Deprecates a maintenance type, keeping its rules and records
+    fn deprecate(
+        &self,
+        organization_id: uuid::Uuid,
+        maintenance_type: MaintenanceType,
+        user_id: uuid::Uuid,
+    ) -> impl Future<Output = Result<MaintenanceTypeView, MaintenanceTypeRepositoryError>> + Send;
+
+    /// Re-points the rules, records and default intervals of `source` to `target`, then deletes
+    /// `source`, in a single transaction
+    fn merge(
+        &self,
+        organization_id: uuid::Uuid,
+        source: MaintenanceType,
+        target: MaintenanceType,
+        user_id: uuid::Uuid,
+    ) -> impl Future<Output = Result<MaintenanceTypeMerge, MaintenanceTypeRepositoryError>> + Send;
+
+    /// Deletes a maintenance type (the database refuses it while rules reference it)
     fn delete(
         &self,
+        organization_id: uuid::Uuid,
         maintenance_type: MaintenanceType,
         user_id: uuid::Uuid,
     ) -> impl Future<Output = Result<(), MaintenanceTypeRepositoryError>> + Send;
@@ -1,2 +1,4 @@
-// pub mod maintenance_repository;
+pub mod maintenance_interval_template_repository;
+pub mod maintenance_record_repository;
+pub mod maintenance_repository;
 pub mod maintenance_type_repository;
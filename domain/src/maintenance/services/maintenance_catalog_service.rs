@@ -0,0 +1,138 @@
+//! Selects the default maintenance intervals of the catalog that apply to a vehicle (UC-040).
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * Only the templates whose scope matches the vehicle are considered.
+//! * For every maintenance type and interval type, the most specific template is kept; two
+//!   templates with the same specificity are resolved by the lowest id (the oldest one).
+use crate::{
+    maintenance::{
+        entities::maintenance_interval_template::MaintenanceIntervalTemplate,
+        value_types::maintenance_interval_type::MaintenanceIntervalType,
+    },
+    vehicle::entities::vehicle::VehicleIdentity,
+};
+use std::collections::HashMap;
+
+/// Returns the templates to apply to the vehicle, sorted by maintenance type and interval type.
+pub fn select_templates<'a>(
+    templates: &'a [MaintenanceIntervalTemplate],
+    vehicle: &VehicleIdentity,
+) -> Vec<&'a MaintenanceIntervalTemplate> {
+    let mut selected: HashMap<(i32, MaintenanceIntervalType), &MaintenanceIntervalTemplate> =
+        HashMap::new();
+
+    for template in templates.iter().filter(|t| t.scope.matches(vehicle)) {
+        let key = (template.maintenance_type_id, template.interval_type.clone());
+        match selected.get(&key) {
+            Some(current)
+                if (current.scope.specificity(), -current.id)
+                    >= (template.scope.specificity(), -template.id) => {}
+            _ => {
+                selected.insert(key, template);
+            }
+        }
+    }
+
+    let mut selected: Vec<_> = selected.into_values().collect();
+    selected.sort_by_key(|t| (t.maintenance_type_id, t.interval_type.as_str().to_string()));
+    selected
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        maintenance::entities::maintenance_interval_template::{
+            MaintenanceTemplateScope, NewMaintenanceIntervalTemplate,
+        },
+        vehicle::value_types::{
+            country_code::CountryCode, engine_type::EngineType, license_plate::LicensePlate,
+            vehicle_vin::VehicleVin,
+        },
+    };
+
+    fn vehicle(engine_type: EngineType) -> VehicleIdentity {
+        VehicleIdentity {
+            id: uuid::Uuid::new_v4(),
+            make: "Toyota".to_string(),
+            model: "Camry".to_string(),
+            year: 2020,
+            vin: VehicleVin::new("1HGBH41JXMN109186").unwrap(),
+            license_plate: LicensePlate::new("123ABC02").unwrap(),
+            country: CountryCode::new("KZ").unwrap(),
+            powertrain: engine_type.into(),
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        }
+    }
+
+    fn template(
+        id: i32,
+        maintenance_type_id: i32,
+        scope: MaintenanceTemplateScope,
+        interval_value: u32,
+    ) -> MaintenanceIntervalTemplate {
+        let mut template = MaintenanceIntervalTemplate::new(
+            maintenance_type_id,
+            scope,
+            NewMaintenanceIntervalTemplate {
+                interval_type: "Kilometers".to_string(),
+                interval_value,
+                yellow_threshold: 80,
+                red_threshold: 95,
+            },
+        )
+        .unwrap();
+        template.id = id;
+        template
+    }
+
+    #[test]
+    fn test_most_specific_template_wins() {
+        let toyota = MaintenanceTemplateScope {
+            make: Some("TOYOTA".to_string()),
+            ..Default::default()
+        };
+        let camry = MaintenanceTemplateScope {
+            model: Some("camry".to_string()),
+            ..toyota.clone()
+        };
+        let lexus = MaintenanceTemplateScope {
+            make: Some("Lexus".to_string()),
+            ..Default::default()
+        };
+        let templates = [
+            template(1, 1, Default::default(), 10_000),
+            template(2, 1, toyota, 12_000),
+            template(3, 1, camry, 15_000),
+            template(4, 1, lexus, 20_000),
+            template(5, 2, Default::default(), 40_000),
+        ];
+
+        let selected = select_templates(&templates, &vehicle(EngineType::Gasoline));
+        let ids: Vec<_> = selected.iter().map(|t| t.id).collect();
+        assert_eq!(ids, [3, 5]);
+    }
+
+    #[test]
+    fn test_engine_type_scope() {
+        let diesel = MaintenanceTemplateScope {
+            engine_type: Some(EngineType::Diesel),
+            ..Default::default()
+        };
+        let templates = [
+            template(1, 1, Default::default(), 10_000),
+            template(2, 1, diesel, 8_000),
+        ];
+
+        assert_eq!(
+            select_templates(&templates, &vehicle(EngineType::Diesel))[0].id,
+            2
+        );
+        assert_eq!(
+            select_templates(&templates, &vehicle(EngineType::Gasoline))[0].id,
+            1
+        );
+    }
+}
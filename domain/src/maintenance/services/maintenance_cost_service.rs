@@ -0,0 +1,145 @@
+//! Aggregation of maintenance costs (per vehicle, per maintenance type, per month, ...).
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * Costs are grouped by a key chosen by the caller AND by currency, as amounts of different
+//!   currencies are never summed up.
+//! * Records without a cost are ignored.
+use crate::{
+    maintenance::{
+        entities::maintenance_cost::{MaintenanceCost, MaintenanceCostBreakdown},
+        value_types::{
+            currency::Currency,
+            money::{Money, MoneyError},
+        },
+    },
+    vehicle::entities::vehicle_status::VehicleStatusIdentity,
+};
+use rust_decimal::Decimal;
+use std::collections::BTreeMap;
+
+/// Aggregated costs of a group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MaintenanceCostTotals {
+    pub breakdown: MaintenanceCostBreakdown,
+    /// Number of maintenance records with a cost in the group.
+    pub record_count: usize,
+}
+
+impl MaintenanceCostTotals {
+    pub fn currency(&self) -> Currency {
+        self.breakdown.currency()
+    }
+
+    /// Returns the total cost per kilometer, `None` if no distance was travelled
+    pub fn per_kilometer(&self, distance_km: i64) -> Option<Money> {
+        if distance_km <= 0 {
+            return None;
+        }
+        self.breakdown
+            .total
+            .checked_div(Decimal::from(distance_km))
+            .map(|money| money.round_dp(4))
+    }
+}
+
+/// Sums maintenance costs by key and currency.
+#[derive(Debug, Clone)]
+pub struct MaintenanceCostAggregator<K: Ord> {
+    groups: BTreeMap<(K, Currency), MaintenanceCostTotals>,
+}
+
+impl<K: Ord> Default for MaintenanceCostAggregator<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord> MaintenanceCostAggregator<K> {
+    pub fn new() -> Self {
+        MaintenanceCostAggregator {
+            groups: BTreeMap::new(),
+        }
+    }
+
+    /// Adds the cost of a record to the group `key`
+    pub fn add(&mut self, key: K, cost: &MaintenanceCost) -> Result<(), MoneyError> {
+        let totals = self
+            .groups
+            .entry((key, cost.currency()))
+            .or_insert_with(|| MaintenanceCostTotals {
+                breakdown: MaintenanceCostBreakdown::zero(cost.currency()),
+                record_count: 0,
+            });
+        totals.breakdown = totals.breakdown.checked_add(cost.breakdown())?;
+        totals.record_count += 1;
+        Ok(())
+    }
+
+    /// Returns the totals ordered by key, then by currency
+    pub fn into_totals(self) -> Vec<(K, MaintenanceCostTotals)> {
+        self.groups
+            .into_iter()
+            .map(|((key, _), totals)| (key, totals))
+            .collect()
+    }
+}
+
+/// Returns the distance travelled between the lowest and the highest odometer reading
+pub fn distance_travelled(statuses: &[VehicleStatusIdentity]) -> i64 {
+    let odometers = statuses.iter().map(|status| i64::from(status.odometer));
+    match (odometers.clone().min(), odometers.max()) {
+        (Some(min), Some(max)) => max - min,
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::maintenance::entities::maintenance_cost::{
+        MaintenanceCostLine, MaintenanceCostLineKind,
+    };
+
+    fn cost(currency: &str, amount: i64) -> MaintenanceCost {
+        let currency = Currency::new(currency).unwrap();
+        MaintenanceCost::new(
+            currency,
+            vec![MaintenanceCostLine {
+                kind: MaintenanceCostLineKind::Part,
+                description: "Oil filter".to_string(),
+                part_number: None,
+                quantity: Decimal::ONE,
+                unit_price: Money::new(Decimal::from(amount), currency),
+            }],
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_groups_by_key_and_currency() {
+        let mut aggregator = MaintenanceCostAggregator::new();
+        aggregator.add("b", &cost("KZT", 100)).unwrap();
+        aggregator.add("a", &cost("KZT", 50)).unwrap();
+        aggregator.add("b", &cost("KZT", 20)).unwrap();
+        aggregator.add("b", &cost("USD", 5)).unwrap();
+
+        let totals = aggregator.into_totals();
+        assert_eq!(totals.len(), 3);
+        assert_eq!(totals[0].0, "a");
+        assert_eq!(totals[1].1.breakdown.total.amount(), Decimal::from(120));
+        assert_eq!(totals[1].1.record_count, 2);
+        assert_eq!(totals[2].1.currency().code(), "USD");
+    }
+
+    #[test]
+    fn test_per_kilometer() {
+        let mut aggregator = MaintenanceCostAggregator::new();
+        aggregator.add((), &cost("KZT", 1000)).unwrap();
+        let (_, totals) = aggregator.into_totals().remove(0);
+
+        assert_eq!(totals.per_kilometer(400).unwrap().amount(), Decimal::new(25, 1));
+        assert!(totals.per_kilometer(0).is_none());
+    }
+}
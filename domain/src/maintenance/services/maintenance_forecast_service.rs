@@ -0,0 +1,379 @@
+//! Forecast of the dates on which maintenance rules will become due, based on usage trends.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * The usage rate (km/day, engine hours/day) is computed from the vehicle statuses of the
+//!   look-back window: the daily rate of every pair of consecutive statuses is computed, outliers
+//!   are rejected with Tukey's fences (IQR), and the remaining intervals are averaged, weighted by
+//!   their duration.
+//! * Intervals where the reading goes backwards are ignored (odometer replaced, typo, ...).
+//! * Kilometer and engine hour rules are projected with the rate; year rules are calendar based
+//!   and don't need a rate.
+//! * A threshold that is already reached is forecast for today.
+use crate::{
+    maintenance::{
+        entities::maintenance_status::{MaintenanceReading, MaintenanceStatus},
+        value_types::maintenance_interval_type::MaintenanceIntervalType,
+    },
+    vehicle::entities::vehicle_status::VehicleStatusIdentity,
+};
+use chrono::NaiveDate;
+
+/// Tuning of the usage rate estimation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UsageTrendSettings {
+    /// Number of days of history used to compute the rates.
+    pub look_back_days: u32,
+    /// Multiplier of the interquartile range beyond which a rate is an outlier (1.5 = Tukey).
+    pub outlier_factor: f64,
+    /// Minimum number of intervals required before outliers are rejected.
+    pub min_intervals_for_outliers: usize,
+}
+
+impl Default for UsageTrendSettings {
+    fn default() -> Self {
+        UsageTrendSettings {
+            look_back_days: 90,
+            outlier_factor: 1.5,
+            min_intervals_for_outliers: 4,
+        }
+    }
+}
+
+/// Average daily usage of a vehicle.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct UsageRates {
+    /// Kilometers per day, `None` if not enough history.
+    pub km_per_day: Option<f64>,
+    /// Engine hours per day, `None` if not enough history or no hour meter.
+    pub engine_hours_per_day: Option<f64>,
+}
+
+/// Projected dates of a maintenance rule reaching its thresholds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MaintenanceForecast {
+    /// The maintenance rule this forecast belongs to.
+    pub maintenance_id: i32,
+    /// Date on which the yellow threshold is reached.
+    pub yellow_at: Option<NaiveDate>,
+    /// Date on which the red threshold is reached.
+    pub red_at: Option<NaiveDate>,
+    /// Date on which the maintenance is due (100%).
+    pub due_at: Option<NaiveDate>,
+}
+
+/// Estimates the daily usage of a vehicle from its status history.
+///
+/// # Arguments
+/// * `statuses` - Status history of the vehicle, in any order.
+/// * `now` - End of the look-back window.
+pub fn estimate_usage(
+    statuses: &[VehicleStatusIdentity],
+    now: chrono::DateTime<chrono::Utc>,
+    settings: &UsageTrendSettings,
+) -> UsageRates {
+    let window_start = now - chrono::Duration::days(i64::from(settings.look_back_days));
+    let mut statuses: Vec<&VehicleStatusIdentity> = statuses
+        .iter()
+        .filter(|status| status.performed_at >= window_start && status.performed_at <= now)
+        .collect();
+    statuses.sort_by_key(|status| status.performed_at);
+
+    let km_samples = samples(&statuses, |status| Some(status.odometer));
+    let hour_samples = samples(&statuses, |status| status.engine_hour_meter);
+
+    UsageRates {
+        km_per_day: average_rate(km_samples, settings),
+        engine_hours_per_day: average_rate(hour_samples, settings),
+    }
+}
+
+/// Projects the dates on which a rule reaches its thresholds.
+///
+/// # Arguments
+/// * `status` - The current due status of the rule.
+/// * `interval_type` - The interval type of the rule.
+/// * `yellow_threshold`, `red_threshold` - Thresholds of the rule, in percent.
+/// * `rates` - Usage rates of the vehicle.
+/// * `today` - The date the forecast is made on.
+pub fn forecast(
+    status: &MaintenanceStatus,
+    interval_type: &MaintenanceIntervalType,
+    yellow_threshold: u32,
+    red_threshold: u32,
+    rates: &UsageRates,
+    today: NaiveDate,
+) -> MaintenanceForecast {
+    let at = |percentage: u32| match (status.last_value, status.current_value, status.next_value) {
+        (
+            MaintenanceReading::Date(last),
+            MaintenanceReading::Date(_),
+            MaintenanceReading::Date(next),
+        ) => {
+            let days = (next - last).num_days() * i64::from(percentage) / 100;
+            let date = last + chrono::Duration::days(days);
+            Some(date.max(today))
+        }
+        (
+            MaintenanceReading::Kilometers(last) | MaintenanceReading::EngineHours(last),
+            MaintenanceReading::Kilometers(current) | MaintenanceReading::EngineHours(current),
+            MaintenanceReading::Kilometers(next) | MaintenanceReading::EngineHours(next),
+        ) => {
+            let rate = match interval_type {
+                MaintenanceIntervalType::Kilometers => rates.km_per_day,
+                MaintenanceIntervalType::EngineHours => rates.engine_hours_per_day,
+                MaintenanceIntervalType::Years => None,
+            };
+            let target = last + (next - last) * i64::from(percentage) / 100;
+            project(current, target, rate, today)
+        }
+        _ => None,
+    };
+
+    MaintenanceForecast {
+        maintenance_id: status.maintenance_id,
+        yellow_at: at(yellow_threshold),
+        red_at: at(red_threshold),
+        due_at: at(100),
+    }
+}
+
+/// A reading at a moment, in days since the first sample.
+struct Sample {
+    day: f64,
+    value: i64,
+}
+
+fn samples(
+    statuses: &[&VehicleStatusIdentity],
+    reading: impl Fn(&VehicleStatusIdentity) -> Option<i32>,
+) -> Vec<Sample> {
+    let Some(first) = statuses.first() else {
+        return Vec::new();
+    };
+    statuses
+        .iter()
+        .filter_map(|status| {
+            reading(status).map(|value| Sample {
+                day: (status.performed_at - first.performed_at).num_seconds() as f64 / 86_400.0,
+                value: i64::from(value),
+            })
+        })
+        .collect()
+}
+
+/// Weighted average of the daily rates of consecutive samples, outliers excluded
+fn average_rate(samples: Vec<Sample>, settings: &UsageTrendSettings) -> Option<f64> {
+    // (rate, duration in days) of every valid interval
+    let intervals: Vec<(f64, f64)> = samples
+        .windows(2)
+        .filter_map(|pair| {
+            let days = pair[1].day - pair[0].day;
+            let delta = pair[1].value - pair[0].value;
+            (days > 0.0 && delta >= 0).then(|| (delta as f64 / days, days))
+        })
+        .collect();
+    if intervals.is_empty() {
+        return None;
+    }
+
+    let kept: Vec<(f64, f64)> = if intervals.len() >= settings.min_intervals_for_outliers {
+        let mut rates: Vec<f64> = intervals.iter().map(|(rate, _)| *rate).collect();
+        rates.sort_by(f64::total_cmp);
+        let q1 = quantile(&rates, 0.25);
+        let q3 = quantile(&rates, 0.75);
+        let margin = (q3 - q1) * settings.outlier_factor;
+        intervals
+            .into_iter()
+            .filter(|(rate, _)| *rate >= q1 - margin && *rate <= q3 + margin)
+            .collect()
+    } else {
+        intervals
+    };
+
+    let total_days: f64 = kept.iter().map(|(_, days)| days).sum();
+    let total_delta: f64 = kept.iter().map(|(rate, days)| rate * days).sum();
+    (total_days > 0.0).then(|| total_delta / total_days)
+}
+
+/// Linear interpolation quantile of sorted values
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let position = (sorted.len() - 1) as f64 * q;
+    let lower = position.floor() as usize;
+    let upper = position.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (position - lower as f64)
+}
+
+/// Date on which `target` is reached from `current` at `rate` per day
+fn project(current: i64, target: i64, rate: Option<f64>, today: NaiveDate) -> Option<NaiveDate> {
+    let remaining = target - current;
+    if remaining <= 0 {
+        return Some(today);
+    }
+    let rate = rate.filter(|rate| *rate > 0.0)?;
+    let days = (remaining as f64 / rate).ceil();
+    // Don't forecast absurdly far dates (e.g., a vehicle barely used)
+    if days > 36_500.0 {
+        return None;
+    }
+    today.checked_add_days(chrono::Days::new(days as u64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::maintenance::entities::maintenance_status::MaintenanceStatusLevel;
+    use chrono::{Duration, TimeZone, Utc};
+
+    fn now() -> chrono::DateTime<chrono::Utc> {
+        Utc.with_ymd_and_hms(2025, 6, 1, 12, 0, 0).unwrap()
+    }
+
+    fn status(days_ago: i64, odometer: i32, hours: Option<i32>) -> VehicleStatusIdentity {
+        let at = now() - Duration::days(days_ago);
+        VehicleStatusIdentity {
+            id: 1,
+            vehicle_id: uuid::Uuid::nil(),
+            performed_by: uuid::Uuid::nil(),
+            performed_at: at,
+            odometer,
+            engine_hour_meter: hours,
+            fuel_level: None,
+            notes: String::new(),
+            created_at: at,
+            updated_at: at,
+        }
+    }
+
+    fn km_status(last: i64, current: i64, next: i64) -> MaintenanceStatus {
+        MaintenanceStatus {
+            maintenance_id: 3,
+            last_value: MaintenanceReading::Kilometers(last),
+            current_value: MaintenanceReading::Kilometers(current),
+            next_value: MaintenanceReading::Kilometers(next),
+            percentage: 0,
+            level: MaintenanceStatusLevel::Green,
+        }
+    }
+
+    #[test]
+    fn test_steady_usage() {
+        let statuses = vec![
+            status(30, 1000, Some(10)),
+            status(20, 2000, None),
+            status(0, 4000, Some(70)),
+        ];
+        let rates = estimate_usage(&statuses, now(), &UsageTrendSettings::default());
+
+        assert_eq!(rates.km_per_day, Some(100.0));
+        assert_eq!(rates.engine_hours_per_day, Some(2.0));
+    }
+
+    #[test]
+    fn test_look_back_window() {
+        let statuses = vec![
+            status(200, 0, None),
+            status(10, 5000, None),
+            status(0, 5500, None),
+        ];
+        let rates = estimate_usage(&statuses, now(), &UsageTrendSettings::default());
+        assert_eq!(rates.km_per_day, Some(50.0));
+    }
+
+    #[test]
+    fn test_outliers_are_rejected() {
+        // 50 km/day, except one day with a 3000 km typo
+        let statuses = vec![
+            status(6, 0, None),
+            status(5, 50, None),
+            status(4, 100, None),
+            status(3, 3100, None),
+            status(2, 3150, None),
+            status(1, 3200, None),
+            status(0, 3250, None),
+        ];
+        let rates = estimate_usage(&statuses, now(), &UsageTrendSettings::default());
+        assert_eq!(rates.km_per_day, Some(50.0));
+    }
+
+    #[test]
+    fn test_rollbacks_are_ignored() {
+        let statuses = vec![
+            status(2, 1000, None),
+            status(1, 900, None),
+            status(0, 1000, None),
+        ];
+        let rates = estimate_usage(&statuses, now(), &UsageTrendSettings::default());
+        assert_eq!(rates.km_per_day, Some(100.0));
+    }
+
+    #[test]
+    fn test_no_history() {
+        let rates = estimate_usage(
+            &[status(0, 1000, None)],
+            now(),
+            &UsageTrendSettings::default(),
+        );
+        assert_eq!(rates, UsageRates::default());
+    }
+
+    #[test]
+    fn test_forecast_kilometers() {
+        let today = now().date_naive();
+        let rates = UsageRates {
+            km_per_day: Some(100.0),
+            engine_hours_per_day: None,
+        };
+        let forecast = forecast(
+            &km_status(10_000, 17_000, 20_000),
+            &MaintenanceIntervalType::Kilometers,
+            80,
+            95,
+            &rates,
+            today,
+        );
+
+        assert_eq!(forecast.yellow_at, Some(today + Duration::days(10)));
+        assert_eq!(forecast.red_at, Some(today + Duration::days(25)));
+        assert_eq!(forecast.due_at, Some(today + Duration::days(30)));
+    }
+
+    #[test]
+    fn test_forecast_without_rate() {
+        let today = now().date_naive();
+        let forecast = forecast(
+            &km_status(0, 9_000, 10_000),
+            &MaintenanceIntervalType::Kilometers,
+            80,
+            95,
+            &UsageRates::default(),
+            today,
+        );
+        assert_eq!(forecast.yellow_at, Some(today));
+        assert_eq!(forecast.due_at, None);
+    }
+
+    #[test]
+    fn test_forecast_years() {
+        let today = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+        let status = MaintenanceStatus {
+            maintenance_id: 1,
+            last_value: MaintenanceReading::Date(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()),
+            current_value: MaintenanceReading::Date(today),
+            next_value: MaintenanceReading::Date(NaiveDate::from_ymd_opt(2026, 1, 1).unwrap()),
+            percentage: 16,
+            level: MaintenanceStatusLevel::Green,
+        };
+        let forecast = forecast(
+            &status,
+            &MaintenanceIntervalType::Years,
+            80,
+            95,
+            &UsageRates::default(),
+            today,
+        );
+
+        assert_eq!(forecast.due_at, NaiveDate::from_ymd_opt(2026, 1, 1));
+        assert_eq!(forecast.yellow_at, NaiveDate::from_ymd_opt(2025, 10, 20));
+    }
+}
@@ -0,0 +1,3 @@
+pub mod maintenance_catalog_service;
+pub mod maintenance_cost_service;
+pub mod maintenance_forecast_service;
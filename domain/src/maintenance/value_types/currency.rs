@@ -0,0 +1,91 @@
+//! Represents an ISO 4217 currency code (e.g., KZT, USD, EUR).
+
+use std::fmt;
+use std::str::FromStr;
+
+/// A three-letter ISO 4217 currency code, stored uppercase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Currency {
+    code: [u8; 3],
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CurrencyError {
+    #[error("Invalid currency code: {0}")]
+    InvalidCode(String),
+}
+
+impl Currency {
+    /// Creates a new Currency after validating the code
+    pub fn new(value: impl Into<String>) -> Result<Self, CurrencyError> {
+        let value = value.into();
+        let normalized = value.trim().to_uppercase();
+
+        let code: [u8; 3] = normalized
+            .as_bytes()
+            .try_into()
+            .map_err(|_| CurrencyError::InvalidCode(value.clone()))?;
+        if !code.iter().all(|c| c.is_ascii_uppercase()) {
+            return Err(CurrencyError::InvalidCode(value));
+        }
+
+        Ok(Self { code })
+    }
+
+    /// Returns the currency code as a string
+    pub fn code(&self) -> &str {
+        // Only ASCII letters are accepted by the constructor
+        std::str::from_utf8(&self.code).unwrap_or_default()
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl FromStr for Currency {
+    type Err = CurrencyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl TryFrom<String> for Currency {
+    type Error = CurrencyError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_currency() {
+        let currency = Currency::new("KZT").unwrap();
+        assert_eq!(currency.code(), "KZT");
+    }
+
+    #[test]
+    fn test_lowercase_conversion() {
+        let currency = Currency::new(" usd ").unwrap();
+        assert_eq!(currency.code(), "USD");
+    }
+
+    #[test]
+    fn test_invalid_length() {
+        assert!(Currency::new("EURO").is_err());
+        assert!(Currency::new("").is_err());
+    }
+
+    #[test]
+    fn test_invalid_characters() {
+        assert!(Currency::new("U5D").is_err());
+        assert!(Currency::new("ТГ1").is_err());
+    }
+}
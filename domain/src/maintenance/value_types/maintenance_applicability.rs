@@ -0,0 +1,131 @@
+//! Represents which powertrains a maintenance type applies to.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * A maintenance rule can only be attached to a vehicle whose engine type the maintenance type
+//!   applies to (no oil change on a battery electric vehicle).
+//! * Unknown engine types (`Other`) accept every maintenance type.
+
+use crate::vehicle::value_types::engine_type::EngineType;
+use std::fmt;
+use std::str::FromStr;
+
+/// The part of the powertrain a maintenance type needs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MaintenanceApplicability {
+    /// Any vehicle (tires, brakes, inspection...)
+    #[default]
+    Any,
+    /// Vehicles with an internal combustion engine (oil change, spark plugs...)
+    CombustionEngine,
+    /// Vehicles with an electric motor and a traction battery (battery health check...)
+    ElectricDrive,
+    /// Vehicles running on CNG or LPG (gas cylinder inspection...)
+    GasSystem,
+    /// Hydrogen fuel cell vehicles (fuel cell stack inspection...)
+    FuelCell,
+}
+
+impl MaintenanceApplicability {
+    /// Returns the string representation of the applicability
+    pub fn as_str(&self) -> &str {
+        match self {
+            MaintenanceApplicability::Any => "any",
+            MaintenanceApplicability::CombustionEngine => "combustion_engine",
+            MaintenanceApplicability::ElectricDrive => "electric_drive",
+            MaintenanceApplicability::GasSystem => "gas_system",
+            MaintenanceApplicability::FuelCell => "fuel_cell",
+        }
+    }
+
+    /// Returns the value of the SQL `maintenance_applicability` enum
+    pub fn as_sql(&self) -> &str {
+        match self {
+            MaintenanceApplicability::Any => "Any",
+            MaintenanceApplicability::CombustionEngine => "CombustionEngine",
+            MaintenanceApplicability::ElectricDrive => "ElectricDrive",
+            MaintenanceApplicability::GasSystem => "GasSystem",
+            MaintenanceApplicability::FuelCell => "FuelCell",
+        }
+    }
+
+    /// Checks if a maintenance type with this applicability can be done on the engine type
+    pub fn applies_to(&self, engine_type: &EngineType) -> bool {
+        if matches!(engine_type, EngineType::Other(_)) {
+            return true;
+        }
+        match self {
+            MaintenanceApplicability::Any => true,
+            MaintenanceApplicability::CombustionEngine => engine_type.has_combustion_engine(),
+            MaintenanceApplicability::ElectricDrive => {
+                engine_type.is_electric_powered() || *engine_type == EngineType::Hydrogen
+            }
+            MaintenanceApplicability::GasSystem => {
+                matches!(engine_type, EngineType::Cng | EngineType::Lpg)
+            }
+            MaintenanceApplicability::FuelCell => *engine_type == EngineType::Hydrogen,
+        }
+    }
+}
+
+impl fmt::Display for MaintenanceApplicability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for MaintenanceApplicability {
+    type Err = String;
+
+    /// Accepts both the string and the SQL representations
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s
+            .trim()
+            .to_lowercase()
+            .replace([' ', '-', '_'], "")
+            .as_str()
+        {
+            "any" => Ok(MaintenanceApplicability::Any),
+            "combustionengine" => Ok(MaintenanceApplicability::CombustionEngine),
+            "electricdrive" => Ok(MaintenanceApplicability::ElectricDrive),
+            "gassystem" => Ok(MaintenanceApplicability::GasSystem),
+            "fuelcell" => Ok(MaintenanceApplicability::FuelCell),
+            _ => Err(format!("Invalid maintenance applicability: {}", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_applies_to() {
+        let oil_change = MaintenanceApplicability::CombustionEngine;
+        assert!(oil_change.applies_to(&EngineType::Diesel));
+        assert!(oil_change.applies_to(&EngineType::PluginHybrid));
+        assert!(!oil_change.applies_to(&EngineType::Electric));
+        assert!(oil_change.applies_to(&EngineType::Other("rotary".to_string())));
+
+        let battery_check = MaintenanceApplicability::ElectricDrive;
+        assert!(battery_check.applies_to(&EngineType::Hybrid));
+        assert!(!battery_check.applies_to(&EngineType::Gasoline));
+
+        assert!(MaintenanceApplicability::GasSystem.applies_to(&EngineType::Lpg));
+        assert!(!MaintenanceApplicability::FuelCell.applies_to(&EngineType::Electric));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for applicability in [
+            MaintenanceApplicability::Any,
+            MaintenanceApplicability::CombustionEngine,
+            MaintenanceApplicability::ElectricDrive,
+            MaintenanceApplicability::GasSystem,
+            MaintenanceApplicability::FuelCell,
+        ] {
+            assert_eq!(applicability.as_str().parse(), Ok(applicability));
+            assert_eq!(applicability.as_sql().parse(), Ok(applicability));
+        }
+    }
+}
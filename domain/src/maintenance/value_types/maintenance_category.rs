@@ -0,0 +1,97 @@
+//! Represents the category of a maintenance type (UC-039).
+
+use std::fmt;
+use std::str::FromStr;
+
+/// Category grouping maintenance types by the part of the vehicle they are about
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MaintenanceCategory {
+    /// Engine and drivetrain (oil, filters, belts, coolant...)
+    Engine,
+    /// Brake pads, discs and fluid
+    Brakes,
+    /// Tire rotation, replacement, seasonal change
+    Tires,
+    /// Body, glass, wipers, corrosion treatment
+    Body,
+    /// Batteries, lights, wiring, charging system
+    Electrical,
+    /// Periodic and regulatory inspections
+    Inspection,
+}
+
+impl MaintenanceCategory {
+    /// Returns the string representation of the category
+    pub fn as_str(&self) -> &str {
+        match self {
+            MaintenanceCategory::Engine => "engine",
+            MaintenanceCategory::Brakes => "brakes",
+            MaintenanceCategory::Tires => "tires",
+            MaintenanceCategory::Body => "body",
+            MaintenanceCategory::Electrical => "electrical",
+            MaintenanceCategory::Inspection => "inspection",
+        }
+    }
+
+    /// Returns the value of the SQL `maintenance_category` enum
+    pub fn as_sql(&self) -> &str {
+        match self {
+            MaintenanceCategory::Engine => "Engine",
+            MaintenanceCategory::Brakes => "Brakes",
+            MaintenanceCategory::Tires => "Tires",
+            MaintenanceCategory::Body => "Body",
+            MaintenanceCategory::Electrical => "Electrical",
+            MaintenanceCategory::Inspection => "Inspection",
+        }
+    }
+
+    /// Returns all the categories
+    pub fn all() -> [MaintenanceCategory; 6] {
+        [
+            MaintenanceCategory::Engine,
+            MaintenanceCategory::Brakes,
+            MaintenanceCategory::Tires,
+            MaintenanceCategory::Body,
+            MaintenanceCategory::Electrical,
+            MaintenanceCategory::Inspection,
+        ]
+    }
+}
+
+impl fmt::Display for MaintenanceCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for MaintenanceCategory {
+    type Err = String;
+
+    /// Accepts both the string and the SQL representations
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "engine" => Ok(MaintenanceCategory::Engine),
+            "brakes" => Ok(MaintenanceCategory::Brakes),
+            "tires" | "tyres" => Ok(MaintenanceCategory::Tires),
+            "body" => Ok(MaintenanceCategory::Body),
+            "electrical" => Ok(MaintenanceCategory::Electrical),
+            "inspection" => Ok(MaintenanceCategory::Inspection),
+            _ => Err(format!("Invalid maintenance category: {}", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        for category in MaintenanceCategory::all() {
+            assert_eq!(category.as_str().parse(), Ok(category));
+            assert_eq!(category.as_sql().parse(), Ok(category));
+        }
+        assert_eq!("Tyres".parse(), Ok(MaintenanceCategory::Tires));
+        assert!("paint".parse::<MaintenanceCategory>().is_err());
+    }
+}
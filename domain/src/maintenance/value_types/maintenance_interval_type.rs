@@ -1,5 +1,7 @@
+use std::str::FromStr;
+
 /// Represents the different types of maintenance intervals.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum MaintenanceIntervalType {
     Kilometers,
     EngineHours,
@@ -15,14 +17,18 @@ impl MaintenanceIntervalType {
             MaintenanceIntervalType::Years => "Years",
         }
     }
+}
+
+impl FromStr for MaintenanceIntervalType {
+    type Err = String;
 
     /// Returns the interval type from a string representation.
-    pub fn from_str(s: &str) -> Option<Self> {
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "Kilometers" => Some(MaintenanceIntervalType::Kilometers),
-            "Engine Hours" => Some(MaintenanceIntervalType::EngineHours),
-            "Years" => Some(MaintenanceIntervalType::Years),
-            _ => None,
+            "Kilometers" => Ok(MaintenanceIntervalType::Kilometers),
+            "Engine Hours" => Ok(MaintenanceIntervalType::EngineHours),
+            "Years" => Ok(MaintenanceIntervalType::Years),
+            _ => Err(format!("Invalid interval type: {}", s)),
         }
     }
 }
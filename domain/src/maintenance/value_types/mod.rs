@@ -1 +1,5 @@
+pub mod currency;
+pub mod maintenance_applicability;
+pub mod maintenance_category;
 pub mod maintenance_interval_type;
+pub mod money;
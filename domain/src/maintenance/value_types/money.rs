@@ -0,0 +1,138 @@
+//! Represents an exact amount of money in a given currency.
+
+use crate::maintenance::value_types::currency::Currency;
+use rust_decimal::Decimal;
+use std::fmt;
+
+/// An exact decimal amount of money with its currency.
+///
+/// Amounts of different currencies are never combined: arithmetic between them is an error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Money {
+    amount: Decimal,
+    currency: Currency,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum MoneyError {
+    #[error("Currency mismatch: expected {expected}, found {found}")]
+    CurrencyMismatch { expected: Currency, found: Currency },
+    #[error("Amount overflow")]
+    Overflow,
+}
+
+impl Money {
+    pub fn new(amount: Decimal, currency: Currency) -> Self {
+        Self { amount, currency }
+    }
+
+    pub fn zero(currency: Currency) -> Self {
+        Self::new(Decimal::ZERO, currency)
+    }
+
+    pub fn amount(&self) -> Decimal {
+        self.amount
+    }
+
+    pub fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.amount.is_sign_negative() && !self.amount.is_zero()
+    }
+
+    /// Adds two amounts of the same currency
+    pub fn checked_add(&self, other: &Money) -> Result<Money, MoneyError> {
+        if self.currency != other.currency {
+            return Err(MoneyError::CurrencyMismatch {
+                expected: self.currency,
+                found: other.currency,
+            });
+        }
+        let amount = self
+            .amount
+            .checked_add(other.amount)
+            .ok_or(MoneyError::Overflow)?;
+        Ok(Money::new(amount, self.currency))
+    }
+
+    /// Multiplies the amount by a factor (e.g., a quantity or a number of hours)
+    pub fn checked_mul(&self, factor: Decimal) -> Result<Money, MoneyError> {
+        let amount = self
+            .amount
+            .checked_mul(factor)
+            .ok_or(MoneyError::Overflow)?;
+        Ok(Money::new(amount, self.currency))
+    }
+
+    /// Divides the amount by a factor, returns `None` when dividing by zero
+    pub fn checked_div(&self, divisor: Decimal) -> Option<Money> {
+        self.amount
+            .checked_div(divisor)
+            .map(|amount| Money::new(amount, self.currency))
+    }
+
+    /// Returns the amount rounded to the given number of decimal places (half away from zero)
+    pub fn round_dp(&self, decimal_places: u32) -> Money {
+        let amount = self.amount.round_dp_with_strategy(
+            decimal_places,
+            rust_decimal::RoundingStrategy::MidpointAwayFromZero,
+        );
+        Money::new(amount, self.currency)
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.2} {}", self.amount, self.currency)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn kzt(amount: &str) -> Money {
+        Money::new(Decimal::from_str(amount).unwrap(), Currency::new("KZT").unwrap())
+    }
+
+    #[test]
+    fn test_exact_addition() {
+        let sum = kzt("0.1").checked_add(&kzt("0.2")).unwrap();
+        assert_eq!(sum, kzt("0.3"));
+    }
+
+    #[test]
+    fn test_currency_mismatch() {
+        let usd = Money::zero(Currency::new("USD").unwrap());
+        assert!(matches!(
+            kzt("1").checked_add(&usd),
+            Err(MoneyError::CurrencyMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_multiplication() {
+        let labor = kzt("7500").checked_mul(Decimal::from_str("1.5").unwrap()).unwrap();
+        assert_eq!(labor, kzt("11250"));
+    }
+
+    #[test]
+    fn test_division_by_zero() {
+        assert!(kzt("10").checked_div(Decimal::ZERO).is_none());
+        assert_eq!(kzt("10").checked_div(Decimal::from(4)).unwrap(), kzt("2.5"));
+    }
+
+    #[test]
+    fn test_rounding() {
+        assert_eq!(kzt("2.345").round_dp(2), kzt("2.35"));
+        assert_eq!(kzt("-2.345").round_dp(2), kzt("-2.35"));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(kzt("1500.5").to_string(), "1500.50 KZT");
+    }
+}
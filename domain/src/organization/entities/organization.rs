@@ -0,0 +1,74 @@
+//! Represents an organization (tenant) owning vehicles, maintenance types and users.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * The name is trimmed, required and unique (across organizations).
+use uuid::Uuid;
+
+/// Maximum length of an organization name, in characters.
+pub const MAX_NAME_LENGTH: usize = 100;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Organization {
+    /// The unique identifier of the organization (the tenant id of its rows).
+    pub id: Uuid,
+    /// The name of the organization.
+    pub name: String,
+    /// Created at timestamp.
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    /// Updated at timestamp.
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl Organization {
+    /// Creates a new organization, with a new id.
+    pub fn new(name: &str) -> Result<Self, OrganizationError> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(OrganizationError::EmptyName);
+        }
+        if name.chars().count() > MAX_NAME_LENGTH {
+            return Err(OrganizationError::NameTooLong(MAX_NAME_LENGTH));
+        }
+
+        let now = chrono::Utc::now();
+        Ok(Organization {
+            id: Uuid::new_v4(),
+            name: name.to_string(),
+            created_at: now,
+            updated_at: now,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum OrganizationError {
+    #[error("Organization name is required")]
+    EmptyName,
+    #[error("Organization name is longer than {0} characters")]
+    NameTooLong(usize),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_name_is_trimmed() {
+        let organization = Organization::new("  Steppe Logistics ").unwrap();
+        assert_eq!(organization.name, "Steppe Logistics");
+    }
+
+    #[test]
+    fn test_invalid_names() {
+        assert_eq!(
+            Organization::new("   ").unwrap_err(),
+            OrganizationError::EmptyName
+        );
+        assert_eq!(
+            Organization::new(&"a".repeat(MAX_NAME_LENGTH + 1)).unwrap_err(),
+            OrganizationError::NameTooLong(MAX_NAME_LENGTH)
+        );
+        assert!(Organization::new(&"é".repeat(MAX_NAME_LENGTH)).is_ok());
+    }
+}
@@ -0,0 +1,11 @@
+//! Organizations (tenants): the client companies whose fleets are managed.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * Every row belongs to an organization, and every repository call is scoped to one: the
+//!   `organization_id` parameter. A row of another organization is never read or written, it is
+//!   as if it didn't exist (e.g., a vehicle of another organization is not found).
+//! * Unique keys are per organization (VIN, license plate per country, maintenance type name).
+//! * The organization of a call is the one of the authenticated user, never an input.
+pub mod entities;
+pub mod repositories;
@@ -0,0 +1 @@
+pub mod organization_repository;
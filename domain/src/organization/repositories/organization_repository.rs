@@ -0,0 +1,28 @@
+//! Repository for managing organizations (tenants).
+
+use crate::organization::entities::organization::Organization;
+use std::future::Future;
+
+/// Errors that can occur when interacting with the organization repository
+#[derive(Debug, thiserror::Error)]
+pub enum OrganizationRepositoryError {
+    #[error("organization already exists: {0}")]
+    AlreadyExists(String),
+    #[error("database error: {0}")]
+    Database(String),
+}
+
+/// Repository interface for organization operations, the only one not scoped to an organization
+pub trait OrganizationRepository: Send + Sync {
+    /// Creates an organization, returns `AlreadyExists` when the name is taken
+    fn create(
+        &self,
+        organization: Organization,
+    ) -> impl Future<Output = Result<Organization, OrganizationRepositoryError>> + Send;
+
+    /// Retrieves an organization by its id
+    fn find_by_id(
+        &self,
+        id: uuid::Uuid,
+    ) -> impl Future<Output = Result<Option<Organization>, OrganizationRepositoryError>> + Send;
+}
@@ -0,0 +1,2 @@
+pub mod telematics_device;
+pub mod telematics_reading;
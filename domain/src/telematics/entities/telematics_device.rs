@@ -0,0 +1,22 @@
+//! Represents a telematics device fitted in a vehicle.
+use crate::telematics::value_types::device_id::DeviceId;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TelematicsDevice {
+    /// The id the device reports itself with.
+    pub device_id: DeviceId,
+    /// The vehicle the device is fitted in.
+    pub vehicle_id: uuid::Uuid,
+    /// When the device was registered.
+    pub registered_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl TelematicsDevice {
+    pub fn new(device_id: DeviceId, vehicle_id: uuid::Uuid) -> Self {
+        TelematicsDevice {
+            device_id,
+            vehicle_id,
+            registered_at: chrono::Utc::now(),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+//! Represents a reading of a telematics device: the meters of a vehicle at a moment.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * A reading has at least an odometer or an engine-hour value; the values are never negative
+//!   and the fuel level is a percentage.
+//! * The moment is the one the device measured at, not the one the reading was received at.
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TelematicsReading {
+    /// When the device took the reading.
+    pub recorded_at: DateTime<Utc>,
+    /// Odometer, in kilometers.
+    pub odometer: Option<i32>,
+    /// Engine hour meter, in hours.
+    pub engine_hours: Option<i32>,
+    /// Fuel level, in percent.
+    pub fuel_level: Option<i32>,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum TelematicsReadingError {
+    #[error("Reading has neither an odometer nor an engine-hour value")]
+    NoMeter,
+    #[error("Negative {0} value")]
+    Negative(&'static str),
+    #[error("Fuel level out of range (0-100): {0}")]
+    FuelLevelOutOfRange(i32),
+}
+
+impl TelematicsReading {
+    pub fn new(
+        recorded_at: DateTime<Utc>,
+        odometer: Option<i32>,
+        engine_hours: Option<i32>,
+        fuel_level: Option<i32>,
+    ) -> Result<Self, TelematicsReadingError> {
+        if odometer.is_none() && engine_hours.is_none() {
+            return Err(TelematicsReadingError::NoMeter);
+        }
+        if odometer.is_some_and(|value| value < 0) {
+            return Err(TelematicsReadingError::Negative("odometer"));
+        }
+        if engine_hours.is_some_and(|value| value < 0) {
+            return Err(TelematicsReadingError::Negative("engine hours"));
+        }
+        if let Some(level) = fuel_level.filter(|level| !(0..=100).contains(level)) {
+            return Err(TelematicsReadingError::FuelLevelOutOfRange(level));
+        }
+
+        Ok(TelematicsReading {
+            recorded_at,
+            odometer,
+            engine_hours,
+            fuel_level,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reading_needs_a_meter() {
+        assert_eq!(
+            TelematicsReading::new(Utc::now(), None, None, Some(40)),
+            Err(TelematicsReadingError::NoMeter)
+        );
+    }
+
+    #[test]
+    fn test_negative_values_are_rejected() {
+        assert_eq!(
+            TelematicsReading::new(Utc::now(), Some(-1), None, None),
+            Err(TelematicsReadingError::Negative("odometer"))
+        );
+        assert_eq!(
+            TelematicsReading::new(Utc::now(), None, Some(12), Some(101)),
+            Err(TelematicsReadingError::FuelLevelOutOfRange(101))
+        );
+    }
+}
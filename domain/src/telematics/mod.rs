@@ -0,0 +1,13 @@
+//! Telematics: odometer and engine-hour readings sent by the devices fitted in the vehicles, which
+//! become vehicle statuses.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * A reading names its vehicle by VIN or by the id of a registered device; a device id is
+//!   unique per organization and belongs to one vehicle.
+//! * Readings are sampled before they are stored (see `telematics_sampling_service`): a device
+//!   sending every second doesn't store a status every second.
+pub mod entities;
+pub mod repositories;
+pub mod services;
+pub mod value_types;
@@ -0,0 +1,39 @@
+//! Repository for managing the telematics devices of an organization.
+
+use crate::telematics::{
+    entities::telematics_device::TelematicsDevice, value_types::device_id::DeviceId,
+};
+use std::future::Future;
+
+/// Errors that can occur when interacting with the telematics device repository
+#[derive(Debug, thiserror::Error)]
+pub enum TelematicsDeviceRepositoryError {
+    #[error("device already registered: {0}")]
+    AlreadyExists(String),
+    #[error("database error: {0}")]
+    Database(String),
+}
+
+/// Repository interface for telematics device operations
+pub trait TelematicsDeviceRepository: Send + Sync {
+    /// Registers a device (`AlreadyExists` when its id is registered in the organization)
+    fn register(
+        &self,
+        organization_id: uuid::Uuid,
+        device: TelematicsDevice,
+    ) -> impl Future<Output = Result<TelematicsDevice, TelematicsDeviceRepositoryError>> + Send;
+
+    /// Retrieves a device by the id it reports itself with
+    fn find_by_device_id(
+        &self,
+        organization_id: uuid::Uuid,
+        device_id: &DeviceId,
+    ) -> impl Future<Output = Result<Option<TelematicsDevice>, TelematicsDeviceRepositoryError>> + Send;
+
+    /// Retrieves the devices fitted in a vehicle, by device id
+    fn find_by_vehicle(
+        &self,
+        organization_id: uuid::Uuid,
+        vehicle_id: uuid::Uuid,
+    ) -> impl Future<Output = Result<Vec<TelematicsDevice>, TelematicsDeviceRepositoryError>> + Send;
+}
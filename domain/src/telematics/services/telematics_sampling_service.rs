@@ -0,0 +1,309 @@
+//! Sampling of telematics readings into vehicle statuses.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * Readings are processed in the order they were recorded, not in the order they arrived: a
+//!   batch may hold them in any order.
+//! * A reading recorded at the same moment as an earlier one (in the batch or a stored status) is
+//!   a duplicate. A reading recorded before the latest stored status is late: it is inserted into
+//!   the history of the vehicle, between the stored statuses around it.
+//! * A reading is kept when at least `min_interval` passed since the status before it (the last
+//!   kept reading or a stored status) and until the stored status after it, the others are
+//!   throttled.
+//! * An odometer lower than the one before the reading, or higher than the one of the stored
+//!   status after it, is rejected. A status needs an odometer, so a reading without one carries
+//!   the last known odometer (and engine hours) forward; with none known it is rejected.
+use crate::{
+    telematics::entities::telematics_reading::TelematicsReading,
+    vehicle::entities::vehicle_status::VehicleStatusIdentity,
+};
+use chrono::{DateTime, Duration, Utc};
+
+/// Tuning of the sampling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SamplingSettings {
+    /// Shortest time between two stored statuses of a vehicle.
+    pub min_interval: Duration,
+}
+
+impl Default for SamplingSettings {
+    fn default() -> Self {
+        SamplingSettings {
+            min_interval: Duration::minutes(15),
+        }
+    }
+}
+
+/// A reading to store as a status, with the meters carried forward.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SampledReading {
+    pub recorded_at: DateTime<Utc>,
+    pub odometer: i32,
+    pub engine_hour_meter: Option<i32>,
+    pub fuel_level: Option<i32>,
+}
+
+/// Why a reading was rejected.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ReadingRejection {
+    /// The odometer is lower than the last known one.
+    #[error("Odometer {odometer} is lower than the last known {previous_odometer}")]
+    OdometerRollback {
+        odometer: i32,
+        previous_odometer: i32,
+    },
+    /// The odometer is higher than the one of the stored status after the reading.
+    #[error("Odometer {odometer} is higher than the later {next_odometer}")]
+    OdometerAhead { odometer: i32, next_odometer: i32 },
+    /// The reading has no odometer and none is known for the vehicle.
+    #[error("Reading has no odometer and none is known for the vehicle")]
+    NoOdometer,
+}
+
+/// Result of the sampling of the readings of one vehicle.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SampledReadings {
+    /// Readings to store, in the order they were recorded.
+    pub kept: Vec<SampledReading>,
+    pub duplicates: usize,
+    /// Kept readings recorded before the latest stored status.
+    pub late: usize,
+    pub throttled: usize,
+    pub rejected: Vec<(TelematicsReading, ReadingRejection)>,
+}
+
+/// Samples the readings of a vehicle into its stored `history`: the statuses from the last one
+/// performed before the earliest reading on, in the order they were performed.
+pub fn sample(
+    mut readings: Vec<TelematicsReading>,
+    history: &[VehicleStatusIdentity],
+    settings: &SamplingSettings,
+) -> SampledReadings {
+    readings.sort_by_key(|reading| reading.recorded_at);
+
+    let mut sampled = SampledReadings::default();
+    let mut stored = history.iter().peekable();
+    let mut last_seen = None;
+    let mut last_kept = None;
+    let mut odometer = None;
+    let mut engine_hours = None;
+
+    for reading in readings {
+        // The stored statuses up to the reading come before it
+        while let Some(status) = stored.next_if(|status| status.performed_at <= reading.recorded_at)
+        {
+            last_seen = Some(status.performed_at);
+            last_kept = last_seen;
+            odometer = Some(status.odometer);
+            engine_hours = status.engine_hour_meter.or(engine_hours);
+        }
+        let next = stored.peek().copied();
+
+        if last_seen == Some(reading.recorded_at) {
+            sampled.duplicates += 1;
+            continue;
+        }
+        last_seen = Some(reading.recorded_at);
+
+        if let (Some(value), Some(previous)) = (reading.odometer, odometer)
+            && value < previous
+        {
+            let rejection = ReadingRejection::OdometerRollback {
+                odometer: value,
+                previous_odometer: previous,
+            };
+            sampled.rejected.push((reading, rejection));
+            continue;
+        }
+        if let (Some(value), Some(next)) = (reading.odometer, next)
+            && value > next.odometer
+        {
+            let rejection = ReadingRejection::OdometerAhead {
+                odometer: value,
+                next_odometer: next.odometer,
+            };
+            sampled.rejected.push((reading, rejection));
+            continue;
+        }
+        let Some(current_odometer) = reading.odometer.or(odometer) else {
+            sampled
+                .rejected
+                .push((reading, ReadingRejection::NoOdometer));
+            continue;
+        };
+        odometer = Some(current_odometer);
+        engine_hours = reading.engine_hours.or(engine_hours);
+
+        if last_kept.is_some_and(|kept_at| reading.recorded_at - kept_at < settings.min_interval)
+            || next
+                .is_some_and(|next| next.performed_at - reading.recorded_at < settings.min_interval)
+        {
+            sampled.throttled += 1;
+            continue;
+        }
+        last_kept = Some(reading.recorded_at);
+        if next.is_some() {
+            sampled.late += 1;
+        }
+        sampled.kept.push(SampledReading {
+            recorded_at: reading.recorded_at,
+            odometer: current_odometer,
+            engine_hour_meter: engine_hours,
+            fuel_level: reading.fuel_level,
+        });
+    }
+
+    sampled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(minute: i64) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 10, 1, 8, 0, 0).unwrap() + Duration::minutes(minute)
+    }
+
+    fn reading(minute: i64, odometer: Option<i32>, engine_hours: Option<i32>) -> TelematicsReading {
+        TelematicsReading::new(at(minute), odometer, engine_hours, None).unwrap()
+    }
+
+    fn status(minute: i64, odometer: i32) -> VehicleStatusIdentity {
+        VehicleStatusIdentity {
+            id: 1,
+            vehicle_id: uuid::Uuid::new_v4(),
+            performed_by: uuid::Uuid::new_v4(),
+            performed_at: at(minute),
+            odometer,
+            engine_hour_meter: Some(300),
+            fuel_level: None,
+            notes: String::new(),
+            created_at: at(minute),
+            updated_at: at(minute),
+        }
+    }
+
+    fn kept_minutes(sampled: &SampledReadings) -> Vec<DateTime<Utc>> {
+        sampled.kept.iter().map(|kept| kept.recorded_at).collect()
+    }
+
+    #[test]
+    fn test_readings_are_reordered_and_throttled() {
+        let readings = vec![
+            reading(20, Some(1020), None),
+            reading(0, Some(1000), None),
+            reading(1, Some(1001), None),
+            reading(16, Some(1016), None),
+        ];
+
+        let sampled = sample(readings, &[], &SamplingSettings::default());
+
+        assert_eq!(kept_minutes(&sampled), vec![at(0), at(16)]);
+        assert_eq!(sampled.throttled, 2);
+    }
+
+    #[test]
+    fn test_duplicates_are_dropped_and_late_readings_kept() {
+        let latest = status(30, 1030);
+        let readings = vec![
+            reading(10, Some(1010), None),
+            reading(30, Some(1030), None),
+            reading(50, Some(1050), None),
+            reading(50, Some(1050), None),
+        ];
+
+        let sampled = sample(readings, &[latest], &SamplingSettings::default());
+
+        assert_eq!(kept_minutes(&sampled), vec![at(10), at(50)]);
+        assert_eq!((sampled.duplicates, sampled.late), (2, 1));
+    }
+
+    #[test]
+    fn test_late_readings_are_inserted_between_stored_statuses() {
+        let history = [status(0, 1000), status(60, 1060)];
+        let readings = vec![
+            reading(20, Some(1020), None),
+            reading(30, Some(1070), None),
+            reading(40, None, Some(320)),
+            reading(50, Some(1050), None),
+            reading(80, Some(1080), None),
+        ];
+
+        let sampled = sample(readings, &history, &SamplingSettings::default());
+
+        // 50 is throttled by the stored status at 60, which 80 comes after
+        assert_eq!(kept_minutes(&sampled), vec![at(20), at(40), at(80)]);
+        assert_eq!(sampled.kept[1].odometer, 1020);
+        assert_eq!(sampled.kept[1].engine_hour_meter, Some(320));
+        assert_eq!((sampled.late, sampled.throttled), (2, 1));
+        assert_eq!(
+            sampled.rejected[0].1,
+            ReadingRejection::OdometerAhead {
+                odometer: 1070,
+                next_odometer: 1060
+            }
+        );
+    }
+
+    #[test]
+    fn test_throttling_starts_from_the_latest_status() {
+        let latest = status(0, 1000);
+
+        let sampled = sample(
+            vec![reading(10, Some(1010), None)],
+            &[latest],
+            &SamplingSettings::default(),
+        );
+
+        assert!(sampled.kept.is_empty());
+        assert_eq!(sampled.throttled, 1);
+    }
+
+    #[test]
+    fn test_odometer_rollback_is_rejected() {
+        let latest = status(0, 1000);
+
+        let sampled = sample(
+            vec![reading(20, Some(990), None), reading(40, Some(1040), None)],
+            &[latest],
+            &SamplingSettings::default(),
+        );
+
+        assert_eq!(kept_minutes(&sampled), vec![at(40)]);
+        assert_eq!(
+            sampled.rejected[0].1,
+            ReadingRejection::OdometerRollback {
+                odometer: 990,
+                previous_odometer: 1000
+            }
+        );
+    }
+
+    #[test]
+    fn test_meters_are_carried_forward() {
+        let latest = status(0, 1000);
+
+        let sampled = sample(
+            vec![reading(20, None, Some(310)), reading(40, Some(1040), None)],
+            &[latest],
+            &SamplingSettings::default(),
+        );
+
+        assert_eq!(sampled.kept[0].odometer, 1000);
+        assert_eq!(sampled.kept[0].engine_hour_meter, Some(310));
+        assert_eq!(sampled.kept[1].engine_hour_meter, Some(310));
+    }
+
+    #[test]
+    fn test_reading_without_known_odometer_is_rejected() {
+        let sampled = sample(
+            vec![reading(0, None, Some(310))],
+            &[],
+            &SamplingSettings::default(),
+        );
+
+        assert!(sampled.kept.is_empty());
+        assert_eq!(sampled.rejected[0].1, ReadingRejection::NoOdometer);
+    }
+}
@@ -0,0 +1,79 @@
+//! Represents the id a telematics device reports itself with (IMEI, serial number, ...).
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * The id is trimmed, required and at most `MAX_LENGTH` characters.
+//! * Only ASCII letters, digits and `-`, `_`, `:`, `.` are allowed, so the id can be part of a
+//!   topic or a path.
+use std::fmt;
+
+/// Longest device id, in characters.
+pub const MAX_LENGTH: usize = 64;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DeviceId(String);
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum DeviceIdError {
+    #[error("Device id cannot be empty")]
+    Empty,
+    #[error("Device id too long: maximum {MAX_LENGTH} characters, got {0}")]
+    TooLong(usize),
+    #[error("Invalid character in device id: {0:?}")]
+    InvalidCharacter(char),
+}
+
+impl DeviceId {
+    pub fn new(value: impl Into<String>) -> Result<Self, DeviceIdError> {
+        let value = value.into().trim().to_string();
+        if value.is_empty() {
+            return Err(DeviceIdError::Empty);
+        }
+        if let Some(invalid) = value
+            .chars()
+            .find(|c| !c.is_ascii_alphanumeric() && !matches!(c, '-' | '_' | ':' | '.'))
+        {
+            return Err(DeviceIdError::InvalidCharacter(invalid));
+        }
+        if value.len() > MAX_LENGTH {
+            return Err(DeviceIdError::TooLong(value.len()));
+        }
+        Ok(DeviceId(value))
+    }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for DeviceId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_device_id_is_trimmed() {
+        assert_eq!(
+            DeviceId::new(" imei:356938035643809 ").unwrap().value(),
+            "imei:356938035643809"
+        );
+    }
+
+    #[test]
+    fn test_blank_device_id_is_rejected() {
+        assert_eq!(DeviceId::new("  "), Err(DeviceIdError::Empty));
+    }
+
+    #[test]
+    fn test_topic_separators_are_rejected() {
+        assert_eq!(
+            DeviceId::new("fleet/truck-1"),
+            Err(DeviceIdError::InvalidCharacter('/'))
+        );
+    }
+}
@@ -0,0 +1,109 @@
+//! Represents a trip of a vehicle: its GPS track between a start and an end.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * A trip has at least two points and ends after it starts.
+//! * The distance is computed from the track when the trip is created, and stored with it.
+use crate::trip::{
+    services::trip_distance_service::track_distance_km, value_types::geo_point::GeoPoint,
+};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trip {
+    /// The unique identifier of the trip.
+    pub id: uuid::Uuid,
+    /// The vehicle driven.
+    pub vehicle_id: uuid::Uuid,
+    /// The user who drove, `None` when unknown.
+    pub driver_id: Option<uuid::Uuid>,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: DateTime<Utc>,
+    /// The GPS track, in driving order.
+    pub polyline: Vec<GeoPoint>,
+    /// Distance along the track, in kilometers.
+    pub distance_km: Decimal,
+    /// Created at timestamp.
+    pub created_at: DateTime<Utc>,
+}
+
+/// The values of a trip to create.
+#[derive(Debug, Clone)]
+pub struct NewTrip {
+    pub vehicle_id: uuid::Uuid,
+    pub driver_id: Option<uuid::Uuid>,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: DateTime<Utc>,
+    pub polyline: Vec<GeoPoint>,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum TripError {
+    #[error("A trip needs at least two points, got {0}")]
+    TooFewPoints(usize),
+    #[error("A trip must end after it starts")]
+    EndsBeforeStart,
+}
+
+impl Trip {
+    pub fn new(trip: NewTrip) -> Result<Self, TripError> {
+        if trip.polyline.len() < 2 {
+            return Err(TripError::TooFewPoints(trip.polyline.len()));
+        }
+        if trip.ended_at <= trip.started_at {
+            return Err(TripError::EndsBeforeStart);
+        }
+
+        Ok(Trip {
+            id: uuid::Uuid::new_v4(),
+            vehicle_id: trip.vehicle_id,
+            driver_id: trip.driver_id,
+            started_at: trip.started_at,
+            ended_at: trip.ended_at,
+            distance_km: track_distance_km(&trip.polyline),
+            polyline: trip.polyline,
+            created_at: Utc::now(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn new_trip(polyline: Vec<GeoPoint>, hours: i64) -> NewTrip {
+        let started_at = Utc.with_ymd_and_hms(2025, 10, 1, 8, 0, 0).unwrap();
+        NewTrip {
+            vehicle_id: uuid::Uuid::new_v4(),
+            driver_id: None,
+            started_at,
+            ended_at: started_at + chrono::Duration::hours(hours),
+            polyline,
+        }
+    }
+
+    #[test]
+    fn test_distance_is_computed() {
+        let polyline = vec![
+            GeoPoint::new(0.0, 0.0).unwrap(),
+            GeoPoint::new(1.0, 0.0).unwrap(),
+        ];
+        let trip = Trip::new(new_trip(polyline, 2)).unwrap();
+        assert_eq!(trip.distance_km, Decimal::new(111_195, 3));
+    }
+
+    #[test]
+    fn test_invalid_trips_are_rejected() {
+        let point = GeoPoint::new(0.0, 0.0).unwrap();
+        assert_eq!(
+            Trip::new(new_trip(vec![point], 1)),
+            Err(TripError::TooFewPoints(1))
+        );
+        assert_eq!(
+            Trip::new(new_trip(vec![point, point], 0)),
+            Err(TripError::EndsBeforeStart)
+        );
+    }
+}
@@ -0,0 +1,13 @@
+//! Trips: the GPS tracks of the vehicles, to check the odometer readings of their statuses.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * A trip is a track of at least two points between its start and its end, driven by a user or
+//!   by an unknown driver. Its distance is computed from the track (haversine) when it is created.
+//! * The track is kept as an encoded polyline: points are rounded to 1e-5 degrees (about a meter).
+//! * The distance of the trips between two statuses is compared with the odometer delta (see
+//!   `distance_reconciliation_service`); a trip only partly between them counts pro rata of time.
+pub mod entities;
+pub mod repositories;
+pub mod services;
+pub mod value_types;
@@ -0,0 +1,38 @@
+//! Repository for managing the trips of the vehicles.
+
+use crate::trip::entities::trip::Trip;
+use std::future::Future;
+
+/// Errors that can occur when interacting with the trip repository
+#[derive(Debug, thiserror::Error)]
+pub enum TripRepositoryError {
+    #[error("database error: {0}")]
+    Database(String),
+}
+
+/// Repository interface for trip operations
+pub trait TripRepository: Send + Sync {
+    /// Stores a trip
+    fn create(
+        &self,
+        organization_id: uuid::Uuid,
+        trip: Trip,
+    ) -> impl Future<Output = Result<Trip, TripRepositoryError>> + Send;
+
+    /// Retrieves a trip by its id
+    fn find_by_id(
+        &self,
+        organization_id: uuid::Uuid,
+        id: uuid::Uuid,
+    ) -> impl Future<Output = Result<Option<Trip>, TripRepositoryError>> + Send;
+
+    /// Retrieves the trips of a vehicle overlapping the given range (ended at or after `from`,
+    /// started at or before `to`), ordered by `started_at`
+    fn find_by_vehicle(
+        &self,
+        organization_id: uuid::Uuid,
+        vehicle_id: uuid::Uuid,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl Future<Output = Result<Vec<Trip>, TripRepositoryError>> + Send;
+}
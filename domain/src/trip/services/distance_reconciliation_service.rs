@@ -0,0 +1,197 @@
+//! Reconciliation of the odometer readings of a vehicle with the distance of its GPS trips.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * Each pair of consecutive statuses (by `performed_at`) is a period; statuses at the same time
+//!   make no period.
+//! * The GPS distance of a period is the distance of the trips within it; a trip only partly in
+//!   the period counts pro rata of the time it overlaps (its track has no time per point).
+//! * A period is a discrepancy when the odometer delta and the GPS distance differ by more than the
+//!   tolerance: `tolerance_ratio` of the odometer delta, and at least `min_tolerance_km`. A period
+//!   without trips compares with a GPS distance of 0 (the tracker was off, or the odometer wrong).
+use crate::{trip::entities::trip::Trip, vehicle::entities::vehicle_status::VehicleStatusIdentity};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+
+/// Tolerance of the reconciliation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReconciliationSettings {
+    /// Accepted difference relative to the odometer delta (e.g., 0.05 = 5%).
+    pub tolerance_ratio: Decimal,
+    /// Accepted difference for short periods, in kilometers.
+    pub min_tolerance_km: Decimal,
+}
+
+impl Default for ReconciliationSettings {
+    fn default() -> Self {
+        ReconciliationSettings {
+            tolerance_ratio: Decimal::new(5, 2),
+            min_tolerance_km: Decimal::ONE,
+        }
+    }
+}
+
+/// The comparison of the distances of a period between two statuses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DistanceReconciliation {
+    pub from_status_id: i32,
+    pub to_status_id: i32,
+    pub from_at: DateTime<Utc>,
+    pub to_at: DateTime<Utc>,
+    /// Odometer delta between the two statuses, negative when the odometer went backwards.
+    pub odometer_km: i64,
+    /// Distance of the trips of the period, with three decimals.
+    pub gps_km: Decimal,
+    /// Odometer delta minus GPS distance.
+    pub difference_km: Decimal,
+    pub tolerance_km: Decimal,
+    /// Number of trips at least partly in the period.
+    pub trip_count: usize,
+    pub discrepancy: bool,
+}
+
+/// Reconciles the statuses of a vehicle (ordered by `performed_at`) with its trips.
+pub fn reconcile(
+    statuses: &[VehicleStatusIdentity],
+    trips: &[Trip],
+    settings: &ReconciliationSettings,
+) -> Vec<DistanceReconciliation> {
+    statuses
+        .windows(2)
+        .filter(|pair| pair[0].performed_at < pair[1].performed_at)
+        .map(|pair| {
+            let (from, to) = (&pair[0], &pair[1]);
+            let mut gps_km = Decimal::ZERO;
+            let mut trip_count = 0;
+            for trip in trips {
+                let overlap = overlap_ratio(trip, from.performed_at, to.performed_at);
+                if overlap > Decimal::ZERO {
+                    gps_km += trip.distance_km * overlap;
+                    trip_count += 1;
+                }
+            }
+            let gps_km = gps_km.round_dp(3);
+
+            let odometer_km = i64::from(to.odometer) - i64::from(from.odometer);
+            let difference_km = Decimal::from(odometer_km) - gps_km;
+            let tolerance_km = (Decimal::from(odometer_km.abs()) * settings.tolerance_ratio)
+                .round_dp(3)
+                .max(settings.min_tolerance_km);
+
+            DistanceReconciliation {
+                from_status_id: from.id,
+                to_status_id: to.id,
+                from_at: from.performed_at,
+                to_at: to.performed_at,
+                odometer_km,
+                gps_km,
+                difference_km,
+                tolerance_km,
+                trip_count,
+                discrepancy: difference_km.abs() > tolerance_km,
+            }
+        })
+        .collect()
+}
+
+/// Part of the duration of a trip within `[from, to]`, from 0 to 1.
+fn overlap_ratio(trip: &Trip, from: DateTime<Utc>, to: DateTime<Utc>) -> Decimal {
+    let start = trip.started_at.max(from);
+    let end = trip.ended_at.min(to);
+    if end <= start {
+        return Decimal::ZERO;
+    }
+    let duration = (trip.ended_at - trip.started_at).num_milliseconds();
+    Decimal::from((end - start).num_milliseconds()) / Decimal::from(duration)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trip::{entities::trip::NewTrip, value_types::geo_point::GeoPoint};
+    use chrono::TimeZone;
+
+    fn at(hour: i64) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 10, 1, 0, 0, 0).unwrap() + chrono::Duration::hours(hour)
+    }
+
+    fn status(id: i32, hour: i64, odometer: i32) -> VehicleStatusIdentity {
+        VehicleStatusIdentity {
+            id,
+            vehicle_id: uuid::Uuid::nil(),
+            performed_by: uuid::Uuid::nil(),
+            performed_at: at(hour),
+            odometer,
+            engine_hour_meter: None,
+            fuel_level: None,
+            notes: String::new(),
+            created_at: at(hour),
+            updated_at: at(hour),
+        }
+    }
+
+    /// A trip of `distance_km` (kilometers, with three decimals) between two hours.
+    fn trip(from_hour: i64, to_hour: i64, distance_km: i64) -> Trip {
+        let mut trip = Trip::new(NewTrip {
+            vehicle_id: uuid::Uuid::nil(),
+            driver_id: None,
+            started_at: at(from_hour),
+            ended_at: at(to_hour),
+            polyline: vec![
+                GeoPoint::new(0.0, 0.0).unwrap(),
+                GeoPoint::new(0.1, 0.0).unwrap(),
+            ],
+        })
+        .unwrap();
+        trip.distance_km = Decimal::from(distance_km);
+        trip
+    }
+
+    #[test]
+    fn test_matching_distances() {
+        let statuses = [status(1, 0, 1000), status(2, 10, 1100)];
+        let trips = [trip(1, 2, 60), trip(3, 4, 42)];
+
+        let periods = reconcile(&statuses, &trips, &ReconciliationSettings::default());
+
+        assert_eq!(periods.len(), 1);
+        assert_eq!(periods[0].gps_km, Decimal::from(102));
+        assert_eq!(periods[0].difference_km, Decimal::from(-2));
+        assert_eq!(periods[0].tolerance_km, Decimal::from(5));
+        assert_eq!(periods[0].trip_count, 2);
+        assert!(!periods[0].discrepancy);
+    }
+
+    #[test]
+    fn test_trip_across_statuses_is_prorated() {
+        let statuses = [status(1, 0, 1000), status(2, 10, 1050), status(3, 20, 1100)];
+        let trips = [trip(8, 12, 100)];
+
+        let periods = reconcile(&statuses, &trips, &ReconciliationSettings::default());
+
+        assert_eq!(periods[0].gps_km, Decimal::from(50));
+        assert_eq!(periods[1].gps_km, Decimal::from(50));
+        assert!(periods.iter().all(|period| !period.discrepancy));
+    }
+
+    #[test]
+    fn test_discrepancies_are_flagged() {
+        let statuses = [status(1, 0, 1000), status(2, 10, 1100), status(3, 20, 1100)];
+        let trips = [trip(1, 2, 60), trip(12, 13, 3)];
+
+        let periods = reconcile(&statuses, &trips, &ReconciliationSettings::default());
+
+        assert!(periods[0].discrepancy, "40 km more on the odometer");
+        assert!(
+            periods[1].discrepancy,
+            "3 km driven, the odometer didn't move"
+        );
+        assert_eq!(periods[1].tolerance_km, Decimal::ONE);
+    }
+
+    #[test]
+    fn test_statuses_at_the_same_time_make_no_period() {
+        let statuses = [status(1, 0, 1000), status(2, 0, 1000)];
+        assert!(reconcile(&statuses, &[], &ReconciliationSettings::default()).is_empty());
+    }
+}
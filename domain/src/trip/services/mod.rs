@@ -0,0 +1,2 @@
+pub mod distance_reconciliation_service;
+pub mod trip_distance_service;
@@ -0,0 +1,64 @@
+//! Distances along GPS tracks.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * Distances are great-circle distances (haversine) on a sphere of the mean Earth radius, which
+//!   is within 0.5% of the ellipsoid for the distances of a trip.
+//! * The distance of a track is the sum of the distances between its consecutive points, rounded
+//!   to the meter.
+use crate::trip::value_types::geo_point::GeoPoint;
+use rust_decimal::{Decimal, prelude::FromPrimitive};
+
+/// Mean Earth radius (IUGG), in kilometers.
+pub const EARTH_RADIUS_KM: f64 = 6371.0088;
+
+/// Great-circle distance between two points, in kilometers.
+pub fn haversine_km(from: &GeoPoint, to: &GeoPoint) -> f64 {
+    let (from_latitude, to_latitude) = (from.latitude().to_radians(), to.latitude().to_radians());
+    let latitude_delta = to_latitude - from_latitude;
+    let longitude_delta = (to.longitude() - from.longitude()).to_radians();
+
+    let a = (latitude_delta / 2.0).sin().powi(2)
+        + from_latitude.cos() * to_latitude.cos() * (longitude_delta / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_KM * a.sqrt().min(1.0).asin()
+}
+
+/// Distance along a track, in kilometers with three decimals.
+pub fn track_distance_km(points: &[GeoPoint]) -> Decimal {
+    let distance: f64 = points
+        .windows(2)
+        .map(|pair| haversine_km(&pair[0], &pair[1]))
+        .sum();
+    Decimal::from_f64(distance).unwrap_or_default().round_dp(3)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(latitude: f64, longitude: f64) -> GeoPoint {
+        GeoPoint::new(latitude, longitude).unwrap()
+    }
+
+    #[test]
+    fn test_degree_of_latitude() {
+        let distance = haversine_km(&point(10.0, 20.0), &point(11.0, 20.0));
+        assert!((distance - 111.195).abs() < 0.001, "got {}", distance);
+    }
+
+    #[test]
+    fn test_track_distance_adds_up_the_legs() {
+        let track = [
+            point(0.0, 0.0),
+            point(1.0, 0.0),
+            point(1.0, 0.0),
+            point(2.0, 0.0),
+        ];
+        assert_eq!(track_distance_km(&track), Decimal::new(222_390, 3));
+    }
+
+    #[test]
+    fn test_single_point_has_no_distance() {
+        assert_eq!(track_distance_km(&[point(43.2, 76.9)]), Decimal::ZERO);
+    }
+}
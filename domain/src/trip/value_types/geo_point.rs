@@ -0,0 +1,99 @@
+//! Represents a position on Earth (WGS 84).
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * The coordinates are kept in 1e-5 degrees, the precision of an encoded polyline, so a point
+//!   reads back from its polyline unchanged.
+//! * The latitude is within [-90, 90] and the longitude within [-180, 180].
+use std::fmt;
+
+/// Units of the coordinates per degree.
+pub const UNITS_PER_DEGREE: f64 = 1e5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GeoPoint {
+    latitude_e5: i32,
+    longitude_e5: i32,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum GeoPointError {
+    #[error("Latitude out of range (-90 to 90): {0}")]
+    InvalidLatitude(f64),
+    #[error("Longitude out of range (-180 to 180): {0}")]
+    InvalidLongitude(f64),
+}
+
+impl GeoPoint {
+    /// Creates a point from coordinates in degrees, rounded to 1e-5 degrees.
+    pub fn new(latitude: f64, longitude: f64) -> Result<Self, GeoPointError> {
+        if !(-90.0..=90.0).contains(&latitude) {
+            return Err(GeoPointError::InvalidLatitude(latitude));
+        }
+        if !(-180.0..=180.0).contains(&longitude) {
+            return Err(GeoPointError::InvalidLongitude(longitude));
+        }
+        Ok(GeoPoint {
+            latitude_e5: (latitude * UNITS_PER_DEGREE).round() as i32,
+            longitude_e5: (longitude * UNITS_PER_DEGREE).round() as i32,
+        })
+    }
+
+    /// Creates a point from coordinates in 1e-5 degrees.
+    pub fn from_e5(latitude_e5: i32, longitude_e5: i32) -> Result<Self, GeoPointError> {
+        Self::new(
+            f64::from(latitude_e5) / UNITS_PER_DEGREE,
+            f64::from(longitude_e5) / UNITS_PER_DEGREE,
+        )
+    }
+
+    pub fn latitude(&self) -> f64 {
+        f64::from(self.latitude_e5) / UNITS_PER_DEGREE
+    }
+
+    pub fn longitude(&self) -> f64 {
+        f64::from(self.longitude_e5) / UNITS_PER_DEGREE
+    }
+
+    pub fn latitude_e5(&self) -> i32 {
+        self.latitude_e5
+    }
+
+    pub fn longitude_e5(&self) -> i32 {
+        self.longitude_e5
+    }
+}
+
+impl fmt::Display for GeoPoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.5},{:.5}", self.latitude(), self.longitude())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coordinates_are_rounded() {
+        let point = GeoPoint::new(43.238949, 76.889709).unwrap();
+        assert_eq!(
+            (point.latitude_e5(), point.longitude_e5()),
+            (4323895, 7688971)
+        );
+        assert_eq!(point.to_string(), "43.23895,76.88971");
+    }
+
+    #[test]
+    fn test_out_of_range_coordinates_are_rejected() {
+        assert_eq!(
+            GeoPoint::new(90.5, 0.0),
+            Err(GeoPointError::InvalidLatitude(90.5))
+        );
+        assert_eq!(
+            GeoPoint::new(0.0, -181.0),
+            Err(GeoPointError::InvalidLongitude(-181.0))
+        );
+        assert!(GeoPoint::new(f64::NAN, 0.0).is_err());
+    }
+}
@@ -0,0 +1,2 @@
+pub mod geo_point;
+pub mod polyline;
@@ -0,0 +1,109 @@
+//! Encoded polylines: a track as text, in the polyline algorithm format (precision 5).
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * Each point is the delta from the previous one, latitude then longitude, in 1e-5 degrees,
+//!   zig-zag encoded in 5-bit chunks offset by 63 (printable ASCII).
+//! * Decoding fails on a truncated value, a character out of the range, or a point out of range.
+use crate::trip::value_types::geo_point::{GeoPoint, GeoPointError};
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum PolylineError {
+    #[error("Invalid character in polyline at {0}")]
+    InvalidCharacter(usize),
+    #[error("Truncated polyline")]
+    Truncated,
+    #[error("Invalid point in polyline: {0}")]
+    InvalidPoint(#[from] GeoPointError),
+}
+
+/// Encodes a track.
+pub fn encode(points: &[GeoPoint]) -> String {
+    let mut encoded = String::new();
+    let (mut latitude, mut longitude) = (0, 0);
+    for point in points {
+        encode_value(point.latitude_e5() - latitude, &mut encoded);
+        encode_value(point.longitude_e5() - longitude, &mut encoded);
+        latitude = point.latitude_e5();
+        longitude = point.longitude_e5();
+    }
+    encoded
+}
+
+/// Decodes a track.
+pub fn decode(encoded: &str) -> Result<Vec<GeoPoint>, PolylineError> {
+    let mut bytes = encoded.bytes().enumerate();
+    let mut points = Vec::new();
+    let (mut latitude, mut longitude) = (0i32, 0i32);
+    while bytes.len() > 0 {
+        latitude += decode_value(&mut bytes)?;
+        longitude += decode_value(&mut bytes)?;
+        points.push(GeoPoint::from_e5(latitude, longitude)?);
+    }
+    Ok(points)
+}
+
+fn encode_value(value: i32, encoded: &mut String) {
+    let mut value = if value < 0 { !(value << 1) } else { value << 1 } as u32;
+    while value >= 0x20 {
+        encoded.push(char::from((0x20 | (value & 0x1f)) as u8 + 63));
+        value >>= 5;
+    }
+    encoded.push(char::from(value as u8 + 63));
+}
+
+fn decode_value(bytes: &mut impl Iterator<Item = (usize, u8)>) -> Result<i32, PolylineError> {
+    let (mut value, mut shift) = (0u32, 0);
+    loop {
+        let (index, byte) = bytes.next().ok_or(PolylineError::Truncated)?;
+        if !(63..127).contains(&byte) || shift > 30 {
+            return Err(PolylineError::InvalidCharacter(index));
+        }
+        let chunk = u32::from(byte - 63);
+        value |= (chunk & 0x1f) << shift;
+        shift += 5;
+        if chunk < 0x20 {
+            break;
+        }
+    }
+    let value = value as i32;
+    Ok(if value & 1 == 1 {
+        !(value >> 1)
+    } else {
+        value >> 1
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn points() -> Vec<GeoPoint> {
+        vec![
+            GeoPoint::new(38.5, -120.2).unwrap(),
+            GeoPoint::new(40.7, -120.95).unwrap(),
+            GeoPoint::new(43.252, -126.453).unwrap(),
+        ]
+    }
+
+    #[test]
+    fn test_reference_polyline() {
+        assert_eq!(encode(&points()), "_p~iF~ps|U_ulLnnqC_mqNvxq`@");
+        assert_eq!(decode("_p~iF~ps|U_ulLnnqC_mqNvxq`@").unwrap(), points());
+    }
+
+    #[test]
+    fn test_empty_polyline() {
+        assert_eq!(encode(&[]), "");
+        assert_eq!(decode("").unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_invalid_polylines_are_rejected() {
+        assert_eq!(decode("_p~iF"), Err(PolylineError::Truncated));
+        assert_eq!(
+            decode("_p~iF ps|U"),
+            Err(PolylineError::InvalidCharacter(5))
+        );
+    }
+}
@@ -8,6 +8,8 @@ pub struct UserIdentity {
     pub id: uuid::Uuid,
     /// Uuid of the user.
     pub uuid: UserId,
+    /// The organization (tenant) of the user, which scopes everything the user reads and writes.
+    pub organization_id: uuid::Uuid,
     /// The username of the user.
     pub username: String,
     /// The email of the user.
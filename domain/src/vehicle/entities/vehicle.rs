@@ -2,16 +2,16 @@
 //! 
 //! *************************************** 100 chars limit **************************************** 
 //! # General rules:
+//! * New vehicles are validated by a `VehicleValidator`: `new` uses the lenient policy, use
+//!   `with_validator` for the strict or legacy import policies.
 //! 
-//! 
-//! # TODO list:
-//! * Add validation rules for the make, model, year
-//! 
-use chrono::Datelike;
-
 use crate::vehicle::{
     entities::vehicle_status::VehicleStatusIdentity,
-    value_types::{engine_type, license_plate, vehicle_vin},
+    services::vehicle_validator::VehicleValidator,
+    value_types::{
+        country_code, engine_type, license_plate, powertrain, validation_policy::ValidationPolicy,
+        vehicle_vin,
+    },
 };
 
 /// Represents the identity of a vehicle (DB record, non-hydrated).
@@ -29,8 +29,11 @@ pub struct VehicleIdentity {
     pub vin: vehicle_vin::VehicleVin,
     /// License plate number of the vehicle.
     pub license_plate: license_plate::LicensePlate,
-    /// The engine type of the vehicle (e.g., Gasoline, Diesel, Electric).
-    pub engine_type: engine_type::EngineType,
+    /// The country the vehicle is registered in (license plates are unique per country).
+    pub country: country_code::CountryCode,
+    /// The engine type of the vehicle (e.g., Gasoline, Diesel, Electric) with its battery and
+    /// fuel tank capacities.
+    pub powertrain: powertrain::Powertrain,
     /// The date and time when the vehicle was created.
     pub created_at: chrono::DateTime<chrono::Utc>,
     /// The date and time when the vehicle was last updated.
@@ -68,24 +71,35 @@ pub struct Vehicle {
 }
 
 impl Vehicle {
-    /// Creates a new vehicle with the provided identity and latest status.
+    /// Creates a new vehicle, validated with the lenient policy.
     pub fn new(data: NewVehicle) -> Result<Vehicle, VehicleError> {
+        Self::with_validator(data, &VehicleValidator::new(ValidationPolicy::Lenient))
+    }
+
+    /// Creates a new vehicle, validated and normalized by the given validator.
+    pub fn with_validator(
+        data: NewVehicle,
+        validator: &VehicleValidator,
+    ) -> Result<Vehicle, VehicleError> {
+        let valid = validator.validate(data)?;
+        let now = chrono::Utc::now();
         let identity = VehicleIdentity {
             id: uuid::Uuid::new_v4(),
-            make: data.make,
-            model: data.model,
-            year: data.year,
-            vin: vehicle_vin::VehicleVin::new(data.vin)?,
-            license_plate: license_plate::LicensePlate::new(data.license_plate)?,
-            engine_type: engine_type::EngineType::new(data.engine_type)?,
-            created_at: chrono::Utc::now(),
-            updated_at: chrono::Utc::now(),
+            make: valid.make,
+            model: valid.model,
+            year: valid.year,
+            vin: valid.vin,
+            license_plate: valid.license_plate,
+            country: valid.country,
+            powertrain: valid.powertrain,
+            created_at: now,
+            updated_at: now,
         };
 
-        Vehicle {
+        Ok(Vehicle {
             identity,
             latest_status: None,
-        }
+        })
     }
 
     // access all fields of the vehicle with getters through the identity
@@ -107,8 +121,14 @@ impl Vehicle {
     pub fn license_plate(&self) -> &license_plate::LicensePlate {
         &self.identity.license_plate
     }
+    pub fn country(&self) -> &country_code::CountryCode {
+        &self.identity.country
+    }
     pub fn engine_type(&self) -> &engine_type::EngineType {
-        &self.identity.engine_type
+        self.identity.powertrain.engine_type()
+    }
+    pub fn powertrain(&self) -> &powertrain::Powertrain {
+        &self.identity.powertrain
     }
     pub fn created_at(&self) -> chrono::DateTime<chrono::Utc> {
         self.identity.created_at
@@ -120,14 +140,58 @@ impl Vehicle {
 
 #[derive(Debug, thiserror::Error)]
 pub enum VehicleError {
-    #[error("Invalid year: {0}")]
-    InvalidYear(u16),
+    #[error("Make cannot be empty")]
+    EmptyMake,
+    #[error("Unknown make: {0}")]
+    UnknownMake(String),
+    #[error("Model cannot be empty")]
+    EmptyModel,
+    #[error("Invalid year {year}: must be between {min} and {max}")]
+    InvalidYear { year: u16, min: u16, max: u16 },
     #[error("Invalid VIN: {0}")]
     InvalidVin(#[from] vehicle_vin::VehicleVinError),
+    #[error("Invalid VIN: {0}")]
+    InvalidVinCheck(#[from] vehicle_vin::VehicleVinValidationError),
     #[error("Invalid license plate: {0}")]
     InvalidLicensePlate(#[from] license_plate::LicensePlateError),
+    #[error("Invalid license plate: {0}")]
+    InvalidLicensePlateFormat(#[from] license_plate::LicensePlateValidationError),
+    #[error("Invalid country: {0}")]
+    InvalidCountry(#[from] country_code::CountryCodeError),
     #[error("Invalid engine type: {0}")]
     InvalidEngineType(#[from] engine_type::EngineTypeError),
+    #[error("Invalid powertrain: {0}")]
+    InvalidPowertrain(#[from] powertrain::PowertrainError),
+    /// Every problem found in the vehicle data
+    #[error("{}", .0.iter().map(ToString::to_string).collect::<Vec<_>>().join("; "))]
+    Invalid(Vec<VehicleError>),
+}
+
+impl VehicleError {
+    /// Returns the name of the field the error is about (`vehicle` for `Invalid`)
+    pub fn field(&self) -> &'static str {
+        match self {
+            VehicleError::EmptyMake | VehicleError::UnknownMake(_) => "make",
+            VehicleError::EmptyModel => "model",
+            VehicleError::InvalidYear { .. } => "year",
+            VehicleError::InvalidVin(_) | VehicleError::InvalidVinCheck(_) => "vin",
+            VehicleError::InvalidLicensePlate(_) | VehicleError::InvalidLicensePlateFormat(_) => {
+                "license_plate"
+            }
+            VehicleError::InvalidCountry(_) => "country",
+            VehicleError::InvalidEngineType(_) => "engine_type",
+            VehicleError::InvalidPowertrain(_) => "powertrain",
+            VehicleError::Invalid(_) => "vehicle",
+        }
+    }
+
+    /// Returns every field error, flattening `Invalid`
+    pub fn errors(&self) -> Vec<&VehicleError> {
+        match self {
+            VehicleError::Invalid(errors) => errors.iter().flat_map(VehicleError::errors).collect(),
+            error => vec![error],
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -137,5 +201,13 @@ pub struct NewVehicle {
     pub year: u16,
     pub vin: String,
     pub license_plate: String,
+    /// Country code of the registration (e.g., "KZ", "US-CA").
+    pub country: String,
     pub engine_type: String,
+    /// Traction battery capacity in kWh, if known.
+    pub battery_capacity: Option<rust_decimal::Decimal>,
+    /// Fuel tank capacity in liters, if known.
+    pub tank_capacity: Option<rust_decimal::Decimal>,
+    /// Accept a pre-1981 / non-ISO VIN (see `VehicleVin::new_legacy`).
+    pub legacy_vin: bool,
 }
@@ -1 +1,2 @@
 pub mod vehicle_repository;
+pub mod vehicle_status_repository;
@@ -1,5 +1,5 @@
 use crate::vehicle::entities::vehicle;
-use std::future::Future;
+use std::{collections::HashSet, future::Future};
 use uuid::Uuid;
 
 #[derive(Debug, thiserror::Error)]
@@ -8,13 +8,24 @@ pub enum VehicleRepositoryError {
     NotFound(Uuid),
     #[error("vehicle already exists: {0}")]
     AlreadyExists(Uuid),
+    #[error("database error: {0}")]
+    Database(String),
+}
+
+/// VINs and (country, license plate) pairs held by vehicles of an organization
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TakenIdentifiers {
+    pub vins: HashSet<String>,
+    pub license_plates: HashSet<(String, String)>,
 }
 
 /// Repository trait for vehicle operations
 pub trait VehicleRepository: Send + Sync {
-    /// Create a new vehicle
+    /// Create a new vehicle (`AlreadyExists` with the id of the vehicle of the organization
+    /// holding the VIN, or the license plate in the same country)
     fn create(
         &self,
+        organization_id: Uuid,
         vehicle: vehicle::NewVehicle,
     ) -> impl Future<Output = Result<vehicle::VehicleIdentity, VehicleRepositoryError>> + Send;
 
@@ -27,21 +38,35 @@ pub trait VehicleRepository: Send + Sync {
     /// Find a vehicle by its ID
     fn find_by_id(
         &self,
+        organization_id: Uuid,
         id: Uuid,
     ) -> impl Future<Output = Result<Option<vehicle::VehicleIdentity>, VehicleRepositoryError>> + Send;
 
     /// Find all vehicles
     fn find_all(
         &self,
+        organization_id: Uuid,
     ) -> impl Future<Output = Result<Vec<vehicle::VehicleIdentity>, VehicleRepositoryError>> + Send;
 
-    /// Check existence of a vehicle by its VIN
+    /// Check existence of a vehicle of the organization by its VIN, or by its license plate in
+    /// the given country
     fn exists_by_vin_or_license_plate(
         &self,
+        organization_id: Uuid,
         vin: &str,
+        country: &str,
         license_plate: &str,
     ) -> impl Future<Output = Result<bool, VehicleRepositoryError>> + Send;
 
+    /// Among the given VINs and (country, license plate) pairs, the ones held by a vehicle of the
+    /// organization, looked up together
+    fn find_taken_identifiers(
+        &self,
+        organization_id: Uuid,
+        vins: &[String],
+        license_plates: &[(String, String)],
+    ) -> impl Future<Output = Result<TakenIdentifiers, VehicleRepositoryError>> + Send;
+
     // /// Update an existing vehicle
     // fn update(
     //     &self,
@@ -49,6 +74,9 @@ pub trait VehicleRepository: Send + Sync {
     // ) -> impl Future<Output = Result<Vehicle, VehicleRepositoryError>> + Send;
 
     /// Delete a vehicle by its ID
-    fn delete(&self, id: Uuid)
-    -> impl Future<Output = Result<bool, VehicleRepositoryError>> + Send;
+    fn delete(
+        &self,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> impl Future<Output = Result<bool, VehicleRepositoryError>> + Send;
 }
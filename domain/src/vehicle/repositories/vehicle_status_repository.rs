@@ -0,0 +1,45 @@
+use crate::vehicle::entities::vehicle_status::VehicleStatusIdentity;
+use std::future::Future;
+use uuid::Uuid;
+
+#[derive(Debug, thiserror::Error)]
+pub enum VehicleStatusRepositoryError {
+    #[error("database error: {0}")]
+    Database(String),
+}
+
+/// Repository trait for vehicle status operations
+pub trait VehicleStatusRepository: Send + Sync {
+    /// Record a status of a vehicle, which becomes its latest status unless another one was
+    /// performed after it (the id is assigned by the storage)
+    fn create(
+        &self,
+        organization_id: Uuid,
+        status: VehicleStatusIdentity,
+    ) -> impl Future<Output = Result<VehicleStatusIdentity, VehicleStatusRepositoryError>> + Send;
+
+    /// Find the latest status of a vehicle
+    fn find_latest(
+        &self,
+        organization_id: Uuid,
+        vehicle_id: Uuid,
+    ) -> impl Future<Output = Result<Option<VehicleStatusIdentity>, VehicleStatusRepositoryError>> + Send;
+
+    /// Find the last status of a vehicle performed before the given time
+    fn find_latest_before(
+        &self,
+        organization_id: Uuid,
+        vehicle_id: Uuid,
+        before: chrono::DateTime<chrono::Utc>,
+    ) -> impl Future<Output = Result<Option<VehicleStatusIdentity>, VehicleStatusRepositoryError>> + Send;
+
+    /// Find the statuses of a vehicle performed within the given range (both ends inclusive),
+    /// ordered by `performed_at`
+    fn find_by_vehicle(
+        &self,
+        organization_id: Uuid,
+        vehicle_id: Uuid,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl Future<Output = Result<Vec<VehicleStatusIdentity>, VehicleStatusRepositoryError>> + Send;
+}
@@ -0,0 +1,111 @@
+//! Embedded list of vehicle makes, used to normalize the make typed by users.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # Personal notes:
+//! * Makes are compared ignoring case, spaces, dashes and dots, so "mercedes benz", "MERCEDES-BENZ"
+//!   and "Mercedes Benz" are all stored as "Mercedes-Benz".
+//! * Aliases include the Cyrillic spellings used in our CIS fleet (e.g., "КАМАЗ").
+
+/// A make with its usual spelling and the other accepted ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KnownMake {
+    /// Make as usually written (e.g., "Volkswagen").
+    pub name: &'static str,
+    /// Other accepted spellings of the make (e.g., "VW").
+    pub aliases: &'static [&'static str],
+}
+
+const fn make(name: &'static str, aliases: &'static [&'static str]) -> KnownMake {
+    KnownMake { name, aliases }
+}
+
+pub const KNOWN_MAKES: &[KnownMake] = &[
+    make("Audi", &[]),
+    make("BMW", &["Bayerische Motoren Werke"]),
+    make("BYD", &[]),
+    make("Chery", &[]),
+    make("Chevrolet", &["Chevy"]),
+    make("Citroen", &["Citroën"]),
+    make("DAF", &[]),
+    make("Dodge", &[]),
+    make("Fiat", &[]),
+    make("Ford", &[]),
+    make("GAZ", &["ГАЗ"]),
+    make("Geely", &[]),
+    make("GMC", &[]),
+    make("Haval", &[]),
+    make("Hino", &[]),
+    make("Honda", &[]),
+    make("Hyundai", &[]),
+    make("Isuzu", &[]),
+    make("Iveco", &[]),
+    make("JAC", &[]),
+    make("Jeep", &[]),
+    make("KAMAZ", &["КАМАЗ"]),
+    make("Kia", &[]),
+    make("Lada", &["VAZ", "ВАЗ", "Лада"]),
+    make("Land Rover", &[]),
+    make("Lexus", &[]),
+    make("MAN", &[]),
+    make("MAZ", &["МАЗ"]),
+    make("Mazda", &[]),
+    make("Mercedes-Benz", &["Mercedes", "MB"]),
+    make("Mitsubishi", &[]),
+    make("Nissan", &[]),
+    make("Opel", &[]),
+    make("Peugeot", &[]),
+    make("Porsche", &[]),
+    make("Ram", &[]),
+    make("Renault", &[]),
+    make("Scania", &[]),
+    make("Skoda", &["Škoda"]),
+    make("Subaru", &[]),
+    make("Suzuki", &[]),
+    make("Tesla", &[]),
+    make("Toyota", &[]),
+    make("UAZ", &["УАЗ"]),
+    make("Volkswagen", &["VW"]),
+    make("Volvo", &[]),
+];
+
+/// Returns the usual spelling of a make, `None` if the make is not known
+pub fn canonical_make(value: &str) -> Option<&'static str> {
+    let key = make_key(value);
+    if key.is_empty() {
+        return None;
+    }
+
+    KNOWN_MAKES
+        .iter()
+        .find(|known| {
+            make_key(known.name) == key || known.aliases.iter().any(|alias| make_key(alias) == key)
+        })
+        .map(|known| known.name)
+}
+
+fn make_key(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| !c.is_whitespace() && !matches!(c, '-' | '.'))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canonical_make() {
+        assert_eq!(canonical_make("toyota"), Some("Toyota"));
+        assert_eq!(canonical_make("mercedes benz"), Some("Mercedes-Benz"));
+        assert_eq!(canonical_make(" vw "), Some("Volkswagen"));
+        assert_eq!(canonical_make("камаз"), Some("KAMAZ"));
+    }
+
+    #[test]
+    fn test_unknown_make() {
+        assert_eq!(canonical_make("Acme Motors"), None);
+        assert_eq!(canonical_make(" "), None);
+    }
+}
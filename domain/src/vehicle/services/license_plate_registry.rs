@@ -0,0 +1,333 @@
+//! Registry of license plate formats, keyed by country code.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * Plates are validated in their normalized form (see `LicensePlate::normalize`): uppercase, no
+//!   separators, Cyrillic look-alike letters replaced with Latin ones.
+//! * A plate is valid if it matches any format of its country code. Formats of a subdivision
+//!   ("US-CA") are looked up first, then the formats of the country ("US").
+//! * The standard registry can be extended (`register`) or replaced by a custom one.
+//!
+//! # Pattern syntax (`PlatePattern`):
+//! * `L` a letter, `D` a digit, `A` a letter or a digit, `[ABC]` one of the listed characters,
+//!   any other character matches itself.
+//! * Each element can be followed by `{n}` or `{min,max}` repetitions.
+use crate::vehicle::value_types::{
+    country_code::CountryCode, license_plate::LicensePlateValidationError,
+};
+use std::{collections::HashMap, sync::OnceLock};
+
+/// A license plate format.
+pub trait PlateFormat: Send + Sync {
+    /// Checks if a normalized plate matches the format
+    fn matches(&self, plate: &str) -> bool;
+}
+
+impl<F: Fn(&str) -> bool + Send + Sync> PlateFormat for F {
+    fn matches(&self, plate: &str) -> bool {
+        self(plate)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PatternClass {
+    Letter,
+    Digit,
+    Alphanumeric,
+    OneOf(Vec<char>),
+}
+
+impl PatternClass {
+    fn accepts(&self, c: char) -> bool {
+        match self {
+            PatternClass::Letter => c.is_ascii_uppercase(),
+            PatternClass::Digit => c.is_ascii_digit(),
+            PatternClass::Alphanumeric => c.is_ascii_uppercase() || c.is_ascii_digit(),
+            PatternClass::OneOf(chars) => chars.contains(&c),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PatternElement {
+    class: PatternClass,
+    min: usize,
+    max: usize,
+}
+
+/// A plate format described with a pattern (e.g., "DDDLLLDD").
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlatePattern {
+    pattern: String,
+    elements: Vec<PatternElement>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PlatePatternError {
+    #[error("Invalid plate pattern '{0}': {1}")]
+    Invalid(String, String),
+}
+
+impl PlatePattern {
+    /// Parses a pattern
+    pub fn new(pattern: &str) -> Result<Self, PlatePatternError> {
+        let invalid = |reason: &str| PlatePatternError::Invalid(pattern.to_string(), reason.into());
+        let mut elements: Vec<PatternElement> = Vec::new();
+        let mut chars = pattern.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            let class = match c {
+                'L' => PatternClass::Letter,
+                'D' => PatternClass::Digit,
+                'A' => PatternClass::Alphanumeric,
+                '[' => {
+                    let set: Vec<char> = chars.by_ref().take_while(|&c| c != ']').collect();
+                    if set.is_empty() {
+                        return Err(invalid("empty character set"));
+                    }
+                    PatternClass::OneOf(set)
+                }
+                '{' => {
+                    let repetition: String = chars.by_ref().take_while(|&c| c != '}').collect();
+                    let element = elements
+                        .last_mut()
+                        .ok_or_else(|| invalid("repetition without element"))?;
+                    let (min, max) = match repetition.split_once(',') {
+                        Some((min, max)) => (min.trim().parse(), max.trim().parse()),
+                        None => (repetition.trim().parse(), repetition.trim().parse()),
+                    };
+                    let (Ok(min), Ok(max)) = (min, max) else {
+                        return Err(invalid("invalid repetition"));
+                    };
+                    if min > max || max == 0 {
+                        return Err(invalid("invalid repetition"));
+                    }
+                    element.min = min;
+                    element.max = max;
+                    continue;
+                }
+                other => PatternClass::OneOf(vec![other]),
+            };
+            elements.push(PatternElement {
+                class,
+                min: 1,
+                max: 1,
+            });
+        }
+
+        if elements.is_empty() {
+            return Err(invalid("empty pattern"));
+        }
+
+        Ok(PlatePattern {
+            pattern: pattern.to_string(),
+            elements,
+        })
+    }
+
+    /// Returns the pattern as written
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+
+    fn matches_from(&self, element: usize, plate: &[char]) -> bool {
+        let Some(current) = self.elements.get(element) else {
+            return plate.is_empty();
+        };
+
+        // Longest run of accepted characters, then backtrack down to the minimum
+        let run = plate
+            .iter()
+            .take(current.max)
+            .take_while(|&&c| current.class.accepts(c))
+            .count();
+        (current.min..=run)
+            .rev()
+            .any(|count| self.matches_from(element + 1, &plate[count..]))
+    }
+}
+
+impl PlateFormat for PlatePattern {
+    fn matches(&self, plate: &str) -> bool {
+        let chars: Vec<char> = plate.chars().collect();
+        self.matches_from(0, &chars)
+    }
+}
+
+/// Plate formats by country code.
+#[derive(Default)]
+pub struct LicensePlateRegistry {
+    formats: HashMap<String, Vec<Box<dyn PlateFormat>>>,
+}
+
+impl LicensePlateRegistry {
+    /// Creates an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the registry with the standard formats, shared by the whole application
+    pub fn standard() -> &'static LicensePlateRegistry {
+        static STANDARD: OnceLock<LicensePlateRegistry> = OnceLock::new();
+        STANDARD.get_or_init(LicensePlateRegistry::with_standard_formats)
+    }
+
+    /// Creates a registry with the standard formats (CIS, some EU countries and US states)
+    pub fn with_standard_formats() -> Self {
+        let mut registry = Self::new();
+        for (country, patterns) in STANDARD_FORMATS {
+            for pattern in *patterns {
+                // Standard patterns are checked by the tests
+                if let Ok(pattern) = PlatePattern::new(pattern) {
+                    registry.register(country, pattern);
+                }
+            }
+        }
+        registry
+    }
+
+    /// Adds a format for a country code (e.g., "KZ", "US-CA")
+    pub fn register(&mut self, country: &str, format: impl PlateFormat + 'static) {
+        self.formats
+            .entry(country.to_uppercase())
+            .or_default()
+            .push(Box::new(format));
+    }
+
+    /// Checks if the registry knows the formats of a country code (or of its country)
+    pub fn supports(&self, country: &CountryCode) -> bool {
+        self.formats_for(country).is_some()
+    }
+
+    /// Validates a normalized plate against the formats of a country code
+    pub fn validate(
+        &self,
+        country: &CountryCode,
+        plate: &str,
+    ) -> Result<(), LicensePlateValidationError> {
+        if plate.is_empty() {
+            return Err(LicensePlateValidationError::Empty);
+        }
+
+        let formats = self
+            .formats_for(country)
+            .ok_or_else(|| LicensePlateValidationError::UnsupportedCountry(country.to_string()))?;
+        if !formats.iter().any(|format| format.matches(plate)) {
+            return Err(LicensePlateValidationError::InvalidFormat(format!(
+                "{} ({})",
+                plate, country
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn formats_for(&self, country: &CountryCode) -> Option<&Vec<Box<dyn PlateFormat>>> {
+        self.formats
+            .get(country.value())
+            .or_else(|| self.formats.get(country.country()))
+    }
+}
+
+const STANDARD_FORMATS: &[(&str, &[&str])] = &[
+    // Kazakhstan: 123ABC45, A123BCD
+    ("KZ", &["DDDLLLDD", "LDDDLLL"]),
+    // Russia, only letters shared by the Cyrillic and Latin alphabets: A123BC77, A123BC777
+    ("RU", &[
+        "[ABEKMHOPCTYX]DDD[ABEKMHOPCTYX]{2}D{2,3}",
+        // Trailers, taxis: AB123477
+        "[ABEKMHOPCTYX]{2}DDDD{2,3}",
+    ]),
+    // Uzbekistan: 01A123BC, 01123ABC
+    ("UZ", &["DDLDDDLL", "DDDDDLLL"]),
+    // Kyrgyzstan: 01123ABC
+    ("KG", &["DDDDDLLL"]),
+    // Germany: district (1-3 letters), letters (1-2), digits (1-4), optional E/H
+    ("DE", &["L{2,5}D{1,4}[EH]{0,1}"]),
+    // France, Italy: AB123CD
+    ("FR", &["LLDDDLL"]),
+    ("IT", &["LLDDDLL"]),
+    // Spain: 1234BCD (no vowels)
+    ("ES", &["DDDD[BCDFGHJKLMNPRSTVWXYZ]{3}"]),
+    // Poland: district (2-3 letters) + 4-5 characters
+    ("PL", &["L{2,3}A{4,5}"]),
+    // Netherlands: 6 characters in various groupings
+    ("NL", &["A{6}"]),
+    // United States: any state not listed below (vanity plates are common)
+    ("US", &["A{1,8}"]),
+    ("US-CA", &["DLLLDDD"]),
+    ("US-NY", &["LLLDDDD"]),
+    ("US-TX", &["LLLDDDD", "LLDLDDD"]),
+    ("US-FL", &["LLLLDD", "DDDLLL", "LLLDLL"]),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn code(value: &str) -> CountryCode {
+        CountryCode::new(value).unwrap()
+    }
+
+    #[test]
+    fn test_standard_patterns_are_valid() {
+        for (country, patterns) in STANDARD_FORMATS {
+            for pattern in *patterns {
+                assert!(PlatePattern::new(pattern).is_ok(), "{}: {}", country, pattern);
+            }
+        }
+    }
+
+    #[test]
+    fn test_pattern_repetitions() {
+        let pattern = PlatePattern::new("L{2,5}D{1,4}[EH]{0,1}").unwrap();
+        assert!(pattern.matches("BAB1234"));
+        assert!(pattern.matches("MXY12E"));
+        assert!(!pattern.matches("B1234"));
+        assert!(!pattern.matches("BAB12345"));
+    }
+
+    #[test]
+    fn test_invalid_patterns() {
+        assert!(PlatePattern::new("").is_err());
+        assert!(PlatePattern::new("{2}").is_err());
+        assert!(PlatePattern::new("L{3,2}").is_err());
+        assert!(PlatePattern::new("[]").is_err());
+    }
+
+    #[test]
+    fn test_standard_registry() {
+        let registry = LicensePlateRegistry::standard();
+        assert!(registry.validate(&code("KZ"), "123ABC45").is_ok());
+        assert!(registry.validate(&code("RU"), "A123BC777").is_ok());
+        assert!(registry.validate(&code("RU"), "D123BC77").is_err());
+        assert!(registry.validate(&code("UZ"), "01A123BC").is_ok());
+        assert!(registry.validate(&code("KG"), "01123ABC").is_ok());
+        assert!(registry.validate(&code("ES"), "1234BCD").is_ok());
+        assert!(registry.validate(&code("ES"), "1234ABC").is_err());
+    }
+
+    #[test]
+    fn test_subdivision_falls_back_to_country() {
+        let registry = LicensePlateRegistry::standard();
+        assert!(registry.validate(&code("US-CA"), "7ABC123").is_ok());
+        assert!(registry.validate(&code("US-CA"), "ABC1234").is_err());
+        assert!(registry.validate(&code("US-WY"), "GOWYO").is_ok());
+    }
+
+    #[test]
+    fn test_unsupported_country() {
+        assert!(matches!(
+            LicensePlateRegistry::standard().validate(&code("JP"), "1234"),
+            Err(LicensePlateValidationError::UnsupportedCountry(_))
+        ));
+    }
+
+    #[test]
+    fn test_custom_format() {
+        let mut registry = LicensePlateRegistry::new();
+        registry.register("jp", |plate: &str| plate.len() == 4);
+        assert!(registry.supports(&code("JP")));
+        assert!(registry.validate(&code("JP"), "1234").is_ok());
+    }
+}
@@ -0,0 +1,5 @@
+pub mod known_makes;
+pub mod license_plate_registry;
+pub mod vehicle_validator;
+pub mod vin_decoder;
+pub mod wmi_table;
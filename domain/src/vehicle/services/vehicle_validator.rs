@@ -0,0 +1,396 @@
+//! Validates and normalizes the data of a new vehicle according to a `ValidationPolicy`.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * Every field is checked, and all the problems are returned at once in `VehicleError::Invalid`
+//!   so a form or an import row can be fixed in one go.
+//! * The year must be between the minimum year and next year (model years run ahead of the
+//!   calendar).
+//! * The strict policy checks the VIN check digit of the North American VINs, the only ones it is
+//!   mandatory for.
+//! * Makes are replaced by their usual spelling when known (see `known_makes`), and extra spaces
+//!   are removed from the make and model.
+
+use crate::vehicle::{
+    entities::vehicle::{NewVehicle, VehicleError},
+    services::{
+        known_makes::canonical_make,
+        license_plate_registry::LicensePlateRegistry,
+        vin_decoder::{VinRegion, decode_vin},
+    },
+    value_types::{
+        country_code::CountryCode, engine_type::EngineType, license_plate::LicensePlate,
+        license_plate::LicensePlateValidationError, powertrain::Powertrain,
+        validation_policy::ValidationPolicy, vehicle_vin::VehicleVin,
+    },
+};
+use chrono::Datelike;
+
+/// Oldest year accepted by the strict and lenient policies
+pub const DEFAULT_MIN_YEAR: u16 = 1950;
+/// Oldest year accepted when importing old records (the first automobile)
+pub const LEGACY_MIN_YEAR: u16 = 1886;
+
+/// The fields of a new vehicle, validated and normalized
+#[derive(Debug, Clone)]
+pub struct ValidatedVehicle {
+    pub make: String,
+    pub model: String,
+    pub year: u16,
+    pub vin: VehicleVin,
+    pub license_plate: LicensePlate,
+    pub country: CountryCode,
+    pub powertrain: Powertrain,
+}
+
+/// Validates new vehicles with a policy
+#[derive(Clone, Copy)]
+pub struct VehicleValidator<'a> {
+    policy: ValidationPolicy,
+    plate_registry: &'a LicensePlateRegistry,
+    min_year: u16,
+    current_year: u16,
+}
+
+impl VehicleValidator<'static> {
+    /// Creates a validator using the standard plate formats and the current year
+    pub fn new(policy: ValidationPolicy) -> Self {
+        VehicleValidator {
+            policy,
+            plate_registry: LicensePlateRegistry::standard(),
+            min_year: match policy {
+                ValidationPolicy::LegacyImport => LEGACY_MIN_YEAR,
+                _ => DEFAULT_MIN_YEAR,
+            },
+            current_year: u16::try_from(chrono::Utc::now().year()).unwrap_or(u16::MAX - 1),
+        }
+    }
+}
+
+impl<'a> VehicleValidator<'a> {
+    /// Checks license plates with a custom registry instead of the standard formats
+    pub fn with_plate_registry<'b>(
+        self,
+        plate_registry: &'b LicensePlateRegistry,
+    ) -> VehicleValidator<'b> {
+        VehicleValidator {
+            policy: self.policy,
+            plate_registry,
+            min_year: self.min_year,
+            current_year: self.current_year,
+        }
+    }
+
+    pub fn with_min_year(mut self, min_year: u16) -> Self {
+        self.min_year = min_year;
+        self
+    }
+
+    /// Overrides the current year (the clock is read once, when the validator is created)
+    pub fn with_current_year(mut self, current_year: u16) -> Self {
+        self.current_year = current_year;
+        self
+    }
+
+    pub fn policy(&self) -> ValidationPolicy {
+        self.policy
+    }
+
+    /// Returns the oldest and newest accepted years
+    pub fn year_range(&self) -> (u16, u16) {
+        (self.min_year, self.current_year.saturating_add(1))
+    }
+
+    /// Validates and normalizes every field, collecting all the errors
+    pub fn validate(&self, data: NewVehicle) -> Result<ValidatedVehicle, VehicleError> {
+        let mut errors = Vec::new();
+
+        let make = self
+            .validate_make(&data.make)
+            .map_err(|e| errors.push(e))
+            .ok();
+        let model = collapse_whitespace(&data.model);
+        if model.is_empty() {
+            errors.push(VehicleError::EmptyModel);
+        }
+        let year = self
+            .validate_year(data.year)
+            .map_err(|e| errors.push(e))
+            .ok();
+        let vin = self
+            .validate_vin(data.vin, data.legacy_vin)
+            .map_err(|e| errors.push(e))
+            .ok();
+        let country = CountryCode::new(data.country)
+            .map_err(|e| errors.push(e.into()))
+            .ok();
+        let license_plate = LicensePlate::new(data.license_plate)
+            .map_err(|e| errors.push(e.into()))
+            .ok();
+        if let (Some(plate), Some(country)) = (&license_plate, &country)
+            && let Err(e) = self.validate_plate_format(plate, country)
+        {
+            errors.push(e);
+        }
+        let powertrain = EngineType::new(data.engine_type)
+            .map_err(VehicleError::from)
+            .and_then(|engine_type| {
+                Ok(Powertrain::new(
+                    engine_type,
+                    data.battery_capacity,
+                    data.tank_capacity,
+                )?)
+            })
+            .map_err(|e| errors.push(e))
+            .ok();
+
+        match (make, year, vin, country, license_plate, powertrain) {
+            (
+                Some(make),
+                Some(year),
+                Some(vin),
+                Some(country),
+                Some(license_plate),
+                Some(powertrain),
+            ) if errors.is_empty() => Ok(ValidatedVehicle {
+                make,
+                model,
+                year,
+                vin,
+                license_plate,
+                country,
+                powertrain,
+            }),
+            _ => Err(VehicleError::Invalid(errors)),
+        }
+    }
+
+    fn validate_make(&self, make: &str) -> Result<String, VehicleError> {
+        let make = collapse_whitespace(make);
+        if make.is_empty() {
+            return Err(VehicleError::EmptyMake);
+        }
+
+        match canonical_make(&make) {
+            Some(known) => Ok(known.to_string()),
+            None if self.policy == ValidationPolicy::Strict => Err(VehicleError::UnknownMake(make)),
+            None => Ok(make),
+        }
+    }
+
+    fn validate_year(&self, year: u16) -> Result<u16, VehicleError> {
+        let (min, max) = self.year_range();
+        if year < min || year > max {
+            return Err(VehicleError::InvalidYear { year, min, max });
+        }
+        Ok(year)
+    }
+
+    fn validate_vin(&self, vin: String, legacy: bool) -> Result<VehicleVin, VehicleError> {
+        if legacy {
+            return Ok(VehicleVin::new_legacy(vin)?);
+        }
+
+        match (VehicleVin::new(vin.as_str()), self.policy) {
+            // The check digit is only mandatory in North America
+            (Ok(vin), ValidationPolicy::Strict) => {
+                if decode_vin(&vin)
+                    .is_some_and(|decoded| decoded.region == Some(VinRegion::NorthAmerica))
+                {
+                    VehicleVin::validate(vin.value())?;
+                }
+                Ok(vin)
+            }
+            (Ok(vin), _) => Ok(vin),
+            // Old records often have pre-1981 identifiers
+            (Err(e), ValidationPolicy::LegacyImport) => {
+                VehicleVin::new_legacy(vin).map_err(|_| e.into())
+            }
+            (Err(e), _) => Err(e.into()),
+        }
+    }
+
+    fn validate_plate_format(
+        &self,
+        plate: &LicensePlate,
+        country: &CountryCode,
+    ) -> Result<(), VehicleError> {
+        match (
+            self.plate_registry.validate(country, plate.value()),
+            self.policy,
+        ) {
+            (Ok(()), _) | (Err(_), ValidationPolicy::LegacyImport) => Ok(()),
+            (
+                Err(LicensePlateValidationError::UnsupportedCountry(_)),
+                ValidationPolicy::Lenient,
+            ) => Ok(()),
+            (Err(e), _) => Err(e.into()),
+        }
+    }
+}
+
+fn collapse_whitespace(value: &str) -> String {
+    value.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_vehicle() -> NewVehicle {
+        NewVehicle {
+            make: " toyota ".to_string(),
+            model: "Land  Cruiser".to_string(),
+            year: 2021,
+            vin: "1HGBH41JXMN109186".to_string(),
+            license_plate: "123 abc 45".to_string(),
+            country: "KZ".to_string(),
+            engine_type: "diesel".to_string(),
+            battery_capacity: None,
+            tank_capacity: Some(rust_decimal::Decimal::from(93)),
+            legacy_vin: false,
+        }
+    }
+
+    fn validator(policy: ValidationPolicy) -> VehicleValidator<'static> {
+        VehicleValidator::new(policy).with_current_year(2025)
+    }
+
+    fn errors(result: Result<ValidatedVehicle, VehicleError>) -> Vec<&'static str> {
+        match result {
+            Err(VehicleError::Invalid(errors)) => errors.iter().map(VehicleError::field).collect(),
+            other => panic!("expected field errors, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_normalizes_fields() {
+        let vehicle = validator(ValidationPolicy::Strict)
+            .validate(new_vehicle())
+            .unwrap();
+        assert_eq!(vehicle.make, "Toyota");
+        assert_eq!(vehicle.model, "Land Cruiser");
+        assert_eq!(vehicle.license_plate.value(), "123ABC45");
+    }
+
+    #[test]
+    fn test_reports_all_errors() {
+        let data = NewVehicle {
+            make: String::new(),
+            model: " ".to_string(),
+            year: 2030,
+            vin: "SHORT".to_string(),
+            license_plate: "A123BC777".to_string(),
+            ..new_vehicle()
+        };
+        assert_eq!(
+            errors(validator(ValidationPolicy::Lenient).validate(data)),
+            ["make", "model", "year", "vin", "license_plate"]
+        );
+    }
+
+    #[test]
+    fn test_year_range() {
+        let validator = validator(ValidationPolicy::Lenient);
+        assert_eq!(validator.year_range(), (DEFAULT_MIN_YEAR, 2026));
+        let next_year = NewVehicle {
+            year: 2026,
+            ..new_vehicle()
+        };
+        assert!(validator.validate(next_year).is_ok());
+        let too_old = NewVehicle {
+            year: 1949,
+            ..new_vehicle()
+        };
+        assert_eq!(errors(validator.validate(too_old)), ["year"]);
+    }
+
+    #[test]
+    fn test_unknown_make_only_rejected_when_strict() {
+        let data = NewVehicle {
+            make: "Acme  Motors".to_string(),
+            ..new_vehicle()
+        };
+        assert_eq!(
+            errors(validator(ValidationPolicy::Strict).validate(data.clone())),
+            ["make"]
+        );
+        let vehicle = validator(ValidationPolicy::Lenient).validate(data).unwrap();
+        assert_eq!(vehicle.make, "Acme Motors");
+    }
+
+    #[test]
+    fn test_check_digit_only_enforced_when_strict() {
+        let data = NewVehicle {
+            vin: "1HGBH41J1MN109186".to_string(),
+            ..new_vehicle()
+        };
+        assert_eq!(
+            errors(validator(ValidationPolicy::Strict).validate(data.clone())),
+            ["vin"]
+        );
+        assert!(validator(ValidationPolicy::Lenient).validate(data).is_ok());
+    }
+
+    #[test]
+    fn test_check_digit_not_enforced_outside_north_america() {
+        for vin in [
+            "WVWZZZ1JZ3W386752",
+            "WVWZZZ1JZXW000001",
+            "JTDBR32E700000001",
+        ] {
+            let data = NewVehicle {
+                vin: vin.to_string(),
+                ..new_vehicle()
+            };
+            let vehicle = validator(ValidationPolicy::Strict)
+                .validate(data)
+                .unwrap_or_else(|e| panic!("{} is accepted, got {:?}", vin, e));
+            assert_eq!(vehicle.vin.value(), vin);
+        }
+    }
+
+    #[test]
+    fn test_unsupported_country() {
+        let data = NewVehicle {
+            license_plate: "ABC123".to_string(),
+            country: "JP".to_string(),
+            ..new_vehicle()
+        };
+        assert_eq!(
+            errors(validator(ValidationPolicy::Strict).validate(data.clone())),
+            ["license_plate"]
+        );
+        assert!(validator(ValidationPolicy::Lenient).validate(data).is_ok());
+    }
+
+    #[test]
+    fn test_powertrain() {
+        let data = NewVehicle {
+            engine_type: "electric".to_string(),
+            ..new_vehicle()
+        };
+        assert_eq!(
+            errors(validator(ValidationPolicy::Lenient).validate(data)),
+            ["powertrain"]
+        );
+    }
+
+    #[test]
+    fn test_legacy_import() {
+        let data = NewVehicle {
+            year: 1967,
+            vin: "124377N123456".to_string(),
+            license_plate: "OLD1".to_string(),
+            ..new_vehicle()
+        };
+        assert_eq!(
+            errors(validator(ValidationPolicy::Lenient).validate(data.clone())),
+            ["vin", "license_plate"]
+        );
+        let vehicle = validator(ValidationPolicy::LegacyImport)
+            .validate(data)
+            .unwrap();
+        assert!(vehicle.vin.is_legacy());
+    }
+}
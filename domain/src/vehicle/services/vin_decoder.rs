@@ -0,0 +1,316 @@
+//! Decodes a VIN (ISO 3779) into manufacturer, country and model year, and cross-checks the
+//! decoded values with the data submitted for a vehicle.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * Legacy (pre-1981 / non-ISO) VINs are never decoded.
+//! * The model year code repeats every 30 years, so the decoder returns both candidates. In North
+//!   America, the 7th character tells them apart: a digit means 1980-2009, a letter 2010-2039.
+//! * The model year code and the check digit are only mandatory in North America: elsewhere the
+//!   10th character may be anything, and neither is a mismatch.
+//! * An unknown manufacturer is not a mismatch, the make is simply not checked.
+use crate::vehicle::{
+    services::wmi_table::{COUNTRY_TABLE, RANGE_ORDER, WMI_TABLE, WmiEntry},
+    value_types::vehicle_vin::VehicleVin,
+};
+use std::fmt;
+
+/// Model year codes (10th character), starting with 1980 / 2010.
+const MODEL_YEAR_CODES: &str = "ABCDEFGHJKLMNPRSTVWXY123456789";
+
+/// Geographic region of the manufacturer, from the first character of the VIN.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VinRegion {
+    Africa,
+    Asia,
+    Europe,
+    NorthAmerica,
+    Oceania,
+    SouthAmerica,
+}
+
+impl VinRegion {
+    fn from_code(code: char) -> Option<Self> {
+        match code {
+            'A'..='H' => Some(VinRegion::Africa),
+            'J'..='R' => Some(VinRegion::Asia),
+            'S'..='Z' => Some(VinRegion::Europe),
+            '1'..='5' => Some(VinRegion::NorthAmerica),
+            '6' | '7' => Some(VinRegion::Oceania),
+            '8' | '9' => Some(VinRegion::SouthAmerica),
+            _ => None,
+        }
+    }
+
+    /// Returns the string representation of the region
+    pub fn as_str(&self) -> &str {
+        match self {
+            VinRegion::Africa => "Africa",
+            VinRegion::Asia => "Asia",
+            VinRegion::Europe => "Europe",
+            VinRegion::NorthAmerica => "North America",
+            VinRegion::Oceania => "Oceania",
+            VinRegion::SouthAmerica => "South America",
+        }
+    }
+}
+
+/// Information extracted from a VIN.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedVin {
+    /// World Manufacturer Identifier (characters 1-3).
+    pub wmi: String,
+    /// Vehicle Descriptor Section (characters 4-9).
+    pub vds: String,
+    /// Vehicle Identifier Section (characters 10-17).
+    pub vis: String,
+    pub region: Option<VinRegion>,
+    pub country: Option<&'static str>,
+    /// The manufacturer, if the WMI is in the embedded table.
+    pub manufacturer: Option<&'static WmiEntry>,
+    /// Possible model years, most likely first (empty if the 10th character is not a year code).
+    pub model_years: Vec<u16>,
+    pub check_digit_valid: bool,
+    /// Assembly plant code (11th character).
+    pub plant_code: char,
+    /// Production sequence number (characters 12-17).
+    pub serial_number: String,
+}
+
+/// A difference between the decoded VIN and the submitted vehicle data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VinMismatch {
+    Make {
+        submitted: String,
+        decoded: &'static str,
+    },
+    Year {
+        submitted: u16,
+        decoded: Vec<u16>,
+    },
+    CheckDigit,
+}
+
+impl fmt::Display for VinMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VinMismatch::Make { submitted, decoded } => {
+                write!(f, "make '{}' doesn't match the VIN manufacturer '{}'", submitted, decoded)
+            }
+            VinMismatch::Year { submitted, decoded } => {
+                let years: Vec<String> = decoded.iter().map(u16::to_string).collect();
+                write!(
+                    f,
+                    "year {} doesn't match the VIN model year ({})",
+                    submitted,
+                    years.join(" or ")
+                )
+            }
+            VinMismatch::CheckDigit => write!(f, "invalid VIN check digit"),
+        }
+    }
+}
+
+/// Decodes a VIN, returns `None` for legacy VINs
+pub fn decode_vin(vin: &VehicleVin) -> Option<DecodedVin> {
+    if vin.is_legacy() {
+        return None;
+    }
+
+    let value = vin.value();
+    let chars: Vec<char> = value.chars().collect();
+    let region = VinRegion::from_code(chars[0]);
+
+    Some(DecodedVin {
+        wmi: vin.wmi().to_string(),
+        vds: vin.vds().to_string(),
+        vis: vin.vis().to_string(),
+        region,
+        country: country(chars[0], chars[1]),
+        manufacturer: manufacturer(vin.wmi()),
+        model_years: model_years(chars[9], chars[6], region),
+        check_digit_valid: vin.has_valid_check_digit(),
+        plant_code: chars[10],
+        serial_number: value[11..].to_string(),
+    })
+}
+
+/// Compares the decoded VIN with the submitted make and year
+pub fn cross_check_vin(decoded: &DecodedVin, make: &str, year: u16) -> Vec<VinMismatch> {
+    let mut mismatches = Vec::new();
+
+    if let Some(entry) = decoded.manufacturer
+        && !matches_make(entry, make)
+    {
+        mismatches.push(VinMismatch::Make {
+            submitted: make.to_string(),
+            decoded: entry.manufacturer,
+        });
+    }
+
+    if decoded.region == Some(VinRegion::NorthAmerica)
+        && !decoded.model_years.is_empty()
+        && !decoded.model_years.contains(&year)
+    {
+        mismatches.push(VinMismatch::Year {
+            submitted: year,
+            decoded: decoded.model_years.clone(),
+        });
+    }
+
+    if decoded.region == Some(VinRegion::NorthAmerica) && !decoded.check_digit_valid {
+        mismatches.push(VinMismatch::CheckDigit);
+    }
+
+    mismatches
+}
+
+fn country(first: char, second: char) -> Option<&'static str> {
+    let position = |c: char| RANGE_ORDER.find(c);
+    let second = position(second)?;
+
+    COUNTRY_TABLE
+        .iter()
+        .find(|range| {
+            range.first == first
+                && position(range.second_from).is_some_and(|from| from <= second)
+                && position(range.second_to).is_some_and(|to| second <= to)
+        })
+        .map(|range| range.country)
+}
+
+fn manufacturer(wmi: &str) -> Option<&'static WmiEntry> {
+    WMI_TABLE
+        .iter()
+        .filter(|entry| wmi.starts_with(entry.code))
+        .max_by_key(|entry| entry.code.len())
+}
+
+fn model_years(code: char, seventh: char, region: Option<VinRegion>) -> Vec<u16> {
+    let Some(index) = MODEL_YEAR_CODES.find(code) else {
+        return Vec::new();
+    };
+    let first_cycle = 1980 + index as u16;
+    let second_cycle = 2010 + index as u16;
+
+    if region == Some(VinRegion::NorthAmerica) {
+        if seventh.is_ascii_digit() {
+            vec![first_cycle]
+        } else {
+            vec![second_cycle]
+        }
+    } else {
+        vec![second_cycle, first_cycle]
+    }
+}
+
+/// Compares makes ignoring case, spaces and punctuation; "Mercedes" matches "Mercedes-Benz"
+fn matches_make(entry: &WmiEntry, make: &str) -> bool {
+    let normalize = |value: &str| -> String {
+        value
+            .chars()
+            .filter(|c| c.is_alphanumeric())
+            .flat_map(char::to_lowercase)
+            .collect()
+    };
+    let make = normalize(make);
+    if make.is_empty() {
+        return false;
+    }
+
+    std::iter::once(entry.manufacturer)
+        .chain(entry.aliases.iter().copied())
+        .map(normalize)
+        .any(|name| name == make || name.starts_with(&make) || make.starts_with(&name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode(vin: &str) -> DecodedVin {
+        decode_vin(&VehicleVin::new(vin).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_decode_north_american_vin() {
+        let decoded = decode("1HGBH41JXMN109186");
+        assert_eq!(decoded.region, Some(VinRegion::NorthAmerica));
+        assert_eq!(decoded.country, Some("United States"));
+        assert_eq!(decoded.manufacturer.unwrap().manufacturer, "Honda");
+        // 7th character is a digit: first cycle
+        assert_eq!(decoded.model_years, vec![1991]);
+        assert!(decoded.check_digit_valid);
+        assert_eq!(decoded.plant_code, 'N');
+        assert_eq!(decoded.serial_number, "109186");
+    }
+
+    #[test]
+    fn test_decode_european_vin() {
+        let decoded = decode("WVWZZZ1JZ3W386752");
+        assert_eq!(decoded.region, Some(VinRegion::Europe));
+        assert_eq!(decoded.country, Some("Germany"));
+        assert_eq!(decoded.manufacturer.unwrap().manufacturer, "Volkswagen");
+        assert_eq!(decoded.model_years, vec![2033, 2003]);
+    }
+
+    #[test]
+    fn test_longest_prefix_wins() {
+        assert_eq!(decode("JTHBK1GG0D2000000").manufacturer.unwrap().manufacturer, "Lexus");
+        assert_eq!(decode("JTDKN3DU0A0000000").manufacturer.unwrap().manufacturer, "Toyota");
+    }
+
+    #[test]
+    fn test_legacy_vin_is_not_decoded() {
+        assert!(decode_vin(&VehicleVin::new_legacy("124377N123456").unwrap()).is_none());
+    }
+
+    #[test]
+    fn test_cross_check() {
+        let decoded = decode("1HGBH41JXMN109186");
+        assert!(cross_check_vin(&decoded, "honda", 1991).is_empty());
+
+        let mismatches = cross_check_vin(&decoded, "Toyota", 2021);
+        assert_eq!(mismatches.len(), 2);
+        assert!(matches!(mismatches[0], VinMismatch::Make { decoded: "Honda", .. }));
+        assert!(matches!(mismatches[1], VinMismatch::Year { submitted: 2021, .. }));
+    }
+
+    #[test]
+    fn test_make_aliases() {
+        let decoded = decode("WVWZZZ1JZ3W386752");
+        assert!(cross_check_vin(&decoded, "VW", 2003).is_empty());
+        assert!(cross_check_vin(&decoded, "Volks wagen", 2003).is_empty());
+    }
+
+    #[test]
+    fn test_check_digit_only_in_north_america() {
+        let decoded = decode("1HGBH41J1MN109186");
+        assert_eq!(cross_check_vin(&decoded, "Honda", 1991), vec![VinMismatch::CheckDigit]);
+
+        // European manufacturers are not required to compute it
+        let decoded = decode("WVWZZZ1JZ3W386752");
+        assert!(!decoded.check_digit_valid);
+        assert!(cross_check_vin(&decoded, "Volkswagen", 2003).is_empty());
+    }
+
+    #[test]
+    fn test_model_year_only_in_north_america() {
+        // A Mercedes-Benz C-Class of 2009 built in Germany, the 10th character is not a year code
+        let decoded = decode("WDD2040081A123456");
+        assert_eq!(decoded.model_years, vec![2031, 2001]);
+        assert!(cross_check_vin(&decoded, "Mercedes-Benz", 2009).is_empty());
+    }
+
+    #[test]
+    fn test_mismatch_display() {
+        let mismatch = VinMismatch::Year {
+            submitted: 2020,
+            decoded: vec![2033, 2003],
+        };
+        assert_eq!(
+            mismatch.to_string(),
+            "year 2020 doesn't match the VIN model year (2033 or 2003)"
+        );
+    }
+}
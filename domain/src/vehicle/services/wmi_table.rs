@@ -0,0 +1,181 @@
+//! Embedded, offline table of World Manufacturer Identifiers (ISO 3780) and country codes.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # Personal notes:
+//! * Only the manufacturers commonly seen in the fleet are listed. Unknown WMIs are not an error,
+//!   the make simply can't be cross-checked.
+//! * Entries are matched on the longest prefix: a 3 characters entry wins over a 2 characters one.
+
+/// A manufacturer identified by a WMI (or a WMI prefix).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WmiEntry {
+    /// WMI or WMI prefix (2 or 3 characters).
+    pub code: &'static str,
+    /// Make as usually written (e.g., "Volkswagen").
+    pub manufacturer: &'static str,
+    /// Other accepted spellings of the make (e.g., "VW").
+    pub aliases: &'static [&'static str],
+}
+
+/// A range of country codes (first two characters of the VIN).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CountryRange {
+    pub first: char,
+    /// First and last second character of the range, in VIN order (A-Z, then 1-9, then 0).
+    pub second_from: char,
+    pub second_to: char,
+    pub country: &'static str,
+}
+
+/// Order of the characters within a country range.
+pub const RANGE_ORDER: &str = "ABCDEFGHJKLMNPRSTUVWXYZ1234567890";
+
+const fn entry(code: &'static str, manufacturer: &'static str) -> WmiEntry {
+    WmiEntry {
+        code,
+        manufacturer,
+        aliases: &[],
+    }
+}
+
+const fn range(first: char, second_from: char, second_to: char, country: &'static str) -> CountryRange {
+    CountryRange {
+        first,
+        second_from,
+        second_to,
+        country,
+    }
+}
+
+pub const WMI_TABLE: &[WmiEntry] = &[
+    entry("1FA", "Ford"),
+    entry("1FM", "Ford"),
+    entry("1FT", "Ford"),
+    WmiEntry {
+        code: "1G1",
+        manufacturer: "Chevrolet",
+        aliases: &["Chevy"],
+    },
+    WmiEntry {
+        code: "1GC",
+        manufacturer: "Chevrolet",
+        aliases: &["Chevy"],
+    },
+    WmiEntry {
+        code: "1GN",
+        manufacturer: "Chevrolet",
+        aliases: &["Chevy"],
+    },
+    entry("1HG", "Honda"),
+    entry("1J4", "Jeep"),
+    entry("1N4", "Nissan"),
+    entry("2HG", "Honda"),
+    entry("2T1", "Toyota"),
+    WmiEntry {
+        code: "3VW",
+        manufacturer: "Volkswagen",
+        aliases: &["VW"],
+    },
+    entry("4T1", "Toyota"),
+    entry("5FN", "Honda"),
+    entry("5N1", "Nissan"),
+    entry("5YJ", "Tesla"),
+    WmiEntry {
+        code: "9BW",
+        manufacturer: "Volkswagen",
+        aliases: &["VW"],
+    },
+    entry("JF1", "Subaru"),
+    entry("JHM", "Honda"),
+    entry("JM1", "Mazda"),
+    entry("JN1", "Nissan"),
+    entry("JT", "Toyota"),
+    entry("JTH", "Lexus"),
+    entry("KMH", "Hyundai"),
+    entry("KNA", "Kia"),
+    entry("KND", "Kia"),
+    entry("LRW", "Tesla"),
+    entry("SAJ", "Jaguar"),
+    entry("SAL", "Land Rover"),
+    WmiEntry {
+        code: "TMB",
+        manufacturer: "Skoda",
+        aliases: &["Škoda"],
+    },
+    entry("VF1", "Renault"),
+    entry("VF3", "Peugeot"),
+    WmiEntry {
+        code: "VF7",
+        manufacturer: "Citroen",
+        aliases: &["Citroën"],
+    },
+    entry("WAU", "Audi"),
+    entry("WBA", "BMW"),
+    WmiEntry {
+        code: "WDB",
+        manufacturer: "Mercedes-Benz",
+        aliases: &["Mercedes", "MB"],
+    },
+    WmiEntry {
+        code: "WDD",
+        manufacturer: "Mercedes-Benz",
+        aliases: &["Mercedes", "MB"],
+    },
+    entry("WMA", "MAN"),
+    entry("WP0", "Porsche"),
+    WmiEntry {
+        code: "WV1",
+        manufacturer: "Volkswagen",
+        aliases: &["VW"],
+    },
+    WmiEntry {
+        code: "WV2",
+        manufacturer: "Volkswagen",
+        aliases: &["VW"],
+    },
+    WmiEntry {
+        code: "WVW",
+        manufacturer: "Volkswagen",
+        aliases: &["VW"],
+    },
+    WmiEntry {
+        code: "XTA",
+        manufacturer: "Lada",
+        aliases: &["VAZ", "AvtoVAZ"],
+    },
+    entry("YS2", "Scania"),
+    entry("YV1", "Volvo"),
+    entry("ZFA", "Fiat"),
+];
+
+pub const COUNTRY_TABLE: &[CountryRange] = &[
+    range('1', 'A', '0', "United States"),
+    range('4', 'A', '0', "United States"),
+    range('5', 'A', '0', "United States"),
+    range('2', 'A', '0', "Canada"),
+    range('3', 'A', 'W', "Mexico"),
+    range('6', 'A', 'W', "Australia"),
+    range('9', 'A', 'E', "Brazil"),
+    range('9', '3', '9', "Brazil"),
+    range('J', 'A', '0', "Japan"),
+    range('K', 'L', 'R', "South Korea"),
+    range('L', 'A', '0', "China"),
+    range('M', 'A', 'E', "India"),
+    range('S', 'A', 'M', "United Kingdom"),
+    range('S', 'N', 'T', "Germany"),
+    range('S', 'U', 'Z', "Poland"),
+    range('T', 'A', 'H', "Switzerland"),
+    range('T', 'J', 'P', "Czech Republic"),
+    range('T', 'R', 'V', "Hungary"),
+    range('V', 'A', 'E', "Austria"),
+    range('V', 'F', 'R', "France"),
+    range('V', 'S', 'W', "Spain"),
+    range('W', 'A', '0', "Germany"),
+    range('X', 'L', 'R', "Netherlands"),
+    range('X', 'S', 'W', "Russia"),
+    range('X', '3', '0', "Russia"),
+    range('Y', 'A', 'E', "Belgium"),
+    range('Y', 'F', 'K', "Finland"),
+    range('Y', 'S', 'W', "Sweden"),
+    range('Z', 'A', 'R', "Italy"),
+];
@@ -0,0 +1,118 @@
+//! Represents the country (or country subdivision) a vehicle is registered in.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * ISO 3166-1 alpha-2 code ("KZ", "DE"), optionally followed by an ISO 3166-2 subdivision for
+//!   countries where plates are issued by subdivisions ("US-CA", "US-NY").
+//! * Stored uppercase.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// Country code of a vehicle registration (e.g., "KZ", "US-CA")
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CountryCode {
+    value: String,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CountryCodeError {
+    #[error("Country code cannot be empty")]
+    Empty,
+    #[error("Invalid country code: {0}")]
+    InvalidFormat(String),
+}
+
+impl CountryCode {
+    /// Country used when none is given (the fleet's home country)
+    pub const DEFAULT: &'static str = "KZ";
+
+    /// Creates a new CountryCode after validating the format
+    pub fn new(value: impl Into<String>) -> Result<Self, CountryCodeError> {
+        let value = value.into().trim().to_uppercase();
+        if value.is_empty() {
+            return Err(CountryCodeError::Empty);
+        }
+
+        let (country, subdivision) = match value.split_once('-') {
+            Some((country, subdivision)) => (country, Some(subdivision)),
+            None => (value.as_str(), None),
+        };
+        let valid_country = country.len() == 2 && country.chars().all(|c| c.is_ascii_uppercase());
+        let valid_subdivision = subdivision.is_none_or(|subdivision| {
+            (1..=3).contains(&subdivision.len())
+                && subdivision.chars().all(|c| c.is_ascii_alphanumeric())
+        });
+        if !valid_country || !valid_subdivision {
+            return Err(CountryCodeError::InvalidFormat(value));
+        }
+
+        Ok(Self { value })
+    }
+
+    /// Returns the default country code
+    pub fn default_country() -> Self {
+        Self {
+            value: Self::DEFAULT.to_string(),
+        }
+    }
+
+    /// Returns the full code (e.g., "US-CA")
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Returns the ISO 3166-1 part of the code (e.g., "US")
+    pub fn country(&self) -> &str {
+        &self.value[0..2]
+    }
+
+    /// Returns the subdivision part of the code, if any (e.g., "CA")
+    pub fn subdivision(&self) -> Option<&str> {
+        self.value.get(3..)
+    }
+}
+
+impl fmt::Display for CountryCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl FromStr for CountryCode {
+    type Err = CountryCodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_country() {
+        let code = CountryCode::new(" kz ").unwrap();
+        assert_eq!(code.value(), "KZ");
+        assert_eq!(code.country(), "KZ");
+        assert_eq!(code.subdivision(), None);
+    }
+
+    #[test]
+    fn test_subdivision() {
+        let code = CountryCode::new("us-ca").unwrap();
+        assert_eq!(code.value(), "US-CA");
+        assert_eq!(code.country(), "US");
+        assert_eq!(code.subdivision(), Some("CA"));
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(matches!(CountryCode::new(""), Err(CountryCodeError::Empty)));
+        assert!(CountryCode::new("KAZ").is_err());
+        assert!(CountryCode::new("K1").is_err());
+        assert!(CountryCode::new("US-").is_err());
+        assert!(CountryCode::new("US-CALI").is_err());
+    }
+}
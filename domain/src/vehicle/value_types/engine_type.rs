@@ -1,4 +1,11 @@
 //! Represents a vehicle's engine type.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * `as_str` is the API / CSV representation (snake case), `as_sql` the value of the PostgreSQL
+//!   `engine_type` enum (Pascal case). Both are accepted by `FromStr`, so either round-trips.
+//! * `Other` is stored as `Other` in SQL, its name is kept in a separate column and read back as
+//!   is (not parsed again: a stored name like "EV" stays a custom type).
 
 use std::fmt;
 use std::str::FromStr;
@@ -12,16 +19,16 @@ pub enum EngineType {
     Diesel,
     /// Electric motor
     Electric,
-    // /// Hybrid (combination of gasoline and electric)
-    // Hybrid,
-    // /// Plug-in hybrid electric vehicle
-    // PluginHybrid,
-    // /// Compressed Natural Gas
-    // Cng,
-    // /// Liquefied Petroleum Gas
-    // Lpg,
-    // /// Hydrogen fuel cell
-    // Hydrogen,
+    /// Hybrid (combination of gasoline and electric)
+    Hybrid,
+    /// Plug-in hybrid electric vehicle
+    PluginHybrid,
+    /// Compressed Natural Gas
+    Cng,
+    /// Liquefied Petroleum Gas
+    Lpg,
+    /// Hydrogen fuel cell
+    Hydrogen,
     /// Other/Unknown engine type
     Other(String),
 }
@@ -52,11 +59,11 @@ impl EngineType {
             EngineType::Gasoline => "gasoline",
             EngineType::Diesel => "diesel",
             EngineType::Electric => "electric",
-            // EngineType::Hybrid => "hybrid",
-            // EngineType::PluginHybrid => "plugin_hybrid",
-            // EngineType::Cng => "cng",
-            // EngineType::Lpg => "lpg",
-            // EngineType::Hydrogen => "hydrogen",
+            EngineType::Hybrid => "hybrid",
+            EngineType::PluginHybrid => "plugin_hybrid",
+            EngineType::Cng => "cng",
+            EngineType::Lpg => "lpg",
+            EngineType::Hydrogen => "hydrogen",
             EngineType::Other(value) => value,
         }
     }
@@ -67,36 +74,78 @@ impl EngineType {
             EngineType::Gasoline => "Gasoline",
             EngineType::Diesel => "Diesel",
             EngineType::Electric => "Electric",
-            // EngineType::Hybrid => "Hybrid",
-            // EngineType::PluginHybrid => "Plug-in Hybrid",
-            // EngineType::Cng => "CNG",
-            // EngineType::Lpg => "LPG",
-            // EngineType::Hydrogen => "Hydrogen",
+            EngineType::Hybrid => "Hybrid",
+            EngineType::PluginHybrid => "Plug-in Hybrid",
+            EngineType::Cng => "CNG",
+            EngineType::Lpg => "LPG",
+            EngineType::Hydrogen => "Hydrogen",
             EngineType::Other(value) => value,
         }
     }
 
+    /// Returns the value of the SQL `engine_type` enum
+    pub fn as_sql(&self) -> &str {
+        match self {
+            EngineType::Gasoline => "Gasoline",
+            EngineType::Diesel => "Diesel",
+            EngineType::Electric => "Electric",
+            EngineType::Hybrid => "Hybrid",
+            EngineType::PluginHybrid => "PluginHybrid",
+            EngineType::Cng => "Cng",
+            EngineType::Lpg => "Lpg",
+            EngineType::Hydrogen => "Hydrogen",
+            EngineType::Other(_) => "Other",
+        }
+    }
+
+    /// Reads a value of the SQL `engine_type` enum, `other_name` is the name of an `Other` type
+    pub fn from_sql(value: &str, other_name: Option<&str>) -> Result<Self, EngineTypeError> {
+        match (value, other_name) {
+            ("Other", Some(name)) => match name.trim() {
+                "" => Err(EngineTypeError::Empty),
+                name => Ok(EngineType::Other(name.to_string())),
+            },
+            ("Other", None) => Err(EngineTypeError::Empty),
+            _ => Self::new(value),
+        }
+    }
+
     /// Checks if the engine type is electric or hybrid
     pub fn is_electric_powered(&self) -> bool {
         matches!(
             self,
-            EngineType::Electric // | EngineType::Hybrid | EngineType::PluginHybrid
+            EngineType::Electric | EngineType::Hybrid | EngineType::PluginHybrid
         )
     }
 
-    /// Checks if the engine type uses fossil fuels
-    pub fn uses_fossil_fuel(&self) -> bool {
+    /// Checks if the vehicle can be charged from the grid
+    pub fn is_chargeable(&self) -> bool {
+        matches!(self, EngineType::Electric | EngineType::PluginHybrid)
+    }
+
+    /// Checks if the engine type has an internal combustion engine
+    pub fn has_combustion_engine(&self) -> bool {
         matches!(
             self,
-            EngineType::Gasoline | EngineType::Diesel // | EngineType::Hybrid | EngineType::PluginHybrid
+            EngineType::Gasoline
+                | EngineType::Diesel
+                | EngineType::Hybrid
+                | EngineType::PluginHybrid
+                | EngineType::Cng
+                | EngineType::Lpg
         )
     }
 
+    /// Checks if the engine type uses fossil fuels
+    pub fn uses_fossil_fuel(&self) -> bool {
+        self.has_combustion_engine()
+    }
+
     /// Checks if the engine type is alternative fuel
     pub fn is_alternative_fuel(&self) -> bool {
         matches!(
             self,
-            EngineType::Electric // | EngineType::Cng | EngineType::Lpg | EngineType::Hydrogen
+            EngineType::Electric | EngineType::Cng | EngineType::Lpg | EngineType::Hydrogen
         )
     }
 
@@ -106,11 +155,11 @@ impl EngineType {
             EngineType::Gasoline,
             EngineType::Diesel,
             EngineType::Electric,
-            // EngineType::Hybrid,
-            // EngineType::PluginHybrid,
-            // EngineType::Cng,
-            // EngineType::Lpg,
-            // EngineType::Hydrogen,
+            EngineType::Hybrid,
+            EngineType::PluginHybrid,
+            EngineType::Cng,
+            EngineType::Lpg,
+            EngineType::Hydrogen,
         ]
     }
 }
@@ -125,11 +174,16 @@ impl FromStr for EngineType {
             "gasoline" | "petrol" | "gas" => Ok(EngineType::Gasoline),
             "diesel" => Ok(EngineType::Diesel),
             "electric" | "ev" | "bev" => Ok(EngineType::Electric),
-            // "hybrid" | "hev" => Ok(EngineType::Hybrid),
-            // "plugin_hybrid" | "plug_in_hybrid" | "phev" => Ok(EngineType::PluginHybrid),
-            // "cng" | "compressed_natural_gas" => Ok(EngineType::Cng),
-            // "lpg" | "liquefied_petroleum_gas" => Ok(EngineType::Lpg),
-            // "hydrogen" | "fuel_cell" | "fcev" => Ok(EngineType::Hydrogen),
+            "hybrid" | "hev" => Ok(EngineType::Hybrid),
+            "plugin_hybrid" | "pluginhybrid" | "plug_in_hybrid" | "phev" => {
+                Ok(EngineType::PluginHybrid)
+            }
+            "cng" | "compressed_natural_gas" => Ok(EngineType::Cng),
+            "lpg" | "liquefied_petroleum_gas" => Ok(EngineType::Lpg),
+            "hydrogen" | "fuel_cell" | "fcev" => Ok(EngineType::Hydrogen),
+            "other" => Err(EngineTypeError::InvalidType(
+                "the name of the engine type is required".to_string(),
+            )),
             _ => Ok(EngineType::Other(s.trim().to_string())),
         }
     }
@@ -166,7 +220,7 @@ mod tests {
         assert_eq!(EngineType::new("gasoline").unwrap(), EngineType::Gasoline);
         assert_eq!(EngineType::new("diesel").unwrap(), EngineType::Diesel);
         assert_eq!(EngineType::new("electric").unwrap(), EngineType::Electric);
-        // assert_eq!(EngineType::new("hybrid").unwrap(), EngineType::Hybrid);
+        assert_eq!(EngineType::new("hybrid").unwrap(), EngineType::Hybrid);
     }
 
     #[test]
@@ -180,7 +234,7 @@ mod tests {
     fn test_aliases() {
         assert_eq!(EngineType::new("petrol").unwrap(), EngineType::Gasoline);
         assert_eq!(EngineType::new("ev").unwrap(), EngineType::Electric);
-        // assert_eq!(EngineType::new("phev").unwrap(), EngineType::PluginHybrid);
+        assert_eq!(EngineType::new("phev").unwrap(), EngineType::PluginHybrid);
     }
 
     #[test]
@@ -197,8 +251,8 @@ mod tests {
     #[test]
     fn test_electric_powered() {
         assert!(EngineType::Electric.is_electric_powered());
-        // assert!(EngineType::Hybrid.is_electric_powered());
-        // assert!(EngineType::PluginHybrid.is_electric_powered());
+        assert!(EngineType::Hybrid.is_electric_powered());
+        assert!(EngineType::PluginHybrid.is_electric_powered());
         assert!(!EngineType::Gasoline.is_electric_powered());
     }
 
@@ -206,31 +260,80 @@ mod tests {
     fn test_fossil_fuel() {
         assert!(EngineType::Gasoline.uses_fossil_fuel());
         assert!(EngineType::Diesel.uses_fossil_fuel());
-        // assert!(EngineType::Hybrid.uses_fossil_fuel());
+        assert!(EngineType::Hybrid.uses_fossil_fuel());
         assert!(!EngineType::Electric.uses_fossil_fuel());
     }
 
     #[test]
     fn test_alternative_fuel() {
         assert!(EngineType::Electric.is_alternative_fuel());
-        // assert!(EngineType::Cng.is_alternative_fuel());
-        // assert!(EngineType::Hydrogen.is_alternative_fuel());
+        assert!(EngineType::Cng.is_alternative_fuel());
+        assert!(EngineType::Hydrogen.is_alternative_fuel());
         assert!(!EngineType::Gasoline.is_alternative_fuel());
     }
 
     #[test]
     fn test_display() {
         assert_eq!(format!("{}", EngineType::Gasoline), "Gasoline");
-        // assert_eq!(format!("{}", EngineType::PluginHybrid), "Plug-in Hybrid");
+        assert_eq!(format!("{}", EngineType::PluginHybrid), "Plug-in Hybrid");
         assert_eq!(
             format!("{}", EngineType::Other("Rotary".to_string())),
             "Rotary"
         );
     }
 
+    #[test]
+    fn test_sql_round_trip() {
+        for engine in EngineType::standard_types() {
+            assert_eq!(EngineType::new(engine.as_sql()).unwrap(), engine);
+            assert_eq!(EngineType::new(engine.as_str()).unwrap(), engine);
+        }
+        assert_eq!(EngineType::Other("rotary".to_string()).as_sql(), "Other");
+        assert!(EngineType::new("Other").is_err());
+        assert_eq!(
+            EngineType::from_sql("Other", Some("rotary")).unwrap(),
+            EngineType::Other("rotary".to_string())
+        );
+    }
+
+    #[test]
+    fn test_sql_round_trip_of_other_names() {
+        for name in ["EV", "gas", "Other", "rotary"] {
+            let engine = EngineType::Other(name.to_string());
+            assert_eq!(
+                EngineType::from_sql(engine.as_sql(), Some(engine.as_str())).unwrap(),
+                engine,
+                "{} is read back as a custom type",
+                name
+            );
+        }
+        assert_eq!(
+            EngineType::from_sql("Other", Some(" steam ")).unwrap(),
+            EngineType::Other("steam".to_string())
+        );
+        assert!(matches!(
+            EngineType::from_sql("Other", Some("  ")),
+            Err(EngineTypeError::Empty)
+        ));
+    }
+
+    #[test]
+    fn test_combustion_engine() {
+        assert!(EngineType::Hybrid.has_combustion_engine());
+        assert!(EngineType::Lpg.has_combustion_engine());
+        assert!(!EngineType::Electric.has_combustion_engine());
+        assert!(!EngineType::Hydrogen.has_combustion_engine());
+    }
+
+    #[test]
+    fn test_chargeable() {
+        assert!(EngineType::PluginHybrid.is_chargeable());
+        assert!(!EngineType::Hybrid.is_chargeable());
+    }
+
     #[test]
     fn test_as_str() {
         assert_eq!(EngineType::Gasoline.as_str(), "gasoline");
-        // assert_eq!(EngineType::PluginHybrid.as_str(), "plugin_hybrid");
+        assert_eq!(EngineType::PluginHybrid.as_str(), "plugin_hybrid");
     }
 }
@@ -1,15 +1,37 @@
-//! Represents a vehicle's license plate (in Kazakhstan).
-
+//! Represents a vehicle's license plate.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * Plates are stored normalized: uppercase, without spaces or dashes, and with Cyrillic letters
+//!   that look like Latin ones replaced by the Latin letter (А123ВС -> A123BC), so the same plate
+//!   typed on different keyboards is the same value.
+//! * `new` only checks what is true in every country (not empty, at most `MAX_LENGTH` letters and
+//!   digits). The format depends on the country the vehicle is registered in, see
+//!   `LicensePlateRegistry` and `for_country`.
+
+use crate::vehicle::{
+    services::license_plate_registry::LicensePlateRegistry, value_types::country_code::CountryCode,
+};
 use std::fmt;
 
-/// Represents a Kazakhstan vehicle license plate
+/// Represents a vehicle license plate
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct LicensePlate {
     value: String,
 }
 
+/// Longest normalized plate accepted in any country
+pub const MAX_LENGTH: usize = 10;
+
 #[derive(Debug, thiserror::Error)]
-pub enum LicensePlateError {}
+pub enum LicensePlateError {
+    #[error("License plate cannot be empty")]
+    Empty,
+    #[error("License plate too long: maximum {MAX_LENGTH} characters, got {0}")]
+    TooLong(usize),
+    #[error("License plate contains invalid characters: {0}")]
+    InvalidCharacters(String),
+}
 
 #[derive(Debug, thiserror::Error)]
 pub enum LicensePlateValidationError {
@@ -19,61 +41,82 @@ pub enum LicensePlateValidationError {
     Empty,
     #[error("License plate too long: maximum 8 characters")]
     TooLong,
+    #[error("No license plate format registered for country {0}")]
+    UnsupportedCountry(String),
 }
 
 impl LicensePlate {
-    /// Creates a new LicensePlate without validation
+    /// Creates a new LicensePlate after normalizing it and checking the characters and length,
+    /// without checking the format of a country
     pub fn new(value: impl Into<String>) -> Result<Self, LicensePlateError> {
-        let value = value.into().trim().to_uppercase();
-        Ok(Self { value })
-    }
-
-    /// Validates Kazakhstan license plate format
-    pub fn validate(value: &str) -> Result<(), LicensePlateValidationError> {
+        let value = Self::normalize(&value.into());
         if value.is_empty() {
-            return Err(LicensePlateValidationError::Empty);
+            return Err(LicensePlateError::Empty);
         }
 
-        if value.len() > 8 {
-            return Err(LicensePlateValidationError::TooLong);
+        let length = value.chars().count();
+        if length > MAX_LENGTH {
+            return Err(LicensePlateError::TooLong(length));
         }
 
-        // Kazakhstan license plate formats:
-        // - 123ABC45 (3 digits + 3 letters + 2 digits)
-        // - A123BCD (1 letter + 3 digits + 3 letters)
-        let is_valid = Self::is_standard_format(value) || Self::is_alternative_format(value);
-
-        if !is_valid {
-            return Err(LicensePlateValidationError::InvalidFormat(
-                value.to_string(),
-            ));
+        let invalid_chars: String = value
+            .chars()
+            .filter(|c| !c.is_ascii_alphanumeric())
+            .collect();
+        if !invalid_chars.is_empty() {
+            return Err(LicensePlateError::InvalidCharacters(invalid_chars));
         }
 
-        Ok(())
+        Ok(Self { value })
     }
 
-    /// Checks for 123ABC45 format (3 digits + 3 letters + 2 digits)
-    fn is_standard_format(value: &str) -> bool {
-        if value.len() != 8 {
-            return false;
-        }
+    /// Creates a new LicensePlate, validated against the formats of the country
+    pub fn for_country(
+        value: impl Into<String>,
+        country: &CountryCode,
+        registry: &LicensePlateRegistry,
+    ) -> Result<Self, LicensePlateValidationError> {
+        let value = Self::normalize(&value.into());
+        registry.validate(country, &value)?;
+        Ok(Self { value })
+    }
 
-        let chars: Vec<char> = value.chars().collect();
-        chars[0..3].iter().all(|c| c.is_ascii_digit())
-            && chars[3..6].iter().all(|c| c.is_ascii_alphabetic())
-            && chars[6..8].iter().all(|c| c.is_ascii_digit())
+    /// Uppercases, removes separators and replaces Cyrillic look-alike letters with Latin ones
+    pub fn normalize(value: &str) -> String {
+        value
+            .chars()
+            .filter(|c| !c.is_whitespace() && !matches!(c, '-' | '·' | '.'))
+            .flat_map(char::to_uppercase)
+            .map(|c| match c {
+                'А' => 'A',
+                'В' => 'B',
+                'Е' => 'E',
+                'К' => 'K',
+                'М' => 'M',
+                'Н' => 'H',
+                'О' => 'O',
+                'Р' => 'P',
+                'С' => 'C',
+                'Т' => 'T',
+                'У' => 'Y',
+                'Х' => 'X',
+                other => other,
+            })
+            .collect()
     }
 
-    /// Checks for A123BCD format (1 letter + 3 digits + 3 letters)
-    fn is_alternative_format(value: &str) -> bool {
-        if value.len() != 7 {
-            return false;
+    /// Validates Kazakhstan license plate format
+    pub fn validate(value: &str) -> Result<(), LicensePlateValidationError> {
+        if value.is_empty() {
+            return Err(LicensePlateValidationError::Empty);
+        }
+
+        if value.len() > 8 {
+            return Err(LicensePlateValidationError::TooLong);
         }
 
-        let chars: Vec<char> = value.chars().collect();
-        chars[0].is_ascii_alphabetic()
-            && chars[1..4].iter().all(|c| c.is_ascii_digit())
-            && chars[4..7].iter().all(|c| c.is_ascii_alphabetic())
+        // Without a country, the plate is checked against the default one (Kazakhstan)
+        LicensePlateRegistry::standard().validate(&CountryCode::default_country(), value)
     }
 
     /// Returns the license plate value as a string
@@ -156,6 +199,39 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_new_checks_characters_and_length() {
+        assert!(matches!(
+            LicensePlate::new(" - "),
+            Err(LicensePlateError::Empty)
+        ));
+        assert!(matches!(
+            LicensePlate::new("ABC12345678"),
+            Err(LicensePlateError::TooLong(11))
+        ));
+        assert!(matches!(
+            LicensePlate::new("Ж123ЖЖ"),
+            Err(LicensePlateError::InvalidCharacters(chars)) if chars == "ЖЖЖ"
+        ));
+    }
+
+    #[test]
+    fn test_normalize_cyrillic_and_separators() {
+        let plate = LicensePlate::new("а 123 вс-77").unwrap();
+        assert_eq!(plate.value(), "A123BC77");
+    }
+
+    #[test]
+    fn test_for_country() {
+        let registry = LicensePlateRegistry::standard();
+        let russia = CountryCode::new("RU").unwrap();
+        let plate = LicensePlate::for_country("А123ВС 777", &russia, registry).unwrap();
+        assert_eq!(plate.value(), "A123BC777");
+
+        let kazakhstan = CountryCode::default_country();
+        assert!(LicensePlate::for_country("A123BC777", &kazakhstan, registry).is_err());
+    }
+
     #[test]
     fn test_display() {
         let plate = LicensePlate::new("123ABC45").unwrap();
@@ -1,3 +1,6 @@
+pub mod country_code;
 pub mod engine_type;
 pub mod license_plate;
+pub mod powertrain;
+pub mod validation_policy;
 pub mod vehicle_vin;
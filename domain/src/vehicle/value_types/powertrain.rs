@@ -0,0 +1,133 @@
+//! Represents a vehicle's powertrain: its engine type and energy storage.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * The battery capacity (kWh) is the traction battery, only vehicles with an electric drive have
+//!   one. The tank capacity (liters) is the fuel tank, only vehicles with a combustion engine have
+//!   one.
+//! * Both capacities are optional (often unknown when importing) and must be positive when set.
+//! * `Other` engine types accept both, nothing is known about them.
+
+use crate::vehicle::value_types::engine_type::EngineType;
+use rust_decimal::Decimal;
+
+/// Engine type of a vehicle with the capacity of its battery and fuel tank
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Powertrain {
+    engine_type: EngineType,
+    /// Traction battery capacity in kWh
+    battery_capacity: Option<Decimal>,
+    /// Fuel tank capacity in liters
+    tank_capacity: Option<Decimal>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PowertrainError {
+    #[error("Capacity must be positive, got {0}")]
+    NonPositiveCapacity(Decimal),
+    #[error("A {0} vehicle has no traction battery")]
+    BatteryNotApplicable(EngineType),
+    #[error("A {0} vehicle has no fuel tank")]
+    TankNotApplicable(EngineType),
+}
+
+impl Powertrain {
+    /// Creates a powertrain, checking the capacities apply to the engine type
+    pub fn new(
+        engine_type: EngineType,
+        battery_capacity: Option<Decimal>,
+        tank_capacity: Option<Decimal>,
+    ) -> Result<Self, PowertrainError> {
+        let is_other = matches!(engine_type, EngineType::Other(_));
+
+        if let Some(capacity) = battery_capacity {
+            if capacity <= Decimal::ZERO {
+                return Err(PowertrainError::NonPositiveCapacity(capacity));
+            }
+            if !is_other && !engine_type.is_electric_powered() {
+                return Err(PowertrainError::BatteryNotApplicable(engine_type));
+            }
+        }
+        if let Some(capacity) = tank_capacity {
+            if capacity <= Decimal::ZERO {
+                return Err(PowertrainError::NonPositiveCapacity(capacity));
+            }
+            if !is_other && !engine_type.has_combustion_engine() {
+                return Err(PowertrainError::TankNotApplicable(engine_type));
+            }
+        }
+
+        Ok(Powertrain {
+            engine_type,
+            battery_capacity,
+            tank_capacity,
+        })
+    }
+
+    /// Creates a powertrain with unknown capacities
+    pub fn from_engine_type(engine_type: EngineType) -> Self {
+        Powertrain {
+            engine_type,
+            battery_capacity: None,
+            tank_capacity: None,
+        }
+    }
+
+    pub fn engine_type(&self) -> &EngineType {
+        &self.engine_type
+    }
+
+    pub fn battery_capacity(&self) -> Option<Decimal> {
+        self.battery_capacity
+    }
+
+    pub fn tank_capacity(&self) -> Option<Decimal> {
+        self.tank_capacity
+    }
+}
+
+impl From<EngineType> for Powertrain {
+    fn from(engine_type: EngineType) -> Self {
+        Self::from_engine_type(engine_type)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capacities_follow_engine_type() {
+        let hybrid = Powertrain::new(
+            EngineType::PluginHybrid,
+            Some(Decimal::new(138, 1)),
+            Some(Decimal::from(45)),
+        )
+        .unwrap();
+        assert_eq!(hybrid.battery_capacity(), Some(Decimal::new(138, 1)));
+        assert_eq!(hybrid.tank_capacity(), Some(Decimal::from(45)));
+
+        assert!(matches!(
+            Powertrain::new(EngineType::Electric, None, Some(Decimal::from(50))),
+            Err(PowertrainError::TankNotApplicable(EngineType::Electric))
+        ));
+        assert!(matches!(
+            Powertrain::new(EngineType::Diesel, Some(Decimal::from(10)), None),
+            Err(PowertrainError::BatteryNotApplicable(EngineType::Diesel))
+        ));
+    }
+
+    #[test]
+    fn test_non_positive_capacity() {
+        assert!(matches!(
+            Powertrain::new(EngineType::Electric, Some(Decimal::ZERO), None),
+            Err(PowertrainError::NonPositiveCapacity(_))
+        ));
+    }
+
+    #[test]
+    fn test_other_accepts_everything() {
+        let other = EngineType::Other("rotary".to_string());
+        assert!(Powertrain::new(other, Some(Decimal::ONE), Some(Decimal::ONE)).is_ok());
+    }
+}
@@ -0,0 +1,75 @@
+//! Represents how strictly vehicle data is validated on creation.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * `Strict` is meant for vehicles entered by hand: the make must be known, the VIN check digit
+//!   must be valid and the license plate must match a format of its country.
+//! * `Lenient` accepts unknown makes, VINs without a check digit (most of Europe) and plates of
+//!   countries without a registered format.
+//! * `LegacyImport` is `Lenient` for old records: non-ISO VINs are kept as legacy VINs, plate
+//!   formats are not checked and the minimum year is lowered.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// Validation policy applied when a vehicle is created
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ValidationPolicy {
+    Strict,
+    #[default]
+    Lenient,
+    LegacyImport,
+}
+
+impl ValidationPolicy {
+    /// Returns the string representation of the policy
+    pub fn as_str(&self) -> &str {
+        match self {
+            ValidationPolicy::Strict => "strict",
+            ValidationPolicy::Lenient => "lenient",
+            ValidationPolicy::LegacyImport => "legacy_import",
+        }
+    }
+}
+
+impl fmt::Display for ValidationPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for ValidationPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "strict" => Ok(ValidationPolicy::Strict),
+            "lenient" => Ok(ValidationPolicy::Lenient),
+            "legacy_import" | "legacy" => Ok(ValidationPolicy::LegacyImport),
+            _ => Err(format!("Invalid validation policy: {}", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("Strict".parse(), Ok(ValidationPolicy::Strict));
+        assert_eq!("legacy".parse(), Ok(ValidationPolicy::LegacyImport));
+        assert!("loose".parse::<ValidationPolicy>().is_err());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for policy in [
+            ValidationPolicy::Strict,
+            ValidationPolicy::Lenient,
+            ValidationPolicy::LegacyImport,
+        ] {
+            assert_eq!(policy.as_str().parse(), Ok(policy));
+        }
+    }
+}
@@ -1,4 +1,12 @@
 //! Represents a vehicle's VIN (Vehicle Identification Number).
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * A VIN is a 17 characters ISO 3779 identifier (letters I, O and Q are not allowed).
+//! * The check digit is only mandatory in North America, so it is not enforced on creation; use
+//!   `validate` to check it.
+//! * Vehicles built before 1981 (or outside of ISO 3779) have shorter identifiers. They can only be
+//!   created explicitly with `new_legacy` and are never decoded.
 
 use std::fmt;
 
@@ -6,10 +14,21 @@ use std::fmt;
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct VehicleVin {
     value: String,
+    /// Pre-1981 / non-ISO identifier
+    legacy: bool,
 }
 
 #[derive(Debug, thiserror::Error)]
-pub enum VehicleVinError {}
+pub enum VehicleVinError {
+    #[error("VIN cannot be empty")]
+    Empty,
+    #[error("VIN must be exactly 17 characters long, got {0}")]
+    InvalidLength(usize),
+    #[error("Legacy VIN must be at most 17 characters long, got {0}")]
+    InvalidLegacyLength(usize),
+    #[error("VIN contains invalid characters: {0}")]
+    InvalidCharacters(String),
+}
 
 #[derive(Debug, thiserror::Error)]
 pub enum VehicleVinValidationError {
@@ -26,10 +45,57 @@ pub enum VehicleVinValidationError {
 }
 
 impl VehicleVin {
-    /// Creates a new VehicleVin after validating the format
+    /// Creates a new VehicleVin after validating the format (length and characters)
     pub fn new(value: impl Into<String>) -> Result<Self, VehicleVinError> {
         let value = value.into().trim().to_uppercase();
-        Ok(Self { value })
+        if value.is_empty() {
+            return Err(VehicleVinError::Empty);
+        }
+
+        let length = value.chars().count();
+        if length != 17 {
+            return Err(VehicleVinError::InvalidLength(length));
+        }
+
+        let invalid_chars: String = value
+            .chars()
+            .filter(|&c| !Self::is_valid_vin_character(c))
+            .collect();
+        if !invalid_chars.is_empty() {
+            return Err(VehicleVinError::InvalidCharacters(invalid_chars));
+        }
+
+        Ok(Self {
+            value,
+            legacy: false,
+        })
+    }
+
+    /// Creates a pre-1981 / non-ISO VIN: up to 17 alphanumeric characters, letters I, O and Q
+    /// allowed. Such VINs can't be decoded.
+    pub fn new_legacy(value: impl Into<String>) -> Result<Self, VehicleVinError> {
+        let value = value.into().trim().to_uppercase();
+        if value.is_empty() {
+            return Err(VehicleVinError::Empty);
+        }
+
+        let length = value.chars().count();
+        if length > 17 {
+            return Err(VehicleVinError::InvalidLegacyLength(length));
+        }
+
+        let invalid_chars: String = value
+            .chars()
+            .filter(|c| !c.is_ascii_alphanumeric())
+            .collect();
+        if !invalid_chars.is_empty() {
+            return Err(VehicleVinError::InvalidCharacters(invalid_chars));
+        }
+
+        Ok(Self {
+            value,
+            legacy: true,
+        })
     }
 
     /// Validates VIN format and check digit
@@ -64,7 +130,7 @@ impl VehicleVin {
         Ok(())
     }
 
-    /// Checks if character is valid in VIN (alphanumeric except I, O, Q)
+    /// Checks if the character is valid in a VIN (alphanumeric except I, O, Q)
     fn is_valid_vin_character(c: char) -> bool {
         c.is_ascii_alphanumeric() && !matches!(c, 'I' | 'O' | 'Q')
     }
@@ -119,29 +185,46 @@ impl VehicleVin {
         self.value
     }
 
-    /// Returns the World Manufacturer Identifier (first 3 characters)
+    /// Checks the check digit (9th character), always false for legacy VINs
+    pub fn has_valid_check_digit(&self) -> bool {
+        !self.legacy && Self::is_valid_check_digit(&self.value)
+    }
+
+    /// Checks if the VIN is a pre-1981 / non-ISO identifier
+    pub fn is_legacy(&self) -> bool {
+        self.legacy
+    }
+
+    /// Returns the World Manufacturer Identifier (first 3 characters), empty for legacy VINs
     pub fn wmi(&self) -> &str {
-        &self.value[0..3]
+        self.section(0..3)
     }
 
-    /// Returns the Vehicle Descriptor Section (characters 4-9)
+    /// Returns the Vehicle Descriptor Section (characters 4-9), empty for legacy VINs
     pub fn vds(&self) -> &str {
-        &self.value[3..9]
+        self.section(3..9)
     }
 
-    /// Returns the Vehicle Identifier Section (characters 10-17)
+    /// Returns the Vehicle Identifier Section (characters 10-17), empty for legacy VINs
     pub fn vis(&self) -> &str {
-        &self.value[9..17]
+        self.section(9..17)
     }
 
-    /// Returns the model year character (10th position)
-    pub fn model_year_code(&self) -> char {
-        self.value.chars().nth(9).unwrap()
+    /// Returns the model year character (10th position), `None` for legacy VINs
+    pub fn model_year_code(&self) -> Option<char> {
+        self.section(9..10).chars().next()
     }
 
-    /// Returns the assembly plant code (11th position)
-    pub fn assembly_plant_code(&self) -> char {
-        self.value.chars().nth(10).unwrap()
+    /// Returns the assembly plant code (11th position), `None` for legacy VINs
+    pub fn assembly_plant_code(&self) -> Option<char> {
+        self.section(10..11).chars().next()
+    }
+
+    fn section(&self, range: std::ops::Range<usize>) -> &str {
+        if self.legacy {
+            return "";
+        }
+        self.value.get(range).unwrap_or_default()
     }
 }
 
@@ -208,8 +291,31 @@ mod tests {
         assert_eq!(vin.wmi(), "1HG");
         assert_eq!(vin.vds(), "BH41JX");
         assert_eq!(vin.vis(), "MN109186");
-        assert_eq!(vin.model_year_code(), 'M');
-        assert_eq!(vin.assembly_plant_code(), 'N');
+        assert_eq!(vin.model_year_code(), Some('M'));
+        assert_eq!(vin.assembly_plant_code(), Some('N'));
+    }
+
+    #[test]
+    fn test_new_rejects_invalid_format() {
+        assert!(matches!(VehicleVin::new(" "), Err(VehicleVinError::Empty)));
+        assert!(matches!(
+            VehicleVin::new("1HGBH41JXMN10918"),
+            Err(VehicleVinError::InvalidLength(16))
+        ));
+        assert!(matches!(
+            VehicleVin::new("1HGBH41JXMN109I86"),
+            Err(VehicleVinError::InvalidCharacters(_))
+        ));
+    }
+
+    #[test]
+    fn test_legacy_vin() {
+        let vin = VehicleVin::new_legacy("124377n123456").unwrap();
+        assert!(vin.is_legacy());
+        assert_eq!(vin.value(), "124377N123456");
+        assert_eq!(vin.wmi(), "");
+        assert_eq!(vin.model_year_code(), None);
+        assert!(VehicleVin::new_legacy("ABC-123").is_err());
     }
 
     #[test]
@@ -0,0 +1,54 @@
+use domain::{
+    depot::repositories::{
+        depot_repository::DepotRepository, vehicle_movement_repository::VehicleMovementRepository,
+    },
+    diagnostics::repositories::diagnostic_repository::DiagnosticRepository,
+    inspection::repositories::inspection_repository::InspectionRepository,
+    maintenance::repositories::{
+        maintenance_record_repository::MaintenanceRecordRepository,
+        maintenance_repository::MaintenanceRepository,
+        maintenance_type_repository::MaintenanceTypeRepository,
+    },
+    organization::repositories::organization_repository::OrganizationRepository,
+    telematics::repositories::telematics_device_repository::TelematicsDeviceRepository,
+    trip::repositories::trip_repository::TripRepository,
+    user::entities::user::UserIdentity,
+    vehicle::repositories::{
+        vehicle_repository::VehicleRepository, vehicle_status_repository::VehicleStatusRepository,
+    },
+};
+use std::future::Future;
+
+/// The repositories of a backend sharing the same storage. A repository is a handle on the
+/// storage: a clone shares it, and keeps it alive without the backend.
+pub trait ConformanceBackend {
+    type Organizations: OrganizationRepository + Clone;
+    type Vehicles: VehicleRepository + Clone;
+    type VehicleStatuses: VehicleStatusRepository + Clone;
+    type MaintenanceTypes: MaintenanceTypeRepository + Clone;
+    type Maintenances: MaintenanceRepository + Clone;
+    type MaintenanceRecords: MaintenanceRecordRepository + Clone;
+    type Depots: DepotRepository + Clone;
+    type VehicleMovements: VehicleMovementRepository + Clone;
+    type TelematicsDevices: TelematicsDeviceRepository + Clone;
+    type Trips: TripRepository + Clone;
+    type Diagnostics: DiagnosticRepository + Clone;
+    type Inspections: InspectionRepository + Clone;
+
+    fn organizations(&self) -> &Self::Organizations;
+    fn vehicles(&self) -> &Self::Vehicles;
+    fn vehicle_statuses(&self) -> &Self::VehicleStatuses;
+    fn maintenance_types(&self) -> &Self::MaintenanceTypes;
+    fn maintenances(&self) -> &Self::Maintenances;
+    fn maintenance_records(&self) -> &Self::MaintenanceRecords;
+    fn depots(&self) -> &Self::Depots;
+    fn vehicle_movements(&self) -> &Self::VehicleMovements;
+    fn telematics_devices(&self) -> &Self::TelematicsDevices;
+    fn trips(&self) -> &Self::Trips;
+    fn diagnostics(&self) -> &Self::Diagnostics;
+    fn inspections(&self) -> &Self::Inspections;
+
+    /// Adds a user of an existing organization, referenced by the `created_by` / `updated_by`
+    /// columns.
+    fn insert_user(&self, user: UserIdentity) -> impl Future<Output = Result<(), String>>;
+}
@@ -0,0 +1,185 @@
+//! Contracts spanning several repositories: the cascades of the schema.
+use crate::{backend::ConformanceBackend, fixtures};
+use domain::{
+    depot::repositories::vehicle_movement_repository::VehicleMovementRepository,
+    diagnostics::{
+        entities::diagnostic_alert::DiagnosticAlert,
+        repositories::diagnostic_repository::DiagnosticRepository,
+    },
+    inspection::repositories::inspection_repository::InspectionRepository,
+    maintenance::repositories::{
+        maintenance_repository::MaintenanceRepository,
+        maintenance_type_repository::MaintenanceTypeRepository,
+    },
+    telematics::repositories::telematics_device_repository::TelematicsDeviceRepository,
+    trip::repositories::trip_repository::TripRepository,
+    vehicle::repositories::{
+        vehicle_repository::VehicleRepository, vehicle_status_repository::VehicleStatusRepository,
+    },
+};
+use std::future::Future;
+
+/// Runs every check, each on a new backend from `new_backend`.
+pub async fn run<B, F, Fut>(new_backend: F)
+where
+    B: ConformanceBackend,
+    F: Fn() -> Fut,
+    Fut: Future<Output = B>,
+{
+    vehicle_delete_cascades(&new_backend().await).await;
+}
+
+/// Deleting a vehicle deletes its statuses, maintenance rules, records, movements, devices,
+/// trips, DTC events, diagnostic alerts, inspection reports and defects.
+pub async fn vehicle_delete_cascades(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let user = fixtures::user(backend, &organization, "alice").await;
+    let vehicle = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    let other = fixtures::vehicle(backend, &organization, 2, "456DEF02").await;
+    let status = fixtures::status(backend, &vehicle, &user, 1, 10_000).await;
+    fixtures::status(backend, &other, &user, 1, 20_000).await;
+    let oil_change = fixtures::maintenance_type(backend, "Oil Change", &user).await;
+    let maintenance =
+        fixtures::maintenance(backend, oil_change.id, &vehicle, &user, "Kilometers").await;
+    fixtures::record(backend, &maintenance, &status, &user, 1, None).await;
+    let north = fixtures::region(backend, &organization, "North").await;
+    let astana = fixtures::depot(backend, &organization, &north, "Astana").await;
+    fixtures::movement(backend, &vehicle, &astana, &user, 1).await;
+    fixtures::device(backend, &organization, &vehicle, "imei-1").await;
+    fixtures::trip(backend, &organization, &vehicle, Some(&user), 1, 2).await;
+    let event = fixtures::dtc_event(backend, &organization, &vehicle, "P0301", 1).await;
+    let rule = fixtures::diagnostic_rule(backend, &organization, "Misfires", None).await;
+    backend
+        .diagnostics()
+        .open_alert(organization.id, DiagnosticAlert::open(&rule, &event))
+        .await
+        .expect("alert opened");
+    let template = fixtures::inspection_template(backend, &user, "Pre-trip").await;
+    let report = fixtures::inspection_report(backend, &template, &status, &user, &["Brakes"]).await;
+
+    let deleted = backend
+        .vehicles()
+        .delete(organization.id, vehicle.id)
+        .await
+        .expect("vehicle deleted");
+    assert!(deleted, "an existing vehicle is deleted");
+
+    let again = backend
+        .vehicles()
+        .delete(organization.id, vehicle.id)
+        .await
+        .expect("delete ran");
+    assert!(!again, "a deleted vehicle can't be deleted twice");
+
+    let found = backend
+        .vehicles()
+        .find_by_id(organization.id, vehicle.id)
+        .await
+        .expect("vehicle read");
+    assert!(found.is_none());
+
+    let statuses = backend
+        .vehicle_statuses()
+        .find_by_vehicle(organization.id, vehicle.id, None, None)
+        .await
+        .expect("statuses read");
+    assert!(
+        statuses.is_empty(),
+        "the statuses are deleted with the vehicle"
+    );
+
+    let rules = backend
+        .maintenances()
+        .find_by_maintenance_type(organization.id, oil_change.id)
+        .await
+        .expect("rules read");
+    assert!(rules.is_empty(), "the rules are deleted with the vehicle");
+
+    let usage = backend
+        .maintenance_types()
+        .usage(organization.id, oil_change.id)
+        .await
+        .expect("usage read");
+    assert!(
+        !usage.is_in_use(),
+        "the records are deleted with the vehicle"
+    );
+
+    let movements = backend
+        .vehicle_movements()
+        .find_by_vehicle(organization.id, vehicle.id)
+        .await
+        .expect("movements read");
+    assert!(
+        movements.is_empty(),
+        "the movements are deleted with the vehicle"
+    );
+
+    let devices = backend
+        .telematics_devices()
+        .find_by_vehicle(organization.id, vehicle.id)
+        .await
+        .expect("devices read");
+    assert!(
+        devices.is_empty(),
+        "the devices are deleted with the vehicle"
+    );
+
+    let trips = backend
+        .trips()
+        .find_by_vehicle(organization.id, vehicle.id, None, None)
+        .await
+        .expect("trips read");
+    assert!(trips.is_empty(), "the trips are deleted with the vehicle");
+
+    let events = backend
+        .diagnostics()
+        .find_events_by_vehicle(organization.id, vehicle.id, true)
+        .await
+        .expect("events read");
+    assert!(
+        events.is_empty(),
+        "the DTC events are deleted with the vehicle"
+    );
+    let alerts = backend
+        .diagnostics()
+        .find_alerts_by_vehicle(organization.id, vehicle.id, false)
+        .await
+        .expect("alerts read");
+    assert!(
+        alerts.is_empty(),
+        "the diagnostic alerts are deleted with the vehicle"
+    );
+
+    let found = backend
+        .inspections()
+        .find_report(organization.id, report.id)
+        .await
+        .expect("report read");
+    assert!(
+        found.is_none(),
+        "the inspection reports are deleted with the vehicle"
+    );
+    let defects = backend
+        .inspections()
+        .find_defects_by_vehicle(organization.id, vehicle.id, false)
+        .await
+        .expect("defects read");
+    assert!(
+        defects.is_empty(),
+        "the inspection defects are deleted with the vehicle"
+    );
+    let templates = backend
+        .inspections()
+        .find_templates(organization.id)
+        .await
+        .expect("templates read");
+    assert_eq!(templates, vec![template], "the templates are kept");
+
+    let others = backend
+        .vehicle_statuses()
+        .find_by_vehicle(organization.id, other.id, None, None)
+        .await
+        .expect("statuses read");
+    assert_eq!(others.len(), 1, "other vehicles keep their statuses");
+}
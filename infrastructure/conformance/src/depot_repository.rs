@@ -0,0 +1,276 @@
+//! Contracts of `DepotRepository`.
+use crate::{backend::ConformanceBackend, fixtures};
+use domain::depot::{
+    entities::{depot::Depot, region::Region, site::Site},
+    repositories::depot_repository::{DepotRepository, DepotRepositoryError},
+    value_types::location_name::LocationName,
+};
+use futures::future::join_all;
+use std::future::Future;
+
+/// Runs every check, each on a new backend from `new_backend`.
+pub async fn run<B, F, Fut>(new_backend: F)
+where
+    B: ConformanceBackend,
+    F: Fn() -> Fut,
+    Fut: Future<Output = B>,
+{
+    create_and_find(&new_backend().await).await;
+    hierarchy_by_name(&new_backend().await).await;
+    duplicate_names_already_exist(&new_backend().await).await;
+    depot_managers(&new_backend().await).await;
+    isolated_per_organization(&new_backend().await).await;
+    concurrent_creates_of_a_name(&new_backend().await).await;
+}
+
+fn name(value: &str) -> LocationName {
+    LocationName::new(value).expect("valid name")
+}
+
+/// A created region or depot is found by its id, with its fields as given.
+pub async fn create_and_find(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let region = fixtures::region(backend, &organization, "North").await;
+    let depot = fixtures::depot(backend, &organization, &region, "Astana").await;
+    let depots = backend.depots();
+
+    let found = depots
+        .find_region(organization.id, region.id)
+        .await
+        .expect("region read")
+        .expect("created region is found");
+    assert_eq!(found.id, region.id);
+    assert_eq!(found.name.value(), "North");
+
+    let found = depots
+        .find_depot(organization.id, depot.id)
+        .await
+        .expect("depot read")
+        .expect("created depot is found");
+    assert_eq!(found.id, depot.id);
+    assert_eq!(found.region_id, region.id);
+    assert_eq!(found.name.value(), "Astana");
+
+    let missing = depots
+        .find_region(organization.id, uuid::Uuid::new_v4())
+        .await
+        .expect("region read");
+    assert!(missing.is_none(), "an unknown id finds no region");
+    let missing = depots
+        .find_depot(organization.id, uuid::Uuid::new_v4())
+        .await
+        .expect("depot read");
+    assert!(missing.is_none(), "an unknown id finds no depot");
+}
+
+/// Regions, depots and sites are listed by name.
+pub async fn hierarchy_by_name(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let south = fixtures::region(backend, &organization, "South").await;
+    let north = fixtures::region(backend, &organization, "North").await;
+    let shymkent = fixtures::depot(backend, &organization, &south, "Shymkent").await;
+    let astana = fixtures::depot(backend, &organization, &north, "Astana").await;
+    fixtures::site(backend, &organization, &shymkent, "Yard").await;
+    fixtures::site(backend, &organization, &astana, "Workshop").await;
+    fixtures::site(backend, &organization, &astana, "Parking").await;
+    let depots = backend.depots();
+
+    let regions = depots
+        .find_regions(organization.id)
+        .await
+        .expect("regions read");
+    let names: Vec<&str> = regions.iter().map(|region| region.name.value()).collect();
+    assert_eq!(names, vec!["North", "South"]);
+
+    let all = depots
+        .find_depots(organization.id)
+        .await
+        .expect("depots read");
+    let names: Vec<(&str, uuid::Uuid)> = all
+        .iter()
+        .map(|depot| (depot.name.value(), depot.region_id))
+        .collect();
+    assert_eq!(names, vec![("Astana", north.id), ("Shymkent", south.id)]);
+
+    let sites = depots
+        .find_sites(organization.id)
+        .await
+        .expect("sites read");
+    let names: Vec<(&str, uuid::Uuid)> = sites
+        .iter()
+        .map(|site| (site.name.value(), site.depot_id))
+        .collect();
+    assert_eq!(
+        names,
+        vec![
+            ("Parking", astana.id),
+            ("Workshop", astana.id),
+            ("Yard", shymkent.id)
+        ]
+    );
+}
+
+/// Region and depot names are unique in the organization, site names in their depot.
+pub async fn duplicate_names_already_exist(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let north = fixtures::region(backend, &organization, "North").await;
+    let south = fixtures::region(backend, &organization, "South").await;
+    let astana = fixtures::depot(backend, &organization, &north, "Astana").await;
+    let karaganda = fixtures::depot(backend, &organization, &north, "Karaganda").await;
+    fixtures::site(backend, &organization, &astana, "Yard").await;
+    let depots = backend.depots();
+
+    let region = depots
+        .create_region(organization.id, Region::new(name("North")))
+        .await;
+    assert!(
+        matches!(region, Err(DepotRepositoryError::AlreadyExists(_))),
+        "a taken region name already exists, got {:?}",
+        region
+    );
+
+    let depot = depots
+        .create_depot(organization.id, Depot::new(south.id, name("Astana")))
+        .await;
+    assert!(
+        matches!(depot, Err(DepotRepositoryError::AlreadyExists(_))),
+        "a depot name is taken in every region, got {:?}",
+        depot
+    );
+
+    let site = depots
+        .create_site(organization.id, Site::new(astana.id, name("Yard")))
+        .await;
+    assert!(
+        matches!(site, Err(DepotRepositoryError::AlreadyExists(_))),
+        "a taken site name of the depot already exists, got {:?}",
+        site
+    );
+    depots
+        .create_site(organization.id, Site::new(karaganda.id, name("Yard")))
+        .await
+        .expect("another depot has its own site names");
+}
+
+/// A user manages the depots they are assigned to, an assignment is idempotent.
+pub async fn depot_managers(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let manager = fixtures::user(backend, &organization, "alice").await;
+    let other = fixtures::user(backend, &organization, "bob").await;
+    let north = fixtures::region(backend, &organization, "North").await;
+    let astana = fixtures::depot(backend, &organization, &north, "Astana").await;
+    let karaganda = fixtures::depot(backend, &organization, &north, "Karaganda").await;
+    let depots = backend.depots();
+
+    let none = depots
+        .managed_depots(organization.id, manager.id)
+        .await
+        .expect("managed depots read");
+    assert!(none.is_empty(), "a user manages no depot until assigned");
+
+    for depot in [&astana, &karaganda, &astana] {
+        depots
+            .assign_manager(organization.id, depot.id, manager.id)
+            .await
+            .expect("manager assigned");
+    }
+
+    let mut expected = vec![astana.id, karaganda.id];
+    expected.sort();
+    let managed = depots
+        .managed_depots(organization.id, manager.id)
+        .await
+        .expect("managed depots read");
+    assert_eq!(managed, expected, "an assignment is only recorded once");
+
+    let unrelated = depots
+        .managed_depots(organization.id, other.id)
+        .await
+        .expect("managed depots read");
+    assert!(unrelated.is_empty());
+}
+
+/// The locations and managers of an organization are not read in another one, which has its own
+/// names.
+pub async fn isolated_per_organization(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let other = fixtures::organization(backend, "Altai Transit").await;
+    let manager = fixtures::user(backend, &organization, "alice").await;
+    let north = fixtures::region(backend, &organization, "North").await;
+    let astana = fixtures::depot(backend, &organization, &north, "Astana").await;
+    fixtures::site(backend, &organization, &astana, "Yard").await;
+    let depots = backend.depots();
+    depots
+        .assign_manager(organization.id, astana.id, manager.id)
+        .await
+        .expect("manager assigned");
+
+    let regions = depots.find_regions(other.id).await.expect("regions read");
+    assert!(
+        regions.is_empty(),
+        "the regions are not read in another organization"
+    );
+    let all = depots.find_depots(other.id).await.expect("depots read");
+    assert!(
+        all.is_empty(),
+        "the depots are not read in another organization"
+    );
+    let sites = depots.find_sites(other.id).await.expect("sites read");
+    assert!(
+        sites.is_empty(),
+        "the sites are not read in another organization"
+    );
+    let region = depots
+        .find_region(other.id, north.id)
+        .await
+        .expect("region read");
+    assert!(
+        region.is_none(),
+        "a region is not found in another organization"
+    );
+    let depot = depots
+        .find_depot(other.id, astana.id)
+        .await
+        .expect("depot read");
+    assert!(
+        depot.is_none(),
+        "a depot is not found in another organization"
+    );
+    let managed = depots
+        .managed_depots(other.id, manager.id)
+        .await
+        .expect("managed depots read");
+    assert!(
+        managed.is_empty(),
+        "the managers are not read in another organization"
+    );
+
+    let other_north = fixtures::region(backend, &other, "North").await;
+    fixtures::depot(backend, &other, &other_north, "Astana").await;
+}
+
+/// Concurrent creates of a region name: one is created, the others already exist.
+pub async fn concurrent_creates_of_a_name(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let depots = backend.depots();
+
+    let results =
+        join_all((0..8).map(|_| depots.create_region(organization.id, Region::new(name("North")))))
+            .await;
+
+    let created = results.iter().filter(|result| result.is_ok()).count();
+    assert_eq!(created, 1, "a single region is created");
+    for result in &results {
+        assert!(
+            matches!(result, Ok(_) | Err(DepotRepositoryError::AlreadyExists(_))),
+            "the other creates already exist, got {:?}",
+            result
+        );
+    }
+
+    let regions = depots
+        .find_regions(organization.id)
+        .await
+        .expect("regions read");
+    assert_eq!(regions.len(), 1);
+}
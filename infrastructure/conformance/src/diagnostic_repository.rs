@@ -0,0 +1,432 @@
+//! Contracts of `DiagnosticRepository`.
+use crate::{backend::ConformanceBackend, fixtures};
+use domain::{
+    diagnostics::{
+        entities::{
+            diagnostic_alert::DiagnosticAlert,
+            diagnostic_rule::{DiagnosticAction, DiagnosticRule},
+            dtc_event::DtcEvent,
+        },
+        repositories::diagnostic_repository::{DiagnosticRepository, DiagnosticRepositoryError},
+        value_types::{dtc_code::DtcCode, dtc_severity::DtcSeverity, freeze_frame::FreezeFrame},
+    },
+    maintenance::repositories::maintenance_type_repository::MaintenanceTypeRepository,
+};
+use futures::future::join_all;
+use std::future::Future;
+
+/// Runs every check, each on a new backend from `new_backend`.
+pub async fn run<B, F, Fut>(new_backend: F)
+where
+    B: ConformanceBackend,
+    F: Fn() -> Fut,
+    Fut: Future<Output = B>,
+{
+    open_and_find_events(&new_backend().await).await;
+    one_active_event_per_code(&new_backend().await).await;
+    update_event(&new_backend().await).await;
+    rules_by_name(&new_backend().await).await;
+    rules_follow_their_maintenance_type(&new_backend().await).await;
+    open_and_resolve_alerts(&new_backend().await).await;
+    isolated_per_organization(&new_backend().await).await;
+    concurrent_opens(&new_backend().await).await;
+}
+
+/// An opened event is found by its id and as the active event of its code, freeze frame
+/// included.
+pub async fn open_and_find_events(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let vehicle = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    let opened = fixtures::dtc_event(backend, &organization, &vehicle, "P0301", 1).await;
+    let diagnostics = backend.diagnostics();
+
+    let found = diagnostics
+        .find_event(organization.id, opened.id)
+        .await
+        .expect("event read")
+        .expect("opened event is found");
+    assert_eq!(found, opened);
+    assert_eq!(
+        found
+            .freeze_frame
+            .parameters()
+            .get("rpm")
+            .map(String::as_str),
+        Some("2150"),
+        "the freeze frame is kept"
+    );
+
+    let active = diagnostics
+        .find_active_event(organization.id, vehicle.id, &opened.code)
+        .await
+        .expect("event read");
+    assert_eq!(active, Some(opened));
+
+    let other_code = diagnostics
+        .find_active_event(
+            organization.id,
+            vehicle.id,
+            &DtcCode::new("P0302").expect("valid code"),
+        )
+        .await
+        .expect("event read");
+    assert!(other_code.is_none(), "another code has no active event");
+
+    let missing = diagnostics
+        .find_event(organization.id, uuid::Uuid::new_v4())
+        .await
+        .expect("event read");
+    assert!(missing.is_none(), "an unknown id finds no event");
+}
+
+/// A code has at most one active event per vehicle; a cleared code may be opened again.
+pub async fn one_active_event_per_code(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let alice = fixtures::user(backend, &organization, "alice").await;
+    let vehicle = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    let other = fixtures::vehicle(backend, &organization, 2, "456DEF02").await;
+    let mut first = fixtures::dtc_event(backend, &organization, &vehicle, "P0301", 1).await;
+    fixtures::dtc_event(backend, &organization, &other, "P0301", 1).await;
+    let diagnostics = backend.diagnostics();
+
+    let duplicate = DtcEvent::new(
+        vehicle.id,
+        first.code.clone(),
+        DtcSeverity::Critical,
+        FreezeFrame::default(),
+        fixtures::at(2),
+    );
+    match diagnostics.open_event(organization.id, duplicate).await {
+        Err(DiagnosticRepositoryError::AlreadyExists(code)) => assert_eq!(code, "P0301"),
+        other => panic!("expected AlreadyExists, got {:?}", other),
+    }
+
+    first
+        .clear(fixtures::at(3), alice.id)
+        .expect("active event");
+    let cleared = diagnostics
+        .update_event(organization.id, first.clone())
+        .await
+        .expect("event updated");
+    assert!(cleared);
+    let second = fixtures::dtc_event(backend, &organization, &vehicle, "P0301", 4).await;
+
+    let active = diagnostics
+        .find_events_by_vehicle(organization.id, vehicle.id, false)
+        .await
+        .expect("events read");
+    assert_eq!(active, vec![second.clone()], "only the active events");
+    let all = diagnostics
+        .find_events_by_vehicle(organization.id, vehicle.id, true)
+        .await
+        .expect("events read");
+    assert_eq!(
+        all,
+        vec![first, second],
+        "by first seen, the cleared ones too"
+    );
+}
+
+/// An update saves the times, occurrences, severity and clearing of an event.
+pub async fn update_event(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let alice = fixtures::user(backend, &organization, "alice").await;
+    let vehicle = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    let mut event = fixtures::dtc_event(backend, &organization, &vehicle, "P0301", 2).await;
+    let diagnostics = backend.diagnostics();
+
+    event
+        .seen_again(fixtures::at(1), DtcSeverity::Critical)
+        .expect("active event");
+    event
+        .seen_again(fixtures::at(5), DtcSeverity::Info)
+        .expect("active event");
+    let updated = diagnostics
+        .update_event(organization.id, event.clone())
+        .await
+        .expect("event updated");
+    assert!(updated);
+    let found = diagnostics
+        .find_event(organization.id, event.id)
+        .await
+        .expect("event read")
+        .expect("event exists");
+    assert_eq!(found.first_seen_at, fixtures::at(1));
+    assert_eq!(found.last_seen_at, fixtures::at(5));
+    assert_eq!(found.occurrences, 3);
+    assert_eq!(found.severity, DtcSeverity::Critical);
+
+    event
+        .clear(fixtures::at(6), alice.id)
+        .expect("active event");
+    diagnostics
+        .update_event(organization.id, event.clone())
+        .await
+        .expect("event updated");
+    let found = diagnostics
+        .find_event(organization.id, event.id)
+        .await
+        .expect("event read")
+        .expect("event exists");
+    assert_eq!(found, event);
+    assert_eq!(found.cleared_by, Some(alice.id));
+
+    let unknown = DtcEvent::new(
+        vehicle.id,
+        DtcCode::new("P0420").expect("valid code"),
+        DtcSeverity::Warning,
+        FreezeFrame::default(),
+        fixtures::at(1),
+    );
+    let updated = diagnostics
+        .update_event(organization.id, unknown)
+        .await
+        .expect("update ran");
+    assert!(!updated, "an unknown event is not updated");
+}
+
+/// The rules are read by name, a name is unique in an organization.
+pub async fn rules_by_name(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let alice = fixtures::user(backend, &organization, "alice").await;
+    let inspection = fixtures::maintenance_type(backend, "Engine Inspection", &alice).await;
+    let misfires =
+        fixtures::diagnostic_rule(backend, &organization, "Misfires", Some(inspection.id)).await;
+    let alerts = fixtures::diagnostic_rule(backend, &organization, "All warnings", None).await;
+    let diagnostics = backend.diagnostics();
+
+    let rules = diagnostics
+        .find_rules(organization.id)
+        .await
+        .expect("rules read");
+    assert_eq!(rules, vec![alerts, misfires.clone()]);
+    assert_eq!(
+        rules[1].action,
+        DiagnosticAction::MaintenanceTask {
+            maintenance_type_id: inspection.id
+        }
+    );
+
+    let duplicate = DiagnosticRule {
+        id: uuid::Uuid::new_v4(),
+        ..misfires
+    };
+    match diagnostics.create_rule(organization.id, duplicate).await {
+        Err(DiagnosticRepositoryError::AlreadyExists(name)) => assert_eq!(name, "Misfires"),
+        other => panic!("expected AlreadyExists, got {:?}", other),
+    }
+}
+
+/// A merge of maintenance types re-points the rules opening tasks of the source; deleting a
+/// maintenance type deletes its rules.
+pub async fn rules_follow_their_maintenance_type(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let alice = fixtures::user(backend, &organization, "alice").await;
+    let source = fixtures::maintenance_type(backend, "Engine Check", &alice).await;
+    let target = fixtures::maintenance_type(backend, "Engine Inspection", &alice).await;
+    let rule = fixtures::diagnostic_rule(backend, &organization, "Misfires", Some(source.id)).await;
+
+    let source_entity = fixtures::maintenance_type_entity(backend, &organization, source.id).await;
+    let target_entity = fixtures::maintenance_type_entity(backend, &organization, target.id).await;
+    backend
+        .maintenance_types()
+        .merge(
+            organization.id,
+            source_entity,
+            target_entity.clone(),
+            alice.id,
+        )
+        .await
+        .expect("maintenance types merged");
+    let rules = backend
+        .diagnostics()
+        .find_rules(organization.id)
+        .await
+        .expect("rules read");
+    assert_eq!(rules.len(), 1, "the rule outlives the source type");
+    assert_eq!(rules[0].id, rule.id);
+    assert_eq!(rules[0].action.maintenance_type_id(), Some(target.id));
+
+    backend
+        .maintenance_types()
+        .delete(organization.id, target_entity, alice.id)
+        .await
+        .expect("maintenance type deleted");
+    let rules = backend
+        .diagnostics()
+        .find_rules(organization.id)
+        .await
+        .expect("rules read");
+    assert!(rules.is_empty(), "the rule is deleted with its type");
+}
+
+/// A rule opens one alert per event; resolving the alerts of an event closes the open ones.
+pub async fn open_and_resolve_alerts(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let vehicle = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    let misfire = fixtures::dtc_event(backend, &organization, &vehicle, "P0301", 2).await;
+    let catalyst = fixtures::dtc_event(backend, &organization, &vehicle, "P0420", 1).await;
+    let misfires = fixtures::diagnostic_rule(backend, &organization, "Misfires", None).await;
+    let warnings = fixtures::diagnostic_rule(backend, &organization, "Warnings", None).await;
+    let diagnostics = backend.diagnostics();
+
+    let mut opened = Vec::new();
+    for (rule, event) in [
+        (&misfires, &misfire),
+        (&warnings, &misfire),
+        (&warnings, &catalyst),
+    ] {
+        let alert = diagnostics
+            .open_alert(organization.id, DiagnosticAlert::open(rule, event))
+            .await
+            .expect("alert opened");
+        opened.push(alert);
+    }
+    match diagnostics
+        .open_alert(organization.id, DiagnosticAlert::open(&misfires, &misfire))
+        .await
+    {
+        Err(DiagnosticRepositoryError::AlreadyExists(_)) => {}
+        other => panic!("expected AlreadyExists, got {:?}", other),
+    }
+
+    let alerts = diagnostics
+        .find_alerts_by_vehicle(organization.id, vehicle.id, true)
+        .await
+        .expect("alerts read");
+    assert_eq!(alerts.len(), 3);
+    assert_eq!(
+        alerts[0], opened[2],
+        "by opening, which is the first report of the event"
+    );
+
+    let resolved = diagnostics
+        .resolve_alerts(organization.id, misfire.id, fixtures::at(3))
+        .await
+        .expect("alerts resolved");
+    assert_eq!(resolved, 2);
+    let again = diagnostics
+        .resolve_alerts(organization.id, misfire.id, fixtures::at(4))
+        .await
+        .expect("alerts resolved");
+    assert_eq!(again, 0, "resolved alerts are not resolved twice");
+
+    let open = diagnostics
+        .find_alerts_by_vehicle(organization.id, vehicle.id, true)
+        .await
+        .expect("alerts read");
+    assert_eq!(open, vec![opened[2].clone()]);
+    let all = diagnostics
+        .find_alerts_by_vehicle(organization.id, vehicle.id, false)
+        .await
+        .expect("alerts read");
+    assert_eq!(all.len(), 3);
+    assert!(
+        all.iter()
+            .filter(|alert| alert.dtc_event_id == misfire.id)
+            .all(|alert| alert.resolved_at == Some(fixtures::at(3)))
+    );
+}
+
+/// The events, rules and alerts are not read or written in another organization.
+pub async fn isolated_per_organization(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let other = fixtures::organization(backend, "Altai Transit").await;
+    let vehicle = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    let event = fixtures::dtc_event(backend, &organization, &vehicle, "P0301", 1).await;
+    let rule = fixtures::diagnostic_rule(backend, &organization, "Misfires", None).await;
+    let diagnostics = backend.diagnostics();
+    diagnostics
+        .open_alert(organization.id, DiagnosticAlert::open(&rule, &event))
+        .await
+        .expect("alert opened");
+
+    let found = diagnostics
+        .find_event(other.id, event.id)
+        .await
+        .expect("event read");
+    assert!(
+        found.is_none(),
+        "the event is not read in another organization"
+    );
+    let active = diagnostics
+        .find_active_event(other.id, vehicle.id, &event.code)
+        .await
+        .expect("event read");
+    assert!(active.is_none());
+    let events = diagnostics
+        .find_events_by_vehicle(other.id, vehicle.id, true)
+        .await
+        .expect("events read");
+    assert!(events.is_empty());
+    let rules = diagnostics.find_rules(other.id).await.expect("rules read");
+    assert!(
+        rules.is_empty(),
+        "the rules are not read in another organization"
+    );
+    let alerts = diagnostics
+        .find_alerts_by_vehicle(other.id, vehicle.id, false)
+        .await
+        .expect("alerts read");
+    assert!(alerts.is_empty());
+
+    let updated = diagnostics
+        .update_event(
+            other.id,
+            DtcEvent {
+                occurrences: 9,
+                ..event.clone()
+            },
+        )
+        .await
+        .expect("update ran");
+    assert!(
+        !updated,
+        "the event is not updated from another organization"
+    );
+    let resolved = diagnostics
+        .resolve_alerts(other.id, event.id, fixtures::at(2))
+        .await
+        .expect("resolve ran");
+    assert_eq!(
+        resolved, 0,
+        "the alerts are not resolved from another organization"
+    );
+
+    // The same name is free in another organization
+    fixtures::diagnostic_rule(backend, &other, "Misfires", None).await;
+}
+
+/// Concurrent reports of a new code open a single event, the others are told it exists.
+pub async fn concurrent_opens(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let vehicle = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    let diagnostics = backend.diagnostics();
+
+    let results = join_all((0..8).map(|day| {
+        let event = DtcEvent::new(
+            vehicle.id,
+            DtcCode::new("P0301").expect("valid code"),
+            DtcSeverity::Warning,
+            FreezeFrame::default(),
+            fixtures::at(day),
+        );
+        diagnostics.open_event(organization.id, event)
+    }))
+    .await;
+    let opened = results.iter().filter(|result| result.is_ok()).count();
+    assert_eq!(opened, 1, "a single event is opened");
+    assert!(
+        results.iter().all(|result| matches!(
+            result,
+            Ok(_) | Err(DiagnosticRepositoryError::AlreadyExists(_))
+        )),
+        "the other reports find the code active"
+    );
+
+    let events = diagnostics
+        .find_events_by_vehicle(organization.id, vehicle.id, true)
+        .await
+        .expect("events read");
+    assert_eq!(events.len(), 1);
+}
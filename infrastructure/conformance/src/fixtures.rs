@@ -0,0 +1,588 @@
+//! Entities of the checks, created through the repositories of the backend. The fixtures taking
+//! a user create the entities in the organization of the user.
+use crate::backend::ConformanceBackend;
+use domain::{
+    depot::{
+        entities::{depot::Depot, region::Region, site::Site, vehicle_movement::VehicleMovement},
+        repositories::{
+            depot_repository::DepotRepository,
+            vehicle_movement_repository::VehicleMovementRepository,
+        },
+        value_types::location_name::LocationName,
+    },
+    diagnostics::{
+        entities::{
+            diagnostic_rule::{DiagnosticAction, DiagnosticRule, NewDiagnosticRule},
+            dtc_event::DtcEvent,
+        },
+        repositories::diagnostic_repository::DiagnosticRepository,
+        value_types::{dtc_code::DtcCode, dtc_severity::DtcSeverity, freeze_frame::FreezeFrame},
+    },
+    inspection::{
+        entities::{
+            inspection_defect::InspectionDefect,
+            inspection_report::{InspectionReport, ItemAnswer},
+            inspection_template::{InspectionTemplate, NewInspectionItem, NewInspectionTemplate},
+        },
+        repositories::inspection_repository::InspectionRepository,
+        value_types::{inspection_kind::InspectionKind, item_result::ItemResult},
+    },
+    maintenance::{
+        entities::{
+            maintenance::{Maintenance, NewMaintenance},
+            maintenance_cost::{MaintenanceCost, MaintenanceCostLine, MaintenanceCostLineKind},
+            maintenance_record::{MaintenanceRecord, MaintenanceRecordIdentity},
+            maintenance_type::{MaintenanceType, MaintenanceTypeView},
+        },
+        repositories::{
+            maintenance_record_repository::MaintenanceRecordRepository,
+            maintenance_repository::MaintenanceRepository,
+            maintenance_type_repository::MaintenanceTypeRepository,
+        },
+        value_types::{
+            currency::Currency, maintenance_applicability::MaintenanceApplicability, money::Money,
+        },
+    },
+    organization::{
+        entities::organization::Organization,
+        repositories::organization_repository::OrganizationRepository,
+    },
+    telematics::{
+        entities::telematics_device::TelematicsDevice,
+        repositories::telematics_device_repository::TelematicsDeviceRepository,
+        value_types::device_id::DeviceId,
+    },
+    trip::{
+        entities::trip::{NewTrip, Trip},
+        repositories::trip_repository::TripRepository,
+        value_types::geo_point::GeoPoint,
+    },
+    user::{
+        entities::user::UserIdentity,
+        value_types::{Email, UserId},
+    },
+    vehicle::{
+        entities::{
+            vehicle::{NewVehicle, VehicleIdentity},
+            vehicle_status::VehicleStatusIdentity,
+        },
+        repositories::{
+            vehicle_repository::VehicleRepository,
+            vehicle_status_repository::VehicleStatusRepository,
+        },
+    },
+};
+use rust_decimal::Decimal;
+
+/// A point in time of the checks, `day` days after an arbitrary origin (whole seconds, which
+/// every backend stores exactly).
+pub fn at(day: i64) -> chrono::DateTime<chrono::Utc> {
+    chrono::DateTime::from_timestamp(1_750_000_000 + day * 86_400, 0).expect("valid timestamp")
+}
+
+/// A vehicle registered in `country`, the VIN is unique per `index`.
+pub fn new_vehicle(index: u32, license_plate: &str, country: &str) -> NewVehicle {
+    NewVehicle {
+        make: "Toyota".to_string(),
+        model: "Camry".to_string(),
+        year: 2021,
+        vin: format!("JTDBR32E7{:08}", index),
+        license_plate: license_plate.to_string(),
+        country: country.to_string(),
+        engine_type: "gasoline".to_string(),
+        battery_capacity: None,
+        tank_capacity: Some(Decimal::new(605, 1)),
+        legacy_vin: false,
+    }
+}
+
+pub async fn organization(backend: &impl ConformanceBackend, name: &str) -> Organization {
+    backend
+        .organizations()
+        .create(Organization::new(name).expect("valid organization"))
+        .await
+        .expect("organization created")
+}
+
+/// A user of the organization, named `name` (the email is derived from the name, and must be
+/// unique).
+pub async fn user(
+    backend: &impl ConformanceBackend,
+    organization: &Organization,
+    name: &str,
+) -> UserIdentity {
+    let id = uuid::Uuid::new_v4();
+    let user = UserIdentity {
+        id,
+        uuid: UserId::new(id),
+        organization_id: organization.id,
+        username: name.to_string(),
+        email: Email::new(format!("{}@fleet.test", name)).expect("valid email"),
+        first_name: name.to_string(),
+        last_name: "Tester".to_string(),
+    };
+    backend
+        .insert_user(user.clone())
+        .await
+        .expect("user inserted");
+    user
+}
+
+pub async fn vehicle(
+    backend: &impl ConformanceBackend,
+    organization: &Organization,
+    index: u32,
+    license_plate: &str,
+) -> VehicleIdentity {
+    backend
+        .vehicles()
+        .create(organization.id, new_vehicle(index, license_plate, "KZ"))
+        .await
+        .expect("vehicle created")
+}
+
+pub async fn status(
+    backend: &impl ConformanceBackend,
+    vehicle: &VehicleIdentity,
+    user: &UserIdentity,
+    day: i64,
+    odometer: i32,
+) -> VehicleStatusIdentity {
+    backend
+        .vehicle_statuses()
+        .create(
+            user.organization_id,
+            VehicleStatusIdentity {
+                id: 0,
+                vehicle_id: vehicle.id,
+                performed_by: user.id,
+                performed_at: at(day),
+                odometer,
+                engine_hour_meter: None,
+                fuel_level: Some(50),
+                notes: format!("day {}", day),
+                created_at: at(day),
+                updated_at: at(day),
+            },
+        )
+        .await
+        .expect("status created")
+}
+
+pub async fn maintenance_type(
+    backend: &impl ConformanceBackend,
+    name: &str,
+    user: &UserIdentity,
+) -> MaintenanceTypeView {
+    let maintenance_type = MaintenanceType::new(
+        name.to_string(),
+        format!("{} of the vehicle", name),
+        None,
+        MaintenanceApplicability::Any,
+    )
+    .expect("valid maintenance type");
+    backend
+        .maintenance_types()
+        .create(user.organization_id, maintenance_type, user.id)
+        .await
+        .expect("maintenance type created")
+}
+
+/// Reads the entity of a maintenance type view.
+pub async fn maintenance_type_entity(
+    backend: &impl ConformanceBackend,
+    organization: &Organization,
+    id: i32,
+) -> MaintenanceType {
+    backend
+        .maintenance_types()
+        .get_by_id(organization.id, id)
+        .await
+        .expect("maintenance type read")
+        .expect("maintenance type exists")
+}
+
+pub fn new_maintenance(
+    maintenance_type: MaintenanceType,
+    vehicle: &VehicleIdentity,
+    user: &UserIdentity,
+    interval_type: &str,
+) -> Maintenance {
+    Maintenance::new(
+        maintenance_type,
+        vehicle.clone(),
+        user.id,
+        NewMaintenance {
+            interval_type: interval_type.to_string(),
+            interval_value: 10_000,
+            red_threshold: 90,
+            yellow_threshold: 75,
+        },
+    )
+    .expect("valid maintenance")
+}
+
+pub async fn maintenance(
+    backend: &impl ConformanceBackend,
+    maintenance_type_id: i32,
+    vehicle: &VehicleIdentity,
+    user: &UserIdentity,
+    interval_type: &str,
+) -> Maintenance {
+    let maintenance_type = backend
+        .maintenance_types()
+        .get_by_id(user.organization_id, maintenance_type_id)
+        .await
+        .expect("maintenance type read")
+        .expect("maintenance type exists");
+    backend
+        .maintenances()
+        .create(
+            user.organization_id,
+            new_maintenance(maintenance_type, vehicle, user, interval_type),
+        )
+        .await
+        .expect("maintenance created")
+}
+
+/// A cost of an oil change: 4.5 liters of oil and an hour of labor.
+pub fn cost() -> MaintenanceCost {
+    let currency = Currency::new("KZT").expect("valid currency");
+    MaintenanceCost::new(
+        currency,
+        vec![
+            MaintenanceCostLine {
+                kind: MaintenanceCostLineKind::Part,
+                description: "Engine oil 5W-30".to_string(),
+                part_number: Some("08880-83716".to_string()),
+                quantity: Decimal::new(45, 1),
+                unit_price: Money::new(Decimal::new(450_050, 2), currency),
+            },
+            MaintenanceCostLine {
+                kind: MaintenanceCostLineKind::Labor,
+                description: "Oil change".to_string(),
+                part_number: None,
+                quantity: Decimal::ONE,
+                unit_price: Money::new(Decimal::from(8_000), currency),
+            },
+        ],
+        Some("INV-42".to_string()),
+    )
+    .expect("valid cost")
+}
+
+pub async fn record(
+    backend: &impl ConformanceBackend,
+    maintenance: &Maintenance,
+    status: &VehicleStatusIdentity,
+    user: &UserIdentity,
+    day: i64,
+    cost: Option<MaintenanceCost>,
+) -> MaintenanceRecordIdentity {
+    let record = MaintenanceRecord::new(
+        maintenance.vehicle.clone(),
+        maintenance.identity.clone(),
+        user.clone(),
+        status.clone(),
+        at(day),
+        format!("Performed on day {}", day),
+        cost,
+    );
+    backend
+        .maintenance_records()
+        .create(
+            user.organization_id,
+            MaintenanceRecordIdentity {
+                created_at: at(day),
+                updated_at: at(day),
+                ..record.identity
+            },
+        )
+        .await
+        .expect("record created")
+}
+
+pub async fn region(
+    backend: &impl ConformanceBackend,
+    organization: &Organization,
+    name: &str,
+) -> Region {
+    backend
+        .depots()
+        .create_region(
+            organization.id,
+            Region::new(LocationName::new(name).expect("valid name")),
+        )
+        .await
+        .expect("region created")
+}
+
+pub async fn depot(
+    backend: &impl ConformanceBackend,
+    organization: &Organization,
+    region: &Region,
+    name: &str,
+) -> Depot {
+    backend
+        .depots()
+        .create_depot(
+            organization.id,
+            Depot::new(region.id, LocationName::new(name).expect("valid name")),
+        )
+        .await
+        .expect("depot created")
+}
+
+pub async fn site(
+    backend: &impl ConformanceBackend,
+    organization: &Organization,
+    depot: &Depot,
+    name: &str,
+) -> Site {
+    backend
+        .depots()
+        .create_site(
+            organization.id,
+            Site::new(depot.id, LocationName::new(name).expect("valid name")),
+        )
+        .await
+        .expect("site created")
+}
+
+/// Moves a vehicle to a depot on `day`, after its latest movement.
+pub async fn movement(
+    backend: &impl ConformanceBackend,
+    vehicle: &VehicleIdentity,
+    depot: &Depot,
+    user: &UserIdentity,
+    day: i64,
+) -> VehicleMovement {
+    let movements = backend.vehicle_movements();
+    let latest = movements
+        .find_latest(user.organization_id, vehicle.id)
+        .await
+        .expect("latest movement read");
+    let movement = VehicleMovement::new(
+        vehicle.id,
+        latest.as_ref(),
+        depot.id,
+        at(day),
+        user.id,
+        format!("day {}", day),
+    )
+    .expect("valid movement");
+    movements
+        .record(user.organization_id, movement)
+        .await
+        .expect("movement recorded")
+}
+
+/// Registers a device fitted in a vehicle of the organization.
+pub async fn device(
+    backend: &impl ConformanceBackend,
+    organization: &Organization,
+    vehicle: &VehicleIdentity,
+    device_id: &str,
+) -> TelematicsDevice {
+    let device = TelematicsDevice {
+        registered_at: at(1),
+        ..TelematicsDevice::new(
+            DeviceId::new(device_id).expect("valid device id"),
+            vehicle.id,
+        )
+    };
+    backend
+        .telematics_devices()
+        .register(organization.id, device)
+        .await
+        .expect("device registered")
+}
+
+/// A track heading north from Astana, a point per hundredth of a degree (about 1.1 km).
+pub fn track(points: usize) -> Vec<GeoPoint> {
+    (0..points)
+        .map(|index| {
+            GeoPoint::from_e5(5_112_800 + 1_000 * index as i32, 7_143_040).expect("valid point")
+        })
+        .collect()
+}
+
+/// Records a trip of a vehicle of the organization from `from_day` to `to_day`, created at its
+/// end.
+pub async fn trip(
+    backend: &impl ConformanceBackend,
+    organization: &Organization,
+    vehicle: &VehicleIdentity,
+    driver: Option<&UserIdentity>,
+    from_day: i64,
+    to_day: i64,
+) -> Trip {
+    let trip = Trip::new(NewTrip {
+        vehicle_id: vehicle.id,
+        driver_id: driver.map(|driver| driver.id),
+        started_at: at(from_day),
+        ended_at: at(to_day),
+        polyline: track(5),
+    })
+    .expect("valid trip");
+    backend
+        .trips()
+        .create(
+            organization.id,
+            Trip {
+                created_at: at(to_day),
+                ..trip
+            },
+        )
+        .await
+        .expect("trip created")
+}
+
+/// Opens an event of a code of a vehicle of the organization, first seen on `day`.
+pub async fn dtc_event(
+    backend: &impl ConformanceBackend,
+    organization: &Organization,
+    vehicle: &VehicleIdentity,
+    code: &str,
+    day: i64,
+) -> DtcEvent {
+    let freeze_frame = FreezeFrame::new(
+        [("rpm", "2150"), ("coolant_temperature", "104")]
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect(),
+    )
+    .expect("valid freeze frame");
+    let event = DtcEvent::new(
+        vehicle.id,
+        DtcCode::new(code).expect("valid code"),
+        DtcSeverity::Warning,
+        freeze_frame,
+        at(day),
+    );
+    backend
+        .diagnostics()
+        .open_event(organization.id, event)
+        .await
+        .expect("event opened")
+}
+
+/// Creates a rule of the organization matching the warnings, alerting or opening a task of
+/// `maintenance_type_id`.
+pub async fn diagnostic_rule(
+    backend: &impl ConformanceBackend,
+    organization: &Organization,
+    name: &str,
+    maintenance_type_id: Option<i32>,
+) -> DiagnosticRule {
+    let rule = DiagnosticRule::new(NewDiagnosticRule {
+        name: name.to_string(),
+        min_severity: DtcSeverity::Warning,
+        code_prefix: None,
+        action: match maintenance_type_id {
+            None => DiagnosticAction::Alert,
+            Some(maintenance_type_id) => DiagnosticAction::MaintenanceTask {
+                maintenance_type_id,
+            },
+        },
+    })
+    .expect("valid rule");
+    backend
+        .diagnostics()
+        .create_rule(
+            organization.id,
+            DiagnosticRule {
+                created_at: at(1),
+                ..rule
+            },
+        )
+        .await
+        .expect("rule created")
+}
+
+/// Creates a pre-trip template of the organization of the user: critical "Brakes" (commented
+/// when failed), "Lights" (with a photo) and "Horn".
+pub async fn inspection_template(
+    backend: &impl ConformanceBackend,
+    user: &UserIdentity,
+    name: &str,
+) -> InspectionTemplate {
+    let item =
+        |label: &str, critical, photo_required, comment_required_on_fail| NewInspectionItem {
+            label: label.to_string(),
+            critical,
+            photo_required,
+            comment_required_on_fail,
+        };
+    let template = InspectionTemplate::new(
+        NewInspectionTemplate {
+            name: name.to_string(),
+            kind: InspectionKind::PreTrip,
+            items: vec![
+                item("Brakes", true, false, true),
+                item("Lights", false, true, false),
+                item("Horn", false, false, false),
+            ],
+        },
+        user.id,
+    )
+    .expect("valid template");
+    backend
+        .inspections()
+        .create_template(
+            user.organization_id,
+            InspectionTemplate {
+                created_at: at(1),
+                ..template
+            },
+        )
+        .await
+        .expect("template created")
+}
+
+/// Submits a report of the template by the user with a status of the vehicle, the items labelled
+/// in `failing` fail and open their defects.
+pub async fn inspection_report(
+    backend: &impl ConformanceBackend,
+    template: &InspectionTemplate,
+    status: &VehicleStatusIdentity,
+    user: &UserIdentity,
+    failing: &[&str],
+) -> InspectionReport {
+    let answers = template
+        .items
+        .iter()
+        .map(|item| {
+            let failed = failing.contains(&item.label.as_str());
+            ItemAnswer {
+                item_id: item.id,
+                result: if failed {
+                    ItemResult::Fail
+                } else {
+                    ItemResult::Pass
+                },
+                comment: failed.then(|| format!("{} not working", item.label)),
+                photos: if item.photo_required {
+                    vec![format!("photos/{}.jpg", item.id)]
+                } else {
+                    Vec::new()
+                },
+            }
+        })
+        .collect();
+    let report = InspectionReport::submit(
+        template,
+        status.vehicle_id,
+        status.id,
+        user.id,
+        status.performed_at,
+        answers,
+    )
+    .expect("valid report");
+    let defects = InspectionDefect::open_all(&report);
+    backend
+        .inspections()
+        .submit_report(user.organization_id, report, defects)
+        .await
+        .expect("report submitted")
+}
@@ -0,0 +1,418 @@
+//! Contracts of `InspectionRepository`.
+use crate::{backend::ConformanceBackend, fixtures};
+use domain::inspection::{
+    entities::{
+        inspection_defect::InspectionDefect, inspection_report::InspectionReport,
+        inspection_template::InspectionTemplate,
+    },
+    repositories::inspection_repository::{InspectionRepository, InspectionRepositoryError},
+    value_types::{defect_resolution::DefectResolution, item_result::ItemResult},
+};
+use futures::future::join_all;
+use std::future::Future;
+
+/// Runs every check, each on a new backend from `new_backend`.
+pub async fn run<B, F, Fut>(new_backend: F)
+where
+    B: ConformanceBackend,
+    F: Fn() -> Fut,
+    Fut: Future<Output = B>,
+{
+    templates_by_name(&new_backend().await).await;
+    submit_and_find_reports(&new_backend().await).await;
+    reports_with_defects_are_atomic(&new_backend().await).await;
+    defects_by_vehicle(&new_backend().await).await;
+    sign_off_once(&new_backend().await).await;
+    isolated_per_organization(&new_backend().await).await;
+    concurrent_sign_offs(&new_backend().await).await;
+}
+
+/// The templates are read by id and by name, items included; a name is unique in an
+/// organization.
+pub async fn templates_by_name(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let alice = fixtures::user(backend, &organization, "alice").await;
+    let pre_trip = fixtures::inspection_template(backend, &alice, "Pre-trip").await;
+    let daily = fixtures::inspection_template(backend, &alice, "Daily walk-around").await;
+    let inspections = backend.inspections();
+
+    let found = inspections
+        .find_template(organization.id, pre_trip.id)
+        .await
+        .expect("template read")
+        .expect("created template is found");
+    assert_eq!(found, pre_trip);
+    assert_eq!(found.items.len(), 3, "the items are kept, in order");
+    assert!(found.items[0].critical && found.items[0].comment_required_on_fail);
+    assert!(found.items[1].photo_required);
+
+    let templates = inspections
+        .find_templates(organization.id)
+        .await
+        .expect("templates read");
+    assert_eq!(templates, vec![daily, pre_trip.clone()]);
+
+    let duplicate = InspectionTemplate {
+        id: uuid::Uuid::new_v4(),
+        ..pre_trip
+    };
+    match inspections
+        .create_template(organization.id, duplicate)
+        .await
+    {
+        Err(InspectionRepositoryError::AlreadyExists(name)) => assert_eq!(name, "Pre-trip"),
+        other => panic!("expected AlreadyExists, got {:?}", other),
+    }
+
+    let missing = inspections
+        .find_template(organization.id, uuid::Uuid::new_v4())
+        .await
+        .expect("template read");
+    assert!(missing.is_none(), "an unknown id finds no template");
+}
+
+/// A report keeps its answers (comments and photos included) and status; the reports of a
+/// vehicle are read by submission within an inclusive range.
+pub async fn submit_and_find_reports(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let alice = fixtures::user(backend, &organization, "alice").await;
+    let vehicle = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    let other = fixtures::vehicle(backend, &organization, 2, "456DEF02").await;
+    let template = fixtures::inspection_template(backend, &alice, "Pre-trip").await;
+    let mut reports = Vec::new();
+    for (day, odometer) in [(3, 10_300), (1, 10_100), (2, 10_200)] {
+        let status = fixtures::status(backend, &vehicle, &alice, day, odometer).await;
+        reports.push(fixtures::inspection_report(backend, &template, &status, &alice, &[]).await);
+    }
+    let status = fixtures::status(backend, &other, &alice, 2, 5_000).await;
+    let failed =
+        fixtures::inspection_report(backend, &template, &status, &alice, &["Brakes"]).await;
+    let inspections = backend.inspections();
+
+    let found = inspections
+        .find_report(organization.id, failed.id)
+        .await
+        .expect("report read")
+        .expect("submitted report is found");
+    assert_eq!(found, failed);
+    assert_eq!(found.vehicle_status_id, status.id);
+    assert_eq!(found.answers[0].result, ItemResult::Fail);
+    assert_eq!(
+        found.answers[0].comment.as_deref(),
+        Some("Brakes not working"),
+        "the comments are kept"
+    );
+    assert_eq!(found.answers[1].photos.len(), 1, "the photos are kept");
+
+    let all = inspections
+        .find_reports_by_vehicle(organization.id, vehicle.id, None, None)
+        .await
+        .expect("reports read");
+    assert_eq!(
+        all,
+        vec![reports[1].clone(), reports[2].clone(), reports[0].clone()],
+        "by submission, the other vehicles' excluded"
+    );
+    let ranged = inspections
+        .find_reports_by_vehicle(
+            organization.id,
+            vehicle.id,
+            Some(fixtures::at(2)),
+            Some(fixtures::at(3)),
+        )
+        .await
+        .expect("reports read");
+    assert_eq!(
+        ranged,
+        vec![reports[2].clone(), reports[0].clone()],
+        "both ends are inclusive"
+    );
+
+    let missing = inspections
+        .find_report(organization.id, uuid::Uuid::new_v4())
+        .await
+        .expect("report read");
+    assert!(missing.is_none(), "an unknown id finds no report");
+}
+
+/// A report whose defects can't be stored is not stored either.
+pub async fn reports_with_defects_are_atomic(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let alice = fixtures::user(backend, &organization, "alice").await;
+    let vehicle = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    let template = fixtures::inspection_template(backend, &alice, "Pre-trip").await;
+    let status = fixtures::status(backend, &vehicle, &alice, 1, 10_000).await;
+    let submitted =
+        fixtures::inspection_report(backend, &template, &status, &alice, &["Brakes"]).await;
+    let inspections = backend.inspections();
+
+    // Two defects of the same item of a report break its unique key
+    let report = InspectionReport {
+        id: uuid::Uuid::new_v4(),
+        ..submitted
+    };
+    let defect = InspectionDefect::open(&report, &report.answers[0]);
+    let duplicate = InspectionDefect {
+        id: uuid::Uuid::new_v4(),
+        ..defect.clone()
+    };
+    let result = inspections
+        .submit_report(organization.id, report.clone(), vec![defect, duplicate])
+        .await;
+    assert!(result.is_err(), "a duplicate defect fails the submission");
+
+    let found = inspections
+        .find_report(organization.id, report.id)
+        .await
+        .expect("report read");
+    assert!(
+        found.is_none(),
+        "the report is not stored without its defects"
+    );
+    let defects = inspections
+        .find_defects_by_vehicle(organization.id, vehicle.id, false)
+        .await
+        .expect("defects read");
+    assert_eq!(defects.len(), 1, "only the defect of the first report");
+}
+
+/// The defects of a vehicle are read by report, the signed off ones only when asked.
+pub async fn defects_by_vehicle(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let alice = fixtures::user(backend, &organization, "alice").await;
+    let mechanic = fixtures::user(backend, &organization, "bob").await;
+    let vehicle = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    let template = fixtures::inspection_template(backend, &alice, "Pre-trip").await;
+    let later = fixtures::status(backend, &vehicle, &alice, 2, 10_200).await;
+    fixtures::inspection_report(backend, &template, &later, &alice, &["Horn"]).await;
+    let earlier = fixtures::status(backend, &vehicle, &alice, 1, 10_100).await;
+    let report =
+        fixtures::inspection_report(backend, &template, &earlier, &alice, &["Brakes"]).await;
+    let inspections = backend.inspections();
+
+    let defects = inspections
+        .find_defects_by_vehicle(organization.id, vehicle.id, true)
+        .await
+        .expect("defects read");
+    assert_eq!(defects.len(), 2);
+    assert_eq!(defects[0].report_id, report.id, "by report");
+    assert_eq!(defects[0].label, "Brakes");
+    assert!(defects[0].critical, "the criticality of the item is kept");
+    assert_eq!(defects[0].comment.as_deref(), Some("Brakes not working"));
+    assert_eq!(defects[1].label, "Horn");
+    assert!(!defects[1].critical);
+
+    let mut brakes = defects[0].clone();
+    brakes
+        .sign_off(
+            DefectResolution::Repaired,
+            fixtures::at(3),
+            mechanic.id,
+            None,
+        )
+        .expect("open defect");
+    inspections
+        .sign_off_defect(organization.id, brakes.clone())
+        .await
+        .expect("defect signed off");
+
+    let open = inspections
+        .find_defects_by_vehicle(organization.id, vehicle.id, true)
+        .await
+        .expect("defects read");
+    assert_eq!(open, vec![defects[1].clone()], "only the open defects");
+    let all = inspections
+        .find_defects_by_vehicle(organization.id, vehicle.id, false)
+        .await
+        .expect("defects read");
+    assert_eq!(
+        all,
+        vec![brakes, defects[1].clone()],
+        "the signed off ones too"
+    );
+}
+
+/// A defect is signed off once; the sign-off saves the resolution, time, mechanic and notes.
+pub async fn sign_off_once(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let alice = fixtures::user(backend, &organization, "alice").await;
+    let mechanic = fixtures::user(backend, &organization, "bob").await;
+    let vehicle = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    let template = fixtures::inspection_template(backend, &alice, "Pre-trip").await;
+    let status = fixtures::status(backend, &vehicle, &alice, 1, 10_000).await;
+    fixtures::inspection_report(backend, &template, &status, &alice, &["Lights"]).await;
+    let inspections = backend.inspections();
+    let defects = inspections
+        .find_defects_by_vehicle(organization.id, vehicle.id, true)
+        .await
+        .expect("defects read");
+    let open = defects[0].clone();
+
+    let mut signed = open.clone();
+    signed
+        .sign_off(
+            DefectResolution::NoRepairNeeded,
+            fixtures::at(2),
+            mechanic.id,
+            Some("Bulb was loose".to_string()),
+        )
+        .expect("open defect");
+    let saved = inspections
+        .sign_off_defect(organization.id, signed.clone())
+        .await
+        .expect("sign-off ran");
+    assert!(saved);
+    let found = inspections
+        .find_defect(organization.id, open.id)
+        .await
+        .expect("defect read")
+        .expect("defect exists");
+    assert_eq!(found, signed);
+    assert_eq!(found.signed_off_by, Some(mechanic.id));
+    assert_eq!(found.sign_off_notes.as_deref(), Some("Bulb was loose"));
+
+    let mut again = open.clone();
+    again
+        .sign_off(DefectResolution::Repaired, fixtures::at(3), alice.id, None)
+        .expect("open defect");
+    let saved = inspections
+        .sign_off_defect(organization.id, again)
+        .await
+        .expect("sign-off ran");
+    assert!(!saved, "a signed off defect is not signed off twice");
+    let found = inspections
+        .find_defect(organization.id, open.id)
+        .await
+        .expect("defect read");
+    assert_eq!(found, Some(signed), "the first sign-off is kept");
+
+    let mut unknown = InspectionDefect {
+        id: uuid::Uuid::new_v4(),
+        ..open
+    };
+    unknown
+        .sign_off(DefectResolution::Repaired, fixtures::at(3), alice.id, None)
+        .expect("open defect");
+    let saved = inspections
+        .sign_off_defect(organization.id, unknown)
+        .await
+        .expect("sign-off ran");
+    assert!(!saved, "an unknown defect is not signed off");
+}
+
+/// The templates, reports and defects are not read or written in another organization.
+pub async fn isolated_per_organization(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let other = fixtures::organization(backend, "Altai Transit").await;
+    let alice = fixtures::user(backend, &organization, "alice").await;
+    let carol = fixtures::user(backend, &other, "carol").await;
+    let vehicle = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    let template = fixtures::inspection_template(backend, &alice, "Pre-trip").await;
+    let status = fixtures::status(backend, &vehicle, &alice, 1, 10_000).await;
+    let report =
+        fixtures::inspection_report(backend, &template, &status, &alice, &["Brakes"]).await;
+    let inspections = backend.inspections();
+    let defects = inspections
+        .find_defects_by_vehicle(organization.id, vehicle.id, true)
+        .await
+        .expect("defects read");
+
+    let found = inspections
+        .find_template(other.id, template.id)
+        .await
+        .expect("template read");
+    assert!(
+        found.is_none(),
+        "the template is not read in another organization"
+    );
+    let templates = inspections
+        .find_templates(other.id)
+        .await
+        .expect("templates read");
+    assert!(templates.is_empty());
+    let found = inspections
+        .find_report(other.id, report.id)
+        .await
+        .expect("report read");
+    assert!(found.is_none());
+    let reports = inspections
+        .find_reports_by_vehicle(other.id, vehicle.id, None, None)
+        .await
+        .expect("reports read");
+    assert!(reports.is_empty());
+    let found = inspections
+        .find_defect(other.id, defects[0].id)
+        .await
+        .expect("defect read");
+    assert!(found.is_none());
+    let found = inspections
+        .find_defects_by_vehicle(other.id, vehicle.id, false)
+        .await
+        .expect("defects read");
+    assert!(found.is_empty());
+
+    let mut signed = defects[0].clone();
+    signed
+        .sign_off(DefectResolution::Repaired, fixtures::at(2), carol.id, None)
+        .expect("open defect");
+    let saved = inspections
+        .sign_off_defect(other.id, signed)
+        .await
+        .expect("sign-off ran");
+    assert!(
+        !saved,
+        "the defect is not signed off from another organization"
+    );
+    let open = inspections
+        .find_defects_by_vehicle(organization.id, vehicle.id, true)
+        .await
+        .expect("defects read");
+    assert_eq!(open, defects);
+
+    // The same name is free in another organization
+    fixtures::inspection_template(backend, &carol, "Pre-trip").await;
+}
+
+/// Concurrent sign-offs of a defect save a single one, the others find it signed off.
+pub async fn concurrent_sign_offs(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let alice = fixtures::user(backend, &organization, "alice").await;
+    let vehicle = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    let template = fixtures::inspection_template(backend, &alice, "Pre-trip").await;
+    let status = fixtures::status(backend, &vehicle, &alice, 1, 10_000).await;
+    fixtures::inspection_report(backend, &template, &status, &alice, &["Brakes"]).await;
+    let inspections = backend.inspections();
+    let defects = inspections
+        .find_defects_by_vehicle(organization.id, vehicle.id, true)
+        .await
+        .expect("defects read");
+
+    let results = join_all((2..10).map(|day| {
+        let mut defect = defects[0].clone();
+        defect
+            .sign_off(
+                DefectResolution::Repaired,
+                fixtures::at(day),
+                alice.id,
+                None,
+            )
+            .expect("open defect");
+        inspections.sign_off_defect(organization.id, defect)
+    }))
+    .await;
+    let saved = results
+        .iter()
+        .filter(|result| matches!(result, Ok(true)))
+        .count();
+    assert_eq!(saved, 1, "a single sign-off is saved");
+    assert!(
+        results.iter().all(|result| result.is_ok()),
+        "the other sign-offs find the defect signed off"
+    );
+
+    let open = inspections
+        .find_defects_by_vehicle(organization.id, vehicle.id, true)
+        .await
+        .expect("defects read");
+    assert!(open.is_empty());
+}
@@ -0,0 +1,59 @@
+//! Conformance suite of the repository traits: the contracts every backend (in-memory, SQLite,
+//! PostgreSQL) must honor, so the backends can't drift from each other.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * There is a suite per repository trait (`<trait module>::run`), called with a factory of fresh
+//!   (empty) instances: the repository alone when its checks need nothing else, otherwise a
+//!   `ConformanceBackend` (the repositories sharing a storage, and the seeding of the users, which
+//!   have no repository).
+//! * Every check works in an organization; a check `isolated_per_organization` per suite checks a
+//!   second organization neither sees nor changes the rows of the first one, and has its own
+//!   unique keys.
+//! * `run_all` runs every suite; a backend implementing a subset of the traits runs their suites.
+//! * Every check runs on a fresh instance and panics when a contract is broken.
+//! * The variants of the errors are part of the contracts (e.g., `AlreadyExists` on duplicates),
+//!   the wording of a database error is the backend's.
+//! * Concurrency checks run calls concurrently on one instance (`join_all`), the outcome must be
+//!   the one of some serial order.
+use std::future::Future;
+
+pub mod backend;
+pub mod cascades;
+pub mod depot_repository;
+pub mod diagnostic_repository;
+pub mod fixtures;
+pub mod inspection_repository;
+pub mod maintenance_record_repository;
+pub mod maintenance_repository;
+pub mod maintenance_type_repository;
+pub mod organization_repository;
+pub mod telematics_device_repository;
+pub mod trip_repository;
+pub mod vehicle_movement_repository;
+pub mod vehicle_repository;
+pub mod vehicle_status_repository;
+
+pub use backend::ConformanceBackend;
+
+/// Runs every suite, each check on a new backend from `new_backend`.
+pub async fn run_all<B, F, Fut>(new_backend: F)
+where
+    B: ConformanceBackend,
+    F: Fn() -> Fut,
+    Fut: Future<Output = B>,
+{
+    organization_repository::run(|| async { new_backend().await.organizations().clone() }).await;
+    vehicle_repository::run(&new_backend).await;
+    vehicle_status_repository::run(&new_backend).await;
+    maintenance_type_repository::run(&new_backend).await;
+    maintenance_repository::run(&new_backend).await;
+    maintenance_record_repository::run(&new_backend).await;
+    depot_repository::run(&new_backend).await;
+    vehicle_movement_repository::run(&new_backend).await;
+    telematics_device_repository::run(&new_backend).await;
+    trip_repository::run(&new_backend).await;
+    diagnostic_repository::run(&new_backend).await;
+    inspection_repository::run(&new_backend).await;
+    cascades::run(&new_backend).await;
+}
@@ -0,0 +1,120 @@
+//! Contracts of `MaintenanceRecordRepository`.
+use crate::{backend::ConformanceBackend, fixtures};
+use domain::maintenance::repositories::maintenance_record_repository::MaintenanceRecordRepository;
+use rust_decimal::Decimal;
+use std::future::Future;
+
+/// Runs every check, each on a new backend from `new_backend`.
+pub async fn run<B, F, Fut>(new_backend: F)
+where
+    B: ConformanceBackend,
+    F: Fn() -> Fut,
+    Fut: Future<Output = B>,
+{
+    records_by_vehicle(&new_backend().await).await;
+    isolated_per_organization(&new_backend().await).await;
+}
+
+/// Records are read in order of `performed_at` within an inclusive range, hydrated and with
+/// their itemized cost.
+pub async fn records_by_vehicle(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let alice = fixtures::user(backend, &organization, "alice").await;
+    let vehicle = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    let oil_change = fixtures::maintenance_type(backend, "Oil Change", &alice).await;
+    let maintenance =
+        fixtures::maintenance(backend, oil_change.id, &vehicle, &alice, "Kilometers").await;
+    let mut records = Vec::new();
+    for (day, odometer) in [(5, 20_000), (1, 10_000), (3, 15_000)] {
+        let status = fixtures::status(backend, &vehicle, &alice, day, odometer).await;
+        let cost = (day == 3).then(fixtures::cost);
+        records.push(fixtures::record(backend, &maintenance, &status, &alice, day, cost).await);
+    }
+    let repository = backend.maintenance_records();
+
+    let all = repository
+        .find_by_vehicle(organization.id, vehicle.id, None, None)
+        .await
+        .expect("records read");
+    let days: Vec<_> = all
+        .iter()
+        .map(|record| record.identity.performed_at)
+        .collect();
+    assert_eq!(
+        days,
+        vec![fixtures::at(1), fixtures::at(3), fixtures::at(5)]
+    );
+    assert_eq!(all[0].vehicle.id, vehicle.id);
+    assert_eq!(all[0].maintenance.id, maintenance.identity.id);
+    assert_eq!(all[0].user.id, alice.id);
+    assert_eq!(all[0].vehicle_status.odometer, 10_000);
+
+    let range = repository
+        .find_by_vehicle(
+            organization.id,
+            vehicle.id,
+            Some(fixtures::at(3)),
+            Some(fixtures::at(5)),
+        )
+        .await
+        .expect("records read");
+    assert_eq!(range.len(), 2, "both ends are inclusive");
+
+    let with_cost = &range[0];
+    assert_eq!(with_cost.identity.id, records[2].id);
+    let cost = with_cost
+        .identity
+        .cost
+        .as_ref()
+        .expect("the cost is stored");
+    assert_eq!(cost.currency().code(), "KZT");
+    assert_eq!(cost.invoice_reference(), Some("INV-42"));
+    assert_eq!(cost.lines(), fixtures::cost().lines());
+    assert_eq!(cost.total().amount(), Decimal::new(2_825_225, 2));
+    assert!(range[1].identity.cost.is_none());
+
+    let latest = repository
+        .find_latest_by_maintenance(organization.id, maintenance.identity.id)
+        .await
+        .expect("record read")
+        .expect("the rule has records");
+    assert_eq!(latest.identity.id, records[0].id);
+    assert_eq!(latest.identity.performed_at, fixtures::at(5));
+
+    let none = repository
+        .find_latest_by_maintenance(organization.id, maintenance.identity.id + 1)
+        .await
+        .expect("record read");
+    assert!(none.is_none());
+}
+
+/// The records of a vehicle are not read in another organization.
+pub async fn isolated_per_organization(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let other = fixtures::organization(backend, "Altai Transit").await;
+    let alice = fixtures::user(backend, &organization, "alice").await;
+    let vehicle = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    let oil_change = fixtures::maintenance_type(backend, "Oil Change", &alice).await;
+    let maintenance =
+        fixtures::maintenance(backend, oil_change.id, &vehicle, &alice, "Kilometers").await;
+    let status = fixtures::status(backend, &vehicle, &alice, 1, 10_000).await;
+    fixtures::record(backend, &maintenance, &status, &alice, 1, None).await;
+    let records = backend.maintenance_records();
+
+    let by_vehicle = records
+        .find_by_vehicle(other.id, vehicle.id, None, None)
+        .await
+        .expect("records read");
+    assert!(
+        by_vehicle.is_empty(),
+        "the records are not read in another organization"
+    );
+    let latest = records
+        .find_latest_by_maintenance(other.id, maintenance.identity.id)
+        .await
+        .expect("record read");
+    assert!(
+        latest.is_none(),
+        "the records are not read in another organization"
+    );
+}
@@ -0,0 +1,98 @@
+//! Contracts of `MaintenanceRepository`.
+use crate::{backend::ConformanceBackend, fixtures};
+use domain::maintenance::{
+    repositories::maintenance_repository::MaintenanceRepository,
+    value_types::maintenance_interval_type::MaintenanceIntervalType,
+};
+use std::future::Future;
+
+/// Runs every check, each on a new backend from `new_backend`.
+pub async fn run<B, F, Fut>(new_backend: F)
+where
+    B: ConformanceBackend,
+    F: Fn() -> Fut,
+    Fut: Future<Output = B>,
+{
+    create_and_find(&new_backend().await).await;
+    isolated_per_organization(&new_backend().await).await;
+}
+
+/// A rule is unique per vehicle, type and interval type, and is read with its type.
+pub async fn create_and_find(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let alice = fixtures::user(backend, &organization, "alice").await;
+    let vehicle = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    let oil_change = fixtures::maintenance_type(backend, "Oil Change", &alice).await;
+
+    let created =
+        fixtures::maintenance(backend, oil_change.id, &vehicle, &alice, "Kilometers").await;
+    assert!(created.identity.id > 0, "the storage assigns the id");
+
+    let entity = fixtures::maintenance_type_entity(backend, &organization, oil_change.id).await;
+    let duplicate = backend
+        .maintenances()
+        .create(
+            organization.id,
+            fixtures::new_maintenance(entity.clone(), &vehicle, &alice, "Kilometers"),
+        )
+        .await;
+    assert!(duplicate.is_err(), "a rule is unique per interval type");
+
+    backend
+        .maintenances()
+        .create(
+            organization.id,
+            fixtures::new_maintenance(entity, &vehicle, &alice, "Years"),
+        )
+        .await
+        .expect("another interval type is accepted");
+
+    let rules = backend
+        .maintenances()
+        .find_by_vehicle(organization.id, vehicle.id)
+        .await
+        .expect("rules read");
+    assert_eq!(rules.len(), 2);
+    let kilometers = rules
+        .iter()
+        .find(|rule| rule.identity.id == created.identity.id)
+        .expect("the created rule is read");
+    assert_eq!(
+        kilometers.identity.interval_type,
+        MaintenanceIntervalType::Kilometers
+    );
+    assert_eq!(kilometers.identity.interval_value, 10_000);
+    assert_eq!(kilometers.identity.red_threshold, 90);
+    assert_eq!(kilometers.identity.yellow_threshold, 75);
+    assert_eq!(kilometers.identity.created_by, alice.id);
+    assert_eq!(kilometers.maintenance_type.name(), "Oil Change");
+    assert_eq!(kilometers.vehicle.id, vehicle.id);
+}
+
+/// The rules of a vehicle are not read in another organization.
+pub async fn isolated_per_organization(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let other = fixtures::organization(backend, "Altai Transit").await;
+    let alice = fixtures::user(backend, &organization, "alice").await;
+    let vehicle = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    let oil_change = fixtures::maintenance_type(backend, "Oil Change", &alice).await;
+    fixtures::maintenance(backend, oil_change.id, &vehicle, &alice, "Kilometers").await;
+    let rules = backend.maintenances();
+
+    let by_vehicle = rules
+        .find_by_vehicle(other.id, vehicle.id)
+        .await
+        .expect("rules read");
+    assert!(
+        by_vehicle.is_empty(),
+        "the rules are not read in another organization"
+    );
+    let by_type = rules
+        .find_by_maintenance_type(other.id, oil_change.id)
+        .await
+        .expect("rules read");
+    assert!(
+        by_type.is_empty(),
+        "the rules are not read in another organization"
+    );
+}
@@ -0,0 +1,437 @@
+//! Contracts of `MaintenanceTypeRepository`.
+use crate::{backend::ConformanceBackend, fixtures};
+use domain::maintenance::{
+    entities::maintenance_type::MaintenanceType,
+    repositories::{
+        maintenance_record_repository::MaintenanceRecordRepository,
+        maintenance_repository::MaintenanceRepository,
+        maintenance_type_repository::{MaintenanceTypeRepository, MaintenanceTypeRepositoryError},
+    },
+    value_types::maintenance_applicability::MaintenanceApplicability,
+};
+use futures::future::join_all;
+use std::future::Future;
+
+/// Runs every check, each on a new backend from `new_backend`.
+pub async fn run<B, F, Fut>(new_backend: F)
+where
+    B: ConformanceBackend,
+    F: Fn() -> Fut,
+    Fut: Future<Output = B>,
+{
+    create_and_read(&new_backend().await).await;
+    duplicate_names_already_exist(&new_backend().await).await;
+    update(&new_backend().await).await;
+    deprecate(&new_backend().await).await;
+    delete(&new_backend().await).await;
+    merge(&new_backend().await).await;
+    isolated_per_organization(&new_backend().await).await;
+    concurrent_creates_of_a_name(&new_backend().await).await;
+}
+
+/// A created maintenance type is read back as an entity and as a view, with its authors.
+pub async fn create_and_read(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let alice = fixtures::user(backend, &organization, "alice").await;
+    let mut oil_change = MaintenanceType::new(
+        "Oil Change".to_string(),
+        "Replace the engine oil".to_string(),
+        None,
+        MaintenanceApplicability::CombustionEngine,
+    )
+    .expect("valid maintenance type");
+    oil_change.set_checklist(vec![
+        "Drain the oil".to_string(),
+        "Replace the filter".to_string(),
+    ]);
+    let types = backend.maintenance_types();
+
+    let created = types
+        .create(organization.id, oil_change, alice.id)
+        .await
+        .expect("maintenance type created");
+    assert!(created.id > 0, "the storage assigns the id");
+    assert_eq!(created.name, "Oil Change");
+    assert_eq!(created.created_by.id, alice.id);
+    assert_eq!(created.updated_by.id, alice.id);
+
+    let entity = fixtures::maintenance_type_entity(backend, &organization, created.id).await;
+    assert_eq!(entity.id(), created.id);
+    assert_eq!(entity.description(), "Replace the engine oil");
+    assert_eq!(
+        entity.applicability(),
+        MaintenanceApplicability::CombustionEngine
+    );
+    assert_eq!(entity.checklist(), ["Drain the oil", "Replace the filter"]);
+    assert!(!entity.is_deprecated());
+
+    let view = types
+        .get_view_by_id(organization.id, created.id)
+        .await
+        .expect("view read")
+        .expect("view exists");
+    assert_eq!(view.checklist, entity.checklist());
+    assert_eq!(view.created_by.username, "alice");
+
+    assert!(
+        types
+            .get_by_id(organization.id, created.id + 1)
+            .await
+            .expect("read")
+            .is_none()
+    );
+    assert!(
+        types
+            .exists_by_name(organization.id, "Oil Change")
+            .await
+            .expect("checked")
+    );
+    assert!(
+        !types
+            .exists_by_name(organization.id, "Tire Rotation")
+            .await
+            .expect("checked")
+    );
+
+    let all = types
+        .get_all_view(organization.id)
+        .await
+        .expect("views read");
+    assert_eq!(all.len(), 1);
+    assert_eq!(all[0].id, created.id);
+}
+
+fn new_maintenance_type(name: &str) -> MaintenanceType {
+    MaintenanceType::new(
+        name.to_string(),
+        format!("{} of the vehicle", name),
+        None,
+        MaintenanceApplicability::Any,
+    )
+    .expect("valid maintenance type")
+}
+
+/// The name is unique: a create, or an update to a taken name, already exists.
+pub async fn duplicate_names_already_exist(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let alice = fixtures::user(backend, &organization, "alice").await;
+    fixtures::maintenance_type(backend, "Oil Change", &alice).await;
+    let tire_rotation = fixtures::maintenance_type(backend, "Tire Rotation", &alice).await;
+    let types = backend.maintenance_types();
+
+    let created = types
+        .create(
+            organization.id,
+            new_maintenance_type("Oil Change"),
+            alice.id,
+        )
+        .await;
+    assert!(
+        matches!(&created, Err(MaintenanceTypeRepositoryError::AlreadyExists(name)) if name == "Oil Change"),
+        "a duplicate name already exists, got {:?}",
+        created
+    );
+
+    let mut renamed =
+        fixtures::maintenance_type_entity(backend, &organization, tire_rotation.id).await;
+    renamed.set_name("Oil Change".to_string());
+    let updated = types.update(organization.id, renamed, alice.id).await;
+    assert!(
+        matches!(&updated, Err(MaintenanceTypeRepositoryError::AlreadyExists(name)) if name == "Oil Change"),
+        "a rename to a taken name already exists, got {:?}",
+        updated
+    );
+    let read = fixtures::maintenance_type_entity(backend, &organization, tire_rotation.id).await;
+    assert_eq!(
+        read.name(),
+        "Tire Rotation",
+        "a refused rename is not stored"
+    );
+
+    let all = types
+        .get_all_view(organization.id)
+        .await
+        .expect("views read");
+    assert_eq!(all.len(), 2, "duplicates are not stored");
+}
+
+/// An update returns the refreshed view, the author of the creation is kept.
+pub async fn update(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let alice = fixtures::user(backend, &organization, "alice").await;
+    let bob = fixtures::user(backend, &organization, "bob").await;
+    let created = fixtures::maintenance_type(backend, "Oil Change", &alice).await;
+
+    let mut oil_change =
+        fixtures::maintenance_type_entity(backend, &organization, created.id).await;
+    oil_change.set_name("Engine Oil Change".to_string());
+    oil_change.set_description("Replace the engine oil and filter".to_string());
+    oil_change.set_checklist(vec!["Drain the oil".to_string()]);
+    let updated = backend
+        .maintenance_types()
+        .update(organization.id, oil_change, bob.id)
+        .await
+        .expect("maintenance type updated");
+    assert_eq!(updated.id, created.id);
+    assert_eq!(updated.name, "Engine Oil Change");
+    assert_eq!(updated.description, "Replace the engine oil and filter");
+    assert_eq!(updated.checklist, ["Drain the oil"]);
+    assert_eq!(updated.created_by.id, alice.id);
+    assert_eq!(updated.updated_by.id, bob.id);
+    assert!(updated.updated_at >= created.updated_at);
+
+    let read = fixtures::maintenance_type_entity(backend, &organization, created.id).await;
+    assert_eq!(read.name(), "Engine Oil Change");
+    assert!(
+        !backend
+            .maintenance_types()
+            .exists_by_name(organization.id, "Oil Change")
+            .await
+            .expect("checked")
+    );
+}
+
+/// A deprecated type keeps its rules, is still read by id, but is no longer listed.
+pub async fn deprecate(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let alice = fixtures::user(backend, &organization, "alice").await;
+    let vehicle = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    let created = fixtures::maintenance_type(backend, "Oil Change", &alice).await;
+    fixtures::maintenance(backend, created.id, &vehicle, &alice, "Kilometers").await;
+
+    let oil_change = fixtures::maintenance_type_entity(backend, &organization, created.id).await;
+    let deprecated = backend
+        .maintenance_types()
+        .deprecate(organization.id, oil_change, alice.id)
+        .await
+        .expect("maintenance type deprecated");
+    assert!(deprecated.deprecated_at.is_some());
+
+    let read = fixtures::maintenance_type_entity(backend, &organization, created.id).await;
+    assert!(read.is_deprecated());
+    let all = backend
+        .maintenance_types()
+        .get_all_view(organization.id)
+        .await
+        .expect("views read");
+    assert!(all.is_empty(), "deprecated types are not listed");
+    assert!(
+        backend
+            .maintenance_types()
+            .exists_by_name(organization.id, "Oil Change")
+            .await
+            .expect("checked"),
+        "the name of a deprecated type stays taken"
+    );
+
+    let usage = backend
+        .maintenance_types()
+        .usage(organization.id, created.id)
+        .await
+        .expect("usage read");
+    assert_eq!(usage.rules, 1, "the rules are kept");
+}
+
+/// An unused type is deleted, a type referenced by a rule is not.
+pub async fn delete(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let alice = fixtures::user(backend, &organization, "alice").await;
+    let vehicle = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    let unused = fixtures::maintenance_type(backend, "Tire Rotation", &alice).await;
+    let used = fixtures::maintenance_type(backend, "Oil Change", &alice).await;
+    fixtures::maintenance(backend, used.id, &vehicle, &alice, "Kilometers").await;
+    let types = backend.maintenance_types();
+
+    let unused_entity = fixtures::maintenance_type_entity(backend, &organization, unused.id).await;
+    types
+        .delete(organization.id, unused_entity.clone(), alice.id)
+        .await
+        .expect("an unused maintenance type is deleted");
+    assert!(
+        types
+            .get_by_id(organization.id, unused.id)
+            .await
+            .expect("read")
+            .is_none()
+    );
+    assert!(
+        types
+            .delete(organization.id, unused_entity, alice.id)
+            .await
+            .is_err(),
+        "a deleted type can't be deleted twice"
+    );
+
+    let used_entity = fixtures::maintenance_type_entity(backend, &organization, used.id).await;
+    assert!(
+        types
+            .delete(organization.id, used_entity, alice.id)
+            .await
+            .is_err(),
+        "a type referenced by a rule is kept"
+    );
+    assert!(
+        types
+            .get_by_id(organization.id, used.id)
+            .await
+            .expect("read")
+            .is_some()
+    );
+}
+
+/// A merge moves the rules of the source type, folds the rules the target already has for the
+/// same vehicle and interval type (moving their records), then deletes the source.
+pub async fn merge(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let alice = fixtures::user(backend, &organization, "alice").await;
+    let first = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    let second = fixtures::vehicle(backend, &organization, 2, "456DEF02").await;
+    let source = fixtures::maintenance_type(backend, "Oil Service", &alice).await;
+    let target = fixtures::maintenance_type(backend, "Oil Change", &alice).await;
+
+    // The first vehicle has a rule of both types, the second only of the source type
+    let folded = fixtures::maintenance(backend, source.id, &first, &alice, "Kilometers").await;
+    let kept = fixtures::maintenance(backend, target.id, &first, &alice, "Kilometers").await;
+    let moved = fixtures::maintenance(backend, source.id, &second, &alice, "Kilometers").await;
+    let status = fixtures::status(backend, &first, &alice, 1, 10_000).await;
+    fixtures::record(backend, &folded, &status, &alice, 1, None).await;
+
+    let source_entity = fixtures::maintenance_type_entity(backend, &organization, source.id).await;
+    let target_entity = fixtures::maintenance_type_entity(backend, &organization, target.id).await;
+    let merge = backend
+        .maintenance_types()
+        .merge(organization.id, source_entity, target_entity, alice.id)
+        .await
+        .expect("maintenance types merged");
+    assert_eq!(merge.rules_moved, 1);
+    assert_eq!(merge.rules_merged, 1);
+    assert_eq!(merge.records_moved, 1);
+
+    assert!(
+        backend
+            .maintenance_types()
+            .get_by_id(organization.id, source.id)
+            .await
+            .expect("read")
+            .is_none(),
+        "the source type is deleted"
+    );
+
+    let mut rules: Vec<i32> = backend
+        .maintenances()
+        .find_by_maintenance_type(organization.id, target.id)
+        .await
+        .expect("rules read")
+        .iter()
+        .map(|rule| rule.identity.id)
+        .collect();
+    rules.sort();
+    assert_eq!(rules, vec![kept.identity.id, moved.identity.id]);
+
+    let latest = backend
+        .maintenance_records()
+        .find_latest_by_maintenance(organization.id, kept.identity.id)
+        .await
+        .expect("record read")
+        .expect("the record moved to the kept rule");
+    assert_eq!(latest.maintenance.id, kept.identity.id);
+}
+
+/// Of concurrent creates of the same name, one wins and the others already exist.
+pub async fn concurrent_creates_of_a_name(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let alice = fixtures::user(backend, &organization, "alice").await;
+    let types = backend.maintenance_types();
+
+    let results = join_all((0..8).map(|_| {
+        types.create(
+            organization.id,
+            new_maintenance_type("Oil Change"),
+            alice.id,
+        )
+    }))
+    .await;
+    let created = results.iter().filter(|result| result.is_ok()).count();
+    assert_eq!(created, 1, "a single create wins");
+    for result in &results {
+        match result {
+            Ok(_) => {}
+            Err(MaintenanceTypeRepositoryError::AlreadyExists(name)) => {
+                assert_eq!(name, "Oil Change")
+            }
+            Err(e) => panic!("a losing create already exists, got {:?}", e),
+        }
+    }
+
+    let all = types
+        .get_all_view(organization.id)
+        .await
+        .expect("views read");
+    assert_eq!(all.len(), 1);
+}
+
+/// A maintenance type of another organization is not read nor changed, and doesn't take its name.
+pub async fn isolated_per_organization(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let other = fixtures::organization(backend, "Altai Transit").await;
+    let alice = fixtures::user(backend, &organization, "alice").await;
+    let bob = fixtures::user(backend, &other, "bob").await;
+    let created = fixtures::maintenance_type(backend, "Oil Change", &alice).await;
+    let types = backend.maintenance_types();
+
+    assert!(
+        types
+            .get_by_id(other.id, created.id)
+            .await
+            .expect("read")
+            .is_none(),
+        "the type is not read in another organization"
+    );
+    assert!(
+        types
+            .get_view_by_id(other.id, created.id)
+            .await
+            .expect("read")
+            .is_none()
+    );
+    assert!(
+        types
+            .get_all_view(other.id)
+            .await
+            .expect("views read")
+            .is_empty()
+    );
+    assert!(
+        !types
+            .exists_by_name(other.id, "Oil Change")
+            .await
+            .expect("checked"),
+        "the name is free in another organization"
+    );
+
+    let mut renamed = fixtures::maintenance_type_entity(backend, &organization, created.id).await;
+    renamed.set_name("Engine Oil Change".to_string());
+    assert!(
+        types.update(other.id, renamed, bob.id).await.is_err(),
+        "a type of another organization can't be updated"
+    );
+    let entity = fixtures::maintenance_type_entity(backend, &organization, created.id).await;
+    assert!(
+        types.delete(other.id, entity, bob.id).await.is_err(),
+        "a type of another organization can't be deleted"
+    );
+    let read = fixtures::maintenance_type_entity(backend, &organization, created.id).await;
+    assert_eq!(read.name(), "Oil Change");
+
+    let twin = fixtures::maintenance_type(backend, "Oil Change", &bob).await;
+    assert_ne!(twin.id, created.id);
+    assert_eq!(
+        types
+            .get_all_view(organization.id)
+            .await
+            .expect("views read")
+            .len(),
+        1,
+        "each organization lists its own type"
+    );
+}
@@ -0,0 +1,94 @@
+//! Contracts of `OrganizationRepository`, checked on the repository alone.
+use chrono::SubsecRound;
+use domain::organization::{
+    entities::organization::Organization,
+    repositories::organization_repository::{OrganizationRepository, OrganizationRepositoryError},
+};
+use futures::future::join_all;
+use std::future::Future;
+
+/// Runs every check, each on a new repository from `new_repository`.
+pub async fn run<R, F, Fut>(new_repository: F)
+where
+    R: OrganizationRepository,
+    F: Fn() -> Fut,
+    Fut: Future<Output = R>,
+{
+    create_and_find(&new_repository().await).await;
+    duplicate_names_already_exist(&new_repository().await).await;
+    concurrent_creates_of_a_name(&new_repository().await).await;
+}
+
+fn new_organization(name: &str) -> Organization {
+    Organization::new(name).expect("valid organization")
+}
+
+/// A created organization is found by its id, with its fields as given.
+pub async fn create_and_find(repository: &impl OrganizationRepository) {
+    let created = repository
+        .create(new_organization("Steppe Logistics"))
+        .await
+        .expect("organization created");
+
+    let found = repository
+        .find_by_id(created.id)
+        .await
+        .expect("organization read")
+        .expect("created organization is found");
+    assert_eq!(found.id, created.id);
+    assert_eq!(found.name, "Steppe Logistics");
+    // The SQL backends keep microseconds
+    assert_eq!(
+        found.created_at.trunc_subsecs(6),
+        created.created_at.trunc_subsecs(6)
+    );
+
+    let missing = repository
+        .find_by_id(uuid::Uuid::new_v4())
+        .await
+        .expect("organization read");
+    assert!(missing.is_none(), "an unknown id finds no organization");
+}
+
+/// The name is unique across organizations.
+pub async fn duplicate_names_already_exist(repository: &impl OrganizationRepository) {
+    repository
+        .create(new_organization("Steppe Logistics"))
+        .await
+        .expect("organization created");
+
+    let duplicate = repository
+        .create(new_organization("Steppe Logistics"))
+        .await;
+    assert!(
+        matches!(
+            &duplicate,
+            Err(OrganizationRepositoryError::AlreadyExists(name)) if name == "Steppe Logistics"
+        ),
+        "a duplicate name already exists, got {:?}",
+        duplicate
+    );
+
+    repository
+        .create(new_organization("Altai Transit"))
+        .await
+        .expect("another name is accepted");
+}
+
+/// Of concurrent creates of the same name, one wins and the others already exist.
+pub async fn concurrent_creates_of_a_name(repository: &impl OrganizationRepository) {
+    let results =
+        join_all((0..8).map(|_| repository.create(new_organization("Steppe Logistics")))).await;
+
+    let created = results.iter().filter(|result| result.is_ok()).count();
+    assert_eq!(created, 1, "a single create wins");
+    for result in &results {
+        match result {
+            Ok(_) => {}
+            Err(OrganizationRepositoryError::AlreadyExists(name)) => {
+                assert_eq!(name, "Steppe Logistics")
+            }
+            Err(e) => panic!("a losing create already exists, got {:?}", e),
+        }
+    }
+}
@@ -0,0 +1,169 @@
+//! Contracts of `TelematicsDeviceRepository`.
+use crate::{backend::ConformanceBackend, fixtures};
+use domain::telematics::{
+    entities::telematics_device::TelematicsDevice,
+    repositories::telematics_device_repository::{
+        TelematicsDeviceRepository, TelematicsDeviceRepositoryError,
+    },
+    value_types::device_id::DeviceId,
+};
+use futures::future::join_all;
+use std::future::Future;
+
+/// Runs every check, each on a new backend from `new_backend`.
+pub async fn run<B, F, Fut>(new_backend: F)
+where
+    B: ConformanceBackend,
+    F: Fn() -> Fut,
+    Fut: Future<Output = B>,
+{
+    register_and_find(&new_backend().await).await;
+    devices_by_vehicle(&new_backend().await).await;
+    duplicate_device_ids_already_exist(&new_backend().await).await;
+    isolated_per_organization(&new_backend().await).await;
+    concurrent_registrations_of_a_device(&new_backend().await).await;
+}
+
+fn device_id(value: &str) -> DeviceId {
+    DeviceId::new(value).expect("valid device id")
+}
+
+/// A registered device is found by its device id, with its fields as given.
+pub async fn register_and_find(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let vehicle = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    let registered = fixtures::device(backend, &organization, &vehicle, "imei-1").await;
+
+    let found = backend
+        .telematics_devices()
+        .find_by_device_id(organization.id, &device_id("imei-1"))
+        .await
+        .expect("device read")
+        .expect("registered device is found");
+    assert_eq!(found, registered);
+    assert_eq!(found.vehicle_id, vehicle.id);
+    assert_eq!(found.registered_at, fixtures::at(1));
+
+    let missing = backend
+        .telematics_devices()
+        .find_by_device_id(organization.id, &device_id("imei-2"))
+        .await
+        .expect("device read");
+    assert!(missing.is_none(), "an unknown device id finds no device");
+}
+
+/// The devices of a vehicle are read by device id.
+pub async fn devices_by_vehicle(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let vehicle = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    let other = fixtures::vehicle(backend, &organization, 2, "456DEF02").await;
+    fixtures::device(backend, &organization, &vehicle, "obd-7").await;
+    fixtures::device(backend, &organization, &other, "imei-2").await;
+    fixtures::device(backend, &organization, &vehicle, "imei-1").await;
+
+    let devices = backend
+        .telematics_devices()
+        .find_by_vehicle(organization.id, vehicle.id)
+        .await
+        .expect("devices read");
+    let ids: Vec<&str> = devices
+        .iter()
+        .map(|device| device.device_id.value())
+        .collect();
+    assert_eq!(ids, vec!["imei-1", "obd-7"]);
+}
+
+/// A device id is registered once per organization, whatever the vehicle.
+pub async fn duplicate_device_ids_already_exist(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let vehicle = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    let other = fixtures::vehicle(backend, &organization, 2, "456DEF02").await;
+    fixtures::device(backend, &organization, &vehicle, "imei-1").await;
+
+    let duplicate = backend
+        .telematics_devices()
+        .register(
+            organization.id,
+            TelematicsDevice::new(device_id("imei-1"), other.id),
+        )
+        .await;
+    assert!(
+        matches!(
+            &duplicate,
+            Err(TelematicsDeviceRepositoryError::AlreadyExists(id)) if id == "imei-1"
+        ),
+        "a registered device id already exists, got {:?}",
+        duplicate
+    );
+
+    let found = backend
+        .telematics_devices()
+        .find_by_device_id(organization.id, &device_id("imei-1"))
+        .await
+        .expect("device read")
+        .expect("registered device is found");
+    assert_eq!(found.vehicle_id, vehicle.id, "the first registration stays");
+}
+
+/// The devices are not read in another organization, which has its own device ids.
+pub async fn isolated_per_organization(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let other = fixtures::organization(backend, "Altai Transit").await;
+    let vehicle = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    let other_vehicle = fixtures::vehicle(backend, &other, 2, "456DEF02").await;
+    fixtures::device(backend, &organization, &vehicle, "imei-1").await;
+    let devices = backend.telematics_devices();
+
+    let found = devices
+        .find_by_device_id(other.id, &device_id("imei-1"))
+        .await
+        .expect("device read");
+    assert!(
+        found.is_none(),
+        "the device is not read in another organization"
+    );
+    let by_vehicle = devices
+        .find_by_vehicle(other.id, vehicle.id)
+        .await
+        .expect("devices read");
+    assert!(
+        by_vehicle.is_empty(),
+        "the devices of a vehicle are not read in another organization"
+    );
+
+    fixtures::device(backend, &other, &other_vehicle, "imei-1").await;
+    let found = devices
+        .find_by_device_id(organization.id, &device_id("imei-1"))
+        .await
+        .expect("device read")
+        .expect("registered device is found");
+    assert_eq!(
+        found.vehicle_id, vehicle.id,
+        "the device of the other organization doesn't replace it"
+    );
+}
+
+/// Of concurrent registrations of a device id, one wins and the others already exist.
+pub async fn concurrent_registrations_of_a_device(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let vehicle = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    let devices = backend.telematics_devices();
+
+    let results = join_all((0..8).map(|_| {
+        devices.register(
+            organization.id,
+            TelematicsDevice::new(device_id("imei-1"), vehicle.id),
+        )
+    }))
+    .await;
+
+    let registered = results.iter().filter(|result| result.is_ok()).count();
+    assert_eq!(registered, 1, "a single registration wins");
+    for result in &results {
+        match result {
+            Ok(_) => {}
+            Err(TelematicsDeviceRepositoryError::AlreadyExists(id)) => assert_eq!(id, "imei-1"),
+            Err(e) => panic!("a losing registration already exists, got {:?}", e),
+        }
+    }
+}
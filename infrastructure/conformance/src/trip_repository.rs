@@ -0,0 +1,158 @@
+//! Contracts of `TripRepository`.
+use crate::{backend::ConformanceBackend, fixtures};
+use domain::trip::{
+    entities::trip::{NewTrip, Trip},
+    repositories::trip_repository::TripRepository,
+};
+use futures::future::join_all;
+use std::future::Future;
+
+/// Runs every check, each on a new backend from `new_backend`.
+pub async fn run<B, F, Fut>(new_backend: F)
+where
+    B: ConformanceBackend,
+    F: Fn() -> Fut,
+    Fut: Future<Output = B>,
+{
+    create_and_find(&new_backend().await).await;
+    trips_by_vehicle(&new_backend().await).await;
+    isolated_per_organization(&new_backend().await).await;
+    concurrent_creates(&new_backend().await).await;
+}
+
+/// A created trip is found by its id, with its track and distance as given.
+pub async fn create_and_find(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let driver = fixtures::user(backend, &organization, "alice").await;
+    let vehicle = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    let created = fixtures::trip(backend, &organization, &vehicle, Some(&driver), 1, 2).await;
+    let unknown_driver = fixtures::trip(backend, &organization, &vehicle, None, 3, 4).await;
+
+    let found = backend
+        .trips()
+        .find_by_id(organization.id, created.id)
+        .await
+        .expect("trip read")
+        .expect("created trip is found");
+    assert_eq!(found, created);
+    assert_eq!(
+        found.polyline,
+        fixtures::track(5),
+        "the track is kept point by point"
+    );
+    assert_eq!(found.driver_id, Some(driver.id));
+    assert_eq!(found.distance_km, created.distance_km);
+
+    let found = backend
+        .trips()
+        .find_by_id(organization.id, unknown_driver.id)
+        .await
+        .expect("trip read")
+        .expect("created trip is found");
+    assert_eq!(found.driver_id, None, "the driver may be unknown");
+
+    let missing = backend
+        .trips()
+        .find_by_id(organization.id, uuid::Uuid::new_v4())
+        .await
+        .expect("trip read");
+    assert!(missing.is_none(), "an unknown id finds no trip");
+}
+
+/// The trips of a vehicle overlapping a range are read by start, both ends inclusive.
+pub async fn trips_by_vehicle(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let vehicle = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    let other = fixtures::vehicle(backend, &organization, 2, "456DEF02").await;
+    let late = fixtures::trip(backend, &organization, &vehicle, None, 7, 8).await;
+    let early = fixtures::trip(backend, &organization, &vehicle, None, 1, 2).await;
+    let middle = fixtures::trip(backend, &organization, &vehicle, None, 4, 6).await;
+    fixtures::trip(backend, &organization, &other, None, 4, 5).await;
+    let trips = backend.trips();
+
+    let all = trips
+        .find_by_vehicle(organization.id, vehicle.id, None, None)
+        .await
+        .expect("trips read");
+    let ids: Vec<uuid::Uuid> = all.iter().map(|trip| trip.id).collect();
+    assert_eq!(ids, vec![early.id, middle.id, late.id]);
+
+    let overlapping = trips
+        .find_by_vehicle(
+            organization.id,
+            vehicle.id,
+            Some(fixtures::at(2)),
+            Some(fixtures::at(5)),
+        )
+        .await
+        .expect("trips read");
+    let ids: Vec<uuid::Uuid> = overlapping.iter().map(|trip| trip.id).collect();
+    assert_eq!(
+        ids,
+        vec![early.id, middle.id],
+        "a trip ending at the start of the range overlaps it, one starting after it doesn't"
+    );
+
+    let from_late = trips
+        .find_by_vehicle(organization.id, vehicle.id, Some(fixtures::at(7)), None)
+        .await
+        .expect("trips read");
+    let ids: Vec<uuid::Uuid> = from_late.iter().map(|trip| trip.id).collect();
+    assert_eq!(ids, vec![late.id]);
+}
+
+/// The trips are not read in another organization.
+pub async fn isolated_per_organization(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let other = fixtures::organization(backend, "Altai Transit").await;
+    let vehicle = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    let trip = fixtures::trip(backend, &organization, &vehicle, None, 1, 2).await;
+    let trips = backend.trips();
+
+    let found = trips
+        .find_by_id(other.id, trip.id)
+        .await
+        .expect("trip read");
+    assert!(
+        found.is_none(),
+        "the trip is not read in another organization"
+    );
+    let by_vehicle = trips
+        .find_by_vehicle(other.id, vehicle.id, None, None)
+        .await
+        .expect("trips read");
+    assert!(
+        by_vehicle.is_empty(),
+        "the trips of a vehicle are not read in another organization"
+    );
+}
+
+/// Concurrent trips of a vehicle are all stored.
+pub async fn concurrent_creates(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let vehicle = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    let trips = backend.trips();
+
+    let results = join_all((0..8).map(|day| {
+        let trip = Trip::new(NewTrip {
+            vehicle_id: vehicle.id,
+            driver_id: None,
+            started_at: fixtures::at(day),
+            ended_at: fixtures::at(day + 1),
+            polyline: fixtures::track(3),
+        })
+        .expect("valid trip");
+        trips.create(organization.id, trip)
+    }))
+    .await;
+    assert!(
+        results.iter().all(|result| result.is_ok()),
+        "every trip is created"
+    );
+
+    let stored = trips
+        .find_by_vehicle(organization.id, vehicle.id, None, None)
+        .await
+        .expect("trips read");
+    assert_eq!(stored.len(), 8);
+}
@@ -0,0 +1,159 @@
+//! Contracts of `VehicleMovementRepository`.
+use crate::{backend::ConformanceBackend, fixtures};
+use domain::depot::{
+    entities::vehicle_movement::VehicleMovement,
+    repositories::vehicle_movement_repository::VehicleMovementRepository,
+};
+use futures::future::join_all;
+use std::future::Future;
+
+/// Runs every check, each on a new backend from `new_backend`.
+pub async fn run<B, F, Fut>(new_backend: F)
+where
+    B: ConformanceBackend,
+    F: Fn() -> Fut,
+    Fut: Future<Output = B>,
+{
+    latest_movement(&new_backend().await).await;
+    movements_by_vehicle(&new_backend().await).await;
+    isolated_per_organization(&new_backend().await).await;
+    concurrent_movements(&new_backend().await).await;
+}
+
+/// The latest movement is the last one recorded, its depot is the home depot.
+pub async fn latest_movement(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let user = fixtures::user(backend, &organization, "alice").await;
+    let vehicle = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    let north = fixtures::region(backend, &organization, "North").await;
+    let astana = fixtures::depot(backend, &organization, &north, "Astana").await;
+    let karaganda = fixtures::depot(backend, &organization, &north, "Karaganda").await;
+    let movements = backend.vehicle_movements();
+
+    let none = movements
+        .find_latest(organization.id, vehicle.id)
+        .await
+        .expect("latest movement read");
+    assert!(none.is_none(), "a new vehicle has no home depot");
+
+    fixtures::movement(backend, &vehicle, &astana, &user, 1).await;
+    let second = fixtures::movement(backend, &vehicle, &karaganda, &user, 2).await;
+
+    let latest = movements
+        .find_latest(organization.id, vehicle.id)
+        .await
+        .expect("latest movement read")
+        .expect("the vehicle has a home depot");
+    assert_eq!(latest, second);
+    assert_eq!(latest.from_depot_id, Some(astana.id));
+    assert_eq!(latest.to_depot_id, karaganda.id);
+    assert_eq!(latest.moved_by, user.id);
+    assert_eq!(latest.moved_at, fixtures::at(2));
+    assert_eq!(latest.notes, "day 2");
+}
+
+/// The movements of a vehicle are read oldest first.
+pub async fn movements_by_vehicle(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let user = fixtures::user(backend, &organization, "alice").await;
+    let vehicle = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    let other = fixtures::vehicle(backend, &organization, 2, "456DEF02").await;
+    let north = fixtures::region(backend, &organization, "North").await;
+    let astana = fixtures::depot(backend, &organization, &north, "Astana").await;
+    let karaganda = fixtures::depot(backend, &organization, &north, "Karaganda").await;
+    for (day, depot) in [(1, &astana), (3, &karaganda), (5, &astana)] {
+        fixtures::movement(backend, &vehicle, depot, &user, day).await;
+    }
+    fixtures::movement(backend, &other, &karaganda, &user, 2).await;
+
+    let history = backend
+        .vehicle_movements()
+        .find_by_vehicle(organization.id, vehicle.id)
+        .await
+        .expect("movements read");
+    let moves: Vec<(Option<uuid::Uuid>, uuid::Uuid)> = history
+        .iter()
+        .map(|movement| (movement.from_depot_id, movement.to_depot_id))
+        .collect();
+    assert_eq!(
+        moves,
+        vec![
+            (None, astana.id),
+            (Some(astana.id), karaganda.id),
+            (Some(karaganda.id), astana.id)
+        ]
+    );
+}
+
+/// Concurrent movements of a vehicle are all recorded, one of them is the latest.
+pub async fn concurrent_movements(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let user = fixtures::user(backend, &organization, "alice").await;
+    let vehicle = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    let north = fixtures::region(backend, &organization, "North").await;
+    let astana = fixtures::depot(backend, &organization, &north, "Astana").await;
+    let movements = backend.vehicle_movements();
+
+    let recorded = join_all((0..8).map(|day| {
+        let movement = VehicleMovement::new(
+            vehicle.id,
+            None,
+            astana.id,
+            fixtures::at(day),
+            user.id,
+            String::new(),
+        )
+        .expect("valid movement");
+        movements.record(organization.id, movement)
+    }))
+    .await;
+    let recorded: Vec<VehicleMovement> = recorded
+        .into_iter()
+        .map(|movement| movement.expect("movement recorded"))
+        .collect();
+
+    let all = movements
+        .find_by_vehicle(organization.id, vehicle.id)
+        .await
+        .expect("movements read");
+    assert_eq!(all.len(), 8, "every movement is recorded");
+
+    let latest = movements
+        .find_latest(organization.id, vehicle.id)
+        .await
+        .expect("latest movement read")
+        .expect("the vehicle has a home depot");
+    assert!(
+        recorded.contains(&latest),
+        "the latest movement is one of the recorded ones"
+    );
+}
+
+/// The movements of a vehicle are not read in another organization.
+pub async fn isolated_per_organization(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let other = fixtures::organization(backend, "Altai Transit").await;
+    let user = fixtures::user(backend, &organization, "alice").await;
+    let vehicle = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    let north = fixtures::region(backend, &organization, "North").await;
+    let astana = fixtures::depot(backend, &organization, &north, "Astana").await;
+    fixtures::movement(backend, &vehicle, &astana, &user, 1).await;
+    let movements = backend.vehicle_movements();
+
+    let latest = movements
+        .find_latest(other.id, vehicle.id)
+        .await
+        .expect("latest movement read");
+    assert!(
+        latest.is_none(),
+        "the home depot is not read in another organization"
+    );
+    let all = movements
+        .find_by_vehicle(other.id, vehicle.id)
+        .await
+        .expect("movements read");
+    assert!(
+        all.is_empty(),
+        "the movements are not read in another organization"
+    );
+}
@@ -0,0 +1,312 @@
+//! Contracts of `VehicleRepository`.
+use crate::{backend::ConformanceBackend, fixtures};
+use domain::{
+    organization::entities::organization::Organization,
+    vehicle::{
+        repositories::vehicle_repository::{VehicleRepository, VehicleRepositoryError},
+        value_types::engine_type::EngineType,
+    },
+};
+use futures::future::join_all;
+use std::future::Future;
+
+/// Runs every check, each on a new backend from `new_backend`.
+pub async fn run<B, F, Fut>(new_backend: F)
+where
+    B: ConformanceBackend,
+    F: Fn() -> Fut,
+    Fut: Future<Output = B>,
+{
+    create_and_find(&new_backend().await).await;
+    exists_by_vin_or_license_plate(&new_backend().await).await;
+    find_taken_identifiers(&new_backend().await).await;
+    duplicates_already_exist(&new_backend().await).await;
+    delete(&new_backend().await).await;
+    isolated_per_organization(&new_backend().await).await;
+    concurrent_creates_of_a_vehicle(&new_backend().await).await;
+    concurrent_creates_of_vehicles(&new_backend().await).await;
+}
+
+async fn organization(backend: &impl ConformanceBackend) -> Organization {
+    fixtures::organization(backend, "Steppe Logistics").await
+}
+
+/// A created vehicle is found by its id and listed, with its fields as given.
+pub async fn create_and_find(backend: &impl ConformanceBackend) {
+    let organization = organization(backend).await;
+    let created = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    let repository = backend.vehicles();
+
+    let found = repository
+        .find_by_id(organization.id, created.id)
+        .await
+        .expect("vehicle read")
+        .expect("created vehicle is found");
+    assert_eq!(found.id, created.id);
+    assert_eq!(found.make, "Toyota");
+    assert_eq!(found.model, "Camry");
+    assert_eq!(found.year, 2021);
+    assert_eq!(found.vin.value(), "JTDBR32E700000001");
+    assert_eq!(found.license_plate.value(), "123ABC02");
+    assert_eq!(found.country.value(), "KZ");
+    assert_eq!(*found.powertrain.engine_type(), EngineType::Gasoline);
+    assert_eq!(
+        found.powertrain.tank_capacity(),
+        Some(rust_decimal::Decimal::new(605, 1))
+    );
+    assert_eq!(found.powertrain.battery_capacity(), None);
+
+    let missing = repository
+        .find_by_id(organization.id, uuid::Uuid::new_v4())
+        .await
+        .expect("vehicle read");
+    assert!(missing.is_none(), "an unknown id finds no vehicle");
+
+    let all = repository
+        .find_all(organization.id)
+        .await
+        .expect("vehicles read");
+    assert_eq!(all.len(), 1);
+    assert_eq!(all[0].id, created.id);
+}
+
+/// The VIN is unique, the license plate is unique per country.
+pub async fn exists_by_vin_or_license_plate(backend: &impl ConformanceBackend) {
+    let organization = organization(backend).await;
+    fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    let repository = backend.vehicles();
+
+    let by_vin = repository
+        .exists_by_vin_or_license_plate(organization.id, "JTDBR32E700000001", "KZ", "999ZZZ01")
+        .await
+        .expect("existence checked");
+    assert!(by_vin, "the VIN is taken");
+
+    let by_plate = repository
+        .exists_by_vin_or_license_plate(organization.id, "JTDBR32E700000002", "KZ", "123ABC02")
+        .await
+        .expect("existence checked");
+    assert!(by_plate, "the license plate is taken in KZ");
+
+    let other_country = repository
+        .exists_by_vin_or_license_plate(organization.id, "JTDBR32E700000002", "MN", "123ABC02")
+        .await
+        .expect("existence checked");
+    assert!(
+        !other_country,
+        "the license plate is free in another country"
+    );
+}
+
+/// The taken identifiers of a batch are the VINs and the plates (per country) of the vehicles of
+/// the organization, the others are free.
+pub async fn find_taken_identifiers(backend: &impl ConformanceBackend) {
+    let organization = organization(backend).await;
+    fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    fixtures::vehicle(backend, &organization, 2, "456DEF02").await;
+    let other = fixtures::organization(backend, "Altai Transit").await;
+    fixtures::vehicle(backend, &other, 3, "789GHI02").await;
+    let repository = backend.vehicles();
+
+    let vins = [
+        "JTDBR32E700000001",
+        "JTDBR32E700000003",
+        "JTDBR32E700000009",
+    ]
+    .map(String::from);
+    let plates = [("KZ", "456DEF02"), ("MN", "123ABC02"), ("KZ", "789GHI02")]
+        .map(|(country, plate)| (country.to_string(), plate.to_string()));
+    let taken = repository
+        .find_taken_identifiers(organization.id, &vins, &plates)
+        .await
+        .expect("identifiers checked");
+    assert_eq!(
+        taken.vins,
+        ["JTDBR32E700000001".to_string()].into(),
+        "only the VINs of the organization"
+    );
+    assert_eq!(
+        taken.license_plates,
+        [("KZ".to_string(), "456DEF02".to_string())].into(),
+        "only the plates of the organization, in their country"
+    );
+
+    let none = repository
+        .find_taken_identifiers(organization.id, &[], &[])
+        .await
+        .expect("identifiers checked");
+    assert_eq!(none, Default::default(), "nothing asked, nothing taken");
+}
+
+/// A second vehicle with the same VIN, or the same plate in the same country, already exists:
+/// the error carries the id of the existing vehicle.
+pub async fn duplicates_already_exist(backend: &impl ConformanceBackend) {
+    let organization = organization(backend).await;
+    let existing = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    let repository = backend.vehicles();
+
+    let same_vin = repository
+        .create(organization.id, fixtures::new_vehicle(1, "456DEF02", "KZ"))
+        .await;
+    assert!(
+        matches!(same_vin, Err(VehicleRepositoryError::AlreadyExists(id)) if id == existing.id),
+        "a duplicate VIN already exists, got {:?}",
+        same_vin
+    );
+
+    let same_plate = repository
+        .create(organization.id, fixtures::new_vehicle(2, "123ABC02", "KZ"))
+        .await;
+    assert!(
+        matches!(same_plate, Err(VehicleRepositoryError::AlreadyExists(id)) if id == existing.id),
+        "a duplicate plate in the same country already exists, got {:?}",
+        same_plate
+    );
+
+    repository
+        .create(organization.id, fixtures::new_vehicle(3, "123ABC02", "MN"))
+        .await
+        .expect("the same plate is accepted in another country");
+
+    let all = repository
+        .find_all(organization.id)
+        .await
+        .expect("vehicles read");
+    assert_eq!(all.len(), 2, "duplicates are not stored");
+}
+
+/// A vehicle is deleted once.
+pub async fn delete(backend: &impl ConformanceBackend) {
+    let organization = organization(backend).await;
+    let vehicle = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    let other = fixtures::vehicle(backend, &organization, 2, "456DEF02").await;
+    let repository = backend.vehicles();
+
+    let deleted = repository
+        .delete(organization.id, vehicle.id)
+        .await
+        .expect("vehicle deleted");
+    assert!(deleted, "an existing vehicle is deleted");
+    let again = repository
+        .delete(organization.id, vehicle.id)
+        .await
+        .expect("delete ran");
+    assert!(!again, "a deleted vehicle can't be deleted twice");
+
+    let found = repository
+        .find_by_id(organization.id, vehicle.id)
+        .await
+        .expect("vehicle read");
+    assert!(found.is_none());
+    let all = repository
+        .find_all(organization.id)
+        .await
+        .expect("vehicles read");
+    assert_eq!(all.len(), 1);
+    assert_eq!(all[0].id, other.id, "other vehicles are kept");
+}
+
+/// A vehicle of another organization is not found, nor deleted, and doesn't take its VIN or
+/// license plate.
+pub async fn isolated_per_organization(backend: &impl ConformanceBackend) {
+    let organization = organization(backend).await;
+    let other = fixtures::organization(backend, "Altai Transit").await;
+    let vehicle = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    let repository = backend.vehicles();
+
+    let found = repository
+        .find_by_id(other.id, vehicle.id)
+        .await
+        .expect("vehicle read");
+    assert!(
+        found.is_none(),
+        "a vehicle of another organization is not found"
+    );
+    let all = repository.find_all(other.id).await.expect("vehicles read");
+    assert!(
+        all.is_empty(),
+        "the vehicles of another organization are not listed"
+    );
+    let exists = repository
+        .exists_by_vin_or_license_plate(other.id, "JTDBR32E700000001", "KZ", "123ABC02")
+        .await
+        .expect("existence checked");
+    assert!(
+        !exists,
+        "the VIN and the plate are free in another organization"
+    );
+    let deleted = repository
+        .delete(other.id, vehicle.id)
+        .await
+        .expect("delete ran");
+    assert!(
+        !deleted,
+        "a vehicle of another organization can't be deleted"
+    );
+
+    let twin = fixtures::vehicle(backend, &other, 1, "123ABC02").await;
+    assert_ne!(twin.id, vehicle.id);
+    let all = repository
+        .find_all(organization.id)
+        .await
+        .expect("vehicles read");
+    assert_eq!(all.len(), 1);
+    assert_eq!(
+        all[0].id, vehicle.id,
+        "each organization sees its own vehicle"
+    );
+}
+
+/// Of concurrent creates of the same vehicle, one wins and the others already exist.
+pub async fn concurrent_creates_of_a_vehicle(backend: &impl ConformanceBackend) {
+    let organization = organization(backend).await;
+    let repository = backend.vehicles();
+    let results =
+        join_all((0..8).map(|_| {
+            repository.create(organization.id, fixtures::new_vehicle(1, "123ABC02", "KZ"))
+        }))
+        .await;
+
+    let created: Vec<_> = results
+        .iter()
+        .filter_map(|result| result.as_ref().ok())
+        .collect();
+    assert_eq!(created.len(), 1, "a single create wins");
+    let winner = created[0].id;
+    for result in &results {
+        match result {
+            Ok(_) => {}
+            Err(VehicleRepositoryError::AlreadyExists(id)) => assert_eq!(*id, winner),
+            Err(e) => panic!("a losing create already exists, got {:?}", e),
+        }
+    }
+
+    let all = repository
+        .find_all(organization.id)
+        .await
+        .expect("vehicles read");
+    assert_eq!(all.len(), 1);
+}
+
+/// Concurrent creates of different vehicles all succeed.
+pub async fn concurrent_creates_of_vehicles(backend: &impl ConformanceBackend) {
+    let organization = organization(backend).await;
+    let repository = backend.vehicles();
+    let plates: Vec<String> = (0..8).map(|index| format!("{:03}ABC02", index)).collect();
+    let results = join_all(plates.iter().enumerate().map(|(index, plate)| {
+        repository.create(
+            organization.id,
+            fixtures::new_vehicle(index as u32, plate, "KZ"),
+        )
+    }))
+    .await;
+    for result in &results {
+        assert!(result.is_ok(), "every create succeeds, got {:?}", result);
+    }
+
+    let all = repository
+        .find_all(organization.id)
+        .await
+        .expect("vehicles read");
+    assert_eq!(all.len(), 8);
+}
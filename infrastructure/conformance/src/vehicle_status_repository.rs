@@ -0,0 +1,189 @@
+//! Contracts of `VehicleStatusRepository`.
+use crate::{backend::ConformanceBackend, fixtures};
+use domain::vehicle::repositories::vehicle_status_repository::VehicleStatusRepository;
+use futures::future::join_all;
+use std::future::Future;
+
+/// Runs every check, each on a new backend from `new_backend`.
+pub async fn run<B, F, Fut>(new_backend: F)
+where
+    B: ConformanceBackend,
+    F: Fn() -> Fut,
+    Fut: Future<Output = B>,
+{
+    latest_status(&new_backend().await).await;
+    back_dated_status(&new_backend().await).await;
+    statuses_by_vehicle(&new_backend().await).await;
+    isolated_per_organization(&new_backend().await).await;
+    concurrent_statuses(&new_backend().await).await;
+}
+
+/// The latest status is the last one recorded.
+pub async fn latest_status(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let user = fixtures::user(backend, &organization, "alice").await;
+    let vehicle = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    let statuses = backend.vehicle_statuses();
+
+    let none = statuses
+        .find_latest(organization.id, vehicle.id)
+        .await
+        .expect("latest status read");
+    assert!(none.is_none(), "a new vehicle has no status");
+
+    fixtures::status(backend, &vehicle, &user, 1, 10_000).await;
+    let second = fixtures::status(backend, &vehicle, &user, 2, 10_500).await;
+
+    let latest = statuses
+        .find_latest(organization.id, vehicle.id)
+        .await
+        .expect("latest status read")
+        .expect("the vehicle has a status");
+    assert_eq!(latest.id, second.id);
+    assert_eq!(latest.odometer, 10_500);
+    assert_eq!(latest.performed_by, user.id);
+    assert_eq!(latest.performed_at, fixtures::at(2));
+    assert_eq!(latest.fuel_level, Some(50));
+    assert_eq!(latest.notes, "day 2");
+}
+
+/// A status performed before the latest one is inserted into the history, the latest stays.
+pub async fn back_dated_status(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let user = fixtures::user(backend, &organization, "alice").await;
+    let vehicle = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    fixtures::status(backend, &vehicle, &user, 1, 10_000).await;
+    let third = fixtures::status(backend, &vehicle, &user, 3, 10_300).await;
+    let second = fixtures::status(backend, &vehicle, &user, 2, 10_200).await;
+    let statuses = backend.vehicle_statuses();
+
+    let latest = statuses
+        .find_latest(organization.id, vehicle.id)
+        .await
+        .expect("latest status read")
+        .expect("the vehicle has a status");
+    assert_eq!(
+        latest.id, third.id,
+        "the latest status stays the last performed"
+    );
+
+    let all = statuses
+        .find_by_vehicle(organization.id, vehicle.id, None, None)
+        .await
+        .expect("statuses read");
+    let odometers: Vec<i32> = all.iter().map(|status| status.odometer).collect();
+    assert_eq!(odometers, vec![10_000, 10_200, 10_300]);
+
+    let before = statuses
+        .find_latest_before(organization.id, vehicle.id, fixtures::at(3))
+        .await
+        .expect("status read")
+        .expect("a status was performed before");
+    assert_eq!(before.id, second.id);
+    let none = statuses
+        .find_latest_before(organization.id, vehicle.id, fixtures::at(1))
+        .await
+        .expect("status read");
+    assert!(none.is_none(), "the end of the range is exclusive");
+}
+
+/// Statuses are read in order of `performed_at`, within an inclusive range.
+pub async fn statuses_by_vehicle(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let user = fixtures::user(backend, &organization, "alice").await;
+    let vehicle = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    let other = fixtures::vehicle(backend, &organization, 2, "456DEF02").await;
+    for (day, odometer) in [(1, 10_000), (3, 10_300), (5, 10_500)] {
+        fixtures::status(backend, &vehicle, &user, day, odometer).await;
+    }
+    fixtures::status(backend, &other, &user, 2, 50_000).await;
+    let statuses = backend.vehicle_statuses();
+
+    let all = statuses
+        .find_by_vehicle(organization.id, vehicle.id, None, None)
+        .await
+        .expect("statuses read");
+    let odometers: Vec<i32> = all.iter().map(|status| status.odometer).collect();
+    assert_eq!(odometers, vec![10_000, 10_300, 10_500]);
+
+    let range = statuses
+        .find_by_vehicle(
+            organization.id,
+            vehicle.id,
+            Some(fixtures::at(3)),
+            Some(fixtures::at(5)),
+        )
+        .await
+        .expect("statuses read");
+    let odometers: Vec<i32> = range.iter().map(|status| status.odometer).collect();
+    assert_eq!(odometers, vec![10_300, 10_500], "both ends are inclusive");
+
+    let since = statuses
+        .find_by_vehicle(organization.id, vehicle.id, Some(fixtures::at(2)), None)
+        .await
+        .expect("statuses read");
+    assert_eq!(since.len(), 2);
+
+    let until = statuses
+        .find_by_vehicle(organization.id, vehicle.id, None, Some(fixtures::at(2)))
+        .await
+        .expect("statuses read");
+    assert_eq!(until.len(), 1);
+}
+
+/// Concurrent statuses of a vehicle are all recorded, one of them is the latest.
+pub async fn concurrent_statuses(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let user = fixtures::user(backend, &organization, "alice").await;
+    let vehicle = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+
+    let inserted = join_all(
+        (0..8).map(|day| fixtures::status(backend, &vehicle, &user, day, 10_000 + day as i32)),
+    )
+    .await;
+
+    let all = backend
+        .vehicle_statuses()
+        .find_by_vehicle(organization.id, vehicle.id, None, None)
+        .await
+        .expect("statuses read");
+    assert_eq!(all.len(), 8, "every status is recorded");
+
+    let latest = backend
+        .vehicle_statuses()
+        .find_latest(organization.id, vehicle.id)
+        .await
+        .expect("latest status read")
+        .expect("the vehicle has a status");
+    assert!(
+        inserted.iter().any(|status| status.id == latest.id),
+        "the latest status is one of the recorded ones"
+    );
+}
+
+/// The statuses of a vehicle are not read in another organization.
+pub async fn isolated_per_organization(backend: &impl ConformanceBackend) {
+    let organization = fixtures::organization(backend, "Steppe Logistics").await;
+    let other = fixtures::organization(backend, "Altai Transit").await;
+    let user = fixtures::user(backend, &organization, "alice").await;
+    let vehicle = fixtures::vehicle(backend, &organization, 1, "123ABC02").await;
+    fixtures::status(backend, &vehicle, &user, 1, 10_000).await;
+    let statuses = backend.vehicle_statuses();
+
+    let latest = statuses
+        .find_latest(other.id, vehicle.id)
+        .await
+        .expect("latest status read");
+    assert!(
+        latest.is_none(),
+        "the status is not read in another organization"
+    );
+    let all = statuses
+        .find_by_vehicle(other.id, vehicle.id, None, None)
+        .await
+        .expect("statuses read");
+    assert!(
+        all.is_empty(),
+        "the statuses are not read in another organization"
+    );
+}
@@ -0,0 +1,119 @@
+//! Fault injection: makes the next (or every) call of an operation fail with a database error.
+//!
+//! Operations are named `<table>.<method>` (e.g., `vehicles.create`, `maintenance_types.merge`);
+//! a pattern is an operation, a table (`vehicles`) or `*` for every operation.
+use std::sync::{Mutex, PoisonError};
+
+#[derive(Debug, Clone)]
+struct Fault {
+    pattern: String,
+    message: String,
+    /// Number of calls left to fail, `None` for every call.
+    remaining: Option<u32>,
+}
+
+impl Fault {
+    fn matches(&self, operation: &str) -> bool {
+        self.pattern == "*"
+            || self.pattern == operation
+            || operation
+                .strip_prefix(self.pattern.as_str())
+                .is_some_and(|method| method.starts_with('.'))
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct FaultInjector {
+    faults: Mutex<Vec<Fault>>,
+}
+
+impl FaultInjector {
+    /// Fails the next call matching the pattern.
+    pub fn fail_next(&self, pattern: &str, message: &str) {
+        self.fail_times(pattern, 1, message);
+    }
+
+    /// Fails the next `times` calls matching the pattern.
+    pub fn fail_times(&self, pattern: &str, times: u32, message: &str) {
+        self.push(pattern, message, Some(times));
+    }
+
+    /// Fails every call matching the pattern, until `clear`.
+    pub fn fail_always(&self, pattern: &str, message: &str) {
+        self.push(pattern, message, None);
+    }
+
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// Returns the error of the first fault matching the operation, if any.
+    pub(crate) fn check(&self, operation: &str) -> Result<(), String> {
+        let mut faults = self.lock();
+        let Some(index) = faults.iter().position(|fault| fault.matches(operation)) else {
+            return Ok(());
+        };
+
+        let message = format!("{} (injected on {})", faults[index].message, operation);
+        if let Some(remaining) = &mut faults[index].remaining {
+            *remaining -= 1;
+            if *remaining == 0 {
+                faults.remove(index);
+            }
+        }
+        Err(message)
+    }
+
+    fn push(&self, pattern: &str, message: &str, remaining: Option<u32>) {
+        if remaining == Some(0) {
+            return;
+        }
+        self.lock().push(Fault {
+            pattern: pattern.to_string(),
+            message: message.to_string(),
+            remaining,
+        });
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<Fault>> {
+        self.faults.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fail_next_fails_once() {
+        let faults = FaultInjector::default();
+        faults.fail_next("vehicles", "connection reset");
+
+        assert!(faults.check("users.insert").is_ok());
+        assert_eq!(
+            faults.check("vehicles.create").unwrap_err(),
+            "connection reset (injected on vehicles.create)"
+        );
+        assert!(faults.check("vehicles.create").is_ok());
+    }
+
+    #[test]
+    fn test_fail_always_until_clear() {
+        let faults = FaultInjector::default();
+        faults.fail_always("*", "database is down");
+
+        assert!(faults.check("vehicles.create").is_err());
+        assert!(faults.check("fuel_events.find_by_vehicle").is_err());
+        faults.clear();
+        assert!(faults.check("vehicles.create").is_ok());
+    }
+
+    #[test]
+    fn test_table_pattern_is_not_a_prefix() {
+        let faults = FaultInjector::default();
+        faults.fail_times("maintenance", 2, "timeout");
+
+        assert!(faults.check("maintenance_types.create").is_ok());
+        assert!(faults.check("maintenance.create").is_err());
+    }
+}
@@ -0,0 +1,78 @@
+//! Evaluates the filter expressions of the list queries against rows, with the semantics of the
+//! SQL the Postgres adapter compiles them to (`!=` and `not in` keep NULLs, `~` ignores case).
+use application::shared::filter_expression::filter_ast::{
+    FilterCondition, FilterExpression, FilterOperand, FilterOperator, FilterValue,
+};
+use std::cmp::Ordering;
+
+/// Checks if a row matches the expression, `value_of` returns the value of a column of the row
+/// (`None` for NULL).
+pub fn matches(
+    expression: &FilterExpression,
+    value_of: &impl Fn(&str) -> Option<FilterValue>,
+) -> bool {
+    match expression {
+        FilterExpression::Condition(condition) => matches_condition(condition, value_of),
+        FilterExpression::And(left, right) => matches(left, value_of) && matches(right, value_of),
+        FilterExpression::Or(left, right) => matches(left, value_of) || matches(right, value_of),
+        FilterExpression::Not(expression) => !matches(expression, value_of),
+    }
+}
+
+fn matches_condition(
+    condition: &FilterCondition,
+    value_of: &impl Fn(&str) -> Option<FilterValue>,
+) -> bool {
+    let value = value_of(condition.field.column);
+
+    match (&condition.operator, &condition.operand, value) {
+        (FilterOperator::Ne, _, None) | (FilterOperator::NotIn, _, None) => true,
+        (_, _, None) => false,
+        (FilterOperator::Contains, FilterOperand::Value(expected), Some(value)) => text_of(&value)
+            .to_lowercase()
+            .contains(&text_of(expected).to_lowercase()),
+        (FilterOperator::In, FilterOperand::List(expected), Some(value)) => expected
+            .iter()
+            .any(|expected| compare(&value, expected) == Ordering::Equal),
+        (FilterOperator::NotIn, FilterOperand::List(expected), Some(value)) => expected
+            .iter()
+            .all(|expected| compare(&value, expected) != Ordering::Equal),
+        (operator, FilterOperand::Value(expected), Some(value)) => {
+            let ordering = compare(&value, expected);
+            match operator {
+                FilterOperator::Ne => ordering != Ordering::Equal,
+                FilterOperator::Lt => ordering == Ordering::Less,
+                FilterOperator::Le => ordering != Ordering::Greater,
+                FilterOperator::Gt => ordering == Ordering::Greater,
+                FilterOperator::Ge => ordering != Ordering::Less,
+                _ => ordering == Ordering::Equal,
+            }
+        }
+        (_, FilterOperand::List(_), Some(_)) => false,
+    }
+}
+
+pub(crate) fn text_of(value: &FilterValue) -> String {
+    match value {
+        FilterValue::Text(text) | FilterValue::Enum(text) => text.clone(),
+        FilterValue::Integer(integer) => integer.to_string(),
+        FilterValue::Decimal(decimal) => decimal.to_string(),
+        FilterValue::Timestamp(timestamp) => timestamp.to_rfc3339(),
+    }
+}
+
+/// Compares two values of the same type (values of different types compare as text).
+pub(crate) fn compare(left: &FilterValue, right: &FilterValue) -> Ordering {
+    match (left, right) {
+        (FilterValue::Integer(left), FilterValue::Integer(right)) => left.cmp(right),
+        (FilterValue::Decimal(left), FilterValue::Decimal(right)) => left.cmp(right),
+        (FilterValue::Integer(left), FilterValue::Decimal(right)) => {
+            rust_decimal::Decimal::from(*left).cmp(right)
+        }
+        (FilterValue::Decimal(left), FilterValue::Integer(right)) => {
+            left.cmp(&rust_decimal::Decimal::from(*right))
+        }
+        (FilterValue::Timestamp(left), FilterValue::Timestamp(right)) => left.cmp(right),
+        (left, right) => text_of(left).cmp(&text_of(right)),
+    }
+}
@@ -0,0 +1,189 @@
+//! Reads a page of rows after (or before) a keyset position, like the `(sort_column, id)` row
+//! comparisons of the SQL adapters: NULLs sort last in ascending order and first in descending
+//! order.
+use super::filter_expression_eval::compare;
+use application::shared::{
+    filter_expression::filter_ast::FilterValue,
+    pagination::{Keyset, KeysetPosition, SortOrder},
+};
+use std::cmp::Ordering;
+
+/// The sort value (`None` for NULL) and id of a row.
+pub type SortKey = (Option<FilterValue>, FilterValue);
+
+/// Sorts rows by their sort value then id.
+pub fn sort_rows<T>(rows: &mut [T], sort_order: SortOrder, key_of: impl Fn(&T) -> SortKey) {
+    rows.sort_by_cached_key(|row| SortedKey(key_of(row), sort_order));
+}
+
+/// A sort key ordered with the sort order, for `sort_by_cached_key`.
+struct SortedKey(SortKey, SortOrder);
+
+impl PartialEq for SortedKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for SortedKey {}
+
+impl PartialOrd for SortedKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SortedKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare_keys(&self.0, &other.0, self.1)
+    }
+}
+
+/// Returns at most `keyset.limit` rows after (or before) the position, in scanning order.
+pub fn read_page<T>(rows: Vec<T>, keyset: &Keyset, key_of: impl Fn(&T) -> SortKey) -> Vec<T> {
+    let mut rows: Vec<(SortKey, T)> = rows.into_iter().map(|row| (key_of(&row), row)).collect();
+    rows.sort_by(|(left, _), (right, _)| compare_keys(left, right, keyset.sort_order));
+    if keyset.is_backward() {
+        rows.reverse();
+    }
+
+    rows.into_iter()
+        .filter(|(key, _)| {
+            keyset.position.as_ref().is_none_or(|position| {
+                let ordering = compare_to_position(key, position, keyset.sort_order);
+                match keyset.is_backward() {
+                    false => ordering == Ordering::Greater,
+                    true => ordering == Ordering::Less,
+                }
+            })
+        })
+        .take(keyset.limit as usize)
+        .map(|(_, row)| row)
+        .collect()
+}
+
+fn compare_nullable(
+    left: Option<&FilterValue>,
+    right: Option<&FilterValue>,
+    compare: impl Fn(&FilterValue, &FilterValue) -> Ordering,
+) -> Ordering {
+    match (left, right) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(left), Some(right)) => compare(left, right),
+    }
+}
+
+fn apply_order(ordering: Ordering, sort_order: SortOrder) -> Ordering {
+    match sort_order {
+        SortOrder::Asc => ordering,
+        SortOrder::Desc => ordering.reverse(),
+    }
+}
+
+fn compare_keys(left: &SortKey, right: &SortKey, sort_order: SortOrder) -> Ordering {
+    let ordering = compare_nullable(left.0.as_ref(), right.0.as_ref(), compare)
+        .then_with(|| compare(&left.1, &right.1));
+    apply_order(ordering, sort_order)
+}
+
+/// Compares a row to a position, the texts of the position are read as the type of the row.
+fn compare_to_position(
+    key: &SortKey,
+    position: &KeysetPosition,
+    sort_order: SortOrder,
+) -> Ordering {
+    // NULLs sort after every value
+    let by_value = match (&key.0, &position.value) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(value), Some(text)) => compare(value, &parse_like(value, text)),
+    };
+    let ordering = by_value.then_with(|| compare(&key.1, &parse_like(&key.1, &position.id)));
+    apply_order(ordering, sort_order)
+}
+
+/// Reads a text as the type of `like` (as text if it can't be).
+fn parse_like(like: &FilterValue, text: &str) -> FilterValue {
+    let parsed = match like {
+        FilterValue::Integer(_) => text.parse().ok().map(FilterValue::Integer),
+        FilterValue::Decimal(_) => text.parse().ok().map(FilterValue::Decimal),
+        FilterValue::Timestamp(_) => chrono::DateTime::parse_from_rfc3339(text)
+            .ok()
+            .map(|timestamp| FilterValue::Timestamp(timestamp.with_timezone(&chrono::Utc))),
+        FilterValue::Enum(_) => Some(FilterValue::Enum(text.to_string())),
+        FilterValue::Text(_) => None,
+    };
+    parsed.unwrap_or_else(|| FilterValue::Text(text.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use application::shared::cursor::CursorDirection;
+
+    fn keyset(
+        sort_order: SortOrder,
+        position: Option<(Option<i64>, i64)>,
+        direction: CursorDirection,
+    ) -> Keyset {
+        Keyset {
+            sort_column: "year",
+            sort_order,
+            position: position.map(|(value, id)| KeysetPosition {
+                value: value.map(|value| value.to_string()),
+                id: id.to_string(),
+            }),
+            direction,
+            limit: 2,
+        }
+    }
+
+    fn page(rows: &[(Option<i64>, i64)], keyset: &Keyset) -> Vec<i64> {
+        read_page(rows.to_vec(), keyset, |(value, id)| {
+            (value.map(FilterValue::Integer), FilterValue::Integer(*id))
+        })
+        .into_iter()
+        .map(|(_, id)| id)
+        .collect()
+    }
+
+    const ROWS: [(Option<i64>, i64); 5] = [
+        (Some(2020), 1),
+        (None, 2),
+        (Some(2018), 3),
+        (Some(2020), 4),
+        (Some(2019), 5),
+    ];
+
+    #[test]
+    fn test_pages_forward_with_nulls_last() {
+        let first = keyset(SortOrder::Asc, None, CursorDirection::Next);
+        assert_eq!(page(&ROWS, &first), [3, 5]);
+
+        let second = keyset(SortOrder::Asc, Some((Some(2019), 5)), CursorDirection::Next);
+        assert_eq!(page(&ROWS, &second), [1, 4]);
+
+        let last = keyset(SortOrder::Asc, Some((Some(2020), 4)), CursorDirection::Next);
+        assert_eq!(page(&ROWS, &last), [2]);
+    }
+
+    #[test]
+    fn test_pages_backward_in_scanning_order() {
+        let previous = keyset(
+            SortOrder::Desc,
+            Some((Some(2019), 5)),
+            CursorDirection::Previous,
+        );
+        assert_eq!(page(&ROWS, &previous), [1, 4]);
+
+        let previous = keyset(
+            SortOrder::Desc,
+            Some((Some(2020), 4)),
+            CursorDirection::Previous,
+        );
+        assert_eq!(page(&ROWS, &previous), [2]);
+    }
+}
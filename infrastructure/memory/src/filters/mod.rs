@@ -0,0 +1,2 @@
+pub mod filter_expression_eval;
+pub mod keyset;
@@ -0,0 +1,28 @@
+//! In-memory implementation of the repository traits, to run the application layer in tests and
+//! demos without a database.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * Every repository shares a `MemoryStore`, cloning a store or a repository shares the data.
+//! * The store honors the constraints of the SQL schema (`migrations/`): unique keys, foreign keys,
+//!   `ON DELETE CASCADE` / `NO ACTION`, and a single `latest` status and movement per vehicle. A
+//!   violation is reported as a database error with the name of the constraint, except the
+//!   duplicates a trait reports as `AlreadyExists`.
+//! * A write is a transaction: it works on a copy of the tables, which replaces them only when it
+//!   succeeds.
+//! * A transaction of the unit of work writes its own copy of the tables, which replaces the ones
+//!   of its parent on commit, unless they were written since it started (serialization failure).
+//! * A row carries its organization (`TenantRow`): a repository only reads and writes the rows of
+//!   the organization of the call, like the row-level security policies of PostgreSQL, and the
+//!   unique keys are per organization. Foreign keys ignore the organization, like SQL ones.
+//! * Faults can be injected per operation (`MemoryStore::faults`) to simulate database errors.
+//! * `MemoryTelematicsBroker` stands in for the MQTT broker of the telematics devices: it keeps the
+//!   published messages, in order, until they are received.
+//! * Users, which have no repository method to insert them, are seeded through the store, in an
+//!   organization created with `MemoryOrganizationRepository`.
+pub mod faults;
+pub mod filters;
+pub mod repositories;
+pub mod store;
+pub mod telematics_broker;
+pub mod unit_of_work;
@@ -0,0 +1,234 @@
+use crate::store::{DepotManagerRow, MemoryStore, TenantRow, foreign_key, rows_of, unique};
+use domain::depot::{
+    entities::{depot::Depot, region::Region, site::Site},
+    repositories::depot_repository::{DepotRepository, DepotRepositoryError},
+};
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct MemoryDepotRepository {
+    store: MemoryStore,
+}
+
+impl MemoryDepotRepository {
+    pub fn new(store: &MemoryStore) -> Self {
+        MemoryDepotRepository {
+            store: store.clone(),
+        }
+    }
+}
+
+/// The rows of an organization, sorted by name (then id, like the `ORDER BY` of the databases).
+fn by_name<T: Clone>(
+    rows: &[TenantRow<T>],
+    organization_id: Uuid,
+    name: impl Fn(&T) -> (String, Uuid),
+) -> Vec<T> {
+    let mut rows: Vec<T> = rows_of(rows, organization_id).cloned().collect();
+    rows.sort_by_key(|row| name(row));
+    rows
+}
+
+impl DepotRepository for MemoryDepotRepository {
+    async fn create_region(
+        &self,
+        organization_id: Uuid,
+        region: Region,
+    ) -> Result<Region, DepotRepositoryError> {
+        // A duplicate name is reported with the name, not as a database error
+        let created = self
+            .store
+            .write("regions.create", |tables| {
+                tables.organization(organization_id, "regions_organization_id_fkey")?;
+                if rows_of(&tables.regions, organization_id)
+                    .any(|existing| existing.name == region.name)
+                {
+                    return Ok(Err(region.name.value().to_string()));
+                }
+                if tables
+                    .regions
+                    .iter()
+                    .any(|existing| existing.id == region.id)
+                {
+                    return Err(unique("regions_pkey"));
+                }
+
+                tables
+                    .regions
+                    .push(TenantRow::new(organization_id, region.clone()));
+                Ok(Ok(region))
+            })
+            .map_err(DepotRepositoryError::Database)?;
+        created.map_err(DepotRepositoryError::AlreadyExists)
+    }
+
+    async fn create_depot(
+        &self,
+        organization_id: Uuid,
+        depot: Depot,
+    ) -> Result<Depot, DepotRepositoryError> {
+        let created = self
+            .store
+            .write("depots.create", |tables| {
+                tables.organization(organization_id, "depots_organization_id_fkey")?;
+                if !tables
+                    .regions
+                    .iter()
+                    .any(|region| region.id == depot.region_id)
+                {
+                    return Err(foreign_key("depots_region_id_fkey"));
+                }
+                if rows_of(&tables.depots, organization_id)
+                    .any(|existing| existing.name == depot.name)
+                {
+                    return Ok(Err(depot.name.value().to_string()));
+                }
+                if tables.depots.iter().any(|existing| existing.id == depot.id) {
+                    return Err(unique("depots_pkey"));
+                }
+
+                tables
+                    .depots
+                    .push(TenantRow::new(organization_id, depot.clone()));
+                Ok(Ok(depot))
+            })
+            .map_err(DepotRepositoryError::Database)?;
+        created.map_err(DepotRepositoryError::AlreadyExists)
+    }
+
+    async fn create_site(
+        &self,
+        organization_id: Uuid,
+        site: Site,
+    ) -> Result<Site, DepotRepositoryError> {
+        let created = self
+            .store
+            .write("sites.create", |tables| {
+                tables.organization(organization_id, "sites_organization_id_fkey")?;
+                if !tables.depots.iter().any(|depot| depot.id == site.depot_id) {
+                    return Err(foreign_key("sites_depot_id_fkey"));
+                }
+                if rows_of(&tables.sites, organization_id).any(|existing| {
+                    existing.depot_id == site.depot_id && existing.name == site.name
+                }) {
+                    return Ok(Err(site.name.value().to_string()));
+                }
+                if tables.sites.iter().any(|existing| existing.id == site.id) {
+                    return Err(unique("sites_pkey"));
+                }
+
+                tables
+                    .sites
+                    .push(TenantRow::new(organization_id, site.clone()));
+                Ok(Ok(site))
+            })
+            .map_err(DepotRepositoryError::Database)?;
+        created.map_err(DepotRepositoryError::AlreadyExists)
+    }
+
+    async fn find_regions(
+        &self,
+        organization_id: Uuid,
+    ) -> Result<Vec<Region>, DepotRepositoryError> {
+        self.store
+            .read("regions.find_all", |tables| {
+                Ok(by_name(&tables.regions, organization_id, |region| {
+                    (region.name.value().to_string(), region.id)
+                }))
+            })
+            .map_err(DepotRepositoryError::Database)
+    }
+
+    async fn find_depots(&self, organization_id: Uuid) -> Result<Vec<Depot>, DepotRepositoryError> {
+        self.store
+            .read("depots.find_all", |tables| {
+                Ok(by_name(&tables.depots, organization_id, |depot| {
+                    (depot.name.value().to_string(), depot.id)
+                }))
+            })
+            .map_err(DepotRepositoryError::Database)
+    }
+
+    async fn find_sites(&self, organization_id: Uuid) -> Result<Vec<Site>, DepotRepositoryError> {
+        self.store
+            .read("sites.find_all", |tables| {
+                Ok(by_name(&tables.sites, organization_id, |site| {
+                    (site.name.value().to_string(), site.id)
+                }))
+            })
+            .map_err(DepotRepositoryError::Database)
+    }
+
+    async fn find_region(
+        &self,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> Result<Option<Region>, DepotRepositoryError> {
+        self.store
+            .read("regions.find_by_id", |tables| {
+                Ok(rows_of(&tables.regions, organization_id)
+                    .find(|region| region.id == id)
+                    .cloned())
+            })
+            .map_err(DepotRepositoryError::Database)
+    }
+
+    async fn find_depot(
+        &self,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> Result<Option<Depot>, DepotRepositoryError> {
+        self.store
+            .read("depots.find_by_id", |tables| {
+                Ok(rows_of(&tables.depots, organization_id)
+                    .find(|depot| depot.id == id)
+                    .cloned())
+            })
+            .map_err(DepotRepositoryError::Database)
+    }
+
+    async fn assign_manager(
+        &self,
+        organization_id: Uuid,
+        depot_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), DepotRepositoryError> {
+        self.store
+            .write("depot_managers.assign", |tables| {
+                tables.organization(organization_id, "depot_managers_organization_id_fkey")?;
+                if !tables.depots.iter().any(|depot| depot.id == depot_id) {
+                    return Err(foreign_key("depot_managers_depot_id_fkey"));
+                }
+                tables.user(user_id, "depot_managers_user_id_fkey")?;
+
+                // Like `ON CONFLICT DO NOTHING` on the primary key
+                if !rows_of(&tables.depot_managers, organization_id)
+                    .any(|row| row.depot_id == depot_id && row.user_id == user_id)
+                {
+                    tables.depot_managers.push(TenantRow::new(
+                        organization_id,
+                        DepotManagerRow { depot_id, user_id },
+                    ));
+                }
+                Ok(())
+            })
+            .map_err(DepotRepositoryError::Database)
+    }
+
+    async fn managed_depots(
+        &self,
+        organization_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Vec<Uuid>, DepotRepositoryError> {
+        self.store
+            .read("depot_managers.find_by_user", |tables| {
+                let mut depots: Vec<Uuid> = rows_of(&tables.depot_managers, organization_id)
+                    .filter(|row| row.user_id == user_id)
+                    .map(|row| row.depot_id)
+                    .collect();
+                depots.sort();
+                Ok(depots)
+            })
+            .map_err(DepotRepositoryError::Database)
+    }
+}
@@ -0,0 +1,285 @@
+use crate::store::{MemoryStore, TenantRow, foreign_key, rows_of, unique};
+use domain::diagnostics::{
+    entities::{
+        diagnostic_alert::DiagnosticAlert, diagnostic_rule::DiagnosticRule, dtc_event::DtcEvent,
+    },
+    repositories::diagnostic_repository::{DiagnosticRepository, DiagnosticRepositoryError},
+    value_types::dtc_code::DtcCode,
+};
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct MemoryDiagnosticRepository {
+    store: MemoryStore,
+}
+
+impl MemoryDiagnosticRepository {
+    pub fn new(store: &MemoryStore) -> Self {
+        MemoryDiagnosticRepository {
+            store: store.clone(),
+        }
+    }
+}
+
+impl DiagnosticRepository for MemoryDiagnosticRepository {
+    async fn open_event(
+        &self,
+        organization_id: Uuid,
+        event: DtcEvent,
+    ) -> Result<DtcEvent, DiagnosticRepositoryError> {
+        let opened = self
+            .store
+            .write("dtc_events.open_event", |tables| {
+                tables.organization(organization_id, "dtc_events_organization_id_fkey")?;
+                if tables.vehicle(event.vehicle_id).is_none() {
+                    return Err(foreign_key("dtc_events_vehicle_id_fkey"));
+                }
+                if let Some(cleared_by) = event.cleared_by {
+                    tables.user(cleared_by, "dtc_events_cleared_by_fkey")?;
+                }
+                if tables
+                    .dtc_events
+                    .iter()
+                    .any(|existing| existing.id == event.id)
+                {
+                    return Err(unique("dtc_events_pkey"));
+                }
+                // one_active_dtc_event_per_code
+                if event.is_active()
+                    && tables.dtc_events.iter().any(|existing| {
+                        existing.vehicle_id == event.vehicle_id
+                            && existing.code == event.code
+                            && existing.is_active()
+                    })
+                {
+                    return Ok(Err(event.code.to_string()));
+                }
+
+                tables
+                    .dtc_events
+                    .push(TenantRow::new(organization_id, event.clone()));
+                Ok(Ok(event))
+            })
+            .map_err(DiagnosticRepositoryError::Database)?;
+        opened.map_err(DiagnosticRepositoryError::AlreadyExists)
+    }
+
+    async fn update_event(
+        &self,
+        organization_id: Uuid,
+        event: DtcEvent,
+    ) -> Result<bool, DiagnosticRepositoryError> {
+        self.store
+            .write("dtc_events.update_event", |tables| {
+                if let Some(cleared_by) = event.cleared_by {
+                    tables.user(cleared_by, "dtc_events_cleared_by_fkey")?;
+                }
+                if event.is_active()
+                    && tables.dtc_events.iter().any(|existing| {
+                        existing.id != event.id
+                            && existing.vehicle_id == event.vehicle_id
+                            && existing.code == event.code
+                            && existing.is_active()
+                    })
+                {
+                    return Err(unique("one_active_dtc_event_per_code"));
+                }
+                let Some(existing) = tables.dtc_events.iter_mut().find(|existing| {
+                    existing.organization_id == organization_id && existing.id == event.id
+                }) else {
+                    return Ok(false);
+                };
+
+                // Only the columns written by the UPDATE
+                existing.severity = event.severity;
+                existing.first_seen_at = event.first_seen_at;
+                existing.last_seen_at = event.last_seen_at;
+                existing.occurrences = event.occurrences;
+                existing.cleared_at = event.cleared_at;
+                existing.cleared_by = event.cleared_by;
+                Ok(true)
+            })
+            .map_err(DiagnosticRepositoryError::Database)
+    }
+
+    async fn find_event(
+        &self,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> Result<Option<DtcEvent>, DiagnosticRepositoryError> {
+        self.store
+            .read("dtc_events.find_event", |tables| {
+                Ok(rows_of(&tables.dtc_events, organization_id)
+                    .find(|event| event.id == id)
+                    .cloned())
+            })
+            .map_err(DiagnosticRepositoryError::Database)
+    }
+
+    async fn find_active_event(
+        &self,
+        organization_id: Uuid,
+        vehicle_id: Uuid,
+        code: &DtcCode,
+    ) -> Result<Option<DtcEvent>, DiagnosticRepositoryError> {
+        self.store
+            .read("dtc_events.find_active_event", |tables| {
+                Ok(rows_of(&tables.dtc_events, organization_id)
+                    .find(|event| {
+                        event.vehicle_id == vehicle_id && &event.code == code && event.is_active()
+                    })
+                    .cloned())
+            })
+            .map_err(DiagnosticRepositoryError::Database)
+    }
+
+    async fn find_events_by_vehicle(
+        &self,
+        organization_id: Uuid,
+        vehicle_id: Uuid,
+        include_cleared: bool,
+    ) -> Result<Vec<DtcEvent>, DiagnosticRepositoryError> {
+        self.store
+            .read("dtc_events.find_events_by_vehicle", |tables| {
+                let mut events: Vec<DtcEvent> = rows_of(&tables.dtc_events, organization_id)
+                    .filter(|event| {
+                        event.vehicle_id == vehicle_id && (include_cleared || event.is_active())
+                    })
+                    .cloned()
+                    .collect();
+                events.sort_by_key(|event| (event.first_seen_at, event.id));
+                Ok(events)
+            })
+            .map_err(DiagnosticRepositoryError::Database)
+    }
+
+    async fn create_rule(
+        &self,
+        organization_id: Uuid,
+        rule: DiagnosticRule,
+    ) -> Result<DiagnosticRule, DiagnosticRepositoryError> {
+        let created = self
+            .store
+            .write("diagnostic_rules.create_rule", |tables| {
+                tables.organization(organization_id, "diagnostic_rules_organization_id_fkey")?;
+                if let Some(maintenance_type_id) = rule.action.maintenance_type_id()
+                    && tables.maintenance_type(maintenance_type_id).is_none()
+                {
+                    return Err(foreign_key("diagnostic_rules_maintenance_type_id_fkey"));
+                }
+                if rows_of(&tables.diagnostic_rules, organization_id)
+                    .any(|existing| existing.name == rule.name)
+                {
+                    return Ok(Err(rule.name.clone()));
+                }
+
+                tables
+                    .diagnostic_rules
+                    .push(TenantRow::new(organization_id, rule.clone()));
+                Ok(Ok(rule))
+            })
+            .map_err(DiagnosticRepositoryError::Database)?;
+        created.map_err(DiagnosticRepositoryError::AlreadyExists)
+    }
+
+    async fn find_rules(
+        &self,
+        organization_id: Uuid,
+    ) -> Result<Vec<DiagnosticRule>, DiagnosticRepositoryError> {
+        self.store
+            .read("diagnostic_rules.find_rules", |tables| {
+                let mut rules: Vec<DiagnosticRule> =
+                    rows_of(&tables.diagnostic_rules, organization_id)
+                        .cloned()
+                        .collect();
+                rules.sort_by(|a, b| (&a.name, a.id).cmp(&(&b.name, b.id)));
+                Ok(rules)
+            })
+            .map_err(DiagnosticRepositoryError::Database)
+    }
+
+    async fn open_alert(
+        &self,
+        organization_id: Uuid,
+        alert: DiagnosticAlert,
+    ) -> Result<DiagnosticAlert, DiagnosticRepositoryError> {
+        let opened = self
+            .store
+            .write("diagnostic_alerts.open_alert", |tables| {
+                tables.organization(organization_id, "diagnostic_alerts_organization_id_fkey")?;
+                if tables.vehicle(alert.vehicle_id).is_none() {
+                    return Err(foreign_key("diagnostic_alerts_vehicle_id_fkey"));
+                }
+                if !tables
+                    .dtc_events
+                    .iter()
+                    .any(|event| event.id == alert.dtc_event_id)
+                {
+                    return Err(foreign_key("diagnostic_alerts_dtc_event_id_fkey"));
+                }
+                if !tables
+                    .diagnostic_rules
+                    .iter()
+                    .any(|rule| rule.id == alert.rule_id)
+                {
+                    return Err(foreign_key("diagnostic_alerts_rule_id_fkey"));
+                }
+                if tables.diagnostic_alerts.iter().any(|existing| {
+                    existing.dtc_event_id == alert.dtc_event_id && existing.rule_id == alert.rule_id
+                }) {
+                    return Ok(Err(alert.rule_id.to_string()));
+                }
+
+                tables
+                    .diagnostic_alerts
+                    .push(TenantRow::new(organization_id, alert.clone()));
+                Ok(Ok(alert))
+            })
+            .map_err(DiagnosticRepositoryError::Database)?;
+        opened.map_err(DiagnosticRepositoryError::AlreadyExists)
+    }
+
+    async fn find_alerts_by_vehicle(
+        &self,
+        organization_id: Uuid,
+        vehicle_id: Uuid,
+        open_only: bool,
+    ) -> Result<Vec<DiagnosticAlert>, DiagnosticRepositoryError> {
+        self.store
+            .read("diagnostic_alerts.find_alerts_by_vehicle", |tables| {
+                let mut alerts: Vec<DiagnosticAlert> =
+                    rows_of(&tables.diagnostic_alerts, organization_id)
+                        .filter(|alert| {
+                            alert.vehicle_id == vehicle_id && (!open_only || alert.is_open())
+                        })
+                        .cloned()
+                        .collect();
+                alerts.sort_by_key(|alert| (alert.opened_at, alert.id));
+                Ok(alerts)
+            })
+            .map_err(DiagnosticRepositoryError::Database)
+    }
+
+    async fn resolve_alerts(
+        &self,
+        organization_id: Uuid,
+        dtc_event_id: Uuid,
+        resolved_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<u64, DiagnosticRepositoryError> {
+        self.store
+            .write("diagnostic_alerts.resolve_alerts", |tables| {
+                let mut resolved = 0;
+                for alert in &mut tables.diagnostic_alerts {
+                    if alert.organization_id == organization_id
+                        && alert.dtc_event_id == dtc_event_id
+                        && alert.is_open()
+                    {
+                        alert.resolved_at = Some(resolved_at);
+                        resolved += 1;
+                    }
+                }
+                Ok(resolved)
+            })
+            .map_err(DiagnosticRepositoryError::Database)
+    }
+}
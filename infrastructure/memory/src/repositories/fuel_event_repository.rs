@@ -0,0 +1,67 @@
+use crate::store::{MemoryStore, TenantRow, foreign_key, rows_of};
+use domain::fuel::{
+    entities::fuel_event::{FuelEvent, FuelEventIdentity},
+    repositories::fuel_event_repository::{FuelEventRepository, FuelEventRepositoryError},
+};
+
+#[derive(Debug, Clone)]
+pub struct MemoryFuelEventRepository {
+    store: MemoryStore,
+}
+
+impl MemoryFuelEventRepository {
+    pub fn new(store: &MemoryStore) -> Self {
+        MemoryFuelEventRepository {
+            store: store.clone(),
+        }
+    }
+}
+
+impl FuelEventRepository for MemoryFuelEventRepository {
+    async fn create(
+        &self,
+        organization_id: uuid::Uuid,
+        event: FuelEvent,
+    ) -> Result<FuelEventIdentity, FuelEventRepositoryError> {
+        self.store
+            .write("fuel_events.create", |tables| {
+                tables.organization(organization_id, "fuel_events_organization_id_fkey")?;
+                let mut identity = event.identity;
+                if tables.vehicle(identity.vehicle_id).is_none() {
+                    return Err(foreign_key("fuel_events_vehicle_id_fkey"));
+                }
+                tables.user(identity.driver_id, "fuel_events_driver_id_fkey")?;
+                tables.user(identity.created_by, "fuel_events_created_by_fkey")?;
+                tables.user(identity.updated_by, "fuel_events_updated_by_fkey")?;
+
+                identity.id = tables.next_id("fuel_events");
+                tables
+                    .fuel_events
+                    .push(TenantRow::new(organization_id, identity.clone()));
+                Ok(identity)
+            })
+            .map_err(FuelEventRepositoryError::Database)
+    }
+
+    async fn find_by_vehicle(
+        &self,
+        organization_id: uuid::Uuid,
+        vehicle_id: uuid::Uuid,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<FuelEventIdentity>, FuelEventRepositoryError> {
+        self.store
+            .read("fuel_events.find_by_vehicle", |tables| {
+                let mut events: Vec<FuelEventIdentity> =
+                    rows_of(&tables.fuel_events, organization_id)
+                        .filter(|event| event.vehicle_id == vehicle_id)
+                        .filter(|event| from.is_none_or(|from| event.performed_at >= from))
+                        .filter(|event| to.is_none_or(|to| event.performed_at <= to))
+                        .cloned()
+                        .collect();
+                events.sort_by_key(|event| (event.performed_at, event.id));
+                Ok(events)
+            })
+            .map_err(FuelEventRepositoryError::Database)
+    }
+}
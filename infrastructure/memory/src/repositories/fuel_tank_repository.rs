@@ -0,0 +1,68 @@
+use crate::store::{MemoryStore, TenantRow, foreign_key, rows_of};
+use domain::fuel::{
+    entities::fuel_tank::FuelTank,
+    repositories::fuel_tank_repository::{FuelTankRepository, FuelTankRepositoryError},
+};
+
+#[derive(Debug, Clone)]
+pub struct MemoryFuelTankRepository {
+    store: MemoryStore,
+}
+
+impl MemoryFuelTankRepository {
+    pub fn new(store: &MemoryStore) -> Self {
+        MemoryFuelTankRepository {
+            store: store.clone(),
+        }
+    }
+}
+
+impl FuelTankRepository for MemoryFuelTankRepository {
+    async fn find_by_vehicle(
+        &self,
+        organization_id: uuid::Uuid,
+        vehicle_id: uuid::Uuid,
+    ) -> Result<Vec<FuelTank>, FuelTankRepositoryError> {
+        self.store
+            .read("vehicle_fuel_tanks.find_by_vehicle", |tables| {
+                Ok(rows_of(&tables.fuel_tanks, organization_id)
+                    .filter(|tank| tank.vehicle_id == vehicle_id)
+                    .cloned()
+                    .collect())
+            })
+            .map_err(FuelTankRepositoryError::Database)
+    }
+
+    async fn save(
+        &self,
+        organization_id: uuid::Uuid,
+        tank: FuelTank,
+    ) -> Result<(), FuelTankRepositoryError> {
+        self.store
+            .write("vehicle_fuel_tanks.save", |tables| {
+                tables.organization(organization_id, "vehicle_fuel_tanks_organization_id_fkey")?;
+                if tables.vehicle(tank.vehicle_id).is_none() {
+                    return Err(foreign_key("vehicle_fuel_tanks_vehicle_id_fkey"));
+                }
+
+                // INSERT ... ON CONFLICT (vehicle_id, unit) DO UPDATE
+                match tables.fuel_tanks.iter_mut().find(|existing| {
+                    existing.vehicle_id == tank.vehicle_id && existing.unit == tank.unit
+                }) {
+                    Some(existing) if existing.organization_id == organization_id => {
+                        existing.capacity = tank.capacity
+                    }
+                    Some(_) => {
+                        return Err("new row violates row-level security policy for table \
+                                    \"vehicle_fuel_tanks\""
+                            .to_string());
+                    }
+                    None => tables
+                        .fuel_tanks
+                        .push(TenantRow::new(organization_id, tank)),
+                }
+                Ok(())
+            })
+            .map_err(FuelTankRepositoryError::Database)
+    }
+}
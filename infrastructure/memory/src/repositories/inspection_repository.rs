@@ -0,0 +1,250 @@
+use crate::store::{MemoryStore, TenantRow, foreign_key, rows_of, unique};
+use domain::inspection::{
+    entities::{
+        inspection_defect::InspectionDefect, inspection_report::InspectionReport,
+        inspection_template::InspectionTemplate,
+    },
+    repositories::inspection_repository::{InspectionRepository, InspectionRepositoryError},
+};
+use std::collections::HashSet;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct MemoryInspectionRepository {
+    store: MemoryStore,
+}
+
+impl MemoryInspectionRepository {
+    pub fn new(store: &MemoryStore) -> Self {
+        MemoryInspectionRepository {
+            store: store.clone(),
+        }
+    }
+}
+
+impl InspectionRepository for MemoryInspectionRepository {
+    async fn create_template(
+        &self,
+        organization_id: Uuid,
+        template: InspectionTemplate,
+    ) -> Result<InspectionTemplate, InspectionRepositoryError> {
+        let created = self
+            .store
+            .write("inspection_templates.create_template", |tables| {
+                tables
+                    .organization(organization_id, "inspection_templates_organization_id_fkey")?;
+                tables.user(template.created_by, "inspection_templates_created_by_fkey")?;
+                if rows_of(&tables.inspection_templates, organization_id)
+                    .any(|existing| existing.name == template.name)
+                {
+                    return Ok(Err(template.name.clone()));
+                }
+
+                tables
+                    .inspection_templates
+                    .push(TenantRow::new(organization_id, template.clone()));
+                Ok(Ok(template))
+            })
+            .map_err(InspectionRepositoryError::Database)?;
+        created.map_err(InspectionRepositoryError::AlreadyExists)
+    }
+
+    async fn find_template(
+        &self,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> Result<Option<InspectionTemplate>, InspectionRepositoryError> {
+        self.store
+            .read("inspection_templates.find_template", |tables| {
+                Ok(rows_of(&tables.inspection_templates, organization_id)
+                    .find(|template| template.id == id)
+                    .cloned())
+            })
+            .map_err(InspectionRepositoryError::Database)
+    }
+
+    async fn find_templates(
+        &self,
+        organization_id: Uuid,
+    ) -> Result<Vec<InspectionTemplate>, InspectionRepositoryError> {
+        self.store
+            .read("inspection_templates.find_templates", |tables| {
+                let mut templates: Vec<InspectionTemplate> =
+                    rows_of(&tables.inspection_templates, organization_id)
+                        .cloned()
+                        .collect();
+                templates.sort_by(|a, b| (&a.name, a.id).cmp(&(&b.name, b.id)));
+                Ok(templates)
+            })
+            .map_err(InspectionRepositoryError::Database)
+    }
+
+    async fn submit_report(
+        &self,
+        organization_id: Uuid,
+        report: InspectionReport,
+        defects: Vec<InspectionDefect>,
+    ) -> Result<InspectionReport, InspectionRepositoryError> {
+        // A failed write leaves the tables as they were: the report and its defects are atomic
+        self.store
+            .write("inspection_reports.submit_report", |tables| {
+                tables.organization(organization_id, "inspection_reports_organization_id_fkey")?;
+                if tables.vehicle(report.vehicle_id).is_none() {
+                    return Err(foreign_key("inspection_reports_vehicle_id_fkey"));
+                }
+                if !tables
+                    .inspection_templates
+                    .iter()
+                    .any(|template| template.id == report.template_id)
+                {
+                    return Err(foreign_key("inspection_reports_template_id_fkey"));
+                }
+                if !tables
+                    .vehicle_statuses
+                    .iter()
+                    .any(|row| row.status.id == report.vehicle_status_id)
+                {
+                    return Err(foreign_key("inspection_reports_vehicle_status_id_fkey"));
+                }
+                tables.user(report.submitted_by, "inspection_reports_submitted_by_fkey")?;
+                if tables
+                    .inspection_reports
+                    .iter()
+                    .any(|existing| existing.id == report.id)
+                {
+                    return Err(unique("inspection_reports_pkey"));
+                }
+                tables
+                    .inspection_reports
+                    .push(TenantRow::new(organization_id, report.clone()));
+
+                let mut items = HashSet::new();
+                for defect in &defects {
+                    if !tables
+                        .inspection_reports
+                        .iter()
+                        .any(|existing| existing.id == defect.report_id)
+                    {
+                        return Err(foreign_key("inspection_defects_report_id_fkey"));
+                    }
+                    if tables.vehicle(defect.vehicle_id).is_none() {
+                        return Err(foreign_key("inspection_defects_vehicle_id_fkey"));
+                    }
+                    if !items.insert((defect.report_id, defect.item_id))
+                        || tables.inspection_defects.iter().any(|existing| {
+                            existing.report_id == defect.report_id
+                                && existing.item_id == defect.item_id
+                        })
+                    {
+                        return Err(unique("inspection_defects_report_id_item_id_key"));
+                    }
+                    tables
+                        .inspection_defects
+                        .push(TenantRow::new(organization_id, defect.clone()));
+                }
+                Ok(report)
+            })
+            .map_err(InspectionRepositoryError::Database)
+    }
+
+    async fn find_report(
+        &self,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> Result<Option<InspectionReport>, InspectionRepositoryError> {
+        self.store
+            .read("inspection_reports.find_report", |tables| {
+                Ok(rows_of(&tables.inspection_reports, organization_id)
+                    .find(|report| report.id == id)
+                    .cloned())
+            })
+            .map_err(InspectionRepositoryError::Database)
+    }
+
+    async fn find_reports_by_vehicle(
+        &self,
+        organization_id: Uuid,
+        vehicle_id: Uuid,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<InspectionReport>, InspectionRepositoryError> {
+        self.store
+            .read("inspection_reports.find_reports_by_vehicle", |tables| {
+                let mut reports: Vec<InspectionReport> =
+                    rows_of(&tables.inspection_reports, organization_id)
+                        .filter(|report| {
+                            report.vehicle_id == vehicle_id
+                                && from.is_none_or(|from| report.submitted_at >= from)
+                                && to.is_none_or(|to| report.submitted_at <= to)
+                        })
+                        .cloned()
+                        .collect();
+                reports.sort_by_key(|report| (report.submitted_at, report.id));
+                Ok(reports)
+            })
+            .map_err(InspectionRepositoryError::Database)
+    }
+
+    async fn find_defect(
+        &self,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> Result<Option<InspectionDefect>, InspectionRepositoryError> {
+        self.store
+            .read("inspection_defects.find_defect", |tables| {
+                Ok(rows_of(&tables.inspection_defects, organization_id)
+                    .find(|defect| defect.id == id)
+                    .cloned())
+            })
+            .map_err(InspectionRepositoryError::Database)
+    }
+
+    async fn find_defects_by_vehicle(
+        &self,
+        organization_id: Uuid,
+        vehicle_id: Uuid,
+        open_only: bool,
+    ) -> Result<Vec<InspectionDefect>, InspectionRepositoryError> {
+        self.store
+            .read("inspection_defects.find_defects_by_vehicle", |tables| {
+                let mut defects: Vec<InspectionDefect> =
+                    rows_of(&tables.inspection_defects, organization_id)
+                        .filter(|defect| {
+                            defect.vehicle_id == vehicle_id && (!open_only || defect.is_open())
+                        })
+                        .cloned()
+                        .collect();
+                defects.sort_by_key(|defect| (defect.reported_at, defect.id));
+                Ok(defects)
+            })
+            .map_err(InspectionRepositoryError::Database)
+    }
+
+    async fn sign_off_defect(
+        &self,
+        organization_id: Uuid,
+        defect: InspectionDefect,
+    ) -> Result<bool, InspectionRepositoryError> {
+        self.store
+            .write("inspection_defects.sign_off_defect", |tables| {
+                if let Some(signed_off_by) = defect.signed_off_by {
+                    tables.user(signed_off_by, "inspection_defects_signed_off_by_fkey")?;
+                }
+                let Some(existing) = tables.inspection_defects.iter_mut().find(|existing| {
+                    existing.organization_id == organization_id
+                        && existing.id == defect.id
+                        && existing.is_open()
+                }) else {
+                    return Ok(false);
+                };
+
+                // Only the columns written by the UPDATE
+                existing.resolution = defect.resolution;
+                existing.signed_off_at = defect.signed_off_at;
+                existing.signed_off_by = defect.signed_off_by;
+                existing.sign_off_notes = defect.sign_off_notes.clone();
+                Ok(true)
+            })
+            .map_err(InspectionRepositoryError::Database)
+    }
+}
@@ -0,0 +1,114 @@
+use crate::store::{MemoryStore, TenantRow, foreign_key, rows_of, unique};
+use domain::maintenance::{
+    entities::maintenance_interval_template::{
+        MaintenanceIntervalTemplate, MaintenanceTemplateScope,
+    },
+    repositories::maintenance_interval_template_repository::{
+        MaintenanceIntervalTemplateRepository, MaintenanceIntervalTemplateRepositoryError,
+    },
+};
+
+#[derive(Debug, Clone)]
+pub struct MemoryMaintenanceIntervalTemplateRepository {
+    store: MemoryStore,
+}
+
+impl MemoryMaintenanceIntervalTemplateRepository {
+    pub fn new(store: &MemoryStore) -> Self {
+        MemoryMaintenanceIntervalTemplateRepository {
+            store: store.clone(),
+        }
+    }
+}
+
+/// Whether two scopes are the same for `maintenance_interval_templates_scope_key` (make and model
+/// compared ignoring case).
+pub(crate) fn same_scope(
+    left: &MaintenanceTemplateScope,
+    right: &MaintenanceTemplateScope,
+) -> bool {
+    let lower = |value: &Option<String>| value.as_deref().unwrap_or_default().to_lowercase();
+    lower(&left.make) == lower(&right.make)
+        && lower(&left.model) == lower(&right.model)
+        && left.engine_type == right.engine_type
+}
+
+impl MaintenanceIntervalTemplateRepository for MemoryMaintenanceIntervalTemplateRepository {
+    async fn create(
+        &self,
+        organization_id: uuid::Uuid,
+        template: MaintenanceIntervalTemplate,
+        user_id: uuid::Uuid,
+    ) -> Result<MaintenanceIntervalTemplate, MaintenanceIntervalTemplateRepositoryError> {
+        self.store
+            .write("maintenance_interval_templates.create", |tables| {
+                tables.organization(
+                    organization_id,
+                    "maintenance_interval_templates_organization_id_fkey",
+                )?;
+                if tables
+                    .maintenance_type(template.maintenance_type_id)
+                    .is_none()
+                {
+                    return Err(foreign_key(
+                        "maintenance_interval_templates_maintenance_type_id_fkey",
+                    ));
+                }
+                tables.user(user_id, "maintenance_interval_templates_created_by_fkey")?;
+                if tables
+                    .maintenance_interval_templates
+                    .iter()
+                    .any(|existing| {
+                        existing.maintenance_type_id == template.maintenance_type_id
+                            && existing.interval_type == template.interval_type
+                            && same_scope(&existing.scope, &template.scope)
+                    })
+                {
+                    return Err(unique("maintenance_interval_templates_scope_key"));
+                }
+
+                let mut template = template;
+                template.id = tables.next_id("maintenance_interval_templates");
+                tables
+                    .maintenance_interval_templates
+                    .push(TenantRow::new(organization_id, template.clone()));
+                Ok(template)
+            })
+            .map_err(MaintenanceIntervalTemplateRepositoryError::Database)
+    }
+
+    async fn find_all(
+        &self,
+        organization_id: uuid::Uuid,
+    ) -> Result<Vec<MaintenanceIntervalTemplate>, MaintenanceIntervalTemplateRepositoryError> {
+        self.store
+            .read("maintenance_interval_templates.find_all", |tables| {
+                Ok(
+                    rows_of(&tables.maintenance_interval_templates, organization_id)
+                        .cloned()
+                        .collect(),
+                )
+            })
+            .map_err(MaintenanceIntervalTemplateRepositoryError::Database)
+    }
+
+    async fn find_by_maintenance_type(
+        &self,
+        organization_id: uuid::Uuid,
+        maintenance_type_id: i32,
+    ) -> Result<Vec<MaintenanceIntervalTemplate>, MaintenanceIntervalTemplateRepositoryError> {
+        self.store
+            .read(
+                "maintenance_interval_templates.find_by_maintenance_type",
+                |tables| {
+                    Ok(
+                        rows_of(&tables.maintenance_interval_templates, organization_id)
+                            .filter(|template| template.maintenance_type_id == maintenance_type_id)
+                            .cloned()
+                            .collect(),
+                    )
+                },
+            )
+            .map_err(MaintenanceIntervalTemplateRepositoryError::Database)
+    }
+}
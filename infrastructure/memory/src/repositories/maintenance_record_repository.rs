@@ -0,0 +1,325 @@
+use crate::{
+    filters::{filter_expression_eval::matches, keyset::read_page},
+    repositories::vehicle_repository,
+    store::{MemoryStore, Tables, TenantRow, foreign_key, rows_of, unique},
+};
+use application::{
+    maintenance::{
+        models::maintenance_cost_sum::{
+            MaintenanceCostGroupBy, MaintenanceCostGroupKey, MaintenanceCostSum,
+            MaintenanceCostSums, VehicleDistance,
+        },
+        traits::{
+            maintenance_cost_repository::{
+                MaintenanceCostApplicationRepository, MaintenanceCostApplicationRepositoryError,
+            },
+            maintenance_record_repository::{
+                MaintenanceRecordApplicationRepository, MaintenanceRecordApplicationRepositoryError,
+            },
+        },
+    },
+    shared::{
+        filter_expression::filter_ast::{FilterExpression, FilterValue},
+        pagination::Keyset,
+    },
+    vehicle::filters::vehicle_filter::VehicleFilter,
+};
+use chrono::Datelike;
+use domain::maintenance::{
+    entities::maintenance_record::{MaintenanceRecord, MaintenanceRecordIdentity},
+    repositories::maintenance_record_repository::{
+        MaintenanceRecordRepository, MaintenanceRecordRepositoryError,
+    },
+    services::maintenance_cost_service::MaintenanceCostAggregator,
+};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+#[derive(Debug, Clone)]
+pub struct MemoryMaintenanceRecordRepository {
+    store: MemoryStore,
+}
+
+impl MemoryMaintenanceRecordRepository {
+    pub fn new(store: &MemoryStore) -> Self {
+        MemoryMaintenanceRecordRepository {
+            store: store.clone(),
+        }
+    }
+
+    /// The records of a vehicle matching the filter.
+    fn matching(
+        &self,
+        operation: &str,
+        organization_id: uuid::Uuid,
+        vehicle_id: uuid::Uuid,
+        filter: Option<&FilterExpression>,
+    ) -> Result<Vec<MaintenanceRecordIdentity>, MaintenanceRecordApplicationRepositoryError> {
+        self.store
+            .read(operation, |tables| {
+                Ok(rows_of(&tables.maintenance_records, organization_id)
+                    .filter(|record| record.vehicle_id == vehicle_id)
+                    .filter(|record| {
+                        filter.is_none_or(|filter| {
+                            matches(filter, &|column| column_value(record, column))
+                        })
+                    })
+                    .cloned()
+                    .collect())
+            })
+            .map_err(MaintenanceRecordApplicationRepositoryError::DatabaseError)
+    }
+}
+
+/// The records of the vehicles matching the filter performed within the range.
+fn records_of_vehicles<'a>(
+    tables: &'a Tables,
+    organization_id: uuid::Uuid,
+    vehicles: &VehicleFilter,
+    from: Option<chrono::DateTime<chrono::Utc>>,
+    to: Option<chrono::DateTime<chrono::Utc>>,
+) -> Result<Vec<&'a MaintenanceRecordIdentity>, String> {
+    let vehicle_ids = vehicle_repository::matching(tables, organization_id, vehicles)
+        .iter()
+        .map(|view| uuid::Uuid::parse_str(&view.id).map_err(|e| e.to_string()))
+        .collect::<Result<HashSet<_>, _>>()?;
+    Ok(rows_of(&tables.maintenance_records, organization_id)
+        .filter(|record| vehicle_ids.contains(&record.vehicle_id))
+        .filter(|record| from.is_none_or(|from| record.performed_at >= from))
+        .filter(|record| to.is_none_or(|to| record.performed_at <= to))
+        .collect())
+}
+
+/// Value of a column of `MAINTENANCE_RECORD_FILTER_SCHEMA`.
+fn column_value(record: &MaintenanceRecordIdentity, column: &str) -> Option<FilterValue> {
+    match column {
+        "maintenance_id" => Some(FilterValue::Integer(i64::from(record.maintenance_id))),
+        "details" => Some(FilterValue::Text(record.details.clone())),
+        "performed_at" => Some(FilterValue::Timestamp(record.performed_at)),
+        "created_at" => Some(FilterValue::Timestamp(record.created_at)),
+        _ => None,
+    }
+}
+
+impl MaintenanceRecordRepository for MemoryMaintenanceRecordRepository {
+    async fn create(
+        &self,
+        organization_id: uuid::Uuid,
+        record: MaintenanceRecordIdentity,
+    ) -> Result<MaintenanceRecordIdentity, MaintenanceRecordRepositoryError> {
+        self.store
+            .write("maintenance_records.create", |tables| {
+                // Check every foreign key
+                tables.organization(organization_id, "maintenance_records_organization_id_fkey")?;
+                tables.hydrate_record(&record)?;
+                tables.user(record.created_by, "maintenance_records_created_by_fkey")?;
+                if tables
+                    .maintenance_records
+                    .iter()
+                    .any(|existing| existing.id == record.id)
+                {
+                    return Err(unique("maintenance_records_pkey"));
+                }
+
+                tables
+                    .maintenance_records
+                    .push(TenantRow::new(organization_id, record.clone()));
+                Ok(record)
+            })
+            .map_err(MaintenanceRecordRepositoryError::Database)
+    }
+
+    async fn find_by_vehicle(
+        &self,
+        organization_id: uuid::Uuid,
+        vehicle_id: uuid::Uuid,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<MaintenanceRecord>, MaintenanceRecordRepositoryError> {
+        self.store
+            .read("maintenance_records.find_by_vehicle", |tables| {
+                let mut records = rows_of(&tables.maintenance_records, organization_id)
+                    .filter(|record| record.vehicle_id == vehicle_id)
+                    .filter(|record| from.is_none_or(|from| record.performed_at >= from))
+                    .filter(|record| to.is_none_or(|to| record.performed_at <= to))
+                    .map(|record| tables.hydrate_record(record))
+                    .collect::<Result<Vec<_>, _>>()?;
+                records.sort_by_key(|record| record.performed_at());
+                Ok(records)
+            })
+            .map_err(MaintenanceRecordRepositoryError::Database)
+    }
+
+    async fn find_latest_by_maintenance(
+        &self,
+        organization_id: uuid::Uuid,
+        maintenance_id: i32,
+    ) -> Result<Option<MaintenanceRecord>, MaintenanceRecordRepositoryError> {
+        self.store
+            .read("maintenance_records.find_latest_by_maintenance", |tables| {
+                rows_of(&tables.maintenance_records, organization_id)
+                    .filter(|record| record.maintenance_id == maintenance_id)
+                    .max_by_key(|record| record.performed_at)
+                    .map(|record| tables.hydrate_record(record))
+                    .transpose()
+            })
+            .map_err(MaintenanceRecordRepositoryError::Database)
+    }
+}
+
+impl MaintenanceRecordApplicationRepository for MemoryMaintenanceRecordRepository {
+    async fn get_page(
+        &self,
+        organization_id: uuid::Uuid,
+        vehicle_id: uuid::Uuid,
+        filter: Option<&FilterExpression>,
+        keyset: &Keyset,
+    ) -> Result<Vec<MaintenanceRecordIdentity>, MaintenanceRecordApplicationRepositoryError> {
+        let records = self.matching(
+            "maintenance_records.get_page",
+            organization_id,
+            vehicle_id,
+            filter,
+        )?;
+        Ok(read_page(records, keyset, |record| {
+            (
+                Some(FilterValue::Timestamp(record.performed_at)),
+                FilterValue::Text(record.id.to_string()),
+            )
+        }))
+    }
+
+    async fn count(
+        &self,
+        organization_id: uuid::Uuid,
+        vehicle_id: uuid::Uuid,
+        filter: Option<&FilterExpression>,
+    ) -> Result<u64, MaintenanceRecordApplicationRepositoryError> {
+        Ok(self
+            .matching(
+                "maintenance_records.count",
+                organization_id,
+                vehicle_id,
+                filter,
+            )?
+            .len() as u64)
+    }
+}
+
+impl MaintenanceCostApplicationRepository for MemoryMaintenanceRecordRepository {
+    async fn sum_costs(
+        &self,
+        organization_id: uuid::Uuid,
+        vehicles: &VehicleFilter,
+        group_by: MaintenanceCostGroupBy,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<MaintenanceCostSums, MaintenanceCostApplicationRepositoryError> {
+        self.store
+            .read("maintenance_records.sum_costs", |tables| {
+                let mut aggregator = MaintenanceCostAggregator::new();
+                let mut labels: HashMap<MaintenanceCostGroupKey, String> = HashMap::new();
+                let mut records_without_cost = 0;
+
+                for record in records_of_vehicles(tables, organization_id, vehicles, from, to)? {
+                    let Some(cost) = &record.cost else {
+                        records_without_cost += 1;
+                        continue;
+                    };
+                    let (key, label) = match group_by {
+                        MaintenanceCostGroupBy::Vehicle => (
+                            MaintenanceCostGroupKey::Vehicle {
+                                vehicle_id: record.vehicle_id,
+                            },
+                            tables
+                                .vehicle_of(organization_id, record.vehicle_id)
+                                .map(|vehicle| vehicle.license_plate.value().to_string()),
+                        ),
+                        MaintenanceCostGroupBy::MaintenanceType => {
+                            let maintenance_type_id =
+                                rows_of(&tables.maintenances, organization_id)
+                                    .find(|maintenance| maintenance.id == record.maintenance_id)
+                                    .ok_or_else(|| {
+                                        foreign_key("maintenance_records_maintenance_id_fkey")
+                                    })?
+                                    .maintenance_type_id;
+                            // Deprecated types included
+                            (
+                                MaintenanceCostGroupKey::MaintenanceType {
+                                    maintenance_type_id,
+                                },
+                                tables
+                                    .maintenance_type_of(organization_id, maintenance_type_id)
+                                    .map(|row| row.maintenance_type.name().to_string()),
+                            )
+                        }
+                        MaintenanceCostGroupBy::Month => (
+                            MaintenanceCostGroupKey::Month {
+                                year: record.performed_at.year(),
+                                month: record.performed_at.month(),
+                            },
+                            None,
+                        ),
+                    };
+                    aggregator.add(key, cost).map_err(|e| e.to_string())?;
+                    labels.insert(key, label.unwrap_or_default());
+                }
+
+                Ok(MaintenanceCostSums {
+                    groups: aggregator
+                        .into_totals()
+                        .into_iter()
+                        .map(|(key, totals)| MaintenanceCostSum {
+                            key,
+                            label: labels.get(&key).cloned().unwrap_or_default(),
+                            totals,
+                        })
+                        .collect(),
+                    records_without_cost,
+                })
+            })
+            .map_err(MaintenanceCostApplicationRepositoryError::DatabaseError)
+    }
+
+    async fn distance_by_vehicle(
+        &self,
+        organization_id: uuid::Uuid,
+        vehicles: &VehicleFilter,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<VehicleDistance>, MaintenanceCostApplicationRepositoryError> {
+        self.store
+            .read("maintenance_records.distance_by_vehicle", |tables| {
+                let costed: HashSet<uuid::Uuid> =
+                    records_of_vehicles(tables, organization_id, vehicles, from, to)?
+                        .into_iter()
+                        .filter(|record| record.cost.is_some())
+                        .map(|record| record.vehicle_id)
+                        .collect();
+
+                // Lowest and highest odometer readings of each vehicle
+                let mut readings: BTreeMap<uuid::Uuid, (i32, i32)> = BTreeMap::new();
+                for row in rows_of(&tables.vehicle_statuses, organization_id) {
+                    let status = &row.status;
+                    if costed.contains(&status.vehicle_id)
+                        && from.is_none_or(|from| status.performed_at >= from)
+                        && to.is_none_or(|to| status.performed_at <= to)
+                    {
+                        let (min, max) = readings
+                            .entry(status.vehicle_id)
+                            .or_insert((status.odometer, status.odometer));
+                        *min = (*min).min(status.odometer);
+                        *max = (*max).max(status.odometer);
+                    }
+                }
+
+                Ok(readings
+                    .into_iter()
+                    .map(|(vehicle_id, (min, max))| VehicleDistance {
+                        vehicle_id,
+                        distance_km: i64::from(max) - i64::from(min),
+                    })
+                    .collect())
+            })
+            .map_err(MaintenanceCostApplicationRepositoryError::DatabaseError)
+    }
+}
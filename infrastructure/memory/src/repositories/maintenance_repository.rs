@@ -0,0 +1,99 @@
+use crate::store::{MemoryStore, TenantRow, foreign_key, rows_of, unique};
+use domain::maintenance::{
+    entities::maintenance::Maintenance,
+    repositories::maintenance_repository::{MaintenanceRepository, MaintenanceRepositoryError},
+};
+
+#[derive(Debug, Clone)]
+pub struct MemoryMaintenanceRepository {
+    store: MemoryStore,
+}
+
+impl MemoryMaintenanceRepository {
+    pub fn new(store: &MemoryStore) -> Self {
+        MemoryMaintenanceRepository {
+            store: store.clone(),
+        }
+    }
+
+    fn find_by(
+        &self,
+        operation: &str,
+        organization_id: uuid::Uuid,
+        predicate: impl Fn(&Maintenance) -> bool,
+    ) -> Result<Vec<Maintenance>, MaintenanceRepositoryError> {
+        self.store
+            .read(operation, |tables| {
+                let maintenances = rows_of(&tables.maintenances, organization_id)
+                    .map(|identity| tables.hydrate_maintenance(identity))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(maintenances.into_iter().filter(predicate).collect())
+            })
+            .map_err(MaintenanceRepositoryError::Database)
+    }
+}
+
+impl MaintenanceRepository for MemoryMaintenanceRepository {
+    async fn find_by_vehicle(
+        &self,
+        organization_id: uuid::Uuid,
+        vehicle_id: uuid::Uuid,
+    ) -> Result<Vec<Maintenance>, MaintenanceRepositoryError> {
+        self.find_by(
+            "maintenances.find_by_vehicle",
+            organization_id,
+            |maintenance| maintenance.identity.vehicle_id == vehicle_id,
+        )
+    }
+
+    async fn find_by_maintenance_type(
+        &self,
+        organization_id: uuid::Uuid,
+        maintenance_type_id: i32,
+    ) -> Result<Vec<Maintenance>, MaintenanceRepositoryError> {
+        self.find_by(
+            "maintenances.find_by_maintenance_type",
+            organization_id,
+            |maintenance| maintenance.identity.maintenance_type_id == maintenance_type_id,
+        )
+    }
+
+    async fn create(
+        &self,
+        organization_id: uuid::Uuid,
+        maintenance: Maintenance,
+    ) -> Result<Maintenance, MaintenanceRepositoryError> {
+        self.store
+            .write("maintenances.create", |tables| {
+                tables.organization(organization_id, "maintenances_organization_id_fkey")?;
+                let mut identity = maintenance.identity;
+                if tables.vehicle(identity.vehicle_id).is_none() {
+                    return Err(foreign_key("maintenances_vehicle_id_fkey"));
+                }
+                if tables
+                    .maintenance_type(identity.maintenance_type_id)
+                    .is_none()
+                {
+                    return Err(foreign_key("maintenances_maintenance_type_id_fkey"));
+                }
+                tables.user(identity.created_by, "maintenances_created_by_fkey")?;
+                tables.user(identity.updated_by, "maintenances_updated_by_fkey")?;
+                if tables.maintenances.iter().any(|existing| {
+                    existing.vehicle_id == identity.vehicle_id
+                        && existing.maintenance_type_id == identity.maintenance_type_id
+                        && existing.interval_type == identity.interval_type
+                }) {
+                    return Err(unique(
+                        "maintenances_vehicle_id_maintenance_type_id_interval_type_key",
+                    ));
+                }
+
+                identity.id = tables.next_id("maintenances");
+                tables
+                    .maintenances
+                    .push(TenantRow::new(organization_id, identity.clone()));
+                tables.hydrate_maintenance(&identity)
+            })
+            .map_err(MaintenanceRepositoryError::Database)
+    }
+}
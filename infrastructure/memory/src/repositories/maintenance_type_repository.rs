@@ -0,0 +1,418 @@
+use crate::{
+    filters::{filter_expression_eval::matches, keyset::read_page},
+    store::{MaintenanceTypeRow, MemoryStore, Tables, TenantRow, rows_of},
+};
+use application::{
+    maintenance::traits::maintenance_type_repository::{
+        MaintenanceTypeApplicationRepository, MaintenanceTypeApplicationRepositoryError,
+    },
+    shared::{
+        filter_expression::filter_ast::{FilterExpression, FilterValue},
+        pagination::Keyset,
+    },
+};
+use domain::{
+    diagnostics::entities::diagnostic_rule::DiagnosticAction,
+    maintenance::{
+        entities::maintenance_type::{
+            MaintenanceType, MaintenanceTypeMerge, MaintenanceTypeUsage, MaintenanceTypeView,
+        },
+        repositories::maintenance_type_repository::{
+            MaintenanceTypeRepository, MaintenanceTypeRepositoryError,
+        },
+    },
+};
+use std::collections::HashSet;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct MemoryMaintenanceTypeRepository {
+    store: MemoryStore,
+}
+
+impl MemoryMaintenanceTypeRepository {
+    pub fn new(store: &MemoryStore) -> Self {
+        MemoryMaintenanceTypeRepository {
+            store: store.clone(),
+        }
+    }
+
+    /// Views of the maintenance types of an organization matching the filter, deprecated types
+    /// excluded.
+    fn matching(
+        &self,
+        operation: &str,
+        organization_id: Uuid,
+        filter: Option<&FilterExpression>,
+    ) -> Result<Vec<MaintenanceTypeView>, MaintenanceTypeApplicationRepositoryError> {
+        self.store
+            .read(operation, |tables| {
+                rows_of(&tables.maintenance_types, organization_id)
+                    .filter(|row| !row.maintenance_type.is_deprecated())
+                    .filter(|row| {
+                        filter.is_none_or(|filter| {
+                            matches(filter, &|column| column_value(row, column))
+                        })
+                    })
+                    .map(|row| tables.maintenance_type_view(row))
+                    .collect()
+            })
+            .map_err(MaintenanceTypeApplicationRepositoryError::DatabaseError)
+    }
+}
+
+/// Value of a column of `MAINTENANCE_TYPE_FILTER_SCHEMA`.
+fn column_value(row: &MaintenanceTypeRow, column: &str) -> Option<FilterValue> {
+    let maintenance_type = &row.maintenance_type;
+    match column {
+        "name" => Some(FilterValue::Text(maintenance_type.name().to_string())),
+        "description" => Some(FilterValue::Text(
+            maintenance_type.description().to_string(),
+        )),
+        "category" => maintenance_type
+            .category()
+            .map(|category| FilterValue::Enum(category.as_sql().to_string())),
+        "applicability" => Some(FilterValue::Enum(
+            maintenance_type.applicability().as_sql().to_string(),
+        )),
+        "created_at" => Some(FilterValue::Timestamp(row.created_at)),
+        "updated_at" => Some(FilterValue::Timestamp(row.updated_at)),
+        _ => None,
+    }
+}
+
+/// Whether another maintenance type of the organization has the name
+/// (`maintenance_types_organization_id_name_key`).
+fn name_taken(tables: &Tables, organization_id: Uuid, maintenance_type: &MaintenanceType) -> bool {
+    rows_of(&tables.maintenance_types, organization_id).any(|row| {
+        row.maintenance_type.id() != maintenance_type.id()
+            && row.maintenance_type.name() == maintenance_type.name()
+    })
+}
+
+fn row_mut(
+    tables: &mut Tables,
+    organization_id: Uuid,
+    id: i32,
+) -> Result<&mut MaintenanceTypeRow, String> {
+    tables
+        .maintenance_types
+        .iter_mut()
+        .filter(|row| row.organization_id == organization_id)
+        .map(|row| &mut row.row)
+        .find(|row| row.maintenance_type.id() == id)
+        .ok_or_else(|| format!("maintenance type {} not found", id))
+}
+
+impl MaintenanceTypeRepository for MemoryMaintenanceTypeRepository {
+    async fn create(
+        &self,
+        organization_id: Uuid,
+        maintenance_type: MaintenanceType,
+        user_id: Uuid,
+    ) -> Result<MaintenanceTypeView, MaintenanceTypeRepositoryError> {
+        let created = self
+            .store
+            .write("maintenance_types.create", |tables| {
+                tables.organization(organization_id, "maintenance_types_organization_id_fkey")?;
+                if name_taken(tables, organization_id, &maintenance_type) {
+                    return Ok(Err(maintenance_type.name().to_string()));
+                }
+                tables.user(user_id, "maintenance_types_created_by_fkey")?;
+
+                let mut maintenance_type = maintenance_type;
+                maintenance_type.set_id(tables.next_id("maintenance_types"));
+                let now = chrono::Utc::now();
+                let row = MaintenanceTypeRow {
+                    maintenance_type,
+                    created_at: now,
+                    created_by: user_id,
+                    updated_at: now,
+                    updated_by: user_id,
+                };
+                let view = tables.maintenance_type_view(&row)?;
+                tables
+                    .maintenance_types
+                    .push(TenantRow::new(organization_id, row));
+                Ok(Ok(view))
+            })
+            .map_err(MaintenanceTypeRepositoryError::Database)?;
+        created.map_err(MaintenanceTypeRepositoryError::AlreadyExists)
+    }
+
+    async fn get_by_id(
+        &self,
+        organization_id: Uuid,
+        id: i32,
+    ) -> Result<Option<MaintenanceType>, MaintenanceTypeRepositoryError> {
+        self.store
+            .read("maintenance_types.get_by_id", |tables| {
+                Ok(tables
+                    .maintenance_type_of(organization_id, id)
+                    .map(|row| row.maintenance_type.clone()))
+            })
+            .map_err(MaintenanceTypeRepositoryError::Database)
+    }
+
+    async fn get_view_by_id(
+        &self,
+        organization_id: Uuid,
+        id: i32,
+    ) -> Result<Option<MaintenanceTypeView>, MaintenanceTypeRepositoryError> {
+        self.store
+            .read("maintenance_types.get_view_by_id", |tables| {
+                tables
+                    .maintenance_type_of(organization_id, id)
+                    .map(|row| tables.maintenance_type_view(row))
+                    .transpose()
+            })
+            .map_err(MaintenanceTypeRepositoryError::Database)
+    }
+
+    async fn get_all_view(
+        &self,
+        organization_id: Uuid,
+    ) -> Result<Vec<MaintenanceTypeView>, MaintenanceTypeRepositoryError> {
+        self.matching("maintenance_types.get_all_view", organization_id, None)
+            .map_err(
+                |MaintenanceTypeApplicationRepositoryError::DatabaseError(e)| {
+                    MaintenanceTypeRepositoryError::Database(e)
+                },
+            )
+    }
+
+    async fn exists_by_name(
+        &self,
+        organization_id: Uuid,
+        name: &str,
+    ) -> Result<bool, MaintenanceTypeRepositoryError> {
+        self.store
+            .read("maintenance_types.exists_by_name", |tables| {
+                Ok(rows_of(&tables.maintenance_types, organization_id)
+                    .any(|row| row.maintenance_type.name() == name))
+            })
+            .map_err(MaintenanceTypeRepositoryError::Database)
+    }
+
+    async fn update(
+        &self,
+        organization_id: Uuid,
+        maintenance_type: MaintenanceType,
+        user_id: Uuid,
+    ) -> Result<MaintenanceTypeView, MaintenanceTypeRepositoryError> {
+        let updated = self
+            .store
+            .write("maintenance_types.update", |tables| {
+                if name_taken(tables, organization_id, &maintenance_type) {
+                    return Ok(Err(maintenance_type.name().to_string()));
+                }
+                tables.user(user_id, "maintenance_types_updated_by_fkey")?;
+
+                let row = row_mut(tables, organization_id, maintenance_type.id())?;
+                row.maintenance_type = maintenance_type;
+                row.updated_at = chrono::Utc::now();
+                row.updated_by = user_id;
+                let row = row.clone();
+                tables.maintenance_type_view(&row).map(Ok)
+            })
+            .map_err(MaintenanceTypeRepositoryError::Database)?;
+        updated.map_err(MaintenanceTypeRepositoryError::AlreadyExists)
+    }
+
+    async fn usage(
+        &self,
+        organization_id: Uuid,
+        id: i32,
+    ) -> Result<MaintenanceTypeUsage, MaintenanceTypeRepositoryError> {
+        self.store
+            .read("maintenance_types.usage", |tables| {
+                let rules: HashSet<i32> = rows_of(&tables.maintenances, organization_id)
+                    .filter(|maintenance| maintenance.maintenance_type_id == id)
+                    .map(|maintenance| maintenance.id)
+                    .collect();
+                Ok(MaintenanceTypeUsage {
+                    rules: rules.len() as u64,
+                    records: rows_of(&tables.maintenance_records, organization_id)
+                        .filter(|record| rules.contains(&record.maintenance_id))
+                        .count() as u64,
+                })
+            })
+            .map_err(MaintenanceTypeRepositoryError::Database)
+    }
+
+    async fn deprecate(
+        &self,
+        organization_id: Uuid,
+        maintenance_type: MaintenanceType,
+        user_id: Uuid,
+    ) -> Result<MaintenanceTypeView, MaintenanceTypeRepositoryError> {
+        self.store
+            .write("maintenance_types.deprecate", |tables| {
+                tables.user(user_id, "maintenance_types_deprecated_by_fkey")?;
+
+                let now = chrono::Utc::now();
+                let row = row_mut(tables, organization_id, maintenance_type.id())?;
+                row.maintenance_type
+                    .set_deprecated_at(Some(maintenance_type.deprecated_at().unwrap_or(now)));
+                row.updated_at = now;
+                row.updated_by = user_id;
+                let row = row.clone();
+                tables.maintenance_type_view(&row)
+            })
+            .map_err(MaintenanceTypeRepositoryError::Database)
+    }
+
+    async fn merge(
+        &self,
+        organization_id: Uuid,
+        source: MaintenanceType,
+        target: MaintenanceType,
+        user_id: Uuid,
+    ) -> Result<MaintenanceTypeMerge, MaintenanceTypeRepositoryError> {
+        self.store
+            .write("maintenance_types.merge", |tables| {
+                tables.user(user_id, "maintenance_types_updated_by_fkey")?;
+                row_mut(tables, organization_id, target.id())?;
+                row_mut(tables, organization_id, source.id())?;
+                let mut merge = MaintenanceTypeMerge::default();
+
+                // Rules: re-point them, or fold them into the target rule of the same vehicle
+                // and interval type
+                let source_rules: Vec<_> = rows_of(&tables.maintenances, organization_id)
+                    .filter(|rule| rule.maintenance_type_id == source.id())
+                    .cloned()
+                    .collect();
+                for rule in source_rules {
+                    let existing = rows_of(&tables.maintenances, organization_id)
+                        .find(|other| {
+                            other.maintenance_type_id == target.id()
+                                && other.vehicle_id == rule.vehicle_id
+                                && other.interval_type == rule.interval_type
+                        })
+                        .map(|other| other.id);
+                    match existing {
+                        Some(target_rule) => {
+                            for record in &mut tables.maintenance_records {
+                                if record.maintenance_id == rule.id {
+                                    record.maintenance_id = target_rule;
+                                    merge.records_moved += 1;
+                                }
+                            }
+                            tables.maintenances.retain(|other| other.id != rule.id);
+                            merge.rules_merged += 1;
+                        }
+                        None => {
+                            if let Some(moved) = tables
+                                .maintenances
+                                .iter_mut()
+                                .find(|other| other.id == rule.id)
+                            {
+                                moved.maintenance_type_id = target.id();
+                                moved.updated_at = chrono::Utc::now();
+                                moved.updated_by = user_id;
+                            }
+                            merge.rules_moved += 1;
+                        }
+                    }
+                }
+
+                // Default intervals: re-point them, dropping the duplicates of a target interval
+                let target_scopes: Vec<_> =
+                    rows_of(&tables.maintenance_interval_templates, organization_id)
+                        .filter(|template| template.maintenance_type_id == target.id())
+                        .map(|template| (template.scope.clone(), template.interval_type.clone()))
+                        .collect();
+                tables.maintenance_interval_templates.retain(|template| {
+                    template.maintenance_type_id != source.id()
+                        || !target_scopes.iter().any(|(scope, interval_type)| {
+                            super::maintenance_interval_template_repository::same_scope(
+                                scope,
+                                &template.scope,
+                            ) && *interval_type == template.interval_type
+                        })
+                });
+                for template in &mut tables.maintenance_interval_templates {
+                    if template.maintenance_type_id == source.id() {
+                        template.maintenance_type_id = target.id();
+                        merge.templates_moved += 1;
+                    }
+                }
+                // Diagnostic rules would be deleted with the source (ON DELETE CASCADE)
+                for rule in &mut tables.diagnostic_rules {
+                    if rule.action.maintenance_type_id() == Some(source.id()) {
+                        rule.action = DiagnosticAction::MaintenanceTask {
+                            maintenance_type_id: target.id(),
+                        };
+                    }
+                }
+
+                tables
+                    .maintenance_types
+                    .retain(|row| row.maintenance_type.id() != source.id());
+                Ok(merge)
+            })
+            .map_err(MaintenanceTypeRepositoryError::Database)
+    }
+
+    async fn delete(
+        &self,
+        organization_id: Uuid,
+        maintenance_type: MaintenanceType,
+        _user_id: Uuid,
+    ) -> Result<(), MaintenanceTypeRepositoryError> {
+        self.store
+            .write("maintenance_types.delete", |tables| {
+                // ON DELETE NO ACTION
+                if tables
+                    .maintenances
+                    .iter()
+                    .any(|rule| rule.maintenance_type_id == maintenance_type.id())
+                {
+                    return Err(
+                        "update or delete on table \"maintenance_types\" violates foreign \
+                                key constraint \"maintenances_maintenance_type_id_fkey\""
+                            .to_string(),
+                    );
+                }
+
+                row_mut(tables, organization_id, maintenance_type.id())?;
+                tables
+                    .maintenance_types
+                    .retain(|row| row.maintenance_type.id() != maintenance_type.id());
+                // ON DELETE CASCADE
+                tables
+                    .maintenance_interval_templates
+                    .retain(|template| template.maintenance_type_id != maintenance_type.id());
+                tables.delete_diagnostic_rules_of(maintenance_type.id());
+                Ok(())
+            })
+            .map_err(MaintenanceTypeRepositoryError::Database)
+    }
+}
+
+impl MaintenanceTypeApplicationRepository for MemoryMaintenanceTypeRepository {
+    async fn get_view_page(
+        &self,
+        organization_id: Uuid,
+        filter: Option<&FilterExpression>,
+        keyset: &Keyset,
+    ) -> Result<Vec<MaintenanceTypeView>, MaintenanceTypeApplicationRepositoryError> {
+        let views = self.matching("maintenance_types.get_view_page", organization_id, filter)?;
+        Ok(read_page(views, keyset, |view| {
+            (
+                Some(FilterValue::Text(view.name.clone())),
+                FilterValue::Integer(i64::from(view.id)),
+            )
+        }))
+    }
+
+    async fn count(
+        &self,
+        organization_id: Uuid,
+        filter: Option<&FilterExpression>,
+    ) -> Result<u64, MaintenanceTypeApplicationRepositoryError> {
+        Ok(self
+            .matching("maintenance_types.count", organization_id, filter)?
+            .len() as u64)
+    }
+}
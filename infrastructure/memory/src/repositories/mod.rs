@@ -0,0 +1,137 @@
+pub mod depot_repository;
+pub mod diagnostic_repository;
+pub mod fuel_event_repository;
+pub mod fuel_tank_repository;
+pub mod inspection_repository;
+pub mod maintenance_interval_template_repository;
+pub mod maintenance_record_repository;
+pub mod maintenance_repository;
+pub mod maintenance_type_repository;
+pub mod organization_repository;
+pub mod search_repository;
+pub mod telematics_device_repository;
+pub mod trip_repository;
+pub mod vehicle_movement_repository;
+pub mod vehicle_repository;
+pub mod vehicle_status_repository;
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        depot_repository::MemoryDepotRepository, diagnostic_repository::MemoryDiagnosticRepository,
+        inspection_repository::MemoryInspectionRepository,
+        maintenance_record_repository::MemoryMaintenanceRecordRepository,
+        maintenance_repository::MemoryMaintenanceRepository,
+        maintenance_type_repository::MemoryMaintenanceTypeRepository,
+        organization_repository::MemoryOrganizationRepository,
+        telematics_device_repository::MemoryTelematicsDeviceRepository,
+        trip_repository::MemoryTripRepository,
+        vehicle_movement_repository::MemoryVehicleMovementRepository,
+        vehicle_repository::MemoryVehicleRepository,
+        vehicle_status_repository::MemoryVehicleStatusRepository,
+    };
+    use crate::store::MemoryStore;
+    use conformance::ConformanceBackend;
+    use domain::user::entities::user::UserIdentity;
+
+    struct Backend {
+        store: MemoryStore,
+        organizations: MemoryOrganizationRepository,
+        vehicles: MemoryVehicleRepository,
+        vehicle_statuses: MemoryVehicleStatusRepository,
+        maintenance_types: MemoryMaintenanceTypeRepository,
+        maintenances: MemoryMaintenanceRepository,
+        maintenance_records: MemoryMaintenanceRecordRepository,
+        depots: MemoryDepotRepository,
+        vehicle_movements: MemoryVehicleMovementRepository,
+        telematics_devices: MemoryTelematicsDeviceRepository,
+        trips: MemoryTripRepository,
+        diagnostics: MemoryDiagnosticRepository,
+        inspections: MemoryInspectionRepository,
+    }
+
+    impl Backend {
+        async fn new() -> Self {
+            let store = MemoryStore::new();
+            Self {
+                organizations: MemoryOrganizationRepository::new(&store),
+                vehicles: MemoryVehicleRepository::new(&store),
+                vehicle_statuses: MemoryVehicleStatusRepository::new(&store),
+                maintenance_types: MemoryMaintenanceTypeRepository::new(&store),
+                maintenances: MemoryMaintenanceRepository::new(&store),
+                maintenance_records: MemoryMaintenanceRecordRepository::new(&store),
+                depots: MemoryDepotRepository::new(&store),
+                vehicle_movements: MemoryVehicleMovementRepository::new(&store),
+                telematics_devices: MemoryTelematicsDeviceRepository::new(&store),
+                trips: MemoryTripRepository::new(&store),
+                diagnostics: MemoryDiagnosticRepository::new(&store),
+                inspections: MemoryInspectionRepository::new(&store),
+                store,
+            }
+        }
+    }
+
+    impl ConformanceBackend for Backend {
+        type Organizations = MemoryOrganizationRepository;
+        type Vehicles = MemoryVehicleRepository;
+        type VehicleStatuses = MemoryVehicleStatusRepository;
+        type MaintenanceTypes = MemoryMaintenanceTypeRepository;
+        type Maintenances = MemoryMaintenanceRepository;
+        type MaintenanceRecords = MemoryMaintenanceRecordRepository;
+        type Depots = MemoryDepotRepository;
+        type VehicleMovements = MemoryVehicleMovementRepository;
+        type TelematicsDevices = MemoryTelematicsDeviceRepository;
+        type Trips = MemoryTripRepository;
+        type Diagnostics = MemoryDiagnosticRepository;
+        type Inspections = MemoryInspectionRepository;
+
+        fn organizations(&self) -> &Self::Organizations {
+            &self.organizations
+        }
+        fn vehicles(&self) -> &Self::Vehicles {
+            &self.vehicles
+        }
+        fn vehicle_statuses(&self) -> &Self::VehicleStatuses {
+            &self.vehicle_statuses
+        }
+        fn maintenance_types(&self) -> &Self::MaintenanceTypes {
+            &self.maintenance_types
+        }
+        fn maintenances(&self) -> &Self::Maintenances {
+            &self.maintenances
+        }
+        fn maintenance_records(&self) -> &Self::MaintenanceRecords {
+            &self.maintenance_records
+        }
+        fn depots(&self) -> &Self::Depots {
+            &self.depots
+        }
+        fn vehicle_movements(&self) -> &Self::VehicleMovements {
+            &self.vehicle_movements
+        }
+        fn telematics_devices(&self) -> &Self::TelematicsDevices {
+            &self.telematics_devices
+        }
+        fn trips(&self) -> &Self::Trips {
+            &self.trips
+        }
+
+        fn diagnostics(&self) -> &Self::Diagnostics {
+            &self.diagnostics
+        }
+
+        fn inspections(&self) -> &Self::Inspections {
+            &self.inspections
+        }
+
+        async fn insert_user(&self, user: UserIdentity) -> Result<(), String> {
+            self.store.insert_user(user);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn conformance() {
+        conformance::run_all(Backend::new).await;
+    }
+}
@@ -0,0 +1,64 @@
+use crate::store::{MemoryStore, unique};
+use domain::organization::{
+    entities::organization::Organization,
+    repositories::organization_repository::{OrganizationRepository, OrganizationRepositoryError},
+};
+
+#[derive(Debug, Clone)]
+pub struct MemoryOrganizationRepository {
+    store: MemoryStore,
+}
+
+impl MemoryOrganizationRepository {
+    pub fn new(store: &MemoryStore) -> Self {
+        MemoryOrganizationRepository {
+            store: store.clone(),
+        }
+    }
+}
+
+impl OrganizationRepository for MemoryOrganizationRepository {
+    async fn create(
+        &self,
+        organization: Organization,
+    ) -> Result<Organization, OrganizationRepositoryError> {
+        let created = self
+            .store
+            .write("organizations.create", |tables| {
+                if tables
+                    .organizations
+                    .iter()
+                    .any(|existing| existing.name == organization.name)
+                {
+                    return Ok(Err(organization.name));
+                }
+                if tables
+                    .organizations
+                    .iter()
+                    .any(|existing| existing.id == organization.id)
+                {
+                    return Err(unique("organizations_pkey"));
+                }
+
+                tables.organizations.push(organization.clone());
+                Ok(Ok(organization))
+            })
+            .map_err(OrganizationRepositoryError::Database)?;
+        created.map_err(OrganizationRepositoryError::AlreadyExists)
+    }
+
+    async fn find_by_id(
+        &self,
+        id: uuid::Uuid,
+    ) -> Result<Option<Organization>, OrganizationRepositoryError> {
+        self.store
+            .read("organizations.find_by_id", |tables| {
+                Ok(tables
+                    .organizations
+                    .iter()
+                    .find(|organization| organization.id == id)
+                    .cloned())
+            })
+            .map_err(OrganizationRepositoryError::Database)
+    }
+}
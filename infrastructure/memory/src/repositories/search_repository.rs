@@ -0,0 +1,323 @@
+use crate::store::{MemoryStore, Tables, rows_of};
+use application::search::{
+    models::{
+        search_hit::{SearchHit, SearchHitKind, SearchMatch},
+        text_search::{HIGHLIGHT_END, HIGHLIGHT_START, TextSearch},
+    },
+    traits::search_repository::{SearchRepository, SearchRepositoryError},
+};
+use std::collections::HashSet;
+
+/// Length of the snippets, in words.
+const SNIPPET_WORDS: usize = 12;
+
+#[derive(Debug, Clone)]
+pub struct MemorySearchRepository {
+    store: MemoryStore,
+}
+
+impl MemorySearchRepository {
+    pub fn new(store: &MemoryStore) -> Self {
+        MemorySearchRepository {
+            store: store.clone(),
+        }
+    }
+}
+
+fn words(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Whether a word of a text matches a word of the term (a prefix stands for the stemmed forms).
+fn word_matches(word: &str, term_word: &str) -> bool {
+    word.starts_with(term_word)
+}
+
+/// Full-text rank of texts: `None` unless every word of the term is found, otherwise the share
+/// of the words of the texts that match (the earlier texts weigh more, like `setweight`).
+pub(crate) fn rank(term: &str, texts: &[&str]) -> Option<f32> {
+    let term_words = words(term);
+    if term_words.is_empty() {
+        return None;
+    }
+    let texts: Vec<Vec<String>> = texts.iter().map(|text| words(text)).collect();
+    let found = term_words.iter().all(|term_word| {
+        texts
+            .iter()
+            .flatten()
+            .any(|word| word_matches(word, term_word))
+    });
+    if !found {
+        return None;
+    }
+
+    let mut rank = 0.0;
+    for (position, text) in texts.iter().enumerate() {
+        let matched = text
+            .iter()
+            .filter(|word| {
+                term_words
+                    .iter()
+                    .any(|term_word| word_matches(word, term_word))
+            })
+            .count();
+        rank += matched as f32 / (1.0 + text.len() as f32) / (1.0 + position as f32);
+    }
+    Some(rank)
+}
+
+fn trigrams(text: &str) -> HashSet<String> {
+    let mut trigrams = HashSet::new();
+    for word in words(text) {
+        let padded: Vec<char> = format!("  {} ", word).chars().collect();
+        for window in padded.windows(3) {
+            trigrams.insert(window.iter().collect());
+        }
+    }
+    trigrams
+}
+
+/// Trigram similarity of two texts, like `pg_trgm`'s `similarity`.
+pub(crate) fn similarity(left: &str, right: &str) -> f32 {
+    let (left, right) = (trigrams(left), trigrams(right));
+    let union = left.union(&right).count();
+    match union {
+        0 => 0.0,
+        union => left.intersection(&right).count() as f32 / union as f32,
+    }
+}
+
+/// Excerpt of a text with the words matching the term highlighted, like `ts_headline`.
+fn snippet(term: &str, text: &str) -> String {
+    let term_words = words(term);
+    let text_words: Vec<&str> = text.split_whitespace().collect();
+    let highlighted = |word: &str| {
+        words(word).iter().any(|word| {
+            term_words
+                .iter()
+                .any(|term_word| word_matches(word, term_word))
+        })
+    };
+    let first = text_words
+        .iter()
+        .position(|word| highlighted(word))
+        .unwrap_or(0);
+    let start = first.saturating_sub(SNIPPET_WORDS / 2);
+
+    text_words
+        .iter()
+        .skip(start)
+        .take(SNIPPET_WORDS)
+        .map(|word| match highlighted(word) {
+            true => format!("{}{}{}", HIGHLIGHT_START, word, HIGHLIGHT_END),
+            false => word.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// A searchable row: the texts matched with full-text search (the first is the snippet source)
+/// and the values matched with fuzzy search.
+struct Candidate {
+    id: String,
+    title: String,
+    texts: Vec<String>,
+    fuzzy: Vec<String>,
+}
+
+fn hits(kind: SearchHitKind, search: &TextSearch, candidates: Vec<Candidate>) -> Vec<SearchHit> {
+    let mut hits: Vec<SearchHit> = candidates
+        .into_iter()
+        .filter_map(|candidate| {
+            let texts: Vec<&str> = candidate.texts.iter().map(String::as_str).collect();
+            let (rank, matched_by) = match rank(&search.term, &texts) {
+                Some(rank) => (rank, SearchMatch::FullText),
+                None => {
+                    let similarity = candidate
+                        .fuzzy
+                        .iter()
+                        .map(|value| similarity(&search.term, value))
+                        .fold(0.0, f32::max);
+                    if similarity < search.min_similarity || similarity == 0.0 {
+                        return None;
+                    }
+                    // Fuzzy hits rank below full-text hits
+                    (similarity / 10.0, SearchMatch::Fuzzy)
+                }
+            };
+            let source = texts
+                .iter()
+                .find(|text| has_match(&search.term, text))
+                .or(texts.first())
+                .copied()
+                .unwrap_or_default();
+            Some(SearchHit {
+                kind,
+                id: candidate.id,
+                title: candidate.title,
+                snippet: snippet(&search.term, source),
+                rank,
+                matched_by,
+            })
+        })
+        .collect();
+    hits.sort_by(|a, b| b.rank.total_cmp(&a.rank));
+    hits.truncate(search.limit);
+    hits
+}
+
+fn has_match(term: &str, text: &str) -> bool {
+    let term_words = words(term);
+    words(text).iter().any(|word| {
+        term_words
+            .iter()
+            .any(|term_word| word_matches(word, term_word))
+    })
+}
+
+/// Whether the vehicle is based at a depot of `depot_scope`, any vehicle without a scope.
+fn in_scope(
+    tables: &Tables,
+    organization_id: uuid::Uuid,
+    vehicle_id: uuid::Uuid,
+    depot_scope: Option<&[uuid::Uuid]>,
+) -> bool {
+    depot_scope.is_none_or(|scope| {
+        tables
+            .home_depot_of(organization_id, vehicle_id)
+            .is_some_and(|depot_id| scope.contains(&depot_id))
+    })
+}
+
+impl SearchRepository for MemorySearchRepository {
+    async fn search_maintenance_types(
+        &self,
+        organization_id: uuid::Uuid,
+        search: &TextSearch,
+    ) -> Result<Vec<SearchHit>, SearchRepositoryError> {
+        let candidates = self
+            .store
+            .read("search.maintenance_types", |tables| {
+                Ok(rows_of(&tables.maintenance_types, organization_id)
+                    .map(|row| &row.maintenance_type)
+                    .filter(|maintenance_type| !maintenance_type.is_deprecated())
+                    .map(|maintenance_type| Candidate {
+                        id: maintenance_type.id().to_string(),
+                        title: maintenance_type.name().to_string(),
+                        texts: vec![
+                            maintenance_type.name().to_string(),
+                            maintenance_type.description().to_string(),
+                        ],
+                        fuzzy: vec![maintenance_type.name().to_string()],
+                    })
+                    .collect())
+            })
+            .map_err(SearchRepositoryError::DatabaseError)?;
+        Ok(hits(SearchHitKind::MaintenanceType, search, candidates))
+    }
+
+    async fn search_vehicles(
+        &self,
+        organization_id: uuid::Uuid,
+        search: &TextSearch,
+        depot_scope: Option<&[uuid::Uuid]>,
+    ) -> Result<Vec<SearchHit>, SearchRepositoryError> {
+        let candidates = self
+            .store
+            .read("search.vehicles", |tables| {
+                Ok(rows_of(&tables.vehicles, organization_id)
+                    .filter(|vehicle| in_scope(tables, organization_id, vehicle.id, depot_scope))
+                    .map(|vehicle| Candidate {
+                        id: vehicle.id.to_string(),
+                        title: format!(
+                            "{} {} {}",
+                            vehicle.make,
+                            vehicle.model,
+                            vehicle.license_plate.value()
+                        ),
+                        texts: vec![
+                            format!("{} {}", vehicle.make, vehicle.model),
+                            vehicle.license_plate.value().to_string(),
+                            vehicle.vin.value().to_string(),
+                        ],
+                        fuzzy: vec![
+                            format!("{} {}", vehicle.make, vehicle.model),
+                            vehicle.license_plate.value().to_string(),
+                            vehicle.vin.value().to_string(),
+                        ],
+                    })
+                    .collect())
+            })
+            .map_err(SearchRepositoryError::DatabaseError)?;
+        Ok(hits(SearchHitKind::Vehicle, search, candidates))
+    }
+
+    async fn search_maintenance_records(
+        &self,
+        organization_id: uuid::Uuid,
+        search: &TextSearch,
+        depot_scope: Option<&[uuid::Uuid]>,
+    ) -> Result<Vec<SearchHit>, SearchRepositoryError> {
+        let candidates = self
+            .store
+            .read("search.maintenance_records", |tables| {
+                rows_of(&tables.maintenance_records, organization_id)
+                    .filter(|record| {
+                        in_scope(tables, organization_id, record.vehicle_id, depot_scope)
+                    })
+                    .map(|record| {
+                        let record = tables.hydrate_record(record)?;
+                        let maintenance_type = tables
+                            .maintenance_type(record.maintenance.maintenance_type_id)
+                            .map(|row| row.maintenance_type.name().to_string())
+                            .unwrap_or_default();
+                        Ok(Candidate {
+                            id: record.id().to_string(),
+                            title: format!(
+                                "{} {}",
+                                maintenance_type,
+                                record.vehicle.license_plate.value()
+                            ),
+                            texts: vec![record.details().to_string()],
+                            fuzzy: vec![record.details().to_string()],
+                        })
+                    })
+                    .collect()
+            })
+            .map_err(SearchRepositoryError::DatabaseError)?;
+        Ok(hits(SearchHitKind::MaintenanceRecord, search, candidates))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rank_requires_every_word() {
+        assert!(rank("oil change", &["Oil change", "Engine oil and filter"]).is_some());
+        assert!(rank("oil brakes", &["Oil change", "Engine oil and filter"]).is_none());
+        assert!(
+            rank("oil", &["Oil change", ""]).unwrap()
+                > rank("oil", &["Tire rotation", "Check the oil level"]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_snippet_highlights_matched_words() {
+        assert_eq!(
+            snippet("filter", "Replaced the oil filter, air filters checked"),
+            "Replaced the oil <mark>filter,</mark> air <mark>filters</mark> checked"
+        );
+    }
+
+    #[test]
+    fn test_similarity_of_typos() {
+        assert_eq!(similarity("Camry", "camry"), 1.0);
+        assert!(similarity("Camri", "Toyota Camry") > 0.2);
+        assert_eq!(similarity("Volvo", "Camry"), 0.0);
+    }
+}
@@ -0,0 +1,88 @@
+use crate::store::{MemoryStore, TenantRow, foreign_key, rows_of};
+use domain::telematics::{
+    entities::telematics_device::TelematicsDevice,
+    repositories::telematics_device_repository::{
+        TelematicsDeviceRepository, TelematicsDeviceRepositoryError,
+    },
+    value_types::device_id::DeviceId,
+};
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct MemoryTelematicsDeviceRepository {
+    store: MemoryStore,
+}
+
+impl MemoryTelematicsDeviceRepository {
+    pub fn new(store: &MemoryStore) -> Self {
+        MemoryTelematicsDeviceRepository {
+            store: store.clone(),
+        }
+    }
+}
+
+impl TelematicsDeviceRepository for MemoryTelematicsDeviceRepository {
+    async fn register(
+        &self,
+        organization_id: Uuid,
+        device: TelematicsDevice,
+    ) -> Result<TelematicsDevice, TelematicsDeviceRepositoryError> {
+        // A device registered twice is reported with its id, not as a database error
+        let registered = self
+            .store
+            .write("telematics_devices.register", |tables| {
+                tables.organization(organization_id, "telematics_devices_organization_id_fkey")?;
+                if !tables
+                    .vehicles
+                    .iter()
+                    .any(|vehicle| vehicle.id == device.vehicle_id)
+                {
+                    return Err(foreign_key("telematics_devices_vehicle_id_fkey"));
+                }
+                if rows_of(&tables.telematics_devices, organization_id)
+                    .any(|existing| existing.device_id == device.device_id)
+                {
+                    return Ok(Err(device.device_id.value().to_string()));
+                }
+
+                tables
+                    .telematics_devices
+                    .push(TenantRow::new(organization_id, device.clone()));
+                Ok(Ok(device))
+            })
+            .map_err(TelematicsDeviceRepositoryError::Database)?;
+        registered.map_err(TelematicsDeviceRepositoryError::AlreadyExists)
+    }
+
+    async fn find_by_device_id(
+        &self,
+        organization_id: Uuid,
+        device_id: &DeviceId,
+    ) -> Result<Option<TelematicsDevice>, TelematicsDeviceRepositoryError> {
+        self.store
+            .read("telematics_devices.find_by_device_id", |tables| {
+                Ok(rows_of(&tables.telematics_devices, organization_id)
+                    .find(|device| &device.device_id == device_id)
+                    .cloned())
+            })
+            .map_err(TelematicsDeviceRepositoryError::Database)
+    }
+
+    async fn find_by_vehicle(
+        &self,
+        organization_id: Uuid,
+        vehicle_id: Uuid,
+    ) -> Result<Vec<TelematicsDevice>, TelematicsDeviceRepositoryError> {
+        self.store
+            .read("telematics_devices.find_by_vehicle", |tables| {
+                let mut devices: Vec<TelematicsDevice> =
+                    rows_of(&tables.telematics_devices, organization_id)
+                        .filter(|device| device.vehicle_id == vehicle_id)
+                        .cloned()
+                        .collect();
+                devices.sort_by(|a, b| a.device_id.value().cmp(b.device_id.value()));
+                Ok(devices)
+            })
+            .map_err(TelematicsDeviceRepositoryError::Database)
+    }
+}
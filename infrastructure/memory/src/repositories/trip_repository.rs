@@ -0,0 +1,82 @@
+use crate::store::{MemoryStore, TenantRow, foreign_key, rows_of};
+use domain::trip::{
+    entities::trip::Trip,
+    repositories::trip_repository::{TripRepository, TripRepositoryError},
+};
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct MemoryTripRepository {
+    store: MemoryStore,
+}
+
+impl MemoryTripRepository {
+    pub fn new(store: &MemoryStore) -> Self {
+        MemoryTripRepository {
+            store: store.clone(),
+        }
+    }
+}
+
+impl TripRepository for MemoryTripRepository {
+    async fn create(&self, organization_id: Uuid, trip: Trip) -> Result<Trip, TripRepositoryError> {
+        self.store
+            .write("trips.create", |tables| {
+                tables.organization(organization_id, "trips_organization_id_fkey")?;
+                if tables.vehicle(trip.vehicle_id).is_none() {
+                    return Err(foreign_key("trips_vehicle_id_fkey"));
+                }
+                if let Some(driver_id) = trip.driver_id {
+                    tables.user(driver_id, "trips_driver_id_fkey")?;
+                }
+                if trip.ended_at <= trip.started_at {
+                    return Err(
+                        "new row violates check constraint \"trips_period_check\"".to_string()
+                    );
+                }
+
+                tables
+                    .trips
+                    .push(TenantRow::new(organization_id, trip.clone()));
+                Ok(trip)
+            })
+            .map_err(TripRepositoryError::Database)
+    }
+
+    async fn find_by_id(
+        &self,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> Result<Option<Trip>, TripRepositoryError> {
+        self.store
+            .read("trips.find_by_id", |tables| {
+                Ok(rows_of(&tables.trips, organization_id)
+                    .find(|trip| trip.id == id)
+                    .cloned())
+            })
+            .map_err(TripRepositoryError::Database)
+    }
+
+    async fn find_by_vehicle(
+        &self,
+        organization_id: Uuid,
+        vehicle_id: Uuid,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<Trip>, TripRepositoryError> {
+        self.store
+            .read("trips.find_by_vehicle", |tables| {
+                let mut trips: Vec<Trip> = rows_of(&tables.trips, organization_id)
+                    .filter(|trip| {
+                        trip.vehicle_id == vehicle_id
+                            && from.is_none_or(|from| trip.ended_at >= from)
+                            && to.is_none_or(|to| trip.started_at <= to)
+                    })
+                    .cloned()
+                    .collect();
+                trips.sort_by_key(|trip| (trip.started_at, trip.id));
+                Ok(trips)
+            })
+            .map_err(TripRepositoryError::Database)
+    }
+}
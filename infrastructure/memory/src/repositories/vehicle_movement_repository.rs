@@ -0,0 +1,100 @@
+use crate::store::{MemoryStore, TenantRow, VehicleMovementRow, foreign_key, rows_of, unique};
+use domain::depot::{
+    entities::vehicle_movement::VehicleMovement,
+    repositories::vehicle_movement_repository::{
+        VehicleMovementRepository, VehicleMovementRepositoryError,
+    },
+};
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct MemoryVehicleMovementRepository {
+    store: MemoryStore,
+}
+
+impl MemoryVehicleMovementRepository {
+    pub fn new(store: &MemoryStore) -> Self {
+        MemoryVehicleMovementRepository {
+            store: store.clone(),
+        }
+    }
+}
+
+impl VehicleMovementRepository for MemoryVehicleMovementRepository {
+    async fn record(
+        &self,
+        organization_id: Uuid,
+        movement: VehicleMovement,
+    ) -> Result<VehicleMovement, VehicleMovementRepositoryError> {
+        self.store
+            .write("vehicle_movements.record", |tables| {
+                tables.organization(organization_id, "vehicle_movements_organization_id_fkey")?;
+                if tables.vehicle(movement.vehicle_id).is_none() {
+                    return Err(foreign_key("vehicle_movements_vehicle_id_fkey"));
+                }
+                let depot_exists = |id: Uuid| tables.depots.iter().any(|depot| depot.id == id);
+                if movement.from_depot_id.is_some_and(|id| !depot_exists(id)) {
+                    return Err(foreign_key("vehicle_movements_from_depot_id_fkey"));
+                }
+                if !depot_exists(movement.to_depot_id) {
+                    return Err(foreign_key("vehicle_movements_to_depot_id_fkey"));
+                }
+                tables.user(movement.moved_by, "vehicle_movements_moved_by_fkey")?;
+                if tables
+                    .vehicle_movements
+                    .iter()
+                    .any(|row| row.movement.id == movement.id)
+                {
+                    return Err(unique("vehicle_movements_pkey"));
+                }
+
+                for row in &mut tables.vehicle_movements {
+                    if row.movement.vehicle_id == movement.vehicle_id {
+                        row.latest = false;
+                    }
+                }
+                tables.vehicle_movements.push(TenantRow::new(
+                    organization_id,
+                    VehicleMovementRow {
+                        movement: movement.clone(),
+                        latest: true,
+                    },
+                ));
+                Ok(movement)
+            })
+            .map_err(VehicleMovementRepositoryError::Database)
+    }
+
+    async fn find_latest(
+        &self,
+        organization_id: Uuid,
+        vehicle_id: Uuid,
+    ) -> Result<Option<VehicleMovement>, VehicleMovementRepositoryError> {
+        self.store
+            .read("vehicle_movements.find_latest", |tables| {
+                Ok(rows_of(&tables.vehicle_movements, organization_id)
+                    .find(|row| row.latest && row.movement.vehicle_id == vehicle_id)
+                    .map(|row| row.movement.clone()))
+            })
+            .map_err(VehicleMovementRepositoryError::Database)
+    }
+
+    async fn find_by_vehicle(
+        &self,
+        organization_id: Uuid,
+        vehicle_id: Uuid,
+    ) -> Result<Vec<VehicleMovement>, VehicleMovementRepositoryError> {
+        self.store
+            .read("vehicle_movements.find_by_vehicle", |tables| {
+                // Moves at the same time keep the order they were recorded in (stable sort)
+                let mut movements: Vec<_> = rows_of(&tables.vehicle_movements, organization_id)
+                    .map(|row| &row.movement)
+                    .filter(|movement| movement.vehicle_id == vehicle_id)
+                    .cloned()
+                    .collect();
+                movements.sort_by_key(|movement| movement.moved_at);
+                Ok(movements)
+            })
+            .map_err(VehicleMovementRepositoryError::Database)
+    }
+}
@@ -0,0 +1,356 @@
+use crate::{
+    filters::{
+        filter_expression_eval::matches,
+        keyset::{read_page, sort_rows},
+    },
+    repositories::search_repository::rank,
+    store::{MemoryStore, Tables, TenantRow, rows_of},
+};
+use application::{
+    shared::{filter_expression::filter_ast::FilterValue, pagination::Keyset},
+    vehicle::{
+        filters::vehicle_filter::{VehicleFilter, VehicleSortBy},
+        models::vehicle::VehicleView,
+        traits::vehicle_repository::{
+            VehicleApplicationRepository, VehicleApplicationRepositoryError,
+        },
+    },
+};
+use domain::vehicle::{
+    entities::vehicle::{NewVehicle, Vehicle, VehicleIdentity},
+    repositories::vehicle_repository::{
+        TakenIdentifiers, VehicleRepository, VehicleRepositoryError,
+    },
+};
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct MemoryVehicleRepository {
+    store: MemoryStore,
+}
+
+impl MemoryVehicleRepository {
+    pub fn new(store: &MemoryStore) -> Self {
+        MemoryVehicleRepository {
+            store: store.clone(),
+        }
+    }
+}
+
+fn view(
+    vehicle: &VehicleIdentity,
+    home_depot_id: Option<Uuid>,
+    search_rank: Option<f32>,
+) -> VehicleView {
+    VehicleView {
+        id: vehicle.id.to_string(),
+        make: vehicle.make.clone(),
+        model: vehicle.model.clone(),
+        year: vehicle.year,
+        vin: vehicle.vin.value().to_string(),
+        license_plate: vehicle.license_plate.value().to_string(),
+        country: vehicle.country.value().to_string(),
+        engine_type: vehicle.powertrain.engine_type().as_str().to_string(),
+        battery_capacity: vehicle.powertrain.battery_capacity(),
+        tank_capacity: vehicle.powertrain.tank_capacity(),
+        home_depot_id,
+        search_rank,
+        created_at: vehicle.created_at,
+        updated_at: vehicle.updated_at,
+    }
+}
+
+/// Value of a column of `VEHICLE_FILTER_SCHEMA`.
+fn column_value(vehicle: &VehicleIdentity, column: &str) -> Option<FilterValue> {
+    match column {
+        "make" => Some(FilterValue::Text(vehicle.make.clone())),
+        "model" => Some(FilterValue::Text(vehicle.model.clone())),
+        "year" => Some(FilterValue::Integer(i64::from(vehicle.year))),
+        "vin" => Some(FilterValue::Text(vehicle.vin.value().to_string())),
+        "license_plate" => Some(FilterValue::Text(vehicle.license_plate.value().to_string())),
+        "country" => Some(FilterValue::Text(vehicle.country.value().to_string())),
+        "engine_type" => Some(FilterValue::Enum(
+            vehicle.powertrain.engine_type().as_sql().to_string(),
+        )),
+        "battery_capacity" => vehicle
+            .powertrain
+            .battery_capacity()
+            .map(FilterValue::Decimal),
+        "tank_capacity" => vehicle.powertrain.tank_capacity().map(FilterValue::Decimal),
+        "created_at" => Some(FilterValue::Timestamp(vehicle.created_at)),
+        "updated_at" => Some(FilterValue::Timestamp(vehicle.updated_at)),
+        _ => None,
+    }
+}
+
+/// Sort value of a vehicle, typed from the text kept in cursors (`VehicleSortBy::keyset_value`).
+fn sort_value(sort_by: VehicleSortBy, view: &VehicleView) -> Option<FilterValue> {
+    let text = sort_by.keyset_value(view)?;
+    Some(match sort_by {
+        VehicleSortBy::Year => FilterValue::Integer(i64::from(view.year)),
+        VehicleSortBy::CreatedAt => FilterValue::Timestamp(view.created_at),
+        VehicleSortBy::UpdatedAt => FilterValue::Timestamp(view.updated_at),
+        VehicleSortBy::Relevance => text
+            .parse()
+            .map(FilterValue::Decimal)
+            .unwrap_or(FilterValue::Text(text)),
+        _ => FilterValue::Text(text),
+    })
+}
+
+/// Returns the views of the vehicles of an organization matching the filter (fixed fields, home
+/// depot, search and expression).
+pub(crate) fn matching(
+    tables: &Tables,
+    organization_id: Uuid,
+    filter: &VehicleFilter,
+) -> Vec<VehicleView> {
+    // The depots of the region, when filtering by region
+    let region_depots: Option<Vec<Uuid>> = filter.region_id.map(|region_id| {
+        rows_of(&tables.depots, organization_id)
+            .filter(|depot| depot.region_id == region_id)
+            .map(|depot| depot.id)
+            .collect()
+    });
+
+    rows_of(&tables.vehicles, organization_id)
+        .map(|vehicle| (vehicle, tables.home_depot_of(organization_id, vehicle.id)))
+        .filter(|(vehicle, home_depot_id)| {
+            filter.uuid.is_none_or(|uuid| vehicle.id == uuid)
+                && filter
+                    .make
+                    .as_ref()
+                    .is_none_or(|make| &vehicle.make == make)
+                && filter
+                    .model
+                    .as_ref()
+                    .is_none_or(|model| &vehicle.model == model)
+                && filter.year.is_none_or(|year| vehicle.year == year)
+                && filter
+                    .vin
+                    .as_ref()
+                    .is_none_or(|vin| vehicle.vin.value() == vin.value())
+                && filter
+                    .license_plate
+                    .as_ref()
+                    .is_none_or(|plate| vehicle.license_plate.value() == plate.value())
+                && filter
+                    .country
+                    .as_ref()
+                    .is_none_or(|country| &vehicle.country == country)
+                && filter
+                    .engine_type
+                    .as_ref()
+                    .is_none_or(|engine_type| vehicle.powertrain.engine_type() == engine_type)
+                && filter
+                    .depot_id
+                    .is_none_or(|depot_id| *home_depot_id == Some(depot_id))
+                && region_depots
+                    .as_ref()
+                    .is_none_or(|depots| home_depot_id.is_some_and(|id| depots.contains(&id)))
+                && filter
+                    .depot_scope
+                    .as_ref()
+                    .is_none_or(|scope| home_depot_id.is_some_and(|id| scope.contains(&id)))
+                && filter.expression.as_ref().is_none_or(|expression| {
+                    matches(expression, &|column| column_value(vehicle, column))
+                })
+        })
+        .filter_map(|(vehicle, home_depot_id)| match &filter.search {
+            None => Some(view(vehicle, home_depot_id, None)),
+            Some(search) => rank(
+                search,
+                &[
+                    &vehicle.make,
+                    &vehicle.model,
+                    vehicle.license_plate.value(),
+                    vehicle.vin.value(),
+                ],
+            )
+            .map(|rank| view(vehicle, home_depot_id, Some(rank))),
+        })
+        .collect()
+}
+
+impl VehicleRepository for MemoryVehicleRepository {
+    async fn create(
+        &self,
+        organization_id: Uuid,
+        vehicle: NewVehicle,
+    ) -> Result<VehicleIdentity, VehicleRepositoryError> {
+        // A duplicate is reported with the id of the existing vehicle, not as a database error
+        let created = self
+            .store
+            .write("vehicles.create", |tables| {
+                tables.organization(organization_id, "vehicles_organization_id_fkey")?;
+                let vehicle = Vehicle::new(vehicle)
+                    .map_err(|e| format!("new row violates check constraint: {}", e))?
+                    .identity;
+                if let Some(existing) =
+                    rows_of(&tables.vehicles, organization_id).find(|existing| {
+                        existing.vin.value() == vehicle.vin.value()
+                            || (existing.country == vehicle.country
+                                && existing.license_plate.value() == vehicle.license_plate.value())
+                    })
+                {
+                    return Ok(Err(existing.id));
+                }
+
+                tables
+                    .vehicles
+                    .push(TenantRow::new(organization_id, vehicle.clone()));
+                Ok(Ok(vehicle))
+            })
+            .map_err(VehicleRepositoryError::Database)?;
+        created.map_err(VehicleRepositoryError::AlreadyExists)
+    }
+
+    async fn find_by_id(
+        &self,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> Result<Option<VehicleIdentity>, VehicleRepositoryError> {
+        self.store
+            .read("vehicles.find_by_id", |tables| {
+                Ok(tables.vehicle_of(organization_id, id).cloned())
+            })
+            .map_err(VehicleRepositoryError::Database)
+    }
+
+    async fn find_all(
+        &self,
+        organization_id: Uuid,
+    ) -> Result<Vec<VehicleIdentity>, VehicleRepositoryError> {
+        self.store
+            .read("vehicles.find_all", |tables| {
+                Ok(rows_of(&tables.vehicles, organization_id)
+                    .cloned()
+                    .collect())
+            })
+            .map_err(VehicleRepositoryError::Database)
+    }
+
+    async fn exists_by_vin_or_license_plate(
+        &self,
+        organization_id: Uuid,
+        vin: &str,
+        country: &str,
+        license_plate: &str,
+    ) -> Result<bool, VehicleRepositoryError> {
+        self.store
+            .read("vehicles.exists_by_vin_or_license_plate", |tables| {
+                Ok(rows_of(&tables.vehicles, organization_id).any(|vehicle| {
+                    vehicle.vin.value() == vin
+                        || (vehicle.country.value() == country
+                            && vehicle.license_plate.value() == license_plate)
+                }))
+            })
+            .map_err(VehicleRepositoryError::Database)
+    }
+
+    async fn find_taken_identifiers(
+        &self,
+        organization_id: Uuid,
+        vins: &[String],
+        license_plates: &[(String, String)],
+    ) -> Result<TakenIdentifiers, VehicleRepositoryError> {
+        self.store
+            .read("vehicles.find_taken_identifiers", |tables| {
+                let mut taken = TakenIdentifiers::default();
+                for vehicle in rows_of(&tables.vehicles, organization_id) {
+                    let vin = vehicle.vin.value();
+                    if vins.iter().any(|candidate| candidate == vin) {
+                        taken.vins.insert(vin.to_string());
+                    }
+                    let plate = (
+                        vehicle.country.value().to_string(),
+                        vehicle.license_plate.value().to_string(),
+                    );
+                    if license_plates.contains(&plate) {
+                        taken.license_plates.insert(plate);
+                    }
+                }
+                Ok(taken)
+            })
+            .map_err(VehicleRepositoryError::Database)
+    }
+
+    async fn delete(
+        &self,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> Result<bool, VehicleRepositoryError> {
+        self.store
+            .write("vehicles.delete", |tables| {
+                Ok(tables.delete_vehicle(organization_id, id))
+            })
+            .map_err(VehicleRepositoryError::Database)
+    }
+}
+
+impl VehicleApplicationRepository for MemoryVehicleRepository {
+    async fn get_by_filter(
+        &self,
+        organization_id: Uuid,
+        filter: VehicleFilter,
+    ) -> Result<Vec<VehicleView>, VehicleApplicationRepositoryError> {
+        self.store
+            .read("vehicles.get_by_filter", |tables| {
+                let sort_by = filter.sort_by.unwrap_or_default();
+                let mut views = matching(tables, organization_id, &filter);
+                sort_rows(&mut views, filter.sort_order, |view| {
+                    (
+                        sort_value(sort_by, view),
+                        FilterValue::Text(view.id.clone()),
+                    )
+                });
+
+                // A page size of 0 reads every vehicle
+                if filter.page_size == 0 {
+                    return Ok(views);
+                }
+                let offset = filter.page.saturating_sub(1) as usize * filter.page_size as usize;
+                Ok(views
+                    .into_iter()
+                    .skip(offset)
+                    .take(filter.page_size as usize)
+                    .collect())
+            })
+            .map_err(VehicleApplicationRepositoryError::DatabaseError)
+    }
+
+    async fn get_page(
+        &self,
+        organization_id: Uuid,
+        filter: VehicleFilter,
+        keyset: &Keyset,
+    ) -> Result<Vec<VehicleView>, VehicleApplicationRepositoryError> {
+        self.store
+            .read("vehicles.get_page", |tables| {
+                let sort_by = filter.sort_by.unwrap_or_default();
+                Ok(read_page(
+                    matching(tables, organization_id, &filter),
+                    keyset,
+                    |view| {
+                        (
+                            sort_value(sort_by, view),
+                            FilterValue::Text(view.id.clone()),
+                        )
+                    },
+                ))
+            })
+            .map_err(VehicleApplicationRepositoryError::DatabaseError)
+    }
+
+    async fn count(
+        &self,
+        organization_id: Uuid,
+        filter: VehicleFilter,
+    ) -> Result<u64, VehicleApplicationRepositoryError> {
+        self.store
+            .read("vehicles.count", |tables| {
+                Ok(matching(tables, organization_id, &filter).len() as u64)
+            })
+            .map_err(VehicleApplicationRepositoryError::DatabaseError)
+    }
+}
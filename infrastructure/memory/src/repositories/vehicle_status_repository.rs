@@ -0,0 +1,210 @@
+use crate::{
+    filters::{filter_expression_eval::matches, keyset::read_page},
+    store::{MemoryStore, TenantRow, VehicleStatusRow, foreign_key, rows_of},
+};
+use application::{
+    shared::{
+        filter_expression::filter_ast::{FilterExpression, FilterValue},
+        pagination::Keyset,
+    },
+    vehicle::traits::vehicle_status_repository::{
+        VehicleStatusApplicationRepository, VehicleStatusApplicationRepositoryError,
+    },
+};
+use domain::vehicle::{
+    entities::vehicle_status::VehicleStatusIdentity,
+    repositories::vehicle_status_repository::{
+        VehicleStatusRepository, VehicleStatusRepositoryError,
+    },
+};
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct MemoryVehicleStatusRepository {
+    store: MemoryStore,
+}
+
+impl MemoryVehicleStatusRepository {
+    pub fn new(store: &MemoryStore) -> Self {
+        MemoryVehicleStatusRepository {
+            store: store.clone(),
+        }
+    }
+
+    /// Statuses of a vehicle matching the filter.
+    fn matching(
+        &self,
+        operation: &str,
+        organization_id: Uuid,
+        vehicle_id: Uuid,
+        filter: Option<&FilterExpression>,
+    ) -> Result<Vec<VehicleStatusIdentity>, VehicleStatusApplicationRepositoryError> {
+        self.store
+            .read(operation, |tables| {
+                Ok(rows_of(&tables.vehicle_statuses, organization_id)
+                    .map(|row| &row.status)
+                    .filter(|status| status.vehicle_id == vehicle_id)
+                    .filter(|status| {
+                        filter.is_none_or(|filter| {
+                            matches(filter, &|column| column_value(status, column))
+                        })
+                    })
+                    .cloned()
+                    .collect())
+            })
+            .map_err(VehicleStatusApplicationRepositoryError::DatabaseError)
+    }
+}
+
+/// Value of a column of `VEHICLE_STATUS_FILTER_SCHEMA`.
+fn column_value(status: &VehicleStatusIdentity, column: &str) -> Option<FilterValue> {
+    match column {
+        "odometer" => Some(FilterValue::Integer(i64::from(status.odometer))),
+        "engine_hour_meter" => status
+            .engine_hour_meter
+            .map(|value| FilterValue::Integer(i64::from(value))),
+        "fuel_level" => status
+            .fuel_level
+            .map(|value| FilterValue::Integer(i64::from(value))),
+        "notes" => Some(FilterValue::Text(status.notes.clone())),
+        "created_at" => Some(FilterValue::Timestamp(status.created_at)),
+        _ => None,
+    }
+}
+
+impl VehicleStatusRepository for MemoryVehicleStatusRepository {
+    async fn create(
+        &self,
+        organization_id: Uuid,
+        status: VehicleStatusIdentity,
+    ) -> Result<VehicleStatusIdentity, VehicleStatusRepositoryError> {
+        self.store
+            .write("vehicle_statuses.create", |tables| {
+                tables.organization(organization_id, "vehicle_statuses_organization_id_fkey")?;
+                if tables.vehicle(status.vehicle_id).is_none() {
+                    return Err(foreign_key("vehicle_statuses_vehicle_id_fkey"));
+                }
+                tables.user(status.performed_by, "vehicle_statuses_performed_by_fkey")?;
+
+                let mut status = status;
+                status.id = tables.next_id("vehicle_statuses");
+                // A back-dated status is inserted into the history, the latest one stays
+                let latest = !tables.vehicle_statuses.iter().any(|row| {
+                    row.status.vehicle_id == status.vehicle_id
+                        && row.status.performed_at > status.performed_at
+                });
+                if latest {
+                    for row in &mut tables.vehicle_statuses {
+                        if row.status.vehicle_id == status.vehicle_id {
+                            row.latest = false;
+                        }
+                    }
+                }
+                tables.vehicle_statuses.push(TenantRow::new(
+                    organization_id,
+                    VehicleStatusRow {
+                        status: status.clone(),
+                        latest,
+                    },
+                ));
+                Ok(status)
+            })
+            .map_err(VehicleStatusRepositoryError::Database)
+    }
+
+    async fn find_latest(
+        &self,
+        organization_id: Uuid,
+        vehicle_id: Uuid,
+    ) -> Result<Option<VehicleStatusIdentity>, VehicleStatusRepositoryError> {
+        self.store
+            .read("vehicle_statuses.find_latest", |tables| {
+                Ok(rows_of(&tables.vehicle_statuses, organization_id)
+                    .find(|row| row.latest && row.status.vehicle_id == vehicle_id)
+                    .map(|row| row.status.clone()))
+            })
+            .map_err(VehicleStatusRepositoryError::Database)
+    }
+
+    async fn find_latest_before(
+        &self,
+        organization_id: Uuid,
+        vehicle_id: Uuid,
+        before: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Option<VehicleStatusIdentity>, VehicleStatusRepositoryError> {
+        self.store
+            .read("vehicle_statuses.find_latest_before", |tables| {
+                Ok(rows_of(&tables.vehicle_statuses, organization_id)
+                    .map(|row| &row.status)
+                    .filter(|status| {
+                        status.vehicle_id == vehicle_id && status.performed_at < before
+                    })
+                    .max_by_key(|status| (status.performed_at, status.id))
+                    .cloned())
+            })
+            .map_err(VehicleStatusRepositoryError::Database)
+    }
+
+    async fn find_by_vehicle(
+        &self,
+        organization_id: Uuid,
+        vehicle_id: Uuid,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<VehicleStatusIdentity>, VehicleStatusRepositoryError> {
+        self.store
+            .read("vehicle_statuses.find_by_vehicle", |tables| {
+                let mut statuses: Vec<_> = rows_of(&tables.vehicle_statuses, organization_id)
+                    .map(|row| &row.status)
+                    .filter(|status| {
+                        status.vehicle_id == vehicle_id
+                            && from.is_none_or(|from| status.performed_at >= from)
+                            && to.is_none_or(|to| status.performed_at <= to)
+                    })
+                    .cloned()
+                    .collect();
+                statuses.sort_by_key(|status| (status.performed_at, status.id));
+                Ok(statuses)
+            })
+            .map_err(VehicleStatusRepositoryError::Database)
+    }
+}
+
+impl VehicleStatusApplicationRepository for MemoryVehicleStatusRepository {
+    async fn get_page(
+        &self,
+        organization_id: Uuid,
+        vehicle_id: Uuid,
+        filter: Option<&FilterExpression>,
+        keyset: &Keyset,
+    ) -> Result<Vec<VehicleStatusIdentity>, VehicleStatusApplicationRepositoryError> {
+        let statuses = self.matching(
+            "vehicle_statuses.get_page",
+            organization_id,
+            vehicle_id,
+            filter,
+        )?;
+        Ok(read_page(statuses, keyset, |status| {
+            (
+                Some(FilterValue::Timestamp(status.created_at)),
+                FilterValue::Integer(i64::from(status.id)),
+            )
+        }))
+    }
+
+    async fn count(
+        &self,
+        organization_id: Uuid,
+        vehicle_id: Uuid,
+        filter: Option<&FilterExpression>,
+    ) -> Result<u64, VehicleStatusApplicationRepositoryError> {
+        Ok(self
+            .matching(
+                "vehicle_statuses.count",
+                organization_id,
+                vehicle_id,
+                filter,
+            )?
+            .len() as u64)
+    }
+}
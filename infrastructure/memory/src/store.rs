@@ -0,0 +1,454 @@
+//! The tables shared by the in-memory repositories.
+use crate::faults::FaultInjector;
+use domain::{
+    depot::entities::{
+        depot::Depot, region::Region, site::Site, vehicle_movement::VehicleMovement,
+    },
+    diagnostics::entities::{
+        diagnostic_alert::DiagnosticAlert, diagnostic_rule::DiagnosticRule, dtc_event::DtcEvent,
+    },
+    fuel::entities::{fuel_event::FuelEventIdentity, fuel_tank::FuelTank},
+    inspection::entities::{
+        inspection_defect::InspectionDefect, inspection_report::InspectionReport,
+        inspection_template::InspectionTemplate,
+    },
+    maintenance::entities::{
+        maintenance::{Maintenance, MaintenanceIdentity},
+        maintenance_interval_template::MaintenanceIntervalTemplate,
+        maintenance_record::{MaintenanceRecord, MaintenanceRecordIdentity},
+        maintenance_type::{MaintenanceType, MaintenanceTypeView},
+    },
+    organization::entities::organization::Organization,
+    telematics::entities::telematics_device::TelematicsDevice,
+    trip::entities::trip::Trip,
+    user::entities::user::UserIdentity,
+    vehicle::entities::{vehicle::VehicleIdentity, vehicle_status::VehicleStatusIdentity},
+};
+use std::{
+    collections::HashMap,
+    ops::{Deref, DerefMut},
+    sync::{Arc, PoisonError, RwLock},
+};
+use uuid::Uuid;
+
+/// A row of a table of the organizations' data, with its `organization_id` column.
+#[derive(Debug, Clone)]
+pub(crate) struct TenantRow<T> {
+    pub organization_id: Uuid,
+    pub row: T,
+}
+
+impl<T> TenantRow<T> {
+    pub fn new(organization_id: Uuid, row: T) -> Self {
+        TenantRow {
+            organization_id,
+            row,
+        }
+    }
+}
+
+impl<T> Deref for TenantRow<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.row
+    }
+}
+
+impl<T> DerefMut for TenantRow<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.row
+    }
+}
+
+/// The rows of a table that belong to an organization, like the row-level security policies.
+pub(crate) fn rows_of<T>(rows: &[TenantRow<T>], organization_id: Uuid) -> impl Iterator<Item = &T> {
+    rows.iter()
+        .filter(move |row| row.organization_id == organization_id)
+        .map(|row| &row.row)
+}
+
+/// A maintenance type with the columns the entity doesn't hold.
+#[derive(Debug, Clone)]
+pub(crate) struct MaintenanceTypeRow {
+    pub maintenance_type: MaintenanceType,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub created_by: Uuid,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+    pub updated_by: Uuid,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct VehicleStatusRow {
+    pub status: VehicleStatusIdentity,
+    /// At most one latest status per vehicle (`one_latest_status_per_vehicle`).
+    pub latest: bool,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct DepotManagerRow {
+    pub depot_id: Uuid,
+    pub user_id: Uuid,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct VehicleMovementRow {
+    pub movement: VehicleMovement,
+    /// At most one latest movement per vehicle (`one_latest_movement_per_vehicle`).
+    pub latest: bool,
+}
+
+/// The rows of every table, in insertion order.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Tables {
+    pub organizations: Vec<Organization>,
+    pub users: HashMap<Uuid, UserIdentity>,
+    pub vehicles: Vec<TenantRow<VehicleIdentity>>,
+    pub vehicle_statuses: Vec<TenantRow<VehicleStatusRow>>,
+    pub maintenance_types: Vec<TenantRow<MaintenanceTypeRow>>,
+    pub maintenances: Vec<TenantRow<MaintenanceIdentity>>,
+    pub maintenance_records: Vec<TenantRow<MaintenanceRecordIdentity>>,
+    pub maintenance_interval_templates: Vec<TenantRow<MaintenanceIntervalTemplate>>,
+    pub fuel_events: Vec<TenantRow<FuelEventIdentity>>,
+    pub fuel_tanks: Vec<TenantRow<FuelTank>>,
+    pub regions: Vec<TenantRow<Region>>,
+    pub depots: Vec<TenantRow<Depot>>,
+    pub sites: Vec<TenantRow<Site>>,
+    pub depot_managers: Vec<TenantRow<DepotManagerRow>>,
+    pub vehicle_movements: Vec<TenantRow<VehicleMovementRow>>,
+    pub telematics_devices: Vec<TenantRow<TelematicsDevice>>,
+    pub trips: Vec<TenantRow<Trip>>,
+    pub dtc_events: Vec<TenantRow<DtcEvent>>,
+    pub diagnostic_rules: Vec<TenantRow<DiagnosticRule>>,
+    pub diagnostic_alerts: Vec<TenantRow<DiagnosticAlert>>,
+    pub inspection_templates: Vec<TenantRow<InspectionTemplate>>,
+    pub inspection_reports: Vec<TenantRow<InspectionReport>>,
+    pub inspection_defects: Vec<TenantRow<InspectionDefect>>,
+    /// Last value of the `SERIAL` sequence of each table.
+    sequences: HashMap<&'static str, i32>,
+    /// Number of writes, to detect the concurrent writes of a transaction.
+    version: u64,
+}
+
+/// Error of a foreign key violation, worded like PostgreSQL.
+pub(crate) fn foreign_key(constraint: &str) -> String {
+    format!(
+        "insert or update violates foreign key constraint \"{}\"",
+        constraint
+    )
+}
+
+/// Error of a unique key violation, worded like PostgreSQL.
+pub(crate) fn unique(constraint: &str) -> String {
+    format!(
+        "duplicate key value violates unique constraint \"{}\"",
+        constraint
+    )
+}
+
+impl Tables {
+    pub fn next_id(&mut self, table: &'static str) -> i32 {
+        let id = self.sequences.entry(table).or_insert(0);
+        *id += 1;
+        *id
+    }
+
+    pub fn user(&self, id: Uuid, constraint: &str) -> Result<&UserIdentity, String> {
+        self.users.get(&id).ok_or_else(|| foreign_key(constraint))
+    }
+
+    pub fn organization(&self, id: Uuid, constraint: &str) -> Result<&Organization, String> {
+        self.organizations
+            .iter()
+            .find(|organization| organization.id == id)
+            .ok_or_else(|| foreign_key(constraint))
+    }
+
+    /// A vehicle of any organization, for the foreign keys (which ignore the policies).
+    pub fn vehicle(&self, id: Uuid) -> Option<&VehicleIdentity> {
+        self.vehicles
+            .iter()
+            .map(|row| &row.row)
+            .find(|vehicle| vehicle.id == id)
+    }
+
+    pub fn vehicle_of(&self, organization_id: Uuid, id: Uuid) -> Option<&VehicleIdentity> {
+        rows_of(&self.vehicles, organization_id).find(|vehicle| vehicle.id == id)
+    }
+
+    /// The home depot of a vehicle of an organization: the depot of its latest movement.
+    pub fn home_depot_of(&self, organization_id: Uuid, vehicle_id: Uuid) -> Option<Uuid> {
+        rows_of(&self.vehicle_movements, organization_id)
+            .find(|row| row.latest && row.movement.vehicle_id == vehicle_id)
+            .map(|row| row.movement.to_depot_id)
+    }
+
+    /// A maintenance type of any organization, for the foreign keys.
+    pub fn maintenance_type(&self, id: i32) -> Option<&MaintenanceTypeRow> {
+        self.maintenance_types
+            .iter()
+            .map(|row| &row.row)
+            .find(|row| row.maintenance_type.id() == id)
+    }
+
+    pub fn maintenance_type_of(
+        &self,
+        organization_id: Uuid,
+        id: i32,
+    ) -> Option<&MaintenanceTypeRow> {
+        rows_of(&self.maintenance_types, organization_id)
+            .find(|row| row.maintenance_type.id() == id)
+    }
+
+    pub fn maintenance_type_view(
+        &self,
+        row: &MaintenanceTypeRow,
+    ) -> Result<MaintenanceTypeView, String> {
+        let maintenance_type = &row.maintenance_type;
+        Ok(MaintenanceTypeView {
+            id: maintenance_type.id(),
+            name: maintenance_type.name().to_string(),
+            description: maintenance_type.description().to_string(),
+            category: maintenance_type.category(),
+            applicability: maintenance_type.applicability(),
+            checklist: maintenance_type.checklist().to_vec(),
+            deprecated_at: maintenance_type.deprecated_at(),
+            created_at: row.created_at,
+            created_by: self
+                .user(row.created_by, "maintenance_types_created_by_fkey")?
+                .clone(),
+            updated_at: row.updated_at,
+            updated_by: self
+                .user(row.updated_by, "maintenance_types_updated_by_fkey")?
+                .clone(),
+        })
+    }
+
+    pub fn hydrate_maintenance(
+        &self,
+        identity: &MaintenanceIdentity,
+    ) -> Result<Maintenance, String> {
+        Ok(Maintenance {
+            identity: identity.clone(),
+            maintenance_type: self
+                .maintenance_type(identity.maintenance_type_id)
+                .ok_or_else(|| foreign_key("maintenances_maintenance_type_id_fkey"))?
+                .maintenance_type
+                .clone(),
+            vehicle: self
+                .vehicle(identity.vehicle_id)
+                .ok_or_else(|| foreign_key("maintenances_vehicle_id_fkey"))?
+                .clone(),
+        })
+    }
+
+    pub fn hydrate_record(
+        &self,
+        identity: &MaintenanceRecordIdentity,
+    ) -> Result<MaintenanceRecord, String> {
+        Ok(MaintenanceRecord {
+            identity: identity.clone(),
+            vehicle: self
+                .vehicle(identity.vehicle_id)
+                .ok_or_else(|| foreign_key("maintenance_records_vehicle_id_fkey"))?
+                .clone(),
+            maintenance: self
+                .maintenances
+                .iter()
+                .map(|row| &row.row)
+                .find(|maintenance| maintenance.id == identity.maintenance_id)
+                .ok_or_else(|| foreign_key("maintenance_records_maintenance_id_fkey"))?
+                .clone(),
+            user: self
+                .user(identity.user_id, "maintenance_records_performed_by_fkey")?
+                .clone(),
+            vehicle_status: self
+                .vehicle_statuses
+                .iter()
+                .find(|row| row.status.id == identity.vehicle_status_id)
+                .ok_or_else(|| foreign_key("maintenance_records_vehicle_status_id_fkey"))?
+                .status
+                .clone(),
+        })
+    }
+
+    /// Deletes the diagnostic rules of a maintenance type and, like `ON DELETE CASCADE`, their
+    /// alerts.
+    pub fn delete_diagnostic_rules_of(&mut self, maintenance_type_id: i32) {
+        let deleted: Vec<Uuid> = self
+            .diagnostic_rules
+            .iter()
+            .filter(|rule| rule.action.maintenance_type_id() == Some(maintenance_type_id))
+            .map(|rule| rule.id)
+            .collect();
+        self.diagnostic_rules
+            .retain(|rule| !deleted.contains(&rule.id));
+        self.diagnostic_alerts
+            .retain(|alert| !deleted.contains(&alert.rule_id));
+    }
+
+    /// Deletes a vehicle of an organization and, like `ON DELETE CASCADE`, everything that
+    /// references it.
+    pub fn delete_vehicle(&mut self, organization_id: Uuid, id: Uuid) -> bool {
+        let count = self.vehicles.len();
+        self.vehicles
+            .retain(|vehicle| vehicle.organization_id != organization_id || vehicle.id != id);
+        if self.vehicles.len() == count {
+            return false;
+        }
+
+        self.vehicle_statuses
+            .retain(|row| row.status.vehicle_id != id);
+        self.maintenances
+            .retain(|maintenance| maintenance.vehicle_id != id);
+        self.maintenance_records
+            .retain(|record| record.vehicle_id != id);
+        self.fuel_events.retain(|event| event.vehicle_id != id);
+        self.fuel_tanks.retain(|tank| tank.vehicle_id != id);
+        self.vehicle_movements
+            .retain(|row| row.movement.vehicle_id != id);
+        self.telematics_devices
+            .retain(|device| device.vehicle_id != id);
+        self.trips.retain(|trip| trip.vehicle_id != id);
+        self.dtc_events.retain(|event| event.vehicle_id != id);
+        self.diagnostic_alerts
+            .retain(|alert| alert.vehicle_id != id);
+        self.inspection_reports
+            .retain(|report| report.vehicle_id != id);
+        self.inspection_defects
+            .retain(|defect| defect.vehicle_id != id);
+        true
+    }
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    tables: RwLock<Tables>,
+    /// Shared by the stores of the transactions.
+    faults: Arc<FaultInjector>,
+}
+
+/// The storage shared by the in-memory repositories.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryStore {
+    inner: Arc<Inner>,
+}
+
+impl MemoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn faults(&self) -> &FaultInjector {
+        &self.inner.faults
+    }
+
+    /// Adds (or replaces) a user of an organization, referenced by the `created_by` /
+    /// `updated_by` columns.
+    pub fn insert_user(&self, user: UserIdentity) {
+        // Seeding never fails
+        let _ = self.write("users.insert", |tables| {
+            tables.users.insert(user.id, user);
+            Ok(())
+        });
+    }
+
+    /// Reads the tables, failing if a fault is injected on the operation.
+    pub(crate) fn read<T>(
+        &self,
+        operation: &str,
+        read: impl FnOnce(&Tables) -> Result<T, String>,
+    ) -> Result<T, String> {
+        self.inner.faults.check(operation)?;
+        let tables = self
+            .inner
+            .tables
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
+        read(&tables)
+    }
+
+    /// Writes the tables in a transaction: the changes are kept only if `write` succeeds.
+    pub(crate) fn write<T>(
+        &self,
+        operation: &str,
+        write: impl FnOnce(&mut Tables) -> Result<T, String>,
+    ) -> Result<T, String> {
+        self.inner.faults.check(operation)?;
+        let mut tables = self
+            .inner
+            .tables
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        let mut transaction = tables.clone();
+        let result = write(&mut transaction)?;
+        transaction.version += 1;
+        *tables = transaction;
+        Ok(result)
+    }
+
+    /// Starts a transaction: a store of a copy of the tables, sharing the faults, and the version
+    /// of the tables it was copied from.
+    pub(crate) fn begin(&self) -> Result<(MemoryStore, u64), String> {
+        let tables = self.read("transactions.begin", |tables| Ok(tables.clone()))?;
+        let version = tables.version;
+        let store = MemoryStore {
+            inner: Arc::new(Inner {
+                tables: RwLock::new(tables),
+                faults: self.inner.faults.clone(),
+            }),
+        };
+        Ok((store, version))
+    }
+
+    /// Replaces the tables with the ones of a transaction, unless they were written since it
+    /// started (the transactions are serializable).
+    pub(crate) fn commit(&self, transaction: &MemoryStore, version: u64) -> Result<(), String> {
+        let mut committed = transaction.read("transactions.commit", |tables| Ok(tables.clone()))?;
+        let mut tables = self
+            .inner
+            .tables
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        if tables.version != version {
+            return Err("could not serialize access due to concurrent update".to_string());
+        }
+        committed.version = version + 1;
+        *tables = committed;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_failed_write_is_rolled_back() {
+        let store = MemoryStore::new();
+        let result: Result<(), String> = store.write("sequences.next", |tables| {
+            tables.next_id("vehicle_statuses");
+            Err(unique("vehicle_statuses_pkey"))
+        });
+
+        assert!(result.is_err());
+        let next = store.write("sequences.next", |tables| {
+            Ok(tables.next_id("vehicle_statuses"))
+        });
+        assert_eq!(next, Ok(1));
+    }
+
+    #[test]
+    fn test_injected_fault_skips_the_write() {
+        let store = MemoryStore::new();
+        store.faults().fail_next("sequences", "connection reset");
+
+        assert!(
+            store
+                .write("sequences.next", |tables| Ok(tables.next_id("vehicles")))
+                .is_err()
+        );
+        let next = store.write("sequences.next", |tables| Ok(tables.next_id("vehicles")));
+        assert_eq!(next, Ok(1));
+    }
+}
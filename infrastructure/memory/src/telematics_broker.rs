@@ -0,0 +1,120 @@
+//! A local stand-in for the MQTT broker the telematics devices publish to.
+use application::telematics::traits::telematics_broker::{
+    TelematicsBroker, TelematicsBrokerError, TelematicsMessage, organization_topic,
+};
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+/// Keeps the published messages in memory until they are received; cloning the broker shares
+/// the messages.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryTelematicsBroker {
+    messages: Arc<Mutex<VecDeque<TelematicsMessage>>>,
+}
+
+impl MemoryTelematicsBroker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Publishes a message, as a device or a gateway would.
+    pub fn publish(&self, topic: impl Into<String>, payload: impl Into<Vec<u8>>) {
+        self.lock().push_back(TelematicsMessage {
+            topic: topic.into(),
+            payload: payload.into(),
+        });
+    }
+
+    /// Number of messages not received yet, of every organization.
+    pub fn pending(&self) -> usize {
+        self.lock().len()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, VecDeque<TelematicsMessage>> {
+        self.messages.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Whether a topic matches an MQTT topic filter (`+` matches a level, a trailing `#` the rest).
+fn topic_matches(filter: &str, topic: &str) -> bool {
+    let mut topic_levels = topic.split('/');
+    for filter_level in filter.split('/') {
+        if filter_level == "#" {
+            return true;
+        }
+        match topic_levels.next() {
+            Some(level) if filter_level == "+" || filter_level == level => {}
+            _ => return false,
+        }
+    }
+    topic_levels.next().is_none()
+}
+
+impl TelematicsBroker for MemoryTelematicsBroker {
+    async fn receive(
+        &self,
+        organization_id: uuid::Uuid,
+        limit: usize,
+    ) -> Result<Vec<TelematicsMessage>, TelematicsBrokerError> {
+        let filter = organization_topic(organization_id);
+        let mut messages = self.lock();
+        let mut received = Vec::new();
+        let mut pending = VecDeque::with_capacity(messages.len());
+        for message in messages.drain(..) {
+            if received.len() < limit && topic_matches(&filter, &message.topic) {
+                received.push(message);
+            } else {
+                pending.push_back(message);
+            }
+        }
+        *messages = pending;
+        Ok(received)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use application::telematics::traits::telematics_broker::device_topic;
+
+    #[test]
+    fn test_topic_filters() {
+        assert!(topic_matches(
+            "fleet/+/telematics/#",
+            "fleet/a/telematics/imei-1"
+        ));
+        assert!(topic_matches("fleet/a/telematics/#", "fleet/a/telematics"));
+        assert!(!topic_matches(
+            "fleet/a/telematics/#",
+            "fleet/b/telematics/imei-1"
+        ));
+        assert!(!topic_matches(
+            "fleet/a/telematics",
+            "fleet/a/telematics/imei-1"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_messages_are_received_once_per_organization() {
+        let broker = MemoryTelematicsBroker::new();
+        let (mine, theirs) = (uuid::Uuid::new_v4(), uuid::Uuid::new_v4());
+        broker.publish(device_topic(mine, "imei-1"), "first");
+        broker.publish(device_topic(theirs, "imei-2"), "other");
+        broker.publish(device_topic(mine, "imei-1"), "second");
+        broker.publish(device_topic(mine, "imei-3"), "third");
+
+        let received = broker.receive(mine, 2).await.unwrap();
+        let payloads: Vec<&[u8]> = received.iter().map(|m| m.payload.as_slice()).collect();
+        assert_eq!(payloads, vec![b"first".as_slice(), b"second".as_slice()]);
+
+        let received = broker.receive(mine, 10).await.unwrap();
+        assert_eq!(received.len(), 1);
+        assert_eq!(
+            broker.pending(),
+            1,
+            "the other organization's message is left"
+        );
+    }
+}
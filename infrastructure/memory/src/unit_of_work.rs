@@ -0,0 +1,282 @@
+//! Transactions over a copy of the tables.
+use crate::{
+    repositories::{
+        maintenance_record_repository::MemoryMaintenanceRecordRepository,
+        vehicle_status_repository::MemoryVehicleStatusRepository,
+    },
+    store::MemoryStore,
+};
+use application::shared::traits::unit_of_work::{Transaction, UnitOfWork, UnitOfWorkError};
+use std::marker::PhantomData;
+
+/// Starts the transactions of a store.
+#[derive(Debug, Clone)]
+pub struct MemoryUnitOfWork {
+    store: MemoryStore,
+}
+
+impl MemoryUnitOfWork {
+    pub fn new(store: &MemoryStore) -> Self {
+        Self {
+            store: store.clone(),
+        }
+    }
+}
+
+impl UnitOfWork for MemoryUnitOfWork {
+    type Transaction = MemoryTransaction<'static>;
+
+    /// The repositories scope every call to an organization, the transaction needs none.
+    async fn begin(
+        &self,
+        _organization_id: uuid::Uuid,
+    ) -> Result<Self::Transaction, UnitOfWorkError> {
+        MemoryTransaction::begin(&self.store)
+    }
+}
+
+/// A transaction writes a copy of the tables of its parent (the store, or the outer transaction),
+/// which replaces them on commit; a rollback drops the copy.
+#[derive(Debug)]
+pub struct MemoryTransaction<'a> {
+    parent: MemoryStore,
+    /// Version of the tables of the parent when the transaction started.
+    version: u64,
+    store: MemoryStore,
+    vehicle_statuses: MemoryVehicleStatusRepository,
+    maintenance_records: MemoryMaintenanceRecordRepository,
+    /// A nested transaction borrows the outer one.
+    outer: PhantomData<&'a mut ()>,
+}
+
+impl MemoryTransaction<'_> {
+    fn begin(parent: &MemoryStore) -> Result<Self, UnitOfWorkError> {
+        let (store, version) = parent.begin().map_err(UnitOfWorkError::DatabaseError)?;
+        Ok(Self {
+            parent: parent.clone(),
+            version,
+            vehicle_statuses: MemoryVehicleStatusRepository::new(&store),
+            maintenance_records: MemoryMaintenanceRecordRepository::new(&store),
+            store,
+            outer: PhantomData,
+        })
+    }
+}
+
+impl Transaction for MemoryTransaction<'_> {
+    type VehicleStatuses = MemoryVehicleStatusRepository;
+    type MaintenanceRecords = MemoryMaintenanceRecordRepository;
+    type Nested<'b>
+        = MemoryTransaction<'b>
+    where
+        Self: 'b;
+
+    fn vehicle_statuses(&self) -> &Self::VehicleStatuses {
+        &self.vehicle_statuses
+    }
+
+    fn maintenance_records(&self) -> &Self::MaintenanceRecords {
+        &self.maintenance_records
+    }
+
+    async fn begin(&mut self) -> Result<Self::Nested<'_>, UnitOfWorkError> {
+        MemoryTransaction::begin(&self.store)
+    }
+
+    async fn commit(self) -> Result<(), UnitOfWorkError> {
+        self.parent
+            .commit(&self.store, self.version)
+            .map_err(UnitOfWorkError::DatabaseError)
+    }
+
+    async fn rollback(self) -> Result<(), UnitOfWorkError> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{
+        organization_repository::MemoryOrganizationRepository,
+        vehicle_repository::MemoryVehicleRepository,
+    };
+    use domain::{
+        organization::{
+            entities::organization::Organization,
+            repositories::organization_repository::OrganizationRepository,
+        },
+        user::{
+            entities::user::UserIdentity,
+            value_types::{Email, UserId},
+        },
+        vehicle::{
+            entities::{vehicle::VehicleIdentity, vehicle_status::VehicleStatusIdentity},
+            repositories::{
+                vehicle_repository::VehicleRepository,
+                vehicle_status_repository::VehicleStatusRepository,
+            },
+        },
+    };
+
+    async fn seed(store: &MemoryStore) -> (UserIdentity, VehicleIdentity) {
+        let organization = MemoryOrganizationRepository::new(store)
+            .create(Organization::new("Steppe Logistics").unwrap())
+            .await
+            .unwrap();
+        let id = uuid::Uuid::new_v4();
+        let user = UserIdentity {
+            id,
+            uuid: UserId::new(id),
+            organization_id: organization.id,
+            username: "alice".to_string(),
+            email: Email::new("alice@fleet.test".to_string()).unwrap(),
+            first_name: "Alice".to_string(),
+            last_name: "Tester".to_string(),
+        };
+        store.insert_user(user.clone());
+        let vehicle = MemoryVehicleRepository::new(store)
+            .create(
+                organization.id,
+                conformance::fixtures::new_vehicle(1, "123ABC02", "KZ"),
+            )
+            .await
+            .unwrap();
+        (user, vehicle)
+    }
+
+    async fn create_status(
+        statuses: &impl VehicleStatusRepository,
+        seeded: &(UserIdentity, VehicleIdentity),
+        odometer: i32,
+    ) -> VehicleStatusIdentity {
+        let at = conformance::fixtures::at(1);
+        statuses
+            .create(
+                seeded.0.organization_id,
+                VehicleStatusIdentity {
+                    id: 0,
+                    vehicle_id: seeded.1.id,
+                    performed_by: seeded.0.id,
+                    performed_at: at,
+                    odometer,
+                    engine_hour_meter: None,
+                    fuel_level: None,
+                    notes: String::new(),
+                    created_at: at,
+                    updated_at: at,
+                },
+            )
+            .await
+            .unwrap()
+    }
+
+    async fn odometers(store: &MemoryStore, seeded: &(UserIdentity, VehicleIdentity)) -> Vec<i32> {
+        MemoryVehicleStatusRepository::new(store)
+            .find_by_vehicle(seeded.0.organization_id, seeded.1.id, None, None)
+            .await
+            .unwrap()
+            .iter()
+            .map(|status| status.odometer)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_writes_are_visible_once_committed() {
+        let store = MemoryStore::new();
+        let seeded = seed(&store).await;
+        let transaction = MemoryUnitOfWork::new(&store)
+            .begin(seeded.0.organization_id)
+            .await
+            .unwrap();
+
+        create_status(transaction.vehicle_statuses(), &seeded, 10_000).await;
+        let latest = transaction
+            .vehicle_statuses()
+            .find_latest(seeded.0.organization_id, seeded.1.id)
+            .await
+            .unwrap();
+        assert_eq!(latest.map(|status| status.odometer), Some(10_000));
+        assert!(odometers(&store, &seeded).await.is_empty());
+
+        transaction.commit().await.unwrap();
+        assert_eq!(odometers(&store, &seeded).await, vec![10_000]);
+    }
+
+    #[tokio::test]
+    async fn test_rolled_back_or_dropped_writes_are_discarded() {
+        let store = MemoryStore::new();
+        let seeded = seed(&store).await;
+        let unit_of_work = MemoryUnitOfWork::new(&store);
+
+        let transaction = unit_of_work.begin(seeded.0.organization_id).await.unwrap();
+        create_status(transaction.vehicle_statuses(), &seeded, 10_000).await;
+        transaction.rollback().await.unwrap();
+
+        let transaction = unit_of_work.begin(seeded.0.organization_id).await.unwrap();
+        create_status(transaction.vehicle_statuses(), &seeded, 10_500).await;
+        drop(transaction);
+
+        assert!(odometers(&store, &seeded).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_nested_rollback_keeps_the_outer_writes() {
+        let store = MemoryStore::new();
+        let seeded = seed(&store).await;
+        let mut transaction = MemoryUnitOfWork::new(&store)
+            .begin(seeded.0.organization_id)
+            .await
+            .unwrap();
+        create_status(transaction.vehicle_statuses(), &seeded, 10_000).await;
+
+        let nested = transaction.begin().await.unwrap();
+        create_status(nested.vehicle_statuses(), &seeded, 10_500).await;
+        nested.rollback().await.unwrap();
+        let nested = transaction.begin().await.unwrap();
+        create_status(nested.vehicle_statuses(), &seeded, 11_000).await;
+        nested.commit().await.unwrap();
+        transaction.commit().await.unwrap();
+
+        assert_eq!(odometers(&store, &seeded).await, vec![10_000, 11_000]);
+        let latest = MemoryVehicleStatusRepository::new(&store)
+            .find_latest(seeded.0.organization_id, seeded.1.id)
+            .await
+            .unwrap();
+        assert_eq!(latest.map(|status| status.odometer), Some(11_000));
+    }
+
+    #[tokio::test]
+    async fn test_commit_after_a_concurrent_write_fails() {
+        let store = MemoryStore::new();
+        let seeded = seed(&store).await;
+        let transaction = MemoryUnitOfWork::new(&store)
+            .begin(seeded.0.organization_id)
+            .await
+            .unwrap();
+        create_status(transaction.vehicle_statuses(), &seeded, 10_500).await;
+
+        create_status(&MemoryVehicleStatusRepository::new(&store), &seeded, 10_000).await;
+
+        assert!(transaction.commit().await.is_err());
+        assert_eq!(odometers(&store, &seeded).await, vec![10_000]);
+    }
+
+    #[tokio::test]
+    async fn test_injected_fault_fails_the_commit() {
+        let store = MemoryStore::new();
+        let seeded = seed(&store).await;
+        let transaction = MemoryUnitOfWork::new(&store)
+            .begin(seeded.0.organization_id)
+            .await
+            .unwrap();
+        create_status(transaction.vehicle_statuses(), &seeded, 10_000).await;
+
+        store
+            .faults()
+            .fail_next("transactions.commit", "connection reset");
+
+        assert!(transaction.commit().await.is_err());
+        assert!(odometers(&store, &seeded).await.is_empty());
+    }
+}
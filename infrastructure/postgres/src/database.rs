@@ -0,0 +1,260 @@
+//! The connection pool of the PostgreSQL repositories, and the connection they run their
+//! statements on: the pool, or the connection of a transaction of the unit of work.
+use sqlx::{PgConnection, PgPool, Postgres, pool::PoolConnection, postgres::PgPoolOptions};
+use std::sync::{Arc, Mutex, PoisonError};
+use tokio::sync::{Mutex as AsyncMutex, OwnedMutexGuard};
+
+#[derive(Debug, thiserror::Error)]
+pub enum PostgresDatabaseError {
+    #[error("database error: {0}")]
+    Database(String),
+}
+
+/// Formats an error of the driver, for the `Database(String)` variants of the repository errors.
+pub(crate) fn database_error(error: impl std::fmt::Display) -> String {
+    error.to_string()
+}
+
+/// Whether the driver error is a violation of a unique key, for the `AlreadyExists` variants.
+pub(crate) fn is_unique_violation(error: &sqlx::Error) -> bool {
+    error
+        .as_database_error()
+        .is_some_and(|error| error.is_unique_violation())
+}
+
+/// Sets the organization the row-level security policies let a connection see, for its session
+/// or, `local`, until the end of its transaction.
+async fn set_organization(
+    connection: &mut PgConnection,
+    organization_id: uuid::Uuid,
+    local: bool,
+) -> Result<(), String> {
+    sqlx::query("SELECT set_config('app.organization_id', $1, $2)")
+        .bind(organization_id.to_string())
+        .bind(local)
+        .execute(connection)
+        .await
+        .map_err(database_error)?;
+    Ok(())
+}
+
+#[derive(Debug, Clone)]
+pub struct PostgresDatabase {
+    pool: PgPool,
+}
+
+impl PostgresDatabase {
+    /// Connects to the database at `url` (e.g., `postgres://fleet@localhost/fleet`) and migrates
+    /// it.
+    pub async fn connect(url: &str) -> Result<Self, PostgresDatabaseError> {
+        let pool = PgPoolOptions::new()
+            .connect(url)
+            .await
+            .map_err(|e| PostgresDatabaseError::Database(database_error(e)))?;
+        sqlx::migrate!("../../migrations")
+            .run(&pool)
+            .await
+            .map_err(|e| PostgresDatabaseError::Database(database_error(e)))?;
+        Ok(PostgresDatabase { pool })
+    }
+
+    pub fn pool(&self) -> &PgPool {
+        &self.pool
+    }
+}
+
+/// The connection of a transaction of the unit of work, shared by its repositories and by its
+/// nested transactions (savepoints).
+#[derive(Debug)]
+pub(crate) struct SharedTransaction {
+    /// `None` once the transaction is committed or rolled back.
+    connection: Arc<AsyncMutex<Option<PoolConnection<Postgres>>>>,
+    /// Statements ending the savepoints dropped without commit or rollback (a drop can't run a
+    /// statement), run before the next statement.
+    pending: Mutex<Vec<String>>,
+}
+
+impl SharedTransaction {
+    /// Starts a transaction of an organization on a connection of the pool.
+    pub(crate) async fn begin(
+        pool: &PgPool,
+        organization_id: uuid::Uuid,
+    ) -> Result<Arc<Self>, String> {
+        let mut connection = pool.acquire().await.map_err(database_error)?;
+        sqlx::query("BEGIN")
+            .execute(&mut *connection)
+            .await
+            .map_err(database_error)?;
+        set_organization(&mut connection, organization_id, true).await?;
+        Ok(Arc::new(SharedTransaction {
+            connection: Arc::new(AsyncMutex::new(Some(connection))),
+            pending: Mutex::new(Vec::new()),
+        }))
+    }
+
+    /// Runs a statement ending the transaction or one of its savepoints, after the pending ones.
+    /// `COMMIT` and `ROLLBACK` release the connection.
+    pub(crate) async fn run(&self, statement: &str) -> Result<(), String> {
+        let mut session = self.session().await?;
+        sqlx::query(statement)
+            .execute(session.connection())
+            .await
+            .map_err(database_error)?;
+        if (statement == "COMMIT" || statement == "ROLLBACK")
+            && let Session::Transaction(guard) = &mut session
+        {
+            guard.take();
+        }
+        Ok(())
+    }
+
+    /// Defers a statement to the next one of the transaction.
+    pub(crate) fn defer(&self, statement: String) {
+        self.pending
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(statement);
+    }
+
+    /// Ends the transaction when it's dropped without commit or rollback: a connection still in
+    /// the transaction is closed instead of going back to the pool, which rolls it back.
+    pub(crate) fn abandon(&self) {
+        if let Ok(mut guard) = self.connection.try_lock()
+            && let Some(mut connection) = guard.take()
+        {
+            connection.close_on_drop();
+        }
+    }
+
+    async fn session(&self) -> Result<Session, String> {
+        let mut guard = self.connection.clone().lock_owned().await;
+        let Some(connection) = guard.as_mut() else {
+            return Err("the transaction has ended".to_string());
+        };
+        let pending =
+            std::mem::take(&mut *self.pending.lock().unwrap_or_else(PoisonError::into_inner));
+        for statement in pending {
+            sqlx::query(&statement)
+                .execute(&mut **connection)
+                .await
+                .map_err(database_error)?;
+        }
+        Ok(Session::Transaction(guard))
+    }
+}
+
+impl Drop for SharedTransaction {
+    fn drop(&mut self) {
+        self.abandon();
+    }
+}
+
+/// Where the statements of a repository run.
+#[derive(Debug, Clone)]
+pub(crate) enum Connection {
+    Pool(PgPool),
+    Transaction(Arc<SharedTransaction>),
+}
+
+/// A connection to run statements on, held until dropped.
+pub(crate) enum Session {
+    Pool(PoolConnection<Postgres>),
+    Transaction(OwnedMutexGuard<Option<PoolConnection<Postgres>>>),
+}
+
+impl Session {
+    pub(crate) fn connection(&mut self) -> &mut PgConnection {
+        match self {
+            Session::Pool(connection) => connection,
+            // A session of an ended transaction is never returned
+            Session::Transaction(guard) => guard.as_mut().expect("transaction in progress"),
+        }
+    }
+}
+
+/// A session writing atomically: a transaction on the pool, a savepoint in a transaction of the
+/// unit of work (so a failed write doesn't abort it). Dropped without `commit`, the writes are
+/// rolled back.
+pub(crate) enum Write {
+    Pool(sqlx::Transaction<'static, Postgres>),
+    Savepoint(Session, Savepoint),
+}
+
+/// Rolls back the `uow_write` savepoint unless released.
+pub(crate) struct Savepoint {
+    transaction: Arc<SharedTransaction>,
+    released: bool,
+}
+
+impl Write {
+    pub(crate) fn connection(&mut self) -> &mut PgConnection {
+        match self {
+            Write::Pool(transaction) => transaction,
+            Write::Savepoint(session, _) => session.connection(),
+        }
+    }
+
+    pub(crate) async fn commit(self) -> Result<(), String> {
+        match self {
+            Write::Pool(transaction) => transaction.commit().await.map_err(database_error),
+            Write::Savepoint(mut session, mut savepoint) => {
+                sqlx::query("RELEASE SAVEPOINT uow_write")
+                    .execute(session.connection())
+                    .await
+                    .map_err(database_error)?;
+                savepoint.released = true;
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Drop for Savepoint {
+    fn drop(&mut self) {
+        // The transaction of the pool rolls back on drop
+        if !self.released {
+            self.transaction
+                .defer("ROLLBACK TO SAVEPOINT uow_write".to_string());
+            self.transaction
+                .defer("RELEASE SAVEPOINT uow_write".to_string());
+        }
+    }
+}
+
+impl Connection {
+    /// A session to read the rows of an organization. A transaction of the unit of work is already
+    /// set to its organization.
+    pub(crate) async fn session(&self, organization_id: uuid::Uuid) -> Result<Session, String> {
+        match self {
+            Connection::Pool(pool) => {
+                let mut connection = pool.acquire().await.map_err(database_error)?;
+                set_organization(&mut connection, organization_id, false).await?;
+                Ok(Session::Pool(connection))
+            }
+            Connection::Transaction(transaction) => transaction.session().await,
+        }
+    }
+
+    /// A session to write the rows of an organization atomically.
+    pub(crate) async fn write(&self, organization_id: uuid::Uuid) -> Result<Write, String> {
+        match self {
+            Connection::Pool(pool) => {
+                let mut transaction = pool.begin().await.map_err(database_error)?;
+                set_organization(&mut transaction, organization_id, true).await?;
+                Ok(Write::Pool(transaction))
+            }
+            Connection::Transaction(transaction) => {
+                let mut session = transaction.session().await?;
+                sqlx::query("SAVEPOINT uow_write")
+                    .execute(session.connection())
+                    .await
+                    .map_err(database_error)?;
+                let savepoint = Savepoint {
+                    transaction: transaction.clone(),
+                    released: false,
+                };
+                Ok(Write::Savepoint(session, savepoint))
+            }
+        }
+    }
+}
@@ -0,0 +1,307 @@
+//! Compiles the filter expressions of the list queries to parameterized SQL.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * Values are always bound as parameters (`$n`), only the whitelisted columns of the schema are
+//!   written in the SQL.
+//! * The parameters are numbered from `first_param`, so the condition can be appended to a query
+//!   that already has parameters; `SqlFilter::bind` binds `params` in order after them.
+//! * `!=` and `not in` keep the rows where the column is NULL (`IS DISTINCT FROM`), `~` is a
+//!   case-insensitive substring match with the LIKE wildcards of the value escaped.
+//! * Enum values are cast to the PostgreSQL enum of the field (`$1::engine_type`).
+use application::shared::filter_expression::{
+    filter_ast::{FilterCondition, FilterExpression, FilterOperand, FilterOperator, FilterValue},
+    filter_schema::FilterFieldKind,
+};
+use sqlx::{Postgres, postgres::PgArguments, query::Query};
+
+/// A parameter of a compiled filter, to bind with its SQL type.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SqlFilterParam {
+    Text(String),
+    Integer(i64),
+    Decimal(rust_decimal::Decimal),
+    Timestamp(chrono::DateTime<chrono::Utc>),
+    Uuid(uuid::Uuid),
+    TextList(Vec<String>),
+    IntegerList(Vec<i64>),
+    DecimalList(Vec<rust_decimal::Decimal>),
+    TimestampList(Vec<chrono::DateTime<chrono::Utc>>),
+    UuidList(Vec<uuid::Uuid>),
+}
+
+/// A condition for a `WHERE` clause with its parameters.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SqlFilter {
+    pub sql: String,
+    pub params: Vec<SqlFilterParam>,
+}
+
+/// Compiles an expression, numbering its parameters from `first_param`.
+pub fn compile(expression: &FilterExpression, first_param: usize) -> SqlFilter {
+    let mut compiler = Compiler {
+        params: Vec::new(),
+        first_param,
+    };
+    let sql = compiler.expression(expression);
+    SqlFilter {
+        sql,
+        params: compiler.params,
+    }
+}
+
+impl SqlFilter {
+    /// Binds the parameters of the filter to a query, after its own ones.
+    pub fn bind<'q>(
+        &'q self,
+        query: Query<'q, Postgres, PgArguments>,
+    ) -> Query<'q, Postgres, PgArguments> {
+        self.params.iter().fold(query, |query, param| match param {
+            SqlFilterParam::Text(text) => query.bind(text),
+            SqlFilterParam::Integer(integer) => query.bind(integer),
+            SqlFilterParam::Decimal(decimal) => query.bind(decimal),
+            SqlFilterParam::Timestamp(timestamp) => query.bind(timestamp),
+            SqlFilterParam::Uuid(uuid) => query.bind(uuid),
+            SqlFilterParam::TextList(texts) => query.bind(texts),
+            SqlFilterParam::IntegerList(integers) => query.bind(integers),
+            SqlFilterParam::DecimalList(decimals) => query.bind(decimals),
+            SqlFilterParam::TimestampList(timestamps) => query.bind(timestamps),
+            SqlFilterParam::UuidList(uuids) => query.bind(uuids),
+        })
+    }
+}
+
+struct Compiler {
+    params: Vec<SqlFilterParam>,
+    first_param: usize,
+}
+
+impl Compiler {
+    fn expression(&mut self, expression: &FilterExpression) -> String {
+        match expression {
+            FilterExpression::Condition(condition) => self.condition(condition),
+            FilterExpression::And(left, right) => {
+                format!("({} AND {})", self.expression(left), self.expression(right))
+            }
+            FilterExpression::Or(left, right) => {
+                format!("({} OR {})", self.expression(left), self.expression(right))
+            }
+            FilterExpression::Not(expression) => format!("NOT {}", self.expression(expression)),
+        }
+    }
+
+    /// Adds a parameter and returns its placeholder, cast to the enum of the field if any.
+    fn bind(&mut self, param: SqlFilterParam, kind: &FilterFieldKind) -> String {
+        let is_list = matches!(
+            param,
+            SqlFilterParam::TextList(_)
+                | SqlFilterParam::IntegerList(_)
+                | SqlFilterParam::DecimalList(_)
+                | SqlFilterParam::TimestampList(_)
+        );
+        self.params.push(param);
+        let placeholder = format!("${}", self.first_param + self.params.len() - 1);
+
+        match kind {
+            FilterFieldKind::Enum { sql_type, .. } if is_list => {
+                format!("{}::{}[]", placeholder, sql_type)
+            }
+            FilterFieldKind::Enum { sql_type, .. } => format!("{}::{}", placeholder, sql_type),
+            _ => placeholder,
+        }
+    }
+
+    fn condition(&mut self, condition: &FilterCondition) -> String {
+        let column = condition.field.column;
+        let kind = &condition.field.kind;
+
+        match (&condition.operator, &condition.operand) {
+            (FilterOperator::Contains, FilterOperand::Value(value)) => {
+                let pattern = format!("%{}%", escape_like(&text_of(value)));
+                let placeholder = self.bind(SqlFilterParam::Text(pattern), kind);
+                format!("{} ILIKE {} ESCAPE '\\'", column, placeholder)
+            }
+            (FilterOperator::In, FilterOperand::List(values)) => {
+                let placeholder = self.bind(list_param(values), kind);
+                format!("{} = ANY({})", column, placeholder)
+            }
+            (FilterOperator::NotIn, FilterOperand::List(values)) => {
+                let placeholder = self.bind(list_param(values), kind);
+                format!("({} IS NULL OR {} <> ALL({}))", column, column, placeholder)
+            }
+            (operator, FilterOperand::Value(value)) => {
+                let placeholder = self.bind(param(value), kind);
+                let operator = match operator {
+                    FilterOperator::Ne => "IS DISTINCT FROM",
+                    FilterOperator::Lt => "<",
+                    FilterOperator::Le => "<=",
+                    FilterOperator::Gt => ">",
+                    FilterOperator::Ge => ">=",
+                    _ => "=",
+                };
+                format!("{} {} {}", column, operator, placeholder)
+            }
+            // The parser always gives a list to `in` and `not in` and a value to the others
+            (_, FilterOperand::List(values)) => {
+                let placeholder = self.bind(list_param(values), kind);
+                format!("{} = ANY({})", column, placeholder)
+            }
+        }
+    }
+}
+
+fn text_of(value: &FilterValue) -> String {
+    match value {
+        FilterValue::Text(text) | FilterValue::Enum(text) => text.clone(),
+        FilterValue::Integer(integer) => integer.to_string(),
+        FilterValue::Decimal(decimal) => decimal.to_string(),
+        FilterValue::Timestamp(timestamp) => timestamp.to_rfc3339(),
+    }
+}
+
+fn param(value: &FilterValue) -> SqlFilterParam {
+    match value {
+        FilterValue::Text(text) | FilterValue::Enum(text) => SqlFilterParam::Text(text.clone()),
+        FilterValue::Integer(integer) => SqlFilterParam::Integer(*integer),
+        FilterValue::Decimal(decimal) => SqlFilterParam::Decimal(*decimal),
+        FilterValue::Timestamp(timestamp) => SqlFilterParam::Timestamp(*timestamp),
+    }
+}
+
+/// The values of a list share the type of their field.
+fn list_param(values: &[FilterValue]) -> SqlFilterParam {
+    match values.first() {
+        Some(FilterValue::Integer(_)) => SqlFilterParam::IntegerList(
+            values
+                .iter()
+                .filter_map(|value| match value {
+                    FilterValue::Integer(integer) => Some(*integer),
+                    _ => None,
+                })
+                .collect(),
+        ),
+        Some(FilterValue::Decimal(_)) => SqlFilterParam::DecimalList(
+            values
+                .iter()
+                .filter_map(|value| match value {
+                    FilterValue::Decimal(decimal) => Some(*decimal),
+                    _ => None,
+                })
+                .collect(),
+        ),
+        Some(FilterValue::Timestamp(_)) => SqlFilterParam::TimestampList(
+            values
+                .iter()
+                .filter_map(|value| match value {
+                    FilterValue::Timestamp(timestamp) => Some(*timestamp),
+                    _ => None,
+                })
+                .collect(),
+        ),
+        _ => SqlFilterParam::TextList(values.iter().map(text_of).collect()),
+    }
+}
+
+fn escape_like(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use application::{
+        shared::filter_expression::filter_parser::FilterExpressionError,
+        vehicle::filters::vehicle_filter::VEHICLE_FILTER_SCHEMA,
+    };
+
+    fn compile_vehicle(input: &str) -> SqlFilter {
+        compile(
+            &FilterExpression::parse(input, &VEHICLE_FILTER_SCHEMA).unwrap(),
+            1,
+        )
+    }
+
+    #[test]
+    fn test_compile_conjunction() {
+        let filter =
+            compile_vehicle(r#"year>=2018 and engine_type in (diesel,electric) and make~"toy""#);
+
+        assert_eq!(
+            filter.sql,
+            "((year >= $1 AND engine_type = ANY($2::engine_type[])) AND make ILIKE $3 ESCAPE '\\')"
+        );
+        assert_eq!(
+            filter.params,
+            [
+                SqlFilterParam::Integer(2018),
+                SqlFilterParam::TextList(vec!["Diesel".to_string(), "Electric".to_string()]),
+                SqlFilterParam::Text("%toy%".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compile_precedence_and_negation() {
+        let filter = compile(
+            &FilterExpression::parse(
+                "country = KZ or not (model != Camry and created_at < 2025-01-31)",
+                &VEHICLE_FILTER_SCHEMA,
+            )
+            .unwrap(),
+            3,
+        );
+
+        assert_eq!(
+            filter.sql,
+            "(country = $3 OR NOT (model IS DISTINCT FROM $4 AND created_at < $5))"
+        );
+        assert_eq!(filter.params.len(), 3);
+    }
+
+    #[test]
+    fn test_values_are_never_inlined() {
+        let filter = compile_vehicle(r#"make = "x' OR 1=1 --" or model ~ "50%_off\\""#);
+
+        assert_eq!(filter.sql, "(make = $1 OR model ILIKE $2 ESCAPE '\\')");
+        assert_eq!(
+            filter.params,
+            [
+                SqlFilterParam::Text("x' OR 1=1 --".to_string()),
+                SqlFilterParam::Text("%50\\%\\_off\\\\%".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rejects_invalid_filters() {
+        let parse = |input| FilterExpression::parse(input, &VEHICLE_FILTER_SCHEMA);
+
+        assert!(matches!(
+            parse("id = 1"),
+            Err(FilterExpressionError::UnknownField { .. })
+        ));
+        assert!(matches!(
+            parse("year ~ 20"),
+            Err(FilterExpressionError::UnsupportedOperator { .. })
+        ));
+        assert!(matches!(
+            parse("year = recent"),
+            Err(FilterExpressionError::InvalidValue { .. })
+        ));
+        assert!(matches!(
+            parse("engine_type = steam"),
+            Err(FilterExpressionError::InvalidValue { .. })
+        ));
+        assert!(matches!(
+            parse("(year = 2020"),
+            Err(FilterExpressionError::Syntax { .. })
+        ));
+        assert!(matches!(
+            parse("year = 2020 make = Toyota"),
+            Err(FilterExpressionError::Syntax { .. })
+        ));
+        assert!(matches!(parse("  "), Err(FilterExpressionError::Empty)));
+    }
+}
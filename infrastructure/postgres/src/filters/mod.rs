@@ -0,0 +1,2 @@
+pub mod filter_expression_sql;
+pub mod vehicle_filter_sql;
@@ -0,0 +1,169 @@
+//! Compiles a `VehicleFilter` to a parameterized condition on the `vehicles` table.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * The condition is on the columns of `vehicles`, for a query on the table not aliased (e.g.
+//!   `vehicle_id IN (SELECT uuid FROM vehicles WHERE ..)`); the filter expression is compiled by
+//!   `filter_expression_sql` in the same numbering of the parameters.
+//! * The home depot of a vehicle is the depot of its latest movement: a vehicle without one
+//!   doesn't match a depot, a region or a depot scope.
+//! * `search` matches like the vehicle hits of the search repository: the words of the term on
+//!   `search_vector`, or a make and model, license plate or VIN similar to it.
+//! * The paging and sorting of the filter are left to the caller.
+use super::filter_expression_sql::{self, SqlFilter, SqlFilterParam};
+use application::vehicle::filters::vehicle_filter::VehicleFilter;
+use domain::vehicle::value_types::engine_type::EngineType;
+
+pub(crate) const HOME_DEPOT: &str = "(SELECT movement.to_depot_id FROM vehicle_movements movement
+    WHERE movement.vehicle_id = vehicles.uuid AND movement.latest)";
+
+/// Compiles a filter, numbering its parameters from `first_param`. A filter without conditions
+/// compiles to `TRUE`.
+pub fn compile(filter: &VehicleFilter, first_param: usize) -> SqlFilter {
+    let mut conditions = Vec::new();
+    let mut params = Vec::new();
+    let bind = |params: &mut Vec<SqlFilterParam>, param| {
+        params.push(param);
+        format!("${}", first_param + params.len() - 1)
+    };
+
+    if let Some(uuid) = filter.uuid {
+        conditions.push(format!(
+            "uuid = {}",
+            bind(&mut params, SqlFilterParam::Uuid(uuid))
+        ));
+    }
+    for (column, value) in [
+        ("make", filter.make.clone()),
+        ("model", filter.model.clone()),
+        (
+            "vin",
+            filter.vin.as_ref().map(|vin| vin.value().to_string()),
+        ),
+        (
+            "license_plate",
+            filter
+                .license_plate
+                .as_ref()
+                .map(|plate| plate.value().to_string()),
+        ),
+        (
+            "country",
+            filter
+                .country
+                .as_ref()
+                .map(|country| country.value().to_string()),
+        ),
+    ] {
+        if let Some(value) = value {
+            let placeholder = bind(&mut params, SqlFilterParam::Text(value));
+            conditions.push(format!("{} = {}", column, placeholder));
+        }
+    }
+    if let Some(year) = filter.year {
+        let placeholder = bind(&mut params, SqlFilterParam::Integer(i64::from(year)));
+        conditions.push(format!("year = {}", placeholder));
+    }
+    if let Some(engine_type) = &filter.engine_type {
+        let placeholder = bind(
+            &mut params,
+            SqlFilterParam::Text(engine_type.as_sql().to_string()),
+        );
+        conditions.push(match engine_type {
+            EngineType::Other(name) => {
+                let name = bind(&mut params, SqlFilterParam::Text(name.clone()));
+                format!(
+                    "(engine_type = {}::engine_type AND engine_type_other = {})",
+                    placeholder, name
+                )
+            }
+            _ => format!("engine_type = {}::engine_type", placeholder),
+        });
+    }
+    if let Some(depot_id) = filter.depot_id {
+        let placeholder = bind(&mut params, SqlFilterParam::Uuid(depot_id));
+        conditions.push(format!("{} = {}", HOME_DEPOT, placeholder));
+    }
+    if let Some(region_id) = filter.region_id {
+        let placeholder = bind(&mut params, SqlFilterParam::Uuid(region_id));
+        conditions.push(format!(
+            "{} IN (SELECT depot.id FROM depots depot WHERE depot.region_id = {})",
+            HOME_DEPOT, placeholder
+        ));
+    }
+    if let Some(scope) = &filter.depot_scope {
+        let placeholder = bind(&mut params, SqlFilterParam::UuidList(scope.clone()));
+        conditions.push(format!("{} = ANY({})", HOME_DEPOT, placeholder));
+    }
+    if let Some(search) = &filter.search {
+        let term = bind(&mut params, SqlFilterParam::Text(search.clone()));
+        conditions.push(format!(
+            "(search_vector @@ websearch_to_tsquery('simple', {term}) \
+             OR (make || ' ' || model) % {term} OR license_plate % {term} OR vin % {term})"
+        ));
+    }
+    if let Some(expression) = &filter.expression {
+        let compiled = filter_expression_sql::compile(expression, first_param + params.len());
+        conditions.push(compiled.sql);
+        params.extend(compiled.params);
+    }
+
+    SqlFilter {
+        sql: match conditions.is_empty() {
+            true => "TRUE".to_string(),
+            false => conditions.join(" AND "),
+        },
+        params,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use application::{
+        shared::filter_expression::filter_ast::FilterExpression,
+        vehicle::filters::vehicle_filter::{NewVehicleFilter, VEHICLE_FILTER_SCHEMA},
+    };
+
+    #[test]
+    fn test_compile_empty_filter() {
+        let filter = compile(&VehicleFilter::new(NewVehicleFilter::default()), 1);
+
+        assert_eq!(filter.sql, "TRUE");
+        assert!(filter.params.is_empty());
+    }
+
+    #[test]
+    fn test_compile_fields_scope_and_expression() {
+        let depot_id = uuid::Uuid::new_v4();
+        let filter = VehicleFilter {
+            make: Some("Toyota".to_string()),
+            engine_type: Some(EngineType::Other("Steam".to_string())),
+            depot_scope: Some(vec![depot_id]),
+            expression: Some(
+                FilterExpression::parse("year >= 2018", &VEHICLE_FILTER_SCHEMA).unwrap(),
+            ),
+            ..VehicleFilter::new(NewVehicleFilter::default())
+        };
+        let filter = compile(&filter, 3);
+
+        assert_eq!(
+            filter.sql,
+            format!(
+                "make = $3 AND (engine_type = $4::engine_type AND engine_type_other = $5) \
+                 AND {} = ANY($6) AND year >= $7",
+                HOME_DEPOT
+            )
+        );
+        assert_eq!(
+            filter.params,
+            [
+                SqlFilterParam::Text("Toyota".to_string()),
+                SqlFilterParam::Text("Other".to_string()),
+                SqlFilterParam::Text("Steam".to_string()),
+                SqlFilterParam::UuidList(vec![depot_id]),
+                SqlFilterParam::Integer(2018),
+            ]
+        );
+    }
+}
@@ -1,3 +1,25 @@
+//! PostgreSQL implementation of the repository traits.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * A repository runs its statements on the pool, or on the connection of a transaction of the
+//!   unit of work (`PostgresUnitOfWork`); a write of several statements is atomic either way (a
+//!   transaction, or a savepoint of the transaction of the unit of work).
+//! * A nested transaction of the unit of work is a savepoint. A transaction dropped without
+//!   commit or rollback is rolled back: a savepoint before the next statement, the transaction by
+//!   closing its connection.
+//! * Every row belongs to an organization, and row-level security (forced on the owner of the
+//!   tables) hides the rows of the other ones: a session of the pool is set to the organization of
+//!   the call (`app.organization_id`), a transaction to the organization it began with. The
+//!   queries filter on the organization too, which lets them use the indexes.
+//! * The schema is `migrations/`, applied by `PostgresDatabase::connect`. A migration reading or
+//!   writing data sets `app.organization_id`, or runs as a role with `BYPASSRLS`.
+pub mod database;
+pub mod filters;
+pub mod mappers;
+pub mod repositories;
+pub mod unit_of_work;
+
 pub fn add(left: u64, right: u64) -> u64 {
     left + right
 }
@@ -0,0 +1,23 @@
+use super::{get, get_unsigned, interval_type_from_sql};
+use domain::maintenance::entities::maintenance::MaintenanceIdentity;
+use sqlx::postgres::PgRow;
+
+pub(crate) const MAINTENANCE_COLUMNS: &str = "id, vehicle_id, maintenance_type_id, \
+     interval_type::text AS interval_type, interval_value, red_threshold, yellow_threshold, \
+     created_at, created_by, updated_at, updated_by";
+
+pub(crate) fn maintenance_from_row(row: &PgRow) -> Result<MaintenanceIdentity, String> {
+    Ok(MaintenanceIdentity {
+        id: get(row, "id")?,
+        vehicle_id: get(row, "vehicle_id")?,
+        maintenance_type_id: get(row, "maintenance_type_id")?,
+        interval_type: interval_type_from_sql(&get::<String>(row, "interval_type")?)?,
+        interval_value: get_unsigned(row, "interval_value")?,
+        red_threshold: get_unsigned(row, "red_threshold")?,
+        yellow_threshold: get_unsigned(row, "yellow_threshold")?,
+        created_at: get(row, "created_at")?,
+        created_by: get(row, "created_by")?,
+        updated_at: get(row, "updated_at")?,
+        updated_by: get(row, "updated_by")?,
+    })
+}
@@ -0,0 +1,67 @@
+use super::get;
+use domain::maintenance::{
+    entities::{
+        maintenance_cost::{MaintenanceCost, MaintenanceCostLine, MaintenanceCostLineKind},
+        maintenance_record::MaintenanceRecordIdentity,
+    },
+    value_types::{currency::Currency, money::Money},
+};
+use sqlx::postgres::PgRow;
+
+pub(crate) const MAINTENANCE_RECORD_COLUMNS: &str = "id, vehicle_id, maintenance_id, performed_by, \
+     vehicle_status_id, performed_at, details, currency, invoice_reference, created_at, \
+     created_by, updated_at, updated_by";
+
+pub(crate) const COST_LINE_COLUMNS: &str =
+    "kind::text AS kind, description, part_number, quantity, unit_price";
+
+pub(crate) fn cost_line_from_row(
+    row: &PgRow,
+    currency: Currency,
+) -> Result<MaintenanceCostLine, String> {
+    Ok(MaintenanceCostLine {
+        kind: get::<String>(row, "kind")?.parse::<MaintenanceCostLineKind>()?,
+        description: get(row, "description")?,
+        part_number: get(row, "part_number")?,
+        quantity: get(row, "quantity")?,
+        unit_price: Money::new(get(row, "unit_price")?, currency),
+    })
+}
+
+/// Reads a record, `cost_lines` reads the cost lines of its currency (if the cost is known).
+pub(crate) fn maintenance_record_from_row(
+    row: &PgRow,
+    cost_lines: impl FnOnce(Currency) -> Result<Vec<MaintenanceCostLine>, String>,
+) -> Result<MaintenanceRecordIdentity, String> {
+    let cost = match get::<Option<String>>(row, "currency")? {
+        Some(currency) => {
+            let currency = Currency::new(currency).map_err(|e| e.to_string())?;
+            Some(
+                MaintenanceCost::new(
+                    currency,
+                    cost_lines(currency)?,
+                    get(row, "invoice_reference")?,
+                )
+                .map_err(|e| e.to_string())?,
+            )
+        }
+        None => None,
+    };
+    let maintenance_id = get::<Option<i32>>(row, "maintenance_id")?
+        .ok_or_else(|| "maintenance record without a maintenance".to_string())?;
+
+    Ok(MaintenanceRecordIdentity {
+        id: get(row, "id")?,
+        vehicle_id: get(row, "vehicle_id")?,
+        maintenance_id,
+        user_id: get(row, "performed_by")?,
+        vehicle_status_id: get(row, "vehicle_status_id")?,
+        performed_at: get(row, "performed_at")?,
+        details: get(row, "details")?,
+        cost,
+        created_at: get(row, "created_at")?,
+        created_by: get(row, "created_by")?,
+        updated_at: get(row, "updated_at")?,
+        updated_by: get(row, "updated_by")?,
+    })
+}
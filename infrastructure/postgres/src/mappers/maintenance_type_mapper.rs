@@ -0,0 +1,48 @@
+use super::{get, user_mapper::user_from_row};
+use domain::maintenance::{
+    entities::maintenance_type::{MaintenanceType, MaintenanceTypeView},
+    value_types::{
+        maintenance_applicability::MaintenanceApplicability,
+        maintenance_category::MaintenanceCategory,
+    },
+};
+use sqlx::postgres::PgRow;
+
+pub(crate) const MAINTENANCE_TYPE_COLUMNS: &str = "mt.id, mt.name, mt.description, \
+     mt.category::text AS category, mt.applicability::text AS applicability, mt.checklist, \
+     mt.deprecated_at, mt.created_at, mt.updated_at";
+
+pub(crate) fn maintenance_type_from_row(row: &PgRow) -> Result<MaintenanceType, String> {
+    let mut maintenance_type = MaintenanceType::new(
+        get(row, "name")?,
+        get(row, "description")?,
+        get::<Option<String>>(row, "category")?
+            .as_deref()
+            .map(str::parse::<MaintenanceCategory>)
+            .transpose()?,
+        get::<String>(row, "applicability")?.parse::<MaintenanceApplicability>()?,
+    )
+    .map_err(|e| e.to_string())?;
+    maintenance_type.set_id(get(row, "id")?);
+    maintenance_type.set_checklist(get(row, "checklist")?);
+    maintenance_type.set_deprecated_at(get(row, "deprecated_at")?);
+    Ok(maintenance_type)
+}
+
+/// Reads a maintenance type joined with its `created_by_` and `updated_by_` users.
+pub(crate) fn maintenance_type_view_from_row(row: &PgRow) -> Result<MaintenanceTypeView, String> {
+    let maintenance_type = maintenance_type_from_row(row)?;
+    Ok(MaintenanceTypeView {
+        id: maintenance_type.id(),
+        name: maintenance_type.name().to_string(),
+        description: maintenance_type.description().to_string(),
+        category: maintenance_type.category(),
+        applicability: maintenance_type.applicability(),
+        checklist: maintenance_type.checklist().to_vec(),
+        deprecated_at: maintenance_type.deprecated_at(),
+        created_at: get(row, "created_at")?,
+        created_by: user_from_row(row, "created_by_")?,
+        updated_at: get(row, "updated_at")?,
+        updated_by: user_from_row(row, "updated_by_")?,
+    })
+}
@@ -0,0 +1,45 @@
+//! Conversions between PostgreSQL rows and domain entities.
+//!
+//! Enum columns are selected as text (`column::text AS column`) and bound with a cast
+//! (`$1::enum_type`), so the driver needs no type per enum.
+use crate::database::database_error;
+use domain::maintenance::value_types::maintenance_interval_type::MaintenanceIntervalType;
+use sqlx::{Decode, Postgres, Row, Type, postgres::PgRow};
+
+pub mod maintenance_mapper;
+pub mod maintenance_record_mapper;
+pub mod maintenance_type_mapper;
+pub mod user_mapper;
+pub mod vehicle_mapper;
+pub mod vehicle_status_mapper;
+
+/// Reads a column of a row.
+pub(crate) fn get<'r, T>(row: &'r PgRow, column: &str) -> Result<T, String>
+where
+    T: Decode<'r, Postgres> + Type<Postgres>,
+{
+    row.try_get(column).map_err(database_error)
+}
+
+/// Reads an `INTEGER` column of an unsigned value.
+pub(crate) fn get_unsigned(row: &PgRow, column: &str) -> Result<u32, String> {
+    let value: i32 = get(row, column)?;
+    u32::try_from(value).map_err(|_| format!("negative {}: {}", column, value))
+}
+
+pub(crate) fn interval_type_to_sql(interval_type: &MaintenanceIntervalType) -> &'static str {
+    match interval_type {
+        MaintenanceIntervalType::Kilometers => "Kilometers",
+        MaintenanceIntervalType::EngineHours => "EngineHours",
+        MaintenanceIntervalType::Years => "Years",
+    }
+}
+
+pub(crate) fn interval_type_from_sql(value: &str) -> Result<MaintenanceIntervalType, String> {
+    match value {
+        "Kilometers" => Ok(MaintenanceIntervalType::Kilometers),
+        "EngineHours" => Ok(MaintenanceIntervalType::EngineHours),
+        "Years" => Ok(MaintenanceIntervalType::Years),
+        _ => Err(format!("invalid interval type: {}", value)),
+    }
+}
@@ -0,0 +1,32 @@
+use super::get;
+use domain::user::{
+    entities::user::UserIdentity,
+    value_types::{Email, UserId},
+};
+use sqlx::postgres::PgRow;
+
+pub(crate) const USER_COLUMNS: &str =
+    "uuid, organization_id, username, email, first_name, last_name";
+
+/// Selects the columns of a joined user `alias`, prefixed with `prefix`.
+pub(crate) fn user_columns(alias: &str, prefix: &str) -> String {
+    USER_COLUMNS
+        .split(", ")
+        .map(|column| format!("{alias}.{column} AS {prefix}{column}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Reads the user columns, selected with `USER_COLUMNS` (no prefix) or `user_columns`.
+pub(crate) fn user_from_row(row: &PgRow, prefix: &str) -> Result<UserIdentity, String> {
+    let id = get(row, &format!("{prefix}uuid"))?;
+    Ok(UserIdentity {
+        id,
+        uuid: UserId::new(id),
+        organization_id: get(row, &format!("{prefix}organization_id"))?,
+        username: get(row, &format!("{prefix}username"))?,
+        email: Email::new(get(row, &format!("{prefix}email"))?)?,
+        first_name: get(row, &format!("{prefix}first_name"))?,
+        last_name: get(row, &format!("{prefix}last_name"))?,
+    })
+}
@@ -0,0 +1,69 @@
+use super::get;
+use application::vehicle::models::vehicle::VehicleView;
+use domain::vehicle::{
+    entities::vehicle::VehicleIdentity,
+    value_types::{
+        country_code::CountryCode, engine_type::EngineType, license_plate::LicensePlate,
+        powertrain::Powertrain, vehicle_vin::VehicleVin,
+    },
+};
+use sqlx::postgres::PgRow;
+
+pub(crate) const VEHICLE_COLUMNS: &str = "uuid, make, model, year, vin, vin_legacy, license_plate, \
+     country, engine_type::text AS engine_type, engine_type_other, battery_capacity, \
+     tank_capacity, created_at, updated_at";
+
+pub(crate) fn vehicle_from_row(row: &PgRow) -> Result<VehicleIdentity, String> {
+    let vin: String = get(row, "vin")?;
+    let vin = match get::<bool>(row, "vin_legacy")? {
+        true => VehicleVin::new_legacy(vin),
+        false => VehicleVin::new(vin),
+    }
+    .map_err(|e| e.to_string())?;
+    let engine_type = EngineType::from_sql(
+        &get::<String>(row, "engine_type")?,
+        get::<Option<String>>(row, "engine_type_other")?.as_deref(),
+    )
+    .map_err(|e| e.to_string())?;
+    let year: i16 = get(row, "year")?;
+
+    Ok(VehicleIdentity {
+        id: get(row, "uuid")?,
+        make: get(row, "make")?,
+        model: get(row, "model")?,
+        year: u16::try_from(year).map_err(|_| format!("negative year: {}", year))?,
+        vin,
+        license_plate: LicensePlate::new(get::<String>(row, "license_plate")?)
+            .map_err(|e| e.to_string())?,
+        country: CountryCode::new(get::<String>(row, "country")?).map_err(|e| e.to_string())?,
+        powertrain: Powertrain::new(
+            engine_type,
+            get(row, "battery_capacity")?,
+            get(row, "tank_capacity")?,
+        )
+        .map_err(|e| e.to_string())?,
+        created_at: get(row, "created_at")?,
+        updated_at: get(row, "updated_at")?,
+    })
+}
+
+/// Reads a vehicle with its `home_depot_id` and `search_rank`.
+pub(crate) fn vehicle_view_from_row(row: &PgRow) -> Result<VehicleView, String> {
+    let vehicle = vehicle_from_row(row)?;
+    Ok(VehicleView {
+        id: vehicle.id.to_string(),
+        make: vehicle.make,
+        model: vehicle.model,
+        year: vehicle.year,
+        vin: vehicle.vin.value().to_string(),
+        license_plate: vehicle.license_plate.value().to_string(),
+        country: vehicle.country.value().to_string(),
+        engine_type: vehicle.powertrain.engine_type().as_str().to_string(),
+        battery_capacity: vehicle.powertrain.battery_capacity(),
+        tank_capacity: vehicle.powertrain.tank_capacity(),
+        home_depot_id: get(row, "home_depot_id")?,
+        search_rank: get(row, "search_rank")?,
+        created_at: vehicle.created_at,
+        updated_at: vehicle.updated_at,
+    })
+}
@@ -0,0 +1,23 @@
+use super::get;
+use domain::vehicle::entities::vehicle_status::VehicleStatusIdentity;
+use sqlx::postgres::PgRow;
+
+pub(crate) const VEHICLE_STATUS_COLUMNS: &str = "id, vehicle_id, performed_by, odometer, \
+     engine_hour_meter, fuel_level, notes, created_at, updated_at";
+
+/// The table has no `performed_at` column, a status is performed when it is created.
+pub(crate) fn vehicle_status_from_row(row: &PgRow) -> Result<VehicleStatusIdentity, String> {
+    let created_at = get(row, "created_at")?;
+    Ok(VehicleStatusIdentity {
+        id: get(row, "id")?,
+        vehicle_id: get(row, "vehicle_id")?,
+        performed_by: get(row, "performed_by")?,
+        performed_at: created_at,
+        odometer: get::<Option<i32>>(row, "odometer")?.unwrap_or_default(),
+        engine_hour_meter: get(row, "engine_hour_meter")?,
+        fuel_level: get(row, "fuel_level")?,
+        notes: get::<Option<String>>(row, "notes")?.unwrap_or_default(),
+        created_at,
+        updated_at: get(row, "updated_at")?,
+    })
+}
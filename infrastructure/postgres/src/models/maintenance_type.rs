@@ -8,8 +8,37 @@ pub struct MaintenanceType {
     pub name: String,
     /// Description of the maintenance type.
     pub description: String,
+    /// The category of the maintenance type (e.g., Engine, Brakes).
+    pub category: Option<MaintenanceCategory>,
+    /// The powertrains the maintenance type applies to.
+    pub applicability: MaintenanceApplicability,
+    /// Steps of the standard procedure.
+    pub checklist: Vec<String>,
+    /// When the maintenance type was deprecated, if it was.
+    pub deprecated_at: Option<chrono::DateTime<chrono::Utc>>,
     /// Created at timestamp.
     pub created_at: chrono::DateTime<chrono::Utc>,
     /// Updated at timestamp.
     pub updated_at: chrono::DateTime<chrono::Utc>,
 }
+
+/// Represents the powertrains a maintenance type applies to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MaintenanceApplicability {
+    Any,
+    CombustionEngine,
+    ElectricDrive,
+    GasSystem,
+    FuelCell,
+}
+
+/// Represents the category of a maintenance type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MaintenanceCategory {
+    Engine,
+    Brakes,
+    Tires,
+    Body,
+    Electrical,
+    Inspection,
+}
@@ -16,8 +16,12 @@ pub struct Vehicle {
     pub vin: String,
     /// License plate number of the vehicle.
     pub license_plate: String,
+    /// Country the vehicle is registered in, license plates are unique per country.
+    pub country: String,
     /// The engine type of the vehicle (e.g., Gasoline, Diesel, Electric).
     pub engine_type: EngineType,
+    /// Name of the engine type when `engine_type` is `Other`.
+    pub engine_type_other: Option<String>,
     /// How the maintenance should be tracked: kilometer based or hour based.
     pub tracking_type: TrackingType,
     /// Initial odometer reading of the vehicle.
@@ -43,6 +47,12 @@ pub enum EngineType {
     Gasoline,
     Diesel,
     Electric,
+    Hybrid,
+    PluginHybrid,
+    Cng,
+    Lpg,
+    Hydrogen,
+    Other,
 }
 
 impl Vehicle {
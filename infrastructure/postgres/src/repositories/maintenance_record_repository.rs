@@ -0,0 +1,444 @@
+use crate::{
+    database::{Connection, PostgresDatabase, database_error},
+    filters::vehicle_filter_sql,
+    mappers::{
+        get,
+        maintenance_record_mapper::{
+            COST_LINE_COLUMNS, MAINTENANCE_RECORD_COLUMNS, cost_line_from_row,
+            maintenance_record_from_row,
+        },
+        user_mapper::{USER_COLUMNS, user_from_row},
+    },
+    repositories::{
+        maintenance_repository::find_maintenance, vehicle_repository::find_vehicle,
+        vehicle_status_repository::find_vehicle_status,
+    },
+};
+use application::{
+    maintenance::{
+        models::maintenance_cost_sum::{
+            MaintenanceCostGroupBy, MaintenanceCostGroupKey, MaintenanceCostSum,
+            MaintenanceCostSums, VehicleDistance,
+        },
+        traits::maintenance_cost_repository::{
+            MaintenanceCostApplicationRepository, MaintenanceCostApplicationRepositoryError,
+        },
+    },
+    vehicle::filters::vehicle_filter::VehicleFilter,
+};
+use domain::{
+    maintenance::{
+        entities::{
+            maintenance_cost::MaintenanceCostBreakdown,
+            maintenance_record::{MaintenanceRecord, MaintenanceRecordIdentity},
+        },
+        repositories::maintenance_record_repository::{
+            MaintenanceRecordRepository, MaintenanceRecordRepositoryError,
+        },
+        services::maintenance_cost_service::MaintenanceCostTotals,
+        value_types::{currency::Currency, money::Money},
+    },
+    user::entities::user::UserIdentity,
+};
+use rust_decimal::Decimal;
+use sqlx::{PgConnection, postgres::PgRow};
+
+/// The records of the organization ($1) performed within the range ($2, $3, both ends inclusive)
+/// of the vehicles of a compiled `VehicleFilter` (parameters from $4 on).
+const RECORDS_OF_VEHICLES: &str = "r.organization_id = $1
+    AND ($2::timestamptz IS NULL OR r.performed_at >= $2)
+    AND ($3::timestamptz IS NULL OR r.performed_at <= $3)
+    AND r.vehicle_id IN (SELECT uuid FROM vehicles WHERE organization_id = $1 AND {vehicles})";
+
+/// Sums the cost lines of the records by group (the `key` columns and `label`) and currency; the
+/// records without a cost are the groups without a currency.
+const COST_SUMS_QUERY: &str = "
+    SELECT {key}, {label} AS label, r.currency,
+        COALESCE(SUM(line.quantity * line.unit_price) FILTER (WHERE line.kind = 'Part'), 0)
+            AS parts,
+        COALESCE(SUM(line.quantity * line.unit_price) FILTER (WHERE line.kind = 'Labor'), 0)
+            AS labor,
+        COALESCE(SUM(line.quantity * line.unit_price) FILTER (WHERE line.kind = 'Tax'), 0)
+            AS taxes,
+        COALESCE(SUM(line.quantity * line.unit_price) FILTER (WHERE line.kind = 'Fee'), 0)
+            AS fees,
+        COALESCE(SUM(line.quantity * line.unit_price), 0) AS total,
+        COALESCE(SUM(line.quantity) FILTER (WHERE line.kind = 'Labor'), 0) AS labor_hours,
+        COUNT(DISTINCT r.id) AS record_count
+    FROM maintenance_records r
+        {join}
+        LEFT JOIN maintenance_cost_lines line ON line.maintenance_record_id = r.id
+    WHERE {records}
+    GROUP BY {group}, r.currency
+    ORDER BY {group}, r.currency COLLATE \"C\"";
+
+/// The lowest and highest odometer readings of the range of the vehicles with a record with a
+/// cost in it.
+const DISTANCES_QUERY: &str = "
+    SELECT s.vehicle_id, MAX(s.odometer)::bigint - MIN(s.odometer)::bigint AS distance_km
+    FROM vehicle_statuses s
+    WHERE s.organization_id = $1
+        AND ($2::timestamptz IS NULL OR s.created_at >= $2)
+        AND ($3::timestamptz IS NULL OR s.created_at <= $3)
+        AND s.vehicle_id IN (
+            SELECT r.vehicle_id FROM maintenance_records r
+            WHERE r.currency IS NOT NULL AND {records}
+        )
+    GROUP BY s.vehicle_id
+    ORDER BY s.vehicle_id";
+
+#[derive(Debug, Clone)]
+pub struct PostgresMaintenanceRecordRepository {
+    connection: Connection,
+}
+
+impl PostgresMaintenanceRecordRepository {
+    pub fn new(database: &PostgresDatabase) -> Self {
+        Self::on(Connection::Pool(database.pool().clone()))
+    }
+
+    pub(crate) fn on(connection: Connection) -> Self {
+        PostgresMaintenanceRecordRepository { connection }
+    }
+}
+
+async fn find_user(
+    connection: &mut PgConnection,
+    id: uuid::Uuid,
+) -> Result<Option<UserIdentity>, String> {
+    sqlx::query(&format!(
+        "SELECT {} FROM users WHERE uuid = $1",
+        USER_COLUMNS
+    ))
+    .bind(id)
+    .fetch_optional(connection)
+    .await
+    .map_err(database_error)?
+    .map(|row| user_from_row(&row, ""))
+    .transpose()
+}
+
+/// Reads the records of the rows with their cost lines and the entities they reference.
+async fn hydrate(
+    connection: &mut PgConnection,
+    rows: Vec<PgRow>,
+) -> Result<Vec<MaintenanceRecord>, String> {
+    let mut records = Vec::with_capacity(rows.len());
+    for row in rows {
+        let lines = sqlx::query(&format!(
+            "SELECT {} FROM maintenance_cost_lines
+             WHERE maintenance_record_id = $1
+             ORDER BY position",
+            COST_LINE_COLUMNS
+        ))
+        .bind(get::<uuid::Uuid>(&row, "id")?)
+        .fetch_all(&mut *connection)
+        .await
+        .map_err(database_error)?;
+        let identity = maintenance_record_from_row(&row, |currency| {
+            lines
+                .iter()
+                .map(|line| cost_line_from_row(line, currency))
+                .collect()
+        })?;
+
+        records.push(MaintenanceRecord {
+            vehicle: find_vehicle(connection, identity.vehicle_id)
+                .await?
+                .ok_or_else(|| format!("vehicle {} not found", identity.vehicle_id))?,
+            maintenance: find_maintenance(connection, identity.maintenance_id)
+                .await?
+                .ok_or_else(|| format!("maintenance {} not found", identity.maintenance_id))?,
+            user: find_user(connection, identity.user_id)
+                .await?
+                .ok_or_else(|| format!("user {} not found", identity.user_id))?,
+            vehicle_status: find_vehicle_status(connection, identity.vehicle_status_id)
+                .await?
+                .ok_or_else(|| {
+                    format!("vehicle status {} not found", identity.vehicle_status_id)
+                })?,
+            identity,
+        });
+    }
+    Ok(records)
+}
+
+impl MaintenanceRecordRepository for PostgresMaintenanceRecordRepository {
+    async fn create(
+        &self,
+        organization_id: uuid::Uuid,
+        record: MaintenanceRecordIdentity,
+    ) -> Result<MaintenanceRecordIdentity, MaintenanceRecordRepositoryError> {
+        let error = MaintenanceRecordRepositoryError::Database;
+        let mut write = self
+            .connection
+            .write(organization_id)
+            .await
+            .map_err(error)?;
+
+        sqlx::query(
+            "INSERT INTO maintenance_records (
+                 id, vehicle_id, maintenance_id, performed_by, vehicle_status_id, performed_at,
+                 details, currency, invoice_reference, created_at, created_by, updated_at,
+                 updated_by, organization_id
+             )
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)",
+        )
+        .bind(record.id)
+        .bind(record.vehicle_id)
+        .bind(record.maintenance_id)
+        .bind(record.user_id)
+        .bind(record.vehicle_status_id)
+        .bind(record.performed_at)
+        .bind(&record.details)
+        .bind(
+            record
+                .cost
+                .as_ref()
+                .map(|cost| cost.currency().code().to_string()),
+        )
+        .bind(
+            record
+                .cost
+                .as_ref()
+                .and_then(|cost| cost.invoice_reference().map(str::to_string)),
+        )
+        .bind(record.created_at)
+        .bind(record.created_by)
+        .bind(record.updated_at)
+        .bind(record.updated_by)
+        .bind(organization_id)
+        .execute(write.connection())
+        .await
+        .map_err(|e| error(database_error(e)))?;
+
+        for (position, line) in record.cost.iter().flat_map(|cost| cost.lines()).enumerate() {
+            sqlx::query(
+                "INSERT INTO maintenance_cost_lines (
+                     maintenance_record_id, position, kind, description, part_number, quantity,
+                     unit_price, organization_id
+                 )
+                 VALUES ($1, $2, $3::maintenance_cost_line_kind, $4, $5, $6, $7, $8)",
+            )
+            .bind(record.id)
+            .bind(position as i16)
+            .bind(line.kind.as_str())
+            .bind(&line.description)
+            .bind(&line.part_number)
+            .bind(line.quantity)
+            .bind(line.unit_price.amount())
+            .bind(organization_id)
+            .execute(write.connection())
+            .await
+            .map_err(|e| error(database_error(e)))?;
+        }
+
+        write.commit().await.map_err(error)?;
+        Ok(record)
+    }
+
+    async fn find_by_vehicle(
+        &self,
+        organization_id: uuid::Uuid,
+        vehicle_id: uuid::Uuid,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<MaintenanceRecord>, MaintenanceRecordRepositoryError> {
+        let error = MaintenanceRecordRepositoryError::Database;
+        let mut session = self
+            .connection
+            .session(organization_id)
+            .await
+            .map_err(error)?;
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM maintenance_records
+             WHERE organization_id = $1
+                 AND vehicle_id = $2
+                 AND ($3::timestamptz IS NULL OR performed_at >= $3)
+                 AND ($4::timestamptz IS NULL OR performed_at <= $4)
+             ORDER BY performed_at, id",
+            MAINTENANCE_RECORD_COLUMNS
+        ))
+        .bind(organization_id)
+        .bind(vehicle_id)
+        .bind(from)
+        .bind(to)
+        .fetch_all(session.connection())
+        .await
+        .map_err(|e| error(database_error(e)))?;
+        hydrate(session.connection(), rows).await.map_err(error)
+    }
+
+    async fn find_latest_by_maintenance(
+        &self,
+        organization_id: uuid::Uuid,
+        maintenance_id: i32,
+    ) -> Result<Option<MaintenanceRecord>, MaintenanceRecordRepositoryError> {
+        let error = MaintenanceRecordRepositoryError::Database;
+        let mut session = self
+            .connection
+            .session(organization_id)
+            .await
+            .map_err(error)?;
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM maintenance_records
+             WHERE organization_id = $1 AND maintenance_id = $2
+             ORDER BY performed_at DESC, id DESC
+             LIMIT 1",
+            MAINTENANCE_RECORD_COLUMNS
+        ))
+        .bind(organization_id)
+        .bind(maintenance_id)
+        .fetch_all(session.connection())
+        .await
+        .map_err(|e| error(database_error(e)))?;
+        Ok(hydrate(session.connection(), rows)
+            .await
+            .map_err(error)?
+            .into_iter()
+            .next())
+    }
+}
+
+/// The key of a group of cost sums.
+fn cost_group_key(
+    group_by: MaintenanceCostGroupBy,
+    row: &PgRow,
+) -> Result<MaintenanceCostGroupKey, String> {
+    Ok(match group_by {
+        MaintenanceCostGroupBy::Vehicle => MaintenanceCostGroupKey::Vehicle {
+            vehicle_id: get(row, "vehicle_id")?,
+        },
+        MaintenanceCostGroupBy::MaintenanceType => MaintenanceCostGroupKey::MaintenanceType {
+            maintenance_type_id: get(row, "maintenance_type_id")?,
+        },
+        MaintenanceCostGroupBy::Month => MaintenanceCostGroupKey::Month {
+            year: get(row, "year")?,
+            month: get::<i32>(row, "month")? as u32,
+        },
+    })
+}
+
+fn cost_totals(row: &PgRow, currency: Currency) -> Result<MaintenanceCostTotals, String> {
+    let money = |column| get::<Decimal>(row, column).map(|amount| Money::new(amount, currency));
+    let record_count: i64 = get(row, "record_count")?;
+    Ok(MaintenanceCostTotals {
+        breakdown: MaintenanceCostBreakdown {
+            parts: money("parts")?,
+            labor: money("labor")?,
+            taxes: money("taxes")?,
+            fees: money("fees")?,
+            total: money("total")?,
+            labor_hours: get(row, "labor_hours")?,
+        },
+        record_count: record_count as usize,
+    })
+}
+
+impl MaintenanceCostApplicationRepository for PostgresMaintenanceRecordRepository {
+    async fn sum_costs(
+        &self,
+        organization_id: uuid::Uuid,
+        vehicles: &VehicleFilter,
+        group_by: MaintenanceCostGroupBy,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<MaintenanceCostSums, MaintenanceCostApplicationRepositoryError> {
+        let error = MaintenanceCostApplicationRepositoryError::DatabaseError;
+        // Deprecated maintenance types are labelled too
+        let (key, label, join, group) = match group_by {
+            MaintenanceCostGroupBy::Vehicle => (
+                "r.vehicle_id",
+                "v.license_plate",
+                "JOIN vehicles v ON v.uuid = r.vehicle_id",
+                "r.vehicle_id, v.license_plate",
+            ),
+            MaintenanceCostGroupBy::MaintenanceType => (
+                "m.maintenance_type_id",
+                "mt.name",
+                "JOIN maintenances m ON m.id = r.maintenance_id
+                 JOIN maintenance_types mt ON mt.id = m.maintenance_type_id",
+                "m.maintenance_type_id, mt.name",
+            ),
+            MaintenanceCostGroupBy::Month => (
+                "EXTRACT(YEAR FROM r.performed_at AT TIME ZONE 'UTC')::int AS year,
+                 EXTRACT(MONTH FROM r.performed_at AT TIME ZONE 'UTC')::int AS month",
+                "''",
+                "",
+                "year, month",
+            ),
+        };
+        let filter = vehicle_filter_sql::compile(vehicles, 4);
+        let sql = COST_SUMS_QUERY
+            .replace("{key}", key)
+            .replace("{label}", label)
+            .replace("{join}", join)
+            .replace("{records}", RECORDS_OF_VEHICLES)
+            .replace("{vehicles}", &filter.sql)
+            .replace("{group}", group);
+
+        let mut session = self
+            .connection
+            .session(organization_id)
+            .await
+            .map_err(error)?;
+        let query = sqlx::query(&sql).bind(organization_id).bind(from).bind(to);
+        let rows = filter
+            .bind(query)
+            .fetch_all(session.connection())
+            .await
+            .map_err(|e| error(database_error(e)))?;
+
+        let mut sums = MaintenanceCostSums::default();
+        for row in &rows {
+            let Some(currency) = get::<Option<String>>(row, "currency").map_err(error)? else {
+                sums.records_without_cost += get::<i64>(row, "record_count").map_err(error)? as u64;
+                continue;
+            };
+            let currency = Currency::new(currency).map_err(|e| error(e.to_string()))?;
+            sums.groups.push(MaintenanceCostSum {
+                key: cost_group_key(group_by, row).map_err(error)?,
+                label: get(row, "label").map_err(error)?,
+                totals: cost_totals(row, currency).map_err(error)?,
+            });
+        }
+        Ok(sums)
+    }
+
+    async fn distance_by_vehicle(
+        &self,
+        organization_id: uuid::Uuid,
+        vehicles: &VehicleFilter,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<VehicleDistance>, MaintenanceCostApplicationRepositoryError> {
+        let error = MaintenanceCostApplicationRepositoryError::DatabaseError;
+        let filter = vehicle_filter_sql::compile(vehicles, 4);
+        let sql = DISTANCES_QUERY
+            .replace("{records}", RECORDS_OF_VEHICLES)
+            .replace("{vehicles}", &filter.sql);
+
+        let mut session = self
+            .connection
+            .session(organization_id)
+            .await
+            .map_err(error)?;
+        let query = sqlx::query(&sql).bind(organization_id).bind(from).bind(to);
+        filter
+            .bind(query)
+            .fetch_all(session.connection())
+            .await
+            .map_err(database_error)
+            .and_then(|rows| {
+                rows.iter()
+                    .map(|row| {
+                        Ok(VehicleDistance {
+                            vehicle_id: get(row, "vehicle_id")?,
+                            distance_km: get(row, "distance_km")?,
+                        })
+                    })
+                    .collect()
+            })
+            .map_err(error)
+    }
+}
@@ -0,0 +1,165 @@
+use crate::{
+    database::{Connection, PostgresDatabase, database_error},
+    mappers::{
+        interval_type_to_sql,
+        maintenance_mapper::{MAINTENANCE_COLUMNS, maintenance_from_row},
+    },
+    repositories::{
+        maintenance_type_repository::find_maintenance_type, vehicle_repository::find_vehicle,
+    },
+};
+use domain::maintenance::{
+    entities::maintenance::{Maintenance, MaintenanceIdentity},
+    repositories::maintenance_repository::{MaintenanceRepository, MaintenanceRepositoryError},
+};
+use sqlx::PgConnection;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct PostgresMaintenanceRepository {
+    connection: Connection,
+}
+
+impl PostgresMaintenanceRepository {
+    pub fn new(database: &PostgresDatabase) -> Self {
+        PostgresMaintenanceRepository {
+            connection: Connection::Pool(database.pool().clone()),
+        }
+    }
+
+    /// Reads the rules of the organization matching `condition` (on `$2`), hydrated.
+    async fn find_where<T>(
+        &self,
+        organization_id: Uuid,
+        condition: &str,
+        value: T,
+    ) -> Result<Vec<Maintenance>, String>
+    where
+        T: for<'q> sqlx::Encode<'q, sqlx::Postgres> + sqlx::Type<sqlx::Postgres> + Send,
+    {
+        let mut session = self.connection.session(organization_id).await?;
+        let identities = sqlx::query(&format!(
+            "SELECT {} FROM maintenances WHERE organization_id = $1 AND {} ORDER BY id",
+            MAINTENANCE_COLUMNS, condition
+        ))
+        .bind(organization_id)
+        .bind(value)
+        .fetch_all(session.connection())
+        .await
+        .map_err(database_error)?
+        .iter()
+        .map(maintenance_from_row)
+        .collect::<Result<Vec<_>, _>>()?;
+
+        let mut maintenances = Vec::with_capacity(identities.len());
+        for identity in identities {
+            maintenances.push(hydrate(session.connection(), identity).await?);
+        }
+        Ok(maintenances)
+    }
+}
+
+/// Reads a rule, for the repositories hydrating the entities referencing it.
+pub(crate) async fn find_maintenance(
+    connection: &mut PgConnection,
+    id: i32,
+) -> Result<Option<MaintenanceIdentity>, String> {
+    sqlx::query(&format!(
+        "SELECT {} FROM maintenances WHERE id = $1",
+        MAINTENANCE_COLUMNS
+    ))
+    .bind(id)
+    .fetch_optional(connection)
+    .await
+    .map_err(database_error)?
+    .map(|row| maintenance_from_row(&row))
+    .transpose()
+}
+
+async fn hydrate(
+    connection: &mut PgConnection,
+    identity: MaintenanceIdentity,
+) -> Result<Maintenance, String> {
+    Ok(Maintenance {
+        maintenance_type: find_maintenance_type(connection, identity.maintenance_type_id)
+            .await?
+            .ok_or_else(|| {
+                format!(
+                    "maintenance type {} not found",
+                    identity.maintenance_type_id
+                )
+            })?,
+        vehicle: find_vehicle(connection, identity.vehicle_id)
+            .await?
+            .ok_or_else(|| format!("vehicle {} not found", identity.vehicle_id))?,
+        identity,
+    })
+}
+
+impl MaintenanceRepository for PostgresMaintenanceRepository {
+    async fn find_by_vehicle(
+        &self,
+        organization_id: Uuid,
+        vehicle_id: Uuid,
+    ) -> Result<Vec<Maintenance>, MaintenanceRepositoryError> {
+        self.find_where(organization_id, "vehicle_id = $2", vehicle_id)
+            .await
+            .map_err(MaintenanceRepositoryError::Database)
+    }
+
+    async fn find_by_maintenance_type(
+        &self,
+        organization_id: Uuid,
+        maintenance_type_id: i32,
+    ) -> Result<Vec<Maintenance>, MaintenanceRepositoryError> {
+        self.find_where(
+            organization_id,
+            "maintenance_type_id = $2",
+            maintenance_type_id,
+        )
+        .await
+        .map_err(MaintenanceRepositoryError::Database)
+    }
+
+    async fn create(
+        &self,
+        organization_id: Uuid,
+        maintenance: Maintenance,
+    ) -> Result<Maintenance, MaintenanceRepositoryError> {
+        let error = MaintenanceRepositoryError::Database;
+        let mut write = self
+            .connection
+            .write(organization_id)
+            .await
+            .map_err(error)?;
+        let identity = maintenance.identity;
+        let (id,): (i32,) = sqlx::query_as(
+            "INSERT INTO maintenances (
+                 vehicle_id, maintenance_type_id, interval_type, interval_value, red_threshold,
+                 yellow_threshold, created_at, created_by, updated_at, updated_by, organization_id
+             )
+             VALUES ($1, $2, $3::maintenance_interval_type, $4, $5, $6, $7, $8, $9, $10, $11)
+             RETURNING id",
+        )
+        .bind(identity.vehicle_id)
+        .bind(identity.maintenance_type_id)
+        .bind(interval_type_to_sql(&identity.interval_type))
+        .bind(identity.interval_value as i32)
+        .bind(identity.red_threshold as i32)
+        .bind(identity.yellow_threshold as i32)
+        .bind(identity.created_at)
+        .bind(identity.created_by)
+        .bind(identity.updated_at)
+        .bind(identity.updated_by)
+        .bind(organization_id)
+        .fetch_one(write.connection())
+        .await
+        .map_err(|e| error(database_error(e)))?;
+
+        let maintenance = hydrate(write.connection(), MaintenanceIdentity { id, ..identity })
+            .await
+            .map_err(error)?;
+        write.commit().await.map_err(error)?;
+        Ok(maintenance)
+    }
+}
@@ -0,0 +1,528 @@
+use crate::{
+    database::{Connection, PostgresDatabase, database_error, is_unique_violation},
+    mappers::{
+        maintenance_type_mapper::{
+            MAINTENANCE_TYPE_COLUMNS, maintenance_type_from_row, maintenance_type_view_from_row,
+        },
+        user_mapper::user_columns,
+    },
+};
+use domain::maintenance::{
+    entities::maintenance_type::{
+        MaintenanceType, MaintenanceTypeMerge, MaintenanceTypeUsage, MaintenanceTypeView,
+    },
+    repositories::maintenance_type_repository::{
+        MaintenanceTypeRepository, MaintenanceTypeRepositoryError,
+    },
+};
+use sqlx::PgConnection;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct PostgresMaintenanceTypeRepository {
+    connection: Connection,
+}
+
+impl PostgresMaintenanceTypeRepository {
+    pub fn new(database: &PostgresDatabase) -> Self {
+        PostgresMaintenanceTypeRepository {
+            connection: Connection::Pool(database.pool().clone()),
+        }
+    }
+}
+
+fn error(error: impl std::fmt::Display) -> MaintenanceTypeRepositoryError {
+    MaintenanceTypeRepositoryError::Database(database_error(error))
+}
+
+/// Maps the error of a write of `name`, whose only unique key is
+/// `maintenance_types_organization_id_name_key`.
+fn write_error(error: sqlx::Error, name: &str) -> MaintenanceTypeRepositoryError {
+    match is_unique_violation(&error) {
+        true => MaintenanceTypeRepositoryError::AlreadyExists(name.to_string()),
+        false => MaintenanceTypeRepositoryError::Database(database_error(error)),
+    }
+}
+
+fn not_found(id: i32) -> MaintenanceTypeRepositoryError {
+    error(format!("maintenance type {} not found", id))
+}
+
+/// Selects the views of the maintenance types of the organization (`$1`) matching `condition`.
+fn view_query(condition: &str) -> String {
+    format!(
+        "SELECT {}, {}, {}
+         FROM maintenance_types mt
+         JOIN users cu ON cu.uuid = mt.created_by
+         JOIN users uu ON uu.uuid = mt.updated_by
+         WHERE mt.organization_id = $1 AND {}
+         ORDER BY mt.id",
+        MAINTENANCE_TYPE_COLUMNS,
+        user_columns("cu", "created_by_"),
+        user_columns("uu", "updated_by_"),
+        condition
+    )
+}
+
+/// Reads a maintenance type, for the repositories hydrating the entities referencing it.
+pub(crate) async fn find_maintenance_type(
+    connection: &mut PgConnection,
+    id: i32,
+) -> Result<Option<MaintenanceType>, String> {
+    sqlx::query(&format!(
+        "SELECT {} FROM maintenance_types mt WHERE mt.id = $1",
+        MAINTENANCE_TYPE_COLUMNS
+    ))
+    .bind(id)
+    .fetch_optional(connection)
+    .await
+    .map_err(database_error)?
+    .map(|row| maintenance_type_from_row(&row))
+    .transpose()
+}
+
+async fn find_view(
+    connection: &mut PgConnection,
+    organization_id: Uuid,
+    id: i32,
+) -> Result<Option<MaintenanceTypeView>, String> {
+    sqlx::query(&view_query("mt.id = $2"))
+        .bind(organization_id)
+        .bind(id)
+        .fetch_optional(connection)
+        .await
+        .map_err(database_error)?
+        .map(|row| maintenance_type_view_from_row(&row))
+        .transpose()
+}
+
+/// Reads the view of a maintenance type just written.
+async fn written_view(
+    connection: &mut PgConnection,
+    organization_id: Uuid,
+    id: i32,
+) -> Result<MaintenanceTypeView, MaintenanceTypeRepositoryError> {
+    find_view(connection, organization_id, id)
+        .await
+        .map_err(MaintenanceTypeRepositoryError::Database)?
+        .ok_or_else(|| not_found(id))
+}
+
+impl MaintenanceTypeRepository for PostgresMaintenanceTypeRepository {
+    async fn create(
+        &self,
+        organization_id: Uuid,
+        maintenance_type: MaintenanceType,
+        user_id: Uuid,
+    ) -> Result<MaintenanceTypeView, MaintenanceTypeRepositoryError> {
+        let mut write = self
+            .connection
+            .write(organization_id)
+            .await
+            .map_err(MaintenanceTypeRepositoryError::Database)?;
+        let (id,): (i32,) = sqlx::query_as(
+            "INSERT INTO maintenance_types (
+                 name, description, category, applicability, checklist, created_by, updated_by,
+                 organization_id
+             )
+             VALUES ($1, $2, $3::maintenance_category, $4::maintenance_applicability, $5, $6, $6,
+                 $7)
+             RETURNING id",
+        )
+        .bind(maintenance_type.name())
+        .bind(maintenance_type.description())
+        .bind(
+            maintenance_type
+                .category()
+                .map(|category| category.as_sql().to_string()),
+        )
+        .bind(maintenance_type.applicability().as_sql())
+        .bind(maintenance_type.checklist())
+        .bind(user_id)
+        .bind(organization_id)
+        .fetch_one(write.connection())
+        .await
+        .map_err(|e| write_error(e, maintenance_type.name()))?;
+
+        let view = written_view(write.connection(), organization_id, id).await?;
+        write
+            .commit()
+            .await
+            .map_err(MaintenanceTypeRepositoryError::Database)?;
+        Ok(view)
+    }
+
+    async fn get_by_id(
+        &self,
+        organization_id: Uuid,
+        id: i32,
+    ) -> Result<Option<MaintenanceType>, MaintenanceTypeRepositoryError> {
+        let mut session = self
+            .connection
+            .session(organization_id)
+            .await
+            .map_err(MaintenanceTypeRepositoryError::Database)?;
+        sqlx::query(&format!(
+            "SELECT {} FROM maintenance_types mt WHERE mt.organization_id = $1 AND mt.id = $2",
+            MAINTENANCE_TYPE_COLUMNS
+        ))
+        .bind(organization_id)
+        .bind(id)
+        .fetch_optional(session.connection())
+        .await
+        .map_err(database_error)
+        .and_then(|row| row.map(|row| maintenance_type_from_row(&row)).transpose())
+        .map_err(MaintenanceTypeRepositoryError::Database)
+    }
+
+    async fn get_view_by_id(
+        &self,
+        organization_id: Uuid,
+        id: i32,
+    ) -> Result<Option<MaintenanceTypeView>, MaintenanceTypeRepositoryError> {
+        let mut session = self
+            .connection
+            .session(organization_id)
+            .await
+            .map_err(MaintenanceTypeRepositoryError::Database)?;
+        find_view(session.connection(), organization_id, id)
+            .await
+            .map_err(MaintenanceTypeRepositoryError::Database)
+    }
+
+    async fn get_all_view(
+        &self,
+        organization_id: Uuid,
+    ) -> Result<Vec<MaintenanceTypeView>, MaintenanceTypeRepositoryError> {
+        let mut session = self
+            .connection
+            .session(organization_id)
+            .await
+            .map_err(MaintenanceTypeRepositoryError::Database)?;
+        sqlx::query(&view_query("mt.deprecated_at IS NULL"))
+            .bind(organization_id)
+            .fetch_all(session.connection())
+            .await
+            .map_err(database_error)
+            .and_then(|rows| rows.iter().map(maintenance_type_view_from_row).collect())
+            .map_err(MaintenanceTypeRepositoryError::Database)
+    }
+
+    async fn exists_by_name(
+        &self,
+        organization_id: Uuid,
+        name: &str,
+    ) -> Result<bool, MaintenanceTypeRepositoryError> {
+        let mut session = self
+            .connection
+            .session(organization_id)
+            .await
+            .map_err(MaintenanceTypeRepositoryError::Database)?;
+        let (exists,): (bool,) = sqlx::query_as(
+            "SELECT EXISTS(
+                 SELECT 1 FROM maintenance_types WHERE organization_id = $1 AND name = $2
+             )",
+        )
+        .bind(organization_id)
+        .bind(name)
+        .fetch_one(session.connection())
+        .await
+        .map_err(error)?;
+        Ok(exists)
+    }
+
+    async fn update(
+        &self,
+        organization_id: Uuid,
+        maintenance_type: MaintenanceType,
+        user_id: Uuid,
+    ) -> Result<MaintenanceTypeView, MaintenanceTypeRepositoryError> {
+        let mut write = self
+            .connection
+            .write(organization_id)
+            .await
+            .map_err(MaintenanceTypeRepositoryError::Database)?;
+        let result = sqlx::query(
+            "UPDATE maintenance_types
+             SET name = $1, description = $2, category = $3::maintenance_category,
+                 applicability = $4::maintenance_applicability, checklist = $5,
+                 updated_at = now(), updated_by = $6
+             WHERE id = $7 AND organization_id = $8",
+        )
+        .bind(maintenance_type.name())
+        .bind(maintenance_type.description())
+        .bind(
+            maintenance_type
+                .category()
+                .map(|category| category.as_sql().to_string()),
+        )
+        .bind(maintenance_type.applicability().as_sql())
+        .bind(maintenance_type.checklist())
+        .bind(user_id)
+        .bind(maintenance_type.id())
+        .bind(organization_id)
+        .execute(write.connection())
+        .await
+        .map_err(|e| write_error(e, maintenance_type.name()))?;
+        if result.rows_affected() == 0 {
+            return Err(not_found(maintenance_type.id()));
+        }
+
+        let view = written_view(write.connection(), organization_id, maintenance_type.id()).await?;
+        write
+            .commit()
+            .await
+            .map_err(MaintenanceTypeRepositoryError::Database)?;
+        Ok(view)
+    }
+
+    async fn usage(
+        &self,
+        organization_id: Uuid,
+        id: i32,
+    ) -> Result<MaintenanceTypeUsage, MaintenanceTypeRepositoryError> {
+        let mut session = self
+            .connection
+            .session(organization_id)
+            .await
+            .map_err(MaintenanceTypeRepositoryError::Database)?;
+        let (rules, records): (i64, i64) = sqlx::query_as(
+            "SELECT
+                 (SELECT COUNT(*) FROM maintenances
+                  WHERE organization_id = $1 AND maintenance_type_id = $2),
+                 (SELECT COUNT(*)
+                  FROM maintenance_records r
+                  JOIN maintenances m ON m.id = r.maintenance_id
+                  WHERE r.organization_id = $1 AND m.maintenance_type_id = $2)",
+        )
+        .bind(organization_id)
+        .bind(id)
+        .fetch_one(session.connection())
+        .await
+        .map_err(error)?;
+        Ok(MaintenanceTypeUsage {
+            rules: rules as u64,
+            records: records as u64,
+        })
+    }
+
+    async fn deprecate(
+        &self,
+        organization_id: Uuid,
+        maintenance_type: MaintenanceType,
+        user_id: Uuid,
+    ) -> Result<MaintenanceTypeView, MaintenanceTypeRepositoryError> {
+        let mut write = self
+            .connection
+            .write(organization_id)
+            .await
+            .map_err(MaintenanceTypeRepositoryError::Database)?;
+        let result = sqlx::query(
+            "UPDATE maintenance_types
+             SET deprecated_at = COALESCE($1, now()), deprecated_by = $2, updated_at = now(),
+                 updated_by = $2
+             WHERE id = $3 AND organization_id = $4",
+        )
+        .bind(maintenance_type.deprecated_at())
+        .bind(user_id)
+        .bind(maintenance_type.id())
+        .bind(organization_id)
+        .execute(write.connection())
+        .await
+        .map_err(|e| write_error(e, maintenance_type.name()))?;
+        if result.rows_affected() == 0 {
+            return Err(not_found(maintenance_type.id()));
+        }
+
+        let view = written_view(write.connection(), organization_id, maintenance_type.id()).await?;
+        write
+            .commit()
+            .await
+            .map_err(MaintenanceTypeRepositoryError::Database)?;
+        Ok(view)
+    }
+
+    async fn merge(
+        &self,
+        organization_id: Uuid,
+        source: MaintenanceType,
+        target: MaintenanceType,
+        user_id: Uuid,
+    ) -> Result<MaintenanceTypeMerge, MaintenanceTypeRepositoryError> {
+        let mut write = self
+            .connection
+            .write(organization_id)
+            .await
+            .map_err(MaintenanceTypeRepositoryError::Database)?;
+        // Both types are locked, a concurrent write of their rules or templates waits for the
+        // merge
+        let (found,): (i64,) = sqlx::query_as(
+            "SELECT COUNT(*) FROM (
+                 SELECT id FROM maintenance_types
+                 WHERE organization_id = $1 AND id IN ($2, $3)
+                 FOR UPDATE
+             ) types",
+        )
+        .bind(organization_id)
+        .bind(source.id())
+        .bind(target.id())
+        .fetch_one(write.connection())
+        .await
+        .map_err(error)?;
+        if found < 2 {
+            return Err(error(format!(
+                "maintenance types {} and {} not found",
+                source.id(),
+                target.id()
+            )));
+        }
+        let mut merge = MaintenanceTypeMerge::default();
+
+        // Rules: re-point them, or fold them into the target rule of the same vehicle and
+        // interval type
+        let rules: Vec<(i32, Option<i32>)> = sqlx::query_as(
+            "SELECT s.id, t.id
+             FROM maintenances s
+             LEFT JOIN maintenances t
+                 ON t.maintenance_type_id = $2
+                 AND t.organization_id = s.organization_id
+                 AND t.vehicle_id = s.vehicle_id
+                 AND t.interval_type = s.interval_type
+             WHERE s.organization_id = $3 AND s.maintenance_type_id = $1
+             ORDER BY s.id",
+        )
+        .bind(source.id())
+        .bind(target.id())
+        .bind(organization_id)
+        .fetch_all(write.connection())
+        .await
+        .map_err(error)?;
+        for (rule, target_rule) in rules {
+            match target_rule {
+                Some(target_rule) => {
+                    let moved = sqlx::query(
+                        "UPDATE maintenance_records SET maintenance_id = $1
+                         WHERE organization_id = $3 AND maintenance_id = $2",
+                    )
+                    .bind(target_rule)
+                    .bind(rule)
+                    .bind(organization_id)
+                    .execute(write.connection())
+                    .await
+                    .map_err(error)?;
+                    sqlx::query("DELETE FROM maintenances WHERE id = $1")
+                        .bind(rule)
+                        .execute(write.connection())
+                        .await
+                        .map_err(error)?;
+                    merge.records_moved += moved.rows_affected();
+                    merge.rules_merged += 1;
+                }
+                None => {
+                    sqlx::query(
+                        "UPDATE maintenances
+                         SET maintenance_type_id = $1, updated_at = now(), updated_by = $2
+                         WHERE id = $3",
+                    )
+                    .bind(target.id())
+                    .bind(user_id)
+                    .bind(rule)
+                    .execute(write.connection())
+                    .await
+                    .map_err(error)?;
+                    merge.rules_moved += 1;
+                }
+            }
+        }
+
+        // Default intervals: re-point them, dropping the duplicates of a target interval (the
+        // scope compares as in `maintenance_interval_templates_scope_key`)
+        sqlx::query(
+            "DELETE FROM maintenance_interval_templates s
+             WHERE s.organization_id = $3
+                 AND s.maintenance_type_id = $1
+                 AND EXISTS (
+                     SELECT 1 FROM maintenance_interval_templates t
+                     WHERE t.maintenance_type_id = $2
+                         AND t.interval_type = s.interval_type
+                         AND COALESCE(LOWER(t.make), '') = COALESCE(LOWER(s.make), '')
+                         AND COALESCE(LOWER(t.model), '') = COALESCE(LOWER(s.model), '')
+                         AND t.engine_type IS NOT DISTINCT FROM s.engine_type
+                         AND COALESCE(t.engine_type_other, '')
+                             = COALESCE(s.engine_type_other, '')
+                 )",
+        )
+        .bind(source.id())
+        .bind(target.id())
+        .bind(organization_id)
+        .execute(write.connection())
+        .await
+        .map_err(error)?;
+        let moved = sqlx::query(
+            "UPDATE maintenance_interval_templates
+             SET maintenance_type_id = $1, updated_at = now(), updated_by = $2
+             WHERE organization_id = $4 AND maintenance_type_id = $3",
+        )
+        .bind(target.id())
+        .bind(user_id)
+        .bind(source.id())
+        .bind(organization_id)
+        .execute(write.connection())
+        .await
+        .map_err(error)?;
+        merge.templates_moved = moved.rows_affected();
+
+        // Diagnostic rules would be deleted with the source (ON DELETE CASCADE)
+        sqlx::query(
+            "UPDATE diagnostic_rules SET maintenance_type_id = $1
+             WHERE organization_id = $3 AND maintenance_type_id = $2",
+        )
+        .bind(target.id())
+        .bind(source.id())
+        .bind(organization_id)
+        .execute(write.connection())
+        .await
+        .map_err(error)?;
+
+        sqlx::query("DELETE FROM maintenance_types WHERE id = $1 AND organization_id = $2")
+            .bind(source.id())
+            .bind(organization_id)
+            .execute(write.connection())
+            .await
+            .map_err(error)?;
+        write
+            .commit()
+            .await
+            .map_err(MaintenanceTypeRepositoryError::Database)?;
+        Ok(merge)
+    }
+
+    async fn delete(
+        &self,
+        organization_id: Uuid,
+        maintenance_type: MaintenanceType,
+        _user_id: Uuid,
+    ) -> Result<(), MaintenanceTypeRepositoryError> {
+        // The rules referencing the type make the delete fail (ON DELETE NO ACTION), its default
+        // intervals are deleted with it (ON DELETE CASCADE)
+        let mut write = self
+            .connection
+            .write(organization_id)
+            .await
+            .map_err(MaintenanceTypeRepositoryError::Database)?;
+        let result =
+            sqlx::query("DELETE FROM maintenance_types WHERE id = $1 AND organization_id = $2")
+                .bind(maintenance_type.id())
+                .bind(organization_id)
+                .execute(write.connection())
+                .await
+                .map_err(error)?;
+        if result.rows_affected() == 0 {
+            return Err(not_found(maintenance_type.id()));
+        }
+        write
+            .commit()
+            .await
+            .map_err(MaintenanceTypeRepositoryError::Database)
+    }
+}
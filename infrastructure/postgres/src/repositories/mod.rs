@@ -0,0 +1,245 @@
+pub mod maintenance_record_repository;
+pub mod maintenance_repository;
+pub mod maintenance_type_repository;
+pub mod search_repository;
+pub mod vehicle_repository;
+pub mod vehicle_status_repository;
+
+/// The conformance suites of the repositories of this crate, on the database of `DATABASE_URL`
+/// (skipped when it isn't set). The organizations and users the checks need, which this crate
+/// has no repository for, are inserted by `Seeding`; the repositories no suite of this crate
+/// reaches are in-memory ones.
+#[cfg(test)]
+mod tests {
+    use super::{
+        maintenance_record_repository::PostgresMaintenanceRecordRepository,
+        maintenance_repository::PostgresMaintenanceRepository,
+        maintenance_type_repository::PostgresMaintenanceTypeRepository,
+        vehicle_repository::PostgresVehicleRepository,
+        vehicle_status_repository::PostgresVehicleStatusRepository,
+    };
+    use crate::{
+        database::{Connection, PostgresDatabase, database_error},
+        mappers::get,
+    };
+    use conformance::ConformanceBackend;
+    use domain::{
+        organization::{
+            entities::organization::Organization,
+            repositories::organization_repository::{
+                OrganizationRepository, OrganizationRepositoryError,
+            },
+        },
+        user::entities::user::UserIdentity,
+    };
+    use memory::{
+        repositories::{
+            depot_repository::MemoryDepotRepository,
+            diagnostic_repository::MemoryDiagnosticRepository,
+            inspection_repository::MemoryInspectionRepository,
+            telematics_device_repository::MemoryTelematicsDeviceRepository,
+            trip_repository::MemoryTripRepository,
+            vehicle_movement_repository::MemoryVehicleMovementRepository,
+        },
+        store::MemoryStore,
+    };
+    use uuid::Uuid;
+
+    /// Inserts the rows of the checks with plain statements. The database is shared by every
+    /// check: the organization names and the emails get the id of their row, to stay unique.
+    #[derive(Debug, Clone)]
+    struct Seeding {
+        connection: Connection,
+    }
+
+    impl Seeding {
+        async fn execute(
+            &self,
+            organization_id: Uuid,
+            query: sqlx::query::Query<'_, sqlx::Postgres, sqlx::postgres::PgArguments>,
+        ) -> Result<(), String> {
+            let mut write = self.connection.write(organization_id).await?;
+            query
+                .execute(write.connection())
+                .await
+                .map_err(database_error)?;
+            write.commit().await
+        }
+    }
+
+    impl OrganizationRepository for Seeding {
+        async fn create(
+            &self,
+            organization: Organization,
+        ) -> Result<Organization, OrganizationRepositoryError> {
+            let name = format!("{} {}", organization.name, organization.id);
+            self.execute(
+                organization.id,
+                sqlx::query("INSERT INTO organizations (id, name) VALUES ($1, $2)")
+                    .bind(organization.id)
+                    .bind(name),
+            )
+            .await
+            .map_err(OrganizationRepositoryError::Database)?;
+            Ok(organization)
+        }
+
+        /// The name is read without the id it was stored with.
+        async fn find_by_id(
+            &self,
+            id: Uuid,
+        ) -> Result<Option<Organization>, OrganizationRepositoryError> {
+            let mut session = self
+                .connection
+                .session(id)
+                .await
+                .map_err(OrganizationRepositoryError::Database)?;
+            let row = sqlx::query(
+                "SELECT id, name, created_at, updated_at FROM organizations WHERE id = $1",
+            )
+            .bind(id)
+            .fetch_optional(session.connection())
+            .await
+            .map_err(|e| OrganizationRepositoryError::Database(database_error(e)))?;
+            row.map(|row| {
+                let name: String = get(&row, "name")?;
+                let suffix = format!(" {}", id);
+                Ok(Organization {
+                    id: get(&row, "id")?,
+                    name: name.strip_suffix(&suffix).unwrap_or(&name).to_string(),
+                    created_at: get(&row, "created_at")?,
+                    updated_at: get(&row, "updated_at")?,
+                })
+            })
+            .transpose()
+            .map_err(OrganizationRepositoryError::Database)
+        }
+    }
+
+    struct Backend {
+        seeding: Seeding,
+        vehicles: PostgresVehicleRepository,
+        vehicle_statuses: PostgresVehicleStatusRepository,
+        maintenance_types: PostgresMaintenanceTypeRepository,
+        maintenances: PostgresMaintenanceRepository,
+        maintenance_records: PostgresMaintenanceRecordRepository,
+        depots: MemoryDepotRepository,
+        vehicle_movements: MemoryVehicleMovementRepository,
+        telematics_devices: MemoryTelematicsDeviceRepository,
+        trips: MemoryTripRepository,
+        diagnostics: MemoryDiagnosticRepository,
+        inspections: MemoryInspectionRepository,
+    }
+
+    impl Backend {
+        fn new(database: &PostgresDatabase) -> Self {
+            let store = MemoryStore::new();
+            Self {
+                seeding: Seeding {
+                    connection: Connection::Pool(database.pool().clone()),
+                },
+                vehicles: PostgresVehicleRepository::new(database),
+                vehicle_statuses: PostgresVehicleStatusRepository::new(database),
+                maintenance_types: PostgresMaintenanceTypeRepository::new(database),
+                maintenances: PostgresMaintenanceRepository::new(database),
+                maintenance_records: PostgresMaintenanceRecordRepository::new(database),
+                depots: MemoryDepotRepository::new(&store),
+                vehicle_movements: MemoryVehicleMovementRepository::new(&store),
+                telematics_devices: MemoryTelematicsDeviceRepository::new(&store),
+                trips: MemoryTripRepository::new(&store),
+                diagnostics: MemoryDiagnosticRepository::new(&store),
+                inspections: MemoryInspectionRepository::new(&store),
+            }
+        }
+    }
+
+    impl ConformanceBackend for Backend {
+        type Organizations = Seeding;
+        type Vehicles = PostgresVehicleRepository;
+        type VehicleStatuses = PostgresVehicleStatusRepository;
+        type MaintenanceTypes = PostgresMaintenanceTypeRepository;
+        type Maintenances = PostgresMaintenanceRepository;
+        type MaintenanceRecords = PostgresMaintenanceRecordRepository;
+        type Depots = MemoryDepotRepository;
+        type VehicleMovements = MemoryVehicleMovementRepository;
+        type TelematicsDevices = MemoryTelematicsDeviceRepository;
+        type Trips = MemoryTripRepository;
+        type Diagnostics = MemoryDiagnosticRepository;
+        type Inspections = MemoryInspectionRepository;
+
+        fn organizations(&self) -> &Self::Organizations {
+            &self.seeding
+        }
+        fn vehicles(&self) -> &Self::Vehicles {
+            &self.vehicles
+        }
+        fn vehicle_statuses(&self) -> &Self::VehicleStatuses {
+            &self.vehicle_statuses
+        }
+        fn maintenance_types(&self) -> &Self::MaintenanceTypes {
+            &self.maintenance_types
+        }
+        fn maintenances(&self) -> &Self::Maintenances {
+            &self.maintenances
+        }
+        fn maintenance_records(&self) -> &Self::MaintenanceRecords {
+            &self.maintenance_records
+        }
+        fn depots(&self) -> &Self::Depots {
+            &self.depots
+        }
+        fn vehicle_movements(&self) -> &Self::VehicleMovements {
+            &self.vehicle_movements
+        }
+        fn telematics_devices(&self) -> &Self::TelematicsDevices {
+            &self.telematics_devices
+        }
+        fn trips(&self) -> &Self::Trips {
+            &self.trips
+        }
+        fn diagnostics(&self) -> &Self::Diagnostics {
+            &self.diagnostics
+        }
+        fn inspections(&self) -> &Self::Inspections {
+            &self.inspections
+        }
+
+        async fn insert_user(&self, user: UserIdentity) -> Result<(), String> {
+            let email = format!("{}.{}", user.id, user.email.value());
+            self.seeding
+                .execute(
+                    user.organization_id,
+                    sqlx::query(
+                        "INSERT INTO users (
+                             uuid, username, email, first_name, last_name, organization_id
+                         )
+                         VALUES ($1, $2, $3, $4, $5, $6)",
+                    )
+                    .bind(user.id)
+                    .bind(&user.username)
+                    .bind(email)
+                    .bind(&user.first_name)
+                    .bind(&user.last_name)
+                    .bind(user.organization_id),
+                )
+                .await
+        }
+    }
+
+    #[tokio::test]
+    async fn conformance() {
+        let Ok(url) = std::env::var("DATABASE_URL") else {
+            eprintln!("DATABASE_URL isn't set, skipping the conformance suites");
+            return;
+        };
+        let database = PostgresDatabase::connect(&url)
+            .await
+            .expect("database connected and migrated");
+
+        conformance::vehicle_repository::run(|| async { Backend::new(&database) }).await;
+        conformance::vehicle_status_repository::run(|| async { Backend::new(&database) }).await;
+        conformance::maintenance_type_repository::run(|| async { Backend::new(&database) }).await;
+        conformance::maintenance_repository::run(|| async { Backend::new(&database) }).await;
+        conformance::maintenance_record_repository::run(|| async { Backend::new(&database) }).await;
+    }
+}
@@ -0,0 +1,239 @@
+//! Ranked text search on the generated `search_vector` columns and the trigram indexes of
+//! `migrations/20250909090000_text_search.sql`.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * A row matching the words of the term (`websearch_to_tsquery`) is a full-text hit ranked with
+//!   `ts_rank`; otherwise a value similar enough to the term (`%`, the trigram indexes, with
+//!   `min_similarity` as `pg_trgm.similarity_threshold`, and a similarity above 0) makes a fuzzy
+//!   hit, ranked below the full-text ones by similarity.
+//! * The threshold is set locally to a transaction rolled back once read, so it doesn't outlive
+//!   the search.
+//! * The term is parsed with the configuration of its language; the vectors of maintenance types
+//!   and records hold the English and Russian lexemes, the ones of vehicles are not stemmed
+//!   (`simple`), makes and models being names.
+//! * Snippets are `ts_headline` excerpts, the matched words between `HIGHLIGHT_START` and
+//!   `HIGHLIGHT_END`; a fuzzy hit has no highlighted word.
+use crate::{
+    database::{Connection, PostgresDatabase, database_error},
+    mappers::get,
+};
+use application::search::{
+    models::{
+        search_hit::{SearchHit, SearchHitKind, SearchMatch},
+        text_search::{HIGHLIGHT_END, HIGHLIGHT_START, TextSearch},
+    },
+    traits::search_repository::{SearchRepository, SearchRepositoryError},
+};
+use sqlx::postgres::PgRow;
+
+/// Length of the snippets, in words.
+const SNIPPET_WORDS: usize = 12;
+
+/// Every query binds the organization ($1), the text search configuration ($2, unused for the
+/// vehicles), the term ($3), the limit ($4), the `ts_headline` options ($5) and the depot scope
+/// ($6, `NULL` for none, unused for the maintenance types), and selects `id`, `title`, `snippet`,
+/// `rank` and `full_text`.
+const MAINTENANCE_TYPES_QUERY: &str = "
+    SELECT mt.id::text AS id,
+        mt.name AS title,
+        CASE WHEN to_tsvector($2::regconfig, mt.name) @@ query
+            THEN ts_headline($2::regconfig, mt.name, query, $5)
+            ELSE ts_headline($2::regconfig, mt.description, query, $5)
+        END AS snippet,
+        CASE WHEN mt.search_vector @@ query
+            THEN ts_rank(mt.search_vector, query)
+            ELSE fuzzy.similarity / 10::real
+        END AS rank,
+        mt.search_vector @@ query AS full_text
+    FROM maintenance_types mt
+        CROSS JOIN websearch_to_tsquery($2::regconfig, $3) AS query
+        CROSS JOIN LATERAL (SELECT similarity(mt.name, $3) AS similarity) AS fuzzy
+    WHERE mt.organization_id = $1
+        AND mt.deprecated_at IS NULL
+        AND (
+            mt.search_vector @@ query
+            OR (mt.name % $3 AND fuzzy.similarity > 0)
+        )
+    ORDER BY rank DESC, mt.id
+    LIMIT $4";
+
+const VEHICLES_QUERY: &str = "
+    SELECT v.uuid::text AS id,
+        v.make || ' ' || v.model || ' ' || v.license_plate AS title,
+        ts_headline('simple', v.make || ' ' || v.model || ' ' || v.license_plate, query, $5)
+            AS snippet,
+        CASE WHEN v.search_vector @@ query
+            THEN ts_rank(v.search_vector, query)
+            ELSE fuzzy.similarity / 10::real
+        END AS rank,
+        v.search_vector @@ query AS full_text
+    FROM vehicles v
+        CROSS JOIN websearch_to_tsquery('simple', $3) AS query
+        CROSS JOIN LATERAL (
+            SELECT GREATEST(
+                similarity(v.make || ' ' || v.model, $3),
+                similarity(v.license_plate, $3),
+                similarity(v.vin, $3)
+            ) AS similarity
+        ) AS fuzzy
+    WHERE v.organization_id = $1
+        AND ($6::uuid[] IS NULL OR (
+            SELECT movement.to_depot_id FROM vehicle_movements movement
+            WHERE movement.vehicle_id = v.uuid AND movement.latest
+        ) = ANY($6))
+        AND (
+            v.search_vector @@ query
+            OR (
+                ((v.make || ' ' || v.model) % $3 OR v.license_plate % $3 OR v.vin % $3)
+                AND fuzzy.similarity > 0
+            )
+        )
+    ORDER BY rank DESC, v.uuid
+    LIMIT $4";
+
+const MAINTENANCE_RECORDS_QUERY: &str = "
+    SELECT r.id::text AS id,
+        COALESCE(mt.name, '') || ' ' || v.license_plate AS title,
+        ts_headline($2::regconfig, r.details, query, $5) AS snippet,
+        CASE WHEN r.search_vector @@ query
+            THEN ts_rank(r.search_vector, query)
+            ELSE fuzzy.similarity / 10::real
+        END AS rank,
+        r.search_vector @@ query AS full_text
+    FROM maintenance_records r
+        JOIN vehicles v ON v.uuid = r.vehicle_id
+        LEFT JOIN maintenances m ON m.id = r.maintenance_id
+        LEFT JOIN maintenance_types mt ON mt.id = m.maintenance_type_id
+        CROSS JOIN websearch_to_tsquery($2::regconfig, $3) AS query
+        CROSS JOIN LATERAL (SELECT similarity(r.details, $3) AS similarity) AS fuzzy
+    WHERE r.organization_id = $1
+        AND ($6::uuid[] IS NULL OR (
+            SELECT movement.to_depot_id FROM vehicle_movements movement
+            WHERE movement.vehicle_id = v.uuid AND movement.latest
+        ) = ANY($6))
+        AND (
+            r.search_vector @@ query
+            OR (r.details % $3 AND fuzzy.similarity > 0)
+        )
+    ORDER BY rank DESC, r.id
+    LIMIT $4";
+
+#[derive(Debug, Clone)]
+pub struct PostgresSearchRepository {
+    connection: Connection,
+}
+
+impl PostgresSearchRepository {
+    pub fn new(database: &PostgresDatabase) -> Self {
+        Self::on(Connection::Pool(database.pool().clone()))
+    }
+
+    pub(crate) fn on(connection: Connection) -> Self {
+        PostgresSearchRepository { connection }
+    }
+
+    async fn search(
+        &self,
+        kind: SearchHitKind,
+        query: &str,
+        organization_id: uuid::Uuid,
+        search: &TextSearch,
+        depot_scope: Option<&[uuid::Uuid]>,
+    ) -> Result<Vec<SearchHit>, SearchRepositoryError> {
+        let error = SearchRepositoryError::DatabaseError;
+        // Rolled back when dropped, with the threshold
+        let mut read = self
+            .connection
+            .write(organization_id)
+            .await
+            .map_err(error)?;
+        sqlx::query("SELECT set_config('pg_trgm.similarity_threshold', $1, true)")
+            .bind(search.min_similarity.to_string())
+            .execute(read.connection())
+            .await
+            .map_err(|e| error(database_error(e)))?;
+        let headline = format!(
+            "StartSel={}, StopSel={}, MaxWords={}, MinWords={}",
+            HIGHLIGHT_START,
+            HIGHLIGHT_END,
+            SNIPPET_WORDS,
+            SNIPPET_WORDS / 2
+        );
+        sqlx::query(query)
+            .bind(organization_id)
+            .bind(search.language.pg_config())
+            .bind(&search.term)
+            .bind(search.limit as i64)
+            .bind(headline)
+            .bind(depot_scope)
+            .fetch_all(read.connection())
+            .await
+            .map_err(database_error)
+            .and_then(|rows| rows.iter().map(|row| search_hit(kind, row)).collect())
+            .map_err(error)
+    }
+}
+
+fn search_hit(kind: SearchHitKind, row: &PgRow) -> Result<SearchHit, String> {
+    Ok(SearchHit {
+        kind,
+        id: get(row, "id")?,
+        title: get(row, "title")?,
+        snippet: get(row, "snippet")?,
+        rank: get(row, "rank")?,
+        matched_by: match get(row, "full_text")? {
+            true => SearchMatch::FullText,
+            false => SearchMatch::Fuzzy,
+        },
+    })
+}
+
+impl SearchRepository for PostgresSearchRepository {
+    async fn search_maintenance_types(
+        &self,
+        organization_id: uuid::Uuid,
+        search: &TextSearch,
+    ) -> Result<Vec<SearchHit>, SearchRepositoryError> {
+        self.search(
+            SearchHitKind::MaintenanceType,
+            MAINTENANCE_TYPES_QUERY,
+            organization_id,
+            search,
+            None,
+        )
+        .await
+    }
+
+    async fn search_vehicles(
+        &self,
+        organization_id: uuid::Uuid,
+        search: &TextSearch,
+        depot_scope: Option<&[uuid::Uuid]>,
+    ) -> Result<Vec<SearchHit>, SearchRepositoryError> {
+        self.search(
+            SearchHitKind::Vehicle,
+            VEHICLES_QUERY,
+            organization_id,
+            search,
+            depot_scope,
+        )
+        .await
+    }
+
+    async fn search_maintenance_records(
+        &self,
+        organization_id: uuid::Uuid,
+        search: &TextSearch,
+        depot_scope: Option<&[uuid::Uuid]>,
+    ) -> Result<Vec<SearchHit>, SearchRepositoryError> {
+        self.search(
+            SearchHitKind::MaintenanceRecord,
+            MAINTENANCE_RECORDS_QUERY,
+            organization_id,
+            search,
+            depot_scope,
+        )
+        .await
+    }
+}
@@ -0,0 +1,497 @@
+use crate::{
+    database::{Connection, PostgresDatabase, database_error, is_unique_violation},
+    filters::{
+        filter_expression_sql::{SqlFilter, SqlFilterParam},
+        vehicle_filter_sql::{self, HOME_DEPOT},
+    },
+    mappers::{
+        get,
+        vehicle_mapper::{VEHICLE_COLUMNS, vehicle_from_row, vehicle_view_from_row},
+    },
+};
+use application::{
+    shared::pagination::{Keyset, KeysetPosition, SortOrder},
+    vehicle::{
+        filters::vehicle_filter::{VehicleFilter, VehicleSortBy},
+        models::vehicle::VehicleView,
+        traits::vehicle_repository::{
+            VehicleApplicationRepository, VehicleApplicationRepositoryError,
+        },
+    },
+};
+use domain::vehicle::{
+    entities::vehicle::{NewVehicle, Vehicle, VehicleIdentity},
+    repositories::vehicle_repository::{
+        TakenIdentifiers, VehicleRepository, VehicleRepositoryError,
+    },
+    value_types::engine_type::EngineType,
+};
+use sqlx::PgConnection;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct PostgresVehicleRepository {
+    connection: Connection,
+}
+
+impl PostgresVehicleRepository {
+    pub fn new(database: &PostgresDatabase) -> Self {
+        PostgresVehicleRepository {
+            connection: Connection::Pool(database.pool().clone()),
+        }
+    }
+}
+
+fn error(error: impl std::fmt::Display) -> VehicleRepositoryError {
+    VehicleRepositoryError::Database(database_error(error))
+}
+
+/// Reads a vehicle, for the repositories hydrating the entities referencing it.
+pub(crate) async fn find_vehicle(
+    connection: &mut PgConnection,
+    id: Uuid,
+) -> Result<Option<VehicleIdentity>, String> {
+    sqlx::query(&format!(
+        "SELECT {} FROM vehicles WHERE uuid = $1",
+        VEHICLE_COLUMNS
+    ))
+    .bind(id)
+    .fetch_optional(connection)
+    .await
+    .map_err(database_error)?
+    .map(|row| vehicle_from_row(&row))
+    .transpose()
+}
+
+/// The views of the vehicles of the organization (`$1`) matching the filter, as the table `v`
+/// with its `home_depot_id` and `search_rank` (`NULL` without a search, ranked like the vehicle
+/// hits of the search repository otherwise). The parameters of the filter follow `$1`.
+fn views(filter: &VehicleFilter) -> SqlFilter {
+    let SqlFilter { sql, mut params } = vehicle_filter_sql::compile(filter, 2);
+    let search_rank = match &filter.search {
+        None => "NULL::real".to_string(),
+        Some(search) => {
+            params.push(SqlFilterParam::Text(search.clone()));
+            let term = format!("${}", params.len() + 1);
+            format!(
+                "CASE
+                     WHEN search_vector @@ websearch_to_tsquery('simple', {term})
+                     THEN ts_rank(search_vector, websearch_to_tsquery('simple', {term}))
+                     ELSE GREATEST(
+                         similarity(make || ' ' || model, {term}),
+                         similarity(license_plate, {term}),
+                         similarity(vin, {term})
+                     ) / 10::real
+                 END"
+            )
+        }
+    };
+    SqlFilter {
+        sql: format!(
+            "(SELECT {}, {} AS home_depot_id, {} AS search_rank
+              FROM vehicles
+              WHERE organization_id = $1 AND {}) v",
+            VEHICLE_COLUMNS, HOME_DEPOT, search_rank, sql
+        ),
+        params,
+    }
+}
+
+/// The rows after `position` when scanning the sort of the vehicles in the given order, binding its value typed
+/// as the column into `params`. NULLs come last in an ascending order, first in a descending one.
+fn after_position(
+    sort_by: VehicleSortBy,
+    position: &KeysetPosition,
+    ascending: bool,
+    params: &mut Vec<SqlFilterParam>,
+) -> Result<String, String> {
+    let column = format!("v.{}", sort_by.as_column_name());
+    let comparison = match ascending {
+        true => ">",
+        false => "<",
+    };
+    let id: Uuid = position
+        .id
+        .parse()
+        .map_err(|_| format!("invalid vehicle position: {}", position.id))?;
+    params.push(SqlFilterParam::Uuid(id));
+    let id = format!("${}", params.len() + 1);
+
+    let Some(value) = &position.value else {
+        return Ok(match ascending {
+            true => format!("({column} IS NULL AND v.uuid > {id})"),
+            false => format!("({column} IS NOT NULL OR v.uuid < {id})"),
+        });
+    };
+    let invalid = || format!("invalid vehicle position: {}", value);
+    let (param, cast) = match sort_by {
+        VehicleSortBy::Year => (
+            SqlFilterParam::Integer(value.parse().map_err(|_| invalid())?),
+            "",
+        ),
+        VehicleSortBy::CreatedAt | VehicleSortBy::UpdatedAt => (
+            SqlFilterParam::Timestamp(
+                chrono::DateTime::parse_from_rfc3339(value)
+                    .map_err(|_| invalid())?
+                    .with_timezone(&chrono::Utc),
+            ),
+            "",
+        ),
+        VehicleSortBy::Relevance => (SqlFilterParam::Text(value.clone()), "::real"),
+        _ => (SqlFilterParam::Text(value.clone()), ""),
+    };
+    params.push(param);
+    let value = format!("${}{}", params.len() + 1, cast);
+    let after = format!("({column}, v.uuid) {comparison} ({value}, {id})");
+    Ok(match ascending {
+        true => format!("({after} OR {column} IS NULL)"),
+        false => after,
+    })
+}
+
+impl VehicleRepository for PostgresVehicleRepository {
+    /// The vehicle has no author, the trait gives none.
+    async fn create(
+        &self,
+        organization_id: Uuid,
+        vehicle: NewVehicle,
+    ) -> Result<VehicleIdentity, VehicleRepositoryError> {
+        let vehicle = Vehicle::new(vehicle)
+            .map_err(|e| VehicleRepositoryError::Database(e.to_string()))?
+            .identity;
+        let engine_type = vehicle.powertrain.engine_type();
+        let engine_type_other = match engine_type {
+            EngineType::Other(name) => Some(name.clone()),
+            _ => None,
+        };
+
+        let mut write = self
+            .connection
+            .write(organization_id)
+            .await
+            .map_err(VehicleRepositoryError::Database)?;
+        let result = sqlx::query(
+            "INSERT INTO vehicles (
+                 uuid, make, model, year, vin, vin_legacy, license_plate, country, engine_type,
+                 engine_type_other, battery_capacity, tank_capacity, created_at, updated_at,
+                 organization_id
+             )
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9::engine_type, $10, $11, $12, $13, $14,
+                 $15)",
+        )
+        .bind(vehicle.id)
+        .bind(&vehicle.make)
+        .bind(&vehicle.model)
+        .bind(vehicle.year as i16)
+        .bind(vehicle.vin.value())
+        .bind(vehicle.vin.is_legacy())
+        .bind(vehicle.license_plate.value())
+        .bind(vehicle.country.value())
+        .bind(engine_type.as_sql())
+        .bind(engine_type_other)
+        .bind(vehicle.powertrain.battery_capacity())
+        .bind(vehicle.powertrain.tank_capacity())
+        .bind(vehicle.created_at)
+        .bind(vehicle.updated_at)
+        .bind(organization_id)
+        .execute(write.connection())
+        .await;
+
+        match result {
+            Ok(_) => {
+                write
+                    .commit()
+                    .await
+                    .map_err(VehicleRepositoryError::Database)?;
+                Ok(vehicle)
+            }
+            // Report a duplicate with the id of the existing vehicle, read once the failed write
+            // is rolled back (the unique check waited for the write holding the key to commit)
+            Err(e) if is_unique_violation(&e) => {
+                drop(write);
+                let mut session = self
+                    .connection
+                    .session(organization_id)
+                    .await
+                    .map_err(VehicleRepositoryError::Database)?;
+                let existing: Option<(Uuid,)> = sqlx::query_as(
+                    "SELECT uuid FROM vehicles
+                     WHERE organization_id = $1
+                         AND (vin = $2 OR (country = $3 AND license_plate = $4))",
+                )
+                .bind(organization_id)
+                .bind(vehicle.vin.value())
+                .bind(vehicle.country.value())
+                .bind(vehicle.license_plate.value())
+                .fetch_optional(session.connection())
+                .await
+                .map_err(error)?;
+                match existing {
+                    Some((id,)) => Err(VehicleRepositoryError::AlreadyExists(id)),
+                    None => Err(error(e)),
+                }
+            }
+            Err(e) => Err(error(e)),
+        }
+    }
+
+    async fn find_by_id(
+        &self,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> Result<Option<VehicleIdentity>, VehicleRepositoryError> {
+        let mut session = self
+            .connection
+            .session(organization_id)
+            .await
+            .map_err(VehicleRepositoryError::Database)?;
+        sqlx::query(&format!(
+            "SELECT {} FROM vehicles WHERE organization_id = $1 AND uuid = $2",
+            VEHICLE_COLUMNS
+        ))
+        .bind(organization_id)
+        .bind(id)
+        .fetch_optional(session.connection())
+        .await
+        .map_err(database_error)
+        .and_then(|row| row.map(|row| vehicle_from_row(&row)).transpose())
+        .map_err(VehicleRepositoryError::Database)
+    }
+
+    async fn find_all(
+        &self,
+        organization_id: Uuid,
+    ) -> Result<Vec<VehicleIdentity>, VehicleRepositoryError> {
+        let mut session = self
+            .connection
+            .session(organization_id)
+            .await
+            .map_err(VehicleRepositoryError::Database)?;
+        sqlx::query(&format!(
+            "SELECT {} FROM vehicles WHERE organization_id = $1 ORDER BY created_at, uuid",
+            VEHICLE_COLUMNS
+        ))
+        .bind(organization_id)
+        .fetch_all(session.connection())
+        .await
+        .map_err(database_error)
+        .and_then(|rows| rows.iter().map(vehicle_from_row).collect())
+        .map_err(VehicleRepositoryError::Database)
+    }
+
+    async fn exists_by_vin_or_license_plate(
+        &self,
+        organization_id: Uuid,
+        vin: &str,
+        country: &str,
+        license_plate: &str,
+    ) -> Result<bool, VehicleRepositoryError> {
+        let mut session = self
+            .connection
+            .session(organization_id)
+            .await
+            .map_err(VehicleRepositoryError::Database)?;
+        let (exists,): (bool,) = sqlx::query_as(
+            "SELECT EXISTS(
+                 SELECT 1 FROM vehicles
+                 WHERE organization_id = $1
+                     AND (vin = $2 OR (country = $3 AND license_plate = $4))
+             )",
+        )
+        .bind(organization_id)
+        .bind(vin)
+        .bind(country)
+        .bind(license_plate)
+        .fetch_one(session.connection())
+        .await
+        .map_err(error)?;
+        Ok(exists)
+    }
+
+    /// The identifiers are bound as arrays, a statement each for the VINs and the plates.
+    async fn find_taken_identifiers(
+        &self,
+        organization_id: Uuid,
+        vins: &[String],
+        license_plates: &[(String, String)],
+    ) -> Result<TakenIdentifiers, VehicleRepositoryError> {
+        let mut taken = TakenIdentifiers::default();
+        if vins.is_empty() && license_plates.is_empty() {
+            return Ok(taken);
+        }
+        let mut session = self
+            .connection
+            .session(organization_id)
+            .await
+            .map_err(VehicleRepositoryError::Database)?;
+
+        let rows: Vec<(String,)> =
+            sqlx::query_as("SELECT vin FROM vehicles WHERE organization_id = $1 AND vin = ANY($2)")
+                .bind(organization_id)
+                .bind(vins)
+                .fetch_all(session.connection())
+                .await
+                .map_err(error)?;
+        taken.vins.extend(rows.into_iter().map(|(vin,)| vin));
+
+        let (countries, plates): (Vec<&str>, Vec<&str>) = license_plates
+            .iter()
+            .map(|(country, plate)| (country.as_str(), plate.as_str()))
+            .unzip();
+        let rows: Vec<(String, String)> = sqlx::query_as(
+            "SELECT country, license_plate FROM vehicles
+             WHERE organization_id = $1
+                 AND (country, license_plate) IN (
+                     SELECT * FROM UNNEST($2::text[], $3::text[])
+                 )",
+        )
+        .bind(organization_id)
+        .bind(countries)
+        .bind(plates)
+        .fetch_all(session.connection())
+        .await
+        .map_err(error)?;
+        taken.license_plates.extend(rows);
+        Ok(taken)
+    }
+
+    async fn delete(
+        &self,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> Result<bool, VehicleRepositoryError> {
+        let mut write = self
+            .connection
+            .write(organization_id)
+            .await
+            .map_err(VehicleRepositoryError::Database)?;
+        let result = sqlx::query("DELETE FROM vehicles WHERE organization_id = $1 AND uuid = $2")
+            .bind(organization_id)
+            .bind(id)
+            .execute(write.connection())
+            .await
+            .map_err(error)?;
+        write
+            .commit()
+            .await
+            .map_err(VehicleRepositoryError::Database)?;
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+impl VehicleApplicationRepository for PostgresVehicleRepository {
+    async fn get_by_filter(
+        &self,
+        organization_id: Uuid,
+        filter: VehicleFilter,
+    ) -> Result<Vec<VehicleView>, VehicleApplicationRepositoryError> {
+        let error = VehicleApplicationRepositoryError::DatabaseError;
+        let order = match filter.sort_order {
+            SortOrder::Asc => "ASC",
+            SortOrder::Desc => "DESC",
+        };
+        let views = views(&filter);
+        // A page size of 0 reads every vehicle
+        let page = match filter.page_size {
+            0 => String::new(),
+            size => format!(
+                "LIMIT {} OFFSET {}",
+                size,
+                u64::from(filter.page.saturating_sub(1)) * u64::from(size)
+            ),
+        };
+        let sql = format!(
+            "SELECT * FROM {} ORDER BY v.{} {}, v.uuid {} {}",
+            views.sql,
+            filter.sort_by.unwrap_or_default().as_column_name(),
+            order,
+            order,
+            page
+        );
+
+        let mut session = self
+            .connection
+            .session(organization_id)
+            .await
+            .map_err(error)?;
+        views
+            .bind(sqlx::query(&sql).bind(organization_id))
+            .fetch_all(session.connection())
+            .await
+            .map_err(database_error)
+            .and_then(|rows| rows.iter().map(vehicle_view_from_row).collect())
+            .map_err(error)
+    }
+
+    async fn get_page(
+        &self,
+        organization_id: Uuid,
+        filter: VehicleFilter,
+        keyset: &Keyset,
+    ) -> Result<Vec<VehicleView>, VehicleApplicationRepositoryError> {
+        let error = VehicleApplicationRepositoryError::DatabaseError;
+        let sort_by = filter.sort_by.unwrap_or_default();
+        // A previous page is scanned backwards from its position
+        let ascending = (keyset.sort_order == SortOrder::Asc) != keyset.is_backward();
+        let order = match ascending {
+            true => "ASC",
+            false => "DESC",
+        };
+        let mut views = views(&filter);
+        let after = match &keyset.position {
+            Some(position) => {
+                after_position(sort_by, position, ascending, &mut views.params).map_err(error)?
+            }
+            None => "TRUE".to_string(),
+        };
+        let sql = format!(
+            "SELECT * FROM {} WHERE {} ORDER BY v.{} {}, v.uuid {} LIMIT {}",
+            views.sql,
+            after,
+            sort_by.as_column_name(),
+            order,
+            order,
+            keyset.limit
+        );
+
+        let mut session = self
+            .connection
+            .session(organization_id)
+            .await
+            .map_err(error)?;
+        views
+            .bind(sqlx::query(&sql).bind(organization_id))
+            .fetch_all(session.connection())
+            .await
+            .map_err(database_error)
+            .and_then(|rows| rows.iter().map(vehicle_view_from_row).collect())
+            .map_err(error)
+    }
+
+    async fn count(
+        &self,
+        organization_id: Uuid,
+        filter: VehicleFilter,
+    ) -> Result<u64, VehicleApplicationRepositoryError> {
+        let error = VehicleApplicationRepositoryError::DatabaseError;
+        let filter = vehicle_filter_sql::compile(&filter, 2);
+        let sql = format!(
+            "SELECT COUNT(*) AS count FROM vehicles WHERE organization_id = $1 AND {}",
+            filter.sql
+        );
+
+        let mut session = self
+            .connection
+            .session(organization_id)
+            .await
+            .map_err(error)?;
+        let row = filter
+            .bind(sqlx::query(&sql).bind(organization_id))
+            .fetch_one(session.connection())
+            .await
+            .map_err(|e| error(database_error(e)))?;
+        get::<i64>(&row, "count")
+            .map(|count| count as u64)
+            .map_err(error)
+    }
+}
@@ -0,0 +1,333 @@
+use crate::{
+    database::{Connection, PostgresDatabase, database_error},
+    filters::filter_expression_sql::compile,
+    mappers::{
+        get,
+        vehicle_status_mapper::{VEHICLE_STATUS_COLUMNS, vehicle_status_from_row},
+    },
+};
+use application::{
+    shared::{
+        filter_expression::filter_ast::FilterExpression,
+        pagination::{Keyset, KeysetPosition, SortOrder},
+    },
+    vehicle::traits::vehicle_status_repository::{
+        VehicleStatusApplicationRepository, VehicleStatusApplicationRepositoryError,
+    },
+};
+use domain::vehicle::{
+    entities::vehicle_status::VehicleStatusIdentity,
+    repositories::vehicle_status_repository::{
+        VehicleStatusRepository, VehicleStatusRepositoryError,
+    },
+};
+use sqlx::PgConnection;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct PostgresVehicleStatusRepository {
+    connection: Connection,
+}
+
+impl PostgresVehicleStatusRepository {
+    pub fn new(database: &PostgresDatabase) -> Self {
+        Self::on(Connection::Pool(database.pool().clone()))
+    }
+
+    pub(crate) fn on(connection: Connection) -> Self {
+        PostgresVehicleStatusRepository { connection }
+    }
+}
+
+/// Reads a status, for the repositories hydrating the entities referencing it.
+pub(crate) async fn find_vehicle_status(
+    connection: &mut PgConnection,
+    id: i32,
+) -> Result<Option<VehicleStatusIdentity>, String> {
+    sqlx::query(&format!(
+        "SELECT {} FROM vehicle_statuses WHERE id = $1",
+        VEHICLE_STATUS_COLUMNS
+    ))
+    .bind(id)
+    .fetch_optional(connection)
+    .await
+    .map_err(database_error)?
+    .map(|row| vehicle_status_from_row(&row))
+    .transpose()
+}
+
+impl VehicleStatusRepository for PostgresVehicleStatusRepository {
+    /// The table has no `performed_at` column, the status is performed when it is created.
+    async fn create(
+        &self,
+        organization_id: Uuid,
+        status: VehicleStatusIdentity,
+    ) -> Result<VehicleStatusIdentity, VehicleStatusRepositoryError> {
+        let error = VehicleStatusRepositoryError::Database;
+        let mut write = self
+            .connection
+            .write(organization_id)
+            .await
+            .map_err(error)?;
+
+        // Statuses of a vehicle are written one at a time, otherwise a concurrent write would
+        // miss the latest status of this one and keep its own latest too
+        sqlx::query("SELECT 1 FROM vehicles WHERE uuid = $1 FOR NO KEY UPDATE")
+            .bind(status.vehicle_id)
+            .execute(write.connection())
+            .await
+            .map_err(|e| error(database_error(e)))?;
+        // A back-dated status is inserted into the history, the latest one stays
+        let (later,): (bool,) = sqlx::query_as(
+            "SELECT EXISTS (
+                 SELECT 1 FROM vehicle_statuses
+                 WHERE organization_id = $1 AND vehicle_id = $2 AND created_at > $3
+             )",
+        )
+        .bind(organization_id)
+        .bind(status.vehicle_id)
+        .bind(status.performed_at)
+        .fetch_one(write.connection())
+        .await
+        .map_err(|e| error(database_error(e)))?;
+        if !later {
+            sqlx::query(
+                "UPDATE vehicle_statuses SET latest = false
+                 WHERE organization_id = $1 AND vehicle_id = $2 AND latest = true",
+            )
+            .bind(organization_id)
+            .bind(status.vehicle_id)
+            .execute(write.connection())
+            .await
+            .map_err(|e| error(database_error(e)))?;
+        }
+        let (id,): (i32,) = sqlx::query_as(
+            "INSERT INTO vehicle_statuses (
+                 vehicle_id, performed_by, odometer, engine_hour_meter, fuel_level, notes, latest,
+                 created_at, updated_at, organization_id
+             )
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+             RETURNING id",
+        )
+        .bind(status.vehicle_id)
+        .bind(status.performed_by)
+        .bind(status.odometer)
+        .bind(status.engine_hour_meter)
+        .bind(status.fuel_level)
+        .bind(&status.notes)
+        .bind(!later)
+        .bind(status.performed_at)
+        .bind(status.updated_at)
+        .bind(organization_id)
+        .fetch_one(write.connection())
+        .await
+        .map_err(|e| error(database_error(e)))?;
+
+        write.commit().await.map_err(error)?;
+        Ok(VehicleStatusIdentity {
+            id,
+            created_at: status.performed_at,
+            ..status
+        })
+    }
+
+    async fn find_latest(
+        &self,
+        organization_id: Uuid,
+        vehicle_id: Uuid,
+    ) -> Result<Option<VehicleStatusIdentity>, VehicleStatusRepositoryError> {
+        let mut session = self
+            .connection
+            .session(organization_id)
+            .await
+            .map_err(VehicleStatusRepositoryError::Database)?;
+        sqlx::query(&format!(
+            "SELECT {} FROM vehicle_statuses
+             WHERE organization_id = $1 AND vehicle_id = $2 AND latest = true",
+            VEHICLE_STATUS_COLUMNS
+        ))
+        .bind(organization_id)
+        .bind(vehicle_id)
+        .fetch_optional(session.connection())
+        .await
+        .map_err(database_error)
+        .and_then(|row| row.map(|row| vehicle_status_from_row(&row)).transpose())
+        .map_err(VehicleStatusRepositoryError::Database)
+    }
+
+    async fn find_latest_before(
+        &self,
+        organization_id: Uuid,
+        vehicle_id: Uuid,
+        before: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Option<VehicleStatusIdentity>, VehicleStatusRepositoryError> {
+        let mut session = self
+            .connection
+            .session(organization_id)
+            .await
+            .map_err(VehicleStatusRepositoryError::Database)?;
+        sqlx::query(&format!(
+            "SELECT {} FROM vehicle_statuses
+             WHERE organization_id = $1 AND vehicle_id = $2 AND created_at < $3
+             ORDER BY created_at DESC, id DESC
+             LIMIT 1",
+            VEHICLE_STATUS_COLUMNS
+        ))
+        .bind(organization_id)
+        .bind(vehicle_id)
+        .bind(before)
+        .fetch_optional(session.connection())
+        .await
+        .map_err(database_error)
+        .and_then(|row| row.map(|row| vehicle_status_from_row(&row)).transpose())
+        .map_err(VehicleStatusRepositoryError::Database)
+    }
+
+    async fn find_by_vehicle(
+        &self,
+        organization_id: Uuid,
+        vehicle_id: Uuid,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<VehicleStatusIdentity>, VehicleStatusRepositoryError> {
+        let mut session = self
+            .connection
+            .session(organization_id)
+            .await
+            .map_err(VehicleStatusRepositoryError::Database)?;
+        sqlx::query(&format!(
+            "SELECT {} FROM vehicle_statuses
+             WHERE organization_id = $1
+                 AND vehicle_id = $2
+                 AND ($3::timestamptz IS NULL OR created_at >= $3)
+                 AND ($4::timestamptz IS NULL OR created_at <= $4)
+             ORDER BY created_at, id",
+            VEHICLE_STATUS_COLUMNS
+        ))
+        .bind(organization_id)
+        .bind(vehicle_id)
+        .bind(from)
+        .bind(to)
+        .fetch_all(session.connection())
+        .await
+        .map_err(database_error)
+        .and_then(|rows| rows.iter().map(vehicle_status_from_row).collect())
+        .map_err(VehicleStatusRepositoryError::Database)
+    }
+}
+
+/// Reads the position of a page of statuses: a creation date (RFC 3339) and an id.
+fn status_position(
+    position: &KeysetPosition,
+) -> Result<(chrono::DateTime<chrono::Utc>, i32), String> {
+    let created_at = position
+        .value
+        .as_deref()
+        .and_then(|value| chrono::DateTime::parse_from_rfc3339(value).ok())
+        .ok_or_else(|| format!("invalid status position: {:?}", position.value))?;
+    let id = position
+        .id
+        .parse()
+        .map_err(|_| format!("invalid status position: {}", position.id))?;
+    Ok((created_at.with_timezone(&chrono::Utc), id))
+}
+
+impl VehicleStatusApplicationRepository for PostgresVehicleStatusRepository {
+    /// Statuses are sorted by creation date then id, the only sort of the list.
+    async fn get_page(
+        &self,
+        organization_id: Uuid,
+        vehicle_id: Uuid,
+        filter: Option<&FilterExpression>,
+        keyset: &Keyset,
+    ) -> Result<Vec<VehicleStatusIdentity>, VehicleStatusApplicationRepositoryError> {
+        let error = VehicleStatusApplicationRepositoryError::DatabaseError;
+        let position = keyset
+            .position
+            .as_ref()
+            .map(status_position)
+            .transpose()
+            .map_err(error)?;
+        // A previous page is scanned backwards from its position
+        let ascending = (keyset.sort_order == SortOrder::Asc) != keyset.is_backward();
+        let (comparison, order) = match ascending {
+            true => (">", "ASC"),
+            false => ("<", "DESC"),
+        };
+
+        let mut conditions = vec!["organization_id = $1 AND vehicle_id = $2".to_string()];
+        if position.is_some() {
+            conditions.push(format!("(created_at, id) {} ($3, $4)", comparison));
+        }
+        let first_param = match position {
+            Some(_) => 5,
+            None => 3,
+        };
+        let filter = filter.map(|filter| compile(filter, first_param));
+        if let Some(filter) = &filter {
+            conditions.push(filter.sql.clone());
+        }
+        let sql = format!(
+            "SELECT {} FROM vehicle_statuses
+             WHERE {}
+             ORDER BY created_at {}, id {}
+             LIMIT {}",
+            VEHICLE_STATUS_COLUMNS,
+            conditions.join(" AND "),
+            order,
+            order,
+            keyset.limit
+        );
+
+        let mut query = sqlx::query(&sql).bind(organization_id).bind(vehicle_id);
+        if let Some((created_at, id)) = position {
+            query = query.bind(created_at).bind(id);
+        }
+        if let Some(filter) = &filter {
+            query = filter.bind(query);
+        }
+        let mut session = self
+            .connection
+            .session(organization_id)
+            .await
+            .map_err(error)?;
+        query
+            .fetch_all(session.connection())
+            .await
+            .map_err(database_error)
+            .and_then(|rows| rows.iter().map(vehicle_status_from_row).collect())
+            .map_err(error)
+    }
+
+    async fn count(
+        &self,
+        organization_id: Uuid,
+        vehicle_id: Uuid,
+        filter: Option<&FilterExpression>,
+    ) -> Result<u64, VehicleStatusApplicationRepositoryError> {
+        let error = VehicleStatusApplicationRepositoryError::DatabaseError;
+        let filter = filter.map(|filter| compile(filter, 3));
+        let sql = format!(
+            "SELECT COUNT(*) AS count FROM vehicle_statuses
+             WHERE organization_id = $1 AND vehicle_id = $2 AND {}",
+            filter.as_ref().map_or("true", |filter| filter.sql.as_str())
+        );
+
+        let mut query = sqlx::query(&sql).bind(organization_id).bind(vehicle_id);
+        if let Some(filter) = &filter {
+            query = filter.bind(query);
+        }
+        let mut session = self
+            .connection
+            .session(organization_id)
+            .await
+            .map_err(error)?;
+        let row = query
+            .fetch_one(session.connection())
+            .await
+            .map_err(|e| error(database_error(e)))?;
+        get::<i64>(&row, "count")
+            .map(|count| count as u64)
+            .map_err(error)
+    }
+}
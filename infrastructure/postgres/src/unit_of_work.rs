@@ -0,0 +1,140 @@
+//! Transactions of the unit of work, on a connection of the pool; a nested transaction is a
+//! savepoint.
+use crate::{
+    database::{Connection, PostgresDatabase, SharedTransaction},
+    repositories::{
+        maintenance_record_repository::PostgresMaintenanceRecordRepository,
+        vehicle_status_repository::PostgresVehicleStatusRepository,
+    },
+};
+use application::shared::traits::unit_of_work::{Transaction, UnitOfWork, UnitOfWorkError};
+use sqlx::PgPool;
+use std::{marker::PhantomData, sync::Arc};
+
+/// Starts the transactions of a database.
+#[derive(Debug, Clone)]
+pub struct PostgresUnitOfWork {
+    pool: PgPool,
+}
+
+impl PostgresUnitOfWork {
+    pub fn new(database: &PostgresDatabase) -> Self {
+        Self {
+            pool: database.pool().clone(),
+        }
+    }
+}
+
+impl UnitOfWork for PostgresUnitOfWork {
+    type Transaction = PostgresTransaction<'static>;
+
+    /// The transaction is set to the organization for the row-level security policies.
+    async fn begin(
+        &self,
+        organization_id: uuid::Uuid,
+    ) -> Result<Self::Transaction, UnitOfWorkError> {
+        let shared = SharedTransaction::begin(&self.pool, organization_id)
+            .await
+            .map_err(UnitOfWorkError::DatabaseError)?;
+        Ok(PostgresTransaction::new(shared, 0))
+    }
+}
+
+/// A transaction (depth 0) or a savepoint `uow_<depth>` of the transaction.
+#[derive(Debug)]
+pub struct PostgresTransaction<'a> {
+    shared: Arc<SharedTransaction>,
+    depth: u32,
+    ended: bool,
+    vehicle_statuses: PostgresVehicleStatusRepository,
+    maintenance_records: PostgresMaintenanceRecordRepository,
+    /// A nested transaction borrows the outer one.
+    outer: PhantomData<&'a mut ()>,
+}
+
+impl PostgresTransaction<'_> {
+    fn new(shared: Arc<SharedTransaction>, depth: u32) -> Self {
+        let connection = Connection::Transaction(shared.clone());
+        Self {
+            shared,
+            depth,
+            ended: false,
+            vehicle_statuses: PostgresVehicleStatusRepository::on(connection.clone()),
+            maintenance_records: PostgresMaintenanceRecordRepository::on(connection),
+            outer: PhantomData,
+        }
+    }
+
+    async fn end(mut self, statements: &[String]) -> Result<(), UnitOfWorkError> {
+        self.ended = true;
+        for statement in statements {
+            self.shared
+                .run(statement)
+                .await
+                .map_err(UnitOfWorkError::DatabaseError)?;
+        }
+        Ok(())
+    }
+}
+
+impl Transaction for PostgresTransaction<'_> {
+    type VehicleStatuses = PostgresVehicleStatusRepository;
+    type MaintenanceRecords = PostgresMaintenanceRecordRepository;
+    type Nested<'b>
+        = PostgresTransaction<'b>
+    where
+        Self: 'b;
+
+    fn vehicle_statuses(&self) -> &Self::VehicleStatuses {
+        &self.vehicle_statuses
+    }
+
+    fn maintenance_records(&self) -> &Self::MaintenanceRecords {
+        &self.maintenance_records
+    }
+
+    async fn begin(&mut self) -> Result<Self::Nested<'_>, UnitOfWorkError> {
+        let depth = self.depth + 1;
+        self.shared
+            .run(&format!("SAVEPOINT uow_{}", depth))
+            .await
+            .map_err(UnitOfWorkError::DatabaseError)?;
+        Ok(PostgresTransaction::new(self.shared.clone(), depth))
+    }
+
+    async fn commit(self) -> Result<(), UnitOfWorkError> {
+        let statement = match self.depth {
+            0 => "COMMIT".to_string(),
+            depth => format!("RELEASE SAVEPOINT uow_{}", depth),
+        };
+        self.end(&[statement]).await
+    }
+
+    async fn rollback(self) -> Result<(), UnitOfWorkError> {
+        let statements = match self.depth {
+            0 => vec!["ROLLBACK".to_string()],
+            depth => vec![
+                format!("ROLLBACK TO SAVEPOINT uow_{}", depth),
+                format!("RELEASE SAVEPOINT uow_{}", depth),
+            ],
+        };
+        self.end(&statements).await
+    }
+}
+
+impl Drop for PostgresTransaction<'_> {
+    fn drop(&mut self) {
+        if self.ended {
+            return;
+        }
+        match self.depth {
+            0 => self.shared.abandon(),
+            depth => {
+                self.shared
+                    .defer(format!("ROLLBACK TO SAVEPOINT uow_{}", depth));
+                self.shared
+                    .defer(format!("RELEASE SAVEPOINT uow_{}", depth));
+            }
+        }
+    }
+}
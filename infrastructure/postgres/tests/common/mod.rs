@@ -0,0 +1,281 @@
+//! The database of `DATABASE_URL` for the tests of the PostgreSQL repositories; a test returns
+//! early when it isn't set. The tests share the database, each one seeds its own organization.
+#![allow(dead_code)]
+
+use postgres::database::PostgresDatabase;
+use sqlx::{PgConnection, Postgres};
+use uuid::Uuid;
+
+/// Connects to the database of `DATABASE_URL` and migrates it, `None` when it isn't set.
+pub async fn database() -> Option<PostgresDatabase> {
+    let Ok(url) = std::env::var("DATABASE_URL") else {
+        eprintln!("DATABASE_URL isn't set, skipping the test");
+        return None;
+    };
+    Some(
+        PostgresDatabase::connect(&url)
+            .await
+            .expect("database connected and migrated"),
+    )
+}
+
+/// An organization with a user, seeded in the transaction of `seed`.
+pub struct Organization {
+    pub id: Uuid,
+    pub user_id: Uuid,
+}
+
+/// A transaction set to an organization, for inserting the rows of a test.
+pub struct Seed {
+    pub organization: Organization,
+    transaction: sqlx::Transaction<'static, Postgres>,
+}
+
+impl Seed {
+    /// Creates an organization with a user, and starts a transaction to seed its rows.
+    pub async fn new(database: &PostgresDatabase) -> Self {
+        let id = Uuid::new_v4();
+        let user_id = Uuid::new_v4();
+        let mut transaction = database.pool().begin().await.expect("transaction started");
+        sqlx::query("SELECT set_config('app.organization_id', $1, true)")
+            .bind(id.to_string())
+            .execute(&mut *transaction)
+            .await
+            .expect("organization set");
+        sqlx::query("INSERT INTO organizations (id, name) VALUES ($1, $2)")
+            .bind(id)
+            .bind(format!("Organization {}", id))
+            .execute(&mut *transaction)
+            .await
+            .expect("organization created");
+        sqlx::query(
+            "INSERT INTO users (uuid, username, email, first_name, last_name, organization_id)
+             VALUES ($1, 'alice', $2, 'Alice', 'Tester', $3)",
+        )
+        .bind(user_id)
+        .bind(format!("alice.{}@fleet.test", user_id))
+        .bind(id)
+        .execute(&mut *transaction)
+        .await
+        .expect("user created");
+        Seed {
+            organization: Organization { id, user_id },
+            transaction,
+        }
+    }
+
+    pub fn connection(&mut self) -> &mut PgConnection {
+        &mut self.transaction
+    }
+
+    /// Seeds a vehicle, returns its uuid.
+    pub async fn vehicle(&mut self, make: &str, model: &str, plate: &str, vin: &str) -> Uuid {
+        let (uuid,): (Uuid,) = sqlx::query_as(
+            "INSERT INTO vehicles (
+                 make, model, year, vin, license_plate, engine_type, created_by, updated_by,
+                 organization_id
+             )
+             VALUES ($1, $2, 2020, $3, $4, 'Gasoline', $5, $5, $6)
+             RETURNING uuid",
+        )
+        .bind(make)
+        .bind(model)
+        .bind(vin)
+        .bind(plate)
+        .bind(self.organization.user_id)
+        .bind(self.organization.id)
+        .fetch_one(&mut *self.transaction)
+        .await
+        .expect("vehicle created");
+        uuid
+    }
+
+    /// Seeds a status of the vehicle created at `created_at`, returns its id.
+    pub async fn status(
+        &mut self,
+        vehicle_id: Uuid,
+        odometer: i32,
+        notes: &str,
+        created_at: chrono::DateTime<chrono::Utc>,
+    ) -> i32 {
+        let (id,): (i32,) = sqlx::query_as(
+            "INSERT INTO vehicle_statuses (
+                 vehicle_id, performed_by, odometer, notes, created_at, updated_at,
+                 organization_id
+             )
+             VALUES ($1, $2, $3, $4, $5, $5, $6)
+             RETURNING id",
+        )
+        .bind(vehicle_id)
+        .bind(self.organization.user_id)
+        .bind(odometer)
+        .bind(notes)
+        .bind(created_at)
+        .bind(self.organization.id)
+        .fetch_one(&mut *self.transaction)
+        .await
+        .expect("status created");
+        id
+    }
+
+    /// Seeds a maintenance type, deprecated or not, returns its id.
+    pub async fn maintenance_type(
+        &mut self,
+        name: &str,
+        description: &str,
+        deprecated: bool,
+    ) -> i32 {
+        let (id,): (i32,) = sqlx::query_as(
+            "INSERT INTO maintenance_types (
+                 name, description, created_by, updated_by, deprecated_at, deprecated_by,
+                 organization_id
+             )
+             VALUES (
+                 $1, $2, $3, $3, CASE WHEN $4 THEN now() END, CASE WHEN $4 THEN $3 END, $5
+             )
+             RETURNING id",
+        )
+        .bind(name)
+        .bind(description)
+        .bind(self.organization.user_id)
+        .bind(deprecated)
+        .bind(self.organization.id)
+        .fetch_one(&mut *self.transaction)
+        .await
+        .expect("maintenance type created");
+        id
+    }
+
+    /// Seeds a record of a maintenance of the vehicle (a rule every 10 000 km and a status),
+    /// returns its id.
+    pub async fn maintenance_record(
+        &mut self,
+        vehicle_id: Uuid,
+        maintenance_type_id: i32,
+        details: &str,
+    ) -> Uuid {
+        let (maintenance_id,): (i32,) = sqlx::query_as(
+            "INSERT INTO maintenances (
+                 vehicle_id, maintenance_type_id, interval_type, interval_value, red_threshold,
+                 yellow_threshold, created_by, updated_by, organization_id
+             )
+             VALUES ($1, $2, 'Kilometers', 10000, 90, 75, $3, $3, $4)
+             ON CONFLICT (vehicle_id, maintenance_type_id, interval_type)
+                 DO UPDATE SET updated_at = now()
+             RETURNING id",
+        )
+        .bind(vehicle_id)
+        .bind(maintenance_type_id)
+        .bind(self.organization.user_id)
+        .bind(self.organization.id)
+        .fetch_one(&mut *self.transaction)
+        .await
+        .expect("maintenance created");
+        let (status_id,): (i32,) = sqlx::query_as(
+            "INSERT INTO vehicle_statuses (vehicle_id, performed_by, odometer, organization_id)
+             VALUES ($1, $2, 10000, $3)
+             RETURNING id",
+        )
+        .bind(vehicle_id)
+        .bind(self.organization.user_id)
+        .bind(self.organization.id)
+        .fetch_one(&mut *self.transaction)
+        .await
+        .expect("status created");
+        let (id,): (Uuid,) = sqlx::query_as(
+            "INSERT INTO maintenance_records (
+                 vehicle_id, maintenance_id, performed_by, vehicle_status_id, performed_at,
+                 details, created_by, updated_by, organization_id
+             )
+             VALUES ($1, $2, $3, $4, now(), $5, $3, $3, $6)
+             RETURNING id",
+        )
+        .bind(vehicle_id)
+        .bind(maintenance_id)
+        .bind(self.organization.user_id)
+        .bind(status_id)
+        .bind(details)
+        .bind(self.organization.id)
+        .fetch_one(&mut *self.transaction)
+        .await
+        .expect("maintenance record created");
+        id
+    }
+
+    /// Moves a record to `performed_at` and gives it a cost in `currency`, of `(kind, quantity,
+    /// unit price)` lines.
+    pub async fn cost(
+        &mut self,
+        record_id: Uuid,
+        performed_at: chrono::DateTime<chrono::Utc>,
+        currency: &str,
+        lines: &[(&str, &str, &str)],
+    ) {
+        sqlx::query(
+            "UPDATE maintenance_records SET performed_at = $2, currency = $3 WHERE id = $1",
+        )
+        .bind(record_id)
+        .bind(performed_at)
+        .bind(currency)
+        .execute(&mut *self.transaction)
+        .await
+        .expect("record updated");
+        for (position, (kind, quantity, unit_price)) in lines.iter().enumerate() {
+            sqlx::query(
+                "INSERT INTO maintenance_cost_lines (
+                     maintenance_record_id, position, kind, description, quantity, unit_price,
+                     organization_id
+                 )
+                 VALUES (
+                     $1, $2, $3::maintenance_cost_line_kind, $3, $4::numeric, $5::numeric, $6
+                 )",
+            )
+            .bind(record_id)
+            .bind(position as i16)
+            .bind(kind)
+            .bind(quantity)
+            .bind(unit_price)
+            .bind(self.organization.id)
+            .execute(&mut *self.transaction)
+            .await
+            .expect("cost line created");
+        }
+    }
+
+    /// Bases a vehicle at a new depot (in a new region), returns the depot id.
+    pub async fn home_depot(&mut self, vehicle_id: Uuid, name: &str) -> Uuid {
+        let (depot_id,): (Uuid,) = sqlx::query_as(
+            "WITH region AS (
+                 INSERT INTO regions (name, organization_id) VALUES ($1, $2) RETURNING id
+             )
+             INSERT INTO depots (region_id, name, organization_id)
+             SELECT id, $1, $2 FROM region
+             RETURNING id",
+        )
+        .bind(name)
+        .bind(self.organization.id)
+        .fetch_one(&mut *self.transaction)
+        .await
+        .expect("depot created");
+        sqlx::query(
+            "INSERT INTO vehicle_movements (
+                 vehicle_id, to_depot_id, moved_at, moved_by, latest, organization_id
+             )
+             VALUES ($1, $2, now(), $3, true, $4)",
+        )
+        .bind(vehicle_id)
+        .bind(depot_id)
+        .bind(self.organization.user_id)
+        .bind(self.organization.id)
+        .execute(&mut *self.transaction)
+        .await
+        .expect("vehicle moved");
+        depot_id
+    }
+
+    /// Commits the seeded rows.
+    pub async fn commit(self) -> Organization {
+        self.transaction.commit().await.expect("seed committed");
+        self.organization
+    }
+}
@@ -0,0 +1,269 @@
+mod common;
+
+use application::{
+    maintenance::{
+        models::maintenance_cost_sum::{
+            MaintenanceCostGroupBy, MaintenanceCostGroupKey, MaintenanceCostSums, VehicleDistance,
+        },
+        traits::maintenance_cost_repository::MaintenanceCostApplicationRepository,
+    },
+    vehicle::filters::vehicle_filter::{NewVehicleFilter, VehicleFilter},
+};
+use common::Seed;
+use postgres::repositories::maintenance_record_repository::PostgresMaintenanceRecordRepository;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+fn day(date: &str) -> chrono::DateTime<chrono::Utc> {
+    format!("{}T10:00:00Z", date).parse().expect("valid date")
+}
+
+fn all_vehicles() -> VehicleFilter {
+    VehicleFilter::new(NewVehicleFilter::default())
+}
+
+/// `(label, currency, total, record count)` of every group.
+fn totals(sums: &MaintenanceCostSums) -> Vec<(&str, String, Decimal, usize)> {
+    sums.groups
+        .iter()
+        .map(|sum| {
+            (
+                sum.label.as_str(),
+                sum.totals.currency().to_string(),
+                sum.totals.breakdown.total.amount(),
+                sum.totals.record_count,
+            )
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn costs_are_summed_by_group_and_currency() {
+    let Some(database) = common::database().await else {
+        return;
+    };
+    let mut seed = Seed::new(&database).await;
+    let oil_change = seed
+        .maintenance_type("Oil change", "Engine oil", false)
+        .await;
+    let oil_analysis = seed
+        .maintenance_type("Oil analysis", "Oil sample", true)
+        .await;
+    let camry = seed
+        .vehicle("Toyota", "Camry", "123ABC02", "JTDBE32K720123456")
+        .await;
+    let volvo = seed
+        .vehicle("Volvo", "FH16", "456DEF02", "YV2AS02A6EB123456")
+        .await;
+    let astana = seed.home_depot(camry, "Astana").await;
+
+    let record = seed
+        .maintenance_record(camry, oil_change, "Oil change")
+        .await;
+    seed.cost(
+        record,
+        day("2025-06-10"),
+        "KZT",
+        &[("Part", "4.5", "4500.50"), ("Labor", "1.5", "8000")],
+    )
+    .await;
+    let record = seed
+        .maintenance_record(camry, oil_analysis, "Oil sample")
+        .await;
+    seed.cost(record, day("2025-07-02"), "KZT", &[("Fee", "1", "1000")])
+        .await;
+    let record = seed
+        .maintenance_record(volvo, oil_change, "Oil change")
+        .await;
+    seed.cost(record, day("2025-07-15"), "USD", &[("Part", "2", "50")])
+        .await;
+    seed.maintenance_record(volvo, oil_change, "Oil topped up")
+        .await;
+    seed.status(camry, 13_500, "July", day("2025-07-01")).await;
+    let organization = seed.commit().await;
+    let repository = PostgresMaintenanceRecordRepository::new(&database);
+
+    let by_vehicle = repository
+        .sum_costs(
+            organization.id,
+            &all_vehicles(),
+            MaintenanceCostGroupBy::Vehicle,
+            None,
+            None,
+        )
+        .await
+        .expect("costs summed");
+    let mut expected = vec![
+        (
+            camry,
+            ("123ABC02", "KZT".to_string(), Decimal::new(3325225, 2), 2),
+        ),
+        (
+            volvo,
+            ("456DEF02", "USD".to_string(), Decimal::from(100), 1),
+        ),
+    ];
+    expected.sort_by_key(|(vehicle_id, _)| *vehicle_id);
+    assert_eq!(
+        totals(&by_vehicle),
+        expected
+            .into_iter()
+            .map(|(_, totals)| totals)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(by_vehicle.records_without_cost, 1);
+    let camry_sum = by_vehicle
+        .groups
+        .iter()
+        .find(|sum| sum.key == MaintenanceCostGroupKey::Vehicle { vehicle_id: camry })
+        .expect("costs of the Camry");
+    let breakdown = camry_sum.totals.breakdown;
+    assert_eq!(breakdown.parts.amount(), Decimal::new(2025225, 2));
+    assert_eq!(breakdown.labor.amount(), Decimal::from(12_000));
+    assert_eq!(breakdown.fees.amount(), Decimal::from(1_000));
+    assert_eq!(breakdown.taxes.amount(), Decimal::ZERO);
+    assert_eq!(breakdown.labor_hours, Decimal::new(15, 1));
+
+    // The deprecated type keeps its name
+    let by_type = repository
+        .sum_costs(
+            organization.id,
+            &all_vehicles(),
+            MaintenanceCostGroupBy::MaintenanceType,
+            None,
+            None,
+        )
+        .await
+        .expect("costs summed");
+    assert_eq!(
+        totals(&by_type),
+        vec![
+            ("Oil change", "KZT".to_string(), Decimal::new(3225225, 2), 1),
+            ("Oil change", "USD".to_string(), Decimal::from(100), 1),
+            ("Oil analysis", "KZT".to_string(), Decimal::from(1_000), 1),
+        ]
+    );
+
+    let by_month = repository
+        .sum_costs(
+            organization.id,
+            &all_vehicles(),
+            MaintenanceCostGroupBy::Month,
+            Some(day("2025-07-01")),
+            Some(day("2025-12-31")),
+        )
+        .await
+        .expect("costs summed");
+    assert_eq!(
+        by_month
+            .groups
+            .iter()
+            .map(|sum| (sum.key, sum.totals.currency().to_string()))
+            .collect::<Vec<_>>(),
+        vec![
+            (
+                MaintenanceCostGroupKey::Month {
+                    year: 2025,
+                    month: 7
+                },
+                "KZT".to_string()
+            ),
+            (
+                MaintenanceCostGroupKey::Month {
+                    year: 2025,
+                    month: 7
+                },
+                "USD".to_string()
+            ),
+        ]
+    );
+    assert_eq!(by_month.records_without_cost, 0);
+
+    // Only the vehicles of the depot scope
+    let scoped = VehicleFilter {
+        depot_scope: Some(vec![astana]),
+        ..all_vehicles()
+    };
+    let by_vehicle = repository
+        .sum_costs(
+            organization.id,
+            &scoped,
+            MaintenanceCostGroupBy::Vehicle,
+            None,
+            None,
+        )
+        .await
+        .expect("costs summed");
+    assert_eq!(
+        by_vehicle
+            .groups
+            .iter()
+            .map(|sum| sum.key)
+            .collect::<Vec<_>>(),
+        vec![MaintenanceCostGroupKey::Vehicle { vehicle_id: camry }]
+    );
+    let nowhere = VehicleFilter {
+        depot_scope: Some(vec![Uuid::new_v4()]),
+        ..all_vehicles()
+    };
+    assert_eq!(
+        repository
+            .sum_costs(
+                organization.id,
+                &nowhere,
+                MaintenanceCostGroupBy::Vehicle,
+                None,
+                None
+            )
+            .await
+            .expect("costs summed"),
+        MaintenanceCostSums::default()
+    );
+}
+
+#[tokio::test]
+async fn distances_are_read_for_the_vehicles_with_costs() {
+    let Some(database) = common::database().await else {
+        return;
+    };
+    let mut seed = Seed::new(&database).await;
+    let oil_change = seed
+        .maintenance_type("Oil change", "Engine oil", false)
+        .await;
+    let camry = seed
+        .vehicle("Toyota", "Camry", "123ABC02", "JTDBE32K720123456")
+        .await;
+    let volvo = seed
+        .vehicle("Volvo", "FH16", "456DEF02", "YV2AS02A6EB123456")
+        .await;
+    let record = seed
+        .maintenance_record(camry, oil_change, "Oil change")
+        .await;
+    seed.cost(record, day("2025-06-10"), "KZT", &[("Part", "1", "100")])
+        .await;
+    // Without a cost, no distance
+    seed.maintenance_record(volvo, oil_change, "Oil change")
+        .await;
+    seed.status(camry, 12_000, "June", day("2025-06-01")).await;
+    seed.status(camry, 13_500, "July", day("2025-07-01")).await;
+    seed.status(camry, 20_000, "Later", day("2025-09-01")).await;
+    let organization = seed.commit().await;
+    let repository = PostgresMaintenanceRecordRepository::new(&database);
+
+    let distances = repository
+        .distance_by_vehicle(
+            organization.id,
+            &all_vehicles(),
+            Some(day("2025-05-01")),
+            Some(day("2025-08-01")),
+        )
+        .await
+        .expect("distances read");
+    assert_eq!(
+        distances,
+        vec![VehicleDistance {
+            vehicle_id: camry,
+            distance_km: 1_500,
+        }]
+    );
+}
@@ -0,0 +1,264 @@
+mod common;
+
+use application::search::{
+    models::{
+        search_hit::{SearchHit, SearchMatch},
+        search_language::SearchLanguage,
+        text_search::TextSearch,
+    },
+    traits::search_repository::SearchRepository,
+};
+use common::{Organization, Seed};
+use postgres::{
+    database::PostgresDatabase, repositories::search_repository::PostgresSearchRepository,
+};
+
+/// Maintenance types in English and Russian, one deprecated, and an oil change recorded on a
+/// Toyota Camry.
+async fn seeded(database: &PostgresDatabase) -> Organization {
+    let mut seed = Seed::new(database).await;
+    let oil_change = seed
+        .maintenance_type(
+            "Oil change",
+            "Replace the engine oil and the oil filter",
+            false,
+        )
+        .await;
+    seed.maintenance_type(
+        "Brake inspection",
+        "Check the pads and the discs, no oil on them",
+        false,
+    )
+    .await;
+    seed.maintenance_type(
+        "Замена масла",
+        "Замена моторного масла и масляного фильтра",
+        false,
+    )
+    .await;
+    seed.maintenance_type("Oil analysis", "Send an oil sample to the laboratory", true)
+        .await;
+    let camry = seed
+        .vehicle("Toyota", "Camry", "123ABC02", "JTDBE32K720123456")
+        .await;
+    seed.vehicle("Volvo", "FH16", "456DEF02", "YV2AS02A6EB123456")
+        .await;
+    seed.maintenance_record(
+        camry,
+        oil_change,
+        "Replaced the oil filter and both air filters",
+    )
+    .await;
+    seed.commit().await
+}
+
+fn search(term: &str) -> TextSearch {
+    TextSearch::new(term, None, None, None).expect("valid search")
+}
+
+fn titles(hits: &[SearchHit]) -> Vec<&str> {
+    hits.iter().map(|hit| hit.title.as_str()).collect()
+}
+
+#[tokio::test]
+async fn full_text_hits_are_ranked_by_weight() {
+    let Some(database) = common::database().await else {
+        return;
+    };
+    let organization = seeded(&database).await;
+    let repository = PostgresSearchRepository::new(&database);
+
+    let hits = repository
+        .search_maintenance_types(organization.id, &search("oil"))
+        .await
+        .expect("searched");
+    assert_eq!(
+        titles(&hits),
+        vec!["Oil change", "Brake inspection"],
+        "the name weighs more than the description, deprecated types are left out"
+    );
+    assert!(
+        hits.iter()
+            .all(|hit| hit.matched_by == SearchMatch::FullText)
+    );
+    assert!(hits[0].rank > hits[1].rank);
+
+    // Stemmed in both languages
+    let hits = repository
+        .search_maintenance_types(organization.id, &search("changes"))
+        .await
+        .expect("searched");
+    assert_eq!(titles(&hits), vec!["Oil change"]);
+    let russian = search("масло");
+    assert_eq!(russian.language, SearchLanguage::Russian);
+    let hits = repository
+        .search_maintenance_types(organization.id, &russian)
+        .await
+        .expect("searched");
+    assert_eq!(titles(&hits), vec!["Замена масла"]);
+
+    let limited = TextSearch::new("oil", None, None, Some(1)).expect("valid search");
+    let hits = repository
+        .search_maintenance_types(organization.id, &limited)
+        .await
+        .expect("searched");
+    assert_eq!(titles(&hits), vec!["Oil change"]);
+}
+
+#[tokio::test]
+async fn snippets_highlight_the_matched_words() {
+    let Some(database) = common::database().await else {
+        return;
+    };
+    let organization = seeded(&database).await;
+    let repository = PostgresSearchRepository::new(&database);
+
+    let hits = repository
+        .search_maintenance_types(organization.id, &search("filter"))
+        .await
+        .expect("searched");
+    assert_eq!(titles(&hits), vec!["Oil change"]);
+    assert!(
+        hits[0].snippet.ends_with("the oil <mark>filter</mark>"),
+        "an excerpt of the description: {}",
+        hits[0].snippet
+    );
+
+    let hits = repository
+        .search_maintenance_records(organization.id, &search("filters"), None)
+        .await
+        .expect("searched");
+    assert_eq!(titles(&hits), vec!["Oil change 123ABC02"]);
+    assert!(
+        hits[0]
+            .snippet
+            .ends_with("oil <mark>filter</mark> and both air <mark>filters</mark>"),
+        "{}",
+        hits[0].snippet
+    );
+}
+
+#[tokio::test]
+async fn typos_are_fuzzy_hits() {
+    let Some(database) = common::database().await else {
+        return;
+    };
+    let organization = seeded(&database).await;
+    let repository = PostgresSearchRepository::new(&database);
+
+    let hits = repository
+        .search_maintenance_types(organization.id, &search("brake inspektion"))
+        .await
+        .expect("searched");
+    assert_eq!(titles(&hits), vec!["Brake inspection"]);
+    assert_eq!(hits[0].matched_by, SearchMatch::Fuzzy);
+
+    let hits = repository
+        .search_vehicles(organization.id, &search("123ABC20"), None)
+        .await
+        .expect("searched");
+    assert_eq!(titles(&hits), vec!["Toyota Camry 123ABC02"]);
+    assert_eq!(hits[0].matched_by, SearchMatch::Fuzzy);
+
+    let strict = TextSearch::new("123ABC20", None, Some(0.9), None).expect("valid search");
+    let hits = repository
+        .search_vehicles(organization.id, &strict, None)
+        .await
+        .expect("searched");
+    assert!(hits.is_empty(), "{:?}", titles(&hits));
+}
+
+#[tokio::test]
+async fn full_text_hits_rank_above_fuzzy_ones() {
+    let Some(database) = common::database().await else {
+        return;
+    };
+    let mut seed = Seed::new(&database).await;
+    seed.vehicle("Toyota", "Camry", "123ABC02", "JTDBE32K720123456")
+        .await;
+    // Similar to "camry" without containing it
+    seed.vehicle("Toyota", "Camri", "789GHI02", "JTDBE32K720654321")
+        .await;
+    let organization = seed.commit().await;
+    let repository = PostgresSearchRepository::new(&database);
+
+    let search = TextSearch::new("camry", None, Some(0.1), None).expect("valid search");
+    let hits = repository
+        .search_vehicles(organization.id, &search, None)
+        .await
+        .expect("searched");
+    let matched: Vec<_> = hits
+        .iter()
+        .map(|hit| (hit.title.as_str(), hit.matched_by))
+        .collect();
+    assert_eq!(
+        matched,
+        vec![
+            ("Toyota Camry 123ABC02", SearchMatch::FullText),
+            ("Toyota Camri 789GHI02", SearchMatch::Fuzzy),
+        ]
+    );
+    assert_eq!(hits[0].snippet, "Toyota <mark>Camry</mark> 123ABC02");
+}
+
+#[tokio::test]
+async fn hits_are_limited_to_the_organization() {
+    let Some(database) = common::database().await else {
+        return;
+    };
+    let organization = seeded(&database).await;
+    let other = seeded(&database).await;
+    let repository = PostgresSearchRepository::new(&database);
+
+    for organization_id in [organization.id, other.id] {
+        let hits = repository
+            .search_vehicles(organization_id, &search("toyota"), None)
+            .await
+            .expect("searched");
+        assert_eq!(hits.len(), 1, "only the Toyota of the organization");
+    }
+}
+
+#[tokio::test]
+async fn hits_are_limited_to_the_depot_scope() {
+    let Some(database) = common::database().await else {
+        return;
+    };
+    let mut seed = Seed::new(&database).await;
+    let oil_change = seed
+        .maintenance_type("Oil change", "Replace the engine oil", false)
+        .await;
+    let camry = seed
+        .vehicle("Toyota", "Camry", "123ABC02", "JTDBE32K720123456")
+        .await;
+    let corolla = seed
+        .vehicle("Toyota", "Corolla", "456DEF02", "JTDBE32K720654321")
+        .await;
+    let astana = seed.home_depot(camry, "Astana").await;
+    seed.home_depot(corolla, "Almaty").await;
+    for vehicle in [camry, corolla] {
+        seed.maintenance_record(vehicle, oil_change, "Replaced the oil filter")
+            .await;
+    }
+    let organization = seed.commit().await;
+    let repository = PostgresSearchRepository::new(&database);
+    let scope = [astana];
+
+    let hits = repository
+        .search_vehicles(organization.id, &search("toyota"), Some(&scope))
+        .await
+        .expect("searched");
+    assert_eq!(titles(&hits), vec!["Toyota Camry 123ABC02"]);
+    let hits = repository
+        .search_maintenance_records(organization.id, &search("filter"), Some(&scope))
+        .await
+        .expect("searched");
+    assert_eq!(titles(&hits), vec!["Oil change 123ABC02"]);
+
+    // Without a scope, every vehicle of the organization
+    let hits = repository
+        .search_vehicles(organization.id, &search("toyota"), None)
+        .await
+        .expect("searched");
+    assert_eq!(hits.len(), 2);
+}
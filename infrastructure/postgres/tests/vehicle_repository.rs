@@ -0,0 +1,158 @@
+mod common;
+
+use application::{
+    shared::{
+        cursor::CursorDirection,
+        pagination::{Keyset, KeysetPosition, SortOrder},
+    },
+    vehicle::{
+        filters::vehicle_filter::{NewVehicleFilter, VehicleFilter, VehicleSortBy},
+        models::vehicle::VehicleView,
+        traits::vehicle_repository::VehicleApplicationRepository,
+    },
+};
+use common::{Organization, Seed};
+use postgres::{
+    database::PostgresDatabase, repositories::vehicle_repository::PostgresVehicleRepository,
+};
+use uuid::Uuid;
+
+/// A Volvo based at a depot, a Toyota and a MAN without one; returns the depot.
+async fn seeded(database: &PostgresDatabase) -> (Organization, Uuid) {
+    let mut seed = Seed::new(database).await;
+    let volvo = seed
+        .vehicle("Volvo", "FH16", "456DEF02", "YV2AS02A6EB123456")
+        .await;
+    seed.vehicle("Toyota", "Camry", "123ABC02", "JTDBE32K720123456")
+        .await;
+    seed.vehicle("MAN", "TGX", "789GHI02", "WMA06XZZ5FM123456")
+        .await;
+    let depot = seed.home_depot(volvo, "Astana").await;
+    (seed.commit().await, depot)
+}
+
+fn filter(sort_by: VehicleSortBy, sort_order: SortOrder) -> VehicleFilter {
+    VehicleFilter {
+        sort_by: Some(sort_by),
+        sort_order,
+        ..VehicleFilter::new(NewVehicleFilter::default())
+    }
+}
+
+fn keyset(position: Option<&VehicleView>, direction: CursorDirection) -> Keyset {
+    Keyset {
+        sort_column: VehicleSortBy::Make.as_column_name(),
+        sort_order: SortOrder::Asc,
+        position: position.map(|view| KeysetPosition {
+            value: VehicleSortBy::Make.keyset_value(view),
+            id: view.id.clone(),
+        }),
+        direction,
+        limit: 2,
+    }
+}
+
+fn makes(views: &[VehicleView]) -> Vec<&str> {
+    views.iter().map(|view| view.make.as_str()).collect()
+}
+
+#[tokio::test]
+async fn vehicles_are_read_by_page_and_from_a_position() {
+    let Some(database) = common::database().await else {
+        return;
+    };
+    let (organization, _) = seeded(&database).await;
+    let repository = PostgresVehicleRepository::new(&database);
+
+    let all = repository
+        .get_by_filter(
+            organization.id,
+            VehicleFilter {
+                page_size: 0,
+                ..filter(VehicleSortBy::Make, SortOrder::Desc)
+            },
+        )
+        .await
+        .expect("vehicles read");
+    assert_eq!(makes(&all), vec!["Volvo", "Toyota", "MAN"]);
+    let second = repository
+        .get_by_filter(
+            organization.id,
+            VehicleFilter {
+                page: 2,
+                page_size: 2,
+                ..filter(VehicleSortBy::Make, SortOrder::Asc)
+            },
+        )
+        .await
+        .expect("vehicles read");
+    assert_eq!(makes(&second), vec!["Volvo"]);
+
+    let sorted = filter(VehicleSortBy::Make, SortOrder::Asc);
+    let first = repository
+        .get_page(
+            organization.id,
+            sorted.clone(),
+            &keyset(None, CursorDirection::Next),
+        )
+        .await
+        .expect("page read");
+    assert_eq!(makes(&first), vec!["MAN", "Toyota"]);
+    let next = repository
+        .get_page(
+            organization.id,
+            sorted.clone(),
+            &keyset(Some(&first[0]), CursorDirection::Next),
+        )
+        .await
+        .expect("page read");
+    assert_eq!(makes(&next), vec!["Toyota", "Volvo"]);
+
+    // A previous page is read backwards, in scanning order
+    let previous = repository
+        .get_page(
+            organization.id,
+            sorted,
+            &keyset(Some(&next[1]), CursorDirection::Previous),
+        )
+        .await
+        .expect("page read");
+    assert_eq!(makes(&previous), vec!["Toyota", "MAN"]);
+}
+
+#[tokio::test]
+async fn the_depot_scope_and_the_search_filter_the_vehicles() {
+    let Some(database) = common::database().await else {
+        return;
+    };
+    let (organization, depot) = seeded(&database).await;
+    let repository = PostgresVehicleRepository::new(&database);
+
+    let scoped = VehicleFilter {
+        depot_scope: Some(vec![depot]),
+        ..filter(VehicleSortBy::CreatedAt, SortOrder::Asc)
+    };
+    let count = repository
+        .count(organization.id, scoped.clone())
+        .await
+        .expect("vehicles counted");
+    assert_eq!(count, 1);
+    let views = repository
+        .get_by_filter(organization.id, scoped)
+        .await
+        .expect("vehicles read");
+    assert_eq!(makes(&views), vec!["Volvo"]);
+    assert_eq!(views[0].home_depot_id, Some(depot));
+    assert_eq!(views[0].search_rank, None);
+
+    let searched = VehicleFilter {
+        search: Some("Camry".to_string()),
+        ..filter(VehicleSortBy::Relevance, SortOrder::Desc)
+    };
+    let views = repository
+        .get_by_filter(organization.id, searched)
+        .await
+        .expect("vehicles read");
+    assert_eq!(makes(&views), vec!["Toyota"]);
+    assert!(views[0].search_rank.is_some_and(|rank| rank > 0.0));
+}
@@ -0,0 +1,230 @@
+mod common;
+
+use application::{
+    auth::AuthenticatedUser,
+    shared::{
+        cursor::CursorCodec, filter_expression::filter_ast::FilterExpression,
+        pagination::PageRequest,
+    },
+    vehicle::{
+        filters::vehicle_status_filter::VEHICLE_STATUS_FILTER_SCHEMA,
+        traits::vehicle_status_repository::VehicleStatusApplicationRepository,
+        use_cases::queries::get_vehicle_statuses::{
+            GetVehicleStatusesQuery, GetVehicleStatusesResponse, GetVehicleStatusesUseCase,
+        },
+    },
+};
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use common::{Organization, Seed};
+use domain::user::{
+    entities::user::UserIdentity,
+    value_types::{Email, UserId},
+};
+use memory::{repositories::depot_repository::MemoryDepotRepository, store::MemoryStore};
+use postgres::{
+    database::PostgresDatabase,
+    repositories::{
+        vehicle_repository::PostgresVehicleRepository,
+        vehicle_status_repository::PostgresVehicleStatusRepository,
+    },
+};
+use uuid::Uuid;
+
+fn day(day: i64) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 1, 1, 8, 0, 0).unwrap() + Duration::days(day)
+}
+
+/// A vehicle with a status a day from day 1 to 5 (10 000 km a day, the tires checked on even
+/// days) and a second status on day 3.
+async fn seeded(database: &PostgresDatabase) -> (Organization, Uuid) {
+    let mut seed = Seed::new(database).await;
+    let vehicle = seed
+        .vehicle("Toyota", "Camry", "123ABC02", "JTDBE32K720123456")
+        .await;
+    for (on, odometer) in [
+        (1, 10_000),
+        (2, 20_000),
+        (3, 30_000),
+        (3, 30_500),
+        (4, 40_000),
+        (5, 50_000),
+    ] {
+        let notes = match on % 2 {
+            0 => "Tire pressure checked",
+            _ => "",
+        };
+        seed.status(vehicle, odometer, notes, day(on)).await;
+    }
+    (seed.commit().await, vehicle)
+}
+
+/// The user of the organization, who manages no depot.
+async fn user(organization: &Organization) -> AuthenticatedUser {
+    let user = UserIdentity {
+        id: organization.user_id,
+        uuid: UserId::new(organization.user_id),
+        organization_id: organization.id,
+        username: "alice".to_string(),
+        email: Email::new(format!("alice.{}@fleet.test", organization.user_id))
+            .expect("valid email"),
+        first_name: "Alice".to_string(),
+        last_name: "Tester".to_string(),
+    };
+    AuthenticatedUser::resolve(&user, &MemoryDepotRepository::new(&MemoryStore::new()))
+        .await
+        .expect("user resolved")
+}
+
+async fn page(
+    database: &PostgresDatabase,
+    repository: &PostgresVehicleStatusRepository,
+    organization: &Organization,
+    vehicle_id: Uuid,
+    filter: Option<&str>,
+    cursor: Option<String>,
+) -> GetVehicleStatusesResponse {
+    let codec = CursorCodec::new("secret");
+    let vehicles = PostgresVehicleRepository::new(database);
+    GetVehicleStatusesUseCase::new(&vehicles, repository, &codec)
+        .execute(
+            GetVehicleStatusesQuery {
+                vehicle_id,
+                filter: filter.map(|filter| {
+                    FilterExpression::parse(filter, &VEHICLE_STATUS_FILTER_SCHEMA)
+                        .expect("valid filter")
+                }),
+                page: PageRequest {
+                    cursor,
+                    page_size: 2,
+                    include_total: true,
+                },
+            },
+            &user(organization).await,
+        )
+        .await
+        .expect("statuses listed")
+}
+
+fn odometers(page: &GetVehicleStatusesResponse) -> Vec<i32> {
+    page.items.iter().map(|status| status.odometer).collect()
+}
+
+#[tokio::test]
+async fn pages_are_read_forward_and_backward() {
+    let Some(database) = common::database().await else {
+        return;
+    };
+    let (organization, vehicle) = seeded(&database).await;
+    let repository = PostgresVehicleStatusRepository::new(&database);
+
+    let first = page(&database, &repository, &organization, vehicle, None, None).await;
+    assert_eq!(odometers(&first), vec![50_000, 40_000]);
+    assert_eq!(first.total_count, Some(6));
+    assert_eq!(first.previous_cursor, None);
+
+    // The statuses of day 3 share their creation date, the id breaks the tie
+    let second = page(
+        &database,
+        &repository,
+        &organization,
+        vehicle,
+        None,
+        first.next_cursor,
+    )
+    .await;
+    assert_eq!(odometers(&second), vec![30_500, 30_000]);
+    let last = page(
+        &database,
+        &repository,
+        &organization,
+        vehicle,
+        None,
+        second.next_cursor,
+    )
+    .await;
+    assert_eq!(odometers(&last), vec![20_000, 10_000]);
+    assert_eq!(last.next_cursor, None);
+
+    let back = page(
+        &database,
+        &repository,
+        &organization,
+        vehicle,
+        None,
+        last.previous_cursor,
+    )
+    .await;
+    assert_eq!(odometers(&back), vec![30_500, 30_000]);
+    let back = page(
+        &database,
+        &repository,
+        &organization,
+        vehicle,
+        None,
+        back.previous_cursor,
+    )
+    .await;
+    assert_eq!(odometers(&back), vec![50_000, 40_000]);
+    assert_eq!(back.previous_cursor, None);
+}
+
+#[tokio::test]
+async fn filter_expressions_are_applied() {
+    let Some(database) = common::database().await else {
+        return;
+    };
+    let (organization, vehicle) = seeded(&database).await;
+    let repository = PostgresVehicleStatusRepository::new(&database);
+
+    let filter = r#"odometer >= 20000 and notes ~ "TIRE""#;
+    let tires = page(
+        &database,
+        &repository,
+        &organization,
+        vehicle,
+        Some(filter),
+        None,
+    )
+    .await;
+    assert_eq!(odometers(&tires), vec![40_000, 20_000]);
+    assert_eq!(tires.next_cursor, None);
+
+    let filter = "odometer not in (10000, 50000) and created_at < 2025-01-05";
+    let middle = page(
+        &database,
+        &repository,
+        &organization,
+        vehicle,
+        Some(filter),
+        None,
+    )
+    .await;
+    assert_eq!(middle.total_count, Some(3));
+    let next = page(
+        &database,
+        &repository,
+        &organization,
+        vehicle,
+        Some(filter),
+        middle.next_cursor,
+    )
+    .await;
+    assert_eq!(odometers(&next), vec![20_000]);
+
+    // NULL hour meters are distinct from any value
+    let count = |filter: &str| {
+        let filter =
+            FilterExpression::parse(filter, &VEHICLE_STATUS_FILTER_SCHEMA).expect("valid filter");
+        let repository = &repository;
+        let organization_id = organization.id;
+        async move {
+            repository
+                .count(organization_id, vehicle, Some(&filter))
+                .await
+                .expect("statuses counted")
+        }
+    };
+    assert_eq!(count("engine_hour_meter != 5").await, 6);
+    assert_eq!(count("engine_hour_meter = 5").await, 0);
+    assert_eq!(count(r#"notes in ("", "Tire pressure checked")"#).await, 6);
+}
@@ -0,0 +1,204 @@
+-- SQLite mirror of migrations/20250702155309_init_schema.sql, with the columns and constraints of
+-- the later PostgreSQL migrations up to 20250916090000 (SQLite can't alter a constraint, so a new
+-- database starts from the current schema).
+--
+-- Differences with PostgreSQL:
+-- * Enums are TEXT columns with a CHECK constraint, booleans are INTEGER 0/1.
+-- * UUIDs are hyphenated TEXT, decimals are TEXT (exact), arrays are JSON TEXT.
+-- * Timestamps are RFC 3339 TEXT in UTC with microseconds (`YYYY-MM-DDTHH:MM:SS.ffffffZ`), so they
+--   sort as text.
+-- * Maintenance records are identified by a UUID, like the domain.
+
+-- Users
+CREATE TABLE users (
+    uuid TEXT PRIMARY KEY,
+    username TEXT NOT NULL,
+    email TEXT NOT NULL UNIQUE,
+    first_name TEXT NOT NULL,
+    last_name TEXT NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f000Z', 'now')),
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f000Z', 'now'))
+);
+
+-- Vehicles
+CREATE TABLE vehicles (
+    uuid TEXT PRIMARY KEY,
+    make TEXT NOT NULL,
+    model TEXT NOT NULL,
+    year INTEGER NOT NULL,
+    vin TEXT NOT NULL,
+    vin_legacy INTEGER NOT NULL DEFAULT 0 CHECK (vin_legacy IN (0, 1)),
+    license_plate TEXT NOT NULL,
+    country TEXT NOT NULL DEFAULT 'KZ',
+    engine_type TEXT NOT NULL CHECK (engine_type IN (
+        'Gasoline', 'Diesel', 'Electric', 'Hybrid', 'PluginHybrid', 'Cng', 'Lpg', 'Hydrogen', 'Other'
+    )),
+    engine_type_other TEXT,
+    battery_capacity TEXT CHECK (CAST(battery_capacity AS REAL) > 0),
+    tank_capacity TEXT CHECK (CAST(tank_capacity AS REAL) > 0),
+
+    -- Timestamps and user tracking
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f000Z', 'now')),
+    created_by TEXT REFERENCES users(uuid) ON DELETE SET NULL,
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f000Z', 'now')),
+    updated_by TEXT REFERENCES users(uuid) ON DELETE SET NULL,
+
+    CONSTRAINT vehicles_vin_key UNIQUE (vin),
+    CONSTRAINT vehicles_country_license_plate_key UNIQUE (country, license_plate),
+    CONSTRAINT vehicles_engine_type_other_check
+        CHECK ((engine_type = 'Other') = (engine_type_other IS NOT NULL))
+);
+
+CREATE INDEX vehicles_make_id_idx ON vehicles(make, uuid);
+CREATE INDEX vehicles_model_id_idx ON vehicles(model, uuid);
+CREATE INDEX vehicles_year_id_idx ON vehicles(year, uuid);
+CREATE INDEX vehicles_license_plate_id_idx ON vehicles(license_plate, uuid);
+CREATE INDEX vehicles_country_id_idx ON vehicles(country, uuid);
+CREATE INDEX vehicles_engine_type_id_idx ON vehicles(engine_type, uuid);
+CREATE INDEX vehicles_created_at_id_idx ON vehicles(created_at, uuid);
+CREATE INDEX vehicles_updated_at_id_idx ON vehicles(updated_at, uuid);
+
+-- Vehicle Statuses (Historical & Current)
+CREATE TABLE vehicle_statuses (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    vehicle_id TEXT NOT NULL REFERENCES vehicles(uuid) ON DELETE CASCADE,
+    performed_by TEXT NOT NULL REFERENCES users(uuid) ON DELETE CASCADE,
+    odometer INTEGER,
+    engine_hour_meter INTEGER,
+    fuel_level INTEGER,
+    notes TEXT,
+    latest INTEGER NOT NULL DEFAULT 0 CHECK (latest IN (0, 1)),
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f000Z', 'now')),
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f000Z', 'now'))
+);
+
+-- Enforce at most one 'latest' status per vehicle
+CREATE UNIQUE INDEX one_latest_status_per_vehicle
+ON vehicle_statuses(vehicle_id)
+WHERE latest = 1;
+
+CREATE INDEX vehicle_statuses_vehicle_created_at_id_idx
+ON vehicle_statuses(vehicle_id, created_at, id);
+
+-- Maintenance Types (global definitions like "Oil Change", "Brake Check")
+CREATE TABLE maintenance_types (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL,
+    description TEXT NOT NULL,
+    category TEXT CHECK (category IN (
+        'Engine', 'Brakes', 'Tires', 'Body', 'Electrical', 'Inspection'
+    )),
+    applicability TEXT NOT NULL DEFAULT 'Any' CHECK (applicability IN (
+        'Any', 'CombustionEngine', 'ElectricDrive', 'GasSystem', 'FuelCell'
+    )),
+    checklist TEXT NOT NULL DEFAULT '[]' CHECK (json_valid(checklist)),
+    deprecated_at TEXT,
+    deprecated_by TEXT REFERENCES users(uuid) ON DELETE SET NULL,
+
+    -- Timestamps and user tracking
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f000Z', 'now')),
+    created_by TEXT NOT NULL REFERENCES users(uuid) ON DELETE SET NULL,
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f000Z', 'now')),
+    updated_by TEXT NOT NULL REFERENCES users(uuid) ON DELETE SET NULL,
+
+    CONSTRAINT maintenance_types_name_key UNIQUE (name)
+);
+
+CREATE INDEX maintenance_types_name_id_idx ON maintenance_types(name, id)
+WHERE deprecated_at IS NULL;
+
+-- Default intervals of the catalog, optionally narrowed to a make, model and/or engine type
+CREATE TABLE maintenance_interval_templates (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    maintenance_type_id INTEGER NOT NULL REFERENCES maintenance_types(id) ON DELETE CASCADE,
+    make TEXT,
+    model TEXT,
+    engine_type TEXT CHECK (engine_type IN (
+        'Gasoline', 'Diesel', 'Electric', 'Hybrid', 'PluginHybrid', 'Cng', 'Lpg', 'Hydrogen', 'Other'
+    )),
+    engine_type_other TEXT,
+    interval_type TEXT NOT NULL CHECK (interval_type IN ('Kilometers', 'EngineHours', 'Years')),
+    interval_value INTEGER NOT NULL CHECK (interval_value > 0),
+    red_threshold INTEGER NOT NULL CHECK (red_threshold BETWEEN 0 AND 100), -- in percentage
+    yellow_threshold INTEGER NOT NULL CHECK (yellow_threshold <= red_threshold), -- in percentage
+
+    -- Timestamps and user tracking
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f000Z', 'now')),
+    created_by TEXT NOT NULL REFERENCES users(uuid) ON DELETE SET NULL,
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f000Z', 'now')),
+    updated_by TEXT NOT NULL REFERENCES users(uuid) ON DELETE SET NULL,
+
+    CONSTRAINT maintenance_interval_templates_model_check CHECK (model IS NULL OR make IS NOT NULL),
+    CONSTRAINT maintenance_interval_templates_engine_type_other_check
+        CHECK (COALESCE(engine_type = 'Other', 0) = (engine_type_other IS NOT NULL))
+);
+
+-- One template per scope and interval type (NULL scope fields compare equal)
+CREATE UNIQUE INDEX maintenance_interval_templates_scope_key
+ON maintenance_interval_templates (
+    maintenance_type_id,
+    interval_type,
+    COALESCE(LOWER(make), ''),
+    COALESCE(LOWER(model), ''),
+    COALESCE(engine_type, ''),
+    COALESCE(engine_type_other, '')
+);
+
+-- Maintenances (custom rules per vehicle & type)
+CREATE TABLE maintenances (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    vehicle_id TEXT NOT NULL REFERENCES vehicles(uuid) ON DELETE CASCADE,
+    -- NO ACTION: deleting a maintenance type must not silently delete its rules
+    maintenance_type_id INTEGER NOT NULL REFERENCES maintenance_types(id) ON DELETE NO ACTION,
+    interval_type TEXT NOT NULL CHECK (interval_type IN ('Kilometers', 'EngineHours', 'Years')),
+    interval_value INTEGER NOT NULL,
+    red_threshold INTEGER NOT NULL, -- in percentage
+    yellow_threshold INTEGER NOT NULL, -- in percentage
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f000Z', 'now')),
+    created_by TEXT NOT NULL REFERENCES users(uuid) ON DELETE SET NULL,
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f000Z', 'now')),
+    updated_by TEXT NOT NULL REFERENCES users(uuid) ON DELETE SET NULL,
+
+    -- A maintenance type can have a rule per interval type
+    CONSTRAINT maintenances_vehicle_id_maintenance_type_id_interval_type_key
+        UNIQUE (vehicle_id, maintenance_type_id, interval_type)
+);
+
+-- Maintenance Records (what was performed, when)
+CREATE TABLE maintenance_records (
+    id TEXT PRIMARY KEY,
+    -- Relationships
+    vehicle_id TEXT NOT NULL REFERENCES vehicles(uuid) ON DELETE CASCADE,
+    maintenance_id INTEGER REFERENCES maintenances(id) ON DELETE NO ACTION,
+    performed_by TEXT NOT NULL REFERENCES users(uuid) ON DELETE CASCADE,
+    vehicle_status_id INTEGER NOT NULL REFERENCES vehicle_statuses(id) ON DELETE CASCADE,
+    -- Record details
+    performed_at TEXT NOT NULL,
+    details TEXT NOT NULL,
+    -- Cost header (NULL currency means the cost is unknown)
+    currency TEXT CHECK (length(currency) = 3 AND currency = upper(currency)),
+    invoice_reference TEXT,
+    -- Metadata
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f000Z', 'now')),
+    created_by TEXT NOT NULL REFERENCES users(uuid) ON DELETE CASCADE,
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f000Z', 'now')),
+    updated_by TEXT NOT NULL REFERENCES users(uuid) ON DELETE CASCADE
+);
+
+CREATE INDEX maintenance_records_vehicle_performed_at
+ON maintenance_records(vehicle_id, performed_at, id);
+
+-- Maintenance Cost Lines (parts, labor, taxes and fees of a maintenance record)
+CREATE TABLE maintenance_cost_lines (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    maintenance_record_id TEXT NOT NULL REFERENCES maintenance_records(id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    kind TEXT NOT NULL CHECK (kind IN ('Part', 'Labor', 'Tax', 'Fee')),
+    description TEXT NOT NULL,
+    part_number TEXT,
+    quantity TEXT NOT NULL CHECK (CAST(quantity AS REAL) >= 0), -- hours for labor
+    unit_price TEXT NOT NULL CHECK (CAST(unit_price AS REAL) >= 0), -- hourly rate for labor
+
+    -- Keep the order of the lines as entered
+    UNIQUE(maintenance_record_id, position)
+);
@@ -0,0 +1,145 @@
+-- SQLite mirror of migrations/20250930090000_organizations.sql, without row-level security: the
+-- repositories filter every query on the organization.
+--
+-- SQLite can't drop a constraint, so `vehicles` and `maintenance_types`, whose unique keys become
+-- per organization, are rebuilt (new table, copy, drop, rename): the migrations run with the
+-- foreign keys off, or dropping a table would cascade to the rows referencing it. The other tables
+-- get a column whose default, which SQLite can't drop, is the organization of the existing rows;
+-- the repositories always set it.
+
+-- Organizations
+CREATE TABLE organizations (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f000Z', 'now')),
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f000Z', 'now')),
+
+    CONSTRAINT organizations_name_key UNIQUE (name)
+);
+
+-- The existing rows belong to a default organization
+INSERT INTO organizations (id, name) VALUES ('00000000-0000-0000-0000-000000000001', 'Default');
+
+-- Vehicles, with the VIN and the license plate unique per organization
+CREATE TABLE vehicles_new (
+    uuid TEXT PRIMARY KEY,
+    make TEXT NOT NULL,
+    model TEXT NOT NULL,
+    year INTEGER NOT NULL,
+    vin TEXT NOT NULL,
+    vin_legacy INTEGER NOT NULL DEFAULT 0 CHECK (vin_legacy IN (0, 1)),
+    license_plate TEXT NOT NULL,
+    country TEXT NOT NULL DEFAULT 'KZ',
+    engine_type TEXT NOT NULL CHECK (engine_type IN (
+        'Gasoline', 'Diesel', 'Electric', 'Hybrid', 'PluginHybrid', 'Cng', 'Lpg', 'Hydrogen', 'Other'
+    )),
+    engine_type_other TEXT,
+    battery_capacity TEXT CHECK (CAST(battery_capacity AS REAL) > 0),
+    tank_capacity TEXT CHECK (CAST(tank_capacity AS REAL) > 0),
+
+    -- Timestamps and user tracking
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f000Z', 'now')),
+    created_by TEXT REFERENCES users(uuid) ON DELETE SET NULL,
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f000Z', 'now')),
+    updated_by TEXT REFERENCES users(uuid) ON DELETE SET NULL,
+
+    organization_id TEXT NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+
+    CONSTRAINT vehicles_organization_id_vin_key UNIQUE (organization_id, vin),
+    CONSTRAINT vehicles_organization_id_country_license_plate_key
+        UNIQUE (organization_id, country, license_plate),
+    CONSTRAINT vehicles_engine_type_other_check
+        CHECK ((engine_type = 'Other') = (engine_type_other IS NOT NULL))
+);
+
+INSERT INTO vehicles_new (
+    uuid, make, model, year, vin, vin_legacy, license_plate, country, engine_type,
+    engine_type_other, battery_capacity, tank_capacity, created_at, created_by, updated_at,
+    updated_by, organization_id
+)
+SELECT
+    uuid, make, model, year, vin, vin_legacy, license_plate, country, engine_type,
+    engine_type_other, battery_capacity, tank_capacity, created_at, created_by, updated_at,
+    updated_by, '00000000-0000-0000-0000-000000000001'
+FROM vehicles;
+
+DROP TABLE vehicles;
+ALTER TABLE vehicles_new RENAME TO vehicles;
+
+CREATE INDEX vehicles_make_id_idx ON vehicles(make, uuid);
+CREATE INDEX vehicles_model_id_idx ON vehicles(model, uuid);
+CREATE INDEX vehicles_year_id_idx ON vehicles(year, uuid);
+CREATE INDEX vehicles_license_plate_id_idx ON vehicles(license_plate, uuid);
+CREATE INDEX vehicles_country_id_idx ON vehicles(country, uuid);
+CREATE INDEX vehicles_engine_type_id_idx ON vehicles(engine_type, uuid);
+CREATE INDEX vehicles_created_at_id_idx ON vehicles(created_at, uuid);
+CREATE INDEX vehicles_updated_at_id_idx ON vehicles(updated_at, uuid);
+
+-- Maintenance Types, with the name unique per organization
+CREATE TABLE maintenance_types_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL,
+    description TEXT NOT NULL,
+    category TEXT CHECK (category IN (
+        'Engine', 'Brakes', 'Tires', 'Body', 'Electrical', 'Inspection'
+    )),
+    applicability TEXT NOT NULL DEFAULT 'Any' CHECK (applicability IN (
+        'Any', 'CombustionEngine', 'ElectricDrive', 'GasSystem', 'FuelCell'
+    )),
+    checklist TEXT NOT NULL DEFAULT '[]' CHECK (json_valid(checklist)),
+    deprecated_at TEXT,
+    deprecated_by TEXT REFERENCES users(uuid) ON DELETE SET NULL,
+
+    -- Timestamps and user tracking
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f000Z', 'now')),
+    created_by TEXT NOT NULL REFERENCES users(uuid) ON DELETE SET NULL,
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f000Z', 'now')),
+    updated_by TEXT NOT NULL REFERENCES users(uuid) ON DELETE SET NULL,
+
+    organization_id TEXT NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+
+    CONSTRAINT maintenance_types_organization_id_name_key UNIQUE (organization_id, name)
+);
+
+INSERT INTO maintenance_types_new (
+    id, name, description, category, applicability, checklist, deprecated_at, deprecated_by,
+    created_at, created_by, updated_at, updated_by, organization_id
+)
+SELECT
+    id, name, description, category, applicability, checklist, deprecated_at, deprecated_by,
+    created_at, created_by, updated_at, updated_by, '00000000-0000-0000-0000-000000000001'
+FROM maintenance_types;
+
+DROP TABLE maintenance_types;
+ALTER TABLE maintenance_types_new RENAME TO maintenance_types;
+
+CREATE INDEX maintenance_types_name_id_idx ON maintenance_types(name, id)
+WHERE deprecated_at IS NULL;
+
+-- The other tables
+ALTER TABLE users ADD COLUMN organization_id TEXT NOT NULL
+    DEFAULT '00000000-0000-0000-0000-000000000001'
+    REFERENCES organizations(id) ON DELETE CASCADE;
+ALTER TABLE vehicle_statuses ADD COLUMN organization_id TEXT NOT NULL
+    DEFAULT '00000000-0000-0000-0000-000000000001'
+    REFERENCES organizations(id) ON DELETE CASCADE;
+ALTER TABLE maintenance_interval_templates ADD COLUMN organization_id TEXT NOT NULL
+    DEFAULT '00000000-0000-0000-0000-000000000001'
+    REFERENCES organizations(id) ON DELETE CASCADE;
+ALTER TABLE maintenances ADD COLUMN organization_id TEXT NOT NULL
+    DEFAULT '00000000-0000-0000-0000-000000000001'
+    REFERENCES organizations(id) ON DELETE CASCADE;
+ALTER TABLE maintenance_records ADD COLUMN organization_id TEXT NOT NULL
+    DEFAULT '00000000-0000-0000-0000-000000000001'
+    REFERENCES organizations(id) ON DELETE CASCADE;
+ALTER TABLE maintenance_cost_lines ADD COLUMN organization_id TEXT NOT NULL
+    DEFAULT '00000000-0000-0000-0000-000000000001'
+    REFERENCES organizations(id) ON DELETE CASCADE;
+
+CREATE INDEX users_organization_id_idx ON users(organization_id);
+CREATE INDEX vehicle_statuses_organization_id_idx ON vehicle_statuses(organization_id);
+CREATE INDEX maintenance_interval_templates_organization_id_idx
+ON maintenance_interval_templates(organization_id);
+CREATE INDEX maintenances_organization_id_idx ON maintenances(organization_id);
+CREATE INDEX maintenance_records_organization_id_idx ON maintenance_records(organization_id);
+CREATE INDEX maintenance_cost_lines_organization_id_idx ON maintenance_cost_lines(organization_id);
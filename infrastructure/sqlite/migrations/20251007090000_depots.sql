@@ -0,0 +1,79 @@
+-- SQLite mirror of migrations/20251007090000_depots.sql, without row-level security: the
+-- repositories filter every query on the organization.
+
+-- Regions
+CREATE TABLE regions (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f000Z', 'now')),
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f000Z', 'now')),
+
+    organization_id TEXT NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+
+    CONSTRAINT regions_organization_id_name_key UNIQUE (organization_id, name)
+);
+
+-- Depots, a region with depots can't be deleted
+CREATE TABLE depots (
+    id TEXT PRIMARY KEY,
+    region_id TEXT NOT NULL REFERENCES regions(id) ON DELETE NO ACTION,
+    name TEXT NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f000Z', 'now')),
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f000Z', 'now')),
+
+    organization_id TEXT NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+
+    CONSTRAINT depots_organization_id_name_key UNIQUE (organization_id, name)
+);
+
+-- Sites
+CREATE TABLE sites (
+    id TEXT PRIMARY KEY,
+    depot_id TEXT NOT NULL REFERENCES depots(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f000Z', 'now')),
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f000Z', 'now')),
+
+    organization_id TEXT NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+
+    CONSTRAINT sites_depot_id_name_key UNIQUE (depot_id, name)
+);
+
+-- Depot managers
+CREATE TABLE depot_managers (
+    depot_id TEXT NOT NULL REFERENCES depots(id) ON DELETE CASCADE,
+    user_id TEXT NOT NULL REFERENCES users(uuid) ON DELETE CASCADE,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f000Z', 'now')),
+
+    organization_id TEXT NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+
+    PRIMARY KEY (depot_id, user_id)
+);
+
+-- Vehicle Movements, append-only
+CREATE TABLE vehicle_movements (
+    id TEXT PRIMARY KEY,
+    vehicle_id TEXT NOT NULL REFERENCES vehicles(uuid) ON DELETE CASCADE,
+    from_depot_id TEXT REFERENCES depots(id) ON DELETE NO ACTION,
+    to_depot_id TEXT NOT NULL REFERENCES depots(id) ON DELETE NO ACTION,
+    moved_at TEXT NOT NULL,
+    moved_by TEXT NOT NULL REFERENCES users(uuid) ON DELETE CASCADE,
+    notes TEXT NOT NULL DEFAULT '',
+    latest INTEGER NOT NULL DEFAULT 0 CHECK (latest IN (0, 1)),
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f000Z', 'now')),
+
+    organization_id TEXT NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+
+    CONSTRAINT vehicle_movements_depots_check CHECK (from_depot_id IS NOT to_depot_id)
+);
+
+-- Enforce at most one 'latest' movement per vehicle
+CREATE UNIQUE INDEX one_latest_movement_per_vehicle
+ON vehicle_movements(vehicle_id)
+WHERE latest = 1;
+
+CREATE INDEX depots_region_id_idx ON depots(region_id);
+CREATE INDEX depot_managers_user_id_idx ON depot_managers(user_id);
+CREATE INDEX vehicle_movements_vehicle_id_moved_at_idx ON vehicle_movements(vehicle_id, moved_at);
+CREATE INDEX vehicle_movements_to_depot_id_idx ON vehicle_movements(to_depot_id)
+WHERE latest = 1;
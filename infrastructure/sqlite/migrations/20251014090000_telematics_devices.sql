@@ -0,0 +1,15 @@
+-- SQLite mirror of migrations/20251014090000_telematics_devices.sql, without row-level security:
+-- the repositories filter every query on the organization.
+
+-- Telematics devices, a device id is unique per organization
+CREATE TABLE telematics_devices (
+    device_id TEXT NOT NULL,
+    vehicle_id TEXT NOT NULL REFERENCES vehicles(uuid) ON DELETE CASCADE,
+    registered_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f000Z', 'now')),
+
+    organization_id TEXT NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+
+    PRIMARY KEY (organization_id, device_id)
+);
+
+CREATE INDEX telematics_devices_vehicle_id_idx ON telematics_devices(vehicle_id);
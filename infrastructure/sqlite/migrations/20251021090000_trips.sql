@@ -0,0 +1,20 @@
+-- SQLite mirror of migrations/20251021090000_trips.sql, without row-level security: the
+-- repositories filter every query on the organization.
+
+-- Trips, the track is an encoded polyline and the distance a decimal text
+CREATE TABLE trips (
+    id TEXT PRIMARY KEY,
+    vehicle_id TEXT NOT NULL REFERENCES vehicles(uuid) ON DELETE CASCADE,
+    driver_id TEXT REFERENCES users(uuid) ON DELETE SET NULL,
+    started_at TEXT NOT NULL,
+    ended_at TEXT NOT NULL,
+    polyline TEXT NOT NULL,
+    distance_km TEXT NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f000Z', 'now')),
+
+    organization_id TEXT NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+
+    CONSTRAINT trips_period_check CHECK (ended_at > started_at)
+);
+
+CREATE INDEX trips_vehicle_id_started_at_idx ON trips(vehicle_id, started_at);
@@ -0,0 +1,57 @@
+-- SQLite mirror of migrations/20251028090000_diagnostics.sql, without row-level security: the
+-- repositories filter every query on the organization.
+
+-- DTC events, the freeze frame is a JSON object of text values
+CREATE TABLE dtc_events (
+    id TEXT PRIMARY KEY,
+    vehicle_id TEXT NOT NULL REFERENCES vehicles(uuid) ON DELETE CASCADE,
+    code TEXT NOT NULL CHECK (
+        length(code) = 5 AND code GLOB '[PCBU][0-3][0-9A-F][0-9A-F][0-9A-F]'
+    ),
+    severity TEXT NOT NULL CHECK (severity IN ('Info', 'Warning', 'Critical')),
+    freeze_frame TEXT NOT NULL DEFAULT '{}' CHECK (json_valid(freeze_frame)),
+    first_seen_at TEXT NOT NULL,
+    last_seen_at TEXT NOT NULL,
+    occurrences INTEGER NOT NULL DEFAULT 1 CHECK (occurrences > 0),
+    cleared_at TEXT,
+    cleared_by TEXT REFERENCES users(uuid) ON DELETE SET NULL,
+
+    organization_id TEXT NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+
+    CONSTRAINT dtc_events_seen_check CHECK (last_seen_at >= first_seen_at)
+);
+
+-- Enforce at most one active event per code of a vehicle
+CREATE UNIQUE INDEX one_active_dtc_event_per_code
+ON dtc_events(vehicle_id, code)
+WHERE cleared_at IS NULL;
+
+CREATE INDEX dtc_events_vehicle_id_first_seen_at_idx ON dtc_events(vehicle_id, first_seen_at);
+
+CREATE TABLE diagnostic_rules (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL,
+    min_severity TEXT NOT NULL CHECK (min_severity IN ('Info', 'Warning', 'Critical')),
+    code_prefix TEXT,
+    maintenance_type_id INTEGER REFERENCES maintenance_types(id) ON DELETE CASCADE,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f000Z', 'now')),
+
+    organization_id TEXT NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+
+    CONSTRAINT diagnostic_rules_organization_id_name_key UNIQUE (organization_id, name)
+);
+
+CREATE TABLE diagnostic_alerts (
+    id TEXT PRIMARY KEY,
+    vehicle_id TEXT NOT NULL REFERENCES vehicles(uuid) ON DELETE CASCADE,
+    dtc_event_id TEXT NOT NULL REFERENCES dtc_events(id) ON DELETE CASCADE,
+    rule_id TEXT NOT NULL REFERENCES diagnostic_rules(id) ON DELETE CASCADE,
+    opened_at TEXT NOT NULL,
+    resolved_at TEXT,
+
+    organization_id TEXT NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+
+    CONSTRAINT diagnostic_alerts_dtc_event_id_rule_id_key UNIQUE (dtc_event_id, rule_id)
+);
+
+CREATE INDEX diagnostic_alerts_vehicle_id_opened_at_idx ON diagnostic_alerts(vehicle_id, opened_at);
@@ -0,0 +1,59 @@
+-- SQLite mirror of migrations/20251104090000_inspections.sql, without row-level security: the
+-- repositories filter every query on the organization.
+
+-- The items are a JSON array of {id, label, critical, photo_required, comment_required_on_fail}
+CREATE TABLE inspection_templates (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL,
+    kind TEXT NOT NULL CHECK (kind IN ('PreTrip', 'PostTrip')),
+    items TEXT NOT NULL CHECK (json_valid(items) AND json_type(items) = 'array'),
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%f000Z', 'now')),
+    created_by TEXT NOT NULL REFERENCES users(uuid),
+
+    organization_id TEXT NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+
+    CONSTRAINT inspection_templates_organization_id_name_key UNIQUE (organization_id, name)
+);
+
+-- The answers are a JSON array of {item_id, label, critical, result, comment, photos}
+CREATE TABLE inspection_reports (
+    id TEXT PRIMARY KEY,
+    template_id TEXT NOT NULL REFERENCES inspection_templates(id),
+    vehicle_id TEXT NOT NULL REFERENCES vehicles(uuid) ON DELETE CASCADE,
+    vehicle_status_id INTEGER NOT NULL REFERENCES vehicle_statuses(id) ON DELETE CASCADE,
+    kind TEXT NOT NULL CHECK (kind IN ('PreTrip', 'PostTrip')),
+    submitted_by TEXT NOT NULL REFERENCES users(uuid) ON DELETE CASCADE,
+    submitted_at TEXT NOT NULL,
+    answers TEXT NOT NULL CHECK (json_valid(answers) AND json_type(answers) = 'array'),
+
+    organization_id TEXT NOT NULL REFERENCES organizations(id) ON DELETE CASCADE
+);
+
+CREATE INDEX inspection_reports_vehicle_id_submitted_at_idx
+ON inspection_reports(vehicle_id, submitted_at);
+
+CREATE TABLE inspection_defects (
+    id TEXT PRIMARY KEY,
+    report_id TEXT NOT NULL REFERENCES inspection_reports(id) ON DELETE CASCADE,
+    vehicle_id TEXT NOT NULL REFERENCES vehicles(uuid) ON DELETE CASCADE,
+    item_id TEXT NOT NULL,
+    label TEXT NOT NULL,
+    critical INTEGER NOT NULL CHECK (critical IN (0, 1)),
+    comment TEXT,
+    reported_at TEXT NOT NULL,
+    resolution TEXT CHECK (resolution IN ('Repaired', 'NoRepairNeeded')),
+    signed_off_at TEXT,
+    signed_off_by TEXT REFERENCES users(uuid) ON DELETE SET NULL,
+    sign_off_notes TEXT,
+
+    organization_id TEXT NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+
+    CONSTRAINT inspection_defects_report_id_item_id_key UNIQUE (report_id, item_id),
+    CONSTRAINT inspection_defects_sign_off_check CHECK (
+        (resolution IS NULL) = (signed_off_at IS NULL) AND signed_off_at >= reported_at
+    )
+);
+
+CREATE INDEX inspection_defects_open_vehicle_id_idx
+ON inspection_defects(vehicle_id, reported_at)
+WHERE signed_off_at IS NULL;
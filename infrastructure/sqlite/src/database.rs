@@ -0,0 +1,119 @@
+//! The connection pool shared by the SQLite repositories.
+use crate::mappers::timestamp;
+use domain::user::entities::user::UserIdentity;
+use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePool, SqlitePoolOptions};
+use std::{str::FromStr, time::Duration};
+
+#[derive(Debug, thiserror::Error)]
+pub enum SqliteDatabaseError {
+    #[error("database error: {0}")]
+    Database(String),
+}
+
+/// Formats an error of the driver, for the `Database(String)` variants of the repository errors.
+pub(crate) fn database_error(error: impl std::fmt::Display) -> String {
+    error.to_string()
+}
+
+/// Whether the driver error is a violation of a unique key, for the `AlreadyExists` variants.
+pub(crate) fn is_unique_violation(error: &sqlx::Error) -> bool {
+    error
+        .as_database_error()
+        .is_some_and(|error| error.is_unique_violation())
+}
+
+#[derive(Debug, Clone)]
+pub struct SqliteDatabase {
+    pool: SqlitePool,
+}
+
+impl SqliteDatabase {
+    /// Opens (creating it if needed) the database at `url` (e.g., `sqlite://fleet.db`) and
+    /// migrates it.
+    pub async fn connect(url: &str) -> Result<Self, SqliteDatabaseError> {
+        let options = SqliteConnectOptions::from_str(url)
+            .map_err(|e| SqliteDatabaseError::Database(database_error(e)))?
+            .create_if_missing(true)
+            .foreign_keys(true)
+            .journal_mode(SqliteJournalMode::Wal)
+            .busy_timeout(Duration::from_secs(5));
+        let pool = SqlitePoolOptions::new()
+            .connect_with(options)
+            .await
+            .map_err(|e| SqliteDatabaseError::Database(database_error(e)))?;
+        Self::migrated(pool).await
+    }
+
+    /// Opens a private in-memory database, migrated. It lives as long as the pool, which holds a
+    /// single connection.
+    pub async fn in_memory() -> Result<Self, SqliteDatabaseError> {
+        let options = SqliteConnectOptions::from_str("sqlite::memory:")
+            .map_err(|e| SqliteDatabaseError::Database(database_error(e)))?
+            .foreign_keys(true);
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .min_connections(1)
+            .idle_timeout(None)
+            .max_lifetime(None)
+            .connect_with(options)
+            .await
+            .map_err(|e| SqliteDatabaseError::Database(database_error(e)))?;
+        Self::migrated(pool).await
+    }
+
+    /// Migrates with the foreign keys off, like the table rebuilds of SQLite require (dropping the
+    /// old table would cascade to the rows referencing it); a transaction can't turn them off, so
+    /// this is done on the connection running the migrations.
+    async fn migrated(pool: SqlitePool) -> Result<Self, SqliteDatabaseError> {
+        let error = |e: sqlx::Error| SqliteDatabaseError::Database(database_error(e));
+        let mut connection = pool.acquire().await.map_err(error)?;
+        sqlx::query("PRAGMA foreign_keys = OFF")
+            .execute(&mut *connection)
+            .await
+            .map_err(error)?;
+        let migrated = sqlx::migrate!("./migrations")
+            .run(&mut *connection)
+            .await
+            .map_err(|e| SqliteDatabaseError::Database(database_error(e)));
+        sqlx::query("PRAGMA foreign_keys = ON")
+            .execute(&mut *connection)
+            .await
+            .map_err(error)?;
+        migrated?;
+        Ok(SqliteDatabase { pool })
+    }
+
+    pub fn pool(&self) -> &SqlitePool {
+        &self.pool
+    }
+
+    /// Adds (or replaces) a user of an existing organization, referenced by the `created_by` /
+    /// `updated_by` columns.
+    pub async fn insert_user(&self, user: &UserIdentity) -> Result<(), SqliteDatabaseError> {
+        sqlx::query(
+            "INSERT INTO users (
+                 uuid, organization_id, username, email, first_name, last_name, created_at,
+                 updated_at
+             )
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?7)
+             ON CONFLICT (uuid) DO UPDATE SET
+                 organization_id = excluded.organization_id,
+                 username = excluded.username,
+                 email = excluded.email,
+                 first_name = excluded.first_name,
+                 last_name = excluded.last_name,
+                 updated_at = excluded.updated_at",
+        )
+        .bind(user.id.to_string())
+        .bind(user.organization_id.to_string())
+        .bind(&user.username)
+        .bind(user.email.value())
+        .bind(&user.first_name)
+        .bind(&user.last_name)
+        .bind(timestamp(chrono::Utc::now()))
+        .execute(&self.pool)
+        .await
+        .map_err(|e| SqliteDatabaseError::Database(database_error(e)))?;
+        Ok(())
+    }
+}
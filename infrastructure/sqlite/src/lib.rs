@@ -0,0 +1,21 @@
+//! SQLite implementation of the repository traits, for single-site and offline deployments that
+//! run without a PostgreSQL server.
+//!
+//! *************************************** 100 chars limit ****************************************
+//! # General rules:
+//! * The schema (`migrations/`) mirrors the PostgreSQL one: same tables, columns and constraint
+//!   names, enums as `CHECK` constraints and partial unique indexes for the `latest` status and
+//!   movement of a vehicle.
+//! * `SqliteDatabase::connect` migrates the database, the repositories share its pool.
+//! * Values are stored as text where SQLite has no type (UUIDs, decimals, timestamps), see
+//!   `mappers`; timestamps are always written by the repositories, in a format that sorts as text.
+//! * Every row belongs to an organization: SQLite has no row-level security, so every query of a
+//!   repository filters on the organization of the call, and every insert sets it.
+//! * The migrations run with the foreign keys off, for the table rebuilds SQLite needs to change a
+//!   constraint.
+//! * Users, which have no repository method to insert them, are seeded through the database, in an
+//!   organization created with `SqliteOrganizationRepository`.
+//! * Every repository passes the shared `conformance` suite, like the other backends.
+pub mod database;
+pub mod mappers;
+pub mod repositories;
@@ -0,0 +1,62 @@
+use super::{get, get_timestamp, get_uuid, parse_uuid};
+use domain::depot::{
+    entities::{depot::Depot, region::Region, site::Site, vehicle_movement::VehicleMovement},
+    value_types::location_name::LocationName,
+};
+use sqlx::sqlite::SqliteRow;
+
+pub(crate) const REGION_COLUMNS: &str = "id, name, created_at, updated_at";
+
+pub(crate) const DEPOT_COLUMNS: &str = "id, region_id, name, created_at, updated_at";
+
+pub(crate) const SITE_COLUMNS: &str = "id, depot_id, name, created_at, updated_at";
+
+pub(crate) const VEHICLE_MOVEMENT_COLUMNS: &str =
+    "id, vehicle_id, from_depot_id, to_depot_id, moved_at, moved_by, notes";
+
+fn get_name(row: &SqliteRow) -> Result<LocationName, String> {
+    LocationName::new(get::<String>(row, "name")?).map_err(|e| e.to_string())
+}
+
+pub(crate) fn region_from_row(row: &SqliteRow) -> Result<Region, String> {
+    Ok(Region {
+        id: get_uuid(row, "id")?,
+        name: get_name(row)?,
+        created_at: get_timestamp(row, "created_at")?,
+        updated_at: get_timestamp(row, "updated_at")?,
+    })
+}
+
+pub(crate) fn depot_from_row(row: &SqliteRow) -> Result<Depot, String> {
+    Ok(Depot {
+        id: get_uuid(row, "id")?,
+        region_id: get_uuid(row, "region_id")?,
+        name: get_name(row)?,
+        created_at: get_timestamp(row, "created_at")?,
+        updated_at: get_timestamp(row, "updated_at")?,
+    })
+}
+
+pub(crate) fn site_from_row(row: &SqliteRow) -> Result<Site, String> {
+    Ok(Site {
+        id: get_uuid(row, "id")?,
+        depot_id: get_uuid(row, "depot_id")?,
+        name: get_name(row)?,
+        created_at: get_timestamp(row, "created_at")?,
+        updated_at: get_timestamp(row, "updated_at")?,
+    })
+}
+
+pub(crate) fn vehicle_movement_from_row(row: &SqliteRow) -> Result<VehicleMovement, String> {
+    Ok(VehicleMovement {
+        id: get_uuid(row, "id")?,
+        vehicle_id: get_uuid(row, "vehicle_id")?,
+        from_depot_id: get::<Option<String>>(row, "from_depot_id")?
+            .map(|id| parse_uuid(&id))
+            .transpose()?,
+        to_depot_id: get_uuid(row, "to_depot_id")?,
+        moved_at: get_timestamp(row, "moved_at")?,
+        moved_by: get_uuid(row, "moved_by")?,
+        notes: get(row, "notes")?,
+    })
+}
@@ -0,0 +1,81 @@
+use super::{get, get_timestamp, get_uuid, parse_timestamp, parse_uuid};
+use domain::diagnostics::{
+    entities::{
+        diagnostic_alert::DiagnosticAlert,
+        diagnostic_rule::{DiagnosticAction, DiagnosticRule},
+        dtc_event::DtcEvent,
+    },
+    value_types::{dtc_code::DtcCode, freeze_frame::FreezeFrame},
+};
+use sqlx::sqlite::SqliteRow;
+use std::collections::BTreeMap;
+
+pub(crate) const DTC_EVENT_COLUMNS: &str = "id, vehicle_id, code, severity, freeze_frame, \
+     first_seen_at, last_seen_at, occurrences, cleared_at, cleared_by";
+
+pub(crate) const DIAGNOSTIC_RULE_COLUMNS: &str =
+    "id, name, min_severity, code_prefix, maintenance_type_id, created_at";
+
+pub(crate) const DIAGNOSTIC_ALERT_COLUMNS: &str =
+    "id, vehicle_id, dtc_event_id, rule_id, opened_at, resolved_at";
+
+pub(crate) fn freeze_frame_to_sql(freeze_frame: &FreezeFrame) -> String {
+    serde_json::Value::from_iter(
+        freeze_frame
+            .parameters()
+            .iter()
+            .map(|(name, value)| (name.clone(), serde_json::Value::from(value.as_str()))),
+    )
+    .to_string()
+}
+
+pub(crate) fn dtc_event_from_row(row: &SqliteRow) -> Result<DtcEvent, String> {
+    let parameters: BTreeMap<String, String> =
+        serde_json::from_str(&get::<String>(row, "freeze_frame")?)
+            .map_err(|e| format!("invalid freeze frame: {}", e))?;
+    Ok(DtcEvent {
+        id: get_uuid(row, "id")?,
+        vehicle_id: get_uuid(row, "vehicle_id")?,
+        code: DtcCode::new(get::<String>(row, "code")?).map_err(|e| e.to_string())?,
+        severity: get::<String>(row, "severity")?.parse()?,
+        freeze_frame: FreezeFrame::new(parameters).map_err(|e| e.to_string())?,
+        first_seen_at: get_timestamp(row, "first_seen_at")?,
+        last_seen_at: get_timestamp(row, "last_seen_at")?,
+        occurrences: get(row, "occurrences")?,
+        cleared_at: get::<Option<String>>(row, "cleared_at")?
+            .map(|value| parse_timestamp(&value))
+            .transpose()?,
+        cleared_by: get::<Option<String>>(row, "cleared_by")?
+            .map(|value| parse_uuid(&value))
+            .transpose()?,
+    })
+}
+
+pub(crate) fn diagnostic_rule_from_row(row: &SqliteRow) -> Result<DiagnosticRule, String> {
+    Ok(DiagnosticRule {
+        id: get_uuid(row, "id")?,
+        name: get(row, "name")?,
+        min_severity: get::<String>(row, "min_severity")?.parse()?,
+        code_prefix: get(row, "code_prefix")?,
+        action: match get::<Option<i32>>(row, "maintenance_type_id")? {
+            None => DiagnosticAction::Alert,
+            Some(maintenance_type_id) => DiagnosticAction::MaintenanceTask {
+                maintenance_type_id,
+            },
+        },
+        created_at: get_timestamp(row, "created_at")?,
+    })
+}
+
+pub(crate) fn diagnostic_alert_from_row(row: &SqliteRow) -> Result<DiagnosticAlert, String> {
+    Ok(DiagnosticAlert {
+        id: get_uuid(row, "id")?,
+        vehicle_id: get_uuid(row, "vehicle_id")?,
+        dtc_event_id: get_uuid(row, "dtc_event_id")?,
+        rule_id: get_uuid(row, "rule_id")?,
+        opened_at: get_timestamp(row, "opened_at")?,
+        resolved_at: get::<Option<String>>(row, "resolved_at")?
+            .map(|value| parse_timestamp(&value))
+            .transpose()?,
+    })
+}
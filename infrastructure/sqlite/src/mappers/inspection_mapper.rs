@@ -0,0 +1,148 @@
+use super::{get, get_timestamp, get_uuid, parse_timestamp, parse_uuid};
+use domain::inspection::{
+    entities::{
+        inspection_defect::InspectionDefect,
+        inspection_report::{InspectionAnswer, InspectionReport},
+        inspection_template::{InspectionItem, InspectionTemplate},
+    },
+    value_types::item_result::ItemResult,
+};
+use serde_json::{Value, json};
+use sqlx::sqlite::SqliteRow;
+
+pub(crate) const INSPECTION_TEMPLATE_COLUMNS: &str =
+    "id, name, kind, items, created_at, created_by";
+
+pub(crate) const INSPECTION_REPORT_COLUMNS: &str = "id, template_id, vehicle_id, \
+     vehicle_status_id, kind, submitted_by, submitted_at, answers";
+
+pub(crate) const INSPECTION_DEFECT_COLUMNS: &str = "id, report_id, vehicle_id, item_id, label, \
+     critical, comment, reported_at, resolution, signed_off_at, signed_off_by, sign_off_notes";
+
+pub(crate) fn items_to_sql(items: &[InspectionItem]) -> String {
+    Value::from_iter(items.iter().map(|item| {
+        json!({
+            "id": item.id.to_string(),
+            "label": item.label,
+            "critical": item.critical,
+            "photo_required": item.photo_required,
+            "comment_required_on_fail": item.comment_required_on_fail,
+        })
+    }))
+    .to_string()
+}
+
+pub(crate) fn answers_to_sql(answers: &[InspectionAnswer]) -> String {
+    Value::from_iter(answers.iter().map(|answer| {
+        json!({
+            "item_id": answer.item_id.to_string(),
+            "label": answer.label,
+            "critical": answer.critical,
+            "result": answer.result.as_str(),
+            "comment": answer.comment,
+            "photos": answer.photos,
+        })
+    }))
+    .to_string()
+}
+
+/// The elements of a JSON array column.
+fn json_array(row: &SqliteRow, column: &str) -> Result<Vec<Value>, String> {
+    match serde_json::from_str(&get::<String>(row, column)?) {
+        Ok(Value::Array(values)) => Ok(values),
+        Ok(_) => Err(format!("{} is not a JSON array", column)),
+        Err(e) => Err(format!("invalid {}: {}", column, e)),
+    }
+}
+
+fn json_str<'v>(value: &'v Value, field: &str) -> Result<&'v str, String> {
+    value[field]
+        .as_str()
+        .ok_or_else(|| format!("missing text field {}", field))
+}
+
+fn json_bool(value: &Value, field: &str) -> Result<bool, String> {
+    value[field]
+        .as_bool()
+        .ok_or_else(|| format!("missing boolean field {}", field))
+}
+
+fn item_from_json(value: &Value) -> Result<InspectionItem, String> {
+    Ok(InspectionItem {
+        id: parse_uuid(json_str(value, "id")?)?,
+        label: json_str(value, "label")?.to_string(),
+        critical: json_bool(value, "critical")?,
+        photo_required: json_bool(value, "photo_required")?,
+        comment_required_on_fail: json_bool(value, "comment_required_on_fail")?,
+    })
+}
+
+fn answer_from_json(value: &Value) -> Result<InspectionAnswer, String> {
+    let photos = value["photos"]
+        .as_array()
+        .ok_or("missing array field photos")?
+        .iter()
+        .map(|photo| photo.as_str().map(str::to_string).ok_or("invalid photo"))
+        .collect::<Result<Vec<String>, &str>>()?;
+    Ok(InspectionAnswer {
+        item_id: parse_uuid(json_str(value, "item_id")?)?,
+        label: json_str(value, "label")?.to_string(),
+        critical: json_bool(value, "critical")?,
+        result: json_str(value, "result")?.parse::<ItemResult>()?,
+        comment: value["comment"].as_str().map(str::to_string),
+        photos,
+    })
+}
+
+pub(crate) fn inspection_template_from_row(row: &SqliteRow) -> Result<InspectionTemplate, String> {
+    Ok(InspectionTemplate {
+        id: get_uuid(row, "id")?,
+        name: get(row, "name")?,
+        kind: get::<String>(row, "kind")?.parse()?,
+        items: json_array(row, "items")?
+            .iter()
+            .map(item_from_json)
+            .collect::<Result<_, _>>()?,
+        created_at: get_timestamp(row, "created_at")?,
+        created_by: get_uuid(row, "created_by")?,
+    })
+}
+
+pub(crate) fn inspection_report_from_row(row: &SqliteRow) -> Result<InspectionReport, String> {
+    Ok(InspectionReport {
+        id: get_uuid(row, "id")?,
+        template_id: get_uuid(row, "template_id")?,
+        vehicle_id: get_uuid(row, "vehicle_id")?,
+        vehicle_status_id: get(row, "vehicle_status_id")?,
+        kind: get::<String>(row, "kind")?.parse()?,
+        submitted_by: get_uuid(row, "submitted_by")?,
+        submitted_at: get_timestamp(row, "submitted_at")?,
+        answers: json_array(row, "answers")?
+            .iter()
+            .map(answer_from_json)
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+pub(crate) fn inspection_defect_from_row(row: &SqliteRow) -> Result<InspectionDefect, String> {
+    Ok(InspectionDefect {
+        id: get_uuid(row, "id")?,
+        report_id: get_uuid(row, "report_id")?,
+        vehicle_id: get_uuid(row, "vehicle_id")?,
+        item_id: get_uuid(row, "item_id")?,
+        label: get(row, "label")?,
+        critical: get(row, "critical")?,
+        comment: get(row, "comment")?,
+        reported_at: get_timestamp(row, "reported_at")?,
+        resolution: get::<Option<String>>(row, "resolution")?
+            .map(|value| value.parse())
+            .transpose()?,
+        signed_off_at: get::<Option<String>>(row, "signed_off_at")?
+            .map(|value| parse_timestamp(&value))
+            .transpose()?,
+        signed_off_by: get::<Option<String>>(row, "signed_off_by")?
+            .map(|value| parse_uuid(&value))
+            .transpose()?,
+        sign_off_notes: get(row, "sign_off_notes")?,
+    })
+}
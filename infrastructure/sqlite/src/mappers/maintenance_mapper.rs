@@ -0,0 +1,23 @@
+use super::{get, get_timestamp, get_uuid, interval_type_from_sql};
+use domain::maintenance::entities::maintenance::MaintenanceIdentity;
+use sqlx::sqlite::SqliteRow;
+
+pub(crate) const MAINTENANCE_COLUMNS: &str = "id, vehicle_id, maintenance_type_id, interval_type, \
+     interval_value, red_threshold, yellow_threshold, created_at, created_by, updated_at, \
+     updated_by";
+
+pub(crate) fn maintenance_from_row(row: &SqliteRow) -> Result<MaintenanceIdentity, String> {
+    Ok(MaintenanceIdentity {
+        id: get(row, "id")?,
+        vehicle_id: get_uuid(row, "vehicle_id")?,
+        maintenance_type_id: get(row, "maintenance_type_id")?,
+        interval_type: interval_type_from_sql(&get::<String>(row, "interval_type")?)?,
+        interval_value: get(row, "interval_value")?,
+        red_threshold: get(row, "red_threshold")?,
+        yellow_threshold: get(row, "yellow_threshold")?,
+        created_at: get_timestamp(row, "created_at")?,
+        created_by: get_uuid(row, "created_by")?,
+        updated_at: get_timestamp(row, "updated_at")?,
+        updated_by: get_uuid(row, "updated_by")?,
+    })
+}
@@ -0,0 +1,73 @@
+use super::{get, get_timestamp, get_uuid, parse_decimal};
+use domain::maintenance::{
+    entities::{
+        maintenance_cost::{MaintenanceCost, MaintenanceCostLine, MaintenanceCostLineKind},
+        maintenance_record::MaintenanceRecordIdentity,
+    },
+    value_types::{currency::Currency, money::Money},
+};
+use sqlx::sqlite::SqliteRow;
+
+pub(crate) const MAINTENANCE_RECORD_COLUMNS: &str = "id, vehicle_id, maintenance_id, performed_by, \
+     vehicle_status_id, performed_at, details, currency, invoice_reference, created_at, \
+     created_by, updated_at, updated_by";
+
+pub(crate) const COST_LINE_COLUMNS: &str = "kind, description, part_number, quantity, unit_price";
+
+/// Returns the kind, quantity and unit price columns of a cost line.
+pub(crate) fn cost_line_params(line: &MaintenanceCostLine) -> (&str, String, String) {
+    (
+        line.kind.as_str(),
+        line.quantity.to_string(),
+        line.unit_price.amount().to_string(),
+    )
+}
+
+pub(crate) fn cost_line_from_row(
+    row: &SqliteRow,
+    currency: Currency,
+) -> Result<MaintenanceCostLine, String> {
+    Ok(MaintenanceCostLine {
+        kind: get::<String>(row, "kind")?.parse::<MaintenanceCostLineKind>()?,
+        description: get(row, "description")?,
+        part_number: get(row, "part_number")?,
+        quantity: parse_decimal(&get::<String>(row, "quantity")?)?,
+        unit_price: Money::new(parse_decimal(&get::<String>(row, "unit_price")?)?, currency),
+    })
+}
+
+/// Reads a record, `cost_lines` reads the cost lines of its currency (if the cost is known).
+pub(crate) fn maintenance_record_from_row(
+    row: &SqliteRow,
+    cost_lines: impl FnOnce(Currency) -> Result<Vec<MaintenanceCostLine>, String>,
+) -> Result<MaintenanceRecordIdentity, String> {
+    let cost = match get::<Option<String>>(row, "currency")? {
+        Some(currency) => {
+            let currency = Currency::new(currency).map_err(|e| e.to_string())?;
+            Some(
+                MaintenanceCost::new(
+                    currency,
+                    cost_lines(currency)?,
+                    get(row, "invoice_reference")?,
+                )
+                .map_err(|e| e.to_string())?,
+            )
+        }
+        None => None,
+    };
+
+    Ok(MaintenanceRecordIdentity {
+        id: get_uuid(row, "id")?,
+        vehicle_id: get_uuid(row, "vehicle_id")?,
+        maintenance_id: get(row, "maintenance_id")?,
+        user_id: get_uuid(row, "performed_by")?,
+        vehicle_status_id: get(row, "vehicle_status_id")?,
+        performed_at: get_timestamp(row, "performed_at")?,
+        details: get(row, "details")?,
+        cost,
+        created_at: get_timestamp(row, "created_at")?,
+        created_by: get_uuid(row, "created_by")?,
+        updated_at: get_timestamp(row, "updated_at")?,
+        updated_by: get_uuid(row, "updated_by")?,
+    })
+}
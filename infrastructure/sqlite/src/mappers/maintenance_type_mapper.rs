@@ -0,0 +1,61 @@
+use super::{get, get_timestamp, parse_timestamp, user_mapper::user_from_row};
+use domain::maintenance::{
+    entities::maintenance_type::{MaintenanceType, MaintenanceTypeView},
+    value_types::{
+        maintenance_applicability::MaintenanceApplicability,
+        maintenance_category::MaintenanceCategory,
+    },
+};
+use sqlx::sqlite::SqliteRow;
+
+pub(crate) const MAINTENANCE_TYPE_COLUMNS: &str = "mt.id, mt.name, mt.description, mt.category, \
+     mt.applicability, mt.checklist, mt.deprecated_at, mt.created_at, mt.updated_at";
+
+pub(crate) fn checklist_to_sql(checklist: &[String]) -> String {
+    serde_json::Value::from(checklist).to_string()
+}
+
+pub(crate) fn maintenance_type_from_row(row: &SqliteRow) -> Result<MaintenanceType, String> {
+    let mut maintenance_type = MaintenanceType::new(
+        get(row, "name")?,
+        get(row, "description")?,
+        get::<Option<String>>(row, "category")?
+            .as_deref()
+            .map(str::parse::<MaintenanceCategory>)
+            .transpose()?,
+        get::<String>(row, "applicability")?.parse::<MaintenanceApplicability>()?,
+    )
+    .map_err(|e| e.to_string())?;
+    maintenance_type.set_id(get(row, "id")?);
+    maintenance_type.set_checklist(
+        serde_json::from_str(&get::<String>(row, "checklist")?)
+            .map_err(|e| format!("invalid checklist: {}", e))?,
+    );
+    maintenance_type.set_deprecated_at(
+        get::<Option<String>>(row, "deprecated_at")?
+            .as_deref()
+            .map(parse_timestamp)
+            .transpose()?,
+    );
+    Ok(maintenance_type)
+}
+
+/// Reads a maintenance type joined with its `created_by_` and `updated_by_` users.
+pub(crate) fn maintenance_type_view_from_row(
+    row: &SqliteRow,
+) -> Result<MaintenanceTypeView, String> {
+    let maintenance_type = maintenance_type_from_row(row)?;
+    Ok(MaintenanceTypeView {
+        id: maintenance_type.id(),
+        name: maintenance_type.name().to_string(),
+        description: maintenance_type.description().to_string(),
+        category: maintenance_type.category(),
+        applicability: maintenance_type.applicability(),
+        checklist: maintenance_type.checklist().to_vec(),
+        deprecated_at: maintenance_type.deprecated_at(),
+        created_at: get_timestamp(row, "created_at")?,
+        created_by: user_from_row(row, "created_by_")?,
+        updated_at: get_timestamp(row, "updated_at")?,
+        updated_by: user_from_row(row, "updated_by_")?,
+    })
+}
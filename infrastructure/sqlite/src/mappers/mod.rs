@@ -0,0 +1,80 @@
+//! Conversions between SQLite rows and domain entities.
+//!
+//! SQLite has no UUID, decimal or timestamp type: UUIDs are hyphenated text, decimals are text
+//! (exact, unlike `REAL`) and timestamps are RFC 3339 text in UTC with microseconds, whose text
+//! order is the time order.
+use crate::database::database_error;
+use domain::maintenance::value_types::maintenance_interval_type::MaintenanceIntervalType;
+use rust_decimal::Decimal;
+use sqlx::{Decode, Row, Sqlite, Type, sqlite::SqliteRow};
+use std::str::FromStr;
+
+pub mod depot_mapper;
+pub mod diagnostic_mapper;
+pub mod inspection_mapper;
+pub mod maintenance_mapper;
+pub mod maintenance_record_mapper;
+pub mod maintenance_type_mapper;
+pub mod organization_mapper;
+pub mod telematics_device_mapper;
+pub mod trip_mapper;
+pub mod user_mapper;
+pub mod vehicle_mapper;
+pub mod vehicle_status_mapper;
+
+/// Reads a column of a row.
+pub(crate) fn get<'r, T>(row: &'r SqliteRow, column: &str) -> Result<T, String>
+where
+    T: Decode<'r, Sqlite> + Type<Sqlite>,
+{
+    row.try_get(column).map_err(database_error)
+}
+
+pub(crate) fn timestamp(value: chrono::DateTime<chrono::Utc>) -> String {
+    value.to_rfc3339_opts(chrono::SecondsFormat::Micros, true)
+}
+
+pub(crate) fn parse_timestamp(value: &str) -> Result<chrono::DateTime<chrono::Utc>, String> {
+    chrono::DateTime::parse_from_rfc3339(value)
+        .map(|value| value.with_timezone(&chrono::Utc))
+        .map_err(|e| format!("invalid timestamp {}: {}", value, e))
+}
+
+pub(crate) fn parse_uuid(value: &str) -> Result<uuid::Uuid, String> {
+    uuid::Uuid::parse_str(value).map_err(|e| format!("invalid uuid {}: {}", value, e))
+}
+
+pub(crate) fn parse_decimal(value: &str) -> Result<Decimal, String> {
+    Decimal::from_str(value).map_err(|e| format!("invalid decimal {}: {}", value, e))
+}
+
+/// Reads a timestamp column.
+pub(crate) fn get_timestamp(
+    row: &SqliteRow,
+    column: &str,
+) -> Result<chrono::DateTime<chrono::Utc>, String> {
+    parse_timestamp(&get::<String>(row, column)?)
+}
+
+/// Reads a UUID column.
+pub(crate) fn get_uuid(row: &SqliteRow, column: &str) -> Result<uuid::Uuid, String> {
+    parse_uuid(&get::<String>(row, column)?)
+}
+
+/// Returns the value of the `maintenance_interval_type` enum of the PostgreSQL schema.
+pub(crate) fn interval_type_to_sql(interval_type: &MaintenanceIntervalType) -> &'static str {
+    match interval_type {
+        MaintenanceIntervalType::Kilometers => "Kilometers",
+        MaintenanceIntervalType::EngineHours => "EngineHours",
+        MaintenanceIntervalType::Years => "Years",
+    }
+}
+
+pub(crate) fn interval_type_from_sql(value: &str) -> Result<MaintenanceIntervalType, String> {
+    match value {
+        "Kilometers" => Ok(MaintenanceIntervalType::Kilometers),
+        "EngineHours" => Ok(MaintenanceIntervalType::EngineHours),
+        "Years" => Ok(MaintenanceIntervalType::Years),
+        _ => Err(format!("invalid interval type: {}", value)),
+    }
+}
@@ -0,0 +1,14 @@
+use super::{get, get_timestamp, get_uuid};
+use domain::organization::entities::organization::Organization;
+use sqlx::sqlite::SqliteRow;
+
+pub(crate) const ORGANIZATION_COLUMNS: &str = "id, name, created_at, updated_at";
+
+pub(crate) fn organization_from_row(row: &SqliteRow) -> Result<Organization, String> {
+    Ok(Organization {
+        id: get_uuid(row, "id")?,
+        name: get(row, "name")?,
+        created_at: get_timestamp(row, "created_at")?,
+        updated_at: get_timestamp(row, "updated_at")?,
+    })
+}
@@ -0,0 +1,15 @@
+use super::{get, get_timestamp, get_uuid};
+use domain::telematics::{
+    entities::telematics_device::TelematicsDevice, value_types::device_id::DeviceId,
+};
+use sqlx::sqlite::SqliteRow;
+
+pub(crate) const TELEMATICS_DEVICE_COLUMNS: &str = "device_id, vehicle_id, registered_at";
+
+pub(crate) fn telematics_device_from_row(row: &SqliteRow) -> Result<TelematicsDevice, String> {
+    Ok(TelematicsDevice {
+        device_id: DeviceId::new(get::<String>(row, "device_id")?).map_err(|e| e.to_string())?,
+        vehicle_id: get_uuid(row, "vehicle_id")?,
+        registered_at: get_timestamp(row, "registered_at")?,
+    })
+}
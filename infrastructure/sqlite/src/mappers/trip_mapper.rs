@@ -0,0 +1,23 @@
+use super::{get, get_timestamp, get_uuid, parse_decimal, parse_uuid};
+use domain::trip::{entities::trip::Trip, value_types::polyline};
+use sqlx::sqlite::SqliteRow;
+
+pub(crate) const TRIP_COLUMNS: &str =
+    "id, vehicle_id, driver_id, started_at, ended_at, polyline, distance_km, created_at";
+
+pub(crate) fn trip_from_row(row: &SqliteRow) -> Result<Trip, String> {
+    let encoded = get::<String>(row, "polyline")?;
+    Ok(Trip {
+        id: get_uuid(row, "id")?,
+        vehicle_id: get_uuid(row, "vehicle_id")?,
+        driver_id: get::<Option<String>>(row, "driver_id")?
+            .map(|id| parse_uuid(&id))
+            .transpose()?,
+        started_at: get_timestamp(row, "started_at")?,
+        ended_at: get_timestamp(row, "ended_at")?,
+        polyline: polyline::decode(&encoded)
+            .map_err(|e| format!("invalid polyline {}: {}", encoded, e))?,
+        distance_km: parse_decimal(&get::<String>(row, "distance_km")?)?,
+        created_at: get_timestamp(row, "created_at")?,
+    })
+}
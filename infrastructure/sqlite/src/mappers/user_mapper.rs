@@ -0,0 +1,36 @@
+use super::{get, get_uuid};
+use domain::user::{
+    entities::user::UserIdentity,
+    value_types::{Email, UserId},
+};
+use sqlx::sqlite::SqliteRow;
+
+/// Selects the columns of a joined user `alias`, prefixed with `prefix`.
+pub(crate) fn user_columns(alias: &str, prefix: &str) -> String {
+    [
+        "uuid",
+        "organization_id",
+        "username",
+        "email",
+        "first_name",
+        "last_name",
+    ]
+    .iter()
+    .map(|column| format!("{alias}.{column} AS {prefix}{column}"))
+    .collect::<Vec<_>>()
+    .join(", ")
+}
+
+/// Reads the user columns selected with `user_columns`.
+pub(crate) fn user_from_row(row: &SqliteRow, prefix: &str) -> Result<UserIdentity, String> {
+    let id = get_uuid(row, &format!("{prefix}uuid"))?;
+    Ok(UserIdentity {
+        id,
+        uuid: UserId::new(id),
+        organization_id: get_uuid(row, &format!("{prefix}organization_id"))?,
+        username: get(row, &format!("{prefix}username"))?,
+        email: Email::new(get(row, &format!("{prefix}email"))?)?,
+        first_name: get(row, &format!("{prefix}first_name"))?,
+        last_name: get(row, &format!("{prefix}last_name"))?,
+    })
+}
@@ -0,0 +1,52 @@
+use super::{get, get_timestamp, get_uuid, parse_decimal};
+use domain::vehicle::{
+    entities::vehicle::VehicleIdentity,
+    value_types::{
+        country_code::CountryCode, engine_type::EngineType, license_plate::LicensePlate,
+        powertrain::Powertrain, vehicle_vin::VehicleVin,
+    },
+};
+use sqlx::sqlite::SqliteRow;
+
+pub(crate) const VEHICLE_COLUMNS: &str = "uuid, make, model, year, vin, vin_legacy, license_plate, \
+     country, engine_type, engine_type_other, battery_capacity, tank_capacity, created_at, \
+     updated_at";
+
+pub(crate) fn vehicle_from_row(row: &SqliteRow) -> Result<VehicleIdentity, String> {
+    let vin: String = get(row, "vin")?;
+    let vin = match get::<bool>(row, "vin_legacy")? {
+        true => VehicleVin::new_legacy(vin),
+        false => VehicleVin::new(vin),
+    }
+    .map_err(|e| e.to_string())?;
+    let engine_type = EngineType::from_sql(
+        &get::<String>(row, "engine_type")?,
+        get::<Option<String>>(row, "engine_type_other")?.as_deref(),
+    )
+    .map_err(|e| e.to_string())?;
+    let capacity = |column: &str| {
+        get::<Option<String>>(row, column)?
+            .as_deref()
+            .map(parse_decimal)
+            .transpose()
+    };
+
+    Ok(VehicleIdentity {
+        id: get_uuid(row, "uuid")?,
+        make: get(row, "make")?,
+        model: get(row, "model")?,
+        year: get(row, "year")?,
+        vin,
+        license_plate: LicensePlate::new(get::<String>(row, "license_plate")?)
+            .map_err(|e| e.to_string())?,
+        country: CountryCode::new(get::<String>(row, "country")?).map_err(|e| e.to_string())?,
+        powertrain: Powertrain::new(
+            engine_type,
+            capacity("battery_capacity")?,
+            capacity("tank_capacity")?,
+        )
+        .map_err(|e| e.to_string())?,
+        created_at: get_timestamp(row, "created_at")?,
+        updated_at: get_timestamp(row, "updated_at")?,
+    })
+}
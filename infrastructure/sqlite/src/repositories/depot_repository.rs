@@ -0,0 +1,227 @@
+use crate::{
+    database::{SqliteDatabase, database_error, is_unique_violation},
+    mappers::{
+        depot_mapper::{
+            DEPOT_COLUMNS, REGION_COLUMNS, SITE_COLUMNS, depot_from_row, region_from_row,
+            site_from_row,
+        },
+        parse_uuid, timestamp,
+    },
+};
+use domain::depot::{
+    entities::{depot::Depot, region::Region, site::Site},
+    repositories::depot_repository::{DepotRepository, DepotRepositoryError},
+};
+use sqlx::{SqlitePool, sqlite::SqliteRow};
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct SqliteDepotRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteDepotRepository {
+    pub fn new(database: &SqliteDatabase) -> Self {
+        SqliteDepotRepository {
+            pool: database.pool().clone(),
+        }
+    }
+
+    /// Reads the rows of a table of the organization, by name.
+    async fn find_all<T>(
+        &self,
+        organization_id: Uuid,
+        table: &str,
+        columns: &str,
+        from_row: fn(&SqliteRow) -> Result<T, String>,
+    ) -> Result<Vec<T>, DepotRepositoryError> {
+        sqlx::query(&format!(
+            "SELECT {} FROM {} WHERE organization_id = ?1 ORDER BY name, id",
+            columns, table
+        ))
+        .bind(organization_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(database_error)
+        .and_then(|rows| rows.iter().map(from_row).collect())
+        .map_err(DepotRepositoryError::Database)
+    }
+
+    /// Reads a row of a table of the organization by its id.
+    async fn find_one<T>(
+        &self,
+        organization_id: Uuid,
+        id: Uuid,
+        table: &str,
+        columns: &str,
+        from_row: fn(&SqliteRow) -> Result<T, String>,
+    ) -> Result<Option<T>, DepotRepositoryError> {
+        sqlx::query(&format!(
+            "SELECT {} FROM {} WHERE organization_id = ?1 AND id = ?2",
+            columns, table
+        ))
+        .bind(organization_id.to_string())
+        .bind(id.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(database_error)
+        .and_then(|row| row.map(|row| from_row(&row)).transpose())
+        .map_err(DepotRepositoryError::Database)
+    }
+}
+
+/// The ids are new UUIDs: a unique violation is the one of the name.
+fn create_error(e: sqlx::Error, name: &str) -> DepotRepositoryError {
+    match is_unique_violation(&e) {
+        true => DepotRepositoryError::AlreadyExists(name.to_string()),
+        false => DepotRepositoryError::Database(database_error(e)),
+    }
+}
+
+impl DepotRepository for SqliteDepotRepository {
+    async fn create_region(
+        &self,
+        organization_id: Uuid,
+        region: Region,
+    ) -> Result<Region, DepotRepositoryError> {
+        sqlx::query(
+            "INSERT INTO regions (id, name, created_at, updated_at, organization_id)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+        )
+        .bind(region.id.to_string())
+        .bind(region.name.value())
+        .bind(timestamp(region.created_at))
+        .bind(timestamp(region.updated_at))
+        .bind(organization_id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| create_error(e, region.name.value()))?;
+        Ok(region)
+    }
+
+    async fn create_depot(
+        &self,
+        organization_id: Uuid,
+        depot: Depot,
+    ) -> Result<Depot, DepotRepositoryError> {
+        sqlx::query(
+            "INSERT INTO depots (id, region_id, name, created_at, updated_at, organization_id)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        )
+        .bind(depot.id.to_string())
+        .bind(depot.region_id.to_string())
+        .bind(depot.name.value())
+        .bind(timestamp(depot.created_at))
+        .bind(timestamp(depot.updated_at))
+        .bind(organization_id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| create_error(e, depot.name.value()))?;
+        Ok(depot)
+    }
+
+    async fn create_site(
+        &self,
+        organization_id: Uuid,
+        site: Site,
+    ) -> Result<Site, DepotRepositoryError> {
+        sqlx::query(
+            "INSERT INTO sites (id, depot_id, name, created_at, updated_at, organization_id)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        )
+        .bind(site.id.to_string())
+        .bind(site.depot_id.to_string())
+        .bind(site.name.value())
+        .bind(timestamp(site.created_at))
+        .bind(timestamp(site.updated_at))
+        .bind(organization_id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| create_error(e, site.name.value()))?;
+        Ok(site)
+    }
+
+    async fn find_regions(
+        &self,
+        organization_id: Uuid,
+    ) -> Result<Vec<Region>, DepotRepositoryError> {
+        self.find_all(organization_id, "regions", REGION_COLUMNS, region_from_row)
+            .await
+    }
+
+    async fn find_depots(&self, organization_id: Uuid) -> Result<Vec<Depot>, DepotRepositoryError> {
+        self.find_all(organization_id, "depots", DEPOT_COLUMNS, depot_from_row)
+            .await
+    }
+
+    async fn find_sites(&self, organization_id: Uuid) -> Result<Vec<Site>, DepotRepositoryError> {
+        self.find_all(organization_id, "sites", SITE_COLUMNS, site_from_row)
+            .await
+    }
+
+    async fn find_region(
+        &self,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> Result<Option<Region>, DepotRepositoryError> {
+        self.find_one(
+            organization_id,
+            id,
+            "regions",
+            REGION_COLUMNS,
+            region_from_row,
+        )
+        .await
+    }
+
+    async fn find_depot(
+        &self,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> Result<Option<Depot>, DepotRepositoryError> {
+        self.find_one(organization_id, id, "depots", DEPOT_COLUMNS, depot_from_row)
+            .await
+    }
+
+    async fn assign_manager(
+        &self,
+        organization_id: Uuid,
+        depot_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), DepotRepositoryError> {
+        sqlx::query(
+            "INSERT INTO depot_managers (depot_id, user_id, organization_id)
+             VALUES (?1, ?2, ?3)
+             ON CONFLICT (depot_id, user_id) DO NOTHING",
+        )
+        .bind(depot_id.to_string())
+        .bind(user_id.to_string())
+        .bind(organization_id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DepotRepositoryError::Database(database_error(e)))?;
+        Ok(())
+    }
+
+    async fn managed_depots(
+        &self,
+        organization_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Vec<Uuid>, DepotRepositoryError> {
+        let rows: Vec<(String,)> = sqlx::query_as(
+            "SELECT depot_id FROM depot_managers
+             WHERE organization_id = ?1 AND user_id = ?2
+             ORDER BY depot_id",
+        )
+        .bind(organization_id.to_string())
+        .bind(user_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DepotRepositoryError::Database(database_error(e)))?;
+
+        rows.iter()
+            .map(|(depot_id,)| parse_uuid(depot_id))
+            .collect::<Result<_, _>>()
+            .map_err(DepotRepositoryError::Database)
+    }
+}
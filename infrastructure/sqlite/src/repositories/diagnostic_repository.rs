@@ -0,0 +1,259 @@
+use crate::{
+    database::{SqliteDatabase, database_error, is_unique_violation},
+    mappers::{
+        diagnostic_mapper::{
+            DIAGNOSTIC_ALERT_COLUMNS, DIAGNOSTIC_RULE_COLUMNS, DTC_EVENT_COLUMNS,
+            diagnostic_alert_from_row, diagnostic_rule_from_row, dtc_event_from_row,
+            freeze_frame_to_sql,
+        },
+        timestamp,
+    },
+};
+use domain::diagnostics::{
+    entities::{
+        diagnostic_alert::DiagnosticAlert, diagnostic_rule::DiagnosticRule, dtc_event::DtcEvent,
+    },
+    repositories::diagnostic_repository::{DiagnosticRepository, DiagnosticRepositoryError},
+    value_types::dtc_code::DtcCode,
+};
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct SqliteDiagnosticRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteDiagnosticRepository {
+    pub fn new(database: &SqliteDatabase) -> Self {
+        SqliteDiagnosticRepository {
+            pool: database.pool().clone(),
+        }
+    }
+}
+
+/// The ids are new UUIDs: a unique violation is the one of the natural key, reported as `key`.
+fn create_error(e: sqlx::Error, key: String) -> DiagnosticRepositoryError {
+    match is_unique_violation(&e) {
+        true => DiagnosticRepositoryError::AlreadyExists(key),
+        false => DiagnosticRepositoryError::Database(database_error(e)),
+    }
+}
+
+impl DiagnosticRepository for SqliteDiagnosticRepository {
+    async fn open_event(
+        &self,
+        organization_id: Uuid,
+        event: DtcEvent,
+    ) -> Result<DtcEvent, DiagnosticRepositoryError> {
+        sqlx::query(
+            "INSERT INTO dtc_events (id, vehicle_id, code, severity, freeze_frame, first_seen_at,
+                 last_seen_at, occurrences, cleared_at, cleared_by, organization_id)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+        )
+        .bind(event.id.to_string())
+        .bind(event.vehicle_id.to_string())
+        .bind(event.code.value())
+        .bind(event.severity.as_sql())
+        .bind(freeze_frame_to_sql(&event.freeze_frame))
+        .bind(timestamp(event.first_seen_at))
+        .bind(timestamp(event.last_seen_at))
+        .bind(event.occurrences)
+        .bind(event.cleared_at.map(timestamp))
+        .bind(event.cleared_by.map(|id| id.to_string()))
+        .bind(organization_id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| create_error(e, event.code.to_string()))?;
+        Ok(event)
+    }
+
+    async fn update_event(
+        &self,
+        organization_id: Uuid,
+        event: DtcEvent,
+    ) -> Result<bool, DiagnosticRepositoryError> {
+        sqlx::query(
+            "UPDATE dtc_events
+             SET severity = ?3, first_seen_at = ?4, last_seen_at = ?5, occurrences = ?6,
+                 cleared_at = ?7, cleared_by = ?8
+             WHERE organization_id = ?1 AND id = ?2",
+        )
+        .bind(organization_id.to_string())
+        .bind(event.id.to_string())
+        .bind(event.severity.as_sql())
+        .bind(timestamp(event.first_seen_at))
+        .bind(timestamp(event.last_seen_at))
+        .bind(event.occurrences)
+        .bind(event.cleared_at.map(timestamp))
+        .bind(event.cleared_by.map(|id| id.to_string()))
+        .execute(&self.pool)
+        .await
+        .map(|result| result.rows_affected() > 0)
+        .map_err(|e| DiagnosticRepositoryError::Database(database_error(e)))
+    }
+
+    async fn find_event(
+        &self,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> Result<Option<DtcEvent>, DiagnosticRepositoryError> {
+        sqlx::query(&format!(
+            "SELECT {} FROM dtc_events WHERE organization_id = ?1 AND id = ?2",
+            DTC_EVENT_COLUMNS
+        ))
+        .bind(organization_id.to_string())
+        .bind(id.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(database_error)
+        .and_then(|row| row.map(|row| dtc_event_from_row(&row)).transpose())
+        .map_err(DiagnosticRepositoryError::Database)
+    }
+
+    async fn find_active_event(
+        &self,
+        organization_id: Uuid,
+        vehicle_id: Uuid,
+        code: &DtcCode,
+    ) -> Result<Option<DtcEvent>, DiagnosticRepositoryError> {
+        sqlx::query(&format!(
+            "SELECT {} FROM dtc_events
+             WHERE organization_id = ?1 AND vehicle_id = ?2 AND code = ?3 AND cleared_at IS NULL",
+            DTC_EVENT_COLUMNS
+        ))
+        .bind(organization_id.to_string())
+        .bind(vehicle_id.to_string())
+        .bind(code.value())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(database_error)
+        .and_then(|row| row.map(|row| dtc_event_from_row(&row)).transpose())
+        .map_err(DiagnosticRepositoryError::Database)
+    }
+
+    async fn find_events_by_vehicle(
+        &self,
+        organization_id: Uuid,
+        vehicle_id: Uuid,
+        include_cleared: bool,
+    ) -> Result<Vec<DtcEvent>, DiagnosticRepositoryError> {
+        sqlx::query(&format!(
+            "SELECT {} FROM dtc_events
+             WHERE organization_id = ?1 AND vehicle_id = ?2 AND (?3 OR cleared_at IS NULL)
+             ORDER BY first_seen_at, id",
+            DTC_EVENT_COLUMNS
+        ))
+        .bind(organization_id.to_string())
+        .bind(vehicle_id.to_string())
+        .bind(include_cleared)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(database_error)
+        .and_then(|rows| rows.iter().map(dtc_event_from_row).collect())
+        .map_err(DiagnosticRepositoryError::Database)
+    }
+
+    async fn create_rule(
+        &self,
+        organization_id: Uuid,
+        rule: DiagnosticRule,
+    ) -> Result<DiagnosticRule, DiagnosticRepositoryError> {
+        sqlx::query(
+            "INSERT INTO diagnostic_rules (id, name, min_severity, code_prefix,
+                 maintenance_type_id, created_at, organization_id)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+        )
+        .bind(rule.id.to_string())
+        .bind(&rule.name)
+        .bind(rule.min_severity.as_sql())
+        .bind(&rule.code_prefix)
+        .bind(rule.action.maintenance_type_id())
+        .bind(timestamp(rule.created_at))
+        .bind(organization_id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| create_error(e, rule.name.clone()))?;
+        Ok(rule)
+    }
+
+    async fn find_rules(
+        &self,
+        organization_id: Uuid,
+    ) -> Result<Vec<DiagnosticRule>, DiagnosticRepositoryError> {
+        sqlx::query(&format!(
+            "SELECT {} FROM diagnostic_rules WHERE organization_id = ?1 ORDER BY name, id",
+            DIAGNOSTIC_RULE_COLUMNS
+        ))
+        .bind(organization_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(database_error)
+        .and_then(|rows| rows.iter().map(diagnostic_rule_from_row).collect())
+        .map_err(DiagnosticRepositoryError::Database)
+    }
+
+    async fn open_alert(
+        &self,
+        organization_id: Uuid,
+        alert: DiagnosticAlert,
+    ) -> Result<DiagnosticAlert, DiagnosticRepositoryError> {
+        sqlx::query(
+            "INSERT INTO diagnostic_alerts (id, vehicle_id, dtc_event_id, rule_id, opened_at,
+                 resolved_at, organization_id)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+        )
+        .bind(alert.id.to_string())
+        .bind(alert.vehicle_id.to_string())
+        .bind(alert.dtc_event_id.to_string())
+        .bind(alert.rule_id.to_string())
+        .bind(timestamp(alert.opened_at))
+        .bind(alert.resolved_at.map(timestamp))
+        .bind(organization_id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| create_error(e, alert.rule_id.to_string()))?;
+        Ok(alert)
+    }
+
+    async fn find_alerts_by_vehicle(
+        &self,
+        organization_id: Uuid,
+        vehicle_id: Uuid,
+        open_only: bool,
+    ) -> Result<Vec<DiagnosticAlert>, DiagnosticRepositoryError> {
+        sqlx::query(&format!(
+            "SELECT {} FROM diagnostic_alerts
+             WHERE organization_id = ?1 AND vehicle_id = ?2 AND (NOT ?3 OR resolved_at IS NULL)
+             ORDER BY opened_at, id",
+            DIAGNOSTIC_ALERT_COLUMNS
+        ))
+        .bind(organization_id.to_string())
+        .bind(vehicle_id.to_string())
+        .bind(open_only)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(database_error)
+        .and_then(|rows| rows.iter().map(diagnostic_alert_from_row).collect())
+        .map_err(DiagnosticRepositoryError::Database)
+    }
+
+    async fn resolve_alerts(
+        &self,
+        organization_id: Uuid,
+        dtc_event_id: Uuid,
+        resolved_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<u64, DiagnosticRepositoryError> {
+        sqlx::query(
+            "UPDATE diagnostic_alerts SET resolved_at = ?3
+             WHERE organization_id = ?1 AND dtc_event_id = ?2 AND resolved_at IS NULL",
+        )
+        .bind(organization_id.to_string())
+        .bind(dtc_event_id.to_string())
+        .bind(timestamp(resolved_at))
+        .execute(&self.pool)
+        .await
+        .map(|result| result.rows_affected())
+        .map_err(|e| DiagnosticRepositoryError::Database(database_error(e)))
+    }
+}
@@ -0,0 +1,281 @@
+use crate::{
+    database::{SqliteDatabase, database_error, is_unique_violation},
+    mappers::{
+        inspection_mapper::{
+            INSPECTION_DEFECT_COLUMNS, INSPECTION_REPORT_COLUMNS, INSPECTION_TEMPLATE_COLUMNS,
+            answers_to_sql, inspection_defect_from_row, inspection_report_from_row,
+            inspection_template_from_row, items_to_sql,
+        },
+        timestamp,
+    },
+};
+use domain::inspection::{
+    entities::{
+        inspection_defect::InspectionDefect, inspection_report::InspectionReport,
+        inspection_template::InspectionTemplate,
+    },
+    repositories::inspection_repository::{InspectionRepository, InspectionRepositoryError},
+};
+use sqlx::{SqlitePool, sqlite::SqliteRow};
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct SqliteInspectionRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteInspectionRepository {
+    pub fn new(database: &SqliteDatabase) -> Self {
+        SqliteInspectionRepository {
+            pool: database.pool().clone(),
+        }
+    }
+
+    /// Reads a row of a table of the organization by its id.
+    async fn find_one<T>(
+        &self,
+        organization_id: Uuid,
+        id: Uuid,
+        table: &str,
+        columns: &str,
+        from_row: fn(&SqliteRow) -> Result<T, String>,
+    ) -> Result<Option<T>, InspectionRepositoryError> {
+        sqlx::query(&format!(
+            "SELECT {} FROM {} WHERE organization_id = ?1 AND id = ?2",
+            columns, table
+        ))
+        .bind(organization_id.to_string())
+        .bind(id.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(database_error)
+        .and_then(|row| row.map(|row| from_row(&row)).transpose())
+        .map_err(InspectionRepositoryError::Database)
+    }
+}
+
+fn error(e: impl std::fmt::Display) -> InspectionRepositoryError {
+    InspectionRepositoryError::Database(database_error(e))
+}
+
+impl InspectionRepository for SqliteInspectionRepository {
+    async fn create_template(
+        &self,
+        organization_id: Uuid,
+        template: InspectionTemplate,
+    ) -> Result<InspectionTemplate, InspectionRepositoryError> {
+        sqlx::query(
+            "INSERT INTO inspection_templates (id, name, kind, items, created_at, created_by,
+                 organization_id)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+        )
+        .bind(template.id.to_string())
+        .bind(&template.name)
+        .bind(template.kind.as_sql())
+        .bind(items_to_sql(&template.items))
+        .bind(timestamp(template.created_at))
+        .bind(template.created_by.to_string())
+        .bind(organization_id.to_string())
+        .execute(&self.pool)
+        .await
+        // The id is a new UUID: a unique violation is the one of the name
+        .map_err(|e| match is_unique_violation(&e) {
+            true => InspectionRepositoryError::AlreadyExists(template.name.clone()),
+            false => error(e),
+        })?;
+        Ok(template)
+    }
+
+    async fn find_template(
+        &self,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> Result<Option<InspectionTemplate>, InspectionRepositoryError> {
+        self.find_one(
+            organization_id,
+            id,
+            "inspection_templates",
+            INSPECTION_TEMPLATE_COLUMNS,
+            inspection_template_from_row,
+        )
+        .await
+    }
+
+    async fn find_templates(
+        &self,
+        organization_id: Uuid,
+    ) -> Result<Vec<InspectionTemplate>, InspectionRepositoryError> {
+        sqlx::query(&format!(
+            "SELECT {} FROM inspection_templates WHERE organization_id = ?1 ORDER BY name, id",
+            INSPECTION_TEMPLATE_COLUMNS
+        ))
+        .bind(organization_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(database_error)
+        .and_then(|rows| rows.iter().map(inspection_template_from_row).collect())
+        .map_err(InspectionRepositoryError::Database)
+    }
+
+    async fn submit_report(
+        &self,
+        organization_id: Uuid,
+        report: InspectionReport,
+        defects: Vec<InspectionDefect>,
+    ) -> Result<InspectionReport, InspectionRepositoryError> {
+        let mut transaction = self.pool.begin().await.map_err(error)?;
+        sqlx::query(
+            "INSERT INTO inspection_reports (id, template_id, vehicle_id, vehicle_status_id, kind,
+                 submitted_by, submitted_at, answers, organization_id)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+        )
+        .bind(report.id.to_string())
+        .bind(report.template_id.to_string())
+        .bind(report.vehicle_id.to_string())
+        .bind(report.vehicle_status_id)
+        .bind(report.kind.as_sql())
+        .bind(report.submitted_by.to_string())
+        .bind(timestamp(report.submitted_at))
+        .bind(answers_to_sql(&report.answers))
+        .bind(organization_id.to_string())
+        .execute(&mut *transaction)
+        .await
+        .map_err(error)?;
+
+        for defect in &defects {
+            sqlx::query(
+                "INSERT INTO inspection_defects (id, report_id, vehicle_id, item_id, label,
+                     critical, comment, reported_at, resolution, signed_off_at, signed_off_by,
+                     sign_off_notes, organization_id)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
+            )
+            .bind(defect.id.to_string())
+            .bind(defect.report_id.to_string())
+            .bind(defect.vehicle_id.to_string())
+            .bind(defect.item_id.to_string())
+            .bind(&defect.label)
+            .bind(defect.critical)
+            .bind(&defect.comment)
+            .bind(timestamp(defect.reported_at))
+            .bind(
+                defect
+                    .resolution
+                    .map(|resolution| resolution.as_sql().to_string()),
+            )
+            .bind(defect.signed_off_at.map(timestamp))
+            .bind(defect.signed_off_by.map(|id| id.to_string()))
+            .bind(&defect.sign_off_notes)
+            .bind(organization_id.to_string())
+            .execute(&mut *transaction)
+            .await
+            .map_err(error)?;
+        }
+        transaction.commit().await.map_err(error)?;
+        Ok(report)
+    }
+
+    async fn find_report(
+        &self,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> Result<Option<InspectionReport>, InspectionRepositoryError> {
+        self.find_one(
+            organization_id,
+            id,
+            "inspection_reports",
+            INSPECTION_REPORT_COLUMNS,
+            inspection_report_from_row,
+        )
+        .await
+    }
+
+    async fn find_reports_by_vehicle(
+        &self,
+        organization_id: Uuid,
+        vehicle_id: Uuid,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<InspectionReport>, InspectionRepositoryError> {
+        sqlx::query(&format!(
+            "SELECT {} FROM inspection_reports
+             WHERE organization_id = ?1
+                 AND vehicle_id = ?2
+                 AND (?3 IS NULL OR submitted_at >= ?3)
+                 AND (?4 IS NULL OR submitted_at <= ?4)
+             ORDER BY submitted_at, id",
+            INSPECTION_REPORT_COLUMNS
+        ))
+        .bind(organization_id.to_string())
+        .bind(vehicle_id.to_string())
+        .bind(from.map(timestamp))
+        .bind(to.map(timestamp))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(database_error)
+        .and_then(|rows| rows.iter().map(inspection_report_from_row).collect())
+        .map_err(InspectionRepositoryError::Database)
+    }
+
+    async fn find_defect(
+        &self,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> Result<Option<InspectionDefect>, InspectionRepositoryError> {
+        self.find_one(
+            organization_id,
+            id,
+            "inspection_defects",
+            INSPECTION_DEFECT_COLUMNS,
+            inspection_defect_from_row,
+        )
+        .await
+    }
+
+    async fn find_defects_by_vehicle(
+        &self,
+        organization_id: Uuid,
+        vehicle_id: Uuid,
+        open_only: bool,
+    ) -> Result<Vec<InspectionDefect>, InspectionRepositoryError> {
+        sqlx::query(&format!(
+            "SELECT {} FROM inspection_defects
+             WHERE organization_id = ?1 AND vehicle_id = ?2 AND (NOT ?3 OR signed_off_at IS NULL)
+             ORDER BY reported_at, id",
+            INSPECTION_DEFECT_COLUMNS
+        ))
+        .bind(organization_id.to_string())
+        .bind(vehicle_id.to_string())
+        .bind(open_only)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(database_error)
+        .and_then(|rows| rows.iter().map(inspection_defect_from_row).collect())
+        .map_err(InspectionRepositoryError::Database)
+    }
+
+    async fn sign_off_defect(
+        &self,
+        organization_id: Uuid,
+        defect: InspectionDefect,
+    ) -> Result<bool, InspectionRepositoryError> {
+        sqlx::query(
+            "UPDATE inspection_defects
+             SET resolution = ?3, signed_off_at = ?4, signed_off_by = ?5, sign_off_notes = ?6
+             WHERE organization_id = ?1 AND id = ?2 AND signed_off_at IS NULL",
+        )
+        .bind(organization_id.to_string())
+        .bind(defect.id.to_string())
+        .bind(
+            defect
+                .resolution
+                .map(|resolution| resolution.as_sql().to_string()),
+        )
+        .bind(defect.signed_off_at.map(timestamp))
+        .bind(defect.signed_off_by.map(|id| id.to_string()))
+        .bind(&defect.sign_off_notes)
+        .execute(&self.pool)
+        .await
+        .map(|result| result.rows_affected() > 0)
+        .map_err(error)
+    }
+}
@@ -0,0 +1,233 @@
+use crate::{
+    database::{SqliteDatabase, database_error},
+    mappers::{
+        get,
+        maintenance_record_mapper::{
+            COST_LINE_COLUMNS, MAINTENANCE_RECORD_COLUMNS, cost_line_from_row, cost_line_params,
+            maintenance_record_from_row,
+        },
+        timestamp,
+        user_mapper::{user_columns, user_from_row},
+    },
+    repositories::{
+        maintenance_repository::find_maintenance, vehicle_repository::find_vehicle,
+        vehicle_status_repository::find_vehicle_status,
+    },
+};
+use domain::{
+    maintenance::{
+        entities::maintenance_record::{MaintenanceRecord, MaintenanceRecordIdentity},
+        repositories::maintenance_record_repository::{
+            MaintenanceRecordRepository, MaintenanceRecordRepositoryError,
+        },
+    },
+    user::entities::user::UserIdentity,
+};
+use sqlx::{SqliteConnection, SqlitePool, sqlite::SqliteRow};
+
+#[derive(Debug, Clone)]
+pub struct SqliteMaintenanceRecordRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteMaintenanceRecordRepository {
+    pub fn new(database: &SqliteDatabase) -> Self {
+        SqliteMaintenanceRecordRepository {
+            pool: database.pool().clone(),
+        }
+    }
+}
+
+async fn find_user(
+    connection: &mut SqliteConnection,
+    id: uuid::Uuid,
+) -> Result<Option<UserIdentity>, String> {
+    sqlx::query(&format!(
+        "SELECT {} FROM users u WHERE u.uuid = ?1",
+        user_columns("u", "")
+    ))
+    .bind(id.to_string())
+    .fetch_optional(connection)
+    .await
+    .map_err(database_error)?
+    .map(|row| user_from_row(&row, ""))
+    .transpose()
+}
+
+/// Reads the records of the rows with their cost lines and the entities they reference.
+async fn hydrate(
+    connection: &mut SqliteConnection,
+    rows: Vec<SqliteRow>,
+) -> Result<Vec<MaintenanceRecord>, String> {
+    let mut records = Vec::with_capacity(rows.len());
+    for row in rows {
+        let lines = sqlx::query(&format!(
+            "SELECT {} FROM maintenance_cost_lines
+             WHERE maintenance_record_id = ?1
+             ORDER BY position",
+            COST_LINE_COLUMNS
+        ))
+        .bind(get::<String>(&row, "id")?)
+        .fetch_all(&mut *connection)
+        .await
+        .map_err(database_error)?;
+        let identity = maintenance_record_from_row(&row, |currency| {
+            lines
+                .iter()
+                .map(|line| cost_line_from_row(line, currency))
+                .collect()
+        })?;
+
+        records.push(MaintenanceRecord {
+            vehicle: find_vehicle(connection, identity.vehicle_id)
+                .await?
+                .ok_or_else(|| format!("vehicle {} not found", identity.vehicle_id))?,
+            maintenance: find_maintenance(connection, identity.maintenance_id)
+                .await?
+                .ok_or_else(|| format!("maintenance {} not found", identity.maintenance_id))?,
+            user: find_user(connection, identity.user_id)
+                .await?
+                .ok_or_else(|| format!("user {} not found", identity.user_id))?,
+            vehicle_status: find_vehicle_status(connection, identity.vehicle_status_id)
+                .await?
+                .ok_or_else(|| {
+                    format!("vehicle status {} not found", identity.vehicle_status_id)
+                })?,
+            identity,
+        });
+    }
+    Ok(records)
+}
+
+impl MaintenanceRecordRepository for SqliteMaintenanceRecordRepository {
+    async fn create(
+        &self,
+        organization_id: uuid::Uuid,
+        record: MaintenanceRecordIdentity,
+    ) -> Result<MaintenanceRecordIdentity, MaintenanceRecordRepositoryError> {
+        let error = |e: sqlx::Error| MaintenanceRecordRepositoryError::Database(database_error(e));
+        let mut transaction = self.pool.begin().await.map_err(error)?;
+
+        sqlx::query(
+            "INSERT INTO maintenance_records (
+                 id, vehicle_id, maintenance_id, performed_by, vehicle_status_id, performed_at,
+                 details, currency, invoice_reference, created_at, created_by, updated_at,
+                 updated_by, organization_id
+             )
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)",
+        )
+        .bind(record.id.to_string())
+        .bind(record.vehicle_id.to_string())
+        .bind(record.maintenance_id)
+        .bind(record.user_id.to_string())
+        .bind(record.vehicle_status_id)
+        .bind(timestamp(record.performed_at))
+        .bind(&record.details)
+        .bind(
+            record
+                .cost
+                .as_ref()
+                .map(|cost| cost.currency().code().to_string()),
+        )
+        .bind(
+            record
+                .cost
+                .as_ref()
+                .and_then(|cost| cost.invoice_reference().map(str::to_string)),
+        )
+        .bind(timestamp(record.created_at))
+        .bind(record.created_by.to_string())
+        .bind(timestamp(record.updated_at))
+        .bind(record.updated_by.to_string())
+        .bind(organization_id.to_string())
+        .execute(&mut *transaction)
+        .await
+        .map_err(error)?;
+
+        for (position, line) in record.cost.iter().flat_map(|cost| cost.lines()).enumerate() {
+            let (kind, quantity, unit_price) = cost_line_params(line);
+            sqlx::query(
+                "INSERT INTO maintenance_cost_lines (
+                     maintenance_record_id, position, kind, description, part_number, quantity,
+                     unit_price, organization_id
+                 )
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            )
+            .bind(record.id.to_string())
+            .bind(position as i32)
+            .bind(kind)
+            .bind(&line.description)
+            .bind(&line.part_number)
+            .bind(quantity)
+            .bind(unit_price)
+            .bind(organization_id.to_string())
+            .execute(&mut *transaction)
+            .await
+            .map_err(error)?;
+        }
+
+        transaction.commit().await.map_err(error)?;
+        Ok(record)
+    }
+    async fn find_by_vehicle(
+        &self,
+        organization_id: uuid::Uuid,
+        vehicle_id: uuid::Uuid,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<MaintenanceRecord>, MaintenanceRecordRepositoryError> {
+        let error = |e: String| MaintenanceRecordRepositoryError::Database(e);
+        let mut connection = self
+            .pool
+            .acquire()
+            .await
+            .map_err(|e| error(database_error(e)))?;
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM maintenance_records
+             WHERE organization_id = ?1
+                 AND vehicle_id = ?2
+                 AND (?3 IS NULL OR performed_at >= ?3)
+                 AND (?4 IS NULL OR performed_at <= ?4)
+             ORDER BY performed_at, id",
+            MAINTENANCE_RECORD_COLUMNS
+        ))
+        .bind(organization_id.to_string())
+        .bind(vehicle_id.to_string())
+        .bind(from.map(timestamp))
+        .bind(to.map(timestamp))
+        .fetch_all(&mut *connection)
+        .await
+        .map_err(|e| error(database_error(e)))?;
+        hydrate(&mut connection, rows).await.map_err(error)
+    }
+
+    async fn find_latest_by_maintenance(
+        &self,
+        organization_id: uuid::Uuid,
+        maintenance_id: i32,
+    ) -> Result<Option<MaintenanceRecord>, MaintenanceRecordRepositoryError> {
+        let error = |e: String| MaintenanceRecordRepositoryError::Database(e);
+        let mut connection = self
+            .pool
+            .acquire()
+            .await
+            .map_err(|e| error(database_error(e)))?;
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM maintenance_records
+             WHERE organization_id = ?1 AND maintenance_id = ?2
+             ORDER BY performed_at DESC, id DESC
+             LIMIT 1",
+            MAINTENANCE_RECORD_COLUMNS
+        ))
+        .bind(organization_id.to_string())
+        .bind(maintenance_id)
+        .fetch_all(&mut *connection)
+        .await
+        .map_err(|e| error(database_error(e)))?;
+        Ok(hydrate(&mut connection, rows)
+            .await
+            .map_err(error)?
+            .into_iter()
+            .next())
+    }
+}
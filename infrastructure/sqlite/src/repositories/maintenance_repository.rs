@@ -0,0 +1,158 @@
+use crate::{
+    database::{SqliteDatabase, database_error},
+    mappers::{
+        interval_type_to_sql,
+        maintenance_mapper::{MAINTENANCE_COLUMNS, maintenance_from_row},
+        timestamp,
+    },
+    repositories::{
+        maintenance_type_repository::find_maintenance_type, vehicle_repository::find_vehicle,
+    },
+};
+use domain::maintenance::{
+    entities::maintenance::{Maintenance, MaintenanceIdentity},
+    repositories::maintenance_repository::{MaintenanceRepository, MaintenanceRepositoryError},
+};
+use sqlx::{SqliteConnection, SqlitePool};
+
+#[derive(Debug, Clone)]
+pub struct SqliteMaintenanceRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteMaintenanceRepository {
+    pub fn new(database: &SqliteDatabase) -> Self {
+        SqliteMaintenanceRepository {
+            pool: database.pool().clone(),
+        }
+    }
+
+    /// Reads the rules of the organization matching `condition` (on `?2`), hydrated.
+    async fn find_where(
+        &self,
+        organization_id: uuid::Uuid,
+        condition: &str,
+        value: String,
+    ) -> Result<Vec<Maintenance>, String> {
+        let mut connection = self.pool.acquire().await.map_err(database_error)?;
+        let identities = sqlx::query(&format!(
+            "SELECT {} FROM maintenances WHERE organization_id = ?1 AND {} ORDER BY id",
+            MAINTENANCE_COLUMNS, condition
+        ))
+        .bind(organization_id.to_string())
+        .bind(value)
+        .fetch_all(&mut *connection)
+        .await
+        .map_err(database_error)?
+        .iter()
+        .map(maintenance_from_row)
+        .collect::<Result<Vec<_>, _>>()?;
+
+        let mut maintenances = Vec::with_capacity(identities.len());
+        for identity in identities {
+            maintenances.push(hydrate(&mut connection, identity).await?);
+        }
+        Ok(maintenances)
+    }
+}
+
+/// Reads a rule, for the repositories hydrating the entities referencing it.
+pub(crate) async fn find_maintenance(
+    connection: &mut SqliteConnection,
+    id: i32,
+) -> Result<Option<MaintenanceIdentity>, String> {
+    sqlx::query(&format!(
+        "SELECT {} FROM maintenances WHERE id = ?1",
+        MAINTENANCE_COLUMNS
+    ))
+    .bind(id)
+    .fetch_optional(connection)
+    .await
+    .map_err(database_error)?
+    .map(|row| maintenance_from_row(&row))
+    .transpose()
+}
+
+async fn hydrate(
+    connection: &mut SqliteConnection,
+    identity: MaintenanceIdentity,
+) -> Result<Maintenance, String> {
+    Ok(Maintenance {
+        maintenance_type: find_maintenance_type(connection, identity.maintenance_type_id)
+            .await?
+            .ok_or_else(|| {
+                format!(
+                    "maintenance type {} not found",
+                    identity.maintenance_type_id
+                )
+            })?,
+        vehicle: find_vehicle(connection, identity.vehicle_id)
+            .await?
+            .ok_or_else(|| format!("vehicle {} not found", identity.vehicle_id))?,
+        identity,
+    })
+}
+
+impl MaintenanceRepository for SqliteMaintenanceRepository {
+    async fn find_by_vehicle(
+        &self,
+        organization_id: uuid::Uuid,
+        vehicle_id: uuid::Uuid,
+    ) -> Result<Vec<Maintenance>, MaintenanceRepositoryError> {
+        self.find_where(organization_id, "vehicle_id = ?2", vehicle_id.to_string())
+            .await
+            .map_err(MaintenanceRepositoryError::Database)
+    }
+
+    async fn find_by_maintenance_type(
+        &self,
+        organization_id: uuid::Uuid,
+        maintenance_type_id: i32,
+    ) -> Result<Vec<Maintenance>, MaintenanceRepositoryError> {
+        self.find_where(
+            organization_id,
+            "maintenance_type_id = ?2",
+            maintenance_type_id.to_string(),
+        )
+        .await
+        .map_err(MaintenanceRepositoryError::Database)
+    }
+
+    async fn create(
+        &self,
+        organization_id: uuid::Uuid,
+        maintenance: Maintenance,
+    ) -> Result<Maintenance, MaintenanceRepositoryError> {
+        let error = |e: sqlx::Error| MaintenanceRepositoryError::Database(database_error(e));
+        let mut transaction = self.pool.begin().await.map_err(error)?;
+        let identity = maintenance.identity;
+        let (id,): (i32,) = sqlx::query_as(
+            "INSERT INTO maintenances (
+                 vehicle_id, maintenance_type_id, interval_type, interval_value, red_threshold,
+                 yellow_threshold, created_at, created_by, updated_at, updated_by, organization_id
+             )
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
+             RETURNING id",
+        )
+        .bind(identity.vehicle_id.to_string())
+        .bind(identity.maintenance_type_id)
+        .bind(interval_type_to_sql(&identity.interval_type))
+        .bind(identity.interval_value)
+        .bind(identity.red_threshold)
+        .bind(identity.yellow_threshold)
+        .bind(timestamp(identity.created_at))
+        .bind(identity.created_by.to_string())
+        .bind(timestamp(identity.updated_at))
+        .bind(identity.updated_by.to_string())
+        .bind(organization_id.to_string())
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(error)?;
+
+        let maintenance = hydrate(&mut transaction, MaintenanceIdentity { id, ..identity })
+            .await
+            .map_err(MaintenanceRepositoryError::Database)?;
+        transaction.commit().await.map_err(error)?;
+        Ok(maintenance)
+    }
+}
@@ -0,0 +1,497 @@
+use crate::{
+    database::{SqliteDatabase, database_error, is_unique_violation},
+    mappers::{
+        maintenance_type_mapper::{
+            MAINTENANCE_TYPE_COLUMNS, checklist_to_sql, maintenance_type_from_row,
+            maintenance_type_view_from_row,
+        },
+        timestamp,
+        user_mapper::user_columns,
+    },
+};
+use domain::maintenance::{
+    entities::maintenance_type::{
+        MaintenanceType, MaintenanceTypeMerge, MaintenanceTypeUsage, MaintenanceTypeView,
+    },
+    repositories::maintenance_type_repository::{
+        MaintenanceTypeRepository, MaintenanceTypeRepositoryError,
+    },
+};
+use sqlx::{SqliteConnection, SqlitePool};
+
+#[derive(Debug, Clone)]
+pub struct SqliteMaintenanceTypeRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteMaintenanceTypeRepository {
+    pub fn new(database: &SqliteDatabase) -> Self {
+        SqliteMaintenanceTypeRepository {
+            pool: database.pool().clone(),
+        }
+    }
+}
+
+fn error(error: impl std::fmt::Display) -> MaintenanceTypeRepositoryError {
+    MaintenanceTypeRepositoryError::Database(database_error(error))
+}
+
+/// Maps the error of a write of `name`, whose only unique key is
+/// `maintenance_types_organization_id_name_key`.
+fn write_error(error: sqlx::Error, name: &str) -> MaintenanceTypeRepositoryError {
+    match is_unique_violation(&error) {
+        true => MaintenanceTypeRepositoryError::AlreadyExists(name.to_string()),
+        false => MaintenanceTypeRepositoryError::Database(database_error(error)),
+    }
+}
+
+/// Selects the views of the maintenance types of the organization (`?1`) matching `condition`.
+fn view_query(condition: &str) -> String {
+    format!(
+        "SELECT {}, {}, {}
+         FROM maintenance_types mt
+         JOIN users cu ON cu.uuid = mt.created_by
+         JOIN users uu ON uu.uuid = mt.updated_by
+         WHERE mt.organization_id = ?1 AND {}
+         ORDER BY mt.id",
+        MAINTENANCE_TYPE_COLUMNS,
+        user_columns("cu", "created_by_"),
+        user_columns("uu", "updated_by_"),
+        condition
+    )
+}
+
+/// Reads a maintenance type, for the repositories hydrating the entities referencing it.
+pub(crate) async fn find_maintenance_type(
+    connection: &mut SqliteConnection,
+    id: i32,
+) -> Result<Option<MaintenanceType>, String> {
+    sqlx::query(&format!(
+        "SELECT {} FROM maintenance_types mt WHERE mt.id = ?1",
+        MAINTENANCE_TYPE_COLUMNS
+    ))
+    .bind(id)
+    .fetch_optional(connection)
+    .await
+    .map_err(database_error)?
+    .map(|row| maintenance_type_from_row(&row))
+    .transpose()
+}
+
+async fn find_view(
+    connection: &mut SqliteConnection,
+    organization_id: uuid::Uuid,
+    id: i32,
+) -> Result<Option<MaintenanceTypeView>, String> {
+    sqlx::query(&view_query("mt.id = ?2"))
+        .bind(organization_id.to_string())
+        .bind(id)
+        .fetch_optional(connection)
+        .await
+        .map_err(database_error)?
+        .map(|row| maintenance_type_view_from_row(&row))
+        .transpose()
+}
+
+/// Reads the view of a maintenance type just written.
+async fn written_view(
+    connection: &mut SqliteConnection,
+    organization_id: uuid::Uuid,
+    id: i32,
+) -> Result<MaintenanceTypeView, MaintenanceTypeRepositoryError> {
+    find_view(connection, organization_id, id)
+        .await
+        .map_err(MaintenanceTypeRepositoryError::Database)?
+        .ok_or_else(|| error(format!("maintenance type {} not found", id)))
+}
+
+/// Checks that a row matching `condition` (on `?1`) exists.
+async fn check_exists(
+    connection: &mut SqliteConnection,
+    table: &str,
+    condition: &str,
+    value: String,
+    missing: String,
+) -> Result<(), MaintenanceTypeRepositoryError> {
+    let (exists,): (bool,) = sqlx::query_as(&format!(
+        "SELECT EXISTS(SELECT 1 FROM {} WHERE {})",
+        table, condition
+    ))
+    .bind(value)
+    .fetch_one(connection)
+    .await
+    .map_err(error)?;
+    match exists {
+        true => Ok(()),
+        false => Err(error(missing)),
+    }
+}
+
+impl MaintenanceTypeRepository for SqliteMaintenanceTypeRepository {
+    async fn create(
+        &self,
+        organization_id: uuid::Uuid,
+        maintenance_type: MaintenanceType,
+        user_id: uuid::Uuid,
+    ) -> Result<MaintenanceTypeView, MaintenanceTypeRepositoryError> {
+        let mut transaction = self.pool.begin().await.map_err(error)?;
+        let now = timestamp(chrono::Utc::now());
+        let (id,): (i32,) = sqlx::query_as(
+            "INSERT INTO maintenance_types (
+                 name, description, category, applicability, checklist, created_at, created_by,
+                 updated_at, updated_by, organization_id
+             )
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?6, ?7, ?8)
+             RETURNING id",
+        )
+        .bind(maintenance_type.name())
+        .bind(maintenance_type.description())
+        .bind(
+            maintenance_type
+                .category()
+                .map(|category| category.as_sql().to_string()),
+        )
+        .bind(maintenance_type.applicability().as_sql())
+        .bind(checklist_to_sql(maintenance_type.checklist()))
+        .bind(&now)
+        .bind(user_id.to_string())
+        .bind(organization_id.to_string())
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(|e| write_error(e, maintenance_type.name()))?;
+
+        let view = written_view(&mut transaction, organization_id, id).await?;
+        transaction.commit().await.map_err(error)?;
+        Ok(view)
+    }
+
+    async fn get_by_id(
+        &self,
+        organization_id: uuid::Uuid,
+        id: i32,
+    ) -> Result<Option<MaintenanceType>, MaintenanceTypeRepositoryError> {
+        sqlx::query(&format!(
+            "SELECT {} FROM maintenance_types mt WHERE mt.organization_id = ?1 AND mt.id = ?2",
+            MAINTENANCE_TYPE_COLUMNS
+        ))
+        .bind(organization_id.to_string())
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(database_error)
+        .and_then(|row| row.map(|row| maintenance_type_from_row(&row)).transpose())
+        .map_err(MaintenanceTypeRepositoryError::Database)
+    }
+
+    async fn get_view_by_id(
+        &self,
+        organization_id: uuid::Uuid,
+        id: i32,
+    ) -> Result<Option<MaintenanceTypeView>, MaintenanceTypeRepositoryError> {
+        let mut connection = self.pool.acquire().await.map_err(error)?;
+        find_view(&mut connection, organization_id, id)
+            .await
+            .map_err(MaintenanceTypeRepositoryError::Database)
+    }
+
+    async fn get_all_view(
+        &self,
+        organization_id: uuid::Uuid,
+    ) -> Result<Vec<MaintenanceTypeView>, MaintenanceTypeRepositoryError> {
+        sqlx::query(&view_query("mt.deprecated_at IS NULL"))
+            .bind(organization_id.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(database_error)
+            .and_then(|rows| rows.iter().map(maintenance_type_view_from_row).collect())
+            .map_err(MaintenanceTypeRepositoryError::Database)
+    }
+
+    async fn exists_by_name(
+        &self,
+        organization_id: uuid::Uuid,
+        name: &str,
+    ) -> Result<bool, MaintenanceTypeRepositoryError> {
+        let (exists,): (bool,) = sqlx::query_as(
+            "SELECT EXISTS(
+                 SELECT 1 FROM maintenance_types WHERE organization_id = ?1 AND name = ?2
+             )",
+        )
+        .bind(organization_id.to_string())
+        .bind(name)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(error)?;
+        Ok(exists)
+    }
+
+    async fn update(
+        &self,
+        organization_id: uuid::Uuid,
+        maintenance_type: MaintenanceType,
+        user_id: uuid::Uuid,
+    ) -> Result<MaintenanceTypeView, MaintenanceTypeRepositoryError> {
+        let mut transaction = self.pool.begin().await.map_err(error)?;
+        let result = sqlx::query(
+            "UPDATE maintenance_types
+             SET name = ?1, description = ?2, category = ?3, applicability = ?4, checklist = ?5,
+                 updated_at = ?6, updated_by = ?7
+             WHERE id = ?8 AND organization_id = ?9",
+        )
+        .bind(maintenance_type.name())
+        .bind(maintenance_type.description())
+        .bind(
+            maintenance_type
+                .category()
+                .map(|category| category.as_sql().to_string()),
+        )
+        .bind(maintenance_type.applicability().as_sql())
+        .bind(checklist_to_sql(maintenance_type.checklist()))
+        .bind(timestamp(chrono::Utc::now()))
+        .bind(user_id.to_string())
+        .bind(maintenance_type.id())
+        .bind(organization_id.to_string())
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| write_error(e, maintenance_type.name()))?;
+        if result.rows_affected() == 0 {
+            return Err(error(format!(
+                "maintenance type {} not found",
+                maintenance_type.id()
+            )));
+        }
+
+        let view = written_view(&mut transaction, organization_id, maintenance_type.id()).await?;
+        transaction.commit().await.map_err(error)?;
+        Ok(view)
+    }
+
+    async fn usage(
+        &self,
+        organization_id: uuid::Uuid,
+        id: i32,
+    ) -> Result<MaintenanceTypeUsage, MaintenanceTypeRepositoryError> {
+        let (rules, records): (i64, i64) = sqlx::query_as(
+            "SELECT
+                 (SELECT COUNT(*) FROM maintenances
+                  WHERE organization_id = ?1 AND maintenance_type_id = ?2),
+                 (SELECT COUNT(*)
+                  FROM maintenance_records r
+                  JOIN maintenances m ON m.id = r.maintenance_id
+                  WHERE r.organization_id = ?1 AND m.maintenance_type_id = ?2)",
+        )
+        .bind(organization_id.to_string())
+        .bind(id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(error)?;
+        Ok(MaintenanceTypeUsage {
+            rules: rules as u64,
+            records: records as u64,
+        })
+    }
+
+    async fn deprecate(
+        &self,
+        organization_id: uuid::Uuid,
+        maintenance_type: MaintenanceType,
+        user_id: uuid::Uuid,
+    ) -> Result<MaintenanceTypeView, MaintenanceTypeRepositoryError> {
+        let mut transaction = self.pool.begin().await.map_err(error)?;
+        let now = chrono::Utc::now();
+        let result = sqlx::query(
+            "UPDATE maintenance_types
+             SET deprecated_at = ?1, deprecated_by = ?2, updated_at = ?3, updated_by = ?2
+             WHERE id = ?4 AND organization_id = ?5",
+        )
+        .bind(timestamp(maintenance_type.deprecated_at().unwrap_or(now)))
+        .bind(user_id.to_string())
+        .bind(timestamp(now))
+        .bind(maintenance_type.id())
+        .bind(organization_id.to_string())
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| write_error(e, maintenance_type.name()))?;
+        if result.rows_affected() == 0 {
+            return Err(error(format!(
+                "maintenance type {} not found",
+                maintenance_type.id()
+            )));
+        }
+
+        let view = written_view(&mut transaction, organization_id, maintenance_type.id()).await?;
+        transaction.commit().await.map_err(error)?;
+        Ok(view)
+    }
+
+    async fn merge(
+        &self,
+        organization_id: uuid::Uuid,
+        source: MaintenanceType,
+        target: MaintenanceType,
+        user_id: uuid::Uuid,
+    ) -> Result<MaintenanceTypeMerge, MaintenanceTypeRepositoryError> {
+        let mut transaction = self.pool.begin().await.map_err(error)?;
+        check_exists(
+            &mut transaction,
+            "users",
+            "uuid = ?1",
+            user_id.to_string(),
+            "FOREIGN KEY constraint failed".to_string(),
+        )
+        .await?;
+        for id in [target.id(), source.id()] {
+            check_exists(
+                &mut transaction,
+                "maintenance_types",
+                &format!("organization_id = ?1 AND id = {}", id),
+                organization_id.to_string(),
+                format!("maintenance type {} not found", id),
+            )
+            .await?;
+        }
+        let now = timestamp(chrono::Utc::now());
+        let mut merge = MaintenanceTypeMerge::default();
+
+        // Rules: re-point them, or fold them into the target rule of the same vehicle and
+        // interval type
+        let rules: Vec<(i32, Option<i32>)> = sqlx::query_as(
+            "SELECT s.id, t.id
+             FROM maintenances s
+             LEFT JOIN maintenances t
+                 ON t.maintenance_type_id = ?2
+                 AND t.organization_id = s.organization_id
+                 AND t.vehicle_id = s.vehicle_id
+                 AND t.interval_type = s.interval_type
+             WHERE s.organization_id = ?3 AND s.maintenance_type_id = ?1
+             ORDER BY s.id",
+        )
+        .bind(source.id())
+        .bind(target.id())
+        .bind(organization_id.to_string())
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(error)?;
+        for (rule, target_rule) in rules {
+            match target_rule {
+                Some(target_rule) => {
+                    let moved = sqlx::query(
+                        "UPDATE maintenance_records SET maintenance_id = ?1 WHERE maintenance_id = ?2",
+                    )
+                    .bind(target_rule)
+                    .bind(rule)
+                    .execute(&mut *transaction)
+                    .await
+                    .map_err(error)?;
+                    sqlx::query("DELETE FROM maintenances WHERE id = ?1")
+                        .bind(rule)
+                        .execute(&mut *transaction)
+                        .await
+                        .map_err(error)?;
+                    merge.records_moved += moved.rows_affected();
+                    merge.rules_merged += 1;
+                }
+                None => {
+                    sqlx::query(
+                        "UPDATE maintenances
+                         SET maintenance_type_id = ?1, updated_at = ?2, updated_by = ?3
+                         WHERE id = ?4",
+                    )
+                    .bind(target.id())
+                    .bind(&now)
+                    .bind(user_id.to_string())
+                    .bind(rule)
+                    .execute(&mut *transaction)
+                    .await
+                    .map_err(error)?;
+                    merge.rules_moved += 1;
+                }
+            }
+        }
+
+        // Default intervals: re-point them, dropping the duplicates of a target interval
+        sqlx::query(
+            "DELETE FROM maintenance_interval_templates
+             WHERE organization_id = ?3
+                 AND maintenance_type_id = ?1
+                 AND EXISTS (
+                     SELECT 1 FROM maintenance_interval_templates t
+                     WHERE t.maintenance_type_id = ?2
+                         AND t.interval_type = maintenance_interval_templates.interval_type
+                         AND COALESCE(LOWER(t.make), '')
+                             = COALESCE(LOWER(maintenance_interval_templates.make), '')
+                         AND COALESCE(LOWER(t.model), '')
+                             = COALESCE(LOWER(maintenance_interval_templates.model), '')
+                         AND COALESCE(t.engine_type, '')
+                             = COALESCE(maintenance_interval_templates.engine_type, '')
+                         AND COALESCE(t.engine_type_other, '')
+                             = COALESCE(maintenance_interval_templates.engine_type_other, '')
+                 )",
+        )
+        .bind(source.id())
+        .bind(target.id())
+        .bind(organization_id.to_string())
+        .execute(&mut *transaction)
+        .await
+        .map_err(error)?;
+        let moved = sqlx::query(
+            "UPDATE maintenance_interval_templates
+             SET maintenance_type_id = ?1, updated_at = ?2, updated_by = ?3
+             WHERE organization_id = ?5 AND maintenance_type_id = ?4",
+        )
+        .bind(target.id())
+        .bind(&now)
+        .bind(user_id.to_string())
+        .bind(source.id())
+        .bind(organization_id.to_string())
+        .execute(&mut *transaction)
+        .await
+        .map_err(error)?;
+        merge.templates_moved = moved.rows_affected();
+
+        // Diagnostic rules would be deleted with the source (ON DELETE CASCADE)
+        sqlx::query(
+            "UPDATE diagnostic_rules SET maintenance_type_id = ?1
+             WHERE organization_id = ?3 AND maintenance_type_id = ?2",
+        )
+        .bind(target.id())
+        .bind(source.id())
+        .bind(organization_id.to_string())
+        .execute(&mut *transaction)
+        .await
+        .map_err(error)?;
+
+        sqlx::query("DELETE FROM maintenance_types WHERE id = ?1 AND organization_id = ?2")
+            .bind(source.id())
+            .bind(organization_id.to_string())
+            .execute(&mut *transaction)
+            .await
+            .map_err(error)?;
+        transaction.commit().await.map_err(error)?;
+        Ok(merge)
+    }
+
+    async fn delete(
+        &self,
+        organization_id: uuid::Uuid,
+        maintenance_type: MaintenanceType,
+        _user_id: uuid::Uuid,
+    ) -> Result<(), MaintenanceTypeRepositoryError> {
+        // The rules referencing the type make the delete fail (ON DELETE NO ACTION), its default
+        // intervals are deleted with it (ON DELETE CASCADE)
+        let result =
+            sqlx::query("DELETE FROM maintenance_types WHERE id = ?1 AND organization_id = ?2")
+                .bind(maintenance_type.id())
+                .bind(organization_id.to_string())
+                .execute(&self.pool)
+                .await
+                .map_err(error)?;
+        match result.rows_affected() {
+            0 => Err(error(format!(
+                "maintenance type {} not found",
+                maintenance_type.id()
+            ))),
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,158 @@
+pub mod depot_repository;
+pub mod diagnostic_repository;
+pub mod inspection_repository;
+pub mod maintenance_record_repository;
+pub mod maintenance_repository;
+pub mod maintenance_type_repository;
+pub mod organization_repository;
+pub mod telematics_device_repository;
+pub mod trip_repository;
+pub mod vehicle_movement_repository;
+pub mod vehicle_repository;
+pub mod vehicle_status_repository;
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        depot_repository::SqliteDepotRepository, diagnostic_repository::SqliteDiagnosticRepository,
+        inspection_repository::SqliteInspectionRepository,
+        maintenance_record_repository::SqliteMaintenanceRecordRepository,
+        maintenance_repository::SqliteMaintenanceRepository,
+        maintenance_type_repository::SqliteMaintenanceTypeRepository,
+        organization_repository::SqliteOrganizationRepository,
+        telematics_device_repository::SqliteTelematicsDeviceRepository,
+        trip_repository::SqliteTripRepository,
+        vehicle_movement_repository::SqliteVehicleMovementRepository,
+        vehicle_repository::SqliteVehicleRepository,
+        vehicle_status_repository::SqliteVehicleStatusRepository,
+    };
+    use crate::database::SqliteDatabase;
+    use conformance::ConformanceBackend;
+    use domain::user::entities::user::UserIdentity;
+
+    struct Backend {
+        database: SqliteDatabase,
+        organizations: SqliteOrganizationRepository,
+        vehicles: SqliteVehicleRepository,
+        vehicle_statuses: SqliteVehicleStatusRepository,
+        maintenance_types: SqliteMaintenanceTypeRepository,
+        maintenances: SqliteMaintenanceRepository,
+        maintenance_records: SqliteMaintenanceRecordRepository,
+        depots: SqliteDepotRepository,
+        vehicle_movements: SqliteVehicleMovementRepository,
+        telematics_devices: SqliteTelematicsDeviceRepository,
+        trips: SqliteTripRepository,
+        diagnostics: SqliteDiagnosticRepository,
+        inspections: SqliteInspectionRepository,
+    }
+
+    impl Backend {
+        async fn new() -> Self {
+            let database = SqliteDatabase::in_memory()
+                .await
+                .expect("in-memory database");
+            Self::of(database)
+        }
+
+        fn of(database: SqliteDatabase) -> Self {
+            Self {
+                organizations: SqliteOrganizationRepository::new(&database),
+                vehicles: SqliteVehicleRepository::new(&database),
+                vehicle_statuses: SqliteVehicleStatusRepository::new(&database),
+                maintenance_types: SqliteMaintenanceTypeRepository::new(&database),
+                maintenances: SqliteMaintenanceRepository::new(&database),
+                maintenance_records: SqliteMaintenanceRecordRepository::new(&database),
+                depots: SqliteDepotRepository::new(&database),
+                vehicle_movements: SqliteVehicleMovementRepository::new(&database),
+                telematics_devices: SqliteTelematicsDeviceRepository::new(&database),
+                trips: SqliteTripRepository::new(&database),
+                diagnostics: SqliteDiagnosticRepository::new(&database),
+                inspections: SqliteInspectionRepository::new(&database),
+                database,
+            }
+        }
+    }
+
+    impl ConformanceBackend for Backend {
+        type Organizations = SqliteOrganizationRepository;
+        type Vehicles = SqliteVehicleRepository;
+        type VehicleStatuses = SqliteVehicleStatusRepository;
+        type MaintenanceTypes = SqliteMaintenanceTypeRepository;
+        type Maintenances = SqliteMaintenanceRepository;
+        type MaintenanceRecords = SqliteMaintenanceRecordRepository;
+        type Depots = SqliteDepotRepository;
+        type VehicleMovements = SqliteVehicleMovementRepository;
+        type TelematicsDevices = SqliteTelematicsDeviceRepository;
+        type Trips = SqliteTripRepository;
+        type Diagnostics = SqliteDiagnosticRepository;
+        type Inspections = SqliteInspectionRepository;
+
+        fn organizations(&self) -> &Self::Organizations {
+            &self.organizations
+        }
+        fn vehicles(&self) -> &Self::Vehicles {
+            &self.vehicles
+        }
+        fn vehicle_statuses(&self) -> &Self::VehicleStatuses {
+            &self.vehicle_statuses
+        }
+        fn maintenance_types(&self) -> &Self::MaintenanceTypes {
+            &self.maintenance_types
+        }
+        fn maintenances(&self) -> &Self::Maintenances {
+            &self.maintenances
+        }
+        fn maintenance_records(&self) -> &Self::MaintenanceRecords {
+            &self.maintenance_records
+        }
+        fn depots(&self) -> &Self::Depots {
+            &self.depots
+        }
+        fn vehicle_movements(&self) -> &Self::VehicleMovements {
+            &self.vehicle_movements
+        }
+        fn telematics_devices(&self) -> &Self::TelematicsDevices {
+            &self.telematics_devices
+        }
+        fn trips(&self) -> &Self::Trips {
+            &self.trips
+        }
+
+        fn diagnostics(&self) -> &Self::Diagnostics {
+            &self.diagnostics
+        }
+
+        fn inspections(&self) -> &Self::Inspections {
+            &self.inspections
+        }
+
+        async fn insert_user(&self, user: UserIdentity) -> Result<(), String> {
+            self.database
+                .insert_user(&user)
+                .await
+                .map_err(|e| e.to_string())
+        }
+    }
+
+    #[tokio::test]
+    async fn conformance() {
+        conformance::run_all(Backend::new).await;
+    }
+
+    /// A file database has a pool of connections: the concurrency checks really run concurrently.
+    #[tokio::test]
+    async fn conformance_on_a_file_database() {
+        let directory = std::env::temp_dir().join(format!("conformance-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir(&directory).expect("temporary directory");
+        let next = std::sync::atomic::AtomicU32::new(0);
+
+        conformance::run_all(|| async {
+            let index = next.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            let url = format!("sqlite://{}/{}.db", directory.display(), index);
+            Backend::of(SqliteDatabase::connect(&url).await.expect("file database"))
+        })
+        .await;
+
+        std::fs::remove_dir_all(&directory).expect("temporary directory removed");
+    }
+}
@@ -0,0 +1,68 @@
+use crate::{
+    database::{SqliteDatabase, database_error, is_unique_violation},
+    mappers::{
+        organization_mapper::{ORGANIZATION_COLUMNS, organization_from_row},
+        timestamp,
+    },
+};
+use domain::organization::{
+    entities::organization::Organization,
+    repositories::organization_repository::{OrganizationRepository, OrganizationRepositoryError},
+};
+use sqlx::SqlitePool;
+
+#[derive(Debug, Clone)]
+pub struct SqliteOrganizationRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteOrganizationRepository {
+    pub fn new(database: &SqliteDatabase) -> Self {
+        SqliteOrganizationRepository {
+            pool: database.pool().clone(),
+        }
+    }
+}
+
+impl OrganizationRepository for SqliteOrganizationRepository {
+    async fn create(
+        &self,
+        organization: Organization,
+    ) -> Result<Organization, OrganizationRepositoryError> {
+        let result = sqlx::query(
+            "INSERT INTO organizations (id, name, created_at, updated_at)
+             VALUES (?1, ?2, ?3, ?4)",
+        )
+        .bind(organization.id.to_string())
+        .bind(&organization.name)
+        .bind(timestamp(organization.created_at))
+        .bind(timestamp(organization.updated_at))
+        .execute(&self.pool)
+        .await;
+
+        match result {
+            Ok(_) => Ok(organization),
+            // The id is a new UUID: the violated key is `organizations_name_key`
+            Err(e) if is_unique_violation(&e) => Err(OrganizationRepositoryError::AlreadyExists(
+                organization.name,
+            )),
+            Err(e) => Err(OrganizationRepositoryError::Database(database_error(e))),
+        }
+    }
+
+    async fn find_by_id(
+        &self,
+        id: uuid::Uuid,
+    ) -> Result<Option<Organization>, OrganizationRepositoryError> {
+        sqlx::query(&format!(
+            "SELECT {} FROM organizations WHERE id = ?1",
+            ORGANIZATION_COLUMNS
+        ))
+        .bind(id.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(database_error)
+        .and_then(|row| row.map(|row| organization_from_row(&row)).transpose())
+        .map_err(OrganizationRepositoryError::Database)
+    }
+}
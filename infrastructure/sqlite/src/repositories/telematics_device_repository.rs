@@ -0,0 +1,94 @@
+use crate::{
+    database::{SqliteDatabase, database_error, is_unique_violation},
+    mappers::{
+        telematics_device_mapper::{TELEMATICS_DEVICE_COLUMNS, telematics_device_from_row},
+        timestamp,
+    },
+};
+use domain::telematics::{
+    entities::telematics_device::TelematicsDevice,
+    repositories::telematics_device_repository::{
+        TelematicsDeviceRepository, TelematicsDeviceRepositoryError,
+    },
+    value_types::device_id::DeviceId,
+};
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct SqliteTelematicsDeviceRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteTelematicsDeviceRepository {
+    pub fn new(database: &SqliteDatabase) -> Self {
+        SqliteTelematicsDeviceRepository {
+            pool: database.pool().clone(),
+        }
+    }
+}
+
+impl TelematicsDeviceRepository for SqliteTelematicsDeviceRepository {
+    /// The only unique key is the primary key: a unique violation is a registered device id.
+    async fn register(
+        &self,
+        organization_id: Uuid,
+        device: TelematicsDevice,
+    ) -> Result<TelematicsDevice, TelematicsDeviceRepositoryError> {
+        sqlx::query(
+            "INSERT INTO telematics_devices (device_id, vehicle_id, registered_at, organization_id)
+             VALUES (?1, ?2, ?3, ?4)",
+        )
+        .bind(device.device_id.value())
+        .bind(device.vehicle_id.to_string())
+        .bind(timestamp(device.registered_at))
+        .bind(organization_id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| match is_unique_violation(&e) {
+            true => {
+                TelematicsDeviceRepositoryError::AlreadyExists(device.device_id.value().to_string())
+            }
+            false => TelematicsDeviceRepositoryError::Database(database_error(e)),
+        })?;
+        Ok(device)
+    }
+
+    async fn find_by_device_id(
+        &self,
+        organization_id: Uuid,
+        device_id: &DeviceId,
+    ) -> Result<Option<TelematicsDevice>, TelematicsDeviceRepositoryError> {
+        sqlx::query(&format!(
+            "SELECT {} FROM telematics_devices WHERE organization_id = ?1 AND device_id = ?2",
+            TELEMATICS_DEVICE_COLUMNS
+        ))
+        .bind(organization_id.to_string())
+        .bind(device_id.value())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(database_error)
+        .and_then(|row| row.map(|row| telematics_device_from_row(&row)).transpose())
+        .map_err(TelematicsDeviceRepositoryError::Database)
+    }
+
+    async fn find_by_vehicle(
+        &self,
+        organization_id: Uuid,
+        vehicle_id: Uuid,
+    ) -> Result<Vec<TelematicsDevice>, TelematicsDeviceRepositoryError> {
+        sqlx::query(&format!(
+            "SELECT {} FROM telematics_devices
+             WHERE organization_id = ?1 AND vehicle_id = ?2
+             ORDER BY device_id",
+            TELEMATICS_DEVICE_COLUMNS
+        ))
+        .bind(organization_id.to_string())
+        .bind(vehicle_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(database_error)
+        .and_then(|rows| rows.iter().map(telematics_device_from_row).collect())
+        .map_err(TelematicsDeviceRepositoryError::Database)
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{
+    database::{SqliteDatabase, database_error},
+    mappers::{
+        timestamp,
+        trip_mapper::{TRIP_COLUMNS, trip_from_row},
+    },
+};
+use domain::trip::{
+    entities::trip::Trip,
+    repositories::trip_repository::{TripRepository, TripRepositoryError},
+    value_types::polyline,
+};
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct SqliteTripRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteTripRepository {
+    pub fn new(database: &SqliteDatabase) -> Self {
+        SqliteTripRepository {
+            pool: database.pool().clone(),
+        }
+    }
+}
+
+impl TripRepository for SqliteTripRepository {
+    async fn create(&self, organization_id: Uuid, trip: Trip) -> Result<Trip, TripRepositoryError> {
+        sqlx::query(
+            "INSERT INTO trips (id, vehicle_id, driver_id, started_at, ended_at, polyline,
+                 distance_km, created_at, organization_id)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+        )
+        .bind(trip.id.to_string())
+        .bind(trip.vehicle_id.to_string())
+        .bind(trip.driver_id.map(|id| id.to_string()))
+        .bind(timestamp(trip.started_at))
+        .bind(timestamp(trip.ended_at))
+        .bind(polyline::encode(&trip.polyline))
+        .bind(trip.distance_km.to_string())
+        .bind(timestamp(trip.created_at))
+        .bind(organization_id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| TripRepositoryError::Database(database_error(e)))?;
+        Ok(trip)
+    }
+
+    async fn find_by_id(
+        &self,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> Result<Option<Trip>, TripRepositoryError> {
+        sqlx::query(&format!(
+            "SELECT {} FROM trips WHERE organization_id = ?1 AND id = ?2",
+            TRIP_COLUMNS
+        ))
+        .bind(organization_id.to_string())
+        .bind(id.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(database_error)
+        .and_then(|row| row.map(|row| trip_from_row(&row)).transpose())
+        .map_err(TripRepositoryError::Database)
+    }
+
+    async fn find_by_vehicle(
+        &self,
+        organization_id: Uuid,
+        vehicle_id: Uuid,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<Trip>, TripRepositoryError> {
+        sqlx::query(&format!(
+            "SELECT {} FROM trips
+             WHERE organization_id = ?1
+                 AND vehicle_id = ?2
+                 AND (?3 IS NULL OR ended_at >= ?3)
+                 AND (?4 IS NULL OR started_at <= ?4)
+             ORDER BY started_at, id",
+            TRIP_COLUMNS
+        ))
+        .bind(organization_id.to_string())
+        .bind(vehicle_id.to_string())
+        .bind(from.map(timestamp))
+        .bind(to.map(timestamp))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(database_error)
+        .and_then(|rows| rows.iter().map(trip_from_row).collect())
+        .map_err(TripRepositoryError::Database)
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{
+    database::{SqliteDatabase, database_error},
+    mappers::{
+        depot_mapper::{VEHICLE_MOVEMENT_COLUMNS, vehicle_movement_from_row},
+        timestamp,
+    },
+};
+use domain::depot::{
+    entities::vehicle_movement::VehicleMovement,
+    repositories::vehicle_movement_repository::{
+        VehicleMovementRepository, VehicleMovementRepositoryError,
+    },
+};
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct SqliteVehicleMovementRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteVehicleMovementRepository {
+    pub fn new(database: &SqliteDatabase) -> Self {
+        SqliteVehicleMovementRepository {
+            pool: database.pool().clone(),
+        }
+    }
+}
+
+impl VehicleMovementRepository for SqliteVehicleMovementRepository {
+    async fn record(
+        &self,
+        organization_id: Uuid,
+        movement: VehicleMovement,
+    ) -> Result<VehicleMovement, VehicleMovementRepositoryError> {
+        let error = |e: sqlx::Error| VehicleMovementRepositoryError::Database(database_error(e));
+        let mut transaction = self.pool.begin().await.map_err(error)?;
+
+        sqlx::query(
+            "UPDATE vehicle_movements SET latest = 0
+             WHERE organization_id = ?1 AND vehicle_id = ?2 AND latest = 1",
+        )
+        .bind(organization_id.to_string())
+        .bind(movement.vehicle_id.to_string())
+        .execute(&mut *transaction)
+        .await
+        .map_err(error)?;
+        sqlx::query(
+            "INSERT INTO vehicle_movements (
+                 id, vehicle_id, from_depot_id, to_depot_id, moved_at, moved_by, notes, latest,
+                 organization_id
+             )
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, 1, ?8)",
+        )
+        .bind(movement.id.to_string())
+        .bind(movement.vehicle_id.to_string())
+        .bind(movement.from_depot_id.map(|id| id.to_string()))
+        .bind(movement.to_depot_id.to_string())
+        .bind(timestamp(movement.moved_at))
+        .bind(movement.moved_by.to_string())
+        .bind(&movement.notes)
+        .bind(organization_id.to_string())
+        .execute(&mut *transaction)
+        .await
+        .map_err(error)?;
+
+        transaction.commit().await.map_err(error)?;
+        Ok(movement)
+    }
+
+    async fn find_latest(
+        &self,
+        organization_id: Uuid,
+        vehicle_id: Uuid,
+    ) -> Result<Option<VehicleMovement>, VehicleMovementRepositoryError> {
+        sqlx::query(&format!(
+            "SELECT {} FROM vehicle_movements
+             WHERE organization_id = ?1 AND vehicle_id = ?2 AND latest = 1",
+            VEHICLE_MOVEMENT_COLUMNS
+        ))
+        .bind(organization_id.to_string())
+        .bind(vehicle_id.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(database_error)
+        .and_then(|row| row.map(|row| vehicle_movement_from_row(&row)).transpose())
+        .map_err(VehicleMovementRepositoryError::Database)
+    }
+
+    /// Movements at the same time are read in the order they were recorded in (`rowid`).
+    async fn find_by_vehicle(
+        &self,
+        organization_id: Uuid,
+        vehicle_id: Uuid,
+    ) -> Result<Vec<VehicleMovement>, VehicleMovementRepositoryError> {
+        sqlx::query(&format!(
+            "SELECT {} FROM vehicle_movements
+             WHERE organization_id = ?1 AND vehicle_id = ?2
+             ORDER BY moved_at, rowid",
+            VEHICLE_MOVEMENT_COLUMNS
+        ))
+        .bind(organization_id.to_string())
+        .bind(vehicle_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(database_error)
+        .and_then(|rows| rows.iter().map(vehicle_movement_from_row).collect())
+        .map_err(VehicleMovementRepositoryError::Database)
+    }
+}
@@ -0,0 +1,252 @@
+use crate::{
+    database::{SqliteDatabase, database_error, is_unique_violation},
+    mappers::{
+        parse_uuid, timestamp,
+        vehicle_mapper::{VEHICLE_COLUMNS, vehicle_from_row},
+    },
+};
+use domain::vehicle::{
+    entities::vehicle::{NewVehicle, Vehicle, VehicleIdentity},
+    repositories::vehicle_repository::{
+        TakenIdentifiers, VehicleRepository, VehicleRepositoryError,
+    },
+};
+use sqlx::{QueryBuilder, Sqlite, SqliteConnection, SqlitePool};
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct SqliteVehicleRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteVehicleRepository {
+    pub fn new(database: &SqliteDatabase) -> Self {
+        SqliteVehicleRepository {
+            pool: database.pool().clone(),
+        }
+    }
+}
+
+/// Identifiers looked up per statement, well below the bound parameters limit of SQLite
+const TAKEN_IDENTIFIERS_CHUNK: usize = 300;
+
+/// Reads a vehicle, for the repositories hydrating the entities referencing it.
+pub(crate) async fn find_vehicle(
+    connection: &mut SqliteConnection,
+    id: Uuid,
+) -> Result<Option<VehicleIdentity>, String> {
+    sqlx::query(&format!(
+        "SELECT {} FROM vehicles WHERE uuid = ?1",
+        VEHICLE_COLUMNS
+    ))
+    .bind(id.to_string())
+    .fetch_optional(connection)
+    .await
+    .map_err(database_error)?
+    .map(|row| vehicle_from_row(&row))
+    .transpose()
+}
+
+impl VehicleRepository for SqliteVehicleRepository {
+    async fn create(
+        &self,
+        organization_id: Uuid,
+        vehicle: NewVehicle,
+    ) -> Result<VehicleIdentity, VehicleRepositoryError> {
+        let vehicle = Vehicle::new(vehicle)
+            .map_err(|e| {
+                VehicleRepositoryError::Database(format!("CHECK constraint failed: {}", e))
+            })?
+            .identity;
+        let engine_type = vehicle.powertrain.engine_type();
+        let engine_type_other = match engine_type {
+            domain::vehicle::value_types::engine_type::EngineType::Other(name) => {
+                Some(name.clone())
+            }
+            _ => None,
+        };
+
+        let result = sqlx::query(
+            "INSERT INTO vehicles (
+                 uuid, make, model, year, vin, vin_legacy, license_plate, country, engine_type,
+                 engine_type_other, battery_capacity, tank_capacity, created_at, updated_at,
+                 organization_id
+             )
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)",
+        )
+        .bind(vehicle.id.to_string())
+        .bind(&vehicle.make)
+        .bind(&vehicle.model)
+        .bind(vehicle.year)
+        .bind(vehicle.vin.value())
+        .bind(vehicle.vin.is_legacy())
+        .bind(vehicle.license_plate.value())
+        .bind(vehicle.country.value())
+        .bind(engine_type.as_sql())
+        .bind(engine_type_other)
+        .bind(
+            vehicle
+                .powertrain
+                .battery_capacity()
+                .map(|capacity| capacity.to_string()),
+        )
+        .bind(
+            vehicle
+                .powertrain
+                .tank_capacity()
+                .map(|capacity| capacity.to_string()),
+        )
+        .bind(timestamp(vehicle.created_at))
+        .bind(timestamp(vehicle.updated_at))
+        .bind(organization_id.to_string())
+        .execute(&self.pool)
+        .await;
+
+        match result {
+            Ok(_) => Ok(vehicle),
+            // Report a duplicate with the id of the existing vehicle
+            Err(e) if is_unique_violation(&e) => {
+                let existing: Option<(String,)> = sqlx::query_as(
+                    "SELECT uuid FROM vehicles
+                     WHERE organization_id = ?1
+                         AND (vin = ?2 OR (country = ?3 AND license_plate = ?4))",
+                )
+                .bind(organization_id.to_string())
+                .bind(vehicle.vin.value())
+                .bind(vehicle.country.value())
+                .bind(vehicle.license_plate.value())
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(|e| VehicleRepositoryError::Database(database_error(e)))?;
+                match existing {
+                    Some((id,)) => Err(VehicleRepositoryError::AlreadyExists(
+                        parse_uuid(&id).map_err(VehicleRepositoryError::Database)?,
+                    )),
+                    None => Err(VehicleRepositoryError::Database(database_error(e))),
+                }
+            }
+            Err(e) => Err(VehicleRepositoryError::Database(database_error(e))),
+        }
+    }
+
+    async fn find_by_id(
+        &self,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> Result<Option<VehicleIdentity>, VehicleRepositoryError> {
+        sqlx::query(&format!(
+            "SELECT {} FROM vehicles WHERE organization_id = ?1 AND uuid = ?2",
+            VEHICLE_COLUMNS
+        ))
+        .bind(organization_id.to_string())
+        .bind(id.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(database_error)
+        .and_then(|row| row.map(|row| vehicle_from_row(&row)).transpose())
+        .map_err(VehicleRepositoryError::Database)
+    }
+
+    async fn find_all(
+        &self,
+        organization_id: Uuid,
+    ) -> Result<Vec<VehicleIdentity>, VehicleRepositoryError> {
+        sqlx::query(&format!(
+            "SELECT {} FROM vehicles WHERE organization_id = ?1 ORDER BY created_at, uuid",
+            VEHICLE_COLUMNS
+        ))
+        .bind(organization_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(database_error)
+        .and_then(|rows| rows.iter().map(vehicle_from_row).collect())
+        .map_err(VehicleRepositoryError::Database)
+    }
+
+    async fn exists_by_vin_or_license_plate(
+        &self,
+        organization_id: Uuid,
+        vin: &str,
+        country: &str,
+        license_plate: &str,
+    ) -> Result<bool, VehicleRepositoryError> {
+        let (exists,): (bool,) = sqlx::query_as(
+            "SELECT EXISTS(
+                 SELECT 1 FROM vehicles
+                 WHERE organization_id = ?1
+                     AND (vin = ?2 OR (country = ?3 AND license_plate = ?4))
+             )",
+        )
+        .bind(organization_id.to_string())
+        .bind(vin)
+        .bind(country)
+        .bind(license_plate)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| VehicleRepositoryError::Database(database_error(e)))?;
+        Ok(exists)
+    }
+
+    async fn find_taken_identifiers(
+        &self,
+        organization_id: Uuid,
+        vins: &[String],
+        license_plates: &[(String, String)],
+    ) -> Result<TakenIdentifiers, VehicleRepositoryError> {
+        let mut taken = TakenIdentifiers::default();
+        for vins in vins.chunks(TAKEN_IDENTIFIERS_CHUNK) {
+            let mut query =
+                QueryBuilder::<Sqlite>::new("SELECT vin FROM vehicles WHERE organization_id = ");
+            query.push_bind(organization_id.to_string());
+            query.push(" AND vin IN (");
+            let mut values = query.separated(", ");
+            for vin in vins {
+                values.push_bind(vin);
+            }
+            query.push(")");
+            let rows: Vec<(String,)> = query
+                .build_query_as()
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|e| VehicleRepositoryError::Database(database_error(e)))?;
+            taken.vins.extend(rows.into_iter().map(|(vin,)| vin));
+        }
+        for plates in license_plates.chunks(TAKEN_IDENTIFIERS_CHUNK) {
+            let mut query = QueryBuilder::<Sqlite>::new(
+                "SELECT country, license_plate FROM vehicles WHERE organization_id = ",
+            );
+            query.push_bind(organization_id.to_string());
+            query.push(" AND (country, license_plate) IN (VALUES ");
+            let mut values = query.separated(", ");
+            for (country, license_plate) in plates {
+                values.push("(");
+                values.push_bind_unseparated(country);
+                values.push_unseparated(", ");
+                values.push_bind_unseparated(license_plate);
+                values.push_unseparated(")");
+            }
+            query.push(")");
+            let rows: Vec<(String, String)> = query
+                .build_query_as()
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|e| VehicleRepositoryError::Database(database_error(e)))?;
+            taken.license_plates.extend(rows);
+        }
+        Ok(taken)
+    }
+
+    async fn delete(
+        &self,
+        organization_id: Uuid,
+        id: Uuid,
+    ) -> Result<bool, VehicleRepositoryError> {
+        let result = sqlx::query("DELETE FROM vehicles WHERE organization_id = ?1 AND uuid = ?2")
+            .bind(organization_id.to_string())
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| VehicleRepositoryError::Database(database_error(e)))?;
+        Ok(result.rows_affected() > 0)
+    }
+}
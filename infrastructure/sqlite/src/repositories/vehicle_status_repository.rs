@@ -0,0 +1,173 @@
+use crate::{
+    database::{SqliteDatabase, database_error},
+    mappers::{
+        timestamp,
+        vehicle_status_mapper::{VEHICLE_STATUS_COLUMNS, vehicle_status_from_row},
+    },
+};
+use domain::vehicle::{
+    entities::vehicle_status::VehicleStatusIdentity,
+    repositories::vehicle_status_repository::{
+        VehicleStatusRepository, VehicleStatusRepositoryError,
+    },
+};
+use sqlx::{SqliteConnection, SqlitePool};
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct SqliteVehicleStatusRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteVehicleStatusRepository {
+    pub fn new(database: &SqliteDatabase) -> Self {
+        SqliteVehicleStatusRepository {
+            pool: database.pool().clone(),
+        }
+    }
+}
+
+/// Reads a status, for the repositories hydrating the entities referencing it.
+pub(crate) async fn find_vehicle_status(
+    connection: &mut SqliteConnection,
+    id: i32,
+) -> Result<Option<VehicleStatusIdentity>, String> {
+    sqlx::query(&format!(
+        "SELECT {} FROM vehicle_statuses WHERE id = ?1",
+        VEHICLE_STATUS_COLUMNS
+    ))
+    .bind(id)
+    .fetch_optional(connection)
+    .await
+    .map_err(database_error)?
+    .map(|row| vehicle_status_from_row(&row))
+    .transpose()
+}
+
+impl VehicleStatusRepository for SqliteVehicleStatusRepository {
+    /// The table has no `performed_at` column, the status is performed when it is created.
+    async fn create(
+        &self,
+        organization_id: Uuid,
+        status: VehicleStatusIdentity,
+    ) -> Result<VehicleStatusIdentity, VehicleStatusRepositoryError> {
+        let error = |e: sqlx::Error| VehicleStatusRepositoryError::Database(database_error(e));
+        let mut transaction = self.pool.begin().await.map_err(error)?;
+
+        // A back-dated status is inserted into the history, the latest one stays. Writing first
+        // makes a concurrent transaction wait rather than fail to upgrade its read lock
+        sqlx::query(
+            "UPDATE vehicle_statuses SET latest = 0
+             WHERE organization_id = ?1 AND vehicle_id = ?2 AND latest = 1 AND created_at <= ?3",
+        )
+        .bind(organization_id.to_string())
+        .bind(status.vehicle_id.to_string())
+        .bind(timestamp(status.performed_at))
+        .execute(&mut *transaction)
+        .await
+        .map_err(error)?;
+        let (id,): (i32,) = sqlx::query_as(
+            "INSERT INTO vehicle_statuses (
+                 vehicle_id, performed_by, odometer, engine_hour_meter, fuel_level, notes, latest,
+                 created_at, updated_at, organization_id
+             )
+             VALUES (
+                 ?1, ?2, ?3, ?4, ?5, ?6,
+                 NOT EXISTS (
+                     SELECT 1 FROM vehicle_statuses
+                     WHERE organization_id = ?9 AND vehicle_id = ?1 AND created_at > ?7
+                 ),
+                 ?7, ?8, ?9
+             )
+             RETURNING id",
+        )
+        .bind(status.vehicle_id.to_string())
+        .bind(status.performed_by.to_string())
+        .bind(status.odometer)
+        .bind(status.engine_hour_meter)
+        .bind(status.fuel_level)
+        .bind(&status.notes)
+        .bind(timestamp(status.performed_at))
+        .bind(timestamp(status.updated_at))
+        .bind(organization_id.to_string())
+        .fetch_one(&mut *transaction)
+        .await
+        .map_err(error)?;
+
+        transaction.commit().await.map_err(error)?;
+        Ok(VehicleStatusIdentity {
+            id,
+            created_at: status.performed_at,
+            ..status
+        })
+    }
+
+    async fn find_latest(
+        &self,
+        organization_id: Uuid,
+        vehicle_id: Uuid,
+    ) -> Result<Option<VehicleStatusIdentity>, VehicleStatusRepositoryError> {
+        sqlx::query(&format!(
+            "SELECT {} FROM vehicle_statuses
+             WHERE organization_id = ?1 AND vehicle_id = ?2 AND latest = 1",
+            VEHICLE_STATUS_COLUMNS
+        ))
+        .bind(organization_id.to_string())
+        .bind(vehicle_id.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(database_error)
+        .and_then(|row| row.map(|row| vehicle_status_from_row(&row)).transpose())
+        .map_err(VehicleStatusRepositoryError::Database)
+    }
+
+    async fn find_latest_before(
+        &self,
+        organization_id: Uuid,
+        vehicle_id: Uuid,
+        before: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Option<VehicleStatusIdentity>, VehicleStatusRepositoryError> {
+        sqlx::query(&format!(
+            "SELECT {} FROM vehicle_statuses
+             WHERE organization_id = ?1 AND vehicle_id = ?2 AND created_at < ?3
+             ORDER BY created_at DESC, id DESC
+             LIMIT 1",
+            VEHICLE_STATUS_COLUMNS
+        ))
+        .bind(organization_id.to_string())
+        .bind(vehicle_id.to_string())
+        .bind(timestamp(before))
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(database_error)
+        .and_then(|row| row.map(|row| vehicle_status_from_row(&row)).transpose())
+        .map_err(VehicleStatusRepositoryError::Database)
+    }
+
+    async fn find_by_vehicle(
+        &self,
+        organization_id: Uuid,
+        vehicle_id: Uuid,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<VehicleStatusIdentity>, VehicleStatusRepositoryError> {
+        sqlx::query(&format!(
+            "SELECT {} FROM vehicle_statuses
+             WHERE organization_id = ?1
+                 AND vehicle_id = ?2
+                 AND (?3 IS NULL OR created_at >= ?3)
+                 AND (?4 IS NULL OR created_at <= ?4)
+             ORDER BY created_at, id",
+            VEHICLE_STATUS_COLUMNS
+        ))
+        .bind(organization_id.to_string())
+        .bind(vehicle_id.to_string())
+        .bind(from.map(timestamp))
+        .bind(to.map(timestamp))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(database_error)
+        .and_then(|rows| rows.iter().map(vehicle_status_from_row).collect())
+        .map_err(VehicleStatusRepositoryError::Database)
+    }
+}
@@ -0,0 +1,25 @@
+-- Enums
+CREATE TYPE maintenance_cost_line_kind AS ENUM ('Part', 'Labor', 'Tax', 'Fee');
+
+-- Cost header of a maintenance record (NULL currency means the cost is unknown)
+ALTER TABLE maintenance_records
+    ADD COLUMN currency CHAR(3) CHECK (currency ~ '^[A-Z]{3}$'),
+    ADD COLUMN invoice_reference TEXT;
+
+-- Maintenance Cost Lines (parts, labor, taxes and fees of a maintenance record)
+CREATE TABLE maintenance_cost_lines (
+    id SERIAL PRIMARY KEY,
+    maintenance_record_id INTEGER NOT NULL REFERENCES maintenance_records(id) ON DELETE CASCADE,
+    position SMALLINT NOT NULL,
+    kind maintenance_cost_line_kind NOT NULL,
+    description TEXT NOT NULL,
+    part_number TEXT,
+    quantity NUMERIC(12, 3) NOT NULL CHECK (quantity >= 0), -- hours for labor
+    unit_price NUMERIC(14, 4) NOT NULL CHECK (unit_price >= 0), -- hourly rate for labor
+
+    -- Keep the order of the lines as entered
+    UNIQUE(maintenance_record_id, position)
+);
+
+CREATE INDEX maintenance_records_vehicle_performed_at
+ON maintenance_records(vehicle_id, performed_at);
@@ -0,0 +1,38 @@
+-- Enums
+CREATE TYPE energy_unit AS ENUM ('Liters', 'KilowattHours');
+
+-- Fuel Tanks (usable capacity of the tank or battery of a vehicle, one per energy unit)
+CREATE TABLE vehicle_fuel_tanks (
+    vehicle_id UUID NOT NULL REFERENCES vehicles(uuid) ON DELETE CASCADE,
+    unit energy_unit NOT NULL,
+    capacity NUMERIC(10, 3) NOT NULL CHECK (capacity > 0),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (vehicle_id, unit)
+);
+
+-- Fuel Events (refuelings and charging sessions)
+CREATE TABLE fuel_events (
+    id SERIAL PRIMARY KEY,
+    vehicle_id UUID NOT NULL REFERENCES vehicles(uuid) ON DELETE CASCADE,
+    driver_id UUID NOT NULL REFERENCES users(uuid) ON DELETE CASCADE,
+    -- Event details
+    performed_at TIMESTAMPTZ NOT NULL,
+    odometer INTEGER NOT NULL CHECK (odometer >= 0),
+    quantity NUMERIC(10, 3) NOT NULL CHECK (quantity > 0),
+    unit energy_unit NOT NULL,
+    total_price NUMERIC(14, 4) CHECK (total_price >= 0),
+    currency CHAR(3) CHECK (currency ~ '^[A-Z]{3}$'),
+    station TEXT,
+    full_tank BOOLEAN NOT NULL DEFAULT false,
+    -- Metadata
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    created_by UUID NOT NULL REFERENCES users(uuid) ON DELETE CASCADE,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_by UUID NOT NULL REFERENCES users(uuid) ON DELETE CASCADE,
+
+    -- A price is meaningless without its currency
+    CHECK ((total_price IS NULL) = (currency IS NULL))
+);
+
+CREATE INDEX fuel_events_vehicle_performed_at ON fuel_events(vehicle_id, performed_at);
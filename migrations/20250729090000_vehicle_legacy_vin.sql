@@ -0,0 +1,2 @@
+-- Pre-1981 / non-ISO VINs are stored as is and never decoded
+ALTER TABLE vehicles ADD COLUMN vin_legacy BOOLEAN NOT NULL DEFAULT false;
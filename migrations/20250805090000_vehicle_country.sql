@@ -0,0 +1,4 @@
+-- License plates are unique per country instead of globally
+ALTER TABLE vehicles ADD COLUMN country TEXT NOT NULL DEFAULT 'KZ';
+ALTER TABLE vehicles DROP CONSTRAINT vehicles_license_plate_key;
+ALTER TABLE vehicles ADD CONSTRAINT vehicles_country_license_plate_key UNIQUE (country, license_plate);
@@ -0,0 +1,21 @@
+-- Full powertrain set. New enum values can't be used in the transaction that adds them, so the
+-- check below compares the text of the enum.
+ALTER TYPE engine_type ADD VALUE IF NOT EXISTS 'Hybrid';
+ALTER TYPE engine_type ADD VALUE IF NOT EXISTS 'PluginHybrid';
+ALTER TYPE engine_type ADD VALUE IF NOT EXISTS 'Cng';
+ALTER TYPE engine_type ADD VALUE IF NOT EXISTS 'Lpg';
+ALTER TYPE engine_type ADD VALUE IF NOT EXISTS 'Hydrogen';
+ALTER TYPE engine_type ADD VALUE IF NOT EXISTS 'Other';
+
+ALTER TABLE vehicles
+    ADD COLUMN engine_type_other TEXT,
+    ADD COLUMN battery_capacity NUMERIC(8, 2) CHECK (battery_capacity > 0),
+    ADD COLUMN tank_capacity NUMERIC(8, 2) CHECK (tank_capacity > 0),
+    ADD CONSTRAINT vehicles_engine_type_other_check
+        CHECK ((engine_type::TEXT = 'Other') = (engine_type_other IS NOT NULL));
+
+-- Powertrains a maintenance type applies to (e.g., no oil change on an electric vehicle)
+CREATE TYPE maintenance_applicability AS ENUM ('Any', 'CombustionEngine', 'ElectricDrive', 'GasSystem', 'FuelCell');
+
+ALTER TABLE maintenance_types
+    ADD COLUMN applicability maintenance_applicability NOT NULL DEFAULT 'Any';
@@ -0,0 +1,49 @@
+-- Categories of maintenance types (optional for the existing types)
+CREATE TYPE maintenance_category AS ENUM ('Engine', 'Brakes', 'Tires', 'Body', 'Electrical', 'Inspection');
+
+ALTER TABLE maintenance_types
+    ADD COLUMN category maintenance_category;
+
+-- Default intervals of the catalog, optionally narrowed to a make, model and/or engine type
+CREATE TABLE maintenance_interval_templates (
+    id SERIAL PRIMARY KEY,
+    maintenance_type_id INTEGER NOT NULL REFERENCES maintenance_types(id) ON DELETE CASCADE,
+    make TEXT,
+    model TEXT,
+    engine_type engine_type,
+    engine_type_other TEXT,
+    interval_type maintenance_interval_type NOT NULL,
+    interval_value INTEGER NOT NULL CHECK (interval_value > 0),
+    red_threshold INTEGER NOT NULL CHECK (red_threshold BETWEEN 0 AND 100), -- in percentage
+    yellow_threshold INTEGER NOT NULL CHECK (yellow_threshold <= red_threshold), -- in percentage
+
+    -- Timestamps and user tracking
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    created_by UUID NOT NULL REFERENCES users(uuid) ON DELETE SET NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_by UUID NOT NULL REFERENCES users(uuid) ON DELETE SET NULL,
+
+    CONSTRAINT maintenance_interval_templates_model_check CHECK (model IS NULL OR make IS NOT NULL),
+    CONSTRAINT maintenance_interval_templates_engine_type_other_check
+        CHECK (COALESCE(engine_type::TEXT = 'Other', false) = (engine_type_other IS NOT NULL))
+);
+
+-- One template per scope and interval type (NULL scope fields compare equal). An enum cast to
+-- text isn't immutable, so a template of any engine type is indexed as 'Other' with an empty name,
+-- which no template of an 'Other' engine type has.
+CREATE UNIQUE INDEX maintenance_interval_templates_scope_key
+ON maintenance_interval_templates (
+    maintenance_type_id,
+    interval_type,
+    COALESCE(LOWER(make), ''),
+    COALESCE(LOWER(model), ''),
+    COALESCE(engine_type, 'Other'),
+    COALESCE(engine_type_other, '')
+);
+
+-- A maintenance type can have a rule per interval type (e.g., oil change every 10 000 km and
+-- every year)
+ALTER TABLE maintenances
+    DROP CONSTRAINT maintenances_vehicle_id_maintenance_type_id_key,
+    ADD CONSTRAINT maintenances_vehicle_id_maintenance_type_id_interval_type_key
+        UNIQUE (vehicle_id, maintenance_type_id, interval_type);
@@ -0,0 +1,3 @@
+-- Steps of the standard procedure of a maintenance type (e.g., Drain the oil, Replace the filter)
+ALTER TABLE maintenance_types
+    ADD COLUMN checklist TEXT[] NOT NULL DEFAULT '{}';
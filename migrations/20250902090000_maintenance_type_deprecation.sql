@@ -0,0 +1,16 @@
+-- Deprecated (soft deleted) maintenance types are hidden but keep their history
+ALTER TABLE maintenance_types
+    ADD COLUMN deprecated_at TIMESTAMPTZ,
+    ADD COLUMN deprecated_by UUID REFERENCES users(uuid) ON DELETE SET NULL;
+
+-- Deleting a maintenance type must not silently delete the rules and records referencing it.
+-- NO ACTION (checked at the end of the statement) still lets a vehicle delete cascade to both.
+ALTER TABLE maintenances
+    DROP CONSTRAINT maintenances_maintenance_type_id_fkey,
+    ADD CONSTRAINT maintenances_maintenance_type_id_fkey
+        FOREIGN KEY (maintenance_type_id) REFERENCES maintenance_types(id) ON DELETE NO ACTION;
+
+ALTER TABLE maintenance_records
+    DROP CONSTRAINT maintenance_records_maintenance_id_fkey,
+    ADD CONSTRAINT maintenance_records_maintenance_id_fkey
+        FOREIGN KEY (maintenance_id) REFERENCES maintenances(id) ON DELETE NO ACTION;
@@ -0,0 +1,35 @@
+-- Full-text (English and Russian) and fuzzy (trigram) search, see application::search.
+-- The search vectors hold the lexemes of both configurations, so a term stemmed with either one
+-- matches; the name weighs more than the description.
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+ALTER TABLE maintenance_types
+    ADD COLUMN search_vector TSVECTOR GENERATED ALWAYS AS (
+        setweight(to_tsvector('english', name), 'A')
+        || setweight(to_tsvector('russian', name), 'A')
+        || setweight(to_tsvector('english', description), 'B')
+        || setweight(to_tsvector('russian', description), 'B')
+    ) STORED;
+
+CREATE INDEX maintenance_types_search_vector_idx ON maintenance_types USING GIN (search_vector);
+CREATE INDEX maintenance_types_name_trgm_idx ON maintenance_types USING GIN (name gin_trgm_ops);
+
+-- Makes and models are names, they are not stemmed
+ALTER TABLE vehicles
+    ADD COLUMN search_vector TSVECTOR GENERATED ALWAYS AS (
+        setweight(to_tsvector('simple', make), 'A')
+        || setweight(to_tsvector('simple', model), 'A')
+    ) STORED;
+
+CREATE INDEX vehicles_search_vector_idx ON vehicles USING GIN (search_vector);
+CREATE INDEX vehicles_make_model_trgm_idx ON vehicles USING GIN ((make || ' ' || model) gin_trgm_ops);
+CREATE INDEX vehicles_license_plate_trgm_idx ON vehicles USING GIN (license_plate gin_trgm_ops);
+CREATE INDEX vehicles_vin_trgm_idx ON vehicles USING GIN (vin gin_trgm_ops);
+
+ALTER TABLE maintenance_records
+    ADD COLUMN search_vector TSVECTOR GENERATED ALWAYS AS (
+        to_tsvector('english', details) || to_tsvector('russian', details)
+    ) STORED;
+
+CREATE INDEX maintenance_records_search_vector_idx ON maintenance_records USING GIN (search_vector);
+CREATE INDEX maintenance_records_details_trgm_idx ON maintenance_records USING GIN (details gin_trgm_ops);
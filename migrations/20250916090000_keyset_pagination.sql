@@ -0,0 +1,23 @@
+-- Indexes of the keyset (cursor) pagination, see application::shared::pagination.
+-- A page reads the rows after (or before) a position with a row comparison, e.g.
+--   WHERE (created_at, id) > ($1, $2) ORDER BY created_at, id LIMIT $3
+-- so every sort column needs an index ending with the id as tiebreaker (the uuid of a vehicle,
+-- whose table has no id column).
+CREATE INDEX vehicles_make_uuid_idx ON vehicles(make, uuid);
+CREATE INDEX vehicles_model_uuid_idx ON vehicles(model, uuid);
+CREATE INDEX vehicles_year_uuid_idx ON vehicles(year, uuid);
+CREATE INDEX vehicles_license_plate_uuid_idx ON vehicles(license_plate, uuid);
+CREATE INDEX vehicles_country_uuid_idx ON vehicles(country, uuid);
+CREATE INDEX vehicles_engine_type_uuid_idx ON vehicles(engine_type, uuid);
+CREATE INDEX vehicles_created_at_uuid_idx ON vehicles(created_at, uuid);
+CREATE INDEX vehicles_updated_at_uuid_idx ON vehicles(updated_at, uuid);
+
+CREATE INDEX maintenance_types_name_id_idx ON maintenance_types(name, id)
+WHERE deprecated_at IS NULL;
+
+CREATE INDEX vehicle_statuses_vehicle_created_at_id_idx
+ON vehicle_statuses(vehicle_id, created_at, id);
+
+DROP INDEX maintenance_records_vehicle_performed_at;
+CREATE INDEX maintenance_records_vehicle_performed_at
+ON maintenance_records(vehicle_id, performed_at, id);
@@ -0,0 +1,47 @@
+-- Maintenance records are identified by a UUID, assigned by the application like the other
+-- aggregates it creates, so a record and its cost lines are written in the transaction of the
+-- unit of work without reading back a generated id.
+ALTER TABLE maintenance_records ADD COLUMN uuid UUID NOT NULL DEFAULT gen_random_uuid();
+
+ALTER TABLE maintenance_cost_lines ADD COLUMN maintenance_record_uuid UUID;
+UPDATE maintenance_cost_lines
+SET maintenance_record_uuid = maintenance_records.uuid
+FROM maintenance_records
+WHERE maintenance_records.id = maintenance_cost_lines.maintenance_record_id;
+
+ALTER TABLE maintenance_cost_lines
+    DROP CONSTRAINT maintenance_cost_lines_maintenance_record_id_fkey,
+    DROP CONSTRAINT maintenance_cost_lines_maintenance_record_id_position_key,
+    DROP COLUMN maintenance_record_id;
+ALTER TABLE maintenance_cost_lines
+    RENAME COLUMN maintenance_record_uuid TO maintenance_record_id;
+
+DROP INDEX maintenance_records_vehicle_performed_at;
+ALTER TABLE maintenance_records
+    DROP CONSTRAINT maintenance_records_pkey,
+    DROP COLUMN id;
+ALTER TABLE maintenance_records RENAME COLUMN uuid TO id;
+ALTER TABLE maintenance_records ADD CONSTRAINT maintenance_records_pkey PRIMARY KEY (id);
+
+ALTER TABLE maintenance_cost_lines
+    ALTER COLUMN maintenance_record_id SET NOT NULL,
+    ADD CONSTRAINT maintenance_cost_lines_maintenance_record_id_fkey
+        FOREIGN KEY (maintenance_record_id) REFERENCES maintenance_records(id) ON DELETE CASCADE,
+    ADD CONSTRAINT maintenance_cost_lines_maintenance_record_id_position_key
+        UNIQUE (maintenance_record_id, position);
+
+CREATE INDEX maintenance_records_vehicle_performed_at
+ON maintenance_records(vehicle_id, performed_at, id);
+
+-- The rules track who created and last updated them, like the maintenance types; the existing
+-- rules are attributed to the creator of their maintenance type.
+ALTER TABLE maintenances
+    ADD COLUMN created_by UUID REFERENCES users(uuid) ON DELETE SET NULL,
+    ADD COLUMN updated_by UUID REFERENCES users(uuid) ON DELETE SET NULL;
+UPDATE maintenances
+SET created_by = maintenance_types.created_by, updated_by = maintenance_types.created_by
+FROM maintenance_types
+WHERE maintenance_types.id = maintenances.maintenance_type_id;
+ALTER TABLE maintenances
+    ALTER COLUMN created_by SET NOT NULL,
+    ALTER COLUMN updated_by SET NOT NULL;
@@ -0,0 +1,126 @@
+-- Organizations (tenants): every row belongs to one, and row-level security hides the rows of the
+-- other organizations from a session, whose organization is set in `app.organization_id`.
+CREATE TABLE organizations (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    name TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+
+    CONSTRAINT organizations_name_key UNIQUE (name)
+);
+
+-- The existing rows belong to a default organization
+INSERT INTO organizations (id, name)
+VALUES ('00000000-0000-0000-0000-000000000001', 'Default');
+
+ALTER TABLE users
+    ADD COLUMN organization_id UUID NOT NULL DEFAULT '00000000-0000-0000-0000-000000000001'
+        REFERENCES organizations(id) ON DELETE CASCADE;
+ALTER TABLE vehicles
+    ADD COLUMN organization_id UUID NOT NULL DEFAULT '00000000-0000-0000-0000-000000000001'
+        REFERENCES organizations(id) ON DELETE CASCADE;
+ALTER TABLE vehicle_statuses
+    ADD COLUMN organization_id UUID NOT NULL DEFAULT '00000000-0000-0000-0000-000000000001'
+        REFERENCES organizations(id) ON DELETE CASCADE;
+ALTER TABLE maintenance_types
+    ADD COLUMN organization_id UUID NOT NULL DEFAULT '00000000-0000-0000-0000-000000000001'
+        REFERENCES organizations(id) ON DELETE CASCADE;
+ALTER TABLE maintenance_interval_templates
+    ADD COLUMN organization_id UUID NOT NULL DEFAULT '00000000-0000-0000-0000-000000000001'
+        REFERENCES organizations(id) ON DELETE CASCADE;
+ALTER TABLE maintenances
+    ADD COLUMN organization_id UUID NOT NULL DEFAULT '00000000-0000-0000-0000-000000000001'
+        REFERENCES organizations(id) ON DELETE CASCADE;
+ALTER TABLE maintenance_records
+    ADD COLUMN organization_id UUID NOT NULL DEFAULT '00000000-0000-0000-0000-000000000001'
+        REFERENCES organizations(id) ON DELETE CASCADE;
+ALTER TABLE maintenance_cost_lines
+    ADD COLUMN organization_id UUID NOT NULL DEFAULT '00000000-0000-0000-0000-000000000001'
+        REFERENCES organizations(id) ON DELETE CASCADE;
+ALTER TABLE fuel_events
+    ADD COLUMN organization_id UUID NOT NULL DEFAULT '00000000-0000-0000-0000-000000000001'
+        REFERENCES organizations(id) ON DELETE CASCADE;
+ALTER TABLE vehicle_fuel_tanks
+    ADD COLUMN organization_id UUID NOT NULL DEFAULT '00000000-0000-0000-0000-000000000001'
+        REFERENCES organizations(id) ON DELETE CASCADE;
+
+-- New rows are written with the organization of the user, never the default one
+ALTER TABLE users ALTER COLUMN organization_id DROP DEFAULT;
+ALTER TABLE vehicles ALTER COLUMN organization_id DROP DEFAULT;
+ALTER TABLE vehicle_statuses ALTER COLUMN organization_id DROP DEFAULT;
+ALTER TABLE maintenance_types ALTER COLUMN organization_id DROP DEFAULT;
+ALTER TABLE maintenance_interval_templates ALTER COLUMN organization_id DROP DEFAULT;
+ALTER TABLE maintenances ALTER COLUMN organization_id DROP DEFAULT;
+ALTER TABLE maintenance_records ALTER COLUMN organization_id DROP DEFAULT;
+ALTER TABLE maintenance_cost_lines ALTER COLUMN organization_id DROP DEFAULT;
+ALTER TABLE fuel_events ALTER COLUMN organization_id DROP DEFAULT;
+ALTER TABLE vehicle_fuel_tanks ALTER COLUMN organization_id DROP DEFAULT;
+
+-- VINs, license plates and maintenance type names are unique per organization
+ALTER TABLE vehicles
+    DROP CONSTRAINT vehicles_vin_key,
+    DROP CONSTRAINT vehicles_country_license_plate_key,
+    ADD CONSTRAINT vehicles_organization_id_vin_key UNIQUE (organization_id, vin),
+    ADD CONSTRAINT vehicles_organization_id_country_license_plate_key
+        UNIQUE (organization_id, country, license_plate);
+ALTER TABLE maintenance_types
+    DROP CONSTRAINT maintenance_types_name_key,
+    ADD CONSTRAINT maintenance_types_organization_id_name_key UNIQUE (organization_id, name);
+
+CREATE INDEX users_organization_id_idx ON users(organization_id);
+CREATE INDEX vehicle_statuses_organization_id_idx ON vehicle_statuses(organization_id);
+CREATE INDEX maintenance_interval_templates_organization_id_idx
+ON maintenance_interval_templates(organization_id);
+CREATE INDEX maintenances_organization_id_idx ON maintenances(organization_id);
+CREATE INDEX maintenance_records_organization_id_idx ON maintenance_records(organization_id);
+CREATE INDEX maintenance_cost_lines_organization_id_idx ON maintenance_cost_lines(organization_id);
+CREATE INDEX fuel_events_organization_id_idx ON fuel_events(organization_id);
+CREATE INDEX vehicle_fuel_tanks_organization_id_idx ON vehicle_fuel_tanks(organization_id);
+
+-- Row-level security, forced on the owner of the tables too. A session without an organization
+-- sees no rows (`current_setting` is NULL, or empty once a local setting ended). The users and
+-- organizations are left out: a user is looked up by email before their organization is known.
+ALTER TABLE vehicles ENABLE ROW LEVEL SECURITY;
+ALTER TABLE vehicles FORCE ROW LEVEL SECURITY;
+CREATE POLICY tenant_isolation ON vehicles
+    USING (organization_id = NULLIF(current_setting('app.organization_id', true), '')::uuid);
+
+ALTER TABLE vehicle_statuses ENABLE ROW LEVEL SECURITY;
+ALTER TABLE vehicle_statuses FORCE ROW LEVEL SECURITY;
+CREATE POLICY tenant_isolation ON vehicle_statuses
+    USING (organization_id = NULLIF(current_setting('app.organization_id', true), '')::uuid);
+
+ALTER TABLE maintenance_types ENABLE ROW LEVEL SECURITY;
+ALTER TABLE maintenance_types FORCE ROW LEVEL SECURITY;
+CREATE POLICY tenant_isolation ON maintenance_types
+    USING (organization_id = NULLIF(current_setting('app.organization_id', true), '')::uuid);
+
+ALTER TABLE maintenance_interval_templates ENABLE ROW LEVEL SECURITY;
+ALTER TABLE maintenance_interval_templates FORCE ROW LEVEL SECURITY;
+CREATE POLICY tenant_isolation ON maintenance_interval_templates
+    USING (organization_id = NULLIF(current_setting('app.organization_id', true), '')::uuid);
+
+ALTER TABLE maintenances ENABLE ROW LEVEL SECURITY;
+ALTER TABLE maintenances FORCE ROW LEVEL SECURITY;
+CREATE POLICY tenant_isolation ON maintenances
+    USING (organization_id = NULLIF(current_setting('app.organization_id', true), '')::uuid);
+
+ALTER TABLE maintenance_records ENABLE ROW LEVEL SECURITY;
+ALTER TABLE maintenance_records FORCE ROW LEVEL SECURITY;
+CREATE POLICY tenant_isolation ON maintenance_records
+    USING (organization_id = NULLIF(current_setting('app.organization_id', true), '')::uuid);
+
+ALTER TABLE maintenance_cost_lines ENABLE ROW LEVEL SECURITY;
+ALTER TABLE maintenance_cost_lines FORCE ROW LEVEL SECURITY;
+CREATE POLICY tenant_isolation ON maintenance_cost_lines
+    USING (organization_id = NULLIF(current_setting('app.organization_id', true), '')::uuid);
+
+ALTER TABLE fuel_events ENABLE ROW LEVEL SECURITY;
+ALTER TABLE fuel_events FORCE ROW LEVEL SECURITY;
+CREATE POLICY tenant_isolation ON fuel_events
+    USING (organization_id = NULLIF(current_setting('app.organization_id', true), '')::uuid);
+
+ALTER TABLE vehicle_fuel_tanks ENABLE ROW LEVEL SECURITY;
+ALTER TABLE vehicle_fuel_tanks FORCE ROW LEVEL SECURITY;
+CREATE POLICY tenant_isolation ON vehicle_fuel_tanks
+    USING (organization_id = NULLIF(current_setting('app.organization_id', true), '')::uuid);
@@ -0,0 +1,101 @@
+-- Depots: where the vehicles are based. Regions group depots, which have sites (yards, workshops,
+-- parking lots); a vehicle is based at the depot of its latest movement.
+CREATE TABLE regions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    name TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+
+    CONSTRAINT regions_organization_id_name_key UNIQUE (organization_id, name)
+);
+
+-- A region with depots can't be deleted
+CREATE TABLE depots (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    region_id UUID NOT NULL REFERENCES regions(id) ON DELETE NO ACTION,
+    name TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+
+    CONSTRAINT depots_organization_id_name_key UNIQUE (organization_id, name)
+);
+
+CREATE TABLE sites (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    depot_id UUID NOT NULL REFERENCES depots(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+
+    CONSTRAINT sites_depot_id_name_key UNIQUE (depot_id, name)
+);
+
+-- The depots a depot manager is limited to
+CREATE TABLE depot_managers (
+    depot_id UUID NOT NULL REFERENCES depots(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(uuid) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+
+    PRIMARY KEY (depot_id, user_id)
+);
+
+-- Append-only: a depot with movements can't be deleted
+CREATE TABLE vehicle_movements (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    vehicle_id UUID NOT NULL REFERENCES vehicles(uuid) ON DELETE CASCADE,
+    from_depot_id UUID REFERENCES depots(id) ON DELETE NO ACTION,
+    to_depot_id UUID NOT NULL REFERENCES depots(id) ON DELETE NO ACTION,
+    moved_at TIMESTAMPTZ NOT NULL,
+    moved_by UUID NOT NULL REFERENCES users(uuid) ON DELETE CASCADE,
+    notes TEXT NOT NULL DEFAULT '',
+    latest BOOLEAN NOT NULL DEFAULT false,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+
+    CONSTRAINT vehicle_movements_depots_check CHECK (from_depot_id IS DISTINCT FROM to_depot_id)
+);
+
+-- Enforce at most one 'latest' movement per vehicle: its depot is the home depot
+CREATE UNIQUE INDEX one_latest_movement_per_vehicle
+ON vehicle_movements(vehicle_id)
+WHERE latest = true;
+
+CREATE INDEX depots_region_id_idx ON depots(region_id);
+CREATE INDEX depot_managers_user_id_idx ON depot_managers(user_id);
+CREATE INDEX vehicle_movements_vehicle_id_moved_at_idx ON vehicle_movements(vehicle_id, moved_at);
+CREATE INDEX vehicle_movements_to_depot_id_idx ON vehicle_movements(to_depot_id)
+WHERE latest = true;
+
+ALTER TABLE regions ENABLE ROW LEVEL SECURITY;
+ALTER TABLE regions FORCE ROW LEVEL SECURITY;
+CREATE POLICY tenant_isolation ON regions
+    USING (organization_id = NULLIF(current_setting('app.organization_id', true), '')::uuid);
+
+ALTER TABLE depots ENABLE ROW LEVEL SECURITY;
+ALTER TABLE depots FORCE ROW LEVEL SECURITY;
+CREATE POLICY tenant_isolation ON depots
+    USING (organization_id = NULLIF(current_setting('app.organization_id', true), '')::uuid);
+
+ALTER TABLE sites ENABLE ROW LEVEL SECURITY;
+ALTER TABLE sites FORCE ROW LEVEL SECURITY;
+CREATE POLICY tenant_isolation ON sites
+    USING (organization_id = NULLIF(current_setting('app.organization_id', true), '')::uuid);
+
+ALTER TABLE depot_managers ENABLE ROW LEVEL SECURITY;
+ALTER TABLE depot_managers FORCE ROW LEVEL SECURITY;
+CREATE POLICY tenant_isolation ON depot_managers
+    USING (organization_id = NULLIF(current_setting('app.organization_id', true), '')::uuid);
+
+ALTER TABLE vehicle_movements ENABLE ROW LEVEL SECURITY;
+ALTER TABLE vehicle_movements FORCE ROW LEVEL SECURITY;
+CREATE POLICY tenant_isolation ON vehicle_movements
+    USING (organization_id = NULLIF(current_setting('app.organization_id', true), '')::uuid);
@@ -0,0 +1,18 @@
+-- Telematics devices: the devices fitted in the vehicles, whose readings become vehicle statuses.
+-- A device id is unique per organization, a device belongs to one vehicle.
+CREATE TABLE telematics_devices (
+    device_id TEXT NOT NULL,
+    vehicle_id UUID NOT NULL REFERENCES vehicles(uuid) ON DELETE CASCADE,
+    registered_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+
+    PRIMARY KEY (organization_id, device_id)
+);
+
+CREATE INDEX telematics_devices_vehicle_id_idx ON telematics_devices(vehicle_id);
+
+ALTER TABLE telematics_devices ENABLE ROW LEVEL SECURITY;
+ALTER TABLE telematics_devices FORCE ROW LEVEL SECURITY;
+CREATE POLICY tenant_isolation ON telematics_devices
+    USING (organization_id = NULLIF(current_setting('app.organization_id', true), '')::uuid);
@@ -0,0 +1,23 @@
+-- Trips: the GPS tracks of the vehicles, compared with the odometer readings of their statuses.
+-- The track is an encoded polyline (1e-5 degrees), its distance is computed when it is recorded.
+CREATE TABLE trips (
+    id UUID PRIMARY KEY,
+    vehicle_id UUID NOT NULL REFERENCES vehicles(uuid) ON DELETE CASCADE,
+    driver_id UUID REFERENCES users(uuid) ON DELETE SET NULL,
+    started_at TIMESTAMPTZ NOT NULL,
+    ended_at TIMESTAMPTZ NOT NULL,
+    polyline TEXT NOT NULL,
+    distance_km NUMERIC(10, 3) NOT NULL CHECK (distance_km >= 0),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+
+    CONSTRAINT trips_period_check CHECK (ended_at > started_at)
+);
+
+CREATE INDEX trips_vehicle_id_started_at_idx ON trips(vehicle_id, started_at);
+
+ALTER TABLE trips ENABLE ROW LEVEL SECURITY;
+ALTER TABLE trips FORCE ROW LEVEL SECURITY;
+CREATE POLICY tenant_isolation ON trips
+    USING (organization_id = NULLIF(current_setting('app.organization_id', true), '')::uuid);
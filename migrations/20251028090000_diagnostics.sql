@@ -0,0 +1,73 @@
+-- Diagnostics: the OBD-II trouble codes reported by the vehicles, and the rules opening alerts or
+-- maintenance tasks when a severe code appears.
+CREATE TYPE dtc_severity AS ENUM ('Info', 'Warning', 'Critical');
+
+-- A DTC event lasts from the first report of a code until it is cleared
+CREATE TABLE dtc_events (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    vehicle_id UUID NOT NULL REFERENCES vehicles(uuid) ON DELETE CASCADE,
+    code TEXT NOT NULL CHECK (code ~ '^[PCBU][0-3][0-9A-F]{3}$'),
+    severity dtc_severity NOT NULL,
+    freeze_frame JSONB NOT NULL DEFAULT '{}',
+    first_seen_at TIMESTAMPTZ NOT NULL,
+    last_seen_at TIMESTAMPTZ NOT NULL,
+    occurrences INTEGER NOT NULL DEFAULT 1 CHECK (occurrences > 0),
+    cleared_at TIMESTAMPTZ,
+    cleared_by UUID REFERENCES users(uuid) ON DELETE SET NULL,
+
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+
+    CONSTRAINT dtc_events_seen_check CHECK (last_seen_at >= first_seen_at)
+);
+
+-- Enforce at most one active event per code of a vehicle
+CREATE UNIQUE INDEX one_active_dtc_event_per_code
+ON dtc_events(vehicle_id, code)
+WHERE cleared_at IS NULL;
+
+CREATE INDEX dtc_events_vehicle_id_first_seen_at_idx ON dtc_events(vehicle_id, first_seen_at);
+
+-- A rule opening maintenance tasks is deleted with its maintenance type
+CREATE TABLE diagnostic_rules (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    name TEXT NOT NULL,
+    min_severity dtc_severity NOT NULL,
+    code_prefix TEXT CHECK (code_prefix ~ '^[PCBU]([0-3][0-9A-F]{0,3})?$'),
+    maintenance_type_id INTEGER REFERENCES maintenance_types(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+
+    CONSTRAINT diagnostic_rules_organization_id_name_key UNIQUE (organization_id, name)
+);
+
+-- An alert (or maintenance task, after the action of its rule) is open until its code is cleared
+CREATE TABLE diagnostic_alerts (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    vehicle_id UUID NOT NULL REFERENCES vehicles(uuid) ON DELETE CASCADE,
+    dtc_event_id UUID NOT NULL REFERENCES dtc_events(id) ON DELETE CASCADE,
+    rule_id UUID NOT NULL REFERENCES diagnostic_rules(id) ON DELETE CASCADE,
+    opened_at TIMESTAMPTZ NOT NULL,
+    resolved_at TIMESTAMPTZ,
+
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+
+    CONSTRAINT diagnostic_alerts_dtc_event_id_rule_id_key UNIQUE (dtc_event_id, rule_id)
+);
+
+CREATE INDEX diagnostic_alerts_vehicle_id_opened_at_idx ON diagnostic_alerts(vehicle_id, opened_at);
+
+ALTER TABLE dtc_events ENABLE ROW LEVEL SECURITY;
+ALTER TABLE dtc_events FORCE ROW LEVEL SECURITY;
+CREATE POLICY tenant_isolation ON dtc_events
+    USING (organization_id = NULLIF(current_setting('app.organization_id', true), '')::uuid);
+
+ALTER TABLE diagnostic_rules ENABLE ROW LEVEL SECURITY;
+ALTER TABLE diagnostic_rules FORCE ROW LEVEL SECURITY;
+CREATE POLICY tenant_isolation ON diagnostic_rules
+    USING (organization_id = NULLIF(current_setting('app.organization_id', true), '')::uuid);
+
+ALTER TABLE diagnostic_alerts ENABLE ROW LEVEL SECURITY;
+ALTER TABLE diagnostic_alerts FORCE ROW LEVEL SECURITY;
+CREATE POLICY tenant_isolation ON diagnostic_alerts
+    USING (organization_id = NULLIF(current_setting('app.organization_id', true), '')::uuid);
@@ -0,0 +1,78 @@
+-- Inspections: the daily checklists of the drivers (pre-trip and post-trip DVIRs), and the
+-- defects they found until a mechanic signs them off.
+CREATE TYPE inspection_kind AS ENUM ('PreTrip', 'PostTrip');
+CREATE TYPE defect_resolution AS ENUM ('Repaired', 'NoRepairNeeded');
+
+-- The items are a JSON array of {id, label, critical, photo_required, comment_required_on_fail}
+CREATE TABLE inspection_templates (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    name TEXT NOT NULL,
+    kind inspection_kind NOT NULL,
+    items JSONB NOT NULL CHECK (jsonb_typeof(items) = 'array' AND jsonb_array_length(items) > 0),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    created_by UUID NOT NULL REFERENCES users(uuid),
+
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+
+    CONSTRAINT inspection_templates_organization_id_name_key UNIQUE (organization_id, name)
+);
+
+-- A report is deleted with the vehicle status recorded with it. The answers are a JSON array of
+-- {item_id, label, critical, result, comment, photos}, the items as they were when submitted.
+CREATE TABLE inspection_reports (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    template_id UUID NOT NULL REFERENCES inspection_templates(id),
+    vehicle_id UUID NOT NULL REFERENCES vehicles(uuid) ON DELETE CASCADE,
+    vehicle_status_id INTEGER NOT NULL REFERENCES vehicle_statuses(id) ON DELETE CASCADE,
+    kind inspection_kind NOT NULL,
+    submitted_by UUID NOT NULL REFERENCES users(uuid) ON DELETE CASCADE,
+    submitted_at TIMESTAMPTZ NOT NULL,
+    answers JSONB NOT NULL CHECK (jsonb_typeof(answers) = 'array'),
+
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE
+);
+
+CREATE INDEX inspection_reports_vehicle_id_submitted_at_idx
+ON inspection_reports(vehicle_id, submitted_at);
+
+CREATE TABLE inspection_defects (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    report_id UUID NOT NULL REFERENCES inspection_reports(id) ON DELETE CASCADE,
+    vehicle_id UUID NOT NULL REFERENCES vehicles(uuid) ON DELETE CASCADE,
+    item_id UUID NOT NULL,
+    label TEXT NOT NULL,
+    critical BOOLEAN NOT NULL,
+    comment TEXT,
+    reported_at TIMESTAMPTZ NOT NULL,
+    resolution defect_resolution,
+    signed_off_at TIMESTAMPTZ,
+    signed_off_by UUID REFERENCES users(uuid) ON DELETE SET NULL,
+    sign_off_notes TEXT,
+
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+
+    CONSTRAINT inspection_defects_report_id_item_id_key UNIQUE (report_id, item_id),
+    CONSTRAINT inspection_defects_sign_off_check CHECK (
+        (resolution IS NULL) = (signed_off_at IS NULL) AND signed_off_at >= reported_at
+    )
+);
+
+-- The open defects of a vehicle decide whether it is out of service
+CREATE INDEX inspection_defects_open_vehicle_id_idx
+ON inspection_defects(vehicle_id, reported_at)
+WHERE signed_off_at IS NULL;
+
+ALTER TABLE inspection_templates ENABLE ROW LEVEL SECURITY;
+ALTER TABLE inspection_templates FORCE ROW LEVEL SECURITY;
+CREATE POLICY tenant_isolation ON inspection_templates
+    USING (organization_id = NULLIF(current_setting('app.organization_id', true), '')::uuid);
+
+ALTER TABLE inspection_reports ENABLE ROW LEVEL SECURITY;
+ALTER TABLE inspection_reports FORCE ROW LEVEL SECURITY;
+CREATE POLICY tenant_isolation ON inspection_reports
+    USING (organization_id = NULLIF(current_setting('app.organization_id', true), '')::uuid);
+
+ALTER TABLE inspection_defects ENABLE ROW LEVEL SECURITY;
+ALTER TABLE inspection_defects FORCE ROW LEVEL SECURITY;
+CREATE POLICY tenant_isolation ON inspection_defects
+    USING (organization_id = NULLIF(current_setting('app.organization_id', true), '')::uuid);
@@ -0,0 +1,2 @@
+-- Hydrogen (and CNG, sold by weight) is measured in kilograms
+ALTER TYPE energy_unit ADD VALUE IF NOT EXISTS 'Kilograms';
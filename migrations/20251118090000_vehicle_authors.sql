@@ -0,0 +1,5 @@
+-- A vehicle is created without an author (the vehicle repository is given none), as in the SQLite
+-- schema; deleting its author sets them to NULL (ON DELETE SET NULL) instead of failing.
+ALTER TABLE vehicles
+    ALTER COLUMN created_by DROP NOT NULL,
+    ALTER COLUMN updated_by DROP NOT NULL;